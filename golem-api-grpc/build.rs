@@ -41,14 +41,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "proto/golem/component/function_parameter.proto",
                 "proto/golem/component/function_result.proto",
                 "proto/golem/component/component_constraints.proto",
+                "proto/golem/component/capability_policy.proto",
                 "proto/golem/component/component.proto",
                 "proto/golem/component/component_id.proto",
                 "proto/golem/component/component_metadata.proto",
+                "proto/golem/component/component_volume.proto",
+                "proto/golem/component/egress_policy.proto",
+                "proto/golem/component/failure_policy.proto",
+                "proto/golem/component/oplog_policy.proto",
+                "proto/golem/component/secret_reference.proto",
                 "proto/golem/component/versioned_name.proto",
                 "proto/golem/component/versioned_component_id.proto",
                 "proto/golem/component/v1/component_error.proto",
                 "proto/golem/component/v1/component_service.proto",
                 "proto/golem/component/v1/plugin_service.proto",
+                "proto/golem/component/v1/secret_service.proto",
                 "proto/golem/componentcompilation/v1/component_compilation_service.proto",
                 "proto/golem/worker/complete_parameters.proto",
                 "proto/golem/worker/idempotency_key.proto",
@@ -75,6 +82,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "proto/golem/apidefinition/api_definition.proto",
                 "proto/golem/apidefinition/v1/api_definition_service.proto",
                 "proto/golem/apidefinition/v1/api_definition_error.proto",
+                "proto/golem/apideployment/api_deployment.proto",
+                "proto/golem/apideployment/v1/api_deployment_service.proto",
+                "proto/golem/apideployment/v1/api_deployment_error.proto",
                 "proto/grpc/health/v1/health.proto",
             ],
             &[
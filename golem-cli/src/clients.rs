@@ -19,4 +19,5 @@ pub mod component;
 pub mod file_download;
 pub mod health_check;
 pub mod plugin;
+pub mod secret;
 pub mod worker;
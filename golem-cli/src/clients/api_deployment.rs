@@ -14,6 +14,7 @@
 
 use crate::model::{ApiDefinitionId, ApiDefinitionIdWithVersion, ApiDeployment, GolemError};
 use async_trait::async_trait;
+use golem_client::model::{ApiDeploymentRevision, ApiDeploymentRevisionDiff};
 
 #[async_trait]
 pub trait ApiDeploymentClient {
@@ -33,4 +34,16 @@ pub trait ApiDeploymentClient {
     ) -> Result<Vec<ApiDeployment>, GolemError>;
     async fn get(&self, site: &str) -> Result<ApiDeployment, GolemError>;
     async fn delete(&self, site: &str) -> Result<String, GolemError>;
+    async fn history(&self, site: &str) -> Result<Vec<ApiDeploymentRevision>, GolemError>;
+    async fn rollback(
+        &self,
+        site: &str,
+        revision: u64,
+    ) -> Result<ApiDeploymentRevision, GolemError>;
+    async fn diff(
+        &self,
+        site: &str,
+        from: u64,
+        to: u64,
+    ) -> Result<ApiDeploymentRevisionDiff, GolemError>;
 }
@@ -36,4 +36,11 @@ pub trait ApiSecurityClient {
         id: &str,
         project: &Self::ProjectContext,
     ) -> Result<ApiSecurityScheme, GolemError>;
+
+    /// Gets the security scheme, or `None` if it does not exist
+    async fn find(
+        &self,
+        id: &str,
+        project: &Self::ProjectContext,
+    ) -> Result<Option<ApiSecurityScheme>, GolemError>;
 }
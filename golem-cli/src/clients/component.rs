@@ -16,7 +16,9 @@ use crate::model::component::Component;
 use crate::model::{ComponentName, GolemError, PathBufOrStdin};
 use async_trait::async_trait;
 use golem_client::model::ComponentFilePathWithPermissionsList;
-use golem_client::model::{ComponentType, PluginInstallation};
+use golem_client::model::{ComponentGcReport, ComponentType, PluginInstallation};
+use golem_common::model::component_metadata::{CapabilityPolicy, OplogPolicy};
+use golem_common::model::secret::SecretReference;
 use golem_common::uri::oss::urn::ComponentUrn;
 use std::collections::HashMap;
 use std::path::Path;
@@ -82,4 +84,24 @@ pub trait ComponentClient {
         urn: &ComponentUrn,
         installation_id: &Uuid,
     ) -> Result<(), GolemError>;
+
+    async fn set_oplog_policy(
+        &self,
+        urn: &ComponentUrn,
+        policy: OplogPolicy,
+    ) -> Result<Component, GolemError>;
+
+    async fn set_capability_policy(
+        &self,
+        urn: &ComponentUrn,
+        policy: CapabilityPolicy,
+    ) -> Result<Component, GolemError>;
+
+    async fn set_secrets(
+        &self,
+        urn: &ComponentUrn,
+        secrets: Vec<SecretReference>,
+    ) -> Result<Component, GolemError>;
+
+    async fn gc_report(&self) -> Result<ComponentGcReport, GolemError>;
 }
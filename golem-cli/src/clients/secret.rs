@@ -0,0 +1,33 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::GolemError;
+use async_trait::async_trait;
+use golem_client::model::Secret;
+
+#[async_trait]
+pub trait SecretClient {
+    type ProjectContext;
+
+    async fn list(&self, project: &Self::ProjectContext) -> Result<Vec<Secret>, GolemError>;
+
+    async fn create(
+        &self,
+        name: String,
+        value: String,
+        project: &Self::ProjectContext,
+    ) -> Result<Secret, GolemError>;
+
+    async fn delete(&self, name: &str, project: &Self::ProjectContext) -> Result<(), GolemError>;
+}
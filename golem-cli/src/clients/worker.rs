@@ -18,9 +18,12 @@ use crate::model::{
     WorkersMetadataResponse,
 };
 use async_trait::async_trait;
-use golem_client::model::{InvokeParameters, InvokeResult, ScanCursor, WorkerFilter, WorkerId};
+use golem_client::model::{
+    InvokeParameters, InvokeResult, ScanCursor, UpdateDryRunResult, WorkerFilter, WorkerId,
+};
 use golem_common::model::public_oplog::PublicOplogEntry;
 use golem_common::uri::oss::urn::{ComponentUrn, WorkerUrn};
+use std::path::Path;
 
 #[async_trait]
 pub trait WorkerClient {
@@ -30,6 +33,7 @@ pub trait WorkerClient {
         component_urn: ComponentUrn,
         args: Vec<String>,
         env: Vec<(String, String)>,
+        labels: Vec<(String, String)>,
     ) -> Result<WorkerId, GolemError>;
 
     async fn invoke_and_await(
@@ -52,6 +56,19 @@ pub trait WorkerClient {
     async fn resume(&self, worker_urn: WorkerUrn) -> Result<(), GolemError>;
     async fn simulated_crash(&self, worker_urn: WorkerUrn) -> Result<(), GolemError>;
     async fn delete(&self, worker_urn: WorkerUrn) -> Result<(), GolemError>;
+    async fn update_labels(
+        &self,
+        worker_urn: WorkerUrn,
+        labels: Vec<(String, String)>,
+    ) -> Result<(), GolemError>;
+    async fn put_file(
+        &self,
+        worker_urn: WorkerUrn,
+        path: String,
+        source: &Path,
+    ) -> Result<(), GolemError>;
+    async fn delete_file(&self, worker_urn: WorkerUrn, path: String) -> Result<(), GolemError>;
+    async fn make_directory(&self, worker_urn: WorkerUrn, path: String) -> Result<(), GolemError>;
     async fn get_metadata(&self, worker_urn: WorkerUrn) -> Result<WorkerMetadata, GolemError>;
     async fn find_metadata(
         &self,
@@ -95,6 +112,12 @@ pub trait WorkerClient {
         target_version: u64,
     ) -> Result<(), GolemError>;
 
+    async fn dry_run_update(
+        &self,
+        worker_urn: WorkerUrn,
+        target_version: u64,
+    ) -> Result<UpdateDryRunResult, GolemError>;
+
     async fn get_oplog(
         &self,
         worker_urn: WorkerUrn,
@@ -15,12 +15,15 @@
 pub mod api_definition;
 pub mod api_deployment;
 pub mod api_security;
+pub mod app;
 pub mod component;
 pub mod plugin;
 pub mod profile;
+pub mod secret;
 pub mod worker;
 
 use crate::command::api_security::ApiSecuritySchemeSubcommand;
+use crate::command::app::AppCommand;
 use crate::completion;
 use crate::config::ProfileName;
 use crate::diagnose::{self, diagnose};
@@ -35,9 +38,9 @@ use clap::{self, Command, Subcommand};
 use colored::Colorize;
 use component::ComponentSubCommand;
 use golem_common::uri::oss::uri::ComponentUri;
-use golem_wasm_rpc_stubgen::App;
 use plugin::PluginSubcommand;
 use profile::{ProfileSubCommand, UniversalProfileAdd};
+use secret::SecretSubcommand;
 use std::future::Future;
 use std::path::PathBuf;
 use worker::WorkerSubcommand;
@@ -167,12 +170,12 @@ pub enum SharedCommand<
     PluginScopeRef: clap::Args,
     ProfileAdd: clap::Args,
 > {
-    /// Build components with application manifests
+    /// Build and deploy applications with application manifests
     #[cfg(feature = "stubgen")]
     #[group(skip)]
     App {
         #[clap(flatten)]
-        command: App,
+        command: AppCommand<ProjectRef>,
     },
     /// Upload and manage Golem components
     #[command()]
@@ -209,6 +212,13 @@ pub enum SharedCommand<
         subcommand: ApiSecuritySchemeSubcommand<ProjectRef>,
     },
 
+    /// Manage secrets exposed to workers
+    #[command()]
+    Secret {
+        #[command(subcommand)]
+        subcommand: SecretSubcommand<ProjectRef>,
+    },
+
     /// Manage plugins
     #[command()]
     Plugin {
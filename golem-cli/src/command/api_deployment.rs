@@ -65,6 +65,42 @@ pub enum ApiDeploymentSubcommand<ProjectRef: clap::Args> {
         #[arg(value_name = "subdomain.host")]
         site: String,
     },
+
+    /// List the revisions of an api deployment
+    #[command()]
+    History {
+        /// Deployment site
+        #[arg(value_name = "subdomain.host")]
+        site: String,
+    },
+
+    /// Rollback an api deployment to a previous revision
+    #[command()]
+    Rollback {
+        /// Deployment site
+        #[arg(value_name = "subdomain.host")]
+        site: String,
+
+        /// Revision to restore
+        #[arg(short, long)]
+        revision: u64,
+    },
+
+    /// Show the routes changed between two revisions of an api deployment
+    #[command()]
+    Diff {
+        /// Deployment site
+        #[arg(value_name = "subdomain.host")]
+        site: String,
+
+        /// Base revision
+        #[arg(long)]
+        from: u64,
+
+        /// Target revision
+        #[arg(long)]
+        to: u64,
+    },
 }
 
 impl<ProjectRef: clap::Args + Send + Sync + 'static> ApiDeploymentSubcommand<ProjectRef> {
@@ -91,6 +127,11 @@ impl<ProjectRef: clap::Args + Send + Sync + 'static> ApiDeploymentSubcommand<Pro
                 service.list(id, &project_id).await
             }
             ApiDeploymentSubcommand::Delete { site } => service.delete(site).await,
+            ApiDeploymentSubcommand::History { site } => service.history(site).await,
+            ApiDeploymentSubcommand::Rollback { site, revision } => {
+                service.rollback(site, revision).await
+            }
+            ApiDeploymentSubcommand::Diff { site, from, to } => service.diff(site, from, to).await,
         }
     }
 }
@@ -0,0 +1,219 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::component::app_ctx;
+use crate::model::app_ext::{
+    default_app_state_path, DeclarativeApp, DeclarativeComponent, HttpApiExtensions,
+};
+use crate::model::{Format, GolemError, GolemResult};
+use crate::service::app::AppDeployService;
+use crate::service::project::ProjectResolver;
+use clap::{ArgMatches, Args, Command, FromArgMatches, Subcommand};
+use golem_wasm_rpc_stubgen::model::app;
+use golem_wasm_rpc_stubgen::App;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// The `app` command: the build commands of the application manifests, extended with the
+/// declarative deployment commands
+#[derive(Debug)]
+pub enum AppCommand<ProjectRef: clap::Args> {
+    Build(App),
+    Deploy(AppDeploySubcommand<ProjectRef>),
+}
+
+impl<ProjectRef: clap::Args> FromArgMatches for AppCommand<ProjectRef> {
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, clap::Error> {
+        match matches.subcommand_name() {
+            Some(name) if AppDeploySubcommand::<ProjectRef>::has_subcommand(name) => Ok(
+                AppCommand::Deploy(AppDeploySubcommand::from_arg_matches(matches)?),
+            ),
+            _ => Ok(AppCommand::Build(App::from_arg_matches(matches)?)),
+        }
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), clap::Error> {
+        *self = Self::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+impl<ProjectRef: clap::Args> Args for AppCommand<ProjectRef> {
+    fn augment_args(cmd: Command) -> Command {
+        AppDeploySubcommand::<ProjectRef>::augment_subcommands(App::augment_args(cmd))
+    }
+
+    fn augment_args_for_update(cmd: Command) -> Command {
+        AppDeploySubcommand::<ProjectRef>::augment_subcommands_for_update(
+            App::augment_args_for_update(cmd),
+        )
+    }
+}
+
+#[derive(Subcommand, Debug)]
+#[command()]
+pub enum AppDeploySubcommand<ProjectRef: clap::Args> {
+    /// Shows the changes needed for the deployed application to match the application manifests
+    ///
+    /// Besides the components, the manifests can describe their plugin installations and workers,
+    /// and in their `httpApi` sections the API definitions, deployments and security schemes.
+    #[command(verbatim_doc_comment)]
+    Plan {
+        /// The project of the application
+        #[command(flatten)]
+        project_ref: ProjectRef,
+
+        /// Application manifest to use. Can be specified multiple times.
+        #[arg(long, short)]
+        app: Vec<PathBuf>,
+
+        /// Select build profile
+        #[arg(long, short)]
+        build_profile: Option<String>,
+
+        /// Also plan removing the resources created by previous deployments which are no longer
+        /// described by the application manifests
+        #[arg(long)]
+        prune: bool,
+
+        /// The file recording the resources created by the previous deployments, defaults to
+        /// golem-app-state.json next to the root application manifest
+        #[arg(long)]
+        state_file: Option<PathBuf>,
+    },
+    /// Deploys the application as described by the application manifests
+    ///
+    /// The changes are applied in dependency order: components, their plugin installations and
+    /// workers, security schemes, API definitions, and finally API deployments.
+    /// The components referred to by the API definitions are checked before applying anything,
+    /// and if a change fails, the reversible changes applied before it are rolled back.
+    /// The components are expected to be built already.
+    #[command(verbatim_doc_comment)]
+    Apply {
+        /// The project of the application
+        #[command(flatten)]
+        project_ref: ProjectRef,
+
+        /// Application manifest to use. Can be specified multiple times.
+        #[arg(long, short)]
+        app: Vec<PathBuf>,
+
+        /// Select build profile
+        #[arg(long, short)]
+        build_profile: Option<String>,
+
+        /// Also remove the resources created by previous deployments which are no longer described
+        /// by the application manifests
+        #[arg(long)]
+        prune: bool,
+
+        /// The file recording the resources created by the previous deployments, defaults to
+        /// golem-app-state.json next to the root application manifest
+        #[arg(long)]
+        state_file: Option<PathBuf>,
+
+        /// Do not ask for confirmation before applying the changes
+        #[arg(short = 'y', long)]
+        non_interactive: bool,
+    },
+}
+
+impl<ProjectRef: clap::Args + Send + Sync + 'static> AppDeploySubcommand<ProjectRef> {
+    pub async fn handle<ProjectContext: Send + Sync>(
+        self,
+        format: Format,
+        service: Arc<dyn AppDeployService<ProjectContext = ProjectContext> + Send + Sync>,
+        projects: &(dyn ProjectResolver<ProjectRef, ProjectContext> + Send + Sync),
+    ) -> Result<GolemResult, GolemError> {
+        match self {
+            AppDeploySubcommand::Plan {
+                project_ref,
+                app,
+                build_profile,
+                prune,
+                state_file,
+            } => {
+                let project = projects.resolve_id_or_default(project_ref).await?;
+                let app = load_declarative_app(
+                    app,
+                    build_profile.map(|profile| profile.into()),
+                    state_file,
+                )?;
+                let plan = service.plan(&app, &project, prune).await?;
+                Ok(GolemResult::Ok(Box::new(plan)))
+            }
+            AppDeploySubcommand::Apply {
+                project_ref,
+                app,
+                build_profile,
+                prune,
+                state_file,
+                non_interactive,
+            } => {
+                let project = projects.resolve_id_or_default(project_ref).await?;
+                let app = load_declarative_app(
+                    app,
+                    build_profile.map(|profile| profile.into()),
+                    state_file,
+                )?;
+                service
+                    .apply(&app, &project, prune, non_interactive, format)
+                    .await
+            }
+        }
+    }
+}
+
+fn load_declarative_app(
+    sources: Vec<PathBuf>,
+    build_profile: Option<app::ProfileName>,
+    state_file: Option<PathBuf>,
+) -> Result<DeclarativeApp, GolemError> {
+    let http_api = HttpApiExtensions::load(&sources)?;
+    let state_path = match state_file {
+        Some(state_file) => state_file,
+        None => default_app_state_path(&sources)?,
+    };
+    let app_ctx = app_ctx(sources, build_profile.clone())?;
+
+    let components = app_ctx
+        .application
+        .component_names()
+        .into_iter()
+        .map(|name| {
+            let name = app::ComponentName::from(name.to_string());
+            let linked_wasm = app_ctx
+                .application
+                .component_linked_wasm(&name, build_profile.as_ref());
+            let extensions = app_ctx
+                .application
+                .component_properties(&name, build_profile.as_ref())
+                .extensions
+                .as_ref()
+                .unwrap()
+                .clone();
+            DeclarativeComponent {
+                name: name.to_string(),
+                linked_wasm,
+                extensions,
+            }
+        })
+        .collect();
+
+    Ok(DeclarativeApp {
+        components,
+        http_api,
+        state_path,
+    })
+}
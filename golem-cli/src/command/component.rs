@@ -16,7 +16,8 @@ use crate::command::ComponentRefSplit;
 use crate::model::app_ext::GolemComponentExtensions;
 use crate::model::text::component::ComponentAddView;
 use crate::model::{
-    ComponentName, Format, GolemError, GolemResult, PathBufOrStdin, WorkerUpdateMode,
+    ComponentName, Format, GolemError, GolemResult, HostCapabilityArg, PathBufOrStdin,
+    WorkerUpdateMode,
 };
 use crate::parse_key_val;
 use crate::service::component::ComponentService;
@@ -24,6 +25,8 @@ use crate::service::deploy::DeployService;
 use crate::service::project::ProjectResolver;
 use clap::Subcommand;
 use golem_client::model::ComponentType;
+use golem_common::model::component_metadata::{CapabilityPolicy, OplogPolicy};
+use golem_common::model::secret::SecretReference;
 use golem_common::model::PluginInstallationId;
 use golem_wasm_rpc_stubgen::commands::app::{ApplicationContext, ApplicationSourceMode, Config};
 use golem_wasm_rpc_stubgen::log::Output;
@@ -227,6 +230,66 @@ pub enum ComponentSubCommand<ProjectRef: clap::Args, ComponentRef: clap::Args> {
         #[arg(long)]
         installation_id: PluginInstallationId,
     },
+    /// Set the oplog retention and tiering policy of the component
+    ///
+    /// Creates a new component version. Workers apply the policy once they get updated to it.
+    #[command()]
+    SetOplogPolicy {
+        /// The component to set the oplog policy for
+        #[command(flatten)]
+        component_name_or_uri: ComponentRef,
+
+        /// Time a worker has to be idle before its oplog gets moved to the next storage layer
+        #[arg(long)]
+        archive_interval_seconds: Option<u64>,
+
+        /// Zstd compression level used when archiving the oplog into compressed layers
+        #[arg(long)]
+        compression_level: Option<i32>,
+
+        /// Whether archived oplog entries may be moved to blob storage (defaults to true)
+        #[arg(long)]
+        archive_to_blob_storage: Option<bool>,
+
+        /// Time after which the oplog of an exited worker gets deleted
+        #[arg(long)]
+        exited_worker_ttl_seconds: Option<u64>,
+    },
+    /// Set the host capability policy of the component
+    ///
+    /// Creates a new component version. Workers apply the policy once they get updated to it.
+    /// They can still import the interfaces of a denied capability, but using them fails.
+    #[command()]
+    SetCapabilityPolicy {
+        /// The component to set the capability policy for
+        #[command(flatten)]
+        component_name_or_uri: ComponentRef,
+
+        /// Host capability the workers must not use. Can be repeated.
+        #[arg(long, value_enum)]
+        deny: Vec<HostCapabilityArg>,
+
+        /// Restrict outgoing HTTP requests to this host. A leading `*.` matches any subdomain.
+        /// Can be repeated. If not set, every host is allowed.
+        #[arg(long)]
+        allowed_http_host: Vec<String>,
+    },
+    /// Set the secrets exposed to the workers of the latest component version
+    ///
+    /// The values are resolved when a worker gets loaded and never show up in its metadata or oplog.
+    #[command()]
+    SetSecrets {
+        /// The component to set the secrets for
+        #[command(flatten)]
+        component_name_or_uri: ComponentRef,
+
+        /// Secret exposed in an environment variable. Can be repeated. Replaces all the secrets set earlier.
+        #[arg(long, value_parser = parse_key_val, value_name = "ENV=SECRET")]
+        secret: Vec<(String, String)>,
+    },
+    /// Show which component versions the garbage collection keeps, and which ones it would delete
+    #[command()]
+    GcReport {},
 }
 
 #[derive(clap::Args, Debug, Clone)]
@@ -498,11 +561,67 @@ impl<
                     .uninstall_plugin(component_name_or_uri, project_id, &installation_id)
                     .await
             }
+            ComponentSubCommand::SetOplogPolicy {
+                component_name_or_uri,
+                archive_interval_seconds,
+                compression_level,
+                archive_to_blob_storage,
+                exited_worker_ttl_seconds,
+            } => {
+                let (component_name_or_uri, project_ref) = component_name_or_uri.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                let policy = OplogPolicy {
+                    archive_interval_seconds,
+                    compression_level,
+                    archive_to_blob_storage,
+                    exited_worker_ttl_seconds,
+                };
+                service
+                    .set_oplog_policy(component_name_or_uri, project_id, policy)
+                    .await
+            }
+            ComponentSubCommand::SetCapabilityPolicy {
+                component_name_or_uri,
+                deny,
+                allowed_http_host,
+            } => {
+                let (component_name_or_uri, project_ref) = component_name_or_uri.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                let policy = CapabilityPolicy {
+                    denied: deny
+                        .into_iter()
+                        .map(|capability| capability.into())
+                        .collect(),
+                    allowed_http_hosts: if allowed_http_host.is_empty() {
+                        None
+                    } else {
+                        Some(allowed_http_host)
+                    },
+                };
+                service
+                    .set_capability_policy(component_name_or_uri, project_id, policy)
+                    .await
+            }
+            ComponentSubCommand::SetSecrets {
+                component_name_or_uri,
+                secret,
+            } => {
+                let (component_name_or_uri, project_ref) = component_name_or_uri.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                let secrets = secret
+                    .into_iter()
+                    .map(|(env_var, name)| SecretReference { name, env_var })
+                    .collect();
+                service
+                    .set_secrets(component_name_or_uri, project_id, secrets)
+                    .await
+            }
+            ComponentSubCommand::GcReport {} => service.gc_report().await,
         }
     }
 }
 
-fn app_ctx(
+pub(crate) fn app_ctx(
     sources: Vec<PathBuf>,
     build_profile: Option<app::ProfileName>,
 ) -> Result<ApplicationContext<GolemComponentExtensions>, GolemError> {
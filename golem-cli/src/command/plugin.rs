@@ -27,7 +27,8 @@ use crate::service::project::ProjectResolver;
 use async_trait::async_trait;
 use clap::Subcommand;
 use golem_client::model::{
-    ComponentTransformerDefinition, OplogProcessorDefinition, PluginTypeSpecificDefinition,
+    ComponentTransformerDefinition, OplogArchiveSinkDefinition, OplogProcessorDefinition,
+    OplogWebhookSinkDefinition, PluginTypeSpecificDefinition, WasmComponentTransformerDefinition,
 };
 use golem_common::model::{ComponentId, ComponentType};
 use serde::Serialize;
@@ -184,6 +185,55 @@ impl<PluginScopeRef: clap::Args> PluginSubcommand<PluginScopeRef> {
                             component_version: component.versioned_component_id.version,
                         })
                     }
+                    PluginTypeSpecificManifest::WasmComponentTransformer(spec) => {
+                        let component_name = ComponentName(format!(
+                            "component_transformer:{}:{}",
+                            manifest.name, manifest.version
+                        ));
+                        let component_file = PathBufOrStdin::Path(spec.component.clone());
+
+                        info!(
+                            "Uploading component transformer component: {}",
+                            component_name
+                        );
+                        let component = components
+                            .add(
+                                component_name.clone(),
+                                component_file,
+                                ComponentType::Ephemeral,
+                                None,
+                                non_interactive,
+                                format,
+                                vec![],
+                            )
+                            .await?;
+
+                        debug!(
+                            "Uploaded component transformer component {} as {}/{}",
+                            component_name,
+                            component.versioned_component_id.component_id,
+                            component.versioned_component_id.version
+                        );
+
+                        PluginTypeSpecificDefinition::WasmComponentTransformer(
+                            WasmComponentTransformerDefinition {
+                                provided_wit_package: spec.provided_wit_package.clone(),
+                                json_schema: spec.json_schema.clone(),
+                                component_id: component.versioned_component_id.component_id,
+                                component_version: component.versioned_component_id.version,
+                            },
+                        )
+                    }
+                    PluginTypeSpecificManifest::OplogArchiveSink => {
+                        PluginTypeSpecificDefinition::OplogArchiveSink(
+                            OplogArchiveSinkDefinition {},
+                        )
+                    }
+                    PluginTypeSpecificManifest::OplogWebhookSink => {
+                        PluginTypeSpecificDefinition::OplogWebhookSink(
+                            OplogWebhookSinkDefinition {},
+                        )
+                    }
                 };
 
                 let icon = std::fs::read(&manifest.icon)
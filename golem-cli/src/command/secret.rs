@@ -0,0 +1,85 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::{GolemError, GolemResult};
+use crate::service::project::ProjectResolver;
+use crate::service::secret::SecretService;
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+#[command()]
+pub enum SecretSubcommand<ProjectRef: clap::Args> {
+    /// List the stored secrets. Only their names are shown, never their values.
+    #[command()]
+    List {
+        /// The project to list the secrets of
+        #[command(flatten)]
+        project_ref: ProjectRef,
+    },
+
+    /// Create a secret, or replace the value of an existing one
+    #[command()]
+    Create {
+        /// The project of the secret
+        #[command(flatten)]
+        project_ref: ProjectRef,
+
+        /// Name of the secret
+        #[arg(value_name = "secret-name")]
+        name: String,
+
+        /// Value of the secret
+        #[arg(long)]
+        value: String,
+    },
+
+    /// Delete a secret. Workers already running keep its value until they get restarted.
+    #[command()]
+    Delete {
+        /// The project of the secret
+        #[command(flatten)]
+        project_ref: ProjectRef,
+
+        /// Name of the secret
+        #[arg(value_name = "secret-name")]
+        name: String,
+    },
+}
+
+impl<ProjectRef: clap::Args + Send + Sync + 'static> SecretSubcommand<ProjectRef> {
+    pub async fn handle<ProjectContext>(
+        self,
+        service: &(dyn SecretService<ProjectContext = ProjectContext> + Send + Sync),
+        projects: &(dyn ProjectResolver<ProjectRef, ProjectContext> + Send + Sync),
+    ) -> Result<GolemResult, GolemError> {
+        match self {
+            SecretSubcommand::List { project_ref } => {
+                let project_id = projects.resolve_id_or_default(project_ref).await?;
+                service.list(&project_id).await
+            }
+            SecretSubcommand::Create {
+                project_ref,
+                name,
+                value,
+            } => {
+                let project_id = projects.resolve_id_or_default(project_ref).await?;
+                service.create(name, value, &project_id).await
+            }
+            SecretSubcommand::Delete { project_ref, name } => {
+                let project_id = projects.resolve_id_or_default(project_ref).await?;
+                service.delete(name, &project_id).await
+            }
+        }
+    }
+}
@@ -20,6 +20,7 @@ use golem_common::model::TargetWorkerId;
 use golem_common::uri::oss::uri::{ComponentUri, WorkerUri};
 use golem_common::uri::oss::url::{ComponentUrl, WorkerUrl};
 use golem_common::uri::oss::urn::{ComponentUrn, WorkerUrn};
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::join;
 use tokio::task::spawn;
@@ -278,6 +279,10 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
         #[arg(short, long, value_parser = parse_key_val, value_name = "ENV=VAL")]
         env: Vec<(String, String)>,
 
+        /// List of labels (key-value pairs) attached to the worker
+        #[arg(short, long, value_parser = parse_key_val, value_name = "LABEL=VAL")]
+        label: Vec<(String, String)>,
+
         /// List of command line arguments passed to the worker
         #[arg(value_name = "args")]
         args: Vec<String>,
@@ -376,6 +381,24 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
         worker_ref: WorkerRef,
     },
 
+    /// Replaces all the labels of a worker
+    #[command()]
+    UpdateLabels {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// List of labels (key-value pairs) to attach to the worker. If empty, all the labels are removed
+        #[arg(short, long, value_parser = parse_key_val, value_name = "LABEL=VAL")]
+        label: Vec<(String, String)>,
+    },
+
+    /// Manages the files of a worker
+    #[command()]
+    Files {
+        #[command(subcommand)]
+        subcommand: WorkerFilesSubcommand<WorkerRef>,
+    },
+
     /// Retrieves metadata about an existing worker
     #[command()]
     Get {
@@ -391,7 +414,7 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
 
         /// Filter for worker metadata in form of `property op value`.
         ///
-        /// Filter examples: `name = worker-name`, `version >= 0`, `status = Running`, `env.var1 = value`.
+        /// Filter examples: `name = worker-name`, `version >= 0`, `status = Running`, `env.var1 = value`, `label.team=payments`.
         /// Can be used multiple times (AND condition is applied between them)
         #[arg(short, long)]
         filter: Option<Vec<String>>,
@@ -411,6 +434,13 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
         #[arg(long)]
         precise: Option<bool>,
     },
+    /// Lists the failed workers of a component and summarizes their errors
+    #[command()]
+    ListFailed {
+        /// The Golem component the failed workers belong to
+        #[command(flatten)]
+        component_name_or_uri: ComponentRef,
+    },
     /// Updates a worker
     #[command()]
     Update {
@@ -424,6 +454,11 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
         /// The new version of the updated worker
         #[arg(short = 't', long)]
         target_version: u64,
+
+        /// Only replay the worker against the target version in a sandbox and report whether the
+        /// update would succeed, without updating the worker. Only supported in auto mode.
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
     },
     /// Updates a set of workers
     #[command()]
@@ -434,7 +469,7 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
 
         /// Filter for selecting workers by their metadata in form of `property op value`.
         ///
-        /// Filter examples: `name = worker-name`, `version >= 0`, `status = Running`, `env.var1 = value`.
+        /// Filter examples: `name = worker-name`, `version >= 0`, `status = Running`, `env.var1 = value`, `label.team=payments`.
         /// Can be used multiple times (AND condition is applied between them)
         #[arg(short, long)]
         filter: Option<Vec<String>>,
@@ -446,6 +481,11 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
         /// The new version of the updated workers
         #[arg(short = 't', long)]
         target_version: u64,
+
+        /// Only replay the workers against the target version in a sandbox and report whether
+        /// the updates would succeed, without updating the workers. Only supported in auto mode.
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
     },
     /// Queries and dumps a worker's full oplog
     #[command()]
@@ -463,6 +503,47 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
     },
 }
 
+#[derive(Subcommand, Debug)]
+#[command()]
+pub enum WorkerFilesSubcommand<WorkerRef: clap::Args> {
+    /// Uploads a local file to a worker, overwriting it if it already exists
+    #[command()]
+    Put {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Absolute path of the file in the worker's filesystem
+        #[arg(short, long)]
+        path: String,
+
+        /// Local file to upload
+        #[arg(short, long, value_hint = clap::ValueHint::FilePath)]
+        source: PathBuf,
+    },
+
+    /// Deletes a file or an empty directory of a worker
+    #[command()]
+    Rm {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Absolute path of the file or directory in the worker's filesystem
+        #[arg(short, long)]
+        path: String,
+    },
+
+    /// Creates a directory, including its missing parents, in a worker
+    #[command()]
+    Mkdir {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Absolute path of the directory in the worker's filesystem
+        #[arg(short, long)]
+        path: String,
+    },
+}
+
 pub trait WorkerRefSplit<ProjectRef> {
     fn split(self) -> (WorkerUri, Option<ProjectRef>);
 }
@@ -492,12 +573,20 @@ impl<ComponentRef: clap::Args, WorkerRef: clap::Args> WorkerSubcommand<Component
                 component_name_or_uri,
                 worker_name,
                 env,
+                label,
                 args,
             } => {
                 let (component_name_or_uri, project_ref) = component_name_or_uri.split();
                 let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
                 service
-                    .add(component_name_or_uri, worker_name, env, args, project_id)
+                    .add(
+                        component_name_or_uri,
+                        worker_name,
+                        env,
+                        label,
+                        args,
+                        project_id,
+                    )
                     .await
             }
             WorkerSubcommand::IdempotencyKey {} => service.idempotency_key().await,
@@ -615,6 +704,32 @@ impl<ComponentRef: clap::Args, WorkerRef: clap::Args> WorkerSubcommand<Component
                 let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
                 service.delete(worker_uri, project_id).await
             }
+            WorkerSubcommand::UpdateLabels { worker_ref, label } => {
+                let (worker_uri, project_ref) = worker_ref.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                service.update_labels(worker_uri, label, project_id).await
+            }
+            WorkerSubcommand::Files { subcommand } => match subcommand {
+                WorkerFilesSubcommand::Put {
+                    worker_ref,
+                    path,
+                    source,
+                } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service.put_file(worker_uri, path, source, project_id).await
+                }
+                WorkerFilesSubcommand::Rm { worker_ref, path } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service.delete_file(worker_uri, path, project_id).await
+                }
+                WorkerFilesSubcommand::Mkdir { worker_ref, path } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service.make_directory(worker_uri, path, project_id).await
+                }
+            },
             WorkerSubcommand::Get { worker_ref } => {
                 let (worker_uri, project_ref) = worker_ref.split();
                 let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
@@ -640,34 +755,62 @@ impl<ComponentRef: clap::Args, WorkerRef: clap::Args> WorkerSubcommand<Component
                     )
                     .await
             }
+            WorkerSubcommand::ListFailed {
+                component_name_or_uri,
+            } => {
+                let (component_name_or_uri, project_ref) = component_name_or_uri.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                service.list_failed(component_name_or_uri, project_id).await
+            }
             WorkerSubcommand::Update {
                 worker_ref,
                 target_version,
                 mode,
+                dry_run,
             } => {
                 let (worker_uri, project_ref) = worker_ref.split();
                 let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
-                service
-                    .update(worker_uri, target_version, mode, project_id)
-                    .await
+                if dry_run {
+                    check_dry_run_mode(&mode)?;
+                    service
+                        .dry_run_update(worker_uri, target_version, project_id)
+                        .await
+                } else {
+                    service
+                        .update(worker_uri, target_version, mode, project_id)
+                        .await
+                }
             }
             WorkerSubcommand::UpdateMany {
                 component_name_or_uri,
                 filter,
                 mode,
                 target_version,
+                dry_run,
             } => {
                 let (component_name_or_uri, project_ref) = component_name_or_uri.split();
                 let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
-                service
-                    .update_many(
-                        component_name_or_uri,
-                        filter,
-                        target_version,
-                        mode,
-                        project_id,
-                    )
-                    .await
+                if dry_run {
+                    check_dry_run_mode(&mode)?;
+                    service
+                        .dry_run_update_many(
+                            component_name_or_uri,
+                            filter,
+                            target_version,
+                            project_id,
+                        )
+                        .await
+                } else {
+                    service
+                        .update_many(
+                            component_name_or_uri,
+                            filter,
+                            target_version,
+                            mode,
+                            project_id,
+                        )
+                        .await
+                }
             }
             WorkerSubcommand::Oplog {
                 worker_ref,
@@ -703,3 +846,12 @@ fn parse_cursor(s: &str) -> Result<ScanCursor, Box<dyn std::error::Error + Send
         cursor: parts[1].parse()?,
     })
 }
+
+fn check_dry_run_mode(mode: &WorkerUpdateMode) -> Result<(), GolemError> {
+    match mode {
+        WorkerUpdateMode::Automatic => Ok(()),
+        WorkerUpdateMode::Manual => Err(GolemError(
+            "Dry-run is only supported for automatic updates".to_string(),
+        )),
+    }
+}
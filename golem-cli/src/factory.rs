@@ -17,13 +17,16 @@ use crate::clients::api_deployment::ApiDeploymentClient;
 use crate::clients::component::ComponentClient;
 use crate::clients::health_check::HealthCheckClient;
 use crate::clients::plugin::PluginClient;
+use crate::clients::secret::SecretClient;
 use crate::clients::worker::WorkerClient;
 use crate::service::api_definition::{ApiDefinitionService, ApiDefinitionServiceLive};
 use crate::service::api_deployment::{ApiDeploymentService, ApiDeploymentServiceLive};
 use crate::service::api_security::{ApiSecuritySchemeService, ApiSecuritySchemeServiceLive};
+use crate::service::app::{AppDeployService, AppDeployServiceLive};
 use crate::service::component::{ComponentService, ComponentServiceLive};
 use crate::service::deploy::{DeployService, DeployServiceLive};
 use crate::service::project::ProjectResolver;
+use crate::service::secret::{SecretService, SecretServiceLive};
 use crate::service::version::{VersionService, VersionServiceLive};
 use crate::service::worker::{WorkerService, WorkerServiceLive};
 use std::fmt::Display;
@@ -112,6 +115,18 @@ pub trait ServiceFactory {
         })
     }
 
+    fn secret_client(
+        &self,
+    ) -> Box<dyn SecretClient<ProjectContext = Self::ProjectContext> + Send + Sync>;
+
+    fn secret_service(
+        &self,
+    ) -> Arc<dyn SecretService<ProjectContext = Self::ProjectContext> + Send + Sync> {
+        Arc::new(SecretServiceLive {
+            client: self.secret_client(),
+        })
+    }
+
     fn health_check_clients(&self) -> Vec<Arc<dyn HealthCheckClient + Send + Sync>>;
 
     fn version_service(&self) -> Arc<dyn VersionService + Send + Sync> {
@@ -132,6 +147,23 @@ pub trait ServiceFactory {
         })
     }
 
+    fn app_deploy_service(
+        &self,
+    ) -> Arc<dyn AppDeployService<ProjectContext = Self::ProjectContext> + Send + Sync>
+    where
+        Self: Send + Sync + Sized + 'static,
+        Self::ProjectContext: Clone,
+    {
+        Arc::new(AppDeployServiceLive {
+            component_service: self.component_service(),
+            component_client: self.component_client(),
+            worker_service: self.worker_service(),
+            api_definition_client: self.api_definition_client(),
+            api_deployment_client: self.api_deployment_client(),
+            api_security_scheme_client: self.api_security_scheme_client(),
+        })
+    }
+
     fn plugin_client(
         &self,
     ) -> Arc<
@@ -14,6 +14,7 @@
 
 pub mod app_ext;
 pub mod app_ext_raw;
+pub mod app_plan;
 pub mod component;
 pub mod deploy;
 pub mod invoke_result_view;
@@ -459,6 +460,29 @@ impl Display for ApiDefinitionFileFormat {
 #[derive(Clone, PartialEq, Eq, Debug, Display, FromStr)]
 pub struct ApiDefinitionVersion(pub String); // TODO: Validate
 
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum HostCapabilityArg {
+    Http,
+    Sockets,
+    FileSystem,
+    Rpc,
+    KeyValue,
+    BlobStore,
+}
+
+impl From<HostCapabilityArg> for golem_common::model::component_metadata::HostCapability {
+    fn from(value: HostCapabilityArg) -> Self {
+        match value {
+            HostCapabilityArg::Http => Self::Http,
+            HostCapabilityArg::Sockets => Self::Sockets,
+            HostCapabilityArg::FileSystem => Self::FileSystem,
+            HostCapabilityArg::Rpc => Self::Rpc,
+            HostCapabilityArg::KeyValue => Self::KeyValue,
+            HostCapabilityArg::BlobStore => Self::BlobStore,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct JsonValueParser;
 
@@ -580,6 +604,8 @@ pub struct WorkerMetadataView {
     pub total_linear_memory_size: u64,
     #[serde(rename = "ownedResources")]
     pub owned_resources: HashMap<String, golem_client::model::ResourceMetadata>,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
 }
 
 impl TrimDateTime for WorkerMetadataView {
@@ -608,6 +634,7 @@ impl From<WorkerMetadata> for WorkerMetadataView {
             component_size,
             total_linear_memory_size,
             owned_resources,
+            labels,
         } = value;
 
         WorkerMetadataView {
@@ -627,6 +654,7 @@ impl From<WorkerMetadata> for WorkerMetadataView {
             component_size,
             total_linear_memory_size,
             owned_resources,
+            labels,
         }
     }
 }
@@ -647,6 +675,7 @@ pub struct WorkerMetadata {
     pub component_size: u64,
     pub total_linear_memory_size: u64,
     pub owned_resources: HashMap<String, golem_client::model::ResourceMetadata>,
+    pub labels: HashMap<String, String>,
 }
 
 impl From<golem_client::model::WorkerMetadata> for WorkerMetadata {
@@ -666,6 +695,7 @@ impl From<golem_client::model::WorkerMetadata> for WorkerMetadata {
             total_linear_memory_size,
             owned_resources,
             active_plugins: _active_plugins,
+            labels,
         } = value;
 
         WorkerMetadata {
@@ -683,6 +713,7 @@ impl From<golem_client::model::WorkerMetadata> for WorkerMetadata {
             component_size,
             total_linear_memory_size,
             owned_resources,
+            labels,
         }
     }
 }
@@ -713,6 +744,67 @@ impl From<WorkersMetadataResponse> for WorkersMetadataResponseView {
     }
 }
 
+/// The failed workers of a component, and a summary of their errors
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FailedWorkersView {
+    pub workers: Vec<FailedWorkerView>,
+    pub errors: Vec<FailedWorkersErrorView>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FailedWorkerView {
+    #[serde(rename = "workerUrn")]
+    pub worker_urn: WorkerUrn,
+    #[serde(rename = "componentVersion")]
+    pub component_version: u64,
+    #[serde(rename = "retryCount")]
+    pub retry_count: u64,
+    #[serde(rename = "lastError")]
+    pub last_error: Option<String>,
+}
+
+/// Number of failed workers having the same error. Errors are compared by their first line, as
+/// the rest usually contains the worker specific stderr output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FailedWorkersErrorView {
+    pub error: String,
+    pub count: usize,
+}
+
+impl FailedWorkersView {
+    pub fn new(workers: Vec<WorkerMetadata>) -> Self {
+        let workers = workers
+            .into_iter()
+            .map(|worker| FailedWorkerView {
+                worker_urn: WorkerUrn {
+                    id: worker.worker_id.into_target_worker_id(),
+                },
+                component_version: worker.component_version,
+                retry_count: worker.retry_count,
+                last_error: worker.last_error,
+            })
+            .collect::<Vec<_>>();
+
+        let errors = workers
+            .iter()
+            .map(|worker| {
+                worker
+                    .last_error
+                    .as_deref()
+                    .and_then(|error| error.lines().next())
+                    .unwrap_or("Unknown error")
+                    .to_string()
+            })
+            .counts()
+            .into_iter()
+            .map(|(error, count)| FailedWorkersErrorView { error, count })
+            .sorted_by(|a, b| b.count.cmp(&a.count).then_with(|| a.error.cmp(&b.error)))
+            .collect();
+
+        Self { workers, errors }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct WorkersMetadataResponse {
     pub workers: Vec<WorkerMetadata>,
@@ -862,3 +954,98 @@ pub fn decode_api_definition<'de, T: Deserialize<'de>>(
             .map_err(|e| GolemError(format!("Failed to parse yaml api definition: {e:?}"))),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::model::{FailedWorkersErrorView, FailedWorkersView, WorkerMetadata};
+    use chrono::Utc;
+    use golem_common::model::{ComponentId, WorkerId, WorkerStatus};
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    fn failed_worker(
+        component_id: &ComponentId,
+        name: &str,
+        last_error: Option<&str>,
+    ) -> WorkerMetadata {
+        WorkerMetadata {
+            worker_id: WorkerId {
+                component_id: component_id.clone(),
+                worker_name: name.to_string(),
+            },
+            account_id: None,
+            args: vec![],
+            env: HashMap::new(),
+            status: WorkerStatus::Failed,
+            component_version: 1,
+            retry_count: 3,
+            pending_invocation_count: 0,
+            updates: vec![],
+            created_at: Utc::now(),
+            last_error: last_error.map(|error| error.to_string()),
+            component_size: 0,
+            total_linear_memory_size: 0,
+            owned_resources: HashMap::new(),
+            labels: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn failed_workers_view_groups_errors_by_first_line() {
+        let component_id = ComponentId(Uuid::new_v4());
+        let view = FailedWorkersView::new(vec![
+            failed_worker(
+                &component_id,
+                "worker-1",
+                Some("Runtime error: out of fuel\nstderr of worker-1"),
+            ),
+            failed_worker(&component_id, "worker-2", Some("Invalid request")),
+            failed_worker(
+                &component_id,
+                "worker-3",
+                Some("Runtime error: out of fuel\nstderr of worker-3"),
+            ),
+            failed_worker(&component_id, "worker-4", None),
+        ]);
+
+        assert_eq!(view.workers.len(), 4);
+        assert_eq!(
+            view.workers[0].worker_urn.id.worker_name,
+            Some("worker-1".to_string())
+        );
+        assert_eq!(view.workers[0].component_version, 1);
+        assert_eq!(view.workers[0].retry_count, 3);
+        assert_eq!(
+            view.workers[0].last_error,
+            Some("Runtime error: out of fuel\nstderr of worker-1".to_string())
+        );
+        assert_eq!(view.workers[3].last_error, None);
+        assert_eq!(
+            view.errors,
+            vec![
+                FailedWorkersErrorView {
+                    error: "Runtime error: out of fuel".to_string(),
+                    count: 2,
+                },
+                FailedWorkersErrorView {
+                    error: "Invalid request".to_string(),
+                    count: 1,
+                },
+                FailedWorkersErrorView {
+                    error: "Unknown error".to_string(),
+                    count: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn failed_workers_view_of_no_workers_is_empty() {
+        let view = FailedWorkersView::new(vec![]);
+
+        assert!(view.workers.is_empty());
+        assert!(view.errors.is_empty());
+    }
+}
@@ -13,19 +13,33 @@
 // limitations under the License.
 
 use crate::model::app_ext_raw;
+use crate::model::{
+    ApiDefinitionFileFormat, ApiDefinitionIdWithVersion, GolemError, IdentityProviderType,
+};
 use golem_common::model::{
     ComponentFilePathWithPermissions, ComponentFilePermissions, ComponentType,
 };
 use golem_wasm_rpc_stubgen::model::app::ComponentPropertiesExtensions;
 use golem_wasm_rpc_stubgen::validation::ValidationBuilder;
+use itertools::Itertools;
 use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use url::Url;
 
+/// The file name of the application manifests looked up in the current directory and its parents
+pub const APP_MANIFEST_FILE_NAME: &str = "golem.yaml";
+
+/// The file name of the deployment state written next to the root application manifest
+pub const APP_STATE_FILE_NAME: &str = "golem-app-state.json";
+
 #[derive(Clone, Debug)]
 pub struct GolemComponentExtensions {
     pub component_type: ComponentType,
     pub files: Vec<InitialComponentFile>,
+    pub plugins: Vec<PluginInstallation>,
+    pub workers: Vec<Worker>,
 }
 
 impl ComponentPropertiesExtensions for GolemComponentExtensions {
@@ -48,12 +62,45 @@ impl ComponentPropertiesExtensions for GolemComponentExtensions {
             .filter_map(|file| convert_component_file(validation, file, source))
             .collect::<Vec<_>>();
 
-        (files.len() == source_count).then(|| GolemComponentExtensions {
+        let plugins = raw
+            .plugins
+            .into_iter()
+            .map(PluginInstallation::from)
+            .collect::<Vec<_>>();
+        let duplicated_plugins = plugins
+            .iter()
+            .map(|plugin| format!("{}/{}", plugin.name, plugin.version))
+            .duplicates()
+            .collect::<Vec<_>>();
+        for plugin in &duplicated_plugins {
+            validation.add_error(format!("Plugin {plugin} is installed more than once"));
+        }
+
+        let workers = raw
+            .workers
+            .into_iter()
+            .map(Worker::from)
+            .collect::<Vec<_>>();
+        let duplicated_workers = workers
+            .iter()
+            .map(|worker| &worker.name)
+            .duplicates()
+            .collect::<Vec<_>>();
+        for worker in &duplicated_workers {
+            validation.add_error(format!("Worker {worker} is defined more than once"));
+        }
+
+        (files.len() == source_count
+            && duplicated_plugins.is_empty()
+            && duplicated_workers.is_empty())
+        .then(|| GolemComponentExtensions {
             component_type: raw
                 .component_type
                 .unwrap_or(app_ext_raw::ComponentType::Durable)
                 .into(),
             files,
+            plugins,
+            workers,
         })
     }
 }
@@ -140,3 +187,299 @@ impl From<app_ext_raw::ComponentType> for ComponentType {
         }
     }
 }
+
+/// The state of an application described by its manifests, used by the declarative deployment
+#[derive(Clone, Debug)]
+pub struct DeclarativeApp {
+    pub components: Vec<DeclarativeComponent>,
+    pub http_api: HttpApiExtensions,
+    /// The file recording the resources created by previous deployments of the application
+    pub state_path: PathBuf,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeclarativeComponent {
+    pub name: String,
+    pub linked_wasm: PathBuf,
+    pub extensions: GolemComponentExtensions,
+}
+
+/// A plugin installation the component is expected to have
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PluginInstallation {
+    pub name: String,
+    pub version: String,
+    pub priority: i32,
+    pub parameters: HashMap<String, String>,
+}
+
+impl From<app_ext_raw::PluginInstallation> for PluginInstallation {
+    fn from(raw: app_ext_raw::PluginInstallation) -> Self {
+        Self {
+            name: raw.name,
+            version: raw.version,
+            priority: raw.priority.unwrap_or(0),
+            parameters: raw.parameters,
+        }
+    }
+}
+
+/// A worker created up front when the application is deployed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Worker {
+    pub name: String,
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+}
+
+impl From<app_ext_raw::Worker> for Worker {
+    fn from(raw: app_ext_raw::Worker) -> Self {
+        Self {
+            name: raw.name,
+            args: raw.args,
+            env: raw.env.into_iter().sorted().collect(),
+        }
+    }
+}
+
+/// The `httpApi` sections of the application manifests, describing the API definitions,
+/// deployments and security schemes of the application.
+///
+/// These sections are not used by the build commands, so they are read directly from the
+/// manifest files, following their `include` patterns.
+#[derive(Clone, Debug, Default)]
+pub struct HttpApiExtensions {
+    pub definitions: Vec<HttpApiDefinitionSource>,
+    pub deployments: Vec<HttpApiDeployment>,
+    pub security_schemes: Vec<HttpApiSecurityScheme>,
+}
+
+impl HttpApiExtensions {
+    /// Loads the `httpApi` sections of the given manifests, or of the manifest found in the
+    /// current directory or its parents if none is given
+    pub fn load(sources: &[PathBuf]) -> Result<Self, GolemError> {
+        let mut result = HttpApiExtensions::default();
+        for source in find_app_manifests(sources)? {
+            result.add_source(&source)?;
+        }
+
+        let duplicated_definitions = result
+            .definitions
+            .iter()
+            .map(|definition| definition.path.clone())
+            .duplicates()
+            .collect::<Vec<_>>();
+        if !duplicated_definitions.is_empty() {
+            return Err(GolemError(format!(
+                "API definitions are listed more than once: {}",
+                duplicated_definitions
+                    .iter()
+                    .map(|path| path.display())
+                    .join(", ")
+            )));
+        }
+
+        let duplicated_sites = result
+            .deployments
+            .iter()
+            .map(|deployment| deployment.site())
+            .duplicates()
+            .collect::<Vec<_>>();
+        if !duplicated_sites.is_empty() {
+            return Err(GolemError(format!(
+                "API deployments are defined more than once for sites: {}",
+                duplicated_sites.join(", ")
+            )));
+        }
+
+        Ok(result)
+    }
+
+    fn add_source(&mut self, source: &Path) -> Result<(), GolemError> {
+        let manifest = read_app_manifest(source)?;
+
+        let Some(http_api) = manifest.get("httpApi") else {
+            return Ok(());
+        };
+        let http_api: app_ext_raw::HttpApi =
+            serde_yaml::from_value(http_api.clone()).map_err(|err| {
+                GolemError(format!(
+                    "Invalid httpApi section in application manifest {}: {err}",
+                    source.display()
+                ))
+            })?;
+
+        let base_dir = source.parent().unwrap_or(Path::new("."));
+        for definition in http_api.definitions {
+            let path = base_dir.join(&definition.path);
+            let format = match path.extension().and_then(|ext| ext.to_str()) {
+                Some("json") => ApiDefinitionFileFormat::Json,
+                _ => ApiDefinitionFileFormat::Yaml,
+            };
+            self.definitions
+                .push(HttpApiDefinitionSource { path, format });
+        }
+
+        for deployment in http_api.deployments {
+            let definitions = deployment
+                .definitions
+                .iter()
+                .map(|definition| ApiDefinitionIdWithVersion::from_str(definition))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|err| {
+                    GolemError(format!(
+                        "Invalid API deployment in application manifest {}: {err}",
+                        source.display()
+                    ))
+                })?;
+            self.deployments.push(HttpApiDeployment {
+                host: deployment.host,
+                subdomain: deployment.subdomain,
+                definitions,
+            });
+        }
+
+        for scheme in http_api.security_schemes {
+            let provider = IdentityProviderType::from_str(&scheme.provider).map_err(|err| {
+                GolemError(format!(
+                    "Invalid security scheme {} in application manifest {}: {err}",
+                    scheme.id,
+                    source.display()
+                ))
+            })?;
+            self.security_schemes.push(HttpApiSecurityScheme {
+                id: scheme.id,
+                provider,
+                client_id: scheme.client_id,
+                client_secret: scheme.client_secret,
+                scopes: scheme.scopes,
+                redirect_url: scheme.redirect_url,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// An API definition file, in the same format as used by `api-definition add`
+#[derive(Clone, Debug)]
+pub struct HttpApiDefinitionSource {
+    pub path: PathBuf,
+    pub format: ApiDefinitionFileFormat,
+}
+
+#[derive(Clone, Debug)]
+pub struct HttpApiDeployment {
+    pub host: String,
+    pub subdomain: Option<String>,
+    pub definitions: Vec<ApiDefinitionIdWithVersion>,
+}
+
+impl HttpApiDeployment {
+    pub fn site(&self) -> String {
+        match &self.subdomain {
+            Some(subdomain) => format!("{}.{}", subdomain, self.host),
+            None => self.host.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HttpApiSecurityScheme {
+    pub id: String,
+    pub provider: IdentityProviderType,
+    pub client_id: String,
+    pub client_secret: String,
+    pub scopes: Vec<String>,
+    pub redirect_url: String,
+}
+
+/// The default location of the deployment state: next to the first root application manifest
+pub fn default_app_state_path(sources: &[PathBuf]) -> Result<PathBuf, GolemError> {
+    let root = root_app_manifests(sources)?.remove(0);
+    let dir = root.parent().unwrap_or(Path::new("."));
+    Ok(dir.join(APP_STATE_FILE_NAME))
+}
+
+/// The given manifests, or the one found in the current directory or its parents if none is given
+fn root_app_manifests(sources: &[PathBuf]) -> Result<Vec<PathBuf>, GolemError> {
+    if sources.is_empty() {
+        let current_dir = std::env::current_dir()
+            .map_err(|err| GolemError(format!("Failed to get the current directory: {err}")))?;
+        let root = current_dir
+            .ancestors()
+            .map(|dir| dir.join(APP_MANIFEST_FILE_NAME))
+            .find(|path| path.is_file())
+            .ok_or_else(|| {
+                GolemError(format!(
+                    "No application manifest ({APP_MANIFEST_FILE_NAME}) found in the current directory or its parents"
+                ))
+            })?;
+        Ok(vec![root])
+    } else {
+        Ok(sources.to_vec())
+    }
+}
+
+/// Collects the given manifests and the ones matched by their `include` patterns
+fn find_app_manifests(sources: &[PathBuf]) -> Result<Vec<PathBuf>, GolemError> {
+    let roots = root_app_manifests(sources)?;
+
+    let mut visited = HashSet::new();
+    let mut result = Vec::new();
+    let mut queue = roots;
+    while let Some(source) = queue.pop() {
+        let source = source.canonicalize().map_err(|err| {
+            GolemError(format!(
+                "Failed to resolve application manifest {}: {err}",
+                source.display()
+            ))
+        })?;
+        if !visited.insert(source.clone()) {
+            continue;
+        }
+
+        queue.extend(manifest_includes(&source)?);
+        result.push(source);
+    }
+
+    Ok(result)
+}
+
+fn read_app_manifest(source: &Path) -> Result<serde_yaml::Value, GolemError> {
+    let content = std::fs::read_to_string(source).map_err(|err| {
+        GolemError(format!(
+            "Failed to read application manifest {}: {err}",
+            source.display()
+        ))
+    })?;
+    serde_yaml::from_str(&content).map_err(|err| {
+        GolemError(format!(
+            "Failed to parse application manifest {}: {err}",
+            source.display()
+        ))
+    })
+}
+
+fn manifest_includes(source: &Path) -> Result<Vec<PathBuf>, GolemError> {
+    let manifest = read_app_manifest(source)?;
+
+    let patterns = match manifest.get("include") {
+        Some(serde_yaml::Value::String(pattern)) => vec![pattern.clone()],
+        Some(serde_yaml::Value::Sequence(patterns)) => patterns
+            .iter()
+            .filter_map(|pattern| pattern.as_str().map(|pattern| pattern.to_string()))
+            .collect(),
+        _ => vec![],
+    };
+
+    let base_dir = source.parent().unwrap_or(Path::new("."));
+    let mut result = Vec::new();
+    for pattern in patterns {
+        let pattern = base_dir.join(pattern);
+        let paths = glob::glob(&pattern.to_string_lossy())
+            .map_err(|err| GolemError(format!("Invalid include pattern {pattern:?}: {err}")))?;
+        result.extend(paths.filter_map(|path| path.ok()));
+    }
+    Ok(result)
+}
@@ -0,0 +1,175 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+/// The kinds of resources managed by the declarative application deployment
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AppResourceKind {
+    Component,
+    PluginInstallation,
+    Worker,
+    ApiSecurityScheme,
+    ApiDefinition,
+    ApiDeployment,
+}
+
+impl Display for AppResourceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            AppResourceKind::Component => "component",
+            AppResourceKind::PluginInstallation => "plugin installation",
+            AppResourceKind::Worker => "worker",
+            AppResourceKind::ApiSecurityScheme => "API security scheme",
+            AppResourceKind::ApiDefinition => "API definition",
+            AppResourceKind::ApiDeployment => "API deployment",
+        };
+        Display::fmt(&s, f)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AppPlanAction {
+    Create,
+    Update,
+    Delete,
+    Unchanged,
+}
+
+impl Display for AppPlanAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            AppPlanAction::Create => "create",
+            AppPlanAction::Update => "update",
+            AppPlanAction::Delete => "delete",
+            AppPlanAction::Unchanged => "unchanged",
+        };
+        Display::fmt(&s, f)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppPlanStep {
+    pub kind: AppResourceKind,
+    pub name: String,
+    pub action: AppPlanAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Differences between the state described by the application manifests and the deployed one,
+/// with the steps listed in the order they are applied
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppPlan {
+    pub steps: Vec<AppPlanStep>,
+}
+
+impl AppPlan {
+    pub fn changes(&self) -> impl Iterator<Item = &AppPlanStep> {
+        self.steps
+            .iter()
+            .filter(|step| step.action != AppPlanAction::Unchanged)
+    }
+
+    pub fn has_changes(&self) -> bool {
+        self.changes().next().is_some()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppApplyResult {
+    pub applied: Vec<AppPlanStep>,
+}
+
+/// The resources created by `app apply`, per project.
+///
+/// Pruning only removes the resources recorded here, so resources created by other means are
+/// never deleted. The recorded WASM hashes detect component changes which do not change the size.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppDeployState {
+    #[serde(default)]
+    pub projects: BTreeMap<String, AppProjectState>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppProjectState {
+    #[serde(default)]
+    pub components: BTreeMap<String, AppDeployedComponent>,
+    #[serde(default)]
+    pub plugin_installations: BTreeSet<String>,
+    #[serde(default)]
+    pub workers: BTreeSet<String>,
+    #[serde(default)]
+    pub api_definitions: BTreeSet<String>,
+    #[serde(default)]
+    pub api_deployments: BTreeSet<String>,
+}
+
+impl AppProjectState {
+    /// The names of the created resources of the given kind, if they are tracked
+    pub fn created(&self, kind: AppResourceKind) -> Option<&BTreeSet<String>> {
+        match kind {
+            AppResourceKind::PluginInstallation => Some(&self.plugin_installations),
+            AppResourceKind::Worker => Some(&self.workers),
+            AppResourceKind::ApiDefinition => Some(&self.api_definitions),
+            AppResourceKind::ApiDeployment => Some(&self.api_deployments),
+            AppResourceKind::Component | AppResourceKind::ApiSecurityScheme => None,
+        }
+    }
+
+    pub fn is_created(&self, kind: AppResourceKind, name: &str) -> bool {
+        self.created(kind)
+            .map(|names| names.contains(name))
+            .unwrap_or(false)
+    }
+
+    fn created_mut(&mut self, kind: AppResourceKind) -> Option<&mut BTreeSet<String>> {
+        match kind {
+            AppResourceKind::PluginInstallation => Some(&mut self.plugin_installations),
+            AppResourceKind::Worker => Some(&mut self.workers),
+            AppResourceKind::ApiDefinition => Some(&mut self.api_definitions),
+            AppResourceKind::ApiDeployment => Some(&mut self.api_deployments),
+            AppResourceKind::Component | AppResourceKind::ApiSecurityScheme => None,
+        }
+    }
+
+    pub fn add_created(&mut self, kind: AppResourceKind, name: &str) {
+        if let Some(names) = self.created_mut(kind) {
+            names.insert(name.to_string());
+        }
+    }
+
+    pub fn remove_created(&mut self, kind: AppResourceKind, name: &str) {
+        if let Some(names) = self.created_mut(kind) {
+            names.remove(name);
+        }
+    }
+}
+
+/// The component version uploaded by `app apply`, with the SHA-256 hash of its WASM
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppDeployedComponent {
+    pub version: u64,
+    pub wasm_hash: String,
+}
@@ -19,6 +19,10 @@ use chrono::{DateTime, Utc};
 use golem_client::model::{
     AnalysedType, ComponentMetadata, ComponentType, InitialComponentFile, VersionedComponentId,
 };
+use golem_common::model::component_metadata::{
+    CapabilityPolicy, ComponentVolume, EgressPolicy, FailurePolicy, OplogPolicy,
+};
+use golem_common::model::secret::SecretReference;
 use golem_common::model::trim_date::TrimDateTime;
 use golem_common::model::ComponentId;
 use golem_common::uri::oss::urn::ComponentUrn;
@@ -82,6 +86,27 @@ pub struct ComponentView {
     #[serde(default)]
     pub project_id: Option<ProjectId>,
     pub exports: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub oplog_policy: Option<OplogPolicy>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub volumes: Vec<ComponentVolume>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub imports: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub capability_policy: Option<CapabilityPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub egress_policy: Option<EgressPolicy>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub secrets: Vec<SecretReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub failure_policy: Option<FailurePolicy>,
 }
 
 impl TrimDateTime for ComponentView {
@@ -127,6 +152,17 @@ impl From<&Component> for ComponentView {
                     }
                 })
                 .collect(),
+            oplog_policy: value.metadata.oplog_policy.clone(),
+            volumes: value.metadata.volumes.clone(),
+            imports: value.metadata.imports.clone(),
+            capability_policy: value.metadata.capability_policy.clone(),
+            egress_policy: value
+                .metadata
+                .egress_policy
+                .as_ref()
+                .map(|policy| policy.redacted()),
+            secrets: value.metadata.secrets.clone(),
+            failure_policy: value.metadata.failure_policy.clone(),
         }
     }
 }
@@ -20,3 +20,33 @@ pub struct TryUpdateAllWorkersResult {
     pub triggered: Vec<WorkerUrn>,
     pub failed: Vec<WorkerUrn>,
 }
+
+/// Outcome of replaying a worker against a new component version in a sandbox
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct WorkerUpdateDryRunResult {
+    pub worker_urn: WorkerUrn,
+    pub target_version: u64,
+    pub succeeded: bool,
+    pub replayed_until: u64,
+    pub diverged_at: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl WorkerUpdateDryRunResult {
+    pub fn new(worker_urn: WorkerUrn, result: golem_client::model::UpdateDryRunResult) -> Self {
+        Self {
+            worker_urn,
+            target_version: result.target_version,
+            succeeded: result.succeeded,
+            replayed_until: result.replayed_until,
+            diverged_at: result.diverged_at,
+            error: result.error,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct TryUpdateAllWorkersDryRunResult {
+    pub results: Vec<WorkerUpdateDryRunResult>,
+    pub failed: Vec<WorkerUrn>,
+}
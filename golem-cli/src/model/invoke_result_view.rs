@@ -168,6 +168,13 @@ mod tests {
                     results: func_res,
                 })],
                 memories: vec![],
+                oplog_policy: None,
+                volumes: vec![],
+                imports: vec![],
+                capability_policy: None,
+                egress_policy: None,
+                secrets: vec![],
+                failure_policy: None,
             },
             project_id: None,
             created_at: Some(Utc::now()),
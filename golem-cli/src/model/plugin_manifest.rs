@@ -25,6 +25,11 @@ use std::path::PathBuf;
 pub enum PluginTypeSpecificManifest {
     ComponentTransformer(ComponentTransformerManifest),
     OplogProcessor(OplogProcessorManifest),
+    WasmComponentTransformer(WasmComponentTransformerManifest),
+    /// Built-in oplog archive sink, configured by the installation parameters
+    OplogArchiveSink,
+    /// Built-in oplog webhook sink, configured by the installation parameters
+    OplogWebhookSink,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -42,6 +47,14 @@ pub struct OplogProcessorManifest {
     pub component: PathBuf,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmComponentTransformerManifest {
+    pub provided_wit_package: Option<String>,
+    pub json_schema: Option<String>,
+    pub component: PathBuf,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PluginManifest {
@@ -17,6 +17,11 @@ pub mod fmt {
     use colored::control::SHOULD_COLORIZE;
     use colored::Colorize;
     use golem_client::model::WorkerStatus;
+    use golem_common::model::component_metadata::{
+        CapabilityPolicy, ComponentVolume, EgressPolicy, FailedWorkerAction, FailurePolicy,
+        HostCapability, OplogPolicy,
+    };
+    use golem_common::model::secret::SecretReference;
     use itertools::Itertools;
     use regex::Regex;
 
@@ -238,6 +243,165 @@ pub mod fmt {
         exports.iter().map(|e| format_export(e.as_str())).join("\n")
     }
 
+    pub fn format_oplog_policy(policy: &OplogPolicy) -> String {
+        let or_default = |value: Option<String>| value.unwrap_or_else(|| "default".to_string());
+        [
+            format!(
+                "Archive interval: {}",
+                or_default(policy.archive_interval_seconds.map(|s| format!("{s}s")))
+            ),
+            format!(
+                "Compression level: {}",
+                or_default(policy.compression_level.map(|l| l.to_string()))
+            ),
+            format!("Archive to blob storage: {}", policy.allows_blob_storage()),
+            format!(
+                "Exited worker TTL: {}",
+                policy
+                    .exited_worker_ttl_seconds
+                    .map(|s| format!("{s}s"))
+                    .unwrap_or_else(|| "none".to_string())
+            ),
+        ]
+        .join("\n")
+    }
+
+    pub fn format_volumes(volumes: &[ComponentVolume]) -> String {
+        volumes
+            .iter()
+            .map(|volume| format!("{}: {}", format_id(&volume.name), volume.path))
+            .join("\n")
+    }
+
+    pub fn format_imports(imports: &[String]) -> String {
+        imports
+            .iter()
+            .map(|import| match HostCapability::of_import(import) {
+                Some(capability) => format!("{import} ({})", format_id(&capability)),
+                None => import.to_string(),
+            })
+            .join("\n")
+    }
+
+    pub fn format_capability_policy(policy: &CapabilityPolicy) -> String {
+        [
+            format!(
+                "Denied: {}",
+                if policy.denied.is_empty() {
+                    "none".to_string()
+                } else {
+                    policy.denied.iter().join(", ")
+                }
+            ),
+            format!(
+                "Allowed HTTP hosts: {}",
+                policy
+                    .allowed_http_hosts
+                    .as_ref()
+                    .map(|hosts| hosts.join(", "))
+                    .unwrap_or_else(|| "any".to_string())
+            ),
+        ]
+        .join("\n")
+    }
+
+    pub fn format_egress_policy(policy: &EgressPolicy) -> String {
+        [
+            format!(
+                "Allowed destinations: {}",
+                if policy.allowed_destinations.is_empty() {
+                    "any".to_string()
+                } else {
+                    policy
+                        .allowed_destinations
+                        .iter()
+                        .map(|destination| {
+                            if destination.ports.is_empty() {
+                                destination.host.clone()
+                            } else {
+                                format!(
+                                    "{}:{}",
+                                    destination.host,
+                                    destination.ports.iter().join("|")
+                                )
+                            }
+                        })
+                        .join(", ")
+                }
+            ),
+            format!(
+                "Denied addresses: {}",
+                if policy.denied_addresses.is_empty() {
+                    "none".to_string()
+                } else {
+                    policy.denied_addresses.join(", ")
+                }
+            ),
+            format!(
+                "Max request body size: {}",
+                policy
+                    .max_request_body_size
+                    .as_ref()
+                    .map(format_binary_size)
+                    .unwrap_or_else(|| "unlimited".to_string())
+            ),
+            format!(
+                "Timeout: {}",
+                policy
+                    .timeout_millis
+                    .map(|ms| format!("{ms}ms"))
+                    .unwrap_or_else(|| "default".to_string())
+            ),
+            // Header values are not shown as they often contain credentials
+            format!(
+                "Injected headers: {}",
+                if policy.injected_headers.is_empty() {
+                    "none".to_string()
+                } else {
+                    policy
+                        .injected_headers
+                        .iter()
+                        .map(|header| header.name.as_str())
+                        .join(", ")
+                }
+            ),
+        ]
+        .join("\n")
+    }
+
+    pub fn format_secrets(secrets: &[SecretReference]) -> String {
+        secrets
+            .iter()
+            .map(|secret| format!("{} <- {}", secret.env_var, format_id(&secret.name)))
+            .join("\n")
+    }
+
+    pub fn format_failure_policy(policy: &FailurePolicy) -> String {
+        [
+            format!(
+                "Dead-letter handler: {}",
+                policy
+                    .dead_letter_handler
+                    .as_ref()
+                    .map(|handler| format!(
+                        "{}/{}",
+                        handler.worker_id,
+                        format_id(&handler.function_name)
+                    ))
+                    .unwrap_or_else(|| "none".to_string())
+            ),
+            match policy.action {
+                FailedWorkerAction::Delete => format!(
+                    "Action: {} after {}s",
+                    policy.action,
+                    policy.delete_after().as_secs()
+                ),
+                _ => format!("Action: {}", policy.action),
+            },
+        ]
+        .join("\n")
+    }
+
     pub fn format_table<E, R>(table: &[E]) -> String
     where
         R: Title + 'static + for<'b> From<&'b E>,
@@ -315,6 +479,41 @@ pub mod api_security {
     }
 }
 
+pub mod secret {
+    use crate::model::text::fmt::*;
+    use cli_table::Table;
+    use golem_client::model::Secret;
+
+    impl TextFormat for Secret {
+        fn print(&self) {
+            println!("Secret {} stored", format_message_highlight(&self.name));
+        }
+    }
+
+    #[derive(Table)]
+    struct SecretTableView {
+        #[table(title = "Name")]
+        pub name: String,
+        #[table(title = "Created at")]
+        pub created_at: String,
+    }
+
+    impl From<&Secret> for SecretTableView {
+        fn from(value: &Secret) -> Self {
+            Self {
+                name: value.name.clone(),
+                created_at: value.created_at.to_string(),
+            }
+        }
+    }
+
+    impl TextFormat for Vec<Secret> {
+        fn print(&self) {
+            print_table::<_, SecretTableView>(self);
+        }
+    }
+}
+
 pub mod api_definition {
     use crate::model::text::fmt::*;
     use cli_table::{format::Justify, Table};
@@ -479,15 +678,31 @@ pub mod api_deployment {
     use crate::model::text::fmt::*;
     use crate::model::ApiDeployment;
     use cli_table::{print_stdout, Table, WithTitle};
+    use golem_client::model::{
+        ApiDeploymentRevision, ApiDeploymentRevisionDiff, ApiRouteInfo, ApiSite,
+    };
     use indoc::printdoc;
 
     pub fn format_site(api_deployment: &ApiDeployment) -> String {
-        match &api_deployment.site.subdomain {
-            Some(subdomain) => format!("{}.{}", subdomain, api_deployment.site.host),
-            None => api_deployment.site.host.to_string(),
+        format_api_site(&api_deployment.site)
+    }
+
+    fn format_api_site(site: &ApiSite) -> String {
+        match &site.subdomain {
+            Some(subdomain) => format!("{}.{}", subdomain, site.host),
+            None => site.host.to_string(),
         }
     }
 
+    fn format_revision_definitions(revision: &ApiDeploymentRevision) -> String {
+        revision
+            .api_definitions
+            .iter()
+            .map(|def| format!("{}/{}", def.id, def.version))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     impl TextFormat for ApiDeployment {
         fn print(&self) {
             for api_defs in &self.api_definitions {
@@ -533,12 +748,87 @@ pub mod api_deployment {
             .unwrap()
         }
     }
+
+    impl TextFormat for ApiDeploymentRevision {
+        fn print(&self) {
+            printdoc!(
+                "
+                API deployment on {} is at revision {} ({}) with definitions [{}]
+                ",
+                format_message_highlight(&format_api_site(&self.site)),
+                format_message_highlight(&self.revision),
+                self.change,
+                format_revision_definitions(self),
+            );
+        }
+    }
+
+    #[derive(Table)]
+    struct ApiDeploymentRevisionTableView {
+        #[table(title = "Revision")]
+        pub revision: u64,
+        #[table(title = "Change")]
+        pub change: String,
+        #[table(title = "Definitions")]
+        pub definitions: String,
+        #[table(title = "Created by")]
+        pub created_by: String,
+        #[table(title = "Created at")]
+        pub created_at: String,
+    }
+
+    impl From<&ApiDeploymentRevision> for ApiDeploymentRevisionTableView {
+        fn from(value: &ApiDeploymentRevision) -> Self {
+            Self {
+                revision: value.revision,
+                change: match value.source_revision {
+                    Some(source_revision) => format!("{} to {}", value.change, source_revision),
+                    None => value.change.to_string(),
+                },
+                definitions: format_revision_definitions(value),
+                created_by: value.created_by.clone(),
+                created_at: value.created_at.to_string(),
+            }
+        }
+    }
+
+    impl TextFormat for Vec<ApiDeploymentRevision> {
+        fn print(&self) {
+            print_table::<_, ApiDeploymentRevisionTableView>(self);
+        }
+    }
+
+    fn print_routes(prefix: &str, routes: &[ApiRouteInfo]) {
+        for route in routes {
+            println!("{} {} {}", prefix, route.method, route.path);
+        }
+    }
+
+    impl TextFormat for ApiDeploymentRevisionDiff {
+        fn print(&self) {
+            if self.added_routes.is_empty()
+                && self.removed_routes.is_empty()
+                && self.changed_routes.is_empty()
+            {
+                println!(
+                    "No route changes between revision {} and {}",
+                    self.from_revision, self.to_revision
+                );
+            } else {
+                print_routes("+", &self.added_routes);
+                print_routes("-", &self.removed_routes);
+                print_routes("~", &self.changed_routes);
+            }
+        }
+    }
 }
 
 pub mod component {
     use crate::model::component::ComponentView;
     use crate::model::text::fmt::*;
     use cli_table::{format::Justify, print_stdout, Table, WithTitle};
+    use golem_client::model::{ComponentGcReport, ComponentGcReportEntry};
+    use itertools::Itertools;
     use serde::{Deserialize, Serialize};
 
     #[derive(Table)]
@@ -589,7 +879,37 @@ pub mod component {
             .fmt_field_option("Project ID", &view.project_id, format_id)
             .fmt_field("Component size", &view.component_size, format_binary_size)
             .fmt_field_option("Created at", &view.created_at, |d| d.to_string())
-            .fmt_field("Exports", &view.exports, |e| format_exports(e.as_slice()));
+            .fmt_field("Exports", &view.exports, |e| format_exports(e.as_slice()))
+            .fmt_field_option("Oplog policy", &view.oplog_policy, format_oplog_policy)
+            .fmt_field_optional(
+                "Volumes",
+                view.volumes.as_slice(),
+                !view.volumes.is_empty(),
+                format_volumes,
+            )
+            .fmt_field_optional(
+                "Imports",
+                view.imports.as_slice(),
+                !view.imports.is_empty(),
+                format_imports,
+            )
+            .fmt_field_option(
+                "Capability policy",
+                &view.capability_policy,
+                format_capability_policy,
+            )
+            .fmt_field_option("Egress policy", &view.egress_policy, format_egress_policy)
+            .fmt_field_optional(
+                "Secrets",
+                view.secrets.as_slice(),
+                !view.secrets.is_empty(),
+                format_secrets,
+            )
+            .fmt_field_option(
+                "Failure policy",
+                &view.failure_policy,
+                format_failure_policy,
+            );
 
         fields.build()
     }
@@ -642,6 +962,52 @@ pub mod component {
             component_view_fields(&self.0)
         }
     }
+
+    #[derive(Table)]
+    struct ComponentGcReportTableView {
+        #[table(title = "Component ID")]
+        pub component_id: String,
+        #[table(title = "Name")]
+        pub component_name: String,
+        #[table(title = "Retained versions")]
+        pub retained_versions: String,
+        #[table(title = "Removable versions")]
+        pub removable_versions: String,
+        #[table(title = "Reclaimable size", justify = "Justify::Right")]
+        pub reclaimable_size: String,
+    }
+
+    impl From<&ComponentGcReportEntry> for ComponentGcReportTableView {
+        fn from(value: &ComponentGcReportEntry) -> Self {
+            Self {
+                component_id: value.component_id.to_string(),
+                component_name: value.component_name.clone(),
+                retained_versions: value
+                    .retained_versions
+                    .iter()
+                    .map(|retained| {
+                        format!(
+                            "{} ({})",
+                            retained.version,
+                            retained.reasons.iter().join(", ")
+                        )
+                    })
+                    .join("\n"),
+                removable_versions: value.removable_versions.iter().join(", "),
+                reclaimable_size: format_binary_size(&value.reclaimable_size),
+            }
+        }
+    }
+
+    impl TextFormat for ComponentGcReport {
+        fn print(&self) {
+            if self.components.is_empty() {
+                println!("No components found");
+            } else {
+                print_table::<_, ComponentGcReportTableView>(&self.components);
+            }
+        }
+    }
 }
 
 pub mod example {
@@ -759,11 +1125,14 @@ pub mod profile {
 }
 
 pub mod worker {
-    use crate::model::deploy::TryUpdateAllWorkersResult;
+    use crate::model::deploy::{
+        TryUpdateAllWorkersDryRunResult, TryUpdateAllWorkersResult, WorkerUpdateDryRunResult,
+    };
     use crate::model::invoke_result_view::InvokeResultView;
     use crate::model::text::fmt::*;
     use crate::model::{
-        IdempotencyKey, WorkerMetadata, WorkerMetadataView, WorkersMetadataResponseView,
+        FailedWorkerView, FailedWorkersErrorView, FailedWorkersView, IdempotencyKey,
+        WorkerMetadata, WorkerMetadataView, WorkersMetadataResponseView,
     };
     use base64::prelude::BASE64_STANDARD;
     use base64::Engine;
@@ -867,6 +1236,18 @@ pub mod worker {
                             .join(";")
                     },
                 )
+                .fmt_field_optional(
+                    "Labels",
+                    &self.0.labels,
+                    !self.0.labels.is_empty(),
+                    |labels| {
+                        labels
+                            .iter()
+                            .sorted_by_key(|(k, _)| *k)
+                            .map(|(k, v)| format!("{}={}", k, v.bold()))
+                            .join(";")
+                    },
+                )
                 .fmt_field("Status", &self.0.status, format_status)
                 .fmt_field("Retry count", &self.0.retry_count, format_retry_count)
                 .fmt_field_optional(
@@ -935,6 +1316,72 @@ pub mod worker {
         }
     }
 
+    #[derive(Table)]
+    struct FailedWorkerTableView {
+        #[table(title = "Worker URN")]
+        pub worker_urn: WorkerUrn,
+
+        #[table(title = "Name")]
+        pub worker_name: String,
+
+        #[table(title = "Component\nversion", justify = "Justify::Right")]
+        pub component_version: u64,
+
+        #[table(title = "Retry\ncount", justify = "Justify::Right")]
+        pub retry_count: u64,
+
+        #[table(title = "Error")]
+        pub error: String,
+    }
+
+    impl From<&FailedWorkerView> for FailedWorkerTableView {
+        fn from(value: &FailedWorkerView) -> Self {
+            FailedWorkerTableView {
+                worker_urn: value.worker_urn.clone(),
+                worker_name: value.worker_urn.id.worker_name.clone().unwrap_or_default(),
+                component_version: value.component_version,
+                retry_count: value.retry_count,
+                error: value
+                    .last_error
+                    .as_deref()
+                    .and_then(|error| error.lines().next())
+                    .unwrap_or_default()
+                    .to_string(),
+            }
+        }
+    }
+
+    #[derive(Table)]
+    struct FailedWorkersErrorTableView {
+        #[table(title = "Workers", justify = "Justify::Right")]
+        pub count: usize,
+
+        #[table(title = "Error")]
+        pub error: String,
+    }
+
+    impl From<&FailedWorkersErrorView> for FailedWorkersErrorTableView {
+        fn from(value: &FailedWorkersErrorView) -> Self {
+            FailedWorkersErrorTableView {
+                count: value.count,
+                error: value.error.clone(),
+            }
+        }
+    }
+
+    impl TextFormat for FailedWorkersView {
+        fn print(&self) {
+            if self.workers.is_empty() {
+                println!("No failed workers.");
+            } else {
+                print_table::<_, FailedWorkerTableView>(&self.workers);
+                println!();
+                println!("{}", format_warn("Errors of the failed workers:"));
+                print_table::<_, FailedWorkersErrorTableView>(&self.errors);
+            }
+        }
+    }
+
     impl TextFormat for IdempotencyKey {
         fn print(&self) {
             printdoc!(
@@ -986,6 +1433,89 @@ pub mod worker {
         }
     }
 
+    impl MessageWithFields for WorkerUpdateDryRunResult {
+        fn message(&self) -> String {
+            if self.succeeded {
+                format!(
+                    "Update of worker {} to version {} would succeed",
+                    format_main_id(&self.worker_urn),
+                    format_id(&self.target_version)
+                )
+            } else {
+                format_warn(&format!(
+                    "Update of worker {} to version {} would fail",
+                    self.worker_urn, self.target_version
+                ))
+            }
+        }
+
+        fn fields(&self) -> Vec<(String, String)> {
+            let mut fields = FieldsBuilder::new();
+
+            fields
+                .fmt_field("Replayed until", &self.replayed_until, format_id)
+                .fmt_field_option("Diverged at", &self.diverged_at, format_id)
+                .fmt_field_option("Error", &self.error, |error| format_error(error));
+
+            fields.build()
+        }
+    }
+
+    #[derive(Table)]
+    struct WorkerUpdateDryRunTableView {
+        #[table(title = "Worker URN")]
+        pub worker_urn: WorkerUrn,
+
+        #[table(title = "Name")]
+        pub worker_name: String,
+
+        #[table(title = "Succeeded")]
+        pub succeeded: bool,
+
+        #[table(title = "Replayed until", justify = "Justify::Right")]
+        pub replayed_until: u64,
+
+        #[table(title = "Diverged at", justify = "Justify::Right")]
+        pub diverged_at: String,
+
+        #[table(title = "Error")]
+        pub error: String,
+    }
+
+    impl From<&WorkerUpdateDryRunResult> for WorkerUpdateDryRunTableView {
+        fn from(value: &WorkerUpdateDryRunResult) -> Self {
+            WorkerUpdateDryRunTableView {
+                worker_urn: value.worker_urn.clone(),
+                worker_name: value.worker_urn.id.worker_name.clone().unwrap_or_default(),
+                succeeded: value.succeeded,
+                replayed_until: value.replayed_until,
+                diverged_at: value
+                    .diverged_at
+                    .map(|idx| idx.to_string())
+                    .unwrap_or_default(),
+                error: value.error.clone().unwrap_or_default(),
+            }
+        }
+    }
+
+    impl TextFormat for TryUpdateAllWorkersDryRunResult {
+        fn print(&self) {
+            if self.results.is_empty() {
+                println!("No workers to update.");
+            } else {
+                print_table::<_, WorkerUpdateDryRunTableView>(&self.results);
+            }
+
+            if !self.failed.is_empty() {
+                println!(
+                    "{}",
+                    format_warn("Failed to dry-run the update of the following workers:")
+                );
+                print_table::<_, WorkerUrnTableView>(&self.failed);
+            }
+        }
+    }
+
     impl TextFormat for InvokeResultView {
         fn print(&self) {
             fn print_results_format(format: &str) {
@@ -1314,6 +1844,46 @@ pub mod worker {
                     );
                     print_plugin_description(pad, &params.plugin);
                 }
+                PublicOplogEntry::UpdateLabels(params) => {
+                    println!("{}", format_message_highlight("UPDATE LABELS"));
+                    println!("{pad}at:                {}", format_id(&params.timestamp));
+                    println!("{pad}labels:");
+                    for (k, v) in &params.labels {
+                        println!("{pad}  - {}: {}", k, format_id(v));
+                    }
+                }
+                PublicOplogEntry::PutFile(params) => {
+                    println!("{}", format_message_highlight("PUT FILE"));
+                    println!("{pad}at:                {}", format_id(&params.timestamp));
+                    println!("{pad}path:              {}", format_id(&params.path));
+                    println!("{pad}size:              {}", format_id(&params.size));
+                }
+                PublicOplogEntry::DeleteFile(params) => {
+                    println!("{}", format_message_highlight("DELETE FILE"));
+                    println!("{pad}at:                {}", format_id(&params.timestamp));
+                    println!("{pad}path:              {}", format_id(&params.path));
+                }
+                PublicOplogEntry::MakeDirectory(params) => {
+                    println!("{}", format_message_highlight("MAKE DIRECTORY"));
+                    println!("{pad}at:                {}", format_id(&params.timestamp));
+                    println!("{pad}path:              {}", format_id(&params.path));
+                }
+                PublicOplogEntry::CancelPendingInvocation(params) => {
+                    println!("{}", format_message_highlight("CANCEL PENDING INVOCATION"));
+                    println!("{pad}at:                {}", format_id(&params.timestamp));
+                    println!(
+                        "{pad}idempotency key:   {}",
+                        format_id(&params.idempotency_key)
+                    );
+                }
+                PublicOplogEntry::InvocationTimedOut(params) => {
+                    println!("{}", format_message_highlight("INVOCATION TIMED OUT"));
+                    println!("{pad}at:                {}", format_id(&params.timestamp));
+                    println!(
+                        "{pad}idempotency key:   {}",
+                        format_id(&params.idempotency_key)
+                    );
+                }
             }
         }
     }
@@ -1381,6 +1951,15 @@ pub mod plugin {
                     PluginTypeSpecificDefinition::OplogProcessor(_) => {
                         "Oplog Processor".to_string()
                     }
+                    PluginTypeSpecificDefinition::WasmComponentTransformer(_) => {
+                        "WASM Component Transformer".to_string()
+                    }
+                    PluginTypeSpecificDefinition::OplogArchiveSink(_) => {
+                        "Oplog Archive Sink".to_string()
+                    }
+                    PluginTypeSpecificDefinition::OplogWebhookSink(_) => {
+                        "Oplog Webhook Sink".to_string()
+                    }
                 },
                 scope: match &value.scope {
                     DefaultPluginScope::Global(_) => "Global".to_string(),
@@ -1445,6 +2024,17 @@ pub mod plugin {
                     fields.fmt_field("Component ID", &specs.component_id, format_id);
                     fields.fmt_field("Component Version", &specs.component_version, format_id);
                 }
+                PluginTypeSpecificDefinition::WasmComponentTransformer(specs) => {
+                    fields.fmt_field("Type", &"WASM Component Transformer".to_string(), format_id);
+                    fields.fmt_field("Component ID", &specs.component_id, format_id);
+                    fields.fmt_field("Component Version", &specs.component_version, format_id);
+                }
+                PluginTypeSpecificDefinition::OplogArchiveSink(_) => {
+                    fields.fmt_field("Type", &"Oplog Archive Sink".to_string(), format_id);
+                }
+                PluginTypeSpecificDefinition::OplogWebhookSink(_) => {
+                    fields.fmt_field("Type", &"Oplog Webhook Sink".to_string(), format_id);
+                }
             }
 
             fields.build()
@@ -1519,3 +2109,74 @@ pub mod plugin {
         }
     }
 }
+
+pub mod app {
+    use crate::model::app_plan::{AppApplyResult, AppPlan, AppPlanAction, AppPlanStep};
+    use crate::model::text::fmt::*;
+    use cli_table::Table;
+    use colored::Colorize;
+
+    #[derive(Table)]
+    struct AppPlanStepTableView {
+        #[table(title = "Action")]
+        pub action: String,
+        #[table(title = "Kind")]
+        pub kind: String,
+        #[table(title = "Name")]
+        pub name: String,
+        #[table(title = "Reason")]
+        pub reason: String,
+    }
+
+    impl From<&AppPlanStep> for AppPlanStepTableView {
+        fn from(value: &AppPlanStep) -> Self {
+            let action = match value.action {
+                AppPlanAction::Create => value.action.to_string().green().to_string(),
+                AppPlanAction::Update => value.action.to_string().yellow().to_string(),
+                AppPlanAction::Delete => value.action.to_string().red().to_string(),
+                AppPlanAction::Unchanged => value.action.to_string(),
+            };
+            AppPlanStepTableView {
+                action,
+                kind: value.kind.to_string(),
+                name: value.name.clone(),
+                reason: value.reason.clone().unwrap_or_default(),
+            }
+        }
+    }
+
+    impl TextFormat for AppPlan {
+        fn print(&self) {
+            if self.steps.is_empty() {
+                println!("The application manifests do not describe any resources.");
+                return;
+            }
+
+            print_table::<_, AppPlanStepTableView>(&self.steps);
+
+            let changes = self.changes().count();
+            if changes == 0 {
+                println!("\nThe deployed application is up to date.");
+            } else {
+                println!(
+                    "\n{} to apply.",
+                    format_message_highlight(&format!("{changes} change(s)"))
+                );
+            }
+        }
+    }
+
+    impl TextFormat for AppApplyResult {
+        fn print(&self) {
+            if self.applied.is_empty() {
+                println!("The deployed application is up to date.");
+            } else {
+                print_table::<_, AppPlanStepTableView>(&self.applied);
+                println!(
+                    "\nApplied {}.",
+                    format_message_highlight(&format!("{} change(s)", self.applied.len()))
+                );
+            }
+        }
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::model::OssContext;
+use crate::command::app::AppCommand;
 use crate::command::profile::UniversalProfileAdd;
 use crate::command::worker::OssWorkerUriArg;
 use crate::command::{self, NoProfileCommandContext};
@@ -156,7 +157,9 @@ impl<ProfileAdd: clap::Args + Into<UniversalProfileAdd>> CliCommand<OssCommandCo
 {
     async fn run(self, ctx: OssCommandContext) -> Result<GolemResult, GolemError> {
         match self {
-            SharedCommand::App { command } => {
+            SharedCommand::App {
+                command: AppCommand::Build(command),
+            } => {
                 golem_wasm_rpc_stubgen::run_app_command::<GolemComponentExtensions>(
                     {
                         // TODO: it would be nice to use the same logic which is used by default for handling help,
@@ -179,6 +182,19 @@ impl<ProfileAdd: clap::Args + Into<UniversalProfileAdd>> CliCommand<OssCommandCo
                 .map(|_| GolemResult::Str("".to_string()))
                 .map_err(Into::into)
             }
+            SharedCommand::App {
+                command: AppCommand::Deploy(subcommand),
+            } => {
+                let factory = ctx.factory;
+
+                subcommand
+                    .handle(
+                        ctx.format,
+                        factory.app_deploy_service(),
+                        factory.project_resolver().as_ref(),
+                    )
+                    .await
+            }
             SharedCommand::Component { subcommand } => {
                 let factory = ctx.factory;
 
@@ -232,6 +248,16 @@ impl<ProfileAdd: clap::Args + Into<UniversalProfileAdd>> CliCommand<OssCommandCo
                     )
                     .await
             }
+            SharedCommand::Secret { subcommand } => {
+                let factory = ctx.factory;
+
+                subcommand
+                    .handle(
+                        factory.secret_service().as_ref(),
+                        factory.project_resolver().as_ref(),
+                    )
+                    .await
+            }
             SharedCommand::Profile { subcommand } => {
                 subcommand
                     .handle(ctx.cli_kind, &ctx.config_dir, &DummyProfileAuth)
@@ -16,7 +16,10 @@ use async_trait::async_trait;
 use itertools::Itertools;
 
 use crate::clients::api_deployment::ApiDeploymentClient;
-use golem_client::model::{ApiDefinitionInfo, ApiSite};
+use golem_client::model::{
+    ApiDefinitionInfo, ApiDeploymentRevision, ApiDeploymentRevisionDiff,
+    ApiDeploymentRollbackRequest, ApiSite,
+};
 use tracing::info;
 
 use crate::model::{ApiDefinitionId, ApiDefinitionIdWithVersion, ApiDeployment, GolemError};
@@ -89,4 +92,37 @@ impl<C: golem_client::api::ApiDeploymentClient + Sync + Send> ApiDeploymentClien
 
         Ok(self.client.delete_deployment(site).await?)
     }
+
+    async fn history(&self, site: &str) -> Result<Vec<ApiDeploymentRevision>, GolemError> {
+        info!("Getting api deployment revisions for site {site}");
+
+        Ok(self.client.list_deployment_revisions(site).await?)
+    }
+
+    async fn rollback(
+        &self,
+        site: &str,
+        revision: u64,
+    ) -> Result<ApiDeploymentRevision, GolemError> {
+        info!("Rolling back api deployment for site {site} to revision {revision}");
+
+        Ok(self
+            .client
+            .rollback_deployment(site, &ApiDeploymentRollbackRequest { revision })
+            .await?)
+    }
+
+    async fn diff(
+        &self,
+        site: &str,
+        from: u64,
+        to: u64,
+    ) -> Result<ApiDeploymentRevisionDiff, GolemError> {
+        info!("Comparing api deployment revisions {from} and {to} for site {site}");
+
+        Ok(self
+            .client
+            .diff_deployment_revisions(site, from, to)
+            .await?)
+    }
 }
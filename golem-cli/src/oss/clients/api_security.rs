@@ -17,6 +17,7 @@ use async_trait::async_trait;
 use crate::clients::api_security::ApiSecurityClient;
 use crate::model::{ApiSecurityScheme, GolemError};
 use crate::oss::model::OssContext;
+use golem_client::api::ApiSecurityError;
 use golem_client::model::{Provider, SecuritySchemeData};
 use tracing::info;
 
@@ -70,4 +71,18 @@ impl<C: golem_client::api::ApiSecurityClient + Sync + Send> ApiSecurityClient
 
         Ok(ApiSecurityScheme::from(result))
     }
+
+    async fn find(
+        &self,
+        id: &str,
+        _project: &Self::ProjectContext,
+    ) -> Result<Option<ApiSecurityScheme>, GolemError> {
+        info!("Looking up api security scheme {id}");
+
+        match self.client.get(id).await {
+            Ok(result) => Ok(Some(ApiSecurityScheme::from(result))),
+            Err(golem_client::Error::Item(ApiSecurityError::Error404(_))) => Ok(None),
+            Err(err) => Err(GolemError::from(err)),
+        }
+    }
 }
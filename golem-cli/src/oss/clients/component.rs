@@ -22,7 +22,9 @@ use crate::clients::component::ComponentClient;
 use crate::model::component::Component;
 use crate::model::{ComponentName, GolemError, PathBufOrStdin};
 use crate::oss::model::OssContext;
-use golem_client::model::{PluginInstallation, PluginInstallationCreation};
+use golem_client::model::{ComponentGcReport, PluginInstallation, PluginInstallationCreation};
+use golem_common::model::component_metadata::{CapabilityPolicy, OplogPolicy};
+use golem_common::model::secret::SecretReference;
 use golem_common::uri::oss::urn::ComponentUrn;
 use std::path::Path;
 use tokio::fs::File;
@@ -247,4 +249,52 @@ impl<C: golem_client::api::ComponentClient + Sync + Send> ComponentClient
 
         Ok(())
     }
+
+    async fn set_oplog_policy(
+        &self,
+        urn: &ComponentUrn,
+        policy: OplogPolicy,
+    ) -> Result<Component, GolemError> {
+        info!("Setting the oplog policy of {urn}");
+
+        Ok(self
+            .client
+            .set_oplog_policy(&urn.id.0, &policy)
+            .await?
+            .into())
+    }
+
+    async fn set_capability_policy(
+        &self,
+        urn: &ComponentUrn,
+        policy: CapabilityPolicy,
+    ) -> Result<Component, GolemError> {
+        info!("Setting the capability policy of {urn}");
+
+        Ok(self
+            .client
+            .set_capability_policy(&urn.id.0, &policy)
+            .await?
+            .into())
+    }
+
+    async fn set_secrets(
+        &self,
+        urn: &ComponentUrn,
+        secrets: Vec<SecretReference>,
+    ) -> Result<Component, GolemError> {
+        info!("Setting the secrets of {urn}");
+
+        Ok(self
+            .client
+            .set_component_secrets(&urn.id.0, &secrets)
+            .await?
+            .into())
+    }
+
+    async fn gc_report(&self) -> Result<ComponentGcReport, GolemError> {
+        info!("Getting component garbage collection report");
+
+        Ok(self.client.get_component_gc_report().await?)
+    }
 }
@@ -15,7 +15,7 @@
 use crate::model::ResponseContentErrorMapper;
 use golem_client::api::{
     ApiDefinitionError, ApiDeploymentError, ApiSecurityError, ComponentError, HealthCheckError,
-    PluginError, WorkerError,
+    PluginError, SecretError, WorkerError,
 };
 use golem_client::model::{
     GolemError, GolemErrorComponentDownloadFailed, GolemErrorComponentParseFailed,
@@ -55,6 +55,19 @@ impl ResponseContentErrorMapper for PluginError {
     }
 }
 
+impl ResponseContentErrorMapper for SecretError {
+    fn map(self) -> String {
+        match self {
+            SecretError::Error400(errors) => errors.errors.iter().join(", "),
+            SecretError::Error401(error) => error.error,
+            SecretError::Error403(error) => error.error,
+            SecretError::Error404(error) => error.error,
+            SecretError::Error409(error) => error.error,
+            SecretError::Error500(error) => error.error,
+        }
+    }
+}
+
 impl ResponseContentErrorMapper for WorkerError {
     fn map(self) -> String {
         match self {
@@ -229,6 +242,7 @@ pub fn display_golem_error(error: GolemError) -> String {
         GolemError::FileSystemError(GolemErrorFileSystemError { path, reason, .. }) => {
             format!("Error working with file {}: {}", path, reason)
         }
+        GolemError::InvocationTimedOut(_) => "Invocation timed out".to_string(),
     }
 }
 
@@ -0,0 +1,57 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::clients::secret::SecretClient;
+use crate::model::GolemError;
+use crate::oss::model::OssContext;
+use async_trait::async_trait;
+use golem_client::model::{Secret, SecretCreation};
+use tracing::info;
+
+#[derive(Clone)]
+pub struct SecretClientLive<C: golem_client::api::SecretClient + Sync + Send> {
+    pub client: C,
+}
+
+#[async_trait]
+impl<C: golem_client::api::SecretClient + Sync + Send> SecretClient for SecretClientLive<C> {
+    type ProjectContext = OssContext;
+
+    async fn list(&self, _project: &Self::ProjectContext) -> Result<Vec<Secret>, GolemError> {
+        info!("Getting secrets");
+
+        Ok(self.client.list_secrets().await?)
+    }
+
+    async fn create(
+        &self,
+        name: String,
+        value: String,
+        _project: &Self::ProjectContext,
+    ) -> Result<Secret, GolemError> {
+        info!("Creating secret {name}");
+
+        Ok(self
+            .client
+            .create_secret(&SecretCreation { name, value })
+            .await?)
+    }
+
+    async fn delete(&self, name: &str, _project: &Self::ProjectContext) -> Result<(), GolemError> {
+        info!("Deleting secret {name}");
+
+        let _ = self.client.delete_secret(name).await?;
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::Path;
 use std::time::Duration;
 
 use crate::clients::worker::{worker_name_required, WorkerClient};
@@ -25,14 +26,16 @@ use async_trait::async_trait;
 use futures_util::{future, pin_mut, SinkExt, StreamExt};
 use golem_client::api::WorkerError;
 use golem_client::model::{
-    InvokeParameters, InvokeResult, ScanCursor, UpdateWorkerRequest, WorkerCreationRequest,
-    WorkerFilter, WorkerId, WorkersMetadataRequest,
+    DryRunUpdateWorkerRequest, InvokeParameters, InvokeResult, ScanCursor, UpdateDryRunResult,
+    UpdateWorkerLabelsRequest, UpdateWorkerRequest, WorkerCreationRequest, WorkerFilter, WorkerId,
+    WorkersMetadataRequest,
 };
 use golem_client::{Context, Error};
 use golem_common::model::public_oplog::{OplogCursor, PublicOplogEntry};
-use golem_common::model::WorkerEvent;
+use golem_common::model::{LogLevel, WorkerEvent};
 use golem_common::uri::oss::urn::{ComponentUrn, WorkerUrn};
 use native_tls::TlsConnector;
+use tokio::fs::File;
 use tokio::{task, time};
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
 use tokio_tungstenite::tungstenite::protocol::Message;
@@ -54,6 +57,7 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
         component_urn: ComponentUrn,
         args: Vec<String>,
         env: Vec<(String, String)>,
+        labels: Vec<(String, String)>,
     ) -> Result<WorkerId, GolemError> {
         info!("Creating worker {name} of {component_urn}");
 
@@ -65,6 +69,7 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
                     name: name.0,
                     args,
                     env: env.into_iter().collect(),
+                    labels: Some(labels.into_iter().collect()),
                 },
             )
             .await?
@@ -192,6 +197,78 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
         Ok(())
     }
 
+    async fn update_labels(
+        &self,
+        worker_urn: WorkerUrn,
+        labels: Vec<(String, String)>,
+    ) -> Result<(), GolemError> {
+        info!("Updating the labels of {worker_urn}");
+
+        let _ = self
+            .client
+            .update_worker_labels(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &UpdateWorkerLabelsRequest {
+                    labels: labels.into_iter().collect(),
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn put_file(
+        &self,
+        worker_urn: WorkerUrn,
+        path: String,
+        source: &Path,
+    ) -> Result<(), GolemError> {
+        info!("Writing file {path} of {worker_urn}");
+
+        let file = File::open(source)
+            .await
+            .map_err(|e| GolemError(format!("Can't open source file: {e}")))?;
+
+        let _ = self
+            .client
+            .put_file(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &path,
+                file,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_file(&self, worker_urn: WorkerUrn, path: String) -> Result<(), GolemError> {
+        info!("Deleting file {path} of {worker_urn}");
+
+        let _ = self
+            .client
+            .delete_file(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &path,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn make_directory(&self, worker_urn: WorkerUrn, path: String) -> Result<(), GolemError> {
+        info!("Creating directory {path} in {worker_urn}");
+
+        let _ = self
+            .client
+            .make_directory(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &path,
+            )
+            .await?;
+        Ok(())
+    }
+
     async fn get_metadata(&self, worker_urn: WorkerUrn) -> Result<WorkerMetadata, GolemError> {
         info!("Getting worker {worker_urn} metadata");
 
@@ -440,6 +517,16 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
                                 WorkerEvent::Close => {}
                                 WorkerEvent::InvocationStart { .. } => {}
                                 WorkerEvent::InvocationFinished { .. } => {}
+                                WorkerEvent::WorkerFailed {
+                                    timestamp, error, ..
+                                } => {
+                                    output.emit_log(
+                                        timestamp,
+                                        LogLevel::Error,
+                                        "worker-failed".to_string(),
+                                        error,
+                                    );
+                                }
                             },
                         }
                     }
@@ -479,6 +566,23 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
         Ok(())
     }
 
+    async fn dry_run_update(
+        &self,
+        worker_urn: WorkerUrn,
+        target_version: u64,
+    ) -> Result<UpdateDryRunResult, GolemError> {
+        info!("Dry-running the update of worker {worker_urn}");
+
+        Ok(self
+            .client
+            .dry_run_update_worker(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &DryRunUpdateWorkerRequest { target_version },
+            )
+            .await?)
+    }
+
     async fn get_oplog(
         &self,
         worker_urn: WorkerUrn,
@@ -19,6 +19,7 @@ use crate::clients::component::ComponentClient;
 use crate::clients::file_download;
 use crate::clients::health_check::HealthCheckClient;
 use crate::clients::plugin::PluginClient;
+use crate::clients::secret::SecretClient;
 use crate::clients::worker::WorkerClient;
 use crate::config::{HttpClientConfig, OssProfile};
 use crate::factory::ServiceFactory;
@@ -29,6 +30,7 @@ use crate::oss::clients::api_security::ApiSecurityClientLive;
 use crate::oss::clients::component::ComponentClientLive;
 use crate::oss::clients::health_check::HealthCheckClientLive;
 use crate::oss::clients::plugin::PluginClientLive;
+use crate::oss::clients::secret::SecretClientLive;
 use crate::oss::clients::worker::WorkerClientLive;
 use crate::oss::model::OssContext;
 use crate::service::project::{ProjectResolver, ProjectResolverOss};
@@ -194,6 +196,16 @@ impl ServiceFactory for OssServiceFactory {
         })
     }
 
+    fn secret_client(
+        &self,
+    ) -> Box<dyn SecretClient<ProjectContext = Self::ProjectContext> + Send + Sync> {
+        Box::new(SecretClientLive {
+            client: golem_client::api::SecretClientLive {
+                context: self.component_context(),
+            },
+        })
+    }
+
     fn health_check_clients(&self) -> Vec<Arc<dyn HealthCheckClient + Send + Sync>> {
         let contexts = vec![
             self.component_context_health_check(),
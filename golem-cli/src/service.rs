@@ -15,8 +15,10 @@
 pub mod api_definition;
 pub mod api_deployment;
 pub mod api_security;
+pub mod app;
 pub mod component;
 pub mod deploy;
 pub mod project;
+pub mod secret;
 pub mod version;
 pub mod worker;
@@ -34,6 +34,9 @@ pub trait ApiDeploymentService {
         project: &Self::ProjectContext,
     ) -> Result<GolemResult, GolemError>;
     async fn delete(&self, site: String) -> Result<GolemResult, GolemError>;
+    async fn history(&self, site: String) -> Result<GolemResult, GolemError>;
+    async fn rollback(&self, site: String, revision: u64) -> Result<GolemResult, GolemError>;
+    async fn diff(&self, site: String, from: u64, to: u64) -> Result<GolemResult, GolemError>;
 }
 
 pub struct ApiDeploymentServiceLive<ProjectContext> {
@@ -82,4 +85,22 @@ impl<ProjectContext: Send + Sync> ApiDeploymentService
 
         Ok(GolemResult::Str(res))
     }
+
+    async fn history(&self, site: String) -> Result<GolemResult, GolemError> {
+        let revisions = self.client.history(&site).await?;
+
+        Ok(GolemResult::Ok(Box::new(revisions)))
+    }
+
+    async fn rollback(&self, site: String, revision: u64) -> Result<GolemResult, GolemError> {
+        let revision = self.client.rollback(&site, revision).await?;
+
+        Ok(GolemResult::Ok(Box::new(revision)))
+    }
+
+    async fn diff(&self, site: String, from: u64, to: u64) -> Result<GolemResult, GolemError> {
+        let diff = self.client.diff(&site, from, to).await?;
+
+        Ok(GolemResult::Ok(Box::new(diff)))
+    }
 }
@@ -0,0 +1,1149 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::clients::api_definition::ApiDefinitionClient;
+use crate::clients::api_deployment::ApiDeploymentClient;
+use crate::clients::api_security::ApiSecurityClient;
+use crate::clients::component::ComponentClient;
+use crate::model::app_ext::{
+    DeclarativeApp, DeclarativeComponent, HttpApiDefinitionSource, HttpApiDeployment,
+    HttpApiSecurityScheme, PluginInstallation, Worker,
+};
+use crate::model::app_plan::{
+    AppApplyResult, AppDeployState, AppDeployedComponent, AppPlan, AppPlanAction, AppPlanStep,
+    AppProjectState, AppResourceKind,
+};
+use crate::model::component::Component;
+use crate::model::text::api_deployment::format_site;
+use crate::model::{
+    decode_api_definition, ApiDefinitionId, ApiDefinitionIdWithVersion, ApiDefinitionVersion,
+    ComponentName, Format, GolemError, GolemResult, PathBufOrStdin, WorkerName,
+};
+use crate::service::component::ComponentService;
+use crate::service::worker::WorkerService;
+use async_trait::async_trait;
+use golem_client::model::HttpApiDefinitionRequest;
+use golem_common::model::{ComponentId, TargetWorkerId};
+use golem_common::uri::oss::uri::ComponentUri;
+use golem_common::uri::oss::url::ComponentUrl;
+use golem_common::uri::oss::urn::{ComponentUrn, WorkerUrn};
+use inquire::Confirm;
+use itertools::Itertools;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt::Display;
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+/// Declarative deployment of whole applications described by application manifests
+#[async_trait]
+pub trait AppDeployService {
+    type ProjectContext: Send + Sync;
+
+    /// Compares the application manifests with the deployed resources.
+    ///
+    /// With `prune`, the resources created by previous deployments of the application which are
+    /// no longer described by the manifests are planned to be removed. Resources created by other
+    /// means are never removed.
+    async fn plan(
+        &self,
+        app: &DeclarativeApp,
+        project: &Self::ProjectContext,
+        prune: bool,
+    ) -> Result<AppPlan, GolemError>;
+
+    /// Applies the plan of the application, in dependency order: components first (including
+    /// their plugin installations and workers), so the component constraints of the API
+    /// definitions referring to them can be satisfied, then security schemes, API definitions and
+    /// deployments. Removals of deployments and definitions are applied last.
+    ///
+    /// If a change fails, the reversible changes applied before it are undone in reverse order.
+    /// The resources created are recorded in the deployment state of the application.
+    async fn apply(
+        &self,
+        app: &DeclarativeApp,
+        project: &Self::ProjectContext,
+        prune: bool,
+        non_interactive: bool,
+        format: Format,
+    ) -> Result<GolemResult, GolemError>;
+}
+
+pub struct AppDeployServiceLive<ProjectContext> {
+    pub component_service: Arc<dyn ComponentService<ProjectContext = ProjectContext> + Send + Sync>,
+    pub component_client: Box<dyn ComponentClient<ProjectContext = ProjectContext> + Send + Sync>,
+    pub worker_service: Arc<dyn WorkerService<ProjectContext = ProjectContext> + Send + Sync>,
+    pub api_definition_client:
+        Box<dyn ApiDefinitionClient<ProjectContext = ProjectContext> + Send + Sync>,
+    pub api_deployment_client:
+        Box<dyn ApiDeploymentClient<ProjectContext = ProjectContext> + Send + Sync>,
+    pub api_security_scheme_client:
+        Box<dyn ApiSecurityClient<ProjectContext = ProjectContext> + Send + Sync>,
+}
+
+/// A step of the plan, with everything needed for applying it
+struct PlannedStep {
+    step: AppPlanStep,
+    change: Option<AppChange>,
+}
+
+impl PlannedStep {
+    fn new(
+        kind: AppResourceKind,
+        name: impl Into<String>,
+        change: AppChange,
+        reason: Option<String>,
+    ) -> Self {
+        Self {
+            step: AppPlanStep {
+                kind,
+                name: name.into(),
+                action: change.action(),
+                reason,
+            },
+            change: Some(change),
+        }
+    }
+
+    fn unchanged(kind: AppResourceKind, name: impl Into<String>, reason: Option<String>) -> Self {
+        Self {
+            step: AppPlanStep {
+                kind,
+                name: name.into(),
+                action: AppPlanAction::Unchanged,
+                reason,
+            },
+            change: None,
+        }
+    }
+}
+
+enum AppChange {
+    AddComponent(DeclarativeComponent),
+    UpdateComponent(ComponentUrn, DeclarativeComponent),
+    InstallPlugin {
+        component_name: String,
+        installation: PluginInstallation,
+    },
+    ReinstallPlugin {
+        component_name: String,
+        installation_id: Uuid,
+        installation: PluginInstallation,
+    },
+    UninstallPlugin {
+        component_name: String,
+        installation_id: Uuid,
+    },
+    CreateWorker {
+        component_name: String,
+        worker: Worker,
+    },
+    DeleteWorker(WorkerUrn),
+    CreateSecurityScheme(HttpApiSecurityScheme),
+    CreateApiDefinition(HttpApiDefinitionSource),
+    UpdateApiDefinition(HttpApiDefinitionSource),
+    DeleteApiDefinition(ApiDefinitionId, ApiDefinitionVersion),
+    Deploy(HttpApiDeployment),
+    Redeploy {
+        deployment: HttpApiDeployment,
+        previous: HttpApiDeployment,
+    },
+    DeleteDeployment(String),
+}
+
+impl AppChange {
+    fn action(&self) -> AppPlanAction {
+        match self {
+            AppChange::AddComponent(_)
+            | AppChange::InstallPlugin { .. }
+            | AppChange::CreateWorker { .. }
+            | AppChange::CreateSecurityScheme(_)
+            | AppChange::CreateApiDefinition(_)
+            | AppChange::Deploy(_) => AppPlanAction::Create,
+            AppChange::UpdateComponent(_, _)
+            | AppChange::ReinstallPlugin { .. }
+            | AppChange::UpdateApiDefinition(_)
+            | AppChange::Redeploy { .. } => AppPlanAction::Update,
+            AppChange::UninstallPlugin { .. }
+            | AppChange::DeleteWorker(_)
+            | AppChange::DeleteApiDefinition(_, _)
+            | AppChange::DeleteDeployment(_) => AppPlanAction::Delete,
+        }
+    }
+}
+
+impl<ProjectContext: Clone + Display + Send + Sync> AppDeployServiceLive<ProjectContext> {
+    async fn planned_steps(
+        &self,
+        app: &DeclarativeApp,
+        project: &ProjectContext,
+        prune: bool,
+    ) -> Result<Vec<PlannedStep>, GolemError> {
+        let state = load_state(&app.state_path)?
+            .projects
+            .remove(&project.to_string())
+            .unwrap_or_default();
+
+        let mut component_steps = Vec::new();
+        let mut plugin_steps = Vec::new();
+        let mut worker_steps = Vec::new();
+        for component in &app.components {
+            self.plan_component(
+                component,
+                project,
+                prune,
+                &state,
+                &mut component_steps,
+                &mut plugin_steps,
+                &mut worker_steps,
+            )
+            .await?;
+        }
+
+        let mut security_scheme_steps = Vec::new();
+        for scheme in &app.http_api.security_schemes {
+            security_scheme_steps.push(self.plan_security_scheme(scheme, project).await?);
+        }
+
+        let existing_definitions = self.api_definition_client.list(None, project).await?;
+
+        let mut definition_steps = Vec::new();
+        let mut desired_definitions = HashSet::new();
+        let mut changed_definitions = Vec::new();
+        for source in &app.http_api.definitions {
+            let definition = read_api_definition(source).await?;
+            desired_definitions.insert((definition.id.clone(), definition.version.clone()));
+
+            let name = format!("{}/{}", definition.id, definition.version);
+            let existing = existing_definitions.iter().find(|existing| {
+                existing.id == definition.id && existing.version == definition.version
+            });
+            definition_steps.push(match existing {
+                None => PlannedStep::new(
+                    AppResourceKind::ApiDefinition,
+                    name,
+                    AppChange::CreateApiDefinition(source.clone()),
+                    None,
+                ),
+                Some(existing) if existing.draft => PlannedStep::new(
+                    AppResourceKind::ApiDefinition,
+                    name,
+                    AppChange::UpdateApiDefinition(source.clone()),
+                    Some("draft definitions are always updated".to_string()),
+                ),
+                Some(_) => PlannedStep::unchanged(
+                    AppResourceKind::ApiDefinition,
+                    name,
+                    Some("published definitions cannot be changed".to_string()),
+                ),
+            });
+            if definition_steps
+                .last()
+                .is_some_and(|step| step.change.is_some())
+            {
+                changed_definitions.push(definition);
+            }
+        }
+
+        self.check_constraints(&changed_definitions).await?;
+
+        let mut existing_deployments = BTreeMap::new();
+        let definition_ids = existing_definitions
+            .iter()
+            .map(|definition| definition.id.clone())
+            .chain(
+                app.http_api
+                    .deployments
+                    .iter()
+                    .flat_map(|deployment| deployment.definitions.iter())
+                    .map(|definition| definition.id.0.clone()),
+            )
+            .unique();
+        for definition_id in definition_ids {
+            for deployment in self
+                .api_deployment_client
+                .list(&ApiDefinitionId(definition_id), project)
+                .await?
+            {
+                existing_deployments.insert(format_site(&deployment), deployment);
+            }
+        }
+
+        let mut deployment_steps = Vec::new();
+        for deployment in &app.http_api.deployments {
+            let site = deployment.site();
+            let desired = deployment
+                .definitions
+                .iter()
+                .map(|definition| definition.to_string())
+                .collect::<BTreeSet<_>>();
+            deployment_steps.push(match existing_deployments.get(&site) {
+                None => PlannedStep::new(
+                    AppResourceKind::ApiDeployment,
+                    site,
+                    AppChange::Deploy(deployment.clone()),
+                    None,
+                ),
+                Some(existing) => {
+                    let deployed = existing
+                        .api_definitions
+                        .iter()
+                        .map(|definition| format!("{}/{}", definition.id, definition.version))
+                        .collect::<BTreeSet<_>>();
+                    if deployed == desired {
+                        PlannedStep::unchanged(AppResourceKind::ApiDeployment, site, None)
+                    } else {
+                        PlannedStep::new(
+                            AppResourceKind::ApiDeployment,
+                            site,
+                            // Deploying replaces the definitions of an existing deployment
+                            AppChange::Redeploy {
+                                deployment: deployment.clone(),
+                                previous: HttpApiDeployment {
+                                    host: existing.site.host.clone(),
+                                    subdomain: existing.site.subdomain.clone(),
+                                    definitions: existing
+                                        .api_definitions
+                                        .iter()
+                                        .map(|definition| ApiDefinitionIdWithVersion {
+                                            id: ApiDefinitionId(definition.id.clone()),
+                                            version: ApiDefinitionVersion(
+                                                definition.version.clone(),
+                                            ),
+                                        })
+                                        .collect(),
+                                },
+                            },
+                            Some(format!(
+                                "deployed definitions: {}",
+                                deployed.iter().join(", ")
+                            )),
+                        )
+                    }
+                }
+            });
+        }
+
+        let mut removal_steps = Vec::new();
+        if prune {
+            let desired_sites = app
+                .http_api
+                .deployments
+                .iter()
+                .map(|deployment| deployment.site())
+                .collect::<HashSet<_>>();
+            for site in existing_deployments.keys() {
+                if !desired_sites.contains(site)
+                    && state.is_created(AppResourceKind::ApiDeployment, site)
+                {
+                    removal_steps.push(PlannedStep::new(
+                        AppResourceKind::ApiDeployment,
+                        site.clone(),
+                        AppChange::DeleteDeployment(site.clone()),
+                        Some("no longer in the manifests".to_string()),
+                    ));
+                }
+            }
+
+            for definition in &existing_definitions {
+                let name = format!("{}/{}", definition.id, definition.version);
+                if !desired_definitions
+                    .contains(&(definition.id.clone(), definition.version.clone()))
+                    && state.is_created(AppResourceKind::ApiDefinition, &name)
+                {
+                    removal_steps.push(PlannedStep::new(
+                        AppResourceKind::ApiDefinition,
+                        name,
+                        AppChange::DeleteApiDefinition(
+                            ApiDefinitionId(definition.id.clone()),
+                            ApiDefinitionVersion(definition.version.clone()),
+                        ),
+                        Some("no longer in the manifests".to_string()),
+                    ));
+                }
+            }
+        }
+
+        Ok(component_steps
+            .into_iter()
+            .chain(plugin_steps)
+            .chain(worker_steps)
+            .chain(security_scheme_steps)
+            .chain(definition_steps)
+            .chain(deployment_steps)
+            .chain(removal_steps)
+            .collect())
+    }
+
+    async fn plan_component(
+        &self,
+        component: &DeclarativeComponent,
+        project: &ProjectContext,
+        prune: bool,
+        state: &AppProjectState,
+        component_steps: &mut Vec<PlannedStep>,
+        plugin_steps: &mut Vec<PlannedStep>,
+        worker_steps: &mut Vec<PlannedStep>,
+    ) -> Result<(), GolemError> {
+        let Some(existing) = self.find_component(&component.name, project).await? else {
+            component_steps.push(PlannedStep::new(
+                AppResourceKind::Component,
+                &component.name,
+                AppChange::AddComponent(component.clone()),
+                None,
+            ));
+            for installation in &component.extensions.plugins {
+                plugin_steps.push(PlannedStep::new(
+                    AppResourceKind::PluginInstallation,
+                    plugin_step_name(&component.name, installation),
+                    AppChange::InstallPlugin {
+                        component_name: component.name.clone(),
+                        installation: installation.clone(),
+                    },
+                    None,
+                ));
+            }
+            for worker in &component.extensions.workers {
+                worker_steps.push(PlannedStep::new(
+                    AppResourceKind::Worker,
+                    format!("{}/{}", component.name, worker.name),
+                    AppChange::CreateWorker {
+                        component_name: component.name.clone(),
+                        worker: worker.clone(),
+                    },
+                    None,
+                ));
+            }
+            return Ok(());
+        };
+
+        let urn = ComponentUrn {
+            id: ComponentId(existing.versioned_component_id.component_id),
+        };
+
+        let differences =
+            component_differences(component, &existing, state.components.get(&component.name))?;
+        component_steps.push(if differences.is_empty() {
+            PlannedStep::unchanged(AppResourceKind::Component, &component.name, None)
+        } else {
+            PlannedStep::new(
+                AppResourceKind::Component,
+                &component.name,
+                AppChange::UpdateComponent(urn.clone(), component.clone()),
+                Some(differences.join(", ")),
+            )
+        });
+
+        let installations = self
+            .component_client
+            .get_installations(&urn, existing.versioned_component_id.version)
+            .await?;
+        for installation in &component.extensions.plugins {
+            let name = plugin_step_name(&component.name, installation);
+            let installed = installations.iter().find(|installed| {
+                installed.name == installation.name && installed.version == installation.version
+            });
+            plugin_steps.push(match installed {
+                None => PlannedStep::new(
+                    AppResourceKind::PluginInstallation,
+                    name,
+                    AppChange::InstallPlugin {
+                        component_name: component.name.clone(),
+                        installation: installation.clone(),
+                    },
+                    None,
+                ),
+                Some(installed)
+                    if installed.priority != installation.priority
+                        || installed.parameters != installation.parameters =>
+                {
+                    PlannedStep::new(
+                        AppResourceKind::PluginInstallation,
+                        name,
+                        AppChange::ReinstallPlugin {
+                            component_name: component.name.clone(),
+                            installation_id: installed.id,
+                            installation: installation.clone(),
+                        },
+                        Some("priority or parameters changed".to_string()),
+                    )
+                }
+                Some(_) => PlannedStep::unchanged(AppResourceKind::PluginInstallation, name, None),
+            });
+        }
+        if prune {
+            for installed in &installations {
+                let name = format!(
+                    "{}/{}@{}",
+                    component.name, installed.name, installed.version
+                );
+                let managed = component.extensions.plugins.iter().any(|installation| {
+                    installed.name == installation.name && installed.version == installation.version
+                });
+                if !managed && state.is_created(AppResourceKind::PluginInstallation, &name) {
+                    plugin_steps.push(PlannedStep::new(
+                        AppResourceKind::PluginInstallation,
+                        name,
+                        AppChange::UninstallPlugin {
+                            component_name: component.name.clone(),
+                            installation_id: installed.id,
+                        },
+                        Some("no longer in the manifests".to_string()),
+                    ));
+                }
+            }
+        }
+
+        let workers = self
+            .worker_service
+            .list_worker_metadata(&urn, None, Some(true))
+            .await?;
+        for worker in &component.extensions.workers {
+            let name = format!("{}/{}", component.name, worker.name);
+            let exists = workers
+                .iter()
+                .any(|existing| existing.worker_id.worker_name == worker.name);
+            worker_steps.push(if exists {
+                PlannedStep::unchanged(AppResourceKind::Worker, name, None)
+            } else {
+                PlannedStep::new(
+                    AppResourceKind::Worker,
+                    name,
+                    AppChange::CreateWorker {
+                        component_name: component.name.clone(),
+                        worker: worker.clone(),
+                    },
+                    None,
+                )
+            });
+        }
+        if prune {
+            for existing in &workers {
+                let name = format!("{}/{}", component.name, existing.worker_id.worker_name);
+                let managed = component
+                    .extensions
+                    .workers
+                    .iter()
+                    .any(|worker| worker.name == existing.worker_id.worker_name);
+                if !managed && state.is_created(AppResourceKind::Worker, &name) {
+                    worker_steps.push(PlannedStep::new(
+                        AppResourceKind::Worker,
+                        name,
+                        AppChange::DeleteWorker(WorkerUrn {
+                            id: existing.worker_id.clone().into_target_worker_id(),
+                        }),
+                        Some("no longer in the manifests".to_string()),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn plan_security_scheme(
+        &self,
+        scheme: &HttpApiSecurityScheme,
+        project: &ProjectContext,
+    ) -> Result<PlannedStep, GolemError> {
+        // Security schemes cannot be updated or deleted, so only the missing ones are created
+        let step = match self
+            .api_security_scheme_client
+            .find(&scheme.id, project)
+            .await?
+        {
+            Some(existing)
+                if existing.client_id != scheme.client_id
+                    || existing.redirect_url != scheme.redirect_url
+                    || existing.scopes != scheme.scopes =>
+            {
+                PlannedStep::unchanged(
+                    AppResourceKind::ApiSecurityScheme,
+                    &scheme.id,
+                    Some("differs from the manifests, but cannot be updated".to_string()),
+                )
+            }
+            Some(_) => PlannedStep::unchanged(AppResourceKind::ApiSecurityScheme, &scheme.id, None),
+            None => PlannedStep::new(
+                AppResourceKind::ApiSecurityScheme,
+                &scheme.id,
+                AppChange::CreateSecurityScheme(scheme.clone()),
+                None,
+            ),
+        };
+        Ok(step)
+    }
+
+    /// Checks that the component versions the created or updated API definitions bind to exist,
+    /// as the component constraints of the definitions are recorded against them
+    async fn check_constraints(
+        &self,
+        definitions: &[HttpApiDefinitionRequest],
+    ) -> Result<(), GolemError> {
+        let mut checked = HashSet::new();
+        for definition in definitions {
+            for route in &definition.routes {
+                let Some(component_id) = &route.binding.component_id else {
+                    continue;
+                };
+                if !checked.insert((component_id.component_id, component_id.version)) {
+                    continue;
+                }
+
+                let urn = ComponentUrn {
+                    id: ComponentId(component_id.component_id),
+                };
+                self.component_client
+                    .get_metadata(&urn, component_id.version)
+                    .await
+                    .map_err(|GolemError(err)| {
+                        GolemError(format!(
+                            "API definition {}/{} binds to version {} of component {}, which cannot be resolved: {err}",
+                            definition.id,
+                            definition.version,
+                            component_id.version,
+                            component_id.component_id
+                        ))
+                    })?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn find_component(
+        &self,
+        name: &str,
+        project: &ProjectContext,
+    ) -> Result<Option<Component>, GolemError> {
+        let components = self
+            .component_client
+            .find(
+                Some(ComponentName(name.to_string())),
+                &Some(project.clone()),
+            )
+            .await?;
+        Ok(components
+            .into_iter()
+            .max_by_key(|component| component.versioned_component_id.version))
+    }
+
+    async fn resolve_component(
+        &self,
+        name: &str,
+        project: &ProjectContext,
+    ) -> Result<ComponentUrn, GolemError> {
+        self.component_service
+            .resolve_uri(
+                ComponentUri::URL(ComponentUrl {
+                    name: name.to_string(),
+                }),
+                &Some(project.clone()),
+            )
+            .await
+    }
+
+    /// Applies the change and records the created or removed resources in the deployment state.
+    ///
+    /// Returns the change undoing it, if it can be undone.
+    async fn apply_change(
+        &self,
+        step: &AppPlanStep,
+        change: AppChange,
+        project: &ProjectContext,
+        format: Format,
+        state: &mut AppProjectState,
+    ) -> Result<Option<AppChange>, GolemError> {
+        match change {
+            AppChange::AddComponent(component) => {
+                let wasm_hash = wasm_hash(&component.linked_wasm)?;
+                self.component_service
+                    .add(
+                        ComponentName(component.name.clone()),
+                        PathBufOrStdin::Path(component.linked_wasm),
+                        component.extensions.component_type,
+                        Some(project.clone()),
+                        true,
+                        format,
+                        component.extensions.files,
+                    )
+                    .await?;
+                self.record_component(&component.name, wasm_hash, project, state)
+                    .await?;
+                Ok(None)
+            }
+            AppChange::UpdateComponent(urn, component) => {
+                let wasm_hash = wasm_hash(&component.linked_wasm)?;
+                self.component_service
+                    .update(
+                        ComponentUri::URN(urn),
+                        PathBufOrStdin::Path(component.linked_wasm),
+                        Some(component.extensions.component_type),
+                        Some(project.clone()),
+                        true,
+                        format,
+                        component.extensions.files,
+                    )
+                    .await?;
+                self.record_component(&component.name, wasm_hash, project, state)
+                    .await?;
+                Ok(None)
+            }
+            AppChange::InstallPlugin {
+                component_name,
+                installation,
+            } => {
+                let urn = self.resolve_component(&component_name, project).await?;
+                let installed = self.install_plugin(&urn, installation).await?;
+                state.add_created(step.kind, &step.name);
+                Ok(Some(AppChange::UninstallPlugin {
+                    component_name,
+                    installation_id: installed.id,
+                }))
+            }
+            AppChange::ReinstallPlugin {
+                component_name,
+                installation_id,
+                installation,
+            } => {
+                let urn = self.resolve_component(&component_name, project).await?;
+                self.component_client
+                    .uninstall_plugin(&urn, &installation_id)
+                    .await?;
+                self.install_plugin(&urn, installation).await?;
+                Ok(None)
+            }
+            AppChange::UninstallPlugin {
+                component_name,
+                installation_id,
+            } => {
+                let urn = self.resolve_component(&component_name, project).await?;
+                self.component_client
+                    .uninstall_plugin(&urn, &installation_id)
+                    .await?;
+                state.remove_created(step.kind, &step.name);
+                Ok(None)
+            }
+            AppChange::CreateWorker {
+                component_name,
+                worker,
+            } => {
+                let urn = self.resolve_component(&component_name, project).await?;
+                let worker_urn = WorkerUrn {
+                    id: TargetWorkerId {
+                        component_id: urn.id.clone(),
+                        worker_name: Some(worker.name.clone()),
+                    },
+                };
+                self.worker_service
+                    .add_by_urn(
+                        urn,
+                        WorkerName(worker.name),
+                        worker.env,
+                        vec![],
+                        worker.args,
+                    )
+                    .await?;
+                state.add_created(step.kind, &step.name);
+                Ok(Some(AppChange::DeleteWorker(worker_urn)))
+            }
+            AppChange::DeleteWorker(worker_urn) => {
+                self.worker_service.delete_by_urn(worker_urn).await?;
+                state.remove_created(step.kind, &step.name);
+                Ok(None)
+            }
+            AppChange::CreateSecurityScheme(scheme) => {
+                self.api_security_scheme_client
+                    .create(
+                        scheme.id,
+                        scheme.provider.into(),
+                        scheme.client_id,
+                        scheme.client_secret,
+                        scheme.scopes,
+                        scheme.redirect_url,
+                        project,
+                    )
+                    .await?;
+                Ok(None)
+            }
+            AppChange::CreateApiDefinition(source) => {
+                let created = self
+                    .api_definition_client
+                    .create(PathBufOrStdin::Path(source.path), project, &source.format)
+                    .await?;
+                state.add_created(step.kind, &step.name);
+                Ok(Some(AppChange::DeleteApiDefinition(
+                    ApiDefinitionId(created.id),
+                    ApiDefinitionVersion(created.version),
+                )))
+            }
+            AppChange::UpdateApiDefinition(source) => {
+                self.api_definition_client
+                    .update(PathBufOrStdin::Path(source.path), project, &source.format)
+                    .await?;
+                Ok(None)
+            }
+            AppChange::DeleteApiDefinition(id, version) => {
+                self.api_definition_client
+                    .delete(id, version, project)
+                    .await?;
+                state.remove_created(step.kind, &step.name);
+                Ok(None)
+            }
+            AppChange::Deploy(deployment) => {
+                let site = deployment.site();
+                self.deploy(deployment, project).await?;
+                state.add_created(step.kind, &step.name);
+                Ok(Some(AppChange::DeleteDeployment(site)))
+            }
+            AppChange::Redeploy {
+                deployment,
+                previous,
+            } => {
+                self.deploy(deployment.clone(), project).await?;
+                Ok(Some(AppChange::Redeploy {
+                    deployment: previous,
+                    previous: deployment,
+                }))
+            }
+            AppChange::DeleteDeployment(site) => {
+                self.api_deployment_client.delete(&site).await?;
+                state.remove_created(step.kind, &step.name);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Undoes the applied changes in reverse order, returning a description of the outcome
+    async fn rollback(
+        &self,
+        undo: Vec<(AppPlanStep, AppChange)>,
+        irreversible: Vec<AppPlanStep>,
+        project: &ProjectContext,
+        format: Format,
+        state: &mut AppProjectState,
+    ) -> String {
+        let mut rolled_back = Vec::new();
+        let mut failed = Vec::new();
+        for (step, change) in undo.into_iter().rev() {
+            info!("Rolling back: {} {} {}", step.action, step.kind, step.name);
+            match self
+                .apply_change(&step, change, project, format, state)
+                .await
+            {
+                Ok(_) => rolled_back.push(format!("{} {}", step.kind, step.name)),
+                Err(GolemError(err)) => failed.push(format!("{} {} ({err})", step.kind, step.name)),
+            }
+        }
+
+        let mut result = String::new();
+        if !rolled_back.is_empty() {
+            result.push_str(&format!("; rolled back: {}", rolled_back.join(", ")));
+        }
+        if !failed.is_empty() {
+            result.push_str(&format!("; failed to roll back: {}", failed.join(", ")));
+        }
+        if !irreversible.is_empty() {
+            result.push_str(&format!(
+                "; cannot be rolled back: {}",
+                irreversible
+                    .iter()
+                    .map(|step| format!("{} {} {}", step.action, step.kind, step.name))
+                    .join(", ")
+            ));
+        }
+        result
+    }
+
+    /// Records the latest version of the component, uploaded with the WASM of the given hash
+    async fn record_component(
+        &self,
+        name: &str,
+        wasm_hash: String,
+        project: &ProjectContext,
+        state: &mut AppProjectState,
+    ) -> Result<(), GolemError> {
+        if let Some(component) = self.find_component(name, project).await? {
+            state.components.insert(
+                name.to_string(),
+                AppDeployedComponent {
+                    version: component.versioned_component_id.version,
+                    wasm_hash,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    async fn deploy(
+        &self,
+        deployment: HttpApiDeployment,
+        project: &ProjectContext,
+    ) -> Result<(), GolemError> {
+        self.api_deployment_client
+            .deploy(
+                deployment.definitions,
+                &deployment.host,
+                deployment.subdomain,
+                project,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn install_plugin(
+        &self,
+        urn: &ComponentUrn,
+        installation: PluginInstallation,
+    ) -> Result<golem_client::model::PluginInstallation, GolemError> {
+        self.component_client
+            .install_plugin(
+                urn,
+                &installation.name,
+                &installation.version,
+                installation.priority,
+                installation.parameters,
+            )
+            .await
+    }
+}
+
+#[async_trait]
+impl<ProjectContext: Clone + Display + Send + Sync> AppDeployService
+    for AppDeployServiceLive<ProjectContext>
+{
+    type ProjectContext = ProjectContext;
+
+    async fn plan(
+        &self,
+        app: &DeclarativeApp,
+        project: &Self::ProjectContext,
+        prune: bool,
+    ) -> Result<AppPlan, GolemError> {
+        let steps = self.planned_steps(app, project, prune).await?;
+        Ok(AppPlan {
+            steps: steps.into_iter().map(|step| step.step).collect(),
+        })
+    }
+
+    async fn apply(
+        &self,
+        app: &DeclarativeApp,
+        project: &Self::ProjectContext,
+        prune: bool,
+        non_interactive: bool,
+        format: Format,
+    ) -> Result<GolemResult, GolemError> {
+        let steps = self.planned_steps(app, project, prune).await?;
+        let changes = steps
+            .into_iter()
+            .filter_map(|step| step.change.map(|change| (step.step, change)))
+            .collect::<Vec<_>>();
+
+        if changes.is_empty() {
+            return Ok(GolemResult::Ok(Box::new(AppApplyResult {
+                applied: vec![],
+            })));
+        }
+
+        if format == Format::Text && !non_interactive {
+            let plan = AppPlan {
+                steps: changes.iter().map(|(step, _)| step.clone()).collect(),
+            };
+            GolemResult::Ok(Box::new(plan)).print(format);
+
+            let answer = Confirm::new(&format!(
+                "Do you want to apply the {} changes of the application?",
+                changes.len()
+            ))
+            .with_default(false)
+            .prompt();
+
+            match answer {
+                Ok(true) => debug!("Operation confirmed by the user"),
+                Ok(false) => return Ok(GolemResult::Str("Operation canceled by the user".to_string())),
+                Err(error) => return Err(GolemError(format!("Error while asking for confirmation: {}; Use the --non-interactive (-y) flag to bypass it.", error))),
+            }
+        } else if !non_interactive {
+            return Err(GolemError(
+                "Pass the --non-interactive (-y) flag or use text format for manual confirmation"
+                    .to_string(),
+            ));
+        }
+
+        let mut state = load_state(&app.state_path)?;
+        let project_state = state.projects.entry(project.to_string()).or_default();
+
+        let mut applied = Vec::new();
+        let mut undo = Vec::new();
+        let mut irreversible = Vec::new();
+        let mut failure = None;
+        for (step, change) in changes {
+            info!("Applying: {} {} {}", step.action, step.kind, step.name);
+            match self
+                .apply_change(&step, change, project, format, project_state)
+                .await
+            {
+                Ok(Some(undo_change)) => undo.push((step.clone(), undo_change)),
+                Ok(None) => irreversible.push(step.clone()),
+                Err(GolemError(err)) => {
+                    failure = Some(format!(
+                        "Failed to {} {} {} after applying {} change(s): {err}",
+                        step.action,
+                        step.kind,
+                        step.name,
+                        applied.len()
+                    ));
+                    break;
+                }
+            }
+            applied.push(step);
+        }
+
+        if let Some(failure) = failure {
+            let rollback = self
+                .rollback(undo, irreversible, project, format, project_state)
+                .await;
+            save_state(&app.state_path, &state)?;
+            return Err(GolemError(format!("{failure}{rollback}")));
+        }
+
+        save_state(&app.state_path, &state)?;
+        Ok(GolemResult::Ok(Box::new(AppApplyResult { applied })))
+    }
+}
+
+fn plugin_step_name(component_name: &str, installation: &PluginInstallation) -> String {
+    format!(
+        "{}/{}@{}",
+        component_name, installation.name, installation.version
+    )
+}
+
+/// Describes how the deployed version of a component differs from the built one.
+///
+/// The WASM is compared by the hash recorded when `app apply` uploaded the deployed version. If the
+/// deployed version was uploaded by other means, only a size difference is conclusive, so a
+/// component of the same size is still updated.
+fn component_differences(
+    component: &DeclarativeComponent,
+    existing: &Component,
+    deployed: Option<&AppDeployedComponent>,
+) -> Result<Vec<String>, GolemError> {
+    let wasm_size = std::fs::metadata(&component.linked_wasm)
+        .map_err(|err| {
+            GolemError(format!(
+                "Component {} is not built, failed to read {}: {err}",
+                component.name,
+                component.linked_wasm.display()
+            ))
+        })?
+        .len();
+
+    let mut differences = Vec::new();
+    match deployed {
+        Some(deployed) if deployed.version == existing.versioned_component_id.version => {
+            if wasm_hash(&component.linked_wasm)? != deployed.wasm_hash {
+                differences.push("WASM changed".to_string());
+            }
+        }
+        _ if wasm_size != existing.component_size => {
+            differences.push("WASM changed".to_string());
+        }
+        _ => differences.push("WASM of the deployed version is unknown".to_string()),
+    }
+    if component.extensions.component_type != existing.component_type {
+        differences.push(format!(
+            "component type changed to {}",
+            component.extensions.component_type
+        ));
+    }
+
+    let desired_files = component
+        .extensions
+        .files
+        .iter()
+        .map(|file| (file.target.path.to_string(), file.target.permissions))
+        .sorted_by(|a, b| a.0.cmp(&b.0))
+        .collect::<Vec<_>>();
+    let existing_files = existing
+        .files
+        .iter()
+        .map(|file| (file.path.to_string(), file.permissions))
+        .sorted_by(|a, b| a.0.cmp(&b.0))
+        .collect::<Vec<_>>();
+    if desired_files != existing_files {
+        differences.push("initial files changed".to_string());
+    }
+
+    Ok(differences)
+}
+
+/// The hex encoded SHA-256 hash of the component's WASM
+fn wasm_hash(path: &Path) -> Result<String, GolemError> {
+    let wasm = std::fs::read(path).map_err(|err| {
+        GolemError(format!(
+            "Failed to read component WASM {}: {err}",
+            path.display()
+        ))
+    })?;
+    Ok(hex::encode(Sha256::digest(&wasm)))
+}
+
+fn load_state(path: &Path) -> Result<AppDeployState, GolemError> {
+    if !path.exists() {
+        return Ok(AppDeployState::default());
+    }
+    let content = std::fs::read_to_string(path).map_err(|err| {
+        GolemError(format!(
+            "Failed to read application deployment state {}: {err}",
+            path.display()
+        ))
+    })?;
+    serde_json::from_str(&content).map_err(|err| {
+        GolemError(format!(
+            "Failed to parse application deployment state {}: {err}",
+            path.display()
+        ))
+    })
+}
+
+fn save_state(path: &Path, state: &AppDeployState) -> Result<(), GolemError> {
+    let content = serde_json::to_string_pretty(state).map_err(|err| {
+        GolemError(format!(
+            "Failed to serialize application deployment state: {err}"
+        ))
+    })?;
+    std::fs::write(path, content).map_err(|err| {
+        GolemError(format!(
+            "Failed to write application deployment state {}: {err}",
+            path.display()
+        ))
+    })
+}
+
+async fn read_api_definition(
+    source: &HttpApiDefinitionSource,
+) -> Result<HttpApiDefinitionRequest, GolemError> {
+    let content = tokio::fs::read_to_string(&source.path)
+        .await
+        .map_err(|err| {
+            GolemError(format!(
+                "Failed to read API definition {}: {err}",
+                source.path.display()
+            ))
+        })?;
+    decode_api_definition(&content, &source.format)
+}
@@ -22,6 +22,8 @@ use async_trait::async_trait;
 use async_zip::base::write::ZipFileWriter;
 use async_zip::{Compression, ZipEntryBuilder};
 use golem_client::model::ComponentType;
+use golem_common::model::component_metadata::{CapabilityPolicy, OplogPolicy};
+use golem_common::model::secret::SecretReference;
 use golem_common::model::{
     ComponentFilePath, ComponentFilePathWithPermissions, ComponentFilePathWithPermissionsList,
 };
@@ -129,6 +131,29 @@ pub trait ComponentService {
         project: Option<Self::ProjectContext>,
         installation_id: &PluginInstallationId,
     ) -> Result<GolemResult, GolemError>;
+
+    async fn set_oplog_policy(
+        &self,
+        component_uri: ComponentUri,
+        project: Option<Self::ProjectContext>,
+        policy: OplogPolicy,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn set_capability_policy(
+        &self,
+        component_uri: ComponentUri,
+        project: Option<Self::ProjectContext>,
+        policy: CapabilityPolicy,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn set_secrets(
+        &self,
+        component_uri: ComponentUri,
+        project: Option<Self::ProjectContext>,
+        secrets: Vec<SecretReference>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn gc_report(&self) -> Result<GolemResult, GolemError>;
 }
 
 pub struct ComponentServiceLive<ProjectContext> {
@@ -562,6 +587,50 @@ impl<ProjectContext: Display + Send + Sync> ComponentService
             .await?;
         Ok(GolemResult::Str("Plugin uninstalled".to_string()))
     }
+
+    async fn set_oplog_policy(
+        &self,
+        component_uri: ComponentUri,
+        project: Option<Self::ProjectContext>,
+        policy: OplogPolicy,
+    ) -> Result<GolemResult, GolemError> {
+        let urn = self.resolve_uri(component_uri, &project).await?;
+        let component = self.client.set_oplog_policy(&urn, policy).await?;
+        Ok(GolemResult::Str(format!(
+            "Oplog policy set in version {}",
+            component.versioned_component_id.version
+        )))
+    }
+
+    async fn set_capability_policy(
+        &self,
+        component_uri: ComponentUri,
+        project: Option<Self::ProjectContext>,
+        policy: CapabilityPolicy,
+    ) -> Result<GolemResult, GolemError> {
+        let urn = self.resolve_uri(component_uri, &project).await?;
+        let component = self.client.set_capability_policy(&urn, policy).await?;
+        Ok(GolemResult::Str(format!(
+            "Capability policy set in version {}",
+            component.versioned_component_id.version
+        )))
+    }
+
+    async fn set_secrets(
+        &self,
+        component_uri: ComponentUri,
+        project: Option<Self::ProjectContext>,
+        secrets: Vec<SecretReference>,
+    ) -> Result<GolemResult, GolemError> {
+        let urn = self.resolve_uri(component_uri, &project).await?;
+        self.client.set_secrets(&urn, secrets).await?;
+        Ok(GolemResult::Str("Secrets updated".to_string()))
+    }
+
+    async fn gc_report(&self) -> Result<GolemResult, GolemError> {
+        let report = self.client.gc_report().await?;
+        Ok(GolemResult::Ok(Box::new(report)))
+    }
 }
 
 #[derive(Debug, Clone)]
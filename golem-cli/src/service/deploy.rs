@@ -148,6 +148,11 @@ impl<ProjectContext: Display + Send + Sync> DeployService for DeployServiceLive<
                         .iter()
                         .map(|(k, v)| (k.clone(), v.clone()))
                         .collect::<Vec<_>>(),
+                    worker
+                        .labels
+                        .iter()
+                        .map(|(k, v)| (k.clone(), v.clone()))
+                        .collect::<Vec<_>>(),
                     worker.args.clone(),
                 )
                 .await?;
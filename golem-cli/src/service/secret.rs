@@ -0,0 +1,73 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::clients::secret::SecretClient;
+use crate::model::{GolemError, GolemResult};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait SecretService {
+    type ProjectContext;
+
+    async fn list(&self, project: &Self::ProjectContext) -> Result<GolemResult, GolemError>;
+
+    async fn create(
+        &self,
+        name: String,
+        value: String,
+        project: &Self::ProjectContext,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn delete(
+        &self,
+        name: String,
+        project: &Self::ProjectContext,
+    ) -> Result<GolemResult, GolemError>;
+}
+
+pub struct SecretServiceLive<ProjectContext> {
+    pub client: Box<dyn SecretClient<ProjectContext = ProjectContext> + Send + Sync>,
+}
+
+#[async_trait]
+impl<ProjectContext: Send + Sync> SecretService for SecretServiceLive<ProjectContext> {
+    type ProjectContext = ProjectContext;
+
+    async fn list(&self, project: &Self::ProjectContext) -> Result<GolemResult, GolemError> {
+        let secrets = self.client.list(project).await?;
+
+        Ok(GolemResult::Ok(Box::new(secrets)))
+    }
+
+    async fn create(
+        &self,
+        name: String,
+        value: String,
+        project: &Self::ProjectContext,
+    ) -> Result<GolemResult, GolemError> {
+        let secret = self.client.create(name, value, project).await?;
+
+        Ok(GolemResult::Ok(Box::new(secret)))
+    }
+
+    async fn delete(
+        &self,
+        name: String,
+        project: &Self::ProjectContext,
+    ) -> Result<GolemResult, GolemError> {
+        self.client.delete(&name, project).await?;
+
+        Ok(GolemResult::Str("Secret deleted".to_string()))
+    }
+}
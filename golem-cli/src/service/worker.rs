@@ -17,12 +17,14 @@ use crate::command::worker::WorkerConnectOptions;
 use crate::model::component::{
     format_function_name, function_params_types, show_exported_function, Component,
 };
-use crate::model::deploy::TryUpdateAllWorkersResult;
+use crate::model::deploy::{
+    TryUpdateAllWorkersDryRunResult, TryUpdateAllWorkersResult, WorkerUpdateDryRunResult,
+};
 use crate::model::invoke_result_view::InvokeResultView;
 use crate::model::text::worker::{WorkerAddView, WorkerGetView};
 use crate::model::{
-    Format, GolemError, GolemResult, IdempotencyKey, WorkerMetadata, WorkerName, WorkerUpdateMode,
-    WorkersMetadataResponseView,
+    FailedWorkersView, Format, GolemError, GolemResult, IdempotencyKey, WorkerMetadata, WorkerName,
+    WorkerUpdateMode, WorkersMetadataResponseView,
 };
 use crate::service::component::ComponentService;
 use async_trait::async_trait;
@@ -37,6 +39,7 @@ use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::type_annotated_value_from_str;
 use itertools::Itertools;
 use serde_json::Value;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::task::JoinHandle;
 use tracing::{error, info, Instrument};
@@ -51,6 +54,7 @@ pub trait WorkerService {
         component_uri: ComponentUri,
         worker_name: WorkerName,
         env: Vec<(String, String)>,
+        labels: Vec<(String, String)>,
         args: Vec<String>,
         project: Option<Self::ProjectContext>,
     ) -> Result<GolemResult, GolemError>;
@@ -60,6 +64,7 @@ pub trait WorkerService {
         component_urn: ComponentUrn,
         worker_name: WorkerName,
         env: Vec<(String, String)>,
+        labels: Vec<(String, String)>,
         args: Vec<String>,
     ) -> Result<GolemResult, GolemError>;
 
@@ -129,6 +134,35 @@ pub trait WorkerService {
 
     async fn delete_by_urn(&self, worker_urn: WorkerUrn) -> Result<GolemResult, GolemError>;
 
+    async fn update_labels(
+        &self,
+        worker_uri: WorkerUri,
+        labels: Vec<(String, String)>,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn put_file(
+        &self,
+        worker_uri: WorkerUri,
+        path: String,
+        source: PathBuf,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn delete_file(
+        &self,
+        worker_uri: WorkerUri,
+        path: String,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn make_directory(
+        &self,
+        worker_uri: WorkerUri,
+        path: String,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
     async fn get(
         &self,
         worker_uri: WorkerUri,
@@ -152,6 +186,12 @@ pub trait WorkerService {
         project: Option<Self::ProjectContext>,
     ) -> Result<GolemResult, GolemError>;
 
+    async fn list_failed(
+        &self,
+        component_uri: ComponentUri,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
     async fn update(
         &self,
         worker_uri: WorkerUri,
@@ -184,6 +224,21 @@ pub trait WorkerService {
         mode: WorkerUpdateMode,
     ) -> Result<GolemResult, GolemError>;
 
+    async fn dry_run_update(
+        &self,
+        worker_uri: WorkerUri,
+        target_version: u64,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn dry_run_update_many(
+        &self,
+        component_uri: ComponentUri,
+        filter: Option<Vec<String>>,
+        target_version: u64,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
     async fn list_worker_metadata(
         &self,
         component_urn: &ComponentUrn,
@@ -419,11 +474,13 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
         component_uri: ComponentUri,
         worker_name: WorkerName,
         env: Vec<(String, String)>,
+        labels: Vec<(String, String)>,
         args: Vec<String>,
         project: Option<Self::ProjectContext>,
     ) -> Result<GolemResult, GolemError> {
         let component_urn = self.components.resolve_uri(component_uri, &project).await?;
-        self.add_by_urn(component_urn, worker_name, env, args).await
+        self.add_by_urn(component_urn, worker_name, env, labels, args)
+            .await
     }
 
     async fn add_by_urn(
@@ -431,11 +488,12 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
         component_urn: ComponentUrn,
         worker_name: WorkerName,
         env: Vec<(String, String)>,
+        labels: Vec<(String, String)>,
         args: Vec<String>,
     ) -> Result<GolemResult, GolemError> {
         let worker_id = self
             .client
-            .new_worker(worker_name, component_urn, args, env)
+            .new_worker(worker_name, component_urn, args, env, labels)
             .await?;
 
         Ok(GolemResult::Ok(Box::new(WorkerAddView(WorkerUrn {
@@ -518,7 +576,11 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
             .invoke_and_await(
                 worker_urn.clone(),
                 function.clone(),
-                InvokeParameters { params: parameters },
+                InvokeParameters {
+                    params: parameters,
+                    retry_policy: None,
+                    timeout: None,
+                },
                 idempotency_key,
             )
             .await?;
@@ -567,7 +629,11 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
             .invoke(
                 worker_urn,
                 function,
-                InvokeParameters { params: parameters },
+                InvokeParameters {
+                    params: parameters,
+                    retry_policy: None,
+                    timeout: None,
+                },
                 idempotency_key,
             )
             .await?;
@@ -642,6 +708,59 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
         Ok(GolemResult::Str("Deleted".to_string()))
     }
 
+    async fn update_labels(
+        &self,
+        worker_uri: WorkerUri,
+        labels: Vec<(String, String)>,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client.update_labels(worker_urn, labels).await?;
+
+        Ok(GolemResult::Str("Labels updated".to_string()))
+    }
+
+    async fn put_file(
+        &self,
+        worker_uri: WorkerUri,
+        path: String,
+        source: PathBuf,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client.put_file(worker_urn, path, &source).await?;
+
+        Ok(GolemResult::Str("File written".to_string()))
+    }
+
+    async fn delete_file(
+        &self,
+        worker_uri: WorkerUri,
+        path: String,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client.delete_file(worker_urn, path).await?;
+
+        Ok(GolemResult::Str("Deleted".to_string()))
+    }
+
+    async fn make_directory(
+        &self,
+        worker_uri: WorkerUri,
+        path: String,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client.make_directory(worker_urn, path).await?;
+
+        Ok(GolemResult::Str("Directory created".to_string()))
+    }
+
     async fn get(
         &self,
         worker_uri: WorkerUri,
@@ -732,6 +851,23 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
         }
     }
 
+    async fn list_failed(
+        &self,
+        component_uri: ComponentUri,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let component_urn = self.components.resolve_uri(component_uri, &project).await?;
+        let workers = self
+            .list_worker_metadata(
+                &component_urn,
+                Some(vec!["status = Failed".to_string()]),
+                Some(true),
+            )
+            .await?;
+
+        Ok(GolemResult::Ok(Box::new(FailedWorkersView::new(workers))))
+    }
+
     async fn update(
         &self,
         worker_uri: WorkerUri,
@@ -806,6 +942,62 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
         })))
     }
 
+    async fn dry_run_update(
+        &self,
+        worker_uri: WorkerUri,
+        target_version: u64,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+        let result = self
+            .client
+            .dry_run_update(worker_urn.clone(), target_version)
+            .await?;
+
+        Ok(GolemResult::Ok(Box::new(WorkerUpdateDryRunResult::new(
+            worker_urn, result,
+        ))))
+    }
+
+    async fn dry_run_update_many(
+        &self,
+        component_uri: ComponentUri,
+        filter: Option<Vec<String>>,
+        target_version: u64,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let component_urn = self.components.resolve_uri(component_uri, &project).await?;
+        let known_workers = self
+            .list_worker_metadata(&component_urn, filter, Some(true))
+            .await?;
+
+        let to_update = known_workers
+            .into_iter()
+            .filter(|worker| worker.component_version < target_version)
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::new();
+        let mut failed = Vec::new();
+        for worker in to_update {
+            let worker_urn = WorkerUrn {
+                id: worker.worker_id.clone().into_target_worker_id(),
+            };
+            match self
+                .client
+                .dry_run_update(worker_urn.clone(), target_version)
+                .await
+            {
+                Ok(result) => results.push(WorkerUpdateDryRunResult::new(worker_urn, result)),
+                Err(_) => failed.push(worker_urn),
+            }
+        }
+
+        Ok(GolemResult::Ok(Box::new(TryUpdateAllWorkersDryRunResult {
+            results,
+            failed,
+        })))
+    }
+
     async fn list_worker_metadata(
         &self,
         component_urn: &ComponentUrn,
@@ -236,6 +236,10 @@ fn golem_def_with_response(
                 worker_name: Some("\"foo\"".to_string()),
                 idempotency_key: None,
                 response: Some(response),
+                retry_policy: None,
+                invocation_timeout: None,
+                cache_key: None,
+                cache_ttl: None,
                 allow_origin: None,
                 allow_methods: None,
                 allow_headers: None,
@@ -425,6 +429,11 @@ pub fn to_api_definition_with_type_info(
                         binding_type: Some(GatewayBindingType::Default),
                         cors_preflight: None,
                         response_mapping_output: Some(expected_out.clone()),
+                        retry_policy: None,
+                        invocation_timeout: None,
+                        cache_key: None,
+                        cache_key_input: None,
+                        cache_ttl: None,
                     },
                 }
             })
@@ -0,0 +1,258 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::cli::{Cli, CliLive};
+use crate::Tracing;
+use assert2::assert;
+use golem_cli::model::app_plan::{
+    AppApplyResult, AppDeployState, AppPlan, AppPlanAction, AppResourceKind,
+};
+use golem_cli::model::WorkersMetadataResponseView;
+use golem_test_framework::config::{EnvBasedTestDependencies, TestDependencies};
+use itertools::Itertools;
+use std::path::Path;
+use std::sync::Arc;
+use test_r::core::{DynamicTestRegistration, TestType};
+use test_r::{add_test, inherit_test_dep, test_dep, test_gen};
+
+inherit_test_dep!(EnvBasedTestDependencies);
+inherit_test_dep!(Tracing);
+
+#[test_dep]
+fn cli(deps: &EnvBasedTestDependencies) -> CliLive {
+    CliLive::make("app", Arc::new(deps.clone())).unwrap()
+}
+
+#[test_gen]
+fn generated(r: &mut DynamicTestRegistration) {
+    make(r, "_short", "short", true);
+    make(r, "_long", "long", false);
+}
+
+fn make(r: &mut DynamicTestRegistration, suffix: &'static str, name: &'static str, short: bool) {
+    add_test!(
+        r,
+        format!("app_plan_and_apply{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            app_plan_and_apply((deps, name.to_string(), cli.with_args(short)))
+        }
+    );
+    add_test!(
+        r,
+        format!("app_prune_only_created_resources{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            app_prune_only_created_resources((deps, name.to_string(), cli.with_args(short)))
+        }
+    );
+    add_test!(
+        r,
+        format!("app_apply_rollback{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            app_apply_rollback((deps, name.to_string(), cli.with_args(short)))
+        }
+    );
+}
+
+fn app_plan_and_apply(
+    (deps, name, cli): (&EnvBasedTestDependencies, String, CliLive),
+) -> Result<(), anyhow::Error> {
+    let golem_yaml = deps
+        .component_directory()
+        .join(format!("cli-app-deploy/{name}/golem.yaml"));
+    let state_dir = tempfile::tempdir()?;
+    let state_file = state_dir.path().join("golem-app-state.json");
+    let cfg = &cli.config;
+
+    let plan: AppPlan = serde_json::from_value(cli.run_json(&[
+        "app",
+        "plan",
+        &cfg.arg('a', "app"),
+        golem_yaml.to_str().unwrap(),
+        "--state-file",
+        state_file.to_str().unwrap(),
+    ])?)?;
+    let planned = plan
+        .steps
+        .iter()
+        .map(|step| (step.kind, step.action))
+        .collect::<Vec<_>>();
+    assert!(
+        planned
+            == vec![
+                (AppResourceKind::Component, AppPlanAction::Create),
+                (AppResourceKind::Worker, AppPlanAction::Create),
+                (AppResourceKind::Worker, AppPlanAction::Create),
+            ]
+    );
+
+    let result: AppApplyResult = serde_json::from_value(cli.run_json(&[
+        "app",
+        "apply",
+        &cfg.arg('a', "app"),
+        golem_yaml.to_str().unwrap(),
+        "--state-file",
+        state_file.to_str().unwrap(),
+        &cfg.arg('y', "non-interactive"),
+    ])?)?;
+    assert!(result.applied == plan.steps);
+
+    let plan: AppPlan = serde_json::from_value(cli.run_json(&[
+        "app",
+        "plan",
+        &cfg.arg('a', "app"),
+        golem_yaml.to_str().unwrap(),
+        "--state-file",
+        state_file.to_str().unwrap(),
+    ])?)?;
+    assert!(plan.steps.len() == 3);
+    assert!(!plan.has_changes(), "{plan:?}");
+
+    Ok(())
+}
+
+fn app_prune_only_created_resources(
+    (deps, name, cli): (&EnvBasedTestDependencies, String, CliLive),
+) -> Result<(), anyhow::Error> {
+    let app_dir = deps
+        .component_directory()
+        .join(format!("cli-app-prune/{name}"));
+    let golem_yaml = app_dir.join("golem.yaml");
+    let pruned_yaml = app_dir.join("golem-pruned.yaml");
+    let state_dir = tempfile::tempdir()?;
+    let state_file = state_dir.path().join("golem-app-state.json");
+    let component_name = format!("app_prune_CLI_{name}");
+    let cfg = &cli.config;
+
+    let _: AppApplyResult = serde_json::from_value(cli.run_json(&[
+        "app",
+        "apply",
+        &cfg.arg('a', "app"),
+        golem_yaml.to_str().unwrap(),
+        "--state-file",
+        state_file.to_str().unwrap(),
+        &cfg.arg('y', "non-interactive"),
+    ])?)?;
+
+    // A worker of the same component, not created by the application deployment
+    cli.run_unit(&[
+        "worker",
+        "add",
+        &cfg.arg('w', "worker-name"),
+        "manual-worker",
+        &cfg.arg('c', "component-name"),
+        &component_name,
+    ])?;
+
+    let plan: AppPlan = serde_json::from_value(cli.run_json(&[
+        "app",
+        "plan",
+        &cfg.arg('a', "app"),
+        pruned_yaml.to_str().unwrap(),
+        "--state-file",
+        state_file.to_str().unwrap(),
+        "--prune",
+    ])?)?;
+    let changes = plan
+        .changes()
+        .map(|step| (step.kind, step.action, step.name.clone()))
+        .collect::<Vec<_>>();
+    assert!(
+        changes
+            == vec![(
+                AppResourceKind::Worker,
+                AppPlanAction::Delete,
+                format!("{component_name}/worker-2")
+            )]
+    );
+
+    let _: AppApplyResult = serde_json::from_value(cli.run_json(&[
+        "app",
+        "apply",
+        &cfg.arg('a', "app"),
+        pruned_yaml.to_str().unwrap(),
+        "--state-file",
+        state_file.to_str().unwrap(),
+        "--prune",
+        &cfg.arg('y', "non-interactive"),
+    ])?)?;
+
+    let worker_names = worker_names(&cli, &component_name)?;
+    assert!(worker_names == vec!["manual-worker".to_string(), "worker-1".to_string()]);
+
+    let state = read_state(&state_file)?;
+    let project_state = state.projects.values().next().unwrap();
+    assert!(project_state.workers.len() == 1);
+    assert!(project_state.components.contains_key(&component_name));
+
+    Ok(())
+}
+
+fn app_apply_rollback(
+    (deps, name, cli): (&EnvBasedTestDependencies, String, CliLive),
+) -> Result<(), anyhow::Error> {
+    let golem_yaml = deps
+        .component_directory()
+        .join(format!("cli-app-rollback/{name}/golem.yaml"));
+    let state_dir = tempfile::tempdir()?;
+    let state_file = state_dir.path().join("golem-app-state.json");
+    let component_name = format!("app_apply_rollback_CLI_{name}");
+    let cfg = &cli.config;
+
+    // The API definition is invalid, so applying it fails after the worker was created
+    let result = cli.run_json(&[
+        "app",
+        "apply",
+        &cfg.arg('a', "app"),
+        golem_yaml.to_str().unwrap(),
+        "--state-file",
+        state_file.to_str().unwrap(),
+        &cfg.arg('y', "non-interactive"),
+    ]);
+    assert!(result.is_err());
+
+    assert!(worker_names(&cli, &component_name)?.is_empty());
+
+    let state = read_state(&state_file)?;
+    let project_state = state.projects.values().next().unwrap();
+    assert!(project_state.workers.is_empty());
+    assert!(project_state.api_definitions.is_empty());
+    assert!(project_state.components.contains_key(&component_name));
+
+    Ok(())
+}
+
+fn worker_names(cli: &CliLive, component_name: &str) -> Result<Vec<String>, anyhow::Error> {
+    let cfg = &cli.config;
+    let result: WorkersMetadataResponseView = cli.run(&[
+        "worker",
+        "list",
+        &cfg.arg('c', "component-name"),
+        component_name,
+        "--precise",
+        "true",
+    ])?;
+    Ok(result
+        .workers
+        .into_iter()
+        .filter_map(|worker| worker.worker_urn.id.worker_name)
+        .sorted()
+        .collect())
+}
+
+fn read_state(path: &Path) -> Result<AppDeployState, anyhow::Error> {
+    Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+}
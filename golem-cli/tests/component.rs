@@ -118,6 +118,14 @@ fn make(r: &mut DynamicTestRegistration, suffix: &'static str, name: &'static st
             component_update_from_project_file((deps, name.to_string(), cli.with_args(short)))
         }
     );
+    add_test!(
+        r,
+        format!("component_set_secrets{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            component_set_secrets((deps, name.to_string(), cli.with_args(short)))
+        }
+    );
 }
 
 fn component_add_and_find_all(
@@ -385,3 +393,60 @@ fn component_add_and_get_url(
     assert_eq!(res, component, "{res:?} = ({component:?})");
     Ok(())
 }
+
+fn component_set_secrets(
+    (deps, name, cli): (&EnvBasedTestDependencies, String, CliLive),
+) -> Result<(), anyhow::Error> {
+    let component_name = format!("{name} component set secrets");
+    let secret_name = format!("{name}_api_key");
+    let env_service = deps.component_directory().join("environment-service.wasm");
+    let cfg = &cli.config;
+    let component: ComponentView = cli.run_trimmed(&[
+        "component",
+        "add",
+        &cfg.arg('c', "component-name"),
+        &component_name,
+        env_service.to_str().unwrap(),
+    ])?;
+
+    let secret = cli.run_json(&["secret", "create", &secret_name, "--value", "s3cr3t"])?;
+    assert!(secret["name"] == secret_name.as_str());
+    assert!(secret.get("value").is_none());
+
+    let secrets = cli.run_json(&["secret", "list"])?;
+    let listed = secrets
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|secret| secret["name"] == secret_name.as_str());
+    assert!(listed, "{secrets:?}");
+    assert!(!secrets.to_string().contains("s3cr3t"));
+
+    cli.run_unit(&[
+        "component",
+        "set-secrets",
+        &cfg.arg('C', "component"),
+        &component.component_urn.to_string(),
+        "--secret",
+        &format!("API_KEY={secret_name}"),
+    ])?;
+    let res: ComponentView = cli.run_trimmed(&[
+        "component",
+        "get",
+        &cfg.arg('C', "component"),
+        &component.component_urn.to_string(),
+    ])?;
+    assert!(res.secrets.len() == 1);
+    assert!(res.secrets[0].name == secret_name);
+    assert!(res.secrets[0].env_var == "API_KEY");
+
+    cli.run_unit(&["secret", "delete", &secret_name])?;
+    let secrets = cli.run_json(&["secret", "list"])?;
+    let listed = secrets
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|secret| secret["name"] == secret_name.as_str());
+    assert!(!listed, "{secrets:?}");
+    Ok(())
+}
@@ -27,6 +27,7 @@ pub mod cli;
 mod api_definition;
 mod api_deployment;
 mod api_deployment_fileserver;
+mod app;
 mod component;
 mod get;
 mod profile;
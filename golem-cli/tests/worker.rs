@@ -162,6 +162,22 @@ fn make(
             worker_list((deps, name.to_string(), cli.with_args(short), ref_kind))
         }
     );
+    add_test!(
+        r,
+        format!("worker_labels{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            worker_labels((deps, name.to_string(), cli.with_args(short), ref_kind))
+        }
+    );
+    add_test!(
+        r,
+        format!("worker_files{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            worker_files((deps, name.to_string(), cli.with_args(short), ref_kind))
+        }
+    );
     add_test!(
         r,
         format!("worker_update{suffix}"),
@@ -876,6 +892,105 @@ fn worker_simulated_crash(
     Ok(())
 }
 
+fn worker_labels(
+    (deps, name, cli, ref_kind): (
+        &(impl TestDependencies + Send + Sync + 'static),
+        String,
+        CliLive,
+        RefKind,
+    ),
+) -> anyhow::Result<()> {
+    let component =
+        add_environment_service_component(deps, &format!("{name} worker_labels"), &cli)?;
+    let cfg = &cli.config;
+    let worker_name = format!("{name}_worker_labels");
+
+    let _: WorkerUrn = cli.run(&[
+        "worker",
+        "add",
+        &cfg.arg('w', "worker-name"),
+        &worker_name,
+        &cfg.arg('l', "label"),
+        "team=payments",
+        &component_ref_key(cfg, ref_kind),
+        &component_ref_value(&component, ref_kind),
+    ])?;
+
+    let list_by_team = |team: &str| -> anyhow::Result<WorkersMetadataResponseView> {
+        cli.run_trimmed(&[
+            "worker",
+            "list",
+            &component_ref_key(cfg, ref_kind),
+            &component_ref_value(&component, ref_kind),
+            &cfg.arg('f', "filter"),
+            format!("name = {worker_name}").as_str(),
+            &cfg.arg('f', "filter"),
+            format!("label.team = {team}").as_str(),
+        ])
+    };
+
+    assert_eq!(list_by_team("payments")?.workers.len(), 1);
+
+    let mut cli_args = vec!["worker".to_owned(), "update-labels".to_owned()];
+    cli_args.append(&mut worker_ref(cfg, ref_kind, &component, &worker_name));
+    cli_args.push(cfg.arg('l', "label"));
+    cli_args.push("team=billing".to_owned());
+    cli.run_unit(&cli_args)?;
+
+    assert_eq!(list_by_team("payments")?.workers.len(), 0);
+    assert_eq!(list_by_team("billing")?.workers.len(), 1);
+    Ok(())
+}
+
+fn worker_files(
+    (deps, name, cli, ref_kind): (
+        &(impl TestDependencies + Send + Sync + 'static),
+        String,
+        CliLive,
+        RefKind,
+    ),
+) -> anyhow::Result<()> {
+    let component = add_environment_service_component(deps, &format!("{name} worker_files"), &cli)?;
+    let cfg = &cli.config;
+    let worker_name = format!("{name}_worker_files");
+
+    let _: WorkerUrn = cli.run(&[
+        "worker",
+        "add",
+        &cfg.arg('w', "worker-name"),
+        &worker_name,
+        &component_ref_key(cfg, ref_kind),
+        &component_ref_value(&component, ref_kind),
+    ])?;
+
+    let source = tempfile::NamedTempFile::new()?;
+    std::fs::write(source.path(), "hello")?;
+
+    let files_cmd = |subcommand: &str, path: &str| -> Vec<String> {
+        let mut cli_args = vec![
+            "worker".to_owned(),
+            "files".to_owned(),
+            subcommand.to_owned(),
+        ];
+        cli_args.append(&mut worker_ref(cfg, ref_kind, &component, &worker_name));
+        cli_args.push(cfg.arg('p', "path"));
+        cli_args.push(path.to_owned());
+        cli_args
+    };
+
+    let mut put_args = files_cmd("put", "/data/hello.txt");
+    put_args.push(cfg.arg('s', "source"));
+    put_args.push(source.path().to_string_lossy().to_string());
+    cli.run_unit(&put_args)?;
+
+    cli.run_unit(&files_cmd("mkdir", "/data/empty"))?;
+    assert!(cli.run_unit(&files_cmd("mkdir", "/data/empty")).is_err());
+
+    cli.run_unit(&files_cmd("rm", "/data/hello.txt"))?;
+    assert!(cli.run_unit(&files_cmd("rm", "/data/hello.txt")).is_err());
+    Ok(())
+}
+
 fn worker_list(
     (deps, name, cli, ref_kind): (
         &(impl TestDependencies + Send + Sync + 'static),
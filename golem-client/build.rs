@@ -46,6 +46,10 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
         &[
             ("AnalysedExport", "golem_wasm_ast::analysis::AnalysedExport"),
             ("AnalysedType", "golem_wasm_ast::analysis::AnalysedType"),
+            (
+                "CapabilityPolicy",
+                "golem_common::model::component_metadata::CapabilityPolicy",
+            ),
             (
                 "ComponentMetadata",
                 "golem_common::model::component_metadata::ComponentMetadata",
@@ -55,22 +59,62 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
                 "golem_common::model::ComponentFilePathWithPermissionsList",
             ),
             ("ComponentType", "golem_common::model::ComponentType"),
+            (
+                "ComponentVolume",
+                "golem_common::model::component_metadata::ComponentVolume",
+            ),
             ("DefaultComponentOwner", "crate::DefaultComponentOwner"),
             (
                 "DefaultPluginScope",
                 "golem_common::model::plugin::DefaultPluginScope",
             ),
             ("DefaultPluginOwner", "crate::DefaultPluginOwner"),
+            (
+                "HostCapability",
+                "golem_common::model::component_metadata::HostCapability",
+            ),
             (
                 "OplogCursor",
                 "golem_common::model::public_oplog::OplogCursor",
             ),
+            (
+                "OplogPolicy",
+                "golem_common::model::component_metadata::OplogPolicy",
+            ),
+            (
+                "EgressDestination",
+                "golem_common::model::component_metadata::EgressDestination",
+            ),
+            (
+                "EgressHeader",
+                "golem_common::model::component_metadata::EgressHeader",
+            ),
+            (
+                "EgressPolicy",
+                "golem_common::model::component_metadata::EgressPolicy",
+            ),
+            (
+                "DeadLetterHandler",
+                "golem_common::model::component_metadata::DeadLetterHandler",
+            ),
+            (
+                "FailedWorkerAction",
+                "golem_common::model::component_metadata::FailedWorkerAction",
+            ),
+            (
+                "FailurePolicy",
+                "golem_common::model::component_metadata::FailurePolicy",
+            ),
             ("Empty", "golem_common::model::Empty"),
             (
                 "InitialComponentFile",
                 "golem_common::model::InitialComponentFile",
             ),
             ("PromiseId", "golem_common::model::PromiseId"),
+            (
+                "SecretReference",
+                "golem_common::model::secret::SecretReference",
+            ),
             ("ShardId", "golem_common::model::ShardId"),
             (
                 "TypeAnnotatedValue",
@@ -13,8 +13,13 @@
 // limitations under the License.
 
 use bincode::{Decode, Encode};
+use std::collections::{BTreeSet, HashSet};
 use std::fmt::{self, Display, Formatter};
+use std::net::IpAddr;
+use std::time::Duration;
 
+use crate::model::secret::SecretReference;
+use crate::model::{ComponentFilePath, WorkerId};
 use crate::SafeDisplay;
 use golem_wasm_ast::analysis::AnalysedFunctionParameter;
 use golem_wasm_ast::core::Mem;
@@ -24,7 +29,7 @@ use golem_wasm_ast::{
     component::Component,
     IgnoreAllButMetadata,
 };
-use poem_openapi::Object;
+use poem_openapi::{Enum, Object};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object, Encode, Decode)]
@@ -32,6 +37,21 @@ pub struct ComponentMetadata {
     pub exports: Vec<AnalysedExport>,
     pub producers: Vec<Producers>,
     pub memories: Vec<LinearMemory>,
+    #[serde(default)]
+    pub oplog_policy: Option<OplogPolicy>,
+    #[serde(default)]
+    pub volumes: Vec<ComponentVolume>,
+    /// Names of the interfaces imported by the component, such as `wasi:http/outgoing-handler@0.2.0`
+    #[serde(default)]
+    pub imports: Vec<String>,
+    #[serde(default)]
+    pub capability_policy: Option<CapabilityPolicy>,
+    #[serde(default)]
+    pub egress_policy: Option<EgressPolicy>,
+    #[serde(default)]
+    pub secrets: Vec<SecretReference>,
+    #[serde(default)]
+    pub failure_policy: Option<FailurePolicy>,
 }
 
 impl ComponentMetadata {
@@ -39,6 +59,25 @@ impl ComponentMetadata {
         let raw = RawComponentMetadata::analyse_component(data)?;
         Ok(raw.into())
     }
+
+    /// Copies the settings configured through the API, as opposed to the ones analysed from the
+    /// component binary, so that new component versions keep them
+    pub fn inherit_settings(&mut self, previous: &ComponentMetadata) {
+        self.oplog_policy = previous.oplog_policy.clone();
+        self.volumes = previous.volumes.clone();
+        self.capability_policy = previous.capability_policy.clone();
+        self.egress_policy = previous.egress_policy.clone();
+        self.secrets = previous.secrets.clone();
+        self.failure_policy = previous.failure_policy.clone();
+    }
+
+    /// The host capabilities used by the component's imports
+    pub fn capabilities(&self) -> BTreeSet<HostCapability> {
+        self.imports
+            .iter()
+            .filter_map(|name| HostCapability::of_import(name))
+            .collect()
+    }
 }
 
 #[derive(
@@ -247,6 +286,813 @@ impl From<LinearMemory> for golem_api_grpc::proto::golem::component::LinearMemor
     }
 }
 
+/// Per-component oplog retention and tiering policy.
+///
+/// Every field is optional, falling back to the worker executor's global oplog configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object, Encode, Decode)]
+pub struct OplogPolicy {
+    /// Time a worker has to be idle before its oplog gets moved to the next storage layer
+    pub archive_interval_seconds: Option<u64>,
+    /// Zstd compression level used when archiving the oplog into compressed layers
+    pub compression_level: Option<i32>,
+    /// Whether archived oplog entries may be moved to blob storage (defaults to true)
+    pub archive_to_blob_storage: Option<bool>,
+    /// Time after which the oplog of an exited worker gets deleted
+    pub exited_worker_ttl_seconds: Option<u64>,
+}
+
+impl OplogPolicy {
+    pub fn archive_interval(&self) -> Option<Duration> {
+        self.archive_interval_seconds.map(Duration::from_secs)
+    }
+
+    pub fn allows_blob_storage(&self) -> bool {
+        self.archive_to_blob_storage.unwrap_or(true)
+    }
+
+    pub fn exited_worker_ttl(&self) -> Option<Duration> {
+        self.exited_worker_ttl_seconds.map(Duration::from_secs)
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::component::OplogPolicy> for OplogPolicy {
+    fn from(value: golem_api_grpc::proto::golem::component::OplogPolicy) -> Self {
+        Self {
+            archive_interval_seconds: value.archive_interval_seconds,
+            compression_level: value.compression_level,
+            archive_to_blob_storage: value.archive_to_blob_storage,
+            exited_worker_ttl_seconds: value.exited_worker_ttl_seconds,
+        }
+    }
+}
+
+impl From<OplogPolicy> for golem_api_grpc::proto::golem::component::OplogPolicy {
+    fn from(value: OplogPolicy) -> Self {
+        Self {
+            archive_interval_seconds: value.archive_interval_seconds,
+            compression_level: value.compression_level,
+            archive_to_blob_storage: value.archive_to_blob_storage,
+            exited_worker_ttl_seconds: value.exited_worker_ttl_seconds,
+        }
+    }
+}
+
+/// A named persistent volume mounted into every worker of the component.
+///
+/// Unlike the rest of the worker's filesystem, the contents of a volume are not rebuilt by
+/// replaying the oplog. They are kept in blob storage, shared by all the workers of the
+/// component, and written back whenever the worker reaches a commit point.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object, Encode, Decode)]
+pub struct ComponentVolume {
+    /// Name of the volume, identifying its storage. Can contain letters, digits, '-' and '_'
+    pub name: String,
+    /// Absolute path the volume is mounted at
+    pub path: ComponentFilePath,
+}
+
+impl ComponentVolume {
+    /// Checks that the names are valid, that no two volumes share a name, that no volume is
+    /// mounted inside another one and that none of them overlaps with the initial files of the
+    /// component
+    pub fn validate_all(
+        volumes: &[ComponentVolume],
+        initial_files: &[ComponentFilePath],
+    ) -> Result<(), String> {
+        let mut names = HashSet::new();
+        let mut paths = HashSet::new();
+        for volume in volumes {
+            if volume.name.is_empty()
+                || !volume
+                    .name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            {
+                return Err(format!("Invalid volume name: {}", volume.name));
+            }
+            if volume.path.as_path().as_str() == "/" {
+                return Err(format!(
+                    "Volume {} cannot be mounted at the root directory",
+                    volume.name
+                ));
+            }
+            if !names.insert(&volume.name) {
+                return Err(format!("Duplicate volume name: {}", volume.name));
+            }
+            if !paths.insert(&volume.path) {
+                return Err(format!("Duplicate volume path: {}", volume.path));
+            }
+        }
+        for (idx, volume) in volumes.iter().enumerate() {
+            for other in &volumes[idx + 1..] {
+                if Self::overlaps(&volume.path, &other.path) {
+                    return Err(format!(
+                        "Volumes {} and {} have overlapping mount paths",
+                        volume.name, other.name
+                    ));
+                }
+            }
+            for file in initial_files {
+                if Self::overlaps(&volume.path, file) {
+                    return Err(format!(
+                        "Volume {} overlaps with the initial file {}",
+                        volume.name, file
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn overlaps(a: &ComponentFilePath, b: &ComponentFilePath) -> bool {
+        a.as_path().starts_with(b.as_path()) || b.as_path().starts_with(a.as_path())
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::component::ComponentVolume> for ComponentVolume {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::component::ComponentVolume,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name,
+            path: ComponentFilePath::from_abs_str(&value.path)?,
+        })
+    }
+}
+
+impl From<ComponentVolume> for golem_api_grpc::proto::golem::component::ComponentVolume {
+    fn from(value: ComponentVolume) -> Self {
+        Self {
+            name: value.name,
+            path: value.path.to_string(),
+        }
+    }
+}
+
+/// Host capabilities a component can use through its imports
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Enum,
+    Encode,
+    Decode,
+)]
+#[repr(i32)]
+pub enum HostCapability {
+    Http = 0,
+    Sockets = 1,
+    FileSystem = 2,
+    Rpc = 3,
+    KeyValue = 4,
+    BlobStore = 5,
+}
+
+impl HostCapability {
+    /// Classifies an imported interface, such as `wasi:http/outgoing-handler@0.2.0`. Imports not
+    /// guarded by any capability (clocks, random, cli, the Golem API, etc.) return `None`.
+    pub fn of_import(name: &str) -> Option<HostCapability> {
+        let package = name.split('/').next().unwrap_or(name);
+        match package {
+            "wasi:http" => Some(HostCapability::Http),
+            "wasi:sockets" => Some(HostCapability::Sockets),
+            "wasi:filesystem" => Some(HostCapability::FileSystem),
+            "golem:rpc" => Some(HostCapability::Rpc),
+            "wasi:keyvalue" => Some(HostCapability::KeyValue),
+            "wasi:blobstore" => Some(HostCapability::BlobStore),
+            _ => None,
+        }
+    }
+}
+
+impl Display for HostCapability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            HostCapability::Http => write!(f, "http"),
+            HostCapability::Sockets => write!(f, "sockets"),
+            HostCapability::FileSystem => write!(f, "filesystem"),
+            HostCapability::Rpc => write!(f, "rpc"),
+            HostCapability::KeyValue => write!(f, "keyvalue"),
+            HostCapability::BlobStore => write!(f, "blobstore"),
+        }
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::component::HostCapability> for HostCapability {
+    fn from(value: golem_api_grpc::proto::golem::component::HostCapability) -> Self {
+        match value {
+            golem_api_grpc::proto::golem::component::HostCapability::Http => HostCapability::Http,
+            golem_api_grpc::proto::golem::component::HostCapability::Sockets => {
+                HostCapability::Sockets
+            }
+            golem_api_grpc::proto::golem::component::HostCapability::FileSystem => {
+                HostCapability::FileSystem
+            }
+            golem_api_grpc::proto::golem::component::HostCapability::Rpc => HostCapability::Rpc,
+            golem_api_grpc::proto::golem::component::HostCapability::KeyValue => {
+                HostCapability::KeyValue
+            }
+            golem_api_grpc::proto::golem::component::HostCapability::BlobStore => {
+                HostCapability::BlobStore
+            }
+        }
+    }
+}
+
+impl From<HostCapability> for golem_api_grpc::proto::golem::component::HostCapability {
+    fn from(value: HostCapability) -> Self {
+        match value {
+            HostCapability::Http => golem_api_grpc::proto::golem::component::HostCapability::Http,
+            HostCapability::Sockets => {
+                golem_api_grpc::proto::golem::component::HostCapability::Sockets
+            }
+            HostCapability::FileSystem => {
+                golem_api_grpc::proto::golem::component::HostCapability::FileSystem
+            }
+            HostCapability::Rpc => golem_api_grpc::proto::golem::component::HostCapability::Rpc,
+            HostCapability::KeyValue => {
+                golem_api_grpc::proto::golem::component::HostCapability::KeyValue
+            }
+            HostCapability::BlobStore => {
+                golem_api_grpc::proto::golem::component::HostCapability::BlobStore
+            }
+        }
+    }
+}
+
+/// Restricts the host capabilities available to the workers of a component.
+///
+/// A component importing denied capabilities can still be instantiated as long as it does not use
+/// them. The worker executor links the entry points of denied outgoing HTTP, socket and blob store
+/// interfaces to stubs that trap. The other capabilities are checked when called: opening a
+/// key-value bucket or creating an RPC client traps, name lookups fail with `access-denied` and no
+/// preopened directories are exposed. The HTTP host allow-list is enforced on every outgoing
+/// request.
+///
+/// The policy is part of the component version, so workers keep using the policy of the version
+/// they run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Object, Encode, Decode)]
+pub struct CapabilityPolicy {
+    /// Capabilities the component's workers must not use
+    #[serde(default)]
+    pub denied: Vec<HostCapability>,
+    /// If set, outgoing HTTP requests are only allowed to these hosts. A leading `*.` matches
+    /// any subdomain.
+    pub allowed_http_hosts: Option<Vec<String>>,
+}
+
+impl CapabilityPolicy {
+    pub fn denies(&self, capability: HostCapability) -> bool {
+        self.denied.contains(&capability)
+    }
+
+    /// Returns the imports which use a denied capability
+    pub fn denied_imports<'a>(&self, imports: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+        imports
+            .into_iter()
+            .filter(|name| {
+                HostCapability::of_import(name)
+                    .map(|capability| self.denies(capability))
+                    .unwrap_or(false)
+            })
+            .collect()
+    }
+
+    pub fn allows_http_host(&self, host: &str) -> bool {
+        match &self.allowed_http_hosts {
+            None => true,
+            Some(allowed_hosts) => allowed_hosts
+                .iter()
+                .any(|allowed| matches_host_pattern(allowed, host)),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(allowed_hosts) = &self.allowed_http_hosts {
+            for host in allowed_hosts {
+                let domain = host.strip_prefix("*.").unwrap_or(host);
+                if domain.is_empty() || domain.contains(['*', '/', ':', ' ']) {
+                    return Err(format!("Invalid allowed HTTP host: {host}"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::component::CapabilityPolicy> for CapabilityPolicy {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::component::CapabilityPolicy,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            denied: value
+                .denied
+                .into_iter()
+                .map(|capability| {
+                    golem_api_grpc::proto::golem::component::HostCapability::try_from(capability)
+                        .map(|capability| capability.into())
+                        .map_err(|_| format!("Unknown host capability: {capability}"))
+                })
+                .collect::<Result<_, _>>()?,
+            allowed_http_hosts: value.allowed_http_hosts.map(|allowed| allowed.hosts),
+        })
+    }
+}
+
+impl From<CapabilityPolicy> for golem_api_grpc::proto::golem::component::CapabilityPolicy {
+    fn from(value: CapabilityPolicy) -> Self {
+        Self {
+            denied: value
+                .denied
+                .into_iter()
+                .map(|capability| {
+                    golem_api_grpc::proto::golem::component::HostCapability::from(capability) as i32
+                })
+                .collect(),
+            allowed_http_hosts: value
+                .allowed_http_hosts
+                .map(|hosts| golem_api_grpc::proto::golem::component::AllowedHttpHosts { hosts }),
+        }
+    }
+}
+
+/// Rules enforced on the outgoing HTTP requests of workers before they are sent.
+///
+/// Denied requests fail with an `HTTP-request-denied` error code, which is recorded in the oplog
+/// like any other response, so replaying the worker does not depend on the current rules.
+///
+/// Destinations are checked against both the host name and the addresses it resolves to, and the
+/// same address rules apply to the connections made through `wasi:sockets`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Object, Encode, Decode)]
+pub struct EgressPolicy {
+    /// Allowed destinations. When empty, requests to any destination are allowed
+    #[serde(default)]
+    pub allowed_destinations: Vec<EgressDestination>,
+    /// IP addresses or CIDR blocks which must never be reached, even if the host name of a
+    /// request is allowed, such as `169.254.0.0/16`
+    #[serde(default)]
+    #[oai(default)]
+    pub denied_addresses: Vec<String>,
+    /// Maximum size of a request body in bytes
+    pub max_request_body_size: Option<u64>,
+    /// Upper limit of the connect, first byte and between bytes timeouts, in milliseconds
+    pub timeout_millis: Option<u64>,
+    /// Headers set on every request, overriding the ones set by the worker
+    #[serde(default)]
+    pub injected_headers: Vec<EgressHeader>,
+}
+
+/// An allowed destination of outgoing HTTP requests
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object, Encode, Decode)]
+pub struct EgressDestination {
+    /// Host name, domain prefixed with `*.`, IP address or CIDR block such as `10.0.0.0/8`
+    pub host: String,
+    /// Allowed ports. When empty, any port is allowed
+    #[serde(default)]
+    pub ports: Vec<u16>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object, Encode, Decode)]
+pub struct EgressHeader {
+    pub name: String,
+    /// Reference to the secret holding the value of the header, in the form `${secret:NAME}`
+    pub value: String,
+}
+
+impl EgressPolicy {
+    /// Checks the destination of a request by its host, before the host name gets resolved.
+    ///
+    /// A host name not matching any destination can still be allowed by the addresses it
+    /// resolves to, see [`EgressPolicy::allows_addresses`].
+    pub fn allows_destination(&self, host: &str, port: u16) -> bool {
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        if let Ok(ip) = host.parse::<IpAddr>() {
+            if self.denies_address(ip) {
+                return false;
+            }
+        }
+        self.allowed_destinations.is_empty()
+            || self
+                .allowed_destinations
+                .iter()
+                .any(|destination| destination.matches(host, port))
+    }
+
+    /// Checks the addresses the host of a request or socket connection resolved to.
+    ///
+    /// Addresses in a denied block are always rejected. Otherwise the destination is allowed if
+    /// its host is allowed by name, or if every address falls into an allowed IP address or CIDR
+    /// block.
+    pub fn allows_addresses(&self, host: &str, addresses: &[IpAddr], port: u16) -> bool {
+        if addresses
+            .iter()
+            .any(|address| self.denies_address(*address))
+        {
+            return false;
+        }
+        self.allows_destination(host, port)
+            || (!addresses.is_empty()
+                && addresses.iter().all(|address| {
+                    self.allowed_destinations
+                        .iter()
+                        .any(|destination| destination.matches_address(*address, port))
+                }))
+    }
+
+    /// Whether the addresses of a request's host have to be resolved to check it
+    pub fn checks_addresses(&self) -> bool {
+        !self.denied_addresses.is_empty()
+            || self
+                .allowed_destinations
+                .iter()
+                .any(|destination| destination.is_address())
+    }
+
+    fn denies_address(&self, address: IpAddr) -> bool {
+        self.denied_addresses
+            .iter()
+            .any(|block| address_block_contains(block, address))
+    }
+
+    /// Returns a copy of the policy with the header values not referring to a secret hidden.
+    ///
+    /// Policies set before injected headers had to refer to secrets may still hold plain values,
+    /// which must not be returned by the API.
+    pub fn redacted(&self) -> Self {
+        Self {
+            injected_headers: self
+                .injected_headers
+                .iter()
+                .map(|header| EgressHeader {
+                    name: header.name.clone(),
+                    value: if SecretReference::parse(&header.value).is_some() {
+                        header.value.clone()
+                    } else {
+                        "<redacted>".to_string()
+                    },
+                })
+                .collect(),
+            ..self.clone()
+        }
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout_millis.map(Duration::from_millis)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for destination in &self.allowed_destinations {
+            let valid_host = match destination.host.split_once('/') {
+                Some((address, prefix_length)) => parse_cidr(address, prefix_length).is_some(),
+                None => {
+                    let domain = destination
+                        .host
+                        .strip_prefix("*.")
+                        .unwrap_or(&destination.host);
+                    domain.parse::<IpAddr>().is_ok()
+                        || (!domain.is_empty() && !domain.contains(['*', ':', ' ']))
+                }
+            };
+            if !valid_host {
+                return Err(format!(
+                    "Invalid egress destination host: {}",
+                    destination.host
+                ));
+            }
+            if destination.ports.contains(&0) {
+                return Err(format!(
+                    "Invalid port 0 for egress destination {}",
+                    destination.host
+                ));
+            }
+        }
+        for block in &self.denied_addresses {
+            let valid_block = match block.split_once('/') {
+                Some((address, prefix_length)) => parse_cidr(address, prefix_length).is_some(),
+                None => block.parse::<IpAddr>().is_ok(),
+            };
+            if !valid_block {
+                return Err(format!("Invalid denied egress address: {block}"));
+            }
+        }
+        for header in &self.injected_headers {
+            if header.name.is_empty()
+                || !header
+                    .name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c))
+            {
+                return Err(format!("Invalid injected header name: {}", header.name));
+            }
+            if SecretReference::parse(&header.value).is_none() {
+                return Err(format!(
+                    "The value of injected header {} must refer to a secret in the form ${{secret:NAME}}",
+                    header.name
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl EgressDestination {
+    pub fn matches(&self, host: &str, port: u16) -> bool {
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        match host.parse::<IpAddr>() {
+            Ok(ip) => self.matches_address(ip, port),
+            Err(_) => {
+                self.allows_port(port)
+                    && !self.is_address()
+                    && matches_host_pattern(&self.host, host)
+            }
+        }
+    }
+
+    /// Checks a resolved address against a destination given by IP address or CIDR block
+    pub fn matches_address(&self, address: IpAddr, port: u16) -> bool {
+        self.allows_port(port) && address_block_contains(&self.host, address)
+    }
+
+    /// Whether the destination is an IP address or CIDR block rather than a host name
+    pub fn is_address(&self) -> bool {
+        self.host.contains('/') || self.host.parse::<IpAddr>().is_ok()
+    }
+
+    fn allows_port(&self, port: u16) -> bool {
+        self.ports.is_empty() || self.ports.contains(&port)
+    }
+}
+
+/// Checks whether an address equals an IP address or falls into a CIDR block. IPv4-mapped IPv6
+/// addresses are compared as IPv4 addresses.
+fn address_block_contains(block: &str, address: IpAddr) -> bool {
+    let address = address.to_canonical();
+    match block.split_once('/') {
+        Some((network, prefix_length)) => match parse_cidr(network, prefix_length) {
+            Some((network, prefix_length)) => ip_in_network(address, network, prefix_length),
+            None => false,
+        },
+        None => block
+            .parse::<IpAddr>()
+            .map(|ip| ip.to_canonical() == address)
+            .unwrap_or(false),
+    }
+}
+
+fn matches_host_pattern(pattern: &str, host: &str) -> bool {
+    let pattern = pattern.to_ascii_lowercase();
+    let host = host.to_ascii_lowercase();
+    match pattern.strip_prefix("*.") {
+        Some(domain) => host
+            .strip_suffix(domain)
+            .map(|prefix| prefix.ends_with('.'))
+            .unwrap_or(false),
+        None => host == pattern,
+    }
+}
+
+fn parse_cidr(address: &str, prefix_length: &str) -> Option<(IpAddr, u32)> {
+    let address = address.parse::<IpAddr>().ok()?;
+    let prefix_length = prefix_length.parse::<u32>().ok()?;
+    let max_length = if address.is_ipv4() { 32 } else { 128 };
+    (prefix_length <= max_length).then_some((address, prefix_length))
+}
+
+fn ip_in_network(ip: IpAddr, network: IpAddr, prefix_length: u32) -> bool {
+    match (ip, network) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => {
+            let mask = u32::MAX.checked_shl(32 - prefix_length).unwrap_or(0);
+            u32::from(ip) & mask == u32::from(network) & mask
+        }
+        (IpAddr::V6(ip), IpAddr::V6(network)) => {
+            let mask = u128::MAX.checked_shl(128 - prefix_length).unwrap_or(0);
+            u128::from(ip) & mask == u128::from(network) & mask
+        }
+        _ => false,
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::component::EgressPolicy> for EgressPolicy {
+    fn from(value: golem_api_grpc::proto::golem::component::EgressPolicy) -> Self {
+        Self {
+            allowed_destinations: value
+                .allowed_destinations
+                .into_iter()
+                .map(|destination| EgressDestination {
+                    host: destination.host,
+                    ports: destination
+                        .ports
+                        .into_iter()
+                        .map(|port| port as u16)
+                        .collect(),
+                })
+                .collect(),
+            denied_addresses: value.denied_addresses,
+            max_request_body_size: value.max_request_body_size,
+            timeout_millis: value.timeout_millis,
+            injected_headers: value
+                .injected_headers
+                .into_iter()
+                .map(|header| EgressHeader {
+                    name: header.name,
+                    value: header.value,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<EgressPolicy> for golem_api_grpc::proto::golem::component::EgressPolicy {
+    fn from(value: EgressPolicy) -> Self {
+        Self {
+            allowed_destinations: value
+                .allowed_destinations
+                .into_iter()
+                .map(
+                    |destination| golem_api_grpc::proto::golem::component::EgressDestination {
+                        host: destination.host,
+                        ports: destination
+                            .ports
+                            .into_iter()
+                            .map(|port| port as u32)
+                            .collect(),
+                    },
+                )
+                .collect(),
+            denied_addresses: value.denied_addresses,
+            max_request_body_size: value.max_request_body_size,
+            timeout_millis: value.timeout_millis,
+            injected_headers: value
+                .injected_headers
+                .into_iter()
+                .map(
+                    |header| golem_api_grpc::proto::golem::component::EgressHeader {
+                        name: header.name,
+                        value: header.value,
+                    },
+                )
+                .collect(),
+        }
+    }
+}
+
+/// Defines what happens when a worker of the component fails permanently, after exhausting all
+/// the retries allowed by its retry policy.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Object, Encode, Decode)]
+pub struct FailurePolicy {
+    /// Function invoked on another worker with the details of the failure
+    pub dead_letter_handler: Option<DeadLetterHandler>,
+    /// Action taken on the failed worker itself, defaults to keeping it
+    #[serde(default)]
+    pub action: FailedWorkerAction,
+    /// Time to wait before deleting the failed worker when the action is `Delete`
+    pub delete_after_seconds: Option<u64>,
+}
+
+/// A function of a (dead-letter) worker, invoked without awaiting its result when a worker fails.
+///
+/// The function receives the failed worker's id, the error, and the name of the failed function
+/// and the idempotency key of the failed invocation, if the failure happened in an invocation:
+/// `func(worker-id: string, error: string, function-name: option<string>, idempotency-key: option<string>)`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object, Encode, Decode)]
+pub struct DeadLetterHandler {
+    pub worker_id: WorkerId,
+    pub function_name: String,
+}
+
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Enum, Encode, Decode,
+)]
+#[repr(i32)]
+pub enum FailedWorkerAction {
+    /// The worker stays in the failed state
+    #[default]
+    Keep = 0,
+    /// The worker gets deleted
+    Delete = 1,
+    /// The failed invocation gets removed from the worker's history, the worker continues
+    /// from the state it had before the invocation
+    Revert = 2,
+}
+
+impl FailurePolicy {
+    pub fn delete_after(&self) -> Duration {
+        Duration::from_secs(self.delete_after_seconds.unwrap_or_default())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(handler) = &self.dead_letter_handler {
+            if handler.worker_id.worker_name.is_empty() {
+                return Err("The dead-letter handler's worker name cannot be empty".to_string());
+            }
+            if handler.function_name.is_empty() {
+                return Err("The dead-letter handler's function name cannot be empty".to_string());
+            }
+        }
+        if self.delete_after_seconds.is_some() && self.action != FailedWorkerAction::Delete {
+            return Err("delete_after_seconds can only be used with the Delete action".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl Display for FailedWorkerAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FailedWorkerAction::Keep => write!(f, "keep"),
+            FailedWorkerAction::Delete => write!(f, "delete"),
+            FailedWorkerAction::Revert => write!(f, "revert"),
+        }
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::component::FailurePolicy> for FailurePolicy {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::component::FailurePolicy,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            action: value.action().into(),
+            dead_letter_handler: value
+                .dead_letter_handler
+                .map(|handler| -> Result<DeadLetterHandler, String> {
+                    Ok(DeadLetterHandler {
+                        worker_id: WorkerId {
+                            component_id: handler
+                                .component_id
+                                .ok_or("Missing dead-letter handler component id")?
+                                .try_into()?,
+                            worker_name: handler.worker_name,
+                        },
+                        function_name: handler.function_name,
+                    })
+                })
+                .transpose()?,
+            delete_after_seconds: value.delete_after_seconds,
+        })
+    }
+}
+
+impl From<FailurePolicy> for golem_api_grpc::proto::golem::component::FailurePolicy {
+    fn from(value: FailurePolicy) -> Self {
+        Self {
+            dead_letter_handler: value.dead_letter_handler.map(|handler| {
+                golem_api_grpc::proto::golem::component::DeadLetterHandler {
+                    component_id: Some(handler.worker_id.component_id.into()),
+                    worker_name: handler.worker_id.worker_name,
+                    function_name: handler.function_name,
+                }
+            }),
+            action: golem_api_grpc::proto::golem::component::FailedWorkerAction::from(value.action)
+                .into(),
+            delete_after_seconds: value.delete_after_seconds,
+        }
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::component::FailedWorkerAction> for FailedWorkerAction {
+    fn from(value: golem_api_grpc::proto::golem::component::FailedWorkerAction) -> Self {
+        match value {
+            golem_api_grpc::proto::golem::component::FailedWorkerAction::Keep => {
+                FailedWorkerAction::Keep
+            }
+            golem_api_grpc::proto::golem::component::FailedWorkerAction::Delete => {
+                FailedWorkerAction::Delete
+            }
+            golem_api_grpc::proto::golem::component::FailedWorkerAction::Revert => {
+                FailedWorkerAction::Revert
+            }
+        }
+    }
+}
+
+impl From<FailedWorkerAction> for golem_api_grpc::proto::golem::component::FailedWorkerAction {
+    fn from(value: FailedWorkerAction) -> Self {
+        match value {
+            FailedWorkerAction::Keep => {
+                golem_api_grpc::proto::golem::component::FailedWorkerAction::Keep
+            }
+            FailedWorkerAction::Delete => {
+                golem_api_grpc::proto::golem::component::FailedWorkerAction::Delete
+            }
+            FailedWorkerAction::Revert => {
+                golem_api_grpc::proto::golem::component::FailedWorkerAction::Revert
+            }
+        }
+    }
+}
+
 impl From<RawComponentMetadata> for ComponentMetadata {
     fn from(value: RawComponentMetadata) -> Self {
         let producers = value
@@ -263,6 +1109,13 @@ impl From<RawComponentMetadata> for ComponentMetadata {
             exports,
             producers,
             memories,
+            oplog_policy: None,
+            volumes: vec![],
+            imports: value.imports,
+            capability_policy: None,
+            egress_policy: None,
+            secrets: vec![],
+            failure_policy: None,
         }
     }
 }
@@ -289,6 +1142,27 @@ impl TryFrom<golem_api_grpc::proto::golem::component::ComponentMetadata> for Com
                 .into_iter()
                 .map(|memory| memory.into())
                 .collect(),
+            oplog_policy: value.oplog_policy.map(|policy| policy.into()),
+            volumes: value
+                .volumes
+                .into_iter()
+                .map(|volume| volume.try_into())
+                .collect::<Result<_, _>>()?,
+            imports: value.imports,
+            capability_policy: value
+                .capability_policy
+                .map(|policy| policy.try_into())
+                .transpose()?,
+            egress_policy: value.egress_policy.map(|policy| policy.into()),
+            secrets: value
+                .secrets
+                .into_iter()
+                .map(|secret| secret.into())
+                .collect(),
+            failure_policy: value
+                .failure_policy
+                .map(|policy| policy.try_into())
+                .transpose()?,
         })
     }
 }
@@ -311,6 +1185,21 @@ impl From<ComponentMetadata> for golem_api_grpc::proto::golem::component::Compon
                 .into_iter()
                 .map(|memory| memory.into())
                 .collect(),
+            oplog_policy: value.oplog_policy.map(|policy| policy.into()),
+            volumes: value
+                .volumes
+                .into_iter()
+                .map(|volume| volume.into())
+                .collect(),
+            imports: value.imports,
+            capability_policy: value.capability_policy.map(|policy| policy.into()),
+            egress_policy: value.egress_policy.map(|policy| policy.into()),
+            secrets: value
+                .secrets
+                .into_iter()
+                .map(|secret| secret.into())
+                .collect(),
+            failure_policy: value.failure_policy.map(|policy| policy.into()),
         }
     }
 }
@@ -320,6 +1209,7 @@ pub struct RawComponentMetadata {
     pub exports: Vec<AnalysedExport>,
     pub producers: Vec<WasmAstProducers>,
     pub memories: Vec<Mem>,
+    pub imports: Vec<String>,
 }
 
 impl RawComponentMetadata {
@@ -334,6 +1224,12 @@ impl RawComponentMetadata {
             .into_iter()
             .collect::<Vec<_>>();
 
+        let imports = component
+            .imports()
+            .iter()
+            .map(|import| String::from(&import.name))
+            .collect::<Vec<_>>();
+
         let state = AnalysisContext::new(component);
 
         let mut exports = state
@@ -354,6 +1250,7 @@ impl RawComponentMetadata {
             exports,
             producers,
             memories,
+            imports,
         })
     }
 }
@@ -429,3 +1326,266 @@ fn drop_from_constructor(constructor: &AnalysedFunction) -> AnalysedFunction {
         results: vec![],
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use std::net::IpAddr;
+
+    use crate::model::component_metadata::{
+        CapabilityPolicy, ComponentVolume, DeadLetterHandler, EgressDestination, EgressHeader,
+        EgressPolicy, FailedWorkerAction, FailurePolicy, HostCapability,
+    };
+    use crate::model::{ComponentFilePath, ComponentId, WorkerId};
+
+    #[test]
+    fn imports_are_classified_by_package() {
+        assert_eq!(
+            HostCapability::of_import("wasi:http/outgoing-handler@0.2.0"),
+            Some(HostCapability::Http)
+        );
+        assert_eq!(
+            HostCapability::of_import("wasi:sockets/tcp@0.2.0"),
+            Some(HostCapability::Sockets)
+        );
+        assert_eq!(
+            HostCapability::of_import("golem:rpc/types@0.1.0"),
+            Some(HostCapability::Rpc)
+        );
+        assert_eq!(
+            HostCapability::of_import("wasi:clocks/wall-clock@0.2.0"),
+            None
+        );
+        assert_eq!(HostCapability::of_import("golem:api/host@1.1.0"), None);
+    }
+
+    #[test]
+    fn denied_imports() {
+        let policy = CapabilityPolicy {
+            denied: vec![HostCapability::Sockets],
+            allowed_http_hosts: None,
+        };
+        assert_eq!(
+            policy.denied_imports([
+                "wasi:http/outgoing-handler@0.2.0",
+                "wasi:sockets/tcp@0.2.0",
+                "wasi:sockets/network@0.2.0",
+            ]),
+            vec!["wasi:sockets/tcp@0.2.0", "wasi:sockets/network@0.2.0"]
+        );
+    }
+
+    #[test]
+    fn allowed_http_hosts() {
+        let policy = CapabilityPolicy {
+            denied: vec![],
+            allowed_http_hosts: Some(vec![
+                "api.example.com".to_string(),
+                "*.golem.cloud".to_string(),
+            ]),
+        };
+        assert!(policy.allows_http_host("api.example.com"));
+        assert!(policy.allows_http_host("API.Example.com"));
+        assert!(policy.allows_http_host("release.api.golem.cloud"));
+        assert!(!policy.allows_http_host("golem.cloud"));
+        assert!(!policy.allows_http_host("evilgolem.cloud"));
+        assert!(!policy.allows_http_host("example.com"));
+        assert!(CapabilityPolicy::default().allows_http_host("example.com"));
+    }
+
+    #[test]
+    fn egress_destinations() {
+        let policy = EgressPolicy {
+            allowed_destinations: vec![
+                EgressDestination {
+                    host: "*.example.com".to_string(),
+                    ports: vec![443],
+                },
+                EgressDestination {
+                    host: "10.0.0.0/8".to_string(),
+                    ports: vec![],
+                },
+                EgressDestination {
+                    host: "fd00::/8".to_string(),
+                    ports: vec![8080],
+                },
+            ],
+            ..EgressPolicy::default()
+        };
+        assert!(policy.allows_destination("api.example.com", 443));
+        assert!(!policy.allows_destination("api.example.com", 80));
+        assert!(policy.allows_destination("10.1.2.3", 80));
+        assert!(!policy.allows_destination("11.1.2.3", 80));
+        assert!(policy.allows_destination("[fd12::1]", 8080));
+        assert!(!policy.allows_destination("[fe80::1]", 8080));
+        assert!(EgressPolicy::default().allows_destination("anything.com", 1234));
+        assert!(policy.validate().is_ok());
+    }
+
+    #[test]
+    fn egress_resolved_addresses() {
+        let policy = EgressPolicy {
+            allowed_destinations: vec![
+                EgressDestination {
+                    host: "*.example.com".to_string(),
+                    ports: vec![],
+                },
+                EgressDestination {
+                    host: "10.0.0.0/8".to_string(),
+                    ports: vec![],
+                },
+            ],
+            denied_addresses: vec!["169.254.0.0/16".to_string()],
+            ..EgressPolicy::default()
+        };
+        let public: IpAddr = "93.184.215.14".parse().unwrap();
+        let internal: IpAddr = "10.1.2.3".parse().unwrap();
+        let metadata: IpAddr = "169.254.169.254".parse().unwrap();
+        let mapped_metadata: IpAddr = "::ffff:169.254.169.254".parse().unwrap();
+
+        assert!(policy.allows_addresses("api.example.com", &[public], 443));
+        assert!(!policy.allows_addresses("api.example.com", &[metadata], 443));
+        assert!(!policy.allows_addresses("api.example.com", &[mapped_metadata], 443));
+        assert!(!policy.allows_addresses("api.example.com", &[public, metadata], 443));
+        assert!(policy.allows_addresses("db.internal", &[internal], 5432));
+        assert!(!policy.allows_addresses("db.internal", &[internal, public], 5432));
+        assert!(!policy.allows_addresses("db.internal", &[], 5432));
+        assert!(!policy.allows_destination("169.254.169.254", 80));
+        assert!(policy.checks_addresses());
+        assert!(!EgressPolicy::default().checks_addresses());
+
+        let deny_only = EgressPolicy {
+            denied_addresses: vec!["169.254.169.254".to_string()],
+            ..EgressPolicy::default()
+        };
+        assert!(deny_only.allows_addresses("anything.com", &[public], 80));
+        assert!(!deny_only.allows_addresses("anything.com", &[metadata], 80));
+        assert!(deny_only.validate().is_ok());
+    }
+
+    #[test]
+    fn egress_headers_refer_to_secrets() {
+        let policy = EgressPolicy {
+            injected_headers: vec![
+                EgressHeader {
+                    name: "authorization".to_string(),
+                    value: "${secret:api-token}".to_string(),
+                },
+                EgressHeader {
+                    name: "x-api-key".to_string(),
+                    value: "plain".to_string(),
+                },
+            ],
+            ..EgressPolicy::default()
+        };
+        assert!(policy.validate().is_err());
+
+        let redacted = policy.redacted();
+        assert_eq!(redacted.injected_headers[0].value, "${secret:api-token}");
+        assert_eq!(redacted.injected_headers[1].value, "<redacted>");
+    }
+
+    #[test]
+    fn invalid_egress_policies() {
+        let invalid_cidr = EgressPolicy {
+            allowed_destinations: vec![EgressDestination {
+                host: "10.0.0.0/33".to_string(),
+                ports: vec![],
+            }],
+            ..EgressPolicy::default()
+        };
+        assert!(invalid_cidr.validate().is_err());
+
+        let invalid_denied_address = EgressPolicy {
+            denied_addresses: vec!["169.254.0.0".to_string(), "metadata".to_string()],
+            ..EgressPolicy::default()
+        };
+        assert!(invalid_denied_address.validate().is_err());
+
+        let invalid_header = EgressPolicy {
+            injected_headers: vec![EgressHeader {
+                name: "Invalid Header".to_string(),
+                value: "value".to_string(),
+            }],
+            ..EgressPolicy::default()
+        };
+        assert!(invalid_header.validate().is_err());
+    }
+
+    #[test]
+    fn failure_policy_validation() {
+        let handler = DeadLetterHandler {
+            worker_id: WorkerId {
+                component_id: ComponentId::new_v4(),
+                worker_name: "dead-letters".to_string(),
+            },
+            function_name: "golem:it/api.{on-failure}".to_string(),
+        };
+        let policy = FailurePolicy {
+            dead_letter_handler: Some(handler.clone()),
+            action: FailedWorkerAction::Delete,
+            delete_after_seconds: Some(3600),
+        };
+        assert!(policy.validate().is_ok());
+        assert!(FailurePolicy::default().validate().is_ok());
+
+        let delay_without_delete = FailurePolicy {
+            action: FailedWorkerAction::Revert,
+            ..policy.clone()
+        };
+        assert!(delay_without_delete.validate().is_err());
+
+        let missing_function = FailurePolicy {
+            dead_letter_handler: Some(DeadLetterHandler {
+                function_name: "".to_string(),
+                ..handler
+            }),
+            ..policy
+        };
+        assert!(missing_function.validate().is_err());
+    }
+
+    fn volume(name: &str, path: &str) -> ComponentVolume {
+        ComponentVolume {
+            name: name.to_string(),
+            path: ComponentFilePath::from_abs_str(path).unwrap(),
+        }
+    }
+
+    #[test]
+    fn volumes_must_not_overlap() {
+        let file = |path: &str| ComponentFilePath::from_abs_str(path).unwrap();
+
+        assert!(ComponentVolume::validate_all(
+            &[volume("data", "/data"), volume("cache", "/cache")],
+            &[file("/config/app.toml")]
+        )
+        .is_ok());
+        assert!(ComponentVolume::validate_all(
+            &[volume("data", "/data"), volume("nested", "/data/nested")],
+            &[]
+        )
+        .is_err());
+        assert!(ComponentVolume::validate_all(
+            &[volume("data", "/data/x"), volume("parent", "/data")],
+            &[]
+        )
+        .is_err());
+        assert!(ComponentVolume::validate_all(
+            &[volume("data", "/data")],
+            &[file("/data/seed.json")]
+        )
+        .is_err());
+        assert!(ComponentVolume::validate_all(
+            &[volume("data", "/config/app.toml/data")],
+            &[file("/config/app.toml")]
+        )
+        .is_err());
+        assert!(ComponentVolume::validate_all(
+            &[volume("data", "/data")],
+            &[file("/database.json")]
+        )
+        .is_ok());
+    }
+}
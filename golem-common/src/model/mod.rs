@@ -16,6 +16,7 @@ use crate::config::RetryConfig;
 use crate::model::oplog::{
     IndexedResourceKey, OplogEntry, OplogIndex, TimestampedUpdateDescription, WorkerResourceId,
 };
+use crate::model::public_oplog::PublicRetryConfig;
 use crate::model::regions::DeletedRegions;
 use crate::newtype_uuid;
 use crate::uri::oss::urn::WorkerUrn;
@@ -47,7 +48,7 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 use std::borrow::Cow;
 use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::fmt::{Display, Formatter};
 use std::ops::Add;
 use std::str::FromStr;
@@ -64,6 +65,7 @@ pub mod oplog;
 pub mod plugin;
 pub mod public_oplog;
 pub mod regions;
+pub mod secret;
 pub mod trim_date;
 
 newtype_uuid!(
@@ -579,6 +581,18 @@ pub enum ScheduledAction {
         last_oplog_index: OplogIndex,
         next_after: Duration,
     },
+    /// Deletes the oplog and metadata of an exited worker, if the last oplog index did not
+    /// change since the action was scheduled.
+    DeleteExitedWorker {
+        owned_worker_id: OwnedWorkerId,
+        last_oplog_index: OplogIndex,
+    },
+    /// Deletes the oplog and metadata of a failed worker, if the last oplog index did not
+    /// change since the action was scheduled.
+    DeleteFailedWorker {
+        owned_worker_id: OwnedWorkerId,
+        last_oplog_index: OplogIndex,
+    },
 }
 
 impl ScheduledAction {
@@ -591,6 +605,12 @@ impl ScheduledAction {
             ScheduledAction::ArchiveOplog {
                 owned_worker_id, ..
             } => owned_worker_id.clone(),
+            ScheduledAction::DeleteExitedWorker {
+                owned_worker_id, ..
+            } => owned_worker_id.clone(),
+            ScheduledAction::DeleteFailedWorker {
+                owned_worker_id, ..
+            } => owned_worker_id.clone(),
         }
     }
 }
@@ -606,6 +626,16 @@ impl Display for ScheduledAction {
             } => {
                 write!(f, "archive[{}]", owned_worker_id)
             }
+            ScheduledAction::DeleteExitedWorker {
+                owned_worker_id, ..
+            } => {
+                write!(f, "delete[{}]", owned_worker_id)
+            }
+            ScheduledAction::DeleteFailedWorker {
+                owned_worker_id, ..
+            } => {
+                write!(f, "delete-failed[{}]", owned_worker_id)
+            }
         }
     }
 }
@@ -1082,6 +1112,10 @@ pub enum WorkerStatusRecordExtensions {
     Extension1 {
         active_plugins: HashSet<PluginInstallationId>,
     },
+    Extension2 {
+        active_plugins: HashSet<PluginInstallationId>,
+        labels: BTreeMap<String, String>,
+    },
 }
 
 impl ::bincode::Decode for WorkerStatusRecord {
@@ -1147,12 +1181,22 @@ impl WorkerStatusRecord {
     pub fn active_plugins(&self) -> &HashSet<PluginInstallationId> {
         match &self.extensions {
             WorkerStatusRecordExtensions::Extension1 { active_plugins } => active_plugins,
+            WorkerStatusRecordExtensions::Extension2 { active_plugins, .. } => active_plugins,
         }
     }
 
     pub fn active_plugins_mut(&mut self) -> &mut HashSet<PluginInstallationId> {
         match &mut self.extensions {
             WorkerStatusRecordExtensions::Extension1 { active_plugins } => active_plugins,
+            WorkerStatusRecordExtensions::Extension2 { active_plugins, .. } => active_plugins,
+        }
+    }
+
+    pub fn labels(&self) -> &BTreeMap<String, String> {
+        static NO_LABELS: BTreeMap<String, String> = BTreeMap::new();
+        match &self.extensions {
+            WorkerStatusRecordExtensions::Extension1 { .. } => &NO_LABELS,
+            WorkerStatusRecordExtensions::Extension2 { labels, .. } => labels,
         }
     }
 }
@@ -1174,8 +1218,9 @@ impl Default for WorkerStatusRecord {
             total_linear_memory_size: 0,
             owned_resources: HashMap::new(),
             oplog_idx: OplogIndex::default(),
-            extensions: WorkerStatusRecordExtensions::Extension1 {
+            extensions: WorkerStatusRecordExtensions::Extension2 {
                 active_plugins: HashSet::new(),
+                labels: BTreeMap::new(),
             },
         }
     }
@@ -1338,7 +1383,8 @@ impl IntoValue for WorkerStatus {
 
 #[derive(Clone, Debug, PartialEq, Encode, Decode)]
 pub enum WorkerInvocation {
-    ExportedFunction {
+    /// Invocation of an exported function (previous version without invocation options)
+    ExportedFunctionV1 {
         idempotency_key: IdempotencyKey,
         full_function_name: String,
         function_input: Vec<golem_wasm_rpc::Value>,
@@ -1346,12 +1392,22 @@ pub enum WorkerInvocation {
     ManualUpdate {
         target_version: ComponentVersion,
     },
+    /// Invocation of an exported function
+    ExportedFunction {
+        idempotency_key: IdempotencyKey,
+        full_function_name: String,
+        function_input: Vec<golem_wasm_rpc::Value>,
+        options: InvocationOptions,
+    },
 }
 
 impl WorkerInvocation {
     pub fn is_idempotency_key(&self, key: &IdempotencyKey) -> bool {
         match self {
-            Self::ExportedFunction {
+            Self::ExportedFunctionV1 {
+                idempotency_key, ..
+            }
+            | Self::ExportedFunction {
                 idempotency_key, ..
             } => idempotency_key == key,
             _ => false,
@@ -1360,12 +1416,113 @@ impl WorkerInvocation {
 
     pub fn idempotency_key(&self) -> Option<&IdempotencyKey> {
         match self {
-            Self::ExportedFunction {
+            Self::ExportedFunctionV1 {
+                idempotency_key, ..
+            }
+            | Self::ExportedFunction {
                 idempotency_key, ..
             } => Some(idempotency_key),
             _ => None,
         }
     }
+
+    /// Converts invocations persisted by previous versions to the current representation
+    pub fn upgrade(self) -> Self {
+        match self {
+            Self::ExportedFunctionV1 {
+                idempotency_key,
+                full_function_name,
+                function_input,
+            } => Self::ExportedFunction {
+                idempotency_key,
+                full_function_name,
+                function_input,
+                options: InvocationOptions::default(),
+            },
+            other => other,
+        }
+    }
+}
+
+/// Per-invocation overrides of how the worker executes an invocation
+#[derive(Clone, Debug, Default, PartialEq, Encode, Decode)]
+pub struct InvocationOptions {
+    /// Retry policy used for this invocation instead of the worker's retry policy
+    pub retry_policy: Option<RetryConfig>,
+    /// The invocation is abandoned if it does not finish until this point in time
+    pub deadline: Option<Timestamp>,
+}
+
+impl InvocationOptions {
+    pub fn with_timeout(retry_policy: Option<RetryConfig>, timeout: Option<Duration>) -> Self {
+        Self {
+            retry_policy,
+            deadline: timeout.map(|timeout| {
+                Timestamp::from(Timestamp::now_utc().to_millis() + timeout.as_millis() as u64)
+            }),
+        }
+    }
+
+    pub fn is_deadline_exceeded(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| deadline <= Timestamp::now_utc())
+    }
+
+    /// Time left until the deadline, or `None` if the invocation has no deadline
+    pub fn remaining_time(&self) -> Option<Duration> {
+        self.deadline.map(|deadline| {
+            Duration::from_millis(
+                deadline
+                    .to_millis()
+                    .saturating_sub(Timestamp::now_utc().to_millis()),
+            )
+        })
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::InvocationOptions> for InvocationOptions {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::InvocationOptions,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            retry_policy: value
+                .retry_policy
+                .map(|policy| PublicRetryConfig::try_from(policy).map(RetryConfig::from))
+                .transpose()?,
+            deadline: value.deadline.map(|deadline| deadline.into()),
+        })
+    }
+}
+
+impl TryFrom<&golem_api_grpc::proto::golem::worker::InvokeParameters> for InvocationOptions {
+    type Error = String;
+
+    fn try_from(
+        value: &golem_api_grpc::proto::golem::worker::InvokeParameters,
+    ) -> Result<Self, Self::Error> {
+        let retry_policy = value
+            .retry_policy
+            .clone()
+            .map(|policy| PublicRetryConfig::try_from(policy).map(RetryConfig::from))
+            .transpose()?;
+        Ok(Self::with_timeout(
+            retry_policy,
+            value.timeout_millis.map(Duration::from_millis),
+        ))
+    }
+}
+
+impl From<InvocationOptions> for golem_api_grpc::proto::golem::worker::InvocationOptions {
+    fn from(value: InvocationOptions) -> Self {
+        Self {
+            retry_policy: value
+                .retry_policy
+                .map(|policy| PublicRetryConfig::from(policy).into()),
+            deadline: value.deadline.map(|deadline| deadline.into()),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Encode, Decode)]
@@ -1591,6 +1748,29 @@ impl Display for WorkerEnvFilter {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode, Object)]
+pub struct WorkerLabelFilter {
+    pub name: String,
+    pub comparator: StringFilterComparator,
+    pub value: String,
+}
+
+impl WorkerLabelFilter {
+    pub fn new(name: String, comparator: StringFilterComparator, value: String) -> Self {
+        Self {
+            name,
+            comparator,
+            value,
+        }
+    }
+}
+
+impl Display for WorkerLabelFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "label.{} {} {}", self.name, self.comparator, self.value)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode, Object)]
 pub struct WorkerAndFilter {
     pub filters: Vec<WorkerFilter>,
@@ -1672,6 +1852,7 @@ pub enum WorkerFilter {
     And(WorkerAndFilter),
     Or(WorkerOrFilter),
     Not(WorkerNotFilter),
+    Label(WorkerLabelFilter),
 }
 
 impl WorkerFilter {
@@ -1722,6 +1903,14 @@ impl WorkerFilter {
                 }
                 result
             }
+            WorkerFilter::Label(WorkerLabelFilter {
+                name,
+                comparator,
+                value,
+            }) => match metadata.last_known_status.labels().get(&name) {
+                Some(label_value) => comparator.matches(label_value, &value),
+                None => false,
+            },
             WorkerFilter::CreatedAt(WorkerCreatedAtFilter { comparator, value }) => {
                 comparator.matches(&metadata.created_at, &value)
             }
@@ -1775,6 +1964,10 @@ impl WorkerFilter {
         WorkerFilter::Env(WorkerEnvFilter::new(name, comparator, value))
     }
 
+    pub fn new_label(name: String, comparator: StringFilterComparator, value: String) -> Self {
+        WorkerFilter::Label(WorkerLabelFilter::new(name, comparator, value))
+    }
+
     pub fn new_version(comparator: FilterComparator, value: ComponentVersion) -> Self {
         WorkerFilter::Version(WorkerVersionFilter::new(comparator, value))
     }
@@ -1814,6 +2007,9 @@ impl Display for WorkerFilter {
             WorkerFilter::Env(filter) => {
                 write!(f, "{}", filter)
             }
+            WorkerFilter::Label(filter) => {
+                write!(f, "{}", filter)
+            }
             WorkerFilter::Not(filter) => {
                 write!(f, "{}", filter)
             }
@@ -1831,7 +2027,11 @@ impl FromStr for WorkerFilter {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let elements = s.split_whitespace().collect::<Vec<&str>>();
+        let mut elements = s.split_whitespace().collect::<Vec<&str>>();
+        if elements.len() == 1 {
+            // Compact form without whitespace, for example `label.team=payments`
+            elements = split_compact_filter(elements[0]).unwrap_or(elements);
+        }
 
         if elements.len() == 3 {
             let arg = elements[0];
@@ -1864,6 +2064,14 @@ impl FromStr for WorkerFilter {
                         value.to_string(),
                     ))
                 }
+                _ if arg.starts_with("label.") => {
+                    let name = &arg[6..];
+                    Ok(WorkerFilter::new_label(
+                        name.to_string(),
+                        comparator.parse()?,
+                        value.to_string(),
+                    ))
+                }
                 _ => Err(format!("Invalid filter: {}", s)),
             }
         } else {
@@ -1872,6 +2080,22 @@ impl FromStr for WorkerFilter {
     }
 }
 
+/// Splits a filter written without whitespace around the comparator into its three elements
+fn split_compact_filter(s: &str) -> Option<Vec<&str>> {
+    const COMPARATORS: [&str; 7] = ["==", "!=", ">=", "<=", "=", ">", "<"];
+
+    let (position, comparator) = COMPARATORS
+        .iter()
+        .filter_map(|comparator| s.find(comparator).map(|position| (position, *comparator)))
+        .min_by_key(|(position, comparator)| (*position, usize::MAX - comparator.len()))?;
+    let value = &s[position + comparator.len()..];
+    if position == 0 || value.is_empty() {
+        None
+    } else {
+        Some(vec![&s[..position], comparator, value])
+    }
+}
+
 impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerFilter> for WorkerFilter {
     type Error = String;
 
@@ -1905,6 +2129,13 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerFilter> for WorkerFilte
                 golem_api_grpc::proto::golem::worker::worker_filter::Filter::Env(filter) => Ok(
                     WorkerFilter::new_env(filter.name, filter.comparator.try_into()?, filter.value),
                 ),
+                golem_api_grpc::proto::golem::worker::worker_filter::Filter::Label(filter) => {
+                    Ok(WorkerFilter::new_label(
+                        filter.name,
+                        filter.comparator.try_into()?,
+                        filter.value,
+                    ))
+                }
                 golem_api_grpc::proto::golem::worker::worker_filter::Filter::Not(filter) => {
                     let filter = *filter.filter.ok_or_else(|| "Missing filter".to_string())?;
                     Ok(WorkerFilter::new_not(filter.try_into()?))
@@ -1967,6 +2198,17 @@ impl From<WorkerFilter> for golem_api_grpc::proto::golem::worker::WorkerFilter {
                     value,
                 },
             ),
+            WorkerFilter::Label(WorkerLabelFilter {
+                name,
+                comparator,
+                value,
+            }) => golem_api_grpc::proto::golem::worker::worker_filter::Filter::Label(
+                golem_api_grpc::proto::golem::worker::WorkerLabelFilter {
+                    name,
+                    comparator: comparator.into(),
+                    value,
+                },
+            ),
             WorkerFilter::Status(WorkerStatusFilter { comparator, value }) => {
                 golem_api_grpc::proto::golem::worker::worker_filter::Filter::Status(
                     golem_api_grpc::proto::golem::worker::WorkerStatusFilter {
@@ -2340,6 +2582,13 @@ pub enum WorkerEvent {
         function: String,
         idempotency_key: IdempotencyKey,
     },
+    /// The worker failed permanently after exhausting its retries
+    WorkerFailed {
+        timestamp: Timestamp,
+        error: String,
+        function: Option<String>,
+        idempotency_key: Option<IdempotencyKey>,
+    },
     Close,
 }
 
@@ -2383,6 +2632,19 @@ impl WorkerEvent {
         }
     }
 
+    pub fn worker_failed(
+        error: &str,
+        function: Option<&str>,
+        idempotency_key: Option<&IdempotencyKey>,
+    ) -> WorkerEvent {
+        WorkerEvent::WorkerFailed {
+            timestamp: Timestamp::now_utc(),
+            error: error.to_string(),
+            function: function.map(|function| function.to_string()),
+            idempotency_key: idempotency_key.cloned(),
+        }
+    }
+
     pub fn as_oplog_entry(&self) -> Option<OplogEntry> {
         match self {
             WorkerEvent::StdOut { timestamp, bytes } => Some(OplogEntry::Log {
@@ -2417,6 +2679,7 @@ impl WorkerEvent {
             }),
             WorkerEvent::InvocationStart { .. } => None,
             WorkerEvent::InvocationFinished { .. } => None,
+            WorkerEvent::WorkerFailed { .. } => None,
             WorkerEvent::Close => None,
         }
     }
@@ -2461,6 +2724,9 @@ impl Display for WorkerEvent {
             } => {
                 write!(f, "<invocation-finished> {} {}", function, idempotency_key)
             }
+            WorkerEvent::WorkerFailed { error, .. } => {
+                write!(f, "<worker-failed> {}", error)
+            }
             WorkerEvent::Close => {
                 write!(f, "<close>")
             }
@@ -2516,6 +2782,14 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::LogEvent> for WorkerEvent {
                         .ok_or("Missing idempotency key")?
                         .into(),
                 }),
+                golem_api_grpc::proto::golem::worker::log_event::Event::WorkerFailed(event) => {
+                    Ok(WorkerEvent::WorkerFailed {
+                        timestamp: event.timestamp.ok_or("Missing timestamp")?.into(),
+                        error: event.error,
+                        function: event.function,
+                        idempotency_key: event.idempotency_key.map(|key| key.into()),
+                    })
+                }
             },
             None => Err("Missing event".to_string()),
         }
@@ -2591,6 +2865,21 @@ impl TryFrom<WorkerEvent> for golem_api_grpc::proto::golem::worker::LogEvent {
                     },
                 )),
             }),
+            WorkerEvent::WorkerFailed {
+                timestamp,
+                error,
+                function,
+                idempotency_key,
+            } => Ok(golem::worker::LogEvent {
+                event: Some(golem::worker::log_event::Event::WorkerFailed(
+                    golem::worker::WorkerFailed {
+                        timestamp: Some(timestamp.into()),
+                        error,
+                        function,
+                        idempotency_key: idempotency_key.map(|key| key.into()),
+                    },
+                )),
+            }),
             WorkerEvent::Close => Err("Close event is not supported via protobuf".to_string()),
         }
     }
@@ -2726,6 +3015,26 @@ impl Display for ComponentFilePath {
     }
 }
 
+impl Encode for ComponentFilePath {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.0.as_str().encode(encoder)
+    }
+}
+
+impl Decode for ComponentFilePath {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let path: String = Decode::decode(decoder)?;
+        ComponentFilePath::from_abs_str(&path).map_err(DecodeError::OtherString)
+    }
+}
+
+impl<'de> BorrowDecode<'de> for ComponentFilePath {
+    fn borrow_decode<D: BorrowDecoder<'de>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let path: String = BorrowDecode::borrow_decode(decoder)?;
+        ComponentFilePath::from_abs_str(&path).map_err(DecodeError::OtherString)
+    }
+}
+
 impl poem_openapi::types::Type for ComponentFilePath {
     const IS_REQUIRED: bool = true;
 
@@ -3120,7 +3429,7 @@ impl From<GatewayBindingType> for golem_api_grpc::proto::golem::apidefinition::G
 mod tests {
     use test_r::test;
 
-    use std::collections::HashSet;
+    use std::collections::{BTreeMap, HashSet};
     use std::str::FromStr;
     use std::time::SystemTime;
     use std::vec;
@@ -3131,7 +3440,7 @@ mod tests {
         AccountId, ComponentFilePath, ComponentFilePermissions, ComponentId, Empty,
         FilterComparator, IdempotencyKey, InitialComponentFile, InitialComponentFileKey, ShardId,
         StringFilterComparator, TargetWorkerId, Timestamp, WorkerFilter, WorkerId, WorkerMetadata,
-        WorkerStatus, WorkerStatusRecord,
+        WorkerStatus, WorkerStatusRecord, WorkerStatusRecordExtensions,
     };
     use bincode::{Decode, Encode};
 
@@ -3204,6 +3513,26 @@ mod tests {
                 "abc".to_string(),
             )
         );
+
+        assert_eq!(
+            WorkerFilter::from_str("label.team=payments").unwrap(),
+            WorkerFilter::new_label(
+                "team".to_string(),
+                StringFilterComparator::Equal,
+                "payments".to_string(),
+            )
+        );
+
+        assert_eq!(
+            WorkerFilter::from_str("label.team != payments").unwrap(),
+            WorkerFilter::new_label(
+                "team".to_string(),
+                StringFilterComparator::NotEqual,
+                "payments".to_string(),
+            )
+        );
+
+        assert!(WorkerFilter::from_str("label.team").is_err());
     }
 
     #[test]
@@ -3317,10 +3646,28 @@ mod tests {
             parent: None,
             last_known_status: WorkerStatusRecord {
                 component_version: 1,
+                extensions: WorkerStatusRecordExtensions::Extension2 {
+                    active_plugins: HashSet::new(),
+                    labels: BTreeMap::from([("team".to_string(), "payments".to_string())]),
+                },
                 ..WorkerStatusRecord::default()
             },
         };
 
+        assert!(WorkerFilter::new_label(
+            "team".to_string(),
+            StringFilterComparator::Equal,
+            "payments".to_string(),
+        )
+        .matches(&worker_metadata));
+
+        assert!(!WorkerFilter::new_label(
+            "owner".to_string(),
+            StringFilterComparator::Equal,
+            "payments".to_string(),
+        )
+        .matches(&worker_metadata));
+
         assert!(
             WorkerFilter::new_name(StringFilterComparator::Equal, "worker-1".to_string())
                 .and(WorkerFilter::new_status(
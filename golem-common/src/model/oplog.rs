@@ -15,8 +15,8 @@
 use crate::config::RetryConfig;
 use crate::model::regions::OplogRegion;
 use crate::model::{
-    AccountId, ComponentVersion, IdempotencyKey, PluginInstallationId, Timestamp, WorkerId,
-    WorkerInvocation,
+    AccountId, ComponentFilePath, ComponentVersion, IdempotencyKey, InvocationOptions,
+    PluginInstallationId, Timestamp, WorkerId, WorkerInvocation,
 };
 use bincode::de::read::Reader;
 use bincode::de::{BorrowDecoder, Decoder};
@@ -29,7 +29,7 @@ use golem_wasm_ast::analysis::AnalysedType;
 use golem_wasm_rpc::{IntoValue, Value};
 use poem_openapi::{Enum, NewType};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt::{Display, Formatter};
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
@@ -319,8 +319,8 @@ pub enum OplogEntry {
         response: OplogPayload,
         wrapped_function_type: WrappedFunctionType,
     },
-    /// The worker has been invoked
-    ExportedFunctionInvoked {
+    /// The worker has been invoked (previous version without invocation options)
+    ExportedFunctionInvokedV1 {
         timestamp: Timestamp,
         function_name: String,
         request: OplogPayload,
@@ -466,6 +466,45 @@ pub enum OplogEntry {
         new_component_size: u64,
         new_active_plugins: HashSet<PluginInstallationId>,
     },
+    /// Replaces the worker's labels
+    UpdateLabels {
+        timestamp: Timestamp,
+        labels: BTreeMap<String, String>,
+    },
+    /// A file was written into the worker's filesystem from outside of the worker
+    PutFile {
+        timestamp: Timestamp,
+        path: ComponentFilePath,
+        content: OplogPayload,
+    },
+    /// A file or an empty directory was deleted from the worker's filesystem from outside of the worker
+    DeleteFile {
+        timestamp: Timestamp,
+        path: ComponentFilePath,
+    },
+    /// A directory was created in the worker's filesystem from outside of the worker
+    MakeDirectory {
+        timestamp: Timestamp,
+        path: ComponentFilePath,
+    },
+    /// A pending invocation was removed from the worker's invocation queue before it got started
+    CancelPendingInvocation {
+        timestamp: Timestamp,
+        idempotency_key: IdempotencyKey,
+    },
+    /// The worker has been invoked
+    ExportedFunctionInvoked {
+        timestamp: Timestamp,
+        function_name: String,
+        request: OplogPayload,
+        idempotency_key: IdempotencyKey,
+        options: InvocationOptions,
+    },
+    /// An invocation was abandoned because it did not finish before its deadline
+    InvocationTimedOut {
+        timestamp: Timestamp,
+        idempotency_key: IdempotencyKey,
+    },
 }
 
 impl OplogEntry {
@@ -661,6 +700,60 @@ impl OplogEntry {
         }
     }
 
+    pub fn update_labels(labels: BTreeMap<String, String>) -> OplogEntry {
+        OplogEntry::UpdateLabels {
+            timestamp: Timestamp::now_utc(),
+            labels,
+        }
+    }
+
+    pub fn put_file(path: ComponentFilePath, content: OplogPayload) -> OplogEntry {
+        OplogEntry::PutFile {
+            timestamp: Timestamp::now_utc(),
+            path,
+            content,
+        }
+    }
+
+    pub fn delete_file(path: ComponentFilePath) -> OplogEntry {
+        OplogEntry::DeleteFile {
+            timestamp: Timestamp::now_utc(),
+            path,
+        }
+    }
+
+    pub fn make_directory(path: ComponentFilePath) -> OplogEntry {
+        OplogEntry::MakeDirectory {
+            timestamp: Timestamp::now_utc(),
+            path,
+        }
+    }
+
+    pub fn cancel_pending_invocation(idempotency_key: IdempotencyKey) -> OplogEntry {
+        OplogEntry::CancelPendingInvocation {
+            timestamp: Timestamp::now_utc(),
+            idempotency_key,
+        }
+    }
+
+    pub fn invocation_timed_out(idempotency_key: IdempotencyKey) -> OplogEntry {
+        OplogEntry::InvocationTimedOut {
+            timestamp: Timestamp::now_utc(),
+            idempotency_key,
+        }
+    }
+
+    /// True if the oplog entry records a change of the worker's filesystem made from outside
+    /// of the worker, which has to be reapplied during replay
+    pub fn is_file_system_change(&self) -> bool {
+        matches!(
+            self,
+            OplogEntry::PutFile { .. }
+                | OplogEntry::DeleteFile { .. }
+                | OplogEntry::MakeDirectory { .. }
+        )
+    }
+
     pub fn is_end_atomic_region(&self, idx: OplogIndex) -> bool {
         matches!(self, OplogEntry::EndAtomicRegion { begin_index, .. } if *begin_index == idx)
     }
@@ -713,6 +806,12 @@ impl OplogEntry {
                 | OplogEntry::Restart { .. }
                 | OplogEntry::ActivatePlugin { .. }
                 | OplogEntry::DeactivatePlugin { .. }
+                | OplogEntry::UpdateLabels { .. }
+                | OplogEntry::PutFile { .. }
+                | OplogEntry::DeleteFile { .. }
+                | OplogEntry::MakeDirectory { .. }
+                | OplogEntry::CancelPendingInvocation { .. }
+                | OplogEntry::InvocationTimedOut { .. }
         )
     }
 
@@ -720,7 +819,7 @@ impl OplogEntry {
         match self {
             OplogEntry::Create { timestamp, .. }
             | OplogEntry::ImportedFunctionInvokedV1 { timestamp, .. }
-            | OplogEntry::ExportedFunctionInvoked { timestamp, .. }
+            | OplogEntry::ExportedFunctionInvokedV1 { timestamp, .. }
             | OplogEntry::ExportedFunctionCompleted { timestamp, .. }
             | OplogEntry::Suspend { timestamp }
             | OplogEntry::Error { timestamp, .. }
@@ -747,7 +846,14 @@ impl OplogEntry {
             | OplogEntry::CreateV1 { timestamp, .. }
             | OplogEntry::SuccessfulUpdateV1 { timestamp, .. }
             | OplogEntry::ActivatePlugin { timestamp, .. }
-            | OplogEntry::DeactivatePlugin { timestamp, .. } => *timestamp,
+            | OplogEntry::DeactivatePlugin { timestamp, .. }
+            | OplogEntry::UpdateLabels { timestamp, .. }
+            | OplogEntry::PutFile { timestamp, .. }
+            | OplogEntry::DeleteFile { timestamp, .. }
+            | OplogEntry::MakeDirectory { timestamp, .. }
+            | OplogEntry::CancelPendingInvocation { timestamp, .. }
+            | OplogEntry::ExportedFunctionInvoked { timestamp, .. }
+            | OplogEntry::InvocationTimedOut { timestamp, .. } => *timestamp,
         }
     }
 
@@ -835,6 +941,7 @@ pub enum WorkerError {
     InvalidRequest(String),
     StackOverflow,
     OutOfMemory,
+    InvocationTimedOut,
 }
 
 impl WorkerError {
@@ -849,6 +956,7 @@ impl WorkerError {
             WorkerError::InvalidRequest(message) => format!("{message}{error_logs}"),
             WorkerError::StackOverflow => format!("Stack overflow{error_logs}"),
             WorkerError::OutOfMemory => format!("Out of memory{error_logs}"),
+            WorkerError::InvocationTimedOut => format!("Invocation timed out{error_logs}"),
         }
     }
 }
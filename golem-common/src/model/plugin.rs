@@ -1,3 +1,4 @@
+use crate::model::secret::SecretReference;
 use crate::model::{
     AccountId, ComponentId, ComponentVersion, Empty, HasAccountId, PluginInstallationId,
 };
@@ -15,6 +16,8 @@ use sqlx::{Postgres, Sqlite};
 use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
 use std::str::FromStr;
+use std::time::Duration;
+use url::Url;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
@@ -368,6 +371,9 @@ impl<Scope: PluginScope> PluginDefinitionWithoutOwner<Scope> {
 pub enum PluginType {
     ComponentTransformer = 0,
     OplogProcessor = 1,
+    WasmComponentTransformer = 2,
+    OplogArchiveSink = 3,
+    OplogWebhookSink = 4,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Union)]
@@ -376,6 +382,9 @@ pub enum PluginType {
 pub enum PluginTypeSpecificDefinition {
     ComponentTransformer(ComponentTransformerDefinition),
     OplogProcessor(OplogProcessorDefinition),
+    WasmComponentTransformer(WasmComponentTransformerDefinition),
+    OplogArchiveSink(OplogArchiveSinkDefinition),
+    OplogWebhookSink(OplogWebhookSinkDefinition),
 }
 
 impl PluginTypeSpecificDefinition {
@@ -385,6 +394,11 @@ impl PluginTypeSpecificDefinition {
                 PluginType::ComponentTransformer
             }
             PluginTypeSpecificDefinition::OplogProcessor(_) => PluginType::OplogProcessor,
+            PluginTypeSpecificDefinition::WasmComponentTransformer(_) => {
+                PluginType::WasmComponentTransformer
+            }
+            PluginTypeSpecificDefinition::OplogArchiveSink(_) => PluginType::OplogArchiveSink,
+            PluginTypeSpecificDefinition::OplogWebhookSink(_) => PluginType::OplogWebhookSink,
         }
     }
 }
@@ -399,6 +413,15 @@ impl From<PluginTypeSpecificDefinition>
             },
             PluginTypeSpecificDefinition::OplogProcessor(value) => golem_api_grpc::proto::golem::component::PluginTypeSpecificDefinition {
                 definition: Some(golem_api_grpc::proto::golem::component::plugin_type_specific_definition::Definition::OplogProcessor(value.into()))
+            },
+            PluginTypeSpecificDefinition::WasmComponentTransformer(value) => golem_api_grpc::proto::golem::component::PluginTypeSpecificDefinition {
+                definition: Some(golem_api_grpc::proto::golem::component::plugin_type_specific_definition::Definition::WasmComponentTransformer(value.into()))
+            },
+            PluginTypeSpecificDefinition::OplogArchiveSink(value) => golem_api_grpc::proto::golem::component::PluginTypeSpecificDefinition {
+                definition: Some(golem_api_grpc::proto::golem::component::plugin_type_specific_definition::Definition::OplogArchiveSink(value.into()))
+            },
+            PluginTypeSpecificDefinition::OplogWebhookSink(value) => golem_api_grpc::proto::golem::component::PluginTypeSpecificDefinition {
+                definition: Some(golem_api_grpc::proto::golem::component::plugin_type_specific_definition::Definition::OplogWebhookSink(value.into()))
             }
         }
     }
@@ -415,6 +438,9 @@ impl TryFrom<golem_api_grpc::proto::golem::component::PluginTypeSpecificDefiniti
         match value.definition.ok_or("Missing plugin type specific definition")? {
             golem_api_grpc::proto::golem::component::plugin_type_specific_definition::Definition::ComponentTransformer(value) => Ok(PluginTypeSpecificDefinition::ComponentTransformer(value.try_into()?)),
             golem_api_grpc::proto::golem::component::plugin_type_specific_definition::Definition::OplogProcessor(value) => Ok(PluginTypeSpecificDefinition::OplogProcessor(value.try_into()?)),
+            golem_api_grpc::proto::golem::component::plugin_type_specific_definition::Definition::WasmComponentTransformer(value) => Ok(PluginTypeSpecificDefinition::WasmComponentTransformer(value.try_into()?)),
+            golem_api_grpc::proto::golem::component::plugin_type_specific_definition::Definition::OplogArchiveSink(value) => Ok(PluginTypeSpecificDefinition::OplogArchiveSink(value.into())),
+            golem_api_grpc::proto::golem::component::plugin_type_specific_definition::Definition::OplogWebhookSink(value) => Ok(PluginTypeSpecificDefinition::OplogWebhookSink(value.into())),
         }
     }
 }
@@ -506,6 +532,182 @@ impl TryFrom<golem_api_grpc::proto::golem::component::OplogProcessorDefinition>
     }
 }
 
+/// A component transformer implemented by a Golem component exporting the
+/// `golem:api/component-transformer` interface, executed by the component service itself
+/// instead of calling external HTTP services.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct WasmComponentTransformerDefinition {
+    pub provided_wit_package: Option<String>,
+    pub json_schema: Option<String>,
+    pub component_id: ComponentId,
+    pub component_version: ComponentVersion,
+}
+
+impl From<WasmComponentTransformerDefinition>
+    for golem_api_grpc::proto::golem::component::WasmComponentTransformerDefinition
+{
+    fn from(value: WasmComponentTransformerDefinition) -> Self {
+        golem_api_grpc::proto::golem::component::WasmComponentTransformerDefinition {
+            provided_wit_package: value.provided_wit_package,
+            json_schema: value.json_schema,
+            component_id: Some(value.component_id.into()),
+            component_version: value.component_version,
+        }
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::component::WasmComponentTransformerDefinition>
+    for WasmComponentTransformerDefinition
+{
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::component::WasmComponentTransformerDefinition,
+    ) -> Result<Self, Self::Error> {
+        Ok(WasmComponentTransformerDefinition {
+            provided_wit_package: value.provided_wit_package,
+            json_schema: value.json_schema,
+            component_id: value
+                .component_id
+                .ok_or("Missing component_id")?
+                .try_into()?,
+            component_version: value.component_version,
+        })
+    }
+}
+
+/// A built-in oplog processor appending the worker's oplog entries as JSON lines to the
+/// oplog export blob storage, without running a plugin component.
+///
+/// Exported batches are appended to the worker's current segment, named after the first oplog
+/// index it contains, so the archive of a worker can be read by listing its objects in order.
+/// A new segment is started once the current one reaches its maximum size or age.
+///
+/// Configured by the following installation parameters:
+/// - `max-segment-size`: the size in bytes after which a new segment is started (optional,
+///   defaults to 16 MiB)
+/// - `max-segment-age`: the age in seconds after which a new segment is started (optional,
+///   defaults to one day)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct OplogArchiveSinkDefinition {}
+
+impl OplogArchiveSinkDefinition {
+    pub const MAX_SEGMENT_SIZE_PARAMETER: &'static str = "max-segment-size";
+    pub const MAX_SEGMENT_AGE_PARAMETER: &'static str = "max-segment-age";
+
+    pub const DEFAULT_MAX_SEGMENT_SIZE: u64 = 16 * 1024 * 1024;
+    pub const DEFAULT_MAX_SEGMENT_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+    /// Checks the installation parameters
+    pub fn validate_parameters(parameters: &HashMap<String, String>) -> Result<(), String> {
+        Self::max_segment_size(parameters)?;
+        Self::max_segment_age(parameters)?;
+        Ok(())
+    }
+
+    /// Gets the size in bytes after which a new segment is started
+    pub fn max_segment_size(parameters: &HashMap<String, String>) -> Result<u64, String> {
+        Self::positive_parameter(parameters, Self::MAX_SEGMENT_SIZE_PARAMETER)
+            .map(|size| size.unwrap_or(Self::DEFAULT_MAX_SEGMENT_SIZE))
+    }
+
+    /// Gets the age after which a new segment is started
+    pub fn max_segment_age(parameters: &HashMap<String, String>) -> Result<Duration, String> {
+        Self::positive_parameter(parameters, Self::MAX_SEGMENT_AGE_PARAMETER).map(|age| {
+            age.map(Duration::from_secs)
+                .unwrap_or(Self::DEFAULT_MAX_SEGMENT_AGE)
+        })
+    }
+
+    fn positive_parameter(
+        parameters: &HashMap<String, String>,
+        name: &str,
+    ) -> Result<Option<u64>, String> {
+        match parameters.get(name) {
+            Some(value) => match value.parse::<u64>() {
+                Ok(value) if value > 0 => Ok(Some(value)),
+                _ => Err(format!(
+                    "The {name} parameter must be a positive integer, got {value}"
+                )),
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+impl From<OplogArchiveSinkDefinition>
+    for golem_api_grpc::proto::golem::component::OplogArchiveSinkDefinition
+{
+    fn from(_value: OplogArchiveSinkDefinition) -> Self {
+        golem_api_grpc::proto::golem::component::OplogArchiveSinkDefinition {}
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::component::OplogArchiveSinkDefinition>
+    for OplogArchiveSinkDefinition
+{
+    fn from(_value: golem_api_grpc::proto::golem::component::OplogArchiveSinkDefinition) -> Self {
+        OplogArchiveSinkDefinition {}
+    }
+}
+
+/// A built-in oplog processor posting batches of the worker's oplog entries as JSON to a
+/// webhook, without running a plugin component.
+///
+/// Configured by the following installation parameters:
+/// - `url`: the webhook's URL (required)
+/// - `authorization`: reference to the secret holding the value of the `Authorization` header
+///   sent with each request, in the form `${secret:NAME}` (optional)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct OplogWebhookSinkDefinition {}
+
+impl OplogWebhookSinkDefinition {
+    pub const URL_PARAMETER: &'static str = "url";
+    pub const AUTHORIZATION_PARAMETER: &'static str = "authorization";
+
+    /// Checks the installation parameters. The authorization header must refer to a secret, so
+    /// that its value is never stored in or returned with the plugin installation.
+    pub fn validate_parameters(parameters: &HashMap<String, String>) -> Result<(), String> {
+        match parameters.get(Self::URL_PARAMETER) {
+            Some(url) => {
+                Url::parse(url).map_err(|err| format!("Invalid webhook url {url}: {err}"))?;
+            }
+            None => return Err("Missing url parameter for the oplog webhook sink".to_string()),
+        }
+        if let Some(authorization) = parameters.get(Self::AUTHORIZATION_PARAMETER) {
+            if SecretReference::parse(authorization).is_none() {
+                return Err(
+                    "The authorization parameter must refer to a secret in the form ${secret:NAME}"
+                        .to_string(),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<OplogWebhookSinkDefinition>
+    for golem_api_grpc::proto::golem::component::OplogWebhookSinkDefinition
+{
+    fn from(_value: OplogWebhookSinkDefinition) -> Self {
+        golem_api_grpc::proto::golem::component::OplogWebhookSinkDefinition {}
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::component::OplogWebhookSinkDefinition>
+    for OplogWebhookSinkDefinition
+{
+    fn from(_value: golem_api_grpc::proto::golem::component::OplogWebhookSinkDefinition) -> Self {
+        OplogWebhookSinkDefinition {}
+    }
+}
+
 #[async_trait]
 pub trait PluginScope:
     Debug
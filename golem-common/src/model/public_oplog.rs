@@ -178,6 +178,18 @@ impl From<RetryConfig> for PublicRetryConfig {
     }
 }
 
+impl From<PublicRetryConfig> for RetryConfig {
+    fn from(value: PublicRetryConfig) -> Self {
+        RetryConfig {
+            max_attempts: value.max_attempts,
+            min_delay: value.min_delay,
+            max_delay: value.max_delay,
+            multiplier: value.multiplier,
+            max_jitter_factor: value.max_jitter_factor,
+        }
+    }
+}
+
 impl IntoValue for PublicRetryConfig {
     fn into_value(self) -> Value {
         Value::Record(vec![
@@ -824,6 +836,121 @@ impl IntoValue for DeactivatePluginParameters {
     }
 }
 
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Object)]
+pub struct UpdateLabelsParameters {
+    pub timestamp: Timestamp,
+    pub labels: BTreeMap<String, String>,
+}
+
+impl IntoValue for UpdateLabelsParameters {
+    fn into_value(self) -> Value {
+        Value::Record(vec![
+            self.timestamp.into_value(),
+            Value::List(
+                self.labels
+                    .into_iter()
+                    .map(|(k, v)| Value::Tuple(vec![k.into_value(), v.into_value()]))
+                    .collect::<Vec<Value>>(),
+            ),
+        ])
+    }
+
+    fn get_type() -> AnalysedType {
+        record(vec![
+            field("timestamp", Timestamp::get_type()),
+            field("labels", list(tuple(vec![str(), str()]))),
+        ])
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Object)]
+pub struct PutFileParameters {
+    pub timestamp: Timestamp,
+    pub path: String,
+    pub size: u64,
+}
+
+impl IntoValue for PutFileParameters {
+    fn into_value(self) -> Value {
+        Value::Record(vec![
+            self.timestamp.into_value(),
+            self.path.into_value(),
+            self.size.into_value(),
+        ])
+    }
+
+    fn get_type() -> AnalysedType {
+        record(vec![
+            field("timestamp", Timestamp::get_type()),
+            field("path", str()),
+            field("size", u64()),
+        ])
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Object)]
+pub struct FilePathParameters {
+    pub timestamp: Timestamp,
+    pub path: String,
+}
+
+impl IntoValue for FilePathParameters {
+    fn into_value(self) -> Value {
+        Value::Record(vec![self.timestamp.into_value(), self.path.into_value()])
+    }
+
+    fn get_type() -> AnalysedType {
+        record(vec![
+            field("timestamp", Timestamp::get_type()),
+            field("path", str()),
+        ])
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Object)]
+pub struct CancelPendingInvocationParameters {
+    pub timestamp: Timestamp,
+    pub idempotency_key: IdempotencyKey,
+}
+
+impl IntoValue for CancelPendingInvocationParameters {
+    fn into_value(self) -> Value {
+        Value::Record(vec![
+            self.timestamp.into_value(),
+            self.idempotency_key.into_value(),
+        ])
+    }
+
+    fn get_type() -> AnalysedType {
+        record(vec![
+            field("timestamp", Timestamp::get_type()),
+            field("idempotency-key", IdempotencyKey::get_type()),
+        ])
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Object)]
+pub struct InvocationTimedOutParameters {
+    pub timestamp: Timestamp,
+    pub idempotency_key: IdempotencyKey,
+}
+
+impl IntoValue for InvocationTimedOutParameters {
+    fn into_value(self) -> Value {
+        Value::Record(vec![
+            self.timestamp.into_value(),
+            self.idempotency_key.into_value(),
+        ])
+    }
+
+    fn get_type() -> AnalysedType {
+        record(vec![
+            field("timestamp", Timestamp::get_type()),
+            field("idempotency-key", IdempotencyKey::get_type()),
+        ])
+    }
+}
+
 /// A mirror of the core `OplogEntry` type, without the undefined arbitrary payloads.
 ///
 /// Instead, it encodes all payloads with wasm-rpc `Value` types. This makes this the base type
@@ -899,6 +1026,18 @@ pub enum PublicOplogEntry {
     ActivatePlugin(ActivatePluginParameters),
     /// Deactivates a plugin
     DeactivatePlugin(DeactivatePluginParameters),
+    /// Replaces the worker's labels
+    UpdateLabels(UpdateLabelsParameters),
+    /// A file was written into the worker's filesystem from outside of the worker
+    PutFile(PutFileParameters),
+    /// A file or an empty directory was deleted from the worker's filesystem
+    DeleteFile(FilePathParameters),
+    /// A directory was created in the worker's filesystem
+    MakeDirectory(FilePathParameters),
+    /// A pending invocation was removed from the invocation queue before it got started
+    CancelPendingInvocation(CancelPendingInvocationParameters),
+    /// An invocation was abandoned because it did not finish before its deadline
+    InvocationTimedOut(InvocationTimedOutParameters),
 }
 
 impl PublicOplogEntry {
@@ -1120,6 +1259,39 @@ impl PublicOplogEntry {
                 Self::string_match("deactivateplugin", &[], query_path, query)
                     || Self::string_match("deactivate-plugin", &[], query_path, query)
             }
+            PublicOplogEntry::UpdateLabels(params) => {
+                Self::string_match("updatelabels", &[], query_path, query)
+                    || Self::string_match("update-labels", &[], query_path, query)
+                    || params.labels.iter().any(|(k, v)| {
+                        Self::string_match(k, &[], query_path, query)
+                            || Self::string_match(v, &[], query_path, query)
+                    })
+            }
+            PublicOplogEntry::PutFile(params) => {
+                Self::string_match("putfile", &[], query_path, query)
+                    || Self::string_match("put-file", &[], query_path, query)
+                    || Self::string_match(&params.path, &[], query_path, query)
+            }
+            PublicOplogEntry::DeleteFile(params) => {
+                Self::string_match("deletefile", &[], query_path, query)
+                    || Self::string_match("delete-file", &[], query_path, query)
+                    || Self::string_match(&params.path, &[], query_path, query)
+            }
+            PublicOplogEntry::MakeDirectory(params) => {
+                Self::string_match("makedirectory", &[], query_path, query)
+                    || Self::string_match("make-directory", &[], query_path, query)
+                    || Self::string_match(&params.path, &[], query_path, query)
+            }
+            PublicOplogEntry::CancelPendingInvocation(params) => {
+                Self::string_match("cancelpendinginvocation", &[], query_path, query)
+                    || Self::string_match("cancel-pending-invocation", &[], query_path, query)
+                    || Self::string_match(&params.idempotency_key.value, &[], query_path, query)
+            }
+            PublicOplogEntry::InvocationTimedOut(params) => {
+                Self::string_match("invocationtimedout", &[], query_path, query)
+                    || Self::string_match("invocation-timed-out", &[], query_path, query)
+                    || Self::string_match(&params.idempotency_key.value, &[], query_path, query)
+            }
         }
     }
 
@@ -1410,6 +1582,30 @@ impl IntoValue for PublicOplogEntry {
                 case_idx: 26,
                 case_value: Some(Box::new(params.into_value())),
             },
+            PublicOplogEntry::UpdateLabels(params) => Value::Variant {
+                case_idx: 27,
+                case_value: Some(Box::new(params.into_value())),
+            },
+            PublicOplogEntry::PutFile(params) => Value::Variant {
+                case_idx: 28,
+                case_value: Some(Box::new(params.into_value())),
+            },
+            PublicOplogEntry::DeleteFile(params) => Value::Variant {
+                case_idx: 29,
+                case_value: Some(Box::new(params.into_value())),
+            },
+            PublicOplogEntry::MakeDirectory(params) => Value::Variant {
+                case_idx: 30,
+                case_value: Some(Box::new(params.into_value())),
+            },
+            PublicOplogEntry::CancelPendingInvocation(params) => Value::Variant {
+                case_idx: 31,
+                case_value: Some(Box::new(params.into_value())),
+            },
+            PublicOplogEntry::InvocationTimedOut(params) => Value::Variant {
+                case_idx: 32,
+                case_value: Some(Box::new(params.into_value())),
+            },
         }
     }
 
@@ -1457,6 +1653,18 @@ impl IntoValue for PublicOplogEntry {
             case("restart", Timestamp::get_type()),
             case("activate-plugin", ActivatePluginParameters::get_type()),
             case("deactivate-plugin", DeactivatePluginParameters::get_type()),
+            case("update-labels", UpdateLabelsParameters::get_type()),
+            case("put-file", PutFileParameters::get_type()),
+            case("delete-file", FilePathParameters::get_type()),
+            case("make-directory", FilePathParameters::get_type()),
+            case(
+                "cancel-pending-invocation",
+                CancelPendingInvocationParameters::get_type(),
+            ),
+            case(
+                "invocation-timed-out",
+                InvocationTimedOutParameters::get_type(),
+            ),
         ])
     }
 }
@@ -1743,6 +1951,47 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::OplogEntry> for PublicOplogEn
                         .try_into()?,
                 }),
             ),
+            oplog_entry::Entry::UpdateLabels(update) => {
+                Ok(PublicOplogEntry::UpdateLabels(UpdateLabelsParameters {
+                    timestamp: update.timestamp.ok_or("Missing timestamp field")?.into(),
+                    labels: update.labels.into_iter().collect(),
+                }))
+            }
+            oplog_entry::Entry::PutFile(put) => Ok(PublicOplogEntry::PutFile(PutFileParameters {
+                timestamp: put.timestamp.ok_or("Missing timestamp field")?.into(),
+                path: put.path,
+                size: put.size,
+            })),
+            oplog_entry::Entry::DeleteFile(delete) => {
+                Ok(PublicOplogEntry::DeleteFile(FilePathParameters {
+                    timestamp: delete.timestamp.ok_or("Missing timestamp field")?.into(),
+                    path: delete.path,
+                }))
+            }
+            oplog_entry::Entry::MakeDirectory(mkdir) => {
+                Ok(PublicOplogEntry::MakeDirectory(FilePathParameters {
+                    timestamp: mkdir.timestamp.ok_or("Missing timestamp field")?.into(),
+                    path: mkdir.path,
+                }))
+            }
+            oplog_entry::Entry::CancelPendingInvocation(cancel) => Ok(
+                PublicOplogEntry::CancelPendingInvocation(CancelPendingInvocationParameters {
+                    timestamp: cancel.timestamp.ok_or("Missing timestamp field")?.into(),
+                    idempotency_key: cancel
+                        .idempotency_key
+                        .ok_or("Missing idempotency_key field")?
+                        .into(),
+                }),
+            ),
+            oplog_entry::Entry::InvocationTimedOut(timed_out) => Ok(
+                PublicOplogEntry::InvocationTimedOut(InvocationTimedOutParameters {
+                    timestamp: timed_out.timestamp.ok_or("Missing timestamp field")?.into(),
+                    idempotency_key: timed_out
+                        .idempotency_key
+                        .ok_or("Missing idempotency_key field")?
+                        .into(),
+                }),
+            ),
         }
     }
 }
@@ -2068,6 +2317,65 @@ impl TryFrom<PublicOplogEntry> for golem_api_grpc::proto::golem::worker::OplogEn
                     )),
                 }
             }
+            PublicOplogEntry::UpdateLabels(update) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::UpdateLabels(
+                        golem_api_grpc::proto::golem::worker::UpdateLabelsParameters {
+                            timestamp: Some(update.timestamp.into()),
+                            labels: update.labels.into_iter().collect(),
+                        },
+                    )),
+                }
+            }
+            PublicOplogEntry::PutFile(put) => golem_api_grpc::proto::golem::worker::OplogEntry {
+                entry: Some(oplog_entry::Entry::PutFile(
+                    golem_api_grpc::proto::golem::worker::PutFileParameters {
+                        timestamp: Some(put.timestamp.into()),
+                        path: put.path,
+                        size: put.size,
+                    },
+                )),
+            },
+            PublicOplogEntry::DeleteFile(delete) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::DeleteFile(
+                        golem_api_grpc::proto::golem::worker::FilePathParameters {
+                            timestamp: Some(delete.timestamp.into()),
+                            path: delete.path,
+                        },
+                    )),
+                }
+            }
+            PublicOplogEntry::MakeDirectory(mkdir) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::MakeDirectory(
+                        golem_api_grpc::proto::golem::worker::FilePathParameters {
+                            timestamp: Some(mkdir.timestamp.into()),
+                            path: mkdir.path,
+                        },
+                    )),
+                }
+            }
+            PublicOplogEntry::CancelPendingInvocation(cancel) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::CancelPendingInvocation(
+                        golem_api_grpc::proto::golem::worker::CancelPendingInvocationParameters {
+                            timestamp: Some(cancel.timestamp.into()),
+                            idempotency_key: Some(cancel.idempotency_key.into()),
+                        },
+                    )),
+                }
+            }
+            PublicOplogEntry::InvocationTimedOut(timed_out) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::InvocationTimedOut(
+                        golem_api_grpc::proto::golem::worker::InvocationTimedOutParameters {
+                            timestamp: Some(timed_out.timestamp.into()),
+                            idempotency_key: Some(timed_out.idempotency_key.into()),
+                        },
+                    )),
+                }
+            }
         })
     }
 }
@@ -2373,14 +2681,16 @@ impl From<OplogCursor> for golem_api_grpc::proto::golem::worker::OplogCursor {
 #[cfg(test)]
 mod tests {
     use super::{
-        ChangeRetryPolicyParameters, CreateParameters, DescribeResourceParameters,
-        EndRegionParameters, ErrorParameters, ExportedFunctionCompletedParameters,
-        ExportedFunctionInvokedParameters, ExportedFunctionParameters, FailedUpdateParameters,
-        GrowMemoryParameters, ImportedFunctionInvokedParameters, JumpParameters, LogParameters,
-        PendingUpdateParameters, PendingWorkerInvocationParameters, PluginInstallationDescription,
-        PublicOplogEntry, PublicRetryConfig, PublicUpdateDescription, PublicWorkerInvocation,
-        PublicWrappedFunctionType, ResourceParameters, SnapshotBasedUpdateParameters,
-        SuccessfulUpdateParameters, TimestampParameter,
+        CancelPendingInvocationParameters, ChangeRetryPolicyParameters, CreateParameters,
+        DescribeResourceParameters, EndRegionParameters, ErrorParameters,
+        ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
+        ExportedFunctionParameters, FailedUpdateParameters, GrowMemoryParameters,
+        ImportedFunctionInvokedParameters, InvocationTimedOutParameters, JumpParameters,
+        LogParameters, PendingUpdateParameters, PendingWorkerInvocationParameters,
+        PluginInstallationDescription, PublicOplogEntry, PublicRetryConfig,
+        PublicUpdateDescription, PublicWorkerInvocation, PublicWrappedFunctionType,
+        ResourceParameters, SnapshotBasedUpdateParameters, SuccessfulUpdateParameters,
+        TimestampParameter,
     };
     use crate::model::oplog::{LogLevel, OplogIndex, WorkerResourceId};
     use crate::model::regions::OplogRegion;
@@ -2794,4 +3104,26 @@ mod tests {
         let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
         assert_eq!(entry, deserialized);
     }
+
+    #[test]
+    fn cancel_pending_invocation_serialization_poem_serde_equivalence() {
+        let entry = PublicOplogEntry::CancelPendingInvocation(CancelPendingInvocationParameters {
+            timestamp: rounded_ts(Timestamp::now_utc()),
+            idempotency_key: IdempotencyKey::new("idempotency_key".to_string()),
+        });
+        let serialized = entry.to_json_string();
+        let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(entry, deserialized);
+    }
+
+    #[test]
+    fn invocation_timed_out_serialization_poem_serde_equivalence() {
+        let entry = PublicOplogEntry::InvocationTimedOut(InvocationTimedOutParameters {
+            timestamp: rounded_ts(Timestamp::now_utc()),
+            idempotency_key: IdempotencyKey::new("idempotency_key".to_string()),
+        });
+        let serialized = entry.to_json_string();
+        let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(entry, deserialized);
+    }
 }
@@ -0,0 +1,158 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bincode::{Decode, Encode};
+use chrono::{DateTime, Utc};
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// A secret stored by the component service. Its value is never returned by the API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct Secret {
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct SecretCreation {
+    pub name: String,
+    pub value: String,
+}
+
+impl SecretCreation {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_secret_name(&self.name)
+    }
+}
+
+/// Exposes the value of a secret to the workers of a component as an environment variable.
+///
+/// The value is resolved by the worker executor when the worker starts, and it is never stored
+/// in the worker's metadata or oplog.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object, Encode, Decode)]
+pub struct SecretReference {
+    /// Name of the secret
+    pub name: String,
+    /// Name of the environment variable receiving the secret's value
+    pub env_var: String,
+}
+
+impl SecretReference {
+    /// Checks that the secret names are valid and that no two references set the same variable
+    pub fn validate_all(secrets: &[SecretReference]) -> Result<(), String> {
+        let mut env_vars = HashSet::new();
+        for secret in secrets {
+            validate_secret_name(&secret.name)?;
+            if secret.env_var.is_empty() || secret.env_var.contains(['=', '\0']) {
+                return Err(format!(
+                    "Invalid environment variable name for secret {}: {}",
+                    secret.name, secret.env_var
+                ));
+            }
+            if !env_vars.insert(&secret.env_var) {
+                return Err(format!(
+                    "Duplicate environment variable: {}",
+                    secret.env_var
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the name of the referenced secret if `value` has the form `${secret:NAME}`.
+    ///
+    /// Worker environment variables and injected egress headers can refer to secrets this way.
+    pub fn parse(value: &str) -> Option<&str> {
+        value
+            .strip_prefix("${secret:")
+            .and_then(|rest| rest.strip_suffix('}'))
+            .filter(|name| validate_secret_name(name).is_ok())
+    }
+}
+
+/// Secret names can contain letters, digits, '-', '_' and '.'
+pub fn validate_secret_name(name: &str) -> Result<(), String> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+    {
+        Err(format!("Invalid secret name: {name}"))
+    } else {
+        Ok(())
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::component::SecretReference> for SecretReference {
+    fn from(value: golem_api_grpc::proto::golem::component::SecretReference) -> Self {
+        Self {
+            name: value.name,
+            env_var: value.env_var,
+        }
+    }
+}
+
+impl From<SecretReference> for golem_api_grpc::proto::golem::component::SecretReference {
+    fn from(value: SecretReference) -> Self {
+        Self {
+            name: value.name,
+            env_var: value.env_var,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::model::secret::SecretReference;
+
+    #[test]
+    fn parse_secret_references() {
+        assert_eq!(
+            SecretReference::parse("${secret:api-token}"),
+            Some("api-token")
+        );
+        assert_eq!(SecretReference::parse("${secret:}"), None);
+        assert_eq!(SecretReference::parse("${secret:a b}"), None);
+        assert_eq!(SecretReference::parse("secret:api-token"), None);
+        assert_eq!(SecretReference::parse("prefix ${secret:api-token}"), None);
+    }
+
+    #[test]
+    fn invalid_secret_references() {
+        let reference = |name: &str, env_var: &str| SecretReference {
+            name: name.to_string(),
+            env_var: env_var.to_string(),
+        };
+
+        assert!(SecretReference::validate_all(&[
+            reference("db-password", "DB_PASSWORD"),
+            reference("db-password", "DATABASE_PASSWORD"),
+        ])
+        .is_ok());
+        assert!(SecretReference::validate_all(&[reference("db/password", "DB_PASSWORD")]).is_err());
+        assert!(SecretReference::validate_all(&[reference("db-password", "DB=PASSWORD")]).is_err());
+        assert!(SecretReference::validate_all(&[
+            reference("db-password", "DB_PASSWORD"),
+            reference("other-password", "DB_PASSWORD"),
+        ])
+        .is_err());
+    }
+}
@@ -208,6 +208,30 @@ impl<'a> RedisLabelledApi<'a> {
         )
     }
 
+    pub async fn eval<R, K, V>(&self, script: &str, keys: Vec<K>, args: V) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: AsRef<str>,
+        V: TryInto<MultipleValues> + Send,
+        V::Error: Into<RedisError> + Send,
+    {
+        self.ensure_connected().await?;
+        let start = Instant::now();
+        self.record(
+            start,
+            "EVAL",
+            self.pool
+                .eval(
+                    script,
+                    keys.iter()
+                        .map(|k| self.prefixed_key(k))
+                        .collect::<Vec<_>>(),
+                    args,
+                )
+                .await,
+        )
+    }
+
     pub async fn mget<R, K>(&self, keys: K) -> RedisResult<R>
     where
         R: FromRedis,
@@ -873,6 +897,22 @@ impl RedisTransaction {
             .await
     }
 
+    pub async fn expire<K>(&self, key: K, seconds: i64) -> RedisResult<()>
+    where
+        K: AsRef<str>,
+    {
+        self.trx.expire(self.prefixed_key(key), seconds).await
+    }
+
+    pub async fn hset<K, V>(&self, key: K, values: V) -> RedisResult<()>
+    where
+        K: AsRef<str>,
+        V: TryInto<RedisMap> + Send,
+        V::Error: Into<RedisError> + Send,
+    {
+        self.trx.hset(self.prefixed_key(key), values).await
+    }
+
     pub async fn sadd<K, V>(&self, key: K, members: V) -> RedisResult<()>
     where
         K: AsRef<str>,
@@ -22,7 +22,9 @@ use uuid::Uuid;
 use golem_common::config::{ConfigExample, ConfigLoader, HasConfigExamples, RetryConfig};
 use golem_common::tracing::TracingConfig;
 use golem_service_base::config::BlobStorageConfig;
-use golem_worker_executor_base::services::golem_config::CompiledComponentServiceConfig;
+use golem_worker_executor_base::services::golem_config::{
+    CompiledComponentServiceConfig, InstanceAllocatorConfig,
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ServerConfig {
@@ -37,6 +39,9 @@ pub struct ServerConfig {
     // Workers
     pub compile_worker: CompileWorkerConfig,
 
+    // Engine, has to match the worker executors' configuration
+    pub instance_allocator: InstanceAllocatorConfig,
+
     // GRPC
     pub grpc_host: String,
     pub grpc_port: u16,
@@ -85,6 +90,7 @@ impl Default for ServerConfig {
             compiled_component_service: Default::default(),
             blob_storage: BlobStorageConfig::default_local_file_system(),
             compile_worker: Default::default(),
+            instance_allocator: Default::default(),
             grpc_host: "0.0.0.0".to_string(),
             grpc_port: 9091,
             http_host: "0.0.0.0".to_string(),
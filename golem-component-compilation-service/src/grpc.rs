@@ -22,9 +22,11 @@ use async_trait::async_trait;
 use golem_api_grpc::proto::golem::common::{Empty, ErrorBody, ErrorsBody};
 use golem_api_grpc::proto::golem::component;
 use golem_api_grpc::proto::golem::componentcompilation::v1::component_compilation_service_server::ComponentCompilationService as GrpcCompilationServer;
+use golem_api_grpc::proto::golem::componentcompilation::v1::CompilationStatus as GrpcCompilationStatus;
 use golem_api_grpc::proto::golem::componentcompilation::v1::{
-    component_compilation_error, component_compilation_response, ComponentCompilationError,
-    ComponentCompilationRequest, ComponentCompilationResponse,
+    component_compilation_error, component_compilation_response, get_compilation_status_response,
+    CompilationState, ComponentCompilationError, ComponentCompilationRequest,
+    ComponentCompilationResponse, GetCompilationStatusRequest, GetCompilationStatusResponse,
 };
 use golem_common::grpc::proto_component_id_string;
 use golem_common::metrics::api::TraceErrorKind;
@@ -71,6 +73,33 @@ impl GrpcCompilationServer for CompileGrpcService {
             result: Some(response),
         }))
     }
+
+    async fn get_compilation_status(
+        &self,
+        request: Request<GetCompilationStatusRequest>,
+    ) -> Result<tonic::Response<GetCompilationStatusResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "get_compilation_status",
+            component_id = proto_component_id_string(&request.component_id),
+        );
+
+        let response = match self
+            .get_compilation_status_impl(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(status) => record.succeed(get_compilation_status_response::Result::Success(status)),
+            Err(error) => record.fail(
+                get_compilation_status_response::Result::Failure(error.clone()),
+                &ComponentCompilationTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(GetCompilationStatusResponse {
+            result: Some(response),
+        }))
+    }
 }
 
 impl CompileGrpcService {
@@ -85,6 +114,36 @@ impl CompileGrpcService {
             .await?;
         Ok(())
     }
+
+    async fn get_compilation_status_impl(
+        &self,
+        request: GetCompilationStatusRequest,
+    ) -> Result<GrpcCompilationStatus, ComponentCompilationError> {
+        let component_id = make_component_id(request.component_id)?;
+        let component_version = request.component_version;
+        let status = self
+            .service
+            .get_compilation_status(component_id, component_version)
+            .await?;
+        Ok(status.into())
+    }
+}
+
+impl From<crate::model::CompilationStatus> for GrpcCompilationStatus {
+    fn from(value: crate::model::CompilationStatus) -> Self {
+        let (state, error) = match value {
+            crate::model::CompilationStatus::Queued => (CompilationState::Queued, None),
+            crate::model::CompilationStatus::Compiling => (CompilationState::Compiling, None),
+            crate::model::CompilationStatus::Done => (CompilationState::Done, None),
+            crate::model::CompilationStatus::Failed { error } => {
+                (CompilationState::Failed, Some(error))
+            }
+        };
+        Self {
+            state: state as i32,
+            error,
+        }
+    }
 }
 
 impl From<crate::model::CompilationError> for ComponentCompilationError {
@@ -0,0 +1,64 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::CompilationError;
+use crate::service::CompilationService;
+use golem_common::model::ComponentId;
+use http_02::{Response, StatusCode};
+use std::sync::Arc;
+use warp::filters::BoxedFilter;
+use warp::hyper::Body;
+use warp::reply::Reply;
+use warp::{Filter, Rejection};
+
+/// `GET /v1/components/{component_id}/versions/{version}/compilation-status`
+pub fn compilation_status_route(
+    service: Arc<dyn CompilationService + Send + Sync>,
+) -> BoxedFilter<(Response<Body>,)> {
+    warp::path!("v1" / "components" / String / "versions" / u64 / "compilation-status")
+        .and(warp::get())
+        .and_then(move |component_id: String, component_version: u64| {
+            let service = service.clone();
+            async move {
+                let response = match ComponentId::try_from(component_id.as_str()) {
+                    Ok(component_id) => {
+                        match service
+                            .get_compilation_status(component_id, component_version)
+                            .await
+                        {
+                            Ok(status) => warp::reply::json(&status).into_response(),
+                            Err(err @ CompilationError::ComponentNotFound(_)) => {
+                                error_response(StatusCode::NOT_FOUND, err.to_string())
+                            }
+                            Err(err) => {
+                                error_response(StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+                            }
+                        }
+                    }
+                    Err(err) => error_response(StatusCode::BAD_REQUEST, err),
+                };
+                Ok::<_, Rejection>(response)
+            }
+        })
+        .boxed()
+}
+
+fn error_response(status: StatusCode, error: String) -> Response<Body> {
+    warp::reply::with_status(warp::reply::json(&ErrorBody { error }), status).into_response()
+}
+
+#[derive(serde::Serialize)]
+struct ErrorBody {
+    error: String,
+}
@@ -20,6 +20,7 @@ use golem_service_base::storage::blob::s3::S3BlobStorage;
 use golem_service_base::storage::blob::sqlite::SqliteBlobStorage;
 use golem_service_base::storage::blob::BlobStorage;
 use golem_service_base::storage::sqlite::SqlitePool;
+use golem_worker_executor_base::configure_instance_allocator;
 use golem_worker_executor_base::services::compiled_component;
 use grpc::CompileGrpcService;
 use prometheus::Registry;
@@ -38,6 +39,7 @@ use wasmtime::WasmBacktraceDetails;
 
 pub mod config;
 mod grpc;
+mod http;
 pub mod metrics;
 mod model;
 mod service;
@@ -93,17 +95,9 @@ pub async fn run(
     let compiled_component =
         compiled_component::configured(&config.compiled_component_service, blob_storage.clone());
 
-    let engine = wasmtime::Engine::new(&create_wasmtime_config()).expect("Failed to create engine");
-
-    // Start metrics and healthcheck server.
-    let address = config.http_addr().expect("Invalid HTTP address");
-    let http_port = golem_service_base::observability::start_health_and_metrics_server(
-        address,
-        prometheus,
-        "Component Compilation Service is running",
-        join_set,
-    )
-    .await?;
+    let mut wasmtime_config = create_wasmtime_config();
+    configure_instance_allocator(&mut wasmtime_config, &config.instance_allocator);
+    let engine = wasmtime::Engine::new(&wasmtime_config).expect("Failed to create engine");
 
     let compilation_service = ComponentCompilationServiceImpl::new(
         config.compile_worker,
@@ -114,6 +108,17 @@ pub async fn run(
 
     let compilation_service = Arc::new(compilation_service);
 
+    // Start metrics, healthcheck and compilation status server.
+    let address = config.http_addr().expect("Invalid HTTP address");
+    let http_port = golem_service_base::observability::start_health_and_metrics_server_with_routes(
+        address,
+        prometheus,
+        "Component Compilation Service is running",
+        http::compilation_status_route(compilation_service.clone()),
+        join_set,
+    )
+    .await?;
+
     let ipv4_address: Ipv4Addr = config.grpc_host.parse().expect("Invalid IP address");
     let address = SocketAddr::new(ipv4_address.into(), config.grpc_port);
 
@@ -160,6 +165,8 @@ async fn start_grpc_server(
     Ok(grpc_port)
 }
 
+/// Has to be kept in sync with `Bootstrap::create_wasmtime_config` of the worker executor, otherwise
+/// the compiled components get a different engine fingerprint and are never used.
 fn create_wasmtime_config() -> wasmtime::Config {
     let mut config = wasmtime::Config::default();
 
@@ -15,10 +15,11 @@
 use std::fmt::Display;
 
 use golem_common::model::ComponentId;
+use serde::Serialize;
 use tokio::sync::mpsc;
 use wasmtime::component::Component;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ComponentWithVersion {
     pub id: ComponentId,
     pub version: u64,
@@ -40,6 +41,15 @@ pub struct CompiledComponent {
     pub component: Component,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum CompilationStatus {
+    Queued,
+    Compiling,
+    Done,
+    Failed { error: String },
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum CompilationError {
     #[error("Component not found: {0}")]
@@ -29,11 +29,20 @@ pub trait CompilationService {
         component_id: ComponentId,
         component_version: u64,
     ) -> Result<(), CompilationError>;
+
+    async fn get_compilation_status(
+        &self,
+        component_id: ComponentId,
+        component_version: u64,
+    ) -> Result<CompilationStatus, CompilationError>;
 }
 
 #[derive(Clone)]
 pub struct ComponentCompilationServiceImpl {
     queue: mpsc::Sender<CompilationRequest>,
+    statuses: CompilationStatuses,
+    engine: Engine,
+    compiled_component_service: Arc<dyn CompiledComponentService + Send + Sync>,
 }
 
 impl ComponentCompilationServiceImpl {
@@ -47,6 +56,7 @@ impl ComponentCompilationServiceImpl {
     ) -> Self {
         let (compile_tx, compile_rx) = mpsc::channel(100);
         let (upload_tx, upload_rx) = mpsc::channel(100);
+        let statuses = CompilationStatuses::new();
 
         CompileWorker::start(
             component_service.uri(),
@@ -54,13 +64,24 @@ impl ComponentCompilationServiceImpl {
             compile_worker,
             engine.clone(),
             compiled_component_service.clone(),
+            statuses.clone(),
             upload_tx,
             compile_rx,
         );
 
-        UploadWorker::start(compiled_component_service.clone(), upload_rx);
+        UploadWorker::start(
+            engine.clone(),
+            compiled_component_service.clone(),
+            statuses.clone(),
+            upload_rx,
+        );
 
-        Self { queue: compile_tx }
+        Self {
+            queue: compile_tx,
+            statuses,
+            engine,
+            compiled_component_service,
+        }
     }
 }
 
@@ -82,8 +103,38 @@ impl CompilationService for ComponentCompilationServiceImpl {
                 version: component_version,
             },
         };
+        self.statuses
+            .set(&request.component, CompilationStatus::Queued);
         self.queue.send(request).await?;
         crate::metrics::increment_queue_length();
         Ok(())
     }
+
+    async fn get_compilation_status(
+        &self,
+        component_id: ComponentId,
+        component_version: u64,
+    ) -> Result<CompilationStatus, CompilationError> {
+        let component = ComponentWithVersion {
+            id: component_id,
+            version: component_version,
+        };
+        match self.statuses.get(&component) {
+            Some(status) => Ok(status),
+            None => {
+                // Not compiled by this instance, but an artifact compatible with the current
+                // engine may have been stored before
+                let exists = self
+                    .compiled_component_service
+                    .exists(&component.id, component.version, &self.engine)
+                    .await
+                    .map_err(|err| CompilationError::Unexpected(err.to_string()))?;
+                if exists {
+                    Ok(CompilationStatus::Done)
+                } else {
+                    Err(CompilationError::ComponentNotFound(component))
+                }
+            }
+        }
+    }
 }
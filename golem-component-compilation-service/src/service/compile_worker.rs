@@ -14,6 +14,7 @@
 
 use crate::config::CompileWorkerConfig;
 use crate::model::*;
+use crate::service::CompilationStatuses;
 use crate::UriBackConversion;
 use futures_util::TryStreamExt;
 use golem_api_grpc::proto::golem::component::v1::component_service_client::ComponentServiceClient;
@@ -51,6 +52,7 @@ pub struct CompileWorker {
     // Resources
     engine: Engine,
     compiled_component_service: Arc<dyn CompiledComponentService + Send + Sync>,
+    statuses: CompilationStatuses,
     client: GrpcClient<ComponentServiceClient<Channel>>,
 }
 
@@ -62,6 +64,7 @@ impl CompileWorker {
 
         engine: Engine,
         compiled_component_service: Arc<dyn CompiledComponentService + Send + Sync>,
+        statuses: CompilationStatuses,
 
         sender: mpsc::Sender<CompiledComponent>,
         mut recv: mpsc::Receiver<CompilationRequest>,
@@ -70,6 +73,7 @@ impl CompileWorker {
         let worker = Self {
             engine,
             compiled_component_service,
+            statuses,
             config: config.clone(),
             access_token,
             client: GrpcClient::new(
@@ -92,9 +96,23 @@ impl CompileWorker {
             async move {
                 while let Some(request) = recv.recv().await {
                     crate::metrics::decrement_queue_length();
+                    worker
+                        .statuses
+                        .set(&request.component, CompilationStatus::Compiling);
                     let result = worker.compile_component(&request.component).await;
                     match result {
-                        Err(_) => {}
+                        Err(err) => {
+                            tracing::warn!(
+                                "Failed to compile component {}: {err}",
+                                request.component
+                            );
+                            worker.statuses.set(
+                                &request.component,
+                                CompilationStatus::Failed {
+                                    error: err.to_string(),
+                                },
+                            );
+                        }
                         Ok(component) => {
                             tracing::info!("Compiled component {}", request.component);
                             let send_result = sender
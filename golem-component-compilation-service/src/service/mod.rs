@@ -14,8 +14,10 @@
 
 pub mod compile_service;
 mod compile_worker;
+mod status;
 mod upload_worker;
 
 pub use compile_service::CompilationService;
 pub use compile_worker::CompileWorker;
+pub use status::CompilationStatuses;
 pub use upload_worker::UploadWorker;
@@ -0,0 +1,157 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::{CompilationStatus, ComponentWithVersion};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+
+/// Keeps track of the compilation status of the components this instance has been asked to
+/// compile since it started.
+///
+/// Queued and running compilations are always kept, but only the most recent finished ones are
+/// remembered. Once a successful compilation is forgotten, its status is recovered from the
+/// existence of the compiled artifact.
+#[derive(Clone)]
+pub struct CompilationStatuses {
+    state: Arc<RwLock<State>>,
+    max_finished: usize,
+}
+
+#[derive(Default)]
+struct State {
+    statuses: HashMap<ComponentWithVersion, CompilationStatus>,
+    finished: VecDeque<ComponentWithVersion>,
+}
+
+impl CompilationStatuses {
+    const DEFAULT_MAX_FINISHED: usize = 4096;
+
+    pub fn new() -> Self {
+        Self::with_max_finished(Self::DEFAULT_MAX_FINISHED)
+    }
+
+    pub fn with_max_finished(max_finished: usize) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(State::default())),
+            max_finished,
+        }
+    }
+
+    pub fn set(&self, component: &ComponentWithVersion, status: CompilationStatus) {
+        let mut state = self.state.write().unwrap();
+        let finished = Self::is_finished(&status);
+        state.statuses.insert(component.clone(), status);
+
+        if finished {
+            state.finished.retain(|c| c != component);
+            state.finished.push_back(component.clone());
+
+            while state.finished.len() > self.max_finished {
+                if let Some(evicted) = state.finished.pop_front() {
+                    state.statuses.remove(&evicted);
+                }
+            }
+        } else {
+            state.finished.retain(|c| c != component);
+        }
+    }
+
+    pub fn get(&self, component: &ComponentWithVersion) -> Option<CompilationStatus> {
+        self.state.read().unwrap().statuses.get(component).cloned()
+    }
+
+    fn is_finished(status: &CompilationStatus) -> bool {
+        matches!(
+            status,
+            CompilationStatus::Done | CompilationStatus::Failed { .. }
+        )
+    }
+}
+
+impl Default for CompilationStatuses {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::CompilationStatuses;
+    use crate::model::{CompilationStatus, ComponentWithVersion};
+    use golem_common::model::ComponentId;
+    use uuid::Uuid;
+
+    fn component(version: u64) -> ComponentWithVersion {
+        ComponentWithVersion {
+            id: ComponentId(Uuid::new_v4()),
+            version,
+        }
+    }
+
+    #[test]
+    fn only_the_most_recent_finished_statuses_are_kept() {
+        let statuses = CompilationStatuses::with_max_finished(2);
+        let c1 = component(1);
+        let c2 = component(2);
+        let c3 = component(3);
+
+        statuses.set(&c1, CompilationStatus::Done);
+        statuses.set(
+            &c2,
+            CompilationStatus::Failed {
+                error: "failure".to_string(),
+            },
+        );
+        statuses.set(&c3, CompilationStatus::Done);
+
+        assert_eq!(statuses.get(&c1), None);
+        assert_eq!(
+            statuses.get(&c2),
+            Some(CompilationStatus::Failed {
+                error: "failure".to_string()
+            })
+        );
+        assert_eq!(statuses.get(&c3), Some(CompilationStatus::Done));
+    }
+
+    #[test]
+    fn pending_statuses_are_never_evicted() {
+        let statuses = CompilationStatuses::with_max_finished(1);
+        let queued = component(1);
+        let compiling = component(2);
+
+        statuses.set(&queued, CompilationStatus::Queued);
+        statuses.set(&compiling, CompilationStatus::Compiling);
+        for version in 3..10 {
+            statuses.set(&component(version), CompilationStatus::Done);
+        }
+
+        assert_eq!(statuses.get(&queued), Some(CompilationStatus::Queued));
+        assert_eq!(statuses.get(&compiling), Some(CompilationStatus::Compiling));
+    }
+
+    #[test]
+    fn recompiling_a_finished_component_makes_it_pending_again() {
+        let statuses = CompilationStatuses::with_max_finished(1);
+        let c1 = component(1);
+
+        statuses.set(&c1, CompilationStatus::Done);
+        statuses.set(&c1, CompilationStatus::Queued);
+        statuses.set(&component(2), CompilationStatus::Done);
+
+        assert_eq!(statuses.get(&c1), Some(CompilationStatus::Queued));
+    }
+}
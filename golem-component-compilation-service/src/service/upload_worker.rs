@@ -17,22 +17,30 @@ use std::sync::Arc;
 use golem_worker_executor_base::services::compiled_component::CompiledComponentService;
 use tokio::sync::mpsc;
 use tracing::Instrument;
+use wasmtime::Engine;
 
 use crate::model::*;
+use crate::service::CompilationStatuses;
 
 // Worker that uploads compiled components to the cloud.
 #[derive(Clone)]
 pub struct UploadWorker {
+    engine: Engine,
     compiled_component_service: Arc<dyn CompiledComponentService + Send + Sync>,
+    statuses: CompilationStatuses,
 }
 
 impl UploadWorker {
     pub fn start(
+        engine: Engine,
         compiled_component_service: Arc<dyn CompiledComponentService + Send + Sync>,
+        statuses: CompilationStatuses,
         mut recv: mpsc::Receiver<CompiledComponent>,
     ) {
         let worker = Self {
+            engine,
             compiled_component_service,
+            statuses,
         };
 
         tokio::spawn(
@@ -60,14 +68,23 @@ impl UploadWorker {
                 &component_and_version.id,
                 component_and_version.version,
                 &component,
+                &self.engine,
             )
             .await
             .map_err(|err| CompilationError::ComponentUploadFailed(err.to_string()));
 
         if let Err(ref err) = upload_result {
             tracing::warn!("Failed to upload compiled component {component_and_version}: {err:?}");
+            self.statuses.set(
+                &component_and_version,
+                CompilationStatus::Failed {
+                    error: err.to_string(),
+                },
+            );
         } else {
             tracing::info!("Successfully uploaded compiled component {component_and_version}");
+            self.statuses
+                .set(&component_and_version, CompilationStatus::Done);
         }
     }
 }
@@ -14,6 +14,7 @@
 
 use golem_common::model::Empty;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(tag = "type", content = "config")]
@@ -75,3 +76,88 @@ impl ComponentCompilationEnabledConfig {
             .expect("Failed to build ComponentCompilationService URI")
     }
 }
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SecretsConfig {
+    /// Hex encoded 256 bit key used to encrypt the secrets at rest. There is no default, the
+    /// component service does not start until it is set, for example through
+    /// `GOLEM__SECRETS__ENCRYPTION_KEY`.
+    pub encryption_key: Option<String>,
+    /// Access token the worker executors present when resolving the values of secrets. Secret
+    /// values are not returned to any other caller, and not at all while this is unset.
+    pub resolver_access_token: Option<String>,
+}
+
+/// Limits of the sandbox executing WASM component transformer plugins, and the cache of their
+/// compiled components
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WasmTransformerConfig {
+    pub max_memory_size: usize,
+    pub max_fuel: u64,
+    #[serde(with = "humantime_serde")]
+    pub timeout: Duration,
+    pub compiled_cache_capacity: usize,
+    #[serde(with = "humantime_serde")]
+    pub compiled_cache_time_to_idle: Duration,
+}
+
+impl Default for WasmTransformerConfig {
+    fn default() -> Self {
+        Self {
+            max_memory_size: 512 * 1024 * 1024,
+            max_fuel: 100_000_000_000,
+            timeout: Duration::from_secs(60),
+            compiled_cache_capacity: 32,
+            compiled_cache_time_to_idle: Duration::from_secs(12 * 60 * 60),
+        }
+    }
+}
+
+/// Retention policy of the uploaded component versions
+///
+/// Besides the latest `keep_last_versions` versions of each component, every version still used by
+/// a worker, an API definition or a plugin is kept.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ComponentRetentionConfig {
+    pub keep_last_versions: u64,
+    pub worker_service_host: String,
+    pub worker_service_port: u16,
+    pub gc: ComponentGcConfig,
+}
+
+impl ComponentRetentionConfig {
+    pub fn worker_service_uri(&self) -> http_02::Uri {
+        http_02::Uri::builder()
+            .scheme("http")
+            .authority(
+                format!("{}:{}", self.worker_service_host, self.worker_service_port).as_str(),
+            )
+            .path_and_query("/")
+            .build()
+            .expect("Failed to build WorkerService URI")
+    }
+}
+
+impl Default for ComponentRetentionConfig {
+    fn default() -> Self {
+        Self {
+            keep_last_versions: 10,
+            worker_service_host: "localhost".to_string(),
+            worker_service_port: 9007,
+            gc: ComponentGcConfig::Disabled(Empty {}),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "config")]
+pub enum ComponentGcConfig {
+    Enabled(ComponentGcEnabledConfig),
+    Disabled(Empty),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ComponentGcEnabledConfig {
+    #[serde(with = "humantime_serde")]
+    pub interval: Duration,
+}
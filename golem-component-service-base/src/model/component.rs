@@ -97,11 +97,13 @@ impl<Owner: ComponentOwner> Component<Owner> {
 
 impl<Owner: ComponentOwner> From<Component<Owner>> for golem_service_base::model::Component {
     fn from(value: Component<Owner>) -> Self {
+        let mut metadata = value.metadata;
+        metadata.egress_policy = metadata.egress_policy.map(|policy| policy.redacted());
         Self {
             versioned_component_id: value.versioned_component_id,
             component_name: value.component_name,
             component_size: value.component_size,
-            metadata: value.metadata,
+            metadata,
             created_at: Some(value.created_at),
             component_type: Some(value.component_type),
             files: value.files,
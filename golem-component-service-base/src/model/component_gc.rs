@@ -0,0 +1,60 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use golem_common::model::ComponentId;
+use golem_service_base::model::ComponentName;
+use poem_openapi::{Enum, Object};
+use serde::{Deserialize, Serialize};
+
+/// The component versions kept and removed by the component version garbage collection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ComponentGcReport {
+    pub components: Vec<ComponentGcReportEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ComponentGcReportEntry {
+    pub component_id: ComponentId,
+    pub component_name: ComponentName,
+    pub retained_versions: Vec<RetainedComponentVersion>,
+    pub removable_versions: Vec<u64>,
+    /// Size of the component binaries owned only by the removable versions
+    pub reclaimable_size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RetainedComponentVersion {
+    pub version: u64,
+    pub reasons: Vec<ComponentVersionRetentionReason>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Enum)]
+pub enum ComponentVersionRetentionReason {
+    /// One of the latest versions kept by the retention policy
+    Recent,
+    /// Used by at least one worker, either as its current version or as a target of an update
+    UsedByWorker,
+    /// Referenced by at least one API definition
+    UsedByApiDefinition,
+    /// Provides the implementation of a plugin
+    ProvidesPlugin,
+    /// The latest version satisfying the function constraints of the component
+    SatisfiesConstraints,
+}
@@ -13,8 +13,10 @@
 // limitations under the License.
 
 mod component;
+mod component_gc;
 
 pub use component::*;
+pub use component_gc::*;
 use golem_common::model::{ComponentFilePathWithPermissionsList, ComponentType};
 use golem_service_base::poem::TempFileUpload;
 use poem_openapi::types::multipart::Upload;
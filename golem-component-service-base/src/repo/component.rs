@@ -271,6 +271,14 @@ pub trait ComponentRepo<Owner: ComponentOwner>: Debug {
         updated_metadata: Vec<u8>,
     ) -> Result<(), RepoError>;
 
+    async fn update_metadata(
+        &self,
+        namespace: &str,
+        component_id: &Uuid,
+        component_version: i64,
+        metadata: Vec<u8>,
+    ) -> Result<(), RepoError>;
+
     async fn get(
         &self,
         namespace: &str,
@@ -304,6 +312,20 @@ pub trait ComponentRepo<Owner: ComponentOwner>: Debug {
 
     async fn delete(&self, namespace: &str, component_id: &Uuid) -> Result<(), RepoError>;
 
+    async fn delete_version(
+        &self,
+        namespace: &str,
+        component_id: &Uuid,
+        version: u64,
+    ) -> Result<(), RepoError>;
+
+    async fn get_namespaces(&self) -> Result<Vec<String>, RepoError>;
+
+    async fn get_versions_providing_plugins(
+        &self,
+        component_id: &Uuid,
+    ) -> Result<Vec<u64>, RepoError>;
+
     async fn create_or_update_constraint(
         &self,
         component_constraint_record: &ComponentConstraintsRecord,
@@ -448,6 +470,20 @@ impl<Owner: ComponentOwner, Repo: ComponentRepo<Owner> + Send + Sync> ComponentR
         Self::logged_with_id("activate", component_id, result)
     }
 
+    async fn update_metadata(
+        &self,
+        namespace: &str,
+        component_id: &Uuid,
+        component_version: i64,
+        metadata: Vec<u8>,
+    ) -> Result<(), RepoError> {
+        let result = self
+            .repo
+            .update_metadata(namespace, component_id, component_version, metadata)
+            .await;
+        Self::logged_with_id("update_metadata", component_id, result)
+    }
+
     async fn get(
         &self,
         namespace: &str,
@@ -508,6 +544,32 @@ impl<Owner: ComponentOwner, Repo: ComponentRepo<Owner> + Send + Sync> ComponentR
         Self::logged_with_id("delete", component_id, result)
     }
 
+    async fn delete_version(
+        &self,
+        namespace: &str,
+        component_id: &Uuid,
+        version: u64,
+    ) -> Result<(), RepoError> {
+        let result = self
+            .repo
+            .delete_version(namespace, component_id, version)
+            .await;
+        Self::logged_with_id("delete_version", component_id, result)
+    }
+
+    async fn get_namespaces(&self) -> Result<Vec<String>, RepoError> {
+        let result = self.repo.get_namespaces().await;
+        Self::logged("get_namespaces", result)
+    }
+
+    async fn get_versions_providing_plugins(
+        &self,
+        component_id: &Uuid,
+    ) -> Result<Vec<u64>, RepoError> {
+        let result = self.repo.get_versions_providing_plugins(component_id).await;
+        Self::logged_with_id("get_versions_providing_plugins", component_id, result)
+    }
+
     async fn create_or_update_constraint(
         &self,
         component_constraint_record: &ComponentConstraintsRecord,
@@ -992,6 +1054,31 @@ impl<Owner: ComponentOwner> ComponentRepo<Owner> for DbComponentRepo<sqlx::Postg
         Ok(())
     }
 
+    async fn update_metadata(
+        &self,
+        namespace: &str,
+        component_id: &Uuid,
+        component_version: i64,
+        metadata: Vec<u8>,
+    ) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+              UPDATE component_versions
+              SET metadata = $4
+              WHERE component_id IN (SELECT component_id FROM components WHERE namespace = $1 AND component_id = $2)
+                    AND version = $3
+            "#,
+        )
+        .bind(namespace)
+        .bind(component_id)
+        .bind(component_version)
+        .bind(metadata)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(())
+    }
+
     #[when(sqlx::Postgres -> get)]
     async fn get_postgres(
         &self,
@@ -1410,6 +1497,85 @@ impl<Owner: ComponentOwner> ComponentRepo<Owner> for DbComponentRepo<sqlx::Postg
         Ok(())
     }
 
+    async fn delete_version(
+        &self,
+        namespace: &str,
+        component_id: &Uuid,
+        version: u64,
+    ) -> Result<(), RepoError> {
+        let mut transaction = self.db_pool.begin().await?;
+
+        sqlx::query(
+            r#"
+                DELETE FROM component_files
+                WHERE component_id IN (SELECT component_id FROM components WHERE namespace = $1 AND component_id = $2)
+                  AND version = $3
+            "#
+        )
+            .bind(namespace)
+            .bind(component_id)
+            .bind(version as i64)
+            .execute(&mut *transaction)
+            .await?;
+
+        sqlx::query(
+            r#"
+                DELETE FROM component_plugin_installation
+                WHERE component_id IN (SELECT component_id FROM components WHERE namespace = $1 AND component_id = $2)
+                  AND component_version = $3
+            "#
+        )
+            .bind(namespace)
+            .bind(component_id)
+            .bind(version as i64)
+            .execute(&mut *transaction)
+            .await?;
+
+        sqlx::query(
+            r#"
+                DELETE FROM component_versions
+                WHERE component_id IN (SELECT component_id FROM components WHERE namespace = $1 AND component_id = $2)
+                  AND version = $3
+            "#
+        )
+            .bind(namespace)
+            .bind(component_id)
+            .bind(version as i64)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn get_namespaces(&self) -> Result<Vec<String>, RepoError> {
+        let result = sqlx::query("SELECT DISTINCT namespace FROM components")
+            .fetch_all(self.db_pool.deref())
+            .await?;
+
+        Ok(result.into_iter().map(|x| x.get("namespace")).collect())
+    }
+
+    async fn get_versions_providing_plugins(
+        &self,
+        component_id: &Uuid,
+    ) -> Result<Vec<u64>, RepoError> {
+        let result = sqlx::query(
+            r#"
+                SELECT DISTINCT component_version FROM plugins
+                WHERE component_id = $1 AND component_version IS NOT NULL AND NOT deleted
+            "#,
+        )
+        .bind(component_id)
+        .fetch_all(self.db_pool.deref())
+        .await?;
+
+        Ok(result
+            .into_iter()
+            .map(|x| x.get::<i64, _>("component_version") as u64)
+            .collect())
+    }
+
     async fn create_or_update_constraint(
         &self,
         component_constraint_record: &ComponentConstraintsRecord,
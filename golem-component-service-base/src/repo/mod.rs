@@ -14,3 +14,4 @@
 
 pub mod component;
 pub mod plugin;
+pub mod secret;
@@ -15,8 +15,9 @@
 use async_trait::async_trait;
 use conditional_trait_gen::trait_gen;
 use golem_common::model::plugin::{
-    ComponentTransformerDefinition, OplogProcessorDefinition, PluginDefinition, PluginOwner,
-    PluginScope, PluginTypeSpecificDefinition,
+    ComponentTransformerDefinition, OplogArchiveSinkDefinition, OplogProcessorDefinition,
+    OplogWebhookSinkDefinition, PluginDefinition, PluginOwner, PluginScope,
+    PluginTypeSpecificDefinition, WasmComponentTransformerDefinition,
 };
 use golem_common::model::ComponentId;
 use golem_common::repo::RowMeta;
@@ -42,13 +43,13 @@ pub struct PluginRecord<Owner: PluginOwner, Scope: PluginScope> {
     #[sqlx(flatten)]
     owner: Owner::Row,
 
-    // for ComponentTransformer plugin type
+    // for ComponentTransformer and WasmComponentTransformer plugin types
     provided_wit_package: Option<String>,
     json_schema: Option<String>,
     validate_url: Option<String>,
     transform_url: Option<String>,
 
-    // for OplogProcessor plugin type
+    // for OplogProcessor and WasmComponentTransformer plugin types
     component_id: Option<Uuid>,
     component_version: Option<i64>,
 
@@ -74,10 +75,16 @@ impl<Owner: PluginOwner, Scope: PluginScope> From<PluginDefinition<Owner, Scope>
                 PluginTypeSpecificDefinition::ComponentTransformer(def) => {
                     def.provided_wit_package.clone()
                 }
+                PluginTypeSpecificDefinition::WasmComponentTransformer(def) => {
+                    def.provided_wit_package.clone()
+                }
                 _ => None,
             },
             json_schema: match &value.specs {
                 PluginTypeSpecificDefinition::ComponentTransformer(def) => def.json_schema.clone(),
+                PluginTypeSpecificDefinition::WasmComponentTransformer(def) => {
+                    def.json_schema.clone()
+                }
                 _ => None,
             },
             validate_url: match &value.specs {
@@ -95,12 +102,18 @@ impl<Owner: PluginOwner, Scope: PluginScope> From<PluginDefinition<Owner, Scope>
 
             component_id: match &value.specs {
                 PluginTypeSpecificDefinition::OplogProcessor(def) => Some(def.component_id.0),
+                PluginTypeSpecificDefinition::WasmComponentTransformer(def) => {
+                    Some(def.component_id.0)
+                }
                 _ => None,
             },
             component_version: match &value.specs {
                 PluginTypeSpecificDefinition::OplogProcessor(def) => {
                     Some(def.component_version as i64)
                 }
+                PluginTypeSpecificDefinition::WasmComponentTransformer(def) => {
+                    Some(def.component_version as i64)
+                }
                 _ => None,
             },
             deleted: false,
@@ -138,6 +151,23 @@ impl<Owner: PluginOwner, Scope: PluginScope> TryFrom<PluginRecord<Owner, Scope>>
                     .map(|i| i as u64)
                     .ok_or("component_version is required for OplogProcessor rows")?,
             }),
+            2 => PluginTypeSpecificDefinition::WasmComponentTransformer(
+                WasmComponentTransformerDefinition {
+                    provided_wit_package: value.provided_wit_package,
+                    json_schema: value.json_schema,
+                    component_id: ComponentId(
+                        value
+                            .component_id
+                            .ok_or("component_id is required for WasmComponentTransformer rows")?,
+                    ),
+                    component_version: value
+                        .component_version
+                        .map(|i| i as u64)
+                        .ok_or("component_version is required for WasmComponentTransformer rows")?,
+                },
+            ),
+            3 => PluginTypeSpecificDefinition::OplogArchiveSink(OplogArchiveSinkDefinition {}),
+            4 => PluginTypeSpecificDefinition::OplogWebhookSink(OplogWebhookSinkDefinition {}),
             other => return Err(format!("Invalid plugin type: {other}")),
         };
 
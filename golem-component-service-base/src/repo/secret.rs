@@ -0,0 +1,190 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use conditional_trait_gen::trait_gen;
+use golem_service_base::repo::RepoError;
+use sqlx::{Database, Pool};
+use std::fmt::{Debug, Formatter};
+use std::ops::Deref;
+use std::sync::Arc;
+use tracing::{debug, error};
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct SecretRecord {
+    pub namespace: String,
+    pub name: String,
+    /// The encrypted value of the secret
+    pub value: Vec<u8>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[async_trait]
+pub trait SecretRepo: Debug {
+    /// Creates the secret, or replaces its value if it already exists
+    async fn create_or_update(&self, record: &SecretRecord) -> Result<(), RepoError>;
+
+    async fn get_all(&self, namespace: &str) -> Result<Vec<SecretRecord>, RepoError>;
+
+    async fn get(&self, namespace: &str, name: &str) -> Result<Option<SecretRecord>, RepoError>;
+
+    async fn delete(&self, namespace: &str, name: &str) -> Result<bool, RepoError>;
+}
+
+pub struct LoggedSecretRepo<Repo: SecretRepo> {
+    repo: Repo,
+}
+
+impl<Repo: SecretRepo> LoggedSecretRepo<Repo> {
+    pub fn new(repo: Repo) -> Self {
+        Self { repo }
+    }
+
+    fn logged<R>(message: &'static str, result: Result<R, RepoError>) -> Result<R, RepoError> {
+        match &result {
+            Ok(_) => debug!("{}", message),
+            Err(error) => error!(error = error.to_string(), "{message}"),
+        }
+        result
+    }
+
+    fn logged_with_name<R>(
+        message: &'static str,
+        secret_name: &str,
+        result: Result<R, RepoError>,
+    ) -> Result<R, RepoError> {
+        match &result {
+            Ok(_) => debug!(secret_name = secret_name, "{}", message),
+            Err(error) => error!(
+                secret_name = secret_name,
+                error = error.to_string(),
+                "{message}"
+            ),
+        }
+        result
+    }
+}
+
+impl<Repo: SecretRepo> Debug for LoggedSecretRepo<Repo> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.repo.fmt(f)
+    }
+}
+
+#[async_trait]
+impl<Repo: SecretRepo + Send + Sync> SecretRepo for LoggedSecretRepo<Repo> {
+    async fn create_or_update(&self, record: &SecretRecord) -> Result<(), RepoError> {
+        let result = self.repo.create_or_update(record).await;
+        Self::logged_with_name("create_or_update", &record.name, result)
+    }
+
+    async fn get_all(&self, namespace: &str) -> Result<Vec<SecretRecord>, RepoError> {
+        let result = self.repo.get_all(namespace).await;
+        Self::logged("get_all", result)
+    }
+
+    async fn get(&self, namespace: &str, name: &str) -> Result<Option<SecretRecord>, RepoError> {
+        let result = self.repo.get(namespace, name).await;
+        Self::logged_with_name("get", name, result)
+    }
+
+    async fn delete(&self, namespace: &str, name: &str) -> Result<bool, RepoError> {
+        let result = self.repo.delete(namespace, name).await;
+        Self::logged_with_name("delete", name, result)
+    }
+}
+
+pub struct DbSecretRepo<DB: Database> {
+    db_pool: Arc<Pool<DB>>,
+}
+
+impl<DB: Database> DbSecretRepo<DB> {
+    pub fn new(db_pool: Arc<Pool<DB>>) -> Self {
+        Self { db_pool }
+    }
+}
+
+impl<DB: Database> Debug for DbSecretRepo<DB> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DbSecretRepo")
+            .field("db_pool", &self.db_pool)
+            .finish()
+    }
+}
+
+#[trait_gen(sqlx::Postgres -> sqlx::Postgres, sqlx::Sqlite)]
+#[async_trait]
+impl SecretRepo for DbSecretRepo<sqlx::Postgres> {
+    async fn create_or_update(&self, record: &SecretRecord) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+              INSERT INTO secrets
+                (namespace, name, value, created_at)
+              VALUES
+                ($1, $2, $3, $4)
+              ON CONFLICT (namespace, name) DO UPDATE
+                SET value = excluded.value, created_at = excluded.created_at
+            "#,
+        )
+        .bind(&record.namespace)
+        .bind(&record.name)
+        .bind(&record.value)
+        .bind(record.created_at)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_all(&self, namespace: &str) -> Result<Vec<SecretRecord>, RepoError> {
+        sqlx::query_as::<_, SecretRecord>(
+            r#"
+                SELECT namespace, name, value, created_at
+                FROM secrets
+                WHERE namespace = $1
+                ORDER BY name
+            "#,
+        )
+        .bind(namespace)
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn get(&self, namespace: &str, name: &str) -> Result<Option<SecretRecord>, RepoError> {
+        sqlx::query_as::<_, SecretRecord>(
+            r#"
+                SELECT namespace, name, value, created_at
+                FROM secrets
+                WHERE namespace = $1 AND name = $2
+            "#,
+        )
+        .bind(namespace)
+        .bind(name)
+        .fetch_optional(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn delete(&self, namespace: &str, name: &str) -> Result<bool, RepoError> {
+        let result = sqlx::query("DELETE FROM secrets WHERE namespace = $1 AND name = $2")
+            .bind(namespace)
+            .bind(name)
+            .execute(self.db_pool.deref())
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
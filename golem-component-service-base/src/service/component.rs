@@ -18,6 +18,7 @@ use crate::repo::component::{record_metadata_serde, ComponentRecord, FileRecord}
 use crate::repo::component::{ComponentConstraintsRecord, ComponentRepo};
 use crate::service::component_compilation::ComponentCompilationService;
 use crate::service::component_object_store::ComponentObjectStore;
+use crate::service::component_transformer::WasmComponentTransformer;
 use crate::service::plugin::{PluginError, PluginService};
 use async_trait::async_trait;
 use async_zip::tokio::read::seek::ZipFileReader;
@@ -29,11 +30,16 @@ use golem_api_grpc::proto::golem::component::v1::component_error;
 use golem_common::config::RetryConfig;
 use golem_common::model::component::ComponentOwner;
 use golem_common::model::component_constraint::FunctionConstraintCollection;
-use golem_common::model::component_metadata::{ComponentMetadata, ComponentProcessingError};
+use golem_common::model::component_metadata::{
+    CapabilityPolicy, ComponentMetadata, ComponentProcessingError, ComponentVolume, EgressPolicy,
+    FailurePolicy, OplogPolicy,
+};
 use golem_common::model::plugin::{
-    ComponentPluginInstallationTarget, PluginInstallation, PluginInstallationCreation,
-    PluginInstallationUpdate, PluginScope, PluginTypeSpecificDefinition,
+    ComponentPluginInstallationTarget, OplogArchiveSinkDefinition, OplogWebhookSinkDefinition,
+    PluginInstallation, PluginInstallationCreation, PluginInstallationUpdate, PluginScope,
+    PluginTypeSpecificDefinition, WasmComponentTransformerDefinition,
 };
+use golem_common::model::secret::SecretReference;
 use golem_common::model::ComponentVersion;
 use golem_common::model::{AccountId, PluginInstallationId};
 use golem_common::model::{
@@ -104,6 +110,18 @@ pub enum ComponentError {
     InternalPluginError(#[from] Box<PluginError>),
     #[error("Component transformation failed: {0}")]
     TransformationFailed(TransformationFailedReason),
+    #[error("Invalid component volumes: {0}")]
+    InvalidVolumes(String),
+    #[error("Invalid capability policy: {0}")]
+    InvalidCapabilityPolicy(String),
+    #[error("Invalid egress policy: {0}")]
+    InvalidEgressPolicy(String),
+    #[error("Invalid secret references: {0}")]
+    InvalidSecrets(String),
+    #[error("Invalid failure policy: {0}")]
+    InvalidFailurePolicy(String),
+    #[error("Failed to look up the usages of the component versions: {0}")]
+    ComponentReferenceLookupError(String),
 }
 
 impl ComponentError {
@@ -177,6 +195,12 @@ impl SafeDisplay for ComponentError {
             ComponentError::TransformationPluginNotFound { .. } => self.to_string(),
             ComponentError::InternalPluginError(_) => self.to_string(),
             ComponentError::TransformationFailed(_) => self.to_string(),
+            ComponentError::InvalidVolumes(_) => self.to_string(),
+            ComponentError::InvalidCapabilityPolicy(_) => self.to_string(),
+            ComponentError::InvalidEgressPolicy(_) => self.to_string(),
+            ComponentError::InvalidSecrets(_) => self.to_string(),
+            ComponentError::InvalidFailurePolicy(_) => self.to_string(),
+            ComponentError::ComponentReferenceLookupError(_) => self.to_string(),
         }
     }
 }
@@ -259,6 +283,32 @@ impl From<ComponentError> for golem_api_grpc::proto::golem::component::v1::Compo
                     error: value.to_safe_string(),
                 })
             }
+            ComponentError::InvalidVolumes(_) => component_error::Error::BadRequest(ErrorsBody {
+                errors: vec![value.to_safe_string()],
+            }),
+            ComponentError::InvalidCapabilityPolicy(_) => {
+                component_error::Error::BadRequest(ErrorsBody {
+                    errors: vec![value.to_safe_string()],
+                })
+            }
+            ComponentError::InvalidEgressPolicy(_) => {
+                component_error::Error::BadRequest(ErrorsBody {
+                    errors: vec![value.to_safe_string()],
+                })
+            }
+            ComponentError::InvalidSecrets(_) => component_error::Error::BadRequest(ErrorsBody {
+                errors: vec![value.to_safe_string()],
+            }),
+            ComponentError::InvalidFailurePolicy(_) => {
+                component_error::Error::BadRequest(ErrorsBody {
+                    errors: vec![value.to_safe_string()],
+                })
+            }
+            ComponentError::ComponentReferenceLookupError(_) => {
+                component_error::Error::InternalError(ErrorBody {
+                    error: value.to_safe_string(),
+                })
+            }
         };
         Self { error: Some(error) }
     }
@@ -391,6 +441,72 @@ pub trait ComponentService<Owner: ComponentOwner>: Debug {
     async fn delete(&self, component_id: &ComponentId, owner: &Owner)
         -> Result<(), ComponentError>;
 
+    /// Creates a new component version with the given oplog policy. Later versions inherit the
+    /// policy.
+    ///
+    /// Workers apply the new policy once they get updated to the new version.
+    async fn set_oplog_policy(
+        &self,
+        component_id: &ComponentId,
+        oplog_policy: Option<OplogPolicy>,
+        owner: &Owner,
+    ) -> Result<Component<Owner>, ComponentError>;
+
+    /// Sets the persistent volumes of the latest component version. New versions inherit them.
+    ///
+    /// Workers mount the new set of volumes the next time they get loaded.
+    async fn set_volumes(
+        &self,
+        component_id: &ComponentId,
+        volumes: Vec<ComponentVolume>,
+        owner: &Owner,
+    ) -> Result<Component<Owner>, ComponentError>;
+
+    /// Creates a new component version with the given host capability policy. Later versions
+    /// inherit the policy.
+    ///
+    /// Workers keep the policy of the version they run, so recovering a worker replays its
+    /// history under the same policy. They apply the new policy once they get updated to the new
+    /// version.
+    async fn set_capability_policy(
+        &self,
+        component_id: &ComponentId,
+        capability_policy: Option<CapabilityPolicy>,
+        owner: &Owner,
+    ) -> Result<Component<Owner>, ComponentError>;
+
+    /// Sets the egress policy of the latest component version. New versions inherit it.
+    ///
+    /// Worker executors pick up the change once their cached component metadata expires.
+    async fn set_egress_policy(
+        &self,
+        component_id: &ComponentId,
+        egress_policy: Option<EgressPolicy>,
+        owner: &Owner,
+    ) -> Result<Component<Owner>, ComponentError>;
+
+    /// Sets the secrets exposed to the workers of the latest component version. New versions
+    /// inherit them.
+    ///
+    /// Workers receive the new set of secrets the next time they get loaded.
+    async fn set_secrets(
+        &self,
+        component_id: &ComponentId,
+        secrets: Vec<SecretReference>,
+        owner: &Owner,
+    ) -> Result<Component<Owner>, ComponentError>;
+
+    /// Creates a new component version with the given failure policy. Later versions inherit the
+    /// policy.
+    ///
+    /// Workers apply the new policy once they get updated to the new version.
+    async fn set_failure_policy(
+        &self,
+        component_id: &ComponentId,
+        failure_policy: Option<FailurePolicy>,
+        owner: &Owner,
+    ) -> Result<Component<Owner>, ComponentError>;
+
     async fn create_or_update_constraint(
         &self,
         component_constraint: &ComponentConstraints<Owner>,
@@ -439,6 +555,7 @@ pub struct ComponentServiceDefault<Owner: ComponentOwner, Scope: PluginScope> {
     component_compilation: Arc<dyn ComponentCompilationService + Sync + Send>,
     initial_component_files_service: Arc<InitialComponentFilesService>,
     plugin_service: Arc<dyn PluginService<Owner::PluginOwner, Scope> + Sync + Send>,
+    wasm_transformer: Arc<WasmComponentTransformer>,
 }
 
 impl<Owner: ComponentOwner, Scope: PluginScope> Debug for ComponentServiceDefault<Owner, Scope> {
@@ -454,6 +571,7 @@ impl<Owner: ComponentOwner, Scope: PluginScope> ComponentServiceDefault<Owner, S
         component_compilation: Arc<dyn ComponentCompilationService + Sync + Send>,
         initial_component_files_service: Arc<InitialComponentFilesService>,
         plugin_service: Arc<dyn PluginService<Owner::PluginOwner, Scope> + Sync + Send>,
+        wasm_transformer: Arc<WasmComponentTransformer>,
     ) -> Self {
         ComponentServiceDefault {
             component_repo,
@@ -461,6 +579,7 @@ impl<Owner: ComponentOwner, Scope: PluginScope> ComponentServiceDefault<Owner, S
             component_compilation,
             initial_component_files_service,
             plugin_service,
+            wasm_transformer,
         }
     }
 
@@ -688,8 +807,16 @@ impl<Owner: ComponentOwner, Scope: PluginScope> ComponentServiceDefault<Owner, S
         files: Option<Vec<InitialComponentFile>>,
         owner: &Owner,
     ) -> Result<Component<Owner>, ComponentError> {
-        let metadata = ComponentMetadata::analyse_component(&data)
+        let previous_metadata = self
+            .get_latest_version(component_id, owner)
+            .await?
+            .map(|component| component.metadata);
+
+        let mut metadata = ComponentMetadata::analyse_component(&data)
             .map_err(ComponentError::ComponentProcessingError)?;
+        if let Some(previous_metadata) = &previous_metadata {
+            metadata.inherit_settings(previous_metadata);
+        }
 
         let constraints = self
             .component_repo
@@ -706,6 +833,15 @@ impl<Owner: ComponentOwner, Scope: PluginScope> ComponentServiceDefault<Owner, S
             }
         }
 
+        if let Some(files) = &files {
+            let initial_files = files
+                .iter()
+                .map(|file| file.path.clone())
+                .collect::<Vec<_>>();
+            ComponentVolume::validate_all(&metadata.volumes, &initial_files)
+                .map_err(ComponentError::InvalidVolumes)?;
+        }
+
         info!(owner = %owner, "Uploaded component - exports {:?}", metadata.exports);
 
         let files = files.map(|files| {
@@ -743,8 +879,11 @@ impl<Owner: ComponentOwner, Scope: PluginScope> ComponentServiceDefault<Owner, S
         debug!("Result component: {component:?}");
 
         let transformed_data = self.apply_transformations(&component, data.clone()).await?;
-        let transformed_metadata = ComponentMetadata::analyse_component(&transformed_data)
+        let mut transformed_metadata = ComponentMetadata::analyse_component(&transformed_data)
             .map_err(ComponentError::ComponentProcessingError)?;
+        if let Some(previous_metadata) = &previous_metadata {
+            transformed_metadata.inherit_settings(previous_metadata);
+        }
 
         tokio::try_join!(
             self.upload_user_component(&component, data),
@@ -816,24 +955,41 @@ impl<Owner: ComponentOwner, Scope: PluginScope> ComponentServiceDefault<Owner, S
                     .map_err(Box::new)?;
 
                 if let Some(plugin) = plugin {
-                    if let PluginTypeSpecificDefinition::ComponentTransformer(spec) = plugin.specs {
-                        let span = info_span!("component transformation",
-                            owner = %component.owner,
-                            component_id = %component.versioned_component_id,
-                            plugin_name = %installation.name,
-                            plugin_version = %installation.version,
-                            plugin_installation_id = %installation.id,
-                        );
-
-                        data = self
-                            .apply_transformation(
-                                component,
-                                &data,
-                                spec.transform_url,
-                                &installation.parameters,
-                            )
-                            .instrument(span)
-                            .await?;
+                    let span = info_span!("component transformation",
+                        owner = %component.owner,
+                        component_id = %component.versioned_component_id,
+                        plugin_name = %installation.name,
+                        plugin_version = %installation.version,
+                        plugin_installation_id = %installation.id,
+                    );
+
+                    match plugin.specs {
+                        PluginTypeSpecificDefinition::ComponentTransformer(spec) => {
+                            data = self
+                                .apply_transformation(
+                                    component,
+                                    &data,
+                                    spec.transform_url,
+                                    &installation.parameters,
+                                )
+                                .instrument(span)
+                                .await?;
+                        }
+                        PluginTypeSpecificDefinition::WasmComponentTransformer(spec) => {
+                            data = self
+                                .apply_wasm_transformation(
+                                    component,
+                                    &data,
+                                    &plugin.owner,
+                                    &spec,
+                                    &installation.parameters,
+                                )
+                                .instrument(span)
+                                .await?;
+                        }
+                        PluginTypeSpecificDefinition::OplogProcessor(_)
+                        | PluginTypeSpecificDefinition::OplogArchiveSink(_)
+                        | PluginTypeSpecificDefinition::OplogWebhookSink(_) => {}
                     }
                 } else {
                     Err(ComponentError::TransformationPluginNotFound {
@@ -946,6 +1102,98 @@ impl<Owner: ComponentOwner, Scope: PluginScope> ComponentServiceDefault<Owner, S
         }
     }
 
+    async fn apply_wasm_transformation(
+        &self,
+        component: &Component<Owner>,
+        data: &[u8],
+        plugin_owner: &Owner::PluginOwner,
+        spec: &WasmComponentTransformerDefinition,
+        parameters: &HashMap<String, String>,
+    ) -> Result<Vec<u8>, ComponentError> {
+        let transformer_id = VersionedComponentId {
+            component_id: spec.component_id.clone(),
+            version: spec.component_version,
+        };
+
+        info!(transformer_component_id = %transformer_id, "Applying WASM component transformation plugin");
+
+        let transformer_not_found = || {
+            ComponentError::TransformationFailed(TransformationFailedReason::Failure(format!(
+                "Transformer plugin component {transformer_id} not found"
+            )))
+        };
+
+        // The transformer component belongs to the plugin's owner, which is not necessarily
+        // the owner of the component being transformed
+        let transformer_owner = self
+            .get_owner(&transformer_id.component_id)
+            .await?
+            .filter(|owner| owner.account_id() == plugin_owner.account_id())
+            .ok_or_else(transformer_not_found)?;
+
+        let transformer = self
+            .get_by_version(&transformer_id, &transformer_owner)
+            .await?
+            .ok_or_else(transformer_not_found)?;
+
+        let serializable_component: golem_service_base::model::Component = component.clone().into();
+        let metadata = serde_json::to_string(&serializable_component).map_err(|err| {
+            ComponentError::conversion_error("component metadata", err.to_string())
+        })?;
+
+        let object_store = self.object_store.clone();
+        let object_key = transformer.protected_object_store_key();
+        self.wasm_transformer
+            .transform(
+                &transformer_id,
+                || {
+                    Box::pin(async move {
+                        object_store.get(&object_key).await.map_err(|err| {
+                            format!("Failed to download transformer plugin component: {err}")
+                        })
+                    })
+                },
+                data,
+                &metadata,
+                parameters,
+            )
+            .await
+            .map_err(|err| {
+                ComponentError::TransformationFailed(TransformationFailedReason::Failure(err))
+            })
+    }
+
+    /// Checks the parameters of a plugin installation against the plugin's type
+    async fn validate_plugin_parameters(
+        &self,
+        owner: &Owner,
+        plugin_name: &str,
+        plugin_version: &str,
+        parameters: &HashMap<String, String>,
+    ) -> Result<(), PluginError> {
+        let plugin_owner = owner.clone().into();
+        let plugin = self
+            .plugin_service
+            .get(&plugin_owner, plugin_name, plugin_version)
+            .await?;
+
+        let result = match plugin.map(|plugin| plugin.specs) {
+            Some(PluginTypeSpecificDefinition::OplogArchiveSink(_)) => {
+                OplogArchiveSinkDefinition::validate_parameters(parameters)
+            }
+            Some(PluginTypeSpecificDefinition::OplogWebhookSink(_)) => {
+                OplogWebhookSinkDefinition::validate_parameters(parameters)
+            }
+            _ => Ok(()),
+        };
+
+        result.map_err(|details| PluginError::InvalidParameters {
+            plugin_name: plugin_name.to_string(),
+            plugin_version: plugin_version.to_string(),
+            details,
+        })
+    }
+
     async fn retransform(
         &self,
         namespace: &str,
@@ -959,9 +1207,80 @@ impl<Owner: ComponentOwner, Scope: PluginScope> ComponentServiceDefault<Owner, S
                 ComponentError::component_store_error("Failed to download user component", err)
             })?;
 
+        self.transform_and_activate(namespace, new_component, data)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Creates a new component version sharing the code and the files of the latest version,
+    /// with its metadata changed by `update`.
+    ///
+    /// Published versions are never changed in place, as worker executors cache the metadata of
+    /// each component version and workers must replay with the settings they were recorded with.
+    /// Existing workers see the change once they get updated to the new version.
+    async fn create_version_with_updated_metadata(
+        &self,
+        component_id: &ComponentId,
+        owner: &Owner,
+        update: impl FnOnce(&mut ComponentMetadata) + Send,
+    ) -> Result<Component<Owner>, ComponentError> {
+        let latest = self
+            .get_latest_version(component_id, owner)
+            .await?
+            .ok_or(ComponentError::UnknownComponentId(component_id.clone()))?;
+
+        let data = self
+            .object_store
+            .get(&latest.user_object_store_key())
+            .await
+            .map_err(|err| {
+                ComponentError::component_store_error("Failed to download user component", err)
+            })?;
+
+        let mut metadata = latest.metadata;
+        update(&mut metadata);
+
+        let owner_record: Owner::Row = owner.clone().into();
+        let component_record = self
+            .component_repo
+            .update(
+                &owner_record,
+                &owner.to_string(),
+                &component_id.0,
+                data.clone(),
+                record_metadata_serde::serialize(&metadata)
+                    .map_err(|err| ComponentError::conversion_error("metadata", err))?
+                    .to_vec(),
+                None,
+                None,
+            )
+            .await?;
+        let mut component: Component<Owner> = component_record
+            .try_into()
+            .map_err(|e| ComponentError::conversion_error("record", e))?;
+        component.transformed_object_store_key = None;
+
+        self.transform_and_activate(&owner.to_string(), component.clone(), data)
+            .await?;
+
+        self.component_compilation
+            .enqueue_compilation(component_id, component.versioned_component_id.version)
+            .await;
+
+        Ok(component)
+    }
+
+    async fn transform_and_activate(
+        &self,
+        namespace: &str,
+        new_component: Component<Owner>,
+        data: Vec<u8>,
+    ) -> Result<(), ComponentError> {
         let transformed_data = self.apply_transformations(&new_component, data).await?;
-        let transformed_metadata = ComponentMetadata::analyse_component(&transformed_data)
+        let mut transformed_metadata = ComponentMetadata::analyse_component(&transformed_data)
             .map_err(ComponentError::ComponentProcessingError)?;
+        transformed_metadata.inherit_settings(&new_component.metadata);
 
         self.object_store
             .put(
@@ -1367,6 +1686,161 @@ impl<Owner: ComponentOwner, Scope: PluginScope> ComponentService<Owner>
         }
     }
 
+    async fn set_oplog_policy(
+        &self,
+        component_id: &ComponentId,
+        oplog_policy: Option<OplogPolicy>,
+        owner: &Owner,
+    ) -> Result<Component<Owner>, ComponentError> {
+        info!(owner = %owner, component_id = %component_id, "Set component oplog policy");
+
+        self.create_version_with_updated_metadata(component_id, owner, |metadata| {
+            metadata.oplog_policy = oplog_policy;
+        })
+        .await
+    }
+
+    async fn set_volumes(
+        &self,
+        component_id: &ComponentId,
+        volumes: Vec<ComponentVolume>,
+        owner: &Owner,
+    ) -> Result<Component<Owner>, ComponentError> {
+        info!(owner = %owner, component_id = %component_id, "Set component volumes");
+
+        let mut component = self
+            .get_latest_version(component_id, owner)
+            .await?
+            .ok_or(ComponentError::UnknownComponentId(component_id.clone()))?;
+
+        let initial_files = component
+            .files
+            .iter()
+            .map(|file| file.path.clone())
+            .collect::<Vec<_>>();
+        ComponentVolume::validate_all(&volumes, &initial_files)
+            .map_err(ComponentError::InvalidVolumes)?;
+
+        component.metadata.volumes = volumes;
+
+        self.component_repo
+            .update_metadata(
+                &owner.to_string(),
+                &component_id.0,
+                component.versioned_component_id.version as i64,
+                record_metadata_serde::serialize(&component.metadata)
+                    .map_err(|err| ComponentError::conversion_error("metadata", err))?
+                    .to_vec(),
+            )
+            .await?;
+
+        Ok(component)
+    }
+
+    async fn set_capability_policy(
+        &self,
+        component_id: &ComponentId,
+        capability_policy: Option<CapabilityPolicy>,
+        owner: &Owner,
+    ) -> Result<Component<Owner>, ComponentError> {
+        info!(owner = %owner, component_id = %component_id, "Set component capability policy");
+
+        if let Some(capability_policy) = &capability_policy {
+            capability_policy
+                .validate()
+                .map_err(ComponentError::InvalidCapabilityPolicy)?;
+        }
+
+        self.create_version_with_updated_metadata(component_id, owner, |metadata| {
+            metadata.capability_policy = capability_policy;
+        })
+        .await
+    }
+
+    async fn set_egress_policy(
+        &self,
+        component_id: &ComponentId,
+        egress_policy: Option<EgressPolicy>,
+        owner: &Owner,
+    ) -> Result<Component<Owner>, ComponentError> {
+        info!(owner = %owner, component_id = %component_id, "Set component egress policy");
+
+        if let Some(egress_policy) = &egress_policy {
+            egress_policy
+                .validate()
+                .map_err(ComponentError::InvalidEgressPolicy)?;
+        }
+
+        let mut component = self
+            .get_latest_version(component_id, owner)
+            .await?
+            .ok_or(ComponentError::UnknownComponentId(component_id.clone()))?;
+        component.metadata.egress_policy = egress_policy;
+
+        self.component_repo
+            .update_metadata(
+                &owner.to_string(),
+                &component_id.0,
+                component.versioned_component_id.version as i64,
+                record_metadata_serde::serialize(&component.metadata)
+                    .map_err(|err| ComponentError::conversion_error("metadata", err))?
+                    .to_vec(),
+            )
+            .await?;
+
+        Ok(component)
+    }
+
+    async fn set_secrets(
+        &self,
+        component_id: &ComponentId,
+        secrets: Vec<SecretReference>,
+        owner: &Owner,
+    ) -> Result<Component<Owner>, ComponentError> {
+        info!(owner = %owner, component_id = %component_id, "Set component secrets");
+
+        SecretReference::validate_all(&secrets).map_err(ComponentError::InvalidSecrets)?;
+
+        let mut component = self
+            .get_latest_version(component_id, owner)
+            .await?
+            .ok_or(ComponentError::UnknownComponentId(component_id.clone()))?;
+        component.metadata.secrets = secrets;
+
+        self.component_repo
+            .update_metadata(
+                &owner.to_string(),
+                &component_id.0,
+                component.versioned_component_id.version as i64,
+                record_metadata_serde::serialize(&component.metadata)
+                    .map_err(|err| ComponentError::conversion_error("metadata", err))?
+                    .to_vec(),
+            )
+            .await?;
+
+        Ok(component)
+    }
+
+    async fn set_failure_policy(
+        &self,
+        component_id: &ComponentId,
+        failure_policy: Option<FailurePolicy>,
+        owner: &Owner,
+    ) -> Result<Component<Owner>, ComponentError> {
+        info!(owner = %owner, component_id = %component_id, "Set component failure policy");
+
+        if let Some(failure_policy) = &failure_policy {
+            failure_policy
+                .validate()
+                .map_err(ComponentError::InvalidFailurePolicy)?;
+        }
+
+        self.create_version_with_updated_metadata(component_id, owner, |metadata| {
+            metadata.failure_policy = failure_policy;
+        })
+        .await
+    }
+
     async fn create_or_update_constraint(
         &self,
         component_constraint: &ComponentConstraints<Owner>,
@@ -1450,6 +1924,14 @@ impl<Owner: ComponentOwner, Scope: PluginScope> ComponentService<Owner>
             .await?;
 
         if let Some(latest) = latest {
+            self.validate_plugin_parameters(
+                owner,
+                &installation.name,
+                &installation.version,
+                &installation.parameters,
+            )
+            .await?;
+
             let installation = installation.with_generated_id();
             let record = PluginInstallationRecord {
                 installation_id: installation.id.0,
@@ -1506,6 +1988,24 @@ impl<Owner: ComponentOwner, Scope: PluginScope> ComponentService<Owner>
             .await?;
 
         if let Some(latest) = latest {
+            let mut new_component: Component<Owner> = latest
+                .try_into()
+                .map_err(|err| ComponentError::conversion_error("component", err))?;
+
+            if let Some(installation) = new_component
+                .installed_plugins
+                .iter()
+                .find(|installation| &installation.id == installation_id)
+            {
+                self.validate_plugin_parameters(
+                    owner,
+                    &installation.name,
+                    &installation.version,
+                    &update.parameters,
+                )
+                .await?;
+            }
+
             let new_component_version = self
                 .component_repo
                 .update_plugin_installation(
@@ -1526,9 +2026,6 @@ impl<Owner: ComponentOwner, Scope: PluginScope> ComponentService<Owner>
                 component_id: component_id.clone(),
                 version: new_component_version,
             };
-            let mut new_component: Component<Owner> = latest
-                .try_into()
-                .map_err(|err| ComponentError::conversion_error("component", err))?;
             new_component.versioned_component_id = new_versioned_component_id;
             new_component.transformed_object_store_key = None;
 
@@ -0,0 +1,443 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::ComponentRetentionConfig;
+use crate::model::{
+    Component, ComponentGcReport, ComponentGcReportEntry, ComponentVersionRetentionReason,
+    RetainedComponentVersion,
+};
+use crate::repo::component::ComponentRepo;
+use crate::service::component::{ComponentError, ComponentServiceDefault};
+use crate::service::component_object_store::ComponentObjectStore;
+use crate::service::component_references::{ComponentReferenceService, ComponentReferences};
+use async_trait::async_trait;
+use golem_common::model::component::ComponentOwner;
+use golem_common::model::plugin::DefaultPluginScope;
+use golem_common::model::ComponentId;
+use golem_service_base::storage::blob::{BlobStorage, BlobStorageNamespace, ExistsResult};
+use rib::FunctionTypeRegistry;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{Debug, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Deletes the component versions not retained by the retention policy, together with their
+/// stored binaries and compiled artifacts
+#[async_trait]
+pub trait ComponentGcService<Owner: ComponentOwner>: Debug {
+    /// Reports the versions the garbage collection would delete, without deleting anything
+    async fn dry_run(&self, owner: &Owner) -> Result<ComponentGcReport, ComponentError>;
+
+    /// Deletes the removable versions of the components of every owner. The references of each
+    /// component are looked up again right before deleting its versions.
+    async fn collect_garbage(&self) -> Result<ComponentGcReport, ComponentError>;
+}
+
+pub struct ComponentGcServiceDefault<Owner: ComponentOwner> {
+    component_repo: Arc<dyn ComponentRepo<Owner> + Sync + Send>,
+    object_store: Arc<dyn ComponentObjectStore + Sync + Send>,
+    blob_storage: Arc<dyn BlobStorage + Sync + Send>,
+    component_references: Arc<dyn ComponentReferenceService + Sync + Send>,
+    config: ComponentRetentionConfig,
+}
+
+impl<Owner: ComponentOwner> Debug for ComponentGcServiceDefault<Owner> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComponentGcServiceDefault").finish()
+    }
+}
+
+struct ComponentGcPlan<Owner: ComponentOwner> {
+    entry: ComponentGcReportEntry,
+    removable: Vec<Component<Owner>>,
+    removable_object_store_keys: HashSet<String>,
+}
+
+impl<Owner: ComponentOwner> ComponentGcServiceDefault<Owner> {
+    pub fn new(
+        component_repo: Arc<dyn ComponentRepo<Owner> + Sync + Send>,
+        object_store: Arc<dyn ComponentObjectStore + Sync + Send>,
+        blob_storage: Arc<dyn BlobStorage + Sync + Send>,
+        component_references: Arc<dyn ComponentReferenceService + Sync + Send>,
+        config: ComponentRetentionConfig,
+    ) -> Self {
+        Self {
+            component_repo,
+            object_store,
+            blob_storage,
+            component_references,
+            config,
+        }
+    }
+
+    async fn plan(&self, namespace: &str) -> Result<Vec<ComponentGcPlan<Owner>>, ComponentError> {
+        let records = self.component_repo.get_all(namespace).await?;
+
+        let mut components: BTreeMap<ComponentId, Vec<Component<Owner>>> = BTreeMap::new();
+        for record in records {
+            let component: Component<Owner> = record
+                .try_into()
+                .map_err(|e| ComponentError::conversion_error("record", e))?;
+            components
+                .entry(component.versioned_component_id.component_id.clone())
+                .or_default()
+                .push(component);
+        }
+
+        let component_ids = components.keys().cloned().collect::<Vec<_>>();
+        let references = self
+            .component_references
+            .get_references(&component_ids)
+            .await
+            .map_err(ComponentError::ComponentReferenceLookupError)?;
+
+        let mut plans = Vec::new();
+        for (component_id, mut versions) in components {
+            versions.sort_by_key(|component| component.versioned_component_id.version);
+            plans.push(
+                self.plan_component(namespace, &component_id, versions, &references)
+                    .await?,
+            );
+        }
+
+        Ok(plans)
+    }
+
+    /// Plans a single component again with its current versions and references, so a version
+    /// which got referenced after the namespace was planned is not deleted
+    async fn revalidate(
+        &self,
+        namespace: &str,
+        component_id: &ComponentId,
+    ) -> Result<ComponentGcPlan<Owner>, ComponentError> {
+        let mut versions = self
+            .component_repo
+            .get(namespace, &component_id.0)
+            .await?
+            .into_iter()
+            .map(|record| {
+                Component::<Owner>::try_from(record)
+                    .map_err(|e| ComponentError::conversion_error("record", e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        versions.sort_by_key(|component| component.versioned_component_id.version);
+
+        let references = self
+            .component_references
+            .get_references(&[component_id.clone()])
+            .await
+            .map_err(ComponentError::ComponentReferenceLookupError)?;
+
+        self.plan_component(namespace, component_id, versions, &references)
+            .await
+    }
+
+    async fn plan_component(
+        &self,
+        namespace: &str,
+        component_id: &ComponentId,
+        versions: Vec<Component<Owner>>,
+        references: &ComponentReferences,
+    ) -> Result<ComponentGcPlan<Owner>, ComponentError> {
+        let plugin_versions: HashSet<u64> = self
+            .component_repo
+            .get_versions_providing_plugins(&component_id.0)
+            .await?
+            .into_iter()
+            .collect();
+        let constraints = self
+            .component_repo
+            .get_constraint(namespace, &component_id.0)
+            .await?;
+
+        let mut reasons: BTreeMap<u64, Vec<ComponentVersionRetentionReason>> = BTreeMap::new();
+
+        // The latest version is always kept
+        let keep_last_versions = self.config.keep_last_versions.max(1) as usize;
+        for component in versions.iter().rev().take(keep_last_versions) {
+            reasons
+                .entry(component.versioned_component_id.version)
+                .or_default()
+                .push(ComponentVersionRetentionReason::Recent);
+        }
+
+        for component in &versions {
+            let version = component.versioned_component_id.version;
+            if references.used_by_workers(component_id, version) {
+                reasons
+                    .entry(version)
+                    .or_default()
+                    .push(ComponentVersionRetentionReason::UsedByWorker);
+            }
+            if references.used_by_api_definitions(component_id, version) {
+                reasons
+                    .entry(version)
+                    .or_default()
+                    .push(ComponentVersionRetentionReason::UsedByApiDefinition);
+            }
+            if plugin_versions.contains(&version) {
+                reasons
+                    .entry(version)
+                    .or_default()
+                    .push(ComponentVersionRetentionReason::ProvidesPlugin);
+            }
+        }
+
+        if let Some(constraints) = constraints {
+            let find_conflicts = ComponentServiceDefault::<Owner, DefaultPluginScope>::find_component_metadata_conflicts;
+            let satisfying = versions.iter().rev().find(|component| {
+                let type_registry =
+                    FunctionTypeRegistry::from_export_metadata(&component.metadata.exports);
+                find_conflicts(&constraints, &type_registry).is_empty()
+            });
+            if let Some(component) = satisfying {
+                reasons
+                    .entry(component.versioned_component_id.version)
+                    .or_default()
+                    .push(ComponentVersionRetentionReason::SatisfiesConstraints);
+            }
+        }
+
+        let component_name = versions
+            .last()
+            .map(|component| component.component_name.clone())
+            .ok_or(ComponentError::UnknownComponentId(component_id.clone()))?;
+
+        let (retained, removable): (Vec<_>, Vec<_>) = versions
+            .into_iter()
+            .partition(|component| reasons.contains_key(&component.versioned_component_id.version));
+
+        // Versions created by metadata-only updates share the stored binaries of earlier versions
+        let retained_object_store_keys = retained
+            .iter()
+            .flat_map(|component| {
+                [
+                    component.user_object_store_key(),
+                    component.protected_object_store_key(),
+                ]
+            })
+            .collect::<HashSet<_>>();
+
+        let mut removable_object_store_keys = HashSet::new();
+        let mut reclaimable_size = 0;
+        for component in &removable {
+            let user_key = component.user_object_store_key();
+            if !retained_object_store_keys.contains(&user_key)
+                && removable_object_store_keys.insert(user_key)
+            {
+                reclaimable_size += component.component_size;
+            }
+            let protected_key = component.protected_object_store_key();
+            if !retained_object_store_keys.contains(&protected_key) {
+                removable_object_store_keys.insert(protected_key);
+            }
+        }
+
+        let entry = ComponentGcReportEntry {
+            component_id: component_id.clone(),
+            component_name,
+            retained_versions: reasons
+                .into_iter()
+                .map(|(version, reasons)| RetainedComponentVersion { version, reasons })
+                .collect(),
+            removable_versions: removable
+                .iter()
+                .map(|component| component.versioned_component_id.version)
+                .collect(),
+            reclaimable_size,
+        };
+
+        Ok(ComponentGcPlan {
+            entry,
+            removable,
+            removable_object_store_keys,
+        })
+    }
+
+    async fn execute(
+        &self,
+        namespace: &str,
+        plan: &ComponentGcPlan<Owner>,
+    ) -> Result<(), ComponentError> {
+        let component_id = &plan.entry.component_id;
+
+        // Deleting the records first, so a failure can only leave unreferenced data behind
+        for component in &plan.removable {
+            self.component_repo
+                .delete_version(
+                    namespace,
+                    &component_id.0,
+                    component.versioned_component_id.version,
+                )
+                .await?;
+        }
+
+        for key in &plan.removable_object_store_keys {
+            self.object_store.delete(key).await.map_err(|e| {
+                ComponentError::component_store_error("Failed to delete component data", e)
+            })?;
+        }
+
+        self.delete_compiled_components(component_id, &plan.entry.removable_versions)
+            .await
+            .map_err(|e| {
+                ComponentError::component_store_error(
+                    "Failed to delete compiled components",
+                    anyhow::Error::msg(e),
+                )
+            })
+    }
+
+    async fn delete_compiled_components(
+        &self,
+        component_id: &ComponentId,
+        versions: &[u64],
+    ) -> Result<(), String> {
+        let dir = PathBuf::from(component_id.to_string());
+
+        let exists = self
+            .blob_storage
+            .exists(
+                "component_gc",
+                "delete_compiled_components",
+                BlobStorageNamespace::CompilationCache,
+                &dir,
+            )
+            .await?;
+        if exists != ExistsResult::Directory {
+            return Ok(());
+        }
+
+        let entries = self
+            .blob_storage
+            .list_dir(
+                "component_gc",
+                "delete_compiled_components",
+                BlobStorageNamespace::CompilationCache,
+                &dir,
+            )
+            .await?;
+
+        // Compiled components are stored as `<version>-<engine fingerprint>.cwasm`
+        let paths = entries
+            .into_iter()
+            .filter(|path| is_compiled_component_of(path, versions))
+            .collect::<Vec<_>>();
+
+        self.blob_storage
+            .delete_many(
+                "component_gc",
+                "delete_compiled_components",
+                BlobStorageNamespace::CompilationCache,
+                &paths,
+            )
+            .await
+    }
+}
+
+fn is_compiled_component_of(path: &Path, versions: &[u64]) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_suffix(".cwasm"))
+        .and_then(|name| name.split('-').next())
+        .and_then(|version| version.parse::<u64>().ok())
+        .is_some_and(|version| versions.contains(&version))
+}
+
+#[async_trait]
+impl<Owner: ComponentOwner> ComponentGcService<Owner> for ComponentGcServiceDefault<Owner> {
+    async fn dry_run(&self, owner: &Owner) -> Result<ComponentGcReport, ComponentError> {
+        info!(owner = %owner, "Component version garbage collection dry run");
+
+        let plans = self.plan(&owner.to_string()).await?;
+
+        Ok(ComponentGcReport {
+            components: plans.into_iter().map(|plan| plan.entry).collect(),
+        })
+    }
+
+    async fn collect_garbage(&self) -> Result<ComponentGcReport, ComponentError> {
+        let mut components = Vec::new();
+
+        for namespace in self.component_repo.get_namespaces().await? {
+            let plans = match self.plan(&namespace).await {
+                Ok(plans) => plans,
+                Err(err) => {
+                    warn!(
+                        namespace = %namespace,
+                        "Skipping component version garbage collection: {err}"
+                    );
+                    continue;
+                }
+            };
+
+            for plan in plans {
+                if plan.removable.is_empty() {
+                    continue;
+                }
+
+                // Planning every component takes a while, in the meantime workers or API
+                // definitions may have been created using the versions planned to be deleted
+                let plan = match self.revalidate(&namespace, &plan.entry.component_id).await {
+                    Ok(plan) => plan,
+                    Err(err) => {
+                        warn!(
+                            component_id = %plan.entry.component_id,
+                            "Skipping component version garbage collection: {err}"
+                        );
+                        continue;
+                    }
+                };
+                if plan.removable.is_empty() {
+                    continue;
+                }
+
+                match self.execute(&namespace, &plan).await {
+                    Ok(()) => {
+                        info!(
+                            component_id = %plan.entry.component_id,
+                            versions = ?plan.entry.removable_versions,
+                            "Deleted unused component versions"
+                        );
+                        components.push(plan.entry);
+                    }
+                    Err(err) => warn!(
+                        component_id = %plan.entry.component_id,
+                        "Failed to delete unused component versions: {err}"
+                    ),
+                }
+            }
+        }
+
+        Ok(ComponentGcReport { components })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::is_compiled_component_of;
+    use std::path::Path;
+
+    #[test]
+    fn compiled_component_paths_are_matched_by_version() {
+        let path = Path::new("4b8b2f38-4ea6-4e23-a5a2-3c4c3d5d4f3e/12-00ff00ff00ff00ff.cwasm");
+        assert!(is_compiled_component_of(path, &[1, 12]));
+        assert!(!is_compiled_component_of(path, &[1, 2]));
+        assert!(is_compiled_component_of(Path::new("c/3.cwasm"), &[3]));
+        assert!(!is_compiled_component_of(Path::new("c/3.wasm"), &[3]));
+    }
+}
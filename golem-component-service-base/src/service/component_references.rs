@@ -0,0 +1,212 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use golem_api_grpc::proto::golem::apidefinition::api_definition;
+use golem_api_grpc::proto::golem::apidefinition::v1::api_definition_service_client::ApiDefinitionServiceClient;
+use golem_api_grpc::proto::golem::apidefinition::v1::{
+    get_all_api_definitions_response, GetAllApiDefinitionsRequest,
+};
+use golem_api_grpc::proto::golem::worker::v1::worker_service_client::WorkerServiceClient;
+use golem_api_grpc::proto::golem::worker::v1::{
+    get_workers_metadata_response, GetWorkersMetadataRequest,
+};
+use golem_api_grpc::proto::golem::worker::Cursor;
+use golem_common::client::{GrpcClient, GrpcClientConfig};
+use golem_common::model::ComponentId;
+use std::collections::{HashMap, HashSet};
+use tonic::codec::CompressionEncoding;
+use tonic::transport::Channel;
+
+/// The component versions in use outside of the component service
+#[derive(Debug, Clone, Default)]
+pub struct ComponentReferences {
+    pub workers: HashMap<ComponentId, HashSet<u64>>,
+    pub api_definitions: HashMap<ComponentId, HashSet<u64>>,
+}
+
+impl ComponentReferences {
+    pub fn used_by_workers(&self, component_id: &ComponentId, version: u64) -> bool {
+        self.workers
+            .get(component_id)
+            .is_some_and(|versions| versions.contains(&version))
+    }
+
+    pub fn used_by_api_definitions(&self, component_id: &ComponentId, version: u64) -> bool {
+        self.api_definitions
+            .get(component_id)
+            .is_some_and(|versions| versions.contains(&version))
+    }
+}
+
+#[async_trait]
+pub trait ComponentReferenceService {
+    async fn get_references(
+        &self,
+        component_ids: &[ComponentId],
+    ) -> Result<ComponentReferences, String>;
+}
+
+/// Looks up the workers and the API definitions through the worker service
+pub struct ComponentReferenceServiceDefault {
+    worker_client: GrpcClient<WorkerServiceClient<Channel>>,
+    api_definition_client: GrpcClient<ApiDefinitionServiceClient<Channel>>,
+}
+
+impl ComponentReferenceServiceDefault {
+    const WORKERS_PAGE_SIZE: u64 = 1000;
+
+    pub fn new(worker_service_uri: http_02::Uri) -> Self {
+        let worker_client = GrpcClient::new(
+            "worker-service",
+            |channel| {
+                WorkerServiceClient::new(channel)
+                    .send_compressed(CompressionEncoding::Gzip)
+                    .accept_compressed(CompressionEncoding::Gzip)
+            },
+            worker_service_uri.clone(),
+            GrpcClientConfig::default(),
+        );
+        let api_definition_client = GrpcClient::new(
+            "worker-service",
+            |channel| {
+                ApiDefinitionServiceClient::new(channel)
+                    .send_compressed(CompressionEncoding::Gzip)
+                    .accept_compressed(CompressionEncoding::Gzip)
+            },
+            worker_service_uri,
+            GrpcClientConfig::default(),
+        );
+        Self {
+            worker_client,
+            api_definition_client,
+        }
+    }
+
+    async fn get_worker_versions(
+        &self,
+        component_id: &ComponentId,
+    ) -> Result<HashSet<u64>, String> {
+        let mut versions = HashSet::new();
+        let mut cursor = Some(Cursor {
+            layer: 0,
+            cursor: 0,
+        });
+
+        while let Some(current_cursor) = cursor {
+            let component_id = component_id.clone();
+            let response = self
+                .worker_client
+                .call("get_workers_metadata", move |client| {
+                    let request = GetWorkersMetadataRequest {
+                        component_id: Some(component_id.clone().into()),
+                        filter: None,
+                        cursor: Some(current_cursor.clone()),
+                        count: Self::WORKERS_PAGE_SIZE,
+                        precise: false,
+                    };
+                    Box::pin(client.get_workers_metadata(request))
+                })
+                .await
+                .map_err(|status| format!("Failed to get workers: {status}"))?
+                .into_inner();
+
+            match response.result {
+                Some(get_workers_metadata_response::Result::Success(success)) => {
+                    for worker in success.workers {
+                        versions.insert(worker.component_version);
+                        versions.extend(worker.updates.iter().map(|update| update.target_version));
+                    }
+                    cursor = success.cursor;
+                }
+                Some(get_workers_metadata_response::Result::Error(error)) => {
+                    return Err(format!("Failed to get workers: {error:?}"));
+                }
+                None => return Err("Empty response through the worker API".to_string()),
+            }
+        }
+
+        Ok(versions)
+    }
+
+    async fn get_api_definition_versions(
+        &self,
+    ) -> Result<HashMap<ComponentId, HashSet<u64>>, String> {
+        let response = self
+            .api_definition_client
+            .call("get_all_api_definitions", move |client| {
+                Box::pin(client.get_all_api_definitions(GetAllApiDefinitionsRequest {}))
+            })
+            .await
+            .map_err(|status| format!("Failed to get API definitions: {status}"))?
+            .into_inner();
+
+        let definitions = match response.result {
+            Some(get_all_api_definitions_response::Result::Success(success)) => success.definitions,
+            Some(get_all_api_definitions_response::Result::Error(error)) => {
+                return Err(format!("Failed to get API definitions: {error:?}"));
+            }
+            None => return Err("Empty response through the API definition API".to_string()),
+        };
+
+        let mut result: HashMap<ComponentId, HashSet<u64>> = HashMap::new();
+
+        for definition in definitions {
+            let Some(api_definition::Definition::Http(http)) = definition.definition else {
+                continue;
+            };
+
+            for component in http
+                .routes
+                .into_iter()
+                .filter_map(|route| route.binding.and_then(|binding| binding.component))
+            {
+                let component_id: ComponentId = component
+                    .component_id
+                    .ok_or("Missing component id in API definition".to_string())?
+                    .try_into()?;
+
+                result
+                    .entry(component_id)
+                    .or_default()
+                    .insert(component.version);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl ComponentReferenceService for ComponentReferenceServiceDefault {
+    async fn get_references(
+        &self,
+        component_ids: &[ComponentId],
+    ) -> Result<ComponentReferences, String> {
+        let mut workers = HashMap::new();
+        for component_id in component_ids {
+            workers.insert(
+                component_id.clone(),
+                self.get_worker_versions(component_id).await?,
+            );
+        }
+
+        let api_definitions = self.get_api_definition_versions().await?;
+
+        Ok(ComponentReferences {
+            workers,
+            api_definitions,
+        })
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::WasmTransformerConfig;
+use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode, SimpleCache};
+use golem_service_base::model::VersionedComponentId;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+use tracing::debug;
+use wasmtime::component::{Component, Linker, ResourceTable};
+use wasmtime::{Config, Engine, Store, StoreLimits, StoreLimitsBuilder};
+use wasmtime_wasi::{WasiCtx, WasiCtxBuilder, WasiView};
+
+mod bindings {
+    wasmtime::component::bindgen!({
+        path: "../golem-worker-executor-base/wit/deps/golem-plugin",
+        world: "component-transformer-plugin",
+        async: true,
+    });
+}
+
+/// Executes component transformer plugins implemented as WASM components in a sandbox which
+/// has no access to the filesystem, the network or the environment, and is limited in memory,
+/// fuel and execution time.
+///
+/// The compiled transformer components are cached per component version.
+pub struct WasmComponentTransformer {
+    engine: Engine,
+    linker: Linker<TransformerCtx>,
+    compiled_transformers: Cache<VersionedComponentId, (), Component, String>,
+    config: WasmTransformerConfig,
+}
+
+impl WasmComponentTransformer {
+    pub fn new(config: WasmTransformerConfig) -> Result<Self, anyhow::Error> {
+        let mut wasmtime_config = Config::new();
+        wasmtime_config.wasm_component_model(true);
+        wasmtime_config.async_support(true);
+        wasmtime_config.consume_fuel(true);
+
+        let engine = Engine::new(&wasmtime_config)?;
+        let mut linker = Linker::new(&engine);
+        wasmtime_wasi::add_to_linker_async(&mut linker)?;
+
+        let compiled_transformers = Cache::new(
+            Some(config.compiled_cache_capacity),
+            FullCacheEvictionMode::LeastRecentlyUsed(1),
+            BackgroundEvictionMode::OlderThan {
+                ttl: config.compiled_cache_time_to_idle,
+                period: Duration::from_secs(60),
+            },
+            "compiled_transformer",
+        );
+
+        Ok(Self {
+            engine,
+            linker,
+            compiled_transformers,
+            config,
+        })
+    }
+
+    /// Runs the `transform` function of the transformer plugin component on the given component.
+    ///
+    /// The transformer component is only downloaded with `get_transformer` and compiled when it is
+    /// not cached yet.
+    pub async fn transform<F>(
+        &self,
+        transformer_id: &VersionedComponentId,
+        get_transformer: F,
+        component: &[u8],
+        metadata: &str,
+        parameters: &HashMap<String, String>,
+    ) -> Result<Vec<u8>, String>
+    where
+        F: FnOnce() -> Pin<Box<dyn Future<Output = Result<Vec<u8>, String>> + Send>>
+            + Send
+            + 'static,
+    {
+        let engine = self.engine.clone();
+        let transformer = self
+            .compiled_transformers
+            .get_or_insert_simple(transformer_id, || {
+                Box::pin(async move {
+                    let transformer = get_transformer().await?;
+                    // Compiling is CPU-bound and may take seconds for large components
+                    tokio::task::spawn_blocking(move || Component::new(&engine, transformer))
+                        .await
+                        .map_err(|err| err.to_string())
+                        .and_then(|result| result.map_err(|err| err.to_string()))
+                        .map_err(|err| {
+                            format!("Failed to compile transformer plugin component: {err}")
+                        })
+                })
+            })
+            .await?;
+
+        let mut store = Store::new(
+            &self.engine,
+            TransformerCtx {
+                wasi: WasiCtxBuilder::new().build(),
+                table: ResourceTable::new(),
+                limits: StoreLimitsBuilder::new()
+                    .memory_size(self.config.max_memory_size)
+                    .build(),
+            },
+        );
+        store.limiter(|ctx| &mut ctx.limits);
+        store
+            .set_fuel(self.config.max_fuel)
+            .map_err(|err| err.to_string())?;
+        store
+            .fuel_async_yield_interval(Some(FUEL_YIELD_INTERVAL))
+            .map_err(|err| err.to_string())?;
+
+        let parameters = parameters
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect::<Vec<_>>();
+
+        let run = async {
+            let (plugin, _) = bindings::ComponentTransformerPlugin::instantiate_async(
+                &mut store,
+                &transformer,
+                &self.linker,
+            )
+            .await
+            .map_err(|err| format!("Failed to instantiate transformer plugin component: {err}"))?;
+
+            plugin
+                .golem_plugin_component_transformer()
+                .call_transform(&mut store, component, metadata, &parameters)
+                .await
+                .map_err(|err| format!("Transformer plugin component failed: {err}"))?
+        };
+
+        let result = tokio::time::timeout(self.config.timeout, run)
+            .await
+            .map_err(|_| {
+                format!(
+                    "Transformer plugin component timed out after {:?}",
+                    self.config.timeout
+                )
+            })?;
+
+        debug!(
+            remaining_fuel = store.get_fuel().unwrap_or_default(),
+            "Transformer plugin component finished"
+        );
+
+        result
+    }
+}
+
+impl Debug for WasmComponentTransformer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WasmComponentTransformer")
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+const FUEL_YIELD_INTERVAL: u64 = 10_000;
+
+struct TransformerCtx {
+    wasi: WasiCtx,
+    table: ResourceTable,
+    limits: StoreLimits,
+}
+
+impl WasiView for TransformerCtx {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.wasi
+    }
+}
@@ -14,5 +14,9 @@
 
 pub mod component;
 pub mod component_compilation;
+pub mod component_gc;
 pub mod component_object_store;
+pub mod component_references;
+pub mod component_transformer;
 pub mod plugin;
+pub mod secret;
@@ -15,7 +15,7 @@
 use crate::repo::plugin::{PluginRecord, PluginRepo};
 use crate::service::component::ComponentError;
 use async_trait::async_trait;
-use golem_api_grpc::proto::golem::common::ErrorBody;
+use golem_api_grpc::proto::golem::common::{ErrorBody, ErrorsBody};
 use golem_api_grpc::proto::golem::component::v1::component_error;
 use golem_common::model::plugin::{PluginDefinition, PluginOwner, PluginScope};
 use golem_common::model::ComponentId;
@@ -46,6 +46,12 @@ pub enum PluginError {
         plugin_version: String,
         details: String,
     },
+    #[error("Invalid parameters for plugin {plugin_name}@{plugin_version}: {details}")]
+    InvalidParameters {
+        plugin_name: String,
+        plugin_version: String,
+        details: String,
+    },
 }
 
 impl PluginError {
@@ -67,6 +73,7 @@ impl SafeDisplay for PluginError {
             Self::FailedToGetAvailableScopes { .. } => self.to_string(),
             Self::PluginNotFound { .. } => self.to_string(),
             Self::InvalidScope { .. } => self.to_string(),
+            Self::InvalidParameters { .. } => self.to_string(),
         }
     }
 }
@@ -105,6 +112,11 @@ impl From<PluginError> for golem_api_grpc::proto::golem::component::v1::Componen
                     error: value.to_safe_string(),
                 })),
             },
+            PluginError::InvalidParameters { .. } => Self {
+                error: Some(component_error::Error::BadRequest(ErrorsBody {
+                    errors: vec![value.to_safe_string()],
+                })),
+            },
         }
     }
 }
@@ -0,0 +1,345 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::SecretsConfig;
+use crate::repo::secret::{SecretRecord, SecretRepo};
+use crate::service::component::{ComponentError, ComponentService};
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use async_trait::async_trait;
+use golem_api_grpc::proto::golem::common::{ErrorBody, ErrorsBody};
+use golem_api_grpc::proto::golem::component::v1::component_error;
+use golem_common::model::component::ComponentOwner;
+use golem_common::model::secret::{Secret, SecretCreation};
+use golem_common::model::ComponentId;
+use golem_common::SafeDisplay;
+use golem_service_base::repo::RepoError;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use tracing::info;
+
+const NONCE_SIZE: usize = 12;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SecretError {
+    #[error("Internal repository error: {0}")]
+    InternalRepoError(#[from] RepoError),
+    #[error("Internal component error: {0}")]
+    InternalComponentError(#[from] ComponentError),
+    #[error("Internal error: failed to {what} secret {name}")]
+    InternalEncryptionError { what: String, name: String },
+    #[error("Invalid secret: {0}")]
+    InvalidSecret(String),
+    #[error("Component not found: {component_id}")]
+    ComponentNotFound { component_id: ComponentId },
+    #[error("Secret not found: {name}")]
+    SecretNotFound { name: String },
+}
+
+impl SafeDisplay for SecretError {
+    fn to_safe_string(&self) -> String {
+        match self {
+            Self::InternalRepoError(inner) => inner.to_safe_string(),
+            Self::InternalComponentError(inner) => inner.to_safe_string(),
+            Self::InternalEncryptionError { .. } => self.to_string(),
+            Self::InvalidSecret(_) => self.to_string(),
+            Self::ComponentNotFound { .. } => self.to_string(),
+            Self::SecretNotFound { .. } => self.to_string(),
+        }
+    }
+}
+
+impl From<SecretError> for golem_api_grpc::proto::golem::component::v1::ComponentError {
+    fn from(value: SecretError) -> Self {
+        match value {
+            SecretError::InternalRepoError(_) => Self {
+                error: Some(component_error::Error::InternalError(ErrorBody {
+                    error: value.to_safe_string(),
+                })),
+            },
+            SecretError::InternalComponentError(component_error) => component_error.into(),
+            SecretError::InternalEncryptionError { .. } => Self {
+                error: Some(component_error::Error::InternalError(ErrorBody {
+                    error: value.to_safe_string(),
+                })),
+            },
+            SecretError::InvalidSecret(_) => Self {
+                error: Some(component_error::Error::BadRequest(ErrorsBody {
+                    errors: vec![value.to_safe_string()],
+                })),
+            },
+            SecretError::ComponentNotFound { .. } => Self {
+                error: Some(component_error::Error::NotFound(ErrorBody {
+                    error: value.to_safe_string(),
+                })),
+            },
+            SecretError::SecretNotFound { .. } => Self {
+                error: Some(component_error::Error::NotFound(ErrorBody {
+                    error: value.to_safe_string(),
+                })),
+            },
+        }
+    }
+}
+
+#[async_trait]
+pub trait SecretService<Owner: ComponentOwner> {
+    /// Creates a secret owned by `owner`, or replaces the value of an existing one
+    async fn create_or_update(
+        &self,
+        owner: &Owner,
+        secret: SecretCreation,
+    ) -> Result<Secret, SecretError>;
+
+    /// Lists the secrets owned by `owner`, without their values
+    async fn list(&self, owner: &Owner) -> Result<Vec<Secret>, SecretError>;
+
+    /// Deletes a secret belonging to `owner`. Workers already using it keep its value until they
+    /// get restarted.
+    async fn delete(&self, owner: &Owner, name: &str) -> Result<(), SecretError>;
+
+    /// Gets the values of the given secrets of the component's owner, for the worker executor
+    async fn resolve(
+        &self,
+        component_id: &ComponentId,
+        names: &[String],
+    ) -> Result<HashMap<String, String>, SecretError>;
+}
+
+/// Encrypts secret values at rest with AES-256-GCM, binding each value to its namespace and name
+pub struct SecretEncryption {
+    cipher: Aes256Gcm,
+}
+
+impl SecretEncryption {
+    pub fn new(config: &SecretsConfig) -> Result<Self, String> {
+        let encryption_key = config.encryption_key.as_ref().ok_or_else(|| {
+            "No secret encryption key is configured, set secrets.encryption_key to a hex encoded 32 byte key".to_string()
+        })?;
+        let key = hex::decode(encryption_key)
+            .map_err(|err| format!("Invalid secret encryption key: {err}"))?;
+        if key.len() != 32 {
+            return Err("The secret encryption key must be 32 bytes long".to_string());
+        }
+        Ok(Self {
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key)),
+        })
+    }
+
+    pub fn encrypt(&self, namespace: &str, name: &str, value: &str) -> Result<Vec<u8>, String> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let aad = Self::associated_data(namespace, name);
+        let ciphertext = self
+            .cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: value.as_bytes(),
+                    aad: &aad,
+                },
+            )
+            .map_err(|err| err.to_string())?;
+
+        let mut result = nonce.to_vec();
+        result.extend(ciphertext);
+        Ok(result)
+    }
+
+    pub fn decrypt(&self, namespace: &str, name: &str, data: &[u8]) -> Result<String, String> {
+        if data.len() < NONCE_SIZE {
+            return Err("Encrypted value is too short".to_string());
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_SIZE);
+        let aad = Self::associated_data(namespace, name);
+        let plaintext = self
+            .cipher
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|err| err.to_string())?;
+        String::from_utf8(plaintext).map_err(|err| err.to_string())
+    }
+
+    fn associated_data(namespace: &str, name: &str) -> Vec<u8> {
+        format!("{namespace}/{name}").into_bytes()
+    }
+}
+
+impl Debug for SecretEncryption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretEncryption").finish_non_exhaustive()
+    }
+}
+
+pub struct SecretServiceDefault<Owner: ComponentOwner> {
+    secret_repo: Arc<dyn SecretRepo + Send + Sync>,
+    component_service: Arc<dyn ComponentService<Owner> + Send + Sync>,
+    encryption: SecretEncryption,
+}
+
+impl<Owner: ComponentOwner> SecretServiceDefault<Owner> {
+    pub fn new(
+        secret_repo: Arc<dyn SecretRepo + Send + Sync>,
+        component_service: Arc<dyn ComponentService<Owner> + Send + Sync>,
+        encryption: SecretEncryption,
+    ) -> Self {
+        Self {
+            secret_repo,
+            component_service,
+            encryption,
+        }
+    }
+}
+
+#[async_trait]
+impl<Owner: ComponentOwner> SecretService<Owner> for SecretServiceDefault<Owner> {
+    async fn create_or_update(
+        &self,
+        owner: &Owner,
+        secret: SecretCreation,
+    ) -> Result<Secret, SecretError> {
+        info!(owner = %owner, secret_name = secret.name, "Create or update secret");
+
+        secret.validate().map_err(SecretError::InvalidSecret)?;
+
+        let namespace = owner.to_string();
+        let value = self
+            .encryption
+            .encrypt(&namespace, &secret.name, &secret.value)
+            .map_err(|_| SecretError::InternalEncryptionError {
+                what: "encrypt".to_string(),
+                name: secret.name.clone(),
+            })?;
+        let record = SecretRecord {
+            namespace,
+            name: secret.name,
+            value,
+            created_at: chrono::Utc::now(),
+        };
+        self.secret_repo.create_or_update(&record).await?;
+
+        Ok(Secret {
+            name: record.name,
+            created_at: record.created_at,
+        })
+    }
+
+    async fn list(&self, owner: &Owner) -> Result<Vec<Secret>, SecretError> {
+        info!(owner = %owner, "List secrets");
+
+        let records = self.secret_repo.get_all(&owner.to_string()).await?;
+        Ok(records
+            .into_iter()
+            .map(|record| Secret {
+                name: record.name,
+                created_at: record.created_at,
+            })
+            .collect())
+    }
+
+    async fn delete(&self, owner: &Owner, name: &str) -> Result<(), SecretError> {
+        info!(owner = %owner, secret_name = name, "Delete secret");
+
+        if self.secret_repo.delete(&owner.to_string(), name).await? {
+            Ok(())
+        } else {
+            Err(SecretError::SecretNotFound {
+                name: name.to_string(),
+            })
+        }
+    }
+
+    async fn resolve(
+        &self,
+        component_id: &ComponentId,
+        names: &[String],
+    ) -> Result<HashMap<String, String>, SecretError> {
+        info!(component_id = %component_id, "Resolve secrets");
+
+        let owner = self
+            .component_service
+            .get_owner(component_id)
+            .await?
+            .ok_or(SecretError::ComponentNotFound {
+                component_id: component_id.clone(),
+            })?;
+        let namespace = owner.to_string();
+
+        let mut values = HashMap::with_capacity(names.len());
+        for name in names {
+            let record = self
+                .secret_repo
+                .get(&namespace, name)
+                .await?
+                .ok_or(SecretError::SecretNotFound { name: name.clone() })?;
+            let value = self
+                .encryption
+                .decrypt(&namespace, name, &record.value)
+                .map_err(|_| SecretError::InternalEncryptionError {
+                    what: "decrypt".to_string(),
+                    name: name.clone(),
+                })?;
+            values.insert(name.clone(), value);
+        }
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::config::SecretsConfig;
+    use crate::service::secret::SecretEncryption;
+
+    #[test]
+    fn encrypted_values_are_bound_to_their_name() {
+        let encryption = SecretEncryption::new(&SecretsConfig {
+            encryption_key: Some("00".repeat(32)),
+            resolver_access_token: None,
+        })
+        .unwrap();
+
+        let encrypted = encryption.encrypt("default", "token", "s3cr3t").unwrap();
+        assert!(!encrypted
+            .windows("s3cr3t".len())
+            .any(|window| window == b"s3cr3t"));
+        assert_eq!(
+            encryption.decrypt("default", "token", &encrypted).unwrap(),
+            "s3cr3t"
+        );
+        assert!(encryption.decrypt("default", "other", &encrypted).is_err());
+        assert!(encryption.decrypt("other", "token", &encrypted).is_err());
+    }
+
+    #[test]
+    fn invalid_encryption_keys() {
+        assert!(SecretEncryption::new(&SecretsConfig::default()).is_err());
+        assert!(SecretEncryption::new(&SecretsConfig {
+            encryption_key: Some("00112233".to_string()),
+            resolver_access_token: None,
+        })
+        .is_err());
+        assert!(SecretEncryption::new(&SecretsConfig {
+            encryption_key: Some("not hex".to_string()),
+            resolver_access_token: None,
+        })
+        .is_err());
+    }
+}
@@ -17,8 +17,9 @@ use golem_common::model::component::{ComponentOwner, DefaultComponentOwner};
 use golem_common::model::component_constraint::FunctionConstraintCollection;
 use golem_common::model::plugin::{
     ComponentPluginInstallationTarget, ComponentPluginScope, ComponentTransformerDefinition,
-    DefaultPluginOwner, DefaultPluginScope, OplogProcessorDefinition, PluginDefinition,
-    PluginInstallation, PluginOwner, PluginTypeSpecificDefinition,
+    DefaultPluginOwner, DefaultPluginScope, OplogProcessorDefinition, OplogWebhookSinkDefinition,
+    PluginDefinition, PluginInstallation, PluginOwner, PluginTypeSpecificDefinition,
+    WasmComponentTransformerDefinition,
 };
 use golem_common::model::{
     AccountId, ComponentId, ComponentType, Empty, HasAccountId, PluginInstallationId,
@@ -30,6 +31,7 @@ use golem_common::repo::RowMeta;
 use golem_component_service_base::model::Component;
 use golem_component_service_base::repo::component::{ComponentRecord, ComponentRepo};
 use golem_component_service_base::repo::plugin::PluginRepo;
+use golem_component_service_base::repo::secret::{SecretRecord, SecretRepo};
 use golem_service_base::model::{ComponentName, VersionedComponentId};
 use golem_service_base::repo::plugin_installation::PluginInstallationRecord;
 use golem_service_base::repo::RepoError;
@@ -305,6 +307,70 @@ async fn test_repo_component_delete(
     assert!(result4.unwrap().is_empty());
 }
 
+async fn test_repo_component_version_delete(
+    component_repo: Arc<dyn ComponentRepo<DefaultComponentOwner> + Sync + Send>,
+) {
+    let component_name1 = ComponentName("shopping-cart1-component-version-delete".to_string());
+    let data = get_component_data("shopping-cart");
+
+    let component1 = Component::new(
+        ComponentId::new_v4(),
+        component_name1,
+        ComponentType::Durable,
+        &data,
+        vec![],
+        vec![],
+        DefaultComponentOwner,
+    )
+    .unwrap();
+    let record1 = ComponentRecord::try_from_model(component1.clone(), true).unwrap();
+    let namespace = DefaultComponentOwner.to_string();
+    let component_id = component1.versioned_component_id.component_id.0;
+
+    let result1 = component_repo.create(&record1).await;
+
+    let result2 = component_repo
+        .update(
+            &DefaultComponentOwnerRow {},
+            &namespace,
+            &component_id,
+            data.clone(),
+            record1.metadata.clone(),
+            None,
+            None,
+        )
+        .await;
+
+    let result3 = component_repo.get_namespaces().await;
+
+    let result4 = component_repo
+        .delete_version(&namespace, &component_id, 0)
+        .await;
+
+    let result5 = component_repo.get(&namespace, &component_id).await;
+
+    let result6 = component_repo
+        .delete_version("other-namespace", &component_id, 1)
+        .await;
+
+    let result7 = component_repo.get(&namespace, &component_id).await;
+
+    assert!(result1.is_ok());
+    assert_eq!(result2.unwrap().version, 1);
+    assert!(result3.unwrap().contains(&namespace));
+    assert!(result4.is_ok());
+    assert_eq!(
+        result5
+            .unwrap()
+            .iter()
+            .map(|record| record.version)
+            .collect::<Vec<_>>(),
+        vec![1]
+    );
+    assert!(result6.is_ok());
+    assert_eq!(result7.unwrap().len(), 1);
+}
+
 async fn test_repo_component_constraints(
     component_repo: Arc<dyn ComponentRepo<UuidOwner> + Sync + Send>,
 ) {
@@ -478,8 +544,41 @@ async fn test_default_plugin_repo(
     };
     let plugin2_row = plugin2.clone().into();
 
+    let plugin3 = PluginDefinition {
+        name: "plugin3".to_string(),
+        version: "v1".to_string(),
+        description: "a wasm transformer test plugin".to_string(),
+        icon: vec![9, 10, 11, 12],
+        homepage: "https://plugin3.com".to_string(),
+        specs: PluginTypeSpecificDefinition::WasmComponentTransformer(
+            WasmComponentTransformerDefinition {
+                provided_wit_package: None,
+                json_schema: Some("schema".to_string()),
+                component_id: component_id2.clone(),
+                component_version: 1,
+            },
+        ),
+        scope: DefaultPluginScope::Global(Empty {}),
+        owner: DefaultPluginOwner,
+    };
+    let plugin3_row = plugin3.clone().into();
+
+    let plugin4 = PluginDefinition {
+        name: "plugin4".to_string(),
+        version: "v1".to_string(),
+        description: "an oplog webhook sink test plugin".to_string(),
+        icon: vec![13, 14, 15, 16],
+        homepage: "https://plugin4.com".to_string(),
+        specs: PluginTypeSpecificDefinition::OplogWebhookSink(OplogWebhookSinkDefinition {}),
+        scope: DefaultPluginScope::Global(Empty {}),
+        owner: DefaultPluginOwner,
+    };
+    let plugin4_row = plugin4.clone().into();
+
     plugin_repo.create(&plugin1_row).await?;
     plugin_repo.create(&plugin2_row).await?;
+    plugin_repo.create(&plugin3_row).await?;
+    plugin_repo.create(&plugin4_row).await?;
 
     let all2 = plugin_repo.get_all(&plugin_owner_row).await?;
     let scoped2 = plugin_repo
@@ -523,18 +622,22 @@ async fn test_default_plugin_repo(
     assert!(scoped1.is_empty());
     assert!(named1.is_empty());
 
-    assert_eq!(defs.len(), all1.len() + 2);
+    assert_eq!(defs.len(), all1.len() + 4);
     assert_eq!(scoped.len(), 1);
     assert_eq!(named.len(), 1);
 
     assert!(defs.contains(&plugin1));
     assert!(defs.contains(&plugin2));
+    assert!(defs.contains(&plugin3));
+    assert!(defs.contains(&plugin4));
 
     assert_eq!(scoped[0], plugin2);
     assert_eq!(named[0], plugin1);
 
-    assert_eq!(after_delete.len(), all1.len() + 1);
+    assert_eq!(after_delete.len(), all1.len() + 3);
     assert!(after_delete.iter().any(|p| p == &plugin2));
+    assert!(after_delete.iter().any(|p| p == &plugin3));
+    assert!(after_delete.iter().any(|p| p == &plugin4));
 
     Ok(())
 }
@@ -674,3 +777,50 @@ async fn test_default_component_plugin_installation(
 
     Ok(())
 }
+
+async fn test_secret_repo(secret_repo: Arc<dyn SecretRepo + Send + Sync>) -> Result<(), RepoError> {
+    let namespace1 = Uuid::new_v4().to_string();
+    let namespace2 = Uuid::new_v4().to_string();
+
+    let record = |namespace: &str, name: &str, value: &[u8]| SecretRecord {
+        namespace: namespace.to_string(),
+        name: name.to_string(),
+        value: value.to_vec(),
+        created_at: chrono::Utc::now(),
+    };
+
+    secret_repo
+        .create_or_update(&record(&namespace1, "token", b"value1"))
+        .await?;
+    secret_repo
+        .create_or_update(&record(&namespace1, "password", b"value2"))
+        .await?;
+    secret_repo
+        .create_or_update(&record(&namespace2, "token", b"value3"))
+        .await?;
+    secret_repo
+        .create_or_update(&record(&namespace1, "token", b"value4"))
+        .await?;
+
+    let all1 = secret_repo.get_all(&namespace1).await?;
+    let token1 = secret_repo.get(&namespace1, "token").await?;
+    let token2 = secret_repo.get(&namespace2, "token").await?;
+
+    let deleted = secret_repo.delete(&namespace1, "token").await?;
+    let deleted_again = secret_repo.delete(&namespace1, "token").await?;
+    let token1_after_delete = secret_repo.get(&namespace1, "token").await?;
+    let all1_after_delete = secret_repo.get_all(&namespace1).await?;
+
+    assert_eq!(
+        all1.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(),
+        vec!["password", "token"]
+    );
+    assert_eq!(token1.map(|r| r.value), Some(b"value4".to_vec()));
+    assert_eq!(token2.map(|r| r.value), Some(b"value3".to_vec()));
+    assert!(deleted);
+    assert!(!deleted_again);
+    assert!(token1_after_delete.is_none());
+    assert_eq!(all1_after_delete.len(), 1);
+
+    Ok(())
+}
@@ -37,6 +37,7 @@ mod tests {
         ComponentRepo, DbComponentRepo, LoggedComponentRepo,
     };
     use golem_component_service_base::repo::plugin::{DbPluginRepo, LoggedPluginRepo, PluginRepo};
+    use golem_component_service_base::repo::secret::{DbSecretRepo, LoggedSecretRepo, SecretRepo};
     use golem_service_base::repo::RepoError;
     use std::sync::Arc;
     use test_r::{inherit_test_dep, test, test_dep};
@@ -73,6 +74,11 @@ mod tests {
         Arc::new(LoggedPluginRepo::new(DbPluginRepo::new(db.pool.clone())))
     }
 
+    #[test_dep]
+    fn postgres_secret_repo(db: &PostgresDb) -> Arc<dyn SecretRepo + Send + Sync> {
+        Arc::new(LoggedSecretRepo::new(DbSecretRepo::new(db.pool.clone())))
+    }
+
     #[test]
     #[tracing::instrument]
     async fn repo_component_id_unique(
@@ -96,6 +102,14 @@ mod tests {
         crate::all::repo::test_repo_component_delete(component_repo.clone()).await
     }
 
+    #[test]
+    #[tracing::instrument]
+    async fn repo_component_version_delete(
+        component_repo: &Arc<dyn ComponentRepo<DefaultComponentOwner> + Sync + Send>,
+    ) {
+        crate::all::repo::test_repo_component_version_delete(component_repo.clone()).await
+    }
+
     #[test]
     #[tracing::instrument]
     async fn repo_component_constraints(
@@ -126,6 +140,12 @@ mod tests {
         )
         .await
     }
+
+    #[test]
+    #[tracing::instrument]
+    async fn secret_repo(secret_repo: &Arc<dyn SecretRepo + Send + Sync>) -> Result<(), RepoError> {
+        crate::all::repo::test_secret_repo(secret_repo.clone()).await
+    }
 }
 
 struct PostgresDb {
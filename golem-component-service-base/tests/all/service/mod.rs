@@ -17,15 +17,28 @@ use test_r::{inherit_test_dep, test, test_dep};
 use crate::all::repo::sqlite::SqliteDb;
 use crate::all::repo::{constraint_data, get_component_data};
 use crate::Tracing;
+use async_trait::async_trait;
 use golem_common::model::component::DefaultComponentOwner;
-use golem_common::model::plugin::{DefaultPluginOwner, DefaultPluginScope};
+use golem_common::model::component_metadata::{
+    CapabilityPolicy, FailedWorkerAction, FailurePolicy, HostCapability, OplogPolicy,
+};
+use golem_common::model::plugin::{
+    DefaultPluginOwner, DefaultPluginScope, OplogProcessorDefinition, OplogWebhookSinkDefinition,
+    PluginDefinition, PluginInstallationCreation, PluginTypeSpecificDefinition,
+    WasmComponentTransformerDefinition,
+};
 use golem_common::model::{
     ComponentFilePath, ComponentFilePathWithPermissions, ComponentFilePermissions, ComponentId,
-    ComponentType,
+    ComponentType, Empty, VersionedComponentId,
 };
 use golem_common::SafeDisplay;
-use golem_component_service_base::config::ComponentStoreLocalConfig;
-use golem_component_service_base::model::InitialComponentFilesArchiveAndPermissions;
+use golem_component_service_base::config::{
+    ComponentRetentionConfig, ComponentStoreLocalConfig, WasmTransformerConfig,
+};
+use golem_component_service_base::model::{
+    ComponentGcReport, ComponentVersionRetentionReason, InitialComponentFilesArchiveAndPermissions,
+    RetainedComponentVersion,
+};
 use golem_component_service_base::repo::component::{
     ComponentRepo, DbComponentRepo, LoggedComponentRepo,
 };
@@ -36,18 +49,27 @@ use golem_component_service_base::service::component::{
 use golem_component_service_base::service::component_compilation::{
     ComponentCompilationService, ComponentCompilationServiceDisabled,
 };
+use golem_component_service_base::service::component_gc::{
+    ComponentGcService, ComponentGcServiceDefault,
+};
 use golem_component_service_base::service::component_object_store;
 use golem_component_service_base::service::component_object_store::ComponentObjectStore;
-use golem_component_service_base::service::plugin::{PluginService, PluginServiceDefault};
+use golem_component_service_base::service::component_references::{
+    ComponentReferenceService, ComponentReferences,
+};
+use golem_component_service_base::service::component_transformer::WasmComponentTransformer;
+use golem_component_service_base::service::plugin::{
+    PluginError, PluginService, PluginServiceDefault,
+};
 use golem_service_base::model::ComponentName;
 use golem_service_base::service::initial_component_files::InitialComponentFilesService;
 use golem_service_base::storage::blob::fs::FileSystemBlobStorage;
 use golem_service_base::storage::blob::BlobStorage;
 use golem_wasm_ast::analysis::analysed_type::{str, u64};
 use rib::RegistryKey;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
 inherit_test_dep!(Tracing);
@@ -127,10 +149,12 @@ fn component_service(
         component_compilation_service.clone(),
         initial_component_files_service.clone(),
         plugin_service.clone(),
+        Arc::new(WasmComponentTransformer::new(WasmTransformerConfig::default()).unwrap()),
     ))
 }
 
 const COMPONENT_ARCHIVE: &str = "../test-components/cli-project-yaml/data.zip";
+const WASM_TRANSFORMER: &str = "../test-components/wasm-component-transformer/transformer.wat";
 
 #[test]
 #[tracing::instrument]
@@ -599,3 +623,598 @@ async fn test_component_constraint_incompatible_updates(
 
     assert_eq!(component_update_error, expected_error)
 }
+
+#[test]
+#[tracing::instrument]
+async fn test_oplog_policy_creates_new_version(
+    component_service: &Arc<dyn ComponentService<DefaultComponentOwner> + Send + Sync>,
+) {
+    let component = component_service
+        .create(
+            &ComponentId::new_v4(),
+            &ComponentName("oplog-policy-versioning".to_string()),
+            ComponentType::Durable,
+            get_component_data("shopping-cart"),
+            None,
+            vec![],
+            &DefaultComponentOwner,
+        )
+        .await
+        .unwrap();
+    let component_id = component.versioned_component_id.component_id.clone();
+
+    let policy = OplogPolicy {
+        archive_interval_seconds: Some(60),
+        compression_level: None,
+        archive_to_blob_storage: Some(false),
+        exited_worker_ttl_seconds: Some(3600),
+    };
+    let updated = component_service
+        .set_oplog_policy(&component_id, Some(policy.clone()), &DefaultComponentOwner)
+        .await
+        .unwrap();
+
+    assert_eq!(updated.versioned_component_id.version, 1);
+    assert_eq!(updated.metadata.oplog_policy, Some(policy.clone()));
+
+    // The published version is left untouched
+    let original = component_service
+        .get_by_version(&component.versioned_component_id, &DefaultComponentOwner)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(original.metadata.oplog_policy, None);
+
+    let latest = component_service
+        .get_latest_version(&component_id, &DefaultComponentOwner)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(latest.versioned_component_id.version, 1);
+    assert_eq!(latest.metadata.oplog_policy, Some(policy.clone()));
+    assert_eq!(latest.metadata.exports, original.metadata.exports);
+
+    let data = component_service
+        .download(&component_id, Some(1), &DefaultComponentOwner)
+        .await
+        .unwrap();
+    assert_eq!(data, get_component_data("shopping-cart"));
+
+    // Versions created later inherit the policy
+    let next = component_service
+        .update(
+            &component_id,
+            get_component_data("shopping-cart"),
+            None,
+            None,
+            &DefaultComponentOwner,
+        )
+        .await
+        .unwrap();
+    assert_eq!(next.versioned_component_id.version, 2);
+    assert_eq!(next.metadata.oplog_policy, Some(policy));
+}
+
+#[test]
+#[tracing::instrument]
+async fn test_capability_policy_creates_new_version(
+    component_service: &Arc<dyn ComponentService<DefaultComponentOwner> + Send + Sync>,
+) {
+    let component = component_service
+        .create(
+            &ComponentId::new_v4(),
+            &ComponentName("capability-policy-versioning".to_string()),
+            ComponentType::Durable,
+            get_component_data("shopping-cart"),
+            None,
+            vec![],
+            &DefaultComponentOwner,
+        )
+        .await
+        .unwrap();
+    let component_id = component.versioned_component_id.component_id.clone();
+
+    let policy = CapabilityPolicy {
+        denied: vec![HostCapability::BlobStore, HostCapability::Sockets],
+        allowed_http_hosts: Some(vec!["*.golem.cloud".to_string()]),
+    };
+    let updated = component_service
+        .set_capability_policy(&component_id, Some(policy.clone()), &DefaultComponentOwner)
+        .await
+        .unwrap();
+
+    assert_eq!(updated.versioned_component_id.version, 1);
+    assert_eq!(updated.metadata.capability_policy, Some(policy.clone()));
+
+    // Workers of the published version keep running under its policy
+    let original = component_service
+        .get_by_version(&component.versioned_component_id, &DefaultComponentOwner)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(original.metadata.capability_policy, None);
+
+    let next = component_service
+        .update(
+            &component_id,
+            get_component_data("shopping-cart"),
+            None,
+            None,
+            &DefaultComponentOwner,
+        )
+        .await
+        .unwrap();
+    assert_eq!(next.versioned_component_id.version, 2);
+    assert_eq!(next.metadata.capability_policy, Some(policy));
+}
+
+#[test]
+#[tracing::instrument]
+async fn test_failure_policy_creates_new_version(
+    component_service: &Arc<dyn ComponentService<DefaultComponentOwner> + Send + Sync>,
+) {
+    let component = component_service
+        .create(
+            &ComponentId::new_v4(),
+            &ComponentName("failure-policy-versioning".to_string()),
+            ComponentType::Durable,
+            get_component_data("shopping-cart"),
+            None,
+            vec![],
+            &DefaultComponentOwner,
+        )
+        .await
+        .unwrap();
+    let component_id = component.versioned_component_id.component_id.clone();
+
+    let policy = FailurePolicy {
+        dead_letter_handler: None,
+        action: FailedWorkerAction::Delete,
+        delete_after_seconds: Some(60),
+    };
+    let updated = component_service
+        .set_failure_policy(&component_id, Some(policy.clone()), &DefaultComponentOwner)
+        .await
+        .unwrap();
+
+    assert_eq!(updated.versioned_component_id.version, 1);
+    assert_eq!(updated.metadata.failure_policy, Some(policy.clone()));
+
+    // Workers of the published version keep failing under its policy
+    let original = component_service
+        .get_by_version(&component.versioned_component_id, &DefaultComponentOwner)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(original.metadata.failure_policy, None);
+
+    let next = component_service
+        .update(
+            &component_id,
+            get_component_data("shopping-cart"),
+            None,
+            None,
+            &DefaultComponentOwner,
+        )
+        .await
+        .unwrap();
+    assert_eq!(next.versioned_component_id.version, 2);
+    assert_eq!(next.metadata.failure_policy, Some(policy));
+}
+
+#[test]
+#[tracing::instrument]
+async fn test_wasm_component_transformer_plugin(
+    component_service: &Arc<dyn ComponentService<DefaultComponentOwner> + Send + Sync>,
+    plugin_service: &Arc<dyn PluginService<DefaultPluginOwner, DefaultPluginScope> + Send + Sync>,
+) {
+    let transformer_data = wat::parse_file(WASM_TRANSFORMER).unwrap();
+    let transformer = component_service
+        .create(
+            &ComponentId::new_v4(),
+            &ComponentName("wasm-component-transformer".to_string()),
+            ComponentType::Durable,
+            transformer_data,
+            None,
+            vec![],
+            &DefaultComponentOwner,
+        )
+        .await
+        .unwrap();
+
+    let plugin_name = format!("wasm-transformer-{}", Uuid::new_v4());
+    plugin_service
+        .create_plugin(PluginDefinition {
+            name: plugin_name.clone(),
+            version: "v1".to_string(),
+            description: "appends a custom section to the component".to_string(),
+            icon: vec![],
+            homepage: "https://golem.cloud".to_string(),
+            specs: PluginTypeSpecificDefinition::WasmComponentTransformer(
+                WasmComponentTransformerDefinition {
+                    provided_wit_package: None,
+                    json_schema: None,
+                    component_id: transformer.versioned_component_id.component_id.clone(),
+                    component_version: transformer.versioned_component_id.version,
+                },
+            ),
+            scope: DefaultPluginScope::Global(Empty {}),
+            owner: DefaultPluginOwner,
+        })
+        .await
+        .unwrap();
+
+    let data = get_component_data("shopping-cart");
+    let component = component_service
+        .create(
+            &ComponentId::new_v4(),
+            &ComponentName("shopping-cart-wasm-component-transformer".to_string()),
+            ComponentType::Durable,
+            data.clone(),
+            None,
+            vec![],
+            &DefaultComponentOwner,
+        )
+        .await
+        .unwrap();
+    let component_id = component.versioned_component_id.component_id;
+
+    component_service
+        .create_plugin_installation_for_component(
+            &DefaultComponentOwner,
+            &component_id,
+            PluginInstallationCreation {
+                name: plugin_name,
+                version: "v1".to_string(),
+                priority: 0,
+                parameters: HashMap::new(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let transformed = component_service
+        .download(&component_id, None, &DefaultComponentOwner)
+        .await
+        .unwrap();
+
+    let mut expected = data;
+    expected.extend_from_slice(b"\x00\x0c\x0btransformed");
+    assert_eq!(transformed, expected);
+}
+
+#[test]
+#[tracing::instrument]
+async fn test_oplog_webhook_sink_requires_secret_authorization(
+    component_service: &Arc<dyn ComponentService<DefaultComponentOwner> + Send + Sync>,
+    plugin_service: &Arc<dyn PluginService<DefaultPluginOwner, DefaultPluginScope> + Send + Sync>,
+) {
+    let plugin_name = format!("oplog-webhook-{}", Uuid::new_v4());
+    plugin_service
+        .create_plugin(PluginDefinition {
+            name: plugin_name.clone(),
+            version: "v1".to_string(),
+            description: "posts the oplog to a webhook".to_string(),
+            icon: vec![],
+            homepage: "https://golem.cloud".to_string(),
+            specs: PluginTypeSpecificDefinition::OplogWebhookSink(OplogWebhookSinkDefinition {}),
+            scope: DefaultPluginScope::Global(Empty {}),
+            owner: DefaultPluginOwner,
+        })
+        .await
+        .unwrap();
+
+    let component = component_service
+        .create(
+            &ComponentId::new_v4(),
+            &ComponentName("shopping-cart-oplog-webhook".to_string()),
+            ComponentType::Durable,
+            get_component_data("shopping-cart"),
+            None,
+            vec![],
+            &DefaultComponentOwner,
+        )
+        .await
+        .unwrap();
+    let component_id = component.versioned_component_id.component_id;
+
+    let installation = |authorization: &str| PluginInstallationCreation {
+        name: plugin_name.clone(),
+        version: "v1".to_string(),
+        priority: 0,
+        parameters: HashMap::from([
+            ("url".to_string(), "https://example.com/oplog".to_string()),
+            ("authorization".to_string(), authorization.to_string()),
+        ]),
+    };
+
+    let result = component_service
+        .create_plugin_installation_for_component(
+            &DefaultComponentOwner,
+            &component_id,
+            installation("Bearer s3cr3t"),
+        )
+        .await;
+    assert!(matches!(result, Err(PluginError::InvalidParameters { .. })));
+
+    let result = component_service
+        .create_plugin_installation_for_component(
+            &DefaultComponentOwner,
+            &component_id,
+            installation("${secret:oplog-webhook-token}"),
+        )
+        .await;
+    assert!(result.is_ok());
+}
+
+/// Returns the given references, one set per lookup, repeating the last one
+struct TestComponentReferences {
+    references: Mutex<VecDeque<ComponentReferences>>,
+}
+
+impl TestComponentReferences {
+    fn new(references: Vec<ComponentReferences>) -> Self {
+        Self {
+            references: Mutex::new(references.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl ComponentReferenceService for TestComponentReferences {
+    async fn get_references(
+        &self,
+        _component_ids: &[ComponentId],
+    ) -> Result<ComponentReferences, String> {
+        let mut references = self.references.lock().unwrap();
+        if references.len() > 1 {
+            Ok(references.pop_front().unwrap())
+        } else {
+            Ok(references.front().cloned().unwrap_or_default())
+        }
+    }
+}
+
+#[test]
+#[tracing::instrument]
+async fn test_component_gc(
+    object_store: &Arc<dyn ComponentObjectStore + Send + Sync>,
+    component_compilation_service: &Arc<dyn ComponentCompilationService + Send + Sync>,
+    initial_component_files_service: &Arc<InitialComponentFilesService>,
+    blob_storage: &Arc<dyn BlobStorage + Send + Sync>,
+) {
+    // Garbage collection covers every namespace, so it gets a database of its own
+    let db = SqliteDb::new().await;
+    let component_repo: Arc<dyn ComponentRepo<DefaultComponentOwner> + Send + Sync> = Arc::new(
+        LoggedComponentRepo::new(DbComponentRepo::new(db.pool.clone())),
+    );
+    let plugin_service: Arc<
+        dyn PluginService<DefaultPluginOwner, DefaultPluginScope> + Send + Sync,
+    > = Arc::new(PluginServiceDefault::new(Arc::new(LoggedPluginRepo::new(
+        DbPluginRepo::new(db.pool.clone()),
+    ))));
+    let component_service = ComponentServiceDefault::new(
+        component_repo.clone(),
+        object_store.clone(),
+        component_compilation_service.clone(),
+        initial_component_files_service.clone(),
+        plugin_service.clone(),
+        Arc::new(WasmComponentTransformer::new(WasmTransformerConfig::default()).unwrap()),
+    );
+
+    let service = &component_service;
+    let update = move |component_id: ComponentId, data: &'static str| async move {
+        service
+            .update(
+                &component_id,
+                get_component_data(data),
+                None,
+                None,
+                &DefaultComponentOwner,
+            )
+            .await
+            .unwrap()
+    };
+
+    // The oplog processor keeps its first version because a plugin is provided by it
+    let processor = component_service
+        .create(
+            &ComponentId::new_v4(),
+            &ComponentName("gc-oplog-processor".to_string()),
+            ComponentType::Durable,
+            get_component_data("rust-echo"),
+            None,
+            vec![],
+            &DefaultComponentOwner,
+        )
+        .await
+        .unwrap();
+    let processor_id = processor.versioned_component_id.component_id.clone();
+    update(processor_id.clone(), "rust-echo").await;
+    update(processor_id.clone(), "rust-echo").await;
+
+    let plugin_name = format!("oplog-processor-{}", Uuid::new_v4());
+    plugin_service
+        .create_plugin(PluginDefinition {
+            name: plugin_name.clone(),
+            version: "v1".to_string(),
+            description: "processes the oplog".to_string(),
+            icon: vec![],
+            homepage: "https://golem.cloud".to_string(),
+            specs: PluginTypeSpecificDefinition::OplogProcessor(OplogProcessorDefinition {
+                component_id: processor_id.clone(),
+                component_version: processor.versioned_component_id.version,
+            }),
+            scope: DefaultPluginScope::Global(Empty {}),
+            owner: DefaultPluginOwner,
+        })
+        .await
+        .unwrap();
+
+    let component = component_service
+        .create(
+            &ComponentId::new_v4(),
+            &ComponentName("gc-shopping-cart".to_string()),
+            ComponentType::Durable,
+            get_component_data("shopping-cart"),
+            None,
+            vec![],
+            &DefaultComponentOwner,
+        )
+        .await
+        .unwrap();
+    let component_id = component.versioned_component_id.component_id.clone();
+
+    // Installing a plugin creates version 1 sharing the user binary of version 0
+    component_service
+        .create_plugin_installation_for_component(
+            &DefaultComponentOwner,
+            &component_id,
+            PluginInstallationCreation {
+                name: plugin_name,
+                version: "v1".to_string(),
+                priority: 0,
+                parameters: HashMap::new(),
+            },
+        )
+        .await
+        .unwrap();
+    let component_v1 = component_service
+        .get_by_version(
+            &VersionedComponentId {
+                component_id: component_id.clone(),
+                version: 1,
+            },
+            &DefaultComponentOwner,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        component_v1.user_object_store_key(),
+        component.user_object_store_key()
+    );
+
+    let component_v2 = update(component_id.clone(), "shopping-cart").await;
+    update(component_id.clone(), "shopping-cart").await;
+    update(component_id.clone(), "shopping-cart").await;
+    update(component_id.clone(), "rust-echo").await;
+    update(component_id.clone(), "rust-echo").await;
+
+    // Only versions 0 to 4 export the constrained functions
+    component_service
+        .create_or_update_constraint(&constraint_data::get_shopping_cart_component_constraint1(
+            &DefaultComponentOwner,
+            &component_id,
+        ))
+        .await
+        .unwrap();
+
+    let references = ComponentReferences {
+        workers: HashMap::from([(component_id.clone(), HashSet::from([1]))]),
+        api_definitions: HashMap::from([(component_id.clone(), HashSet::from([3]))]),
+    };
+    // A worker gets updated to version 2 after the garbage collection planned to delete it
+    let changed_references = ComponentReferences {
+        workers: HashMap::from([(component_id.clone(), HashSet::from([1, 2]))]),
+        ..references.clone()
+    };
+
+    let gc_service = ComponentGcServiceDefault::new(
+        component_repo.clone(),
+        object_store.clone(),
+        blob_storage.clone(),
+        Arc::new(TestComponentReferences::new(vec![
+            references.clone(),
+            references,
+            changed_references,
+        ])),
+        ComponentRetentionConfig {
+            keep_last_versions: 2,
+            ..ComponentRetentionConfig::default()
+        },
+    );
+
+    let report = gc_service.dry_run(&DefaultComponentOwner).await.unwrap();
+    let entry = |report: &ComponentGcReport, component_id: &ComponentId| {
+        report
+            .components
+            .iter()
+            .find(|entry| &entry.component_id == component_id)
+            .cloned()
+            .unwrap()
+    };
+    let retained = |version: u64, reasons: Vec<ComponentVersionRetentionReason>| {
+        RetainedComponentVersion { version, reasons }
+    };
+
+    let processor_entry = entry(&report, &processor_id);
+    assert_eq!(
+        processor_entry.retained_versions,
+        vec![
+            retained(0, vec![ComponentVersionRetentionReason::ProvidesPlugin]),
+            retained(1, vec![ComponentVersionRetentionReason::Recent]),
+            retained(2, vec![ComponentVersionRetentionReason::Recent]),
+        ]
+    );
+    assert!(processor_entry.removable_versions.is_empty());
+
+    let component_entry = entry(&report, &component_id);
+    assert_eq!(
+        component_entry.retained_versions,
+        vec![
+            retained(1, vec![ComponentVersionRetentionReason::UsedByWorker]),
+            retained(
+                3,
+                vec![ComponentVersionRetentionReason::UsedByApiDefinition]
+            ),
+            retained(
+                4,
+                vec![ComponentVersionRetentionReason::SatisfiesConstraints]
+            ),
+            retained(5, vec![ComponentVersionRetentionReason::Recent]),
+            retained(6, vec![ComponentVersionRetentionReason::Recent]),
+        ]
+    );
+    assert_eq!(component_entry.removable_versions, vec![0, 2]);
+    // The user binary of version 0 is still used by version 1
+    assert_eq!(
+        component_entry.reclaimable_size,
+        component_v2.component_size
+    );
+
+    // The dry run does not delete anything
+    assert!(component_service
+        .get_by_version(&component.versioned_component_id, &DefaultComponentOwner)
+        .await
+        .unwrap()
+        .is_some());
+
+    let report = gc_service.collect_garbage().await.unwrap();
+    assert_eq!(report.components.len(), 1);
+    let component_entry = entry(&report, &component_id);
+    assert_eq!(component_entry.removable_versions, vec![0]);
+
+    assert!(component_service
+        .get_by_version(&component.versioned_component_id, &DefaultComponentOwner)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(component_service
+        .get_by_version(&component_v2.versioned_component_id, &DefaultComponentOwner)
+        .await
+        .unwrap()
+        .is_some());
+    assert!(object_store
+        .get(&component.protected_object_store_key())
+        .await
+        .is_err());
+    assert!(object_store
+        .get(&component_v1.user_object_store_key())
+        .await
+        .is_ok());
+    assert!(object_store
+        .get(&component_v2.protected_object_store_key())
+        .await
+        .is_ok());
+}
@@ -0,0 +1,8 @@
+CREATE TABLE secrets
+(
+    namespace           text    NOT NULL,
+    name                text    NOT NULL,
+    value               bytea   NOT NULL,
+    created_at          timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (namespace, name)
+);
@@ -0,0 +1,8 @@
+CREATE TABLE secrets
+(
+    namespace           text    NOT NULL,
+    name                text    NOT NULL,
+    value               blob    NOT NULL,
+    created_at          timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (namespace, name)
+);
@@ -15,17 +15,22 @@
 use crate::api::{ComponentError, Result};
 use futures_util::TryStreamExt;
 use golem_common::model::component::DefaultComponentOwner;
+use golem_common::model::component_metadata::{
+    CapabilityPolicy, ComponentVolume, EgressPolicy, FailurePolicy, OplogPolicy,
+};
 use golem_common::model::plugin::{
     DefaultPluginOwner, DefaultPluginScope, PluginInstallation, PluginInstallationCreation,
     PluginInstallationUpdate,
 };
+use golem_common::model::secret::SecretReference;
 use golem_common::model::ComponentFilePathWithPermissionsList;
 use golem_common::model::{ComponentId, ComponentType, Empty, PluginInstallationId};
 use golem_common::recorded_http_api_request;
 use golem_component_service_base::model::{
-    InitialComponentFilesArchiveAndPermissions, UpdatePayload,
+    ComponentGcReport, InitialComponentFilesArchiveAndPermissions, UpdatePayload,
 };
 use golem_component_service_base::service::component::ComponentService;
+use golem_component_service_base::service::component_gc::ComponentGcService;
 use golem_component_service_base::service::plugin::{PluginError, PluginService};
 use golem_service_base::api_tags::ApiTags;
 use golem_service_base::model::*;
@@ -42,6 +47,7 @@ pub struct ComponentApi {
     pub component_service: Arc<dyn ComponentService<DefaultComponentOwner> + Sync + Send>,
     pub plugin_service:
         Arc<dyn PluginService<DefaultPluginOwner, DefaultPluginScope> + Sync + Send>,
+    pub component_gc_service: Arc<dyn ComponentGcService<DefaultComponentOwner> + Sync + Send>,
 }
 
 #[OpenApi(prefix_path = "/v1/components", tag = ApiTags::Component)]
@@ -485,6 +491,215 @@ impl ComponentApi {
         record.result(response)
     }
 
+    /// Creates a new component version with the given oplog policy
+    ///
+    /// The policy controls how the oplogs of the component's workers are archived and retained.
+    /// Workers apply it once they get updated to the new version. Versions created later inherit it.
+    #[oai(
+        path = "/:component_id/latest/oplog-policy",
+        method = "put",
+        operation_id = "set_oplog_policy"
+    )]
+    async fn set_oplog_policy(
+        &self,
+        component_id: Path<ComponentId>,
+        policy: Json<OplogPolicy>,
+    ) -> Result<Json<Component>> {
+        let record = recorded_http_api_request!(
+            "set_oplog_policy",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .component_service
+            .set_oplog_policy(&component_id.0, Some(policy.0), &DefaultComponentOwner)
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|component| Json(component.into()));
+
+        record.result(response)
+    }
+
+    /// Sets the persistent volumes of the latest component version
+    ///
+    /// Replaces the full set of volumes mounted into the component's workers. Versions created
+    /// later inherit them. Removing a volume does not delete its stored contents.
+    #[oai(
+        path = "/:component_id/latest/volumes",
+        method = "put",
+        operation_id = "set_component_volumes"
+    )]
+    async fn set_volumes(
+        &self,
+        component_id: Path<ComponentId>,
+        volumes: Json<Vec<ComponentVolume>>,
+    ) -> Result<Json<Component>> {
+        let record = recorded_http_api_request!(
+            "set_component_volumes",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .component_service
+            .set_volumes(&component_id.0, volumes.0, &DefaultComponentOwner)
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|component| Json(component.into()));
+
+        record.result(response)
+    }
+
+    /// Creates a new component version with the given host capability policy
+    ///
+    /// Workers can still import the interfaces of a denied capability, but using them fails at
+    /// runtime. Outgoing HTTP requests are checked against the allowed hosts. Workers apply the
+    /// policy once they get updated to the new version. Versions created later inherit it.
+    #[oai(
+        path = "/:component_id/latest/capability-policy",
+        method = "put",
+        operation_id = "set_capability_policy"
+    )]
+    async fn set_capability_policy(
+        &self,
+        component_id: Path<ComponentId>,
+        policy: Json<CapabilityPolicy>,
+    ) -> Result<Json<Component>> {
+        let record = recorded_http_api_request!(
+            "set_capability_policy",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .component_service
+            .set_capability_policy(&component_id.0, Some(policy.0), &DefaultComponentOwner)
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|component| Json(component.into()));
+
+        record.result(response)
+    }
+
+    /// Sets the egress policy of the latest component version
+    ///
+    /// The policy restricts the destinations of the outgoing HTTP requests of the component's
+    /// workers, and can limit their body size and timeouts or inject headers. Versions created
+    /// later inherit it.
+    #[oai(
+        path = "/:component_id/latest/egress-policy",
+        method = "put",
+        operation_id = "set_egress_policy"
+    )]
+    async fn set_egress_policy(
+        &self,
+        component_id: Path<ComponentId>,
+        policy: Json<EgressPolicy>,
+    ) -> Result<Json<Component>> {
+        let record = recorded_http_api_request!(
+            "set_egress_policy",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .component_service
+            .set_egress_policy(&component_id.0, Some(policy.0), &DefaultComponentOwner)
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|component| Json(component.into()));
+
+        record.result(response)
+    }
+
+    /// Sets the secrets exposed to the workers of the latest component version
+    ///
+    /// Each referenced secret's value is passed to the workers in the given environment variable.
+    /// The values are resolved when a worker gets loaded, and are never stored in its oplog.
+    /// Versions created later inherit the references.
+    #[oai(
+        path = "/:component_id/latest/secrets",
+        method = "put",
+        operation_id = "set_component_secrets"
+    )]
+    async fn set_component_secrets(
+        &self,
+        component_id: Path<ComponentId>,
+        secrets: Json<Vec<SecretReference>>,
+    ) -> Result<Json<Component>> {
+        let record = recorded_http_api_request!(
+            "set_component_secrets",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .component_service
+            .set_secrets(&component_id.0, secrets.0, &DefaultComponentOwner)
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|component| Json(component.into()));
+
+        record.result(response)
+    }
+
+    /// Creates a new component version with the given failure policy
+    ///
+    /// The policy defines what happens when a worker of the component fails permanently after
+    /// exhausting its retries: a dead-letter handler function can be invoked on another worker,
+    /// and the failed worker can be kept, deleted or reverted to its state before the failed
+    /// invocation. Workers apply the policy once they get updated to the new version. Versions
+    /// created later inherit it.
+    #[oai(
+        path = "/:component_id/latest/failure-policy",
+        method = "put",
+        operation_id = "set_failure_policy"
+    )]
+    async fn set_failure_policy(
+        &self,
+        component_id: Path<ComponentId>,
+        policy: Json<FailurePolicy>,
+    ) -> Result<Json<Component>> {
+        let record = recorded_http_api_request!(
+            "set_failure_policy",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .component_service
+            .set_failure_policy(&component_id.0, Some(policy.0), &DefaultComponentOwner)
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|component| Json(component.into()));
+
+        record.result(response)
+    }
+
+    /// Get the component version garbage collection report
+    ///
+    /// Lists the component versions kept by the retention policy together with the reasons, and
+    /// the versions the garbage collection would delete. Nothing is deleted.
+    #[oai(
+        path = "/gc-report",
+        method = "get",
+        operation_id = "get_component_gc_report"
+    )]
+    async fn get_component_gc_report(&self) -> Result<Json<ComponentGcReport>> {
+        let record = recorded_http_api_request!("get_component_gc_report",);
+
+        let response = self
+            .component_gc_service
+            .dry_run(&DefaultComponentOwner)
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(Json);
+
+        record.result(response)
+    }
+
     fn parse_version_path_segment(version: &str) -> Result<u64> {
         version.parse::<u64>().map_err(|_| {
             ComponentError::BadRequest(Json(ErrorsBody {
@@ -17,6 +17,7 @@ use golem_common::metrics::api::TraceErrorKind;
 use golem_common::SafeDisplay;
 use golem_component_service_base::service::component::ComponentError as ComponentServiceError;
 use golem_component_service_base::service::plugin::PluginError;
+use golem_component_service_base::service::secret::SecretError;
 use golem_service_base::model::{ErrorBody, ErrorsBody};
 use poem::endpoint::PrometheusExporter;
 use poem::error::ReadBodyError;
@@ -28,6 +29,7 @@ use prometheus::Registry;
 pub mod component;
 pub mod healthcheck;
 pub mod plugin;
+pub mod secret;
 
 pub fn combined_routes(prometheus_registry: Registry, services: &Services) -> Route {
     let api_service = make_open_api_service(services);
@@ -47,6 +49,7 @@ pub type ApiServices = (
     component::ComponentApi,
     healthcheck::HealthcheckApi,
     plugin::PluginApi,
+    secret::SecretApi,
 );
 
 pub fn make_open_api_service(services: &Services) -> OpenApiService<ApiServices, ()> {
@@ -55,11 +58,15 @@ pub fn make_open_api_service(services: &Services) -> OpenApiService<ApiServices,
             component::ComponentApi {
                 component_service: services.component_service.clone(),
                 plugin_service: services.plugin_service.clone(),
+                component_gc_service: services.component_gc_service.clone(),
             },
             healthcheck::HealthcheckApi,
             plugin::PluginApi {
                 plugin_service: services.plugin_service.clone(),
             },
+            secret::SecretApi {
+                secret_service: services.secret_service.clone(),
+            },
         ),
         "Golem API",
         "1.0",
@@ -171,6 +178,36 @@ impl From<ComponentServiceError> for ComponentError {
                     error: error.to_safe_string(),
                 }))
             }
+            ComponentServiceError::InvalidVolumes(_) => {
+                ComponentError::BadRequest(Json(ErrorsBody {
+                    errors: vec![error.to_safe_string()],
+                }))
+            }
+            ComponentServiceError::InvalidCapabilityPolicy(_) => {
+                ComponentError::BadRequest(Json(ErrorsBody {
+                    errors: vec![error.to_safe_string()],
+                }))
+            }
+            ComponentServiceError::InvalidEgressPolicy(_) => {
+                ComponentError::BadRequest(Json(ErrorsBody {
+                    errors: vec![error.to_safe_string()],
+                }))
+            }
+            ComponentServiceError::InvalidSecrets(_) => {
+                ComponentError::BadRequest(Json(ErrorsBody {
+                    errors: vec![error.to_safe_string()],
+                }))
+            }
+            ComponentServiceError::InvalidFailurePolicy(_) => {
+                ComponentError::BadRequest(Json(ErrorsBody {
+                    errors: vec![error.to_safe_string()],
+                }))
+            }
+            ComponentServiceError::ComponentReferenceLookupError(_) => {
+                ComponentError::InternalError(Json(ErrorBody {
+                    error: error.to_safe_string(),
+                }))
+            }
         }
     }
 }
@@ -205,6 +242,34 @@ impl From<PluginError> for ComponentError {
             PluginError::InvalidScope { .. } => ComponentError::Unauthorized(Json(ErrorBody {
                 error: value.to_safe_string(),
             })),
+            PluginError::InvalidParameters { .. } => ComponentError::BadRequest(Json(ErrorsBody {
+                errors: vec![value.to_safe_string()],
+            })),
+        }
+    }
+}
+
+impl From<SecretError> for ComponentError {
+    fn from(value: SecretError) -> Self {
+        match value {
+            SecretError::InternalRepoError(_) => ComponentError::InternalError(Json(ErrorBody {
+                error: value.to_safe_string(),
+            })),
+            SecretError::InternalComponentError(error) => error.into(),
+            SecretError::InternalEncryptionError { .. } => {
+                ComponentError::InternalError(Json(ErrorBody {
+                    error: value.to_safe_string(),
+                }))
+            }
+            SecretError::InvalidSecret(_) => ComponentError::BadRequest(Json(ErrorsBody {
+                errors: vec![value.to_safe_string()],
+            })),
+            SecretError::ComponentNotFound { .. } => ComponentError::NotFound(Json(ErrorBody {
+                error: value.to_safe_string(),
+            })),
+            SecretError::SecretNotFound { .. } => ComponentError::NotFound(Json(ErrorBody {
+                error: value.to_safe_string(),
+            })),
         }
     }
 }
@@ -0,0 +1,89 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::api::Result;
+use golem_common::model::component::DefaultComponentOwner;
+use golem_common::model::secret::{Secret, SecretCreation};
+use golem_common::model::Empty;
+use golem_common::recorded_http_api_request;
+use golem_component_service_base::service::secret::SecretService;
+use golem_service_base::api_tags::ApiTags;
+use poem_openapi::param::Path;
+use poem_openapi::payload::Json;
+use poem_openapi::OpenApi;
+use std::sync::Arc;
+use tracing::Instrument;
+
+pub struct SecretApi {
+    pub secret_service: Arc<dyn SecretService<DefaultComponentOwner> + Sync + Send>,
+}
+
+#[OpenApi(prefix_path = "/v1/secrets", tag = ApiTags::Secret)]
+impl SecretApi {
+    /// Lists the stored secrets
+    ///
+    /// Only the names of the secrets are returned, never their values.
+    #[oai(path = "/", method = "get", operation_id = "list_secrets")]
+    pub async fn list_secrets(&self) -> Result<Json<Vec<Secret>>> {
+        let record = recorded_http_api_request!("list_secrets",);
+
+        let response = self
+            .secret_service
+            .list(&DefaultComponentOwner)
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(Json);
+
+        record.result(response)
+    }
+
+    /// Creates a secret, or replaces the value of an existing one
+    ///
+    /// Components can expose secrets to their workers as environment variables, and worker
+    /// environment variables or injected egress headers can refer to them with the `${secret:NAME}`
+    /// syntax. Values are stored encrypted and are only decrypted by the worker executors.
+    #[oai(path = "/", method = "post", operation_id = "create_secret")]
+    pub async fn create_secret(&self, secret: Json<SecretCreation>) -> Result<Json<Secret>> {
+        let record = recorded_http_api_request!("create_secret", secret_name = secret.name);
+
+        let response = self
+            .secret_service
+            .create_or_update(&DefaultComponentOwner, secret.0)
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(Json);
+
+        record.result(response)
+    }
+
+    /// Deletes a secret
+    ///
+    /// Workers already running keep the secret's value until they get restarted.
+    #[oai(path = "/:name", method = "delete", operation_id = "delete_secret")]
+    pub async fn delete_secret(&self, name: Path<String>) -> Result<Json<Empty>> {
+        let record = recorded_http_api_request!("delete_secret", secret_name = name.0);
+
+        let response = self
+            .secret_service
+            .delete(&DefaultComponentOwner, &name)
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(Empty {}));
+
+        record.result(response)
+    }
+}
@@ -23,7 +23,8 @@ use golem_common::model::Empty;
 use golem_common::tracing::TracingConfig;
 use golem_component_service_base::config::ComponentCompilationConfig;
 use golem_component_service_base::config::{
-    ComponentStoreConfig, ComponentStoreLocalConfig, ComponentStoreS3Config,
+    ComponentRetentionConfig, ComponentStoreConfig, ComponentStoreLocalConfig,
+    ComponentStoreS3Config, SecretsConfig, WasmTransformerConfig,
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -35,6 +36,9 @@ pub struct ComponentServiceConfig {
     pub component_store: ComponentStoreConfig,
     pub compilation: ComponentCompilationConfig,
     pub blob_storage: BlobStorageConfig,
+    pub secrets: SecretsConfig,
+    pub wasm_transformer: WasmTransformerConfig,
+    pub retention: ComponentRetentionConfig,
 }
 
 impl Default for ComponentServiceConfig {
@@ -53,6 +57,9 @@ impl Default for ComponentServiceConfig {
             }),
             compilation: ComponentCompilationConfig::default(),
             blob_storage: BlobStorageConfig::default(),
+            secrets: SecretsConfig::default(),
+            wasm_transformer: WasmTransformerConfig::default(),
+            retention: ComponentRetentionConfig::default(),
         }
     }
 }
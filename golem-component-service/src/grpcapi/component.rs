@@ -27,19 +27,21 @@ use golem_api_grpc::proto::golem::component::v1::{
     create_component_response, download_component_response,
     get_component_metadata_all_versions_response, get_component_metadata_response,
     get_components_response, get_installed_plugins_response, install_plugin_response,
-    uninstall_plugin_response, update_component_request, update_component_response,
-    update_installed_plugin_response, ComponentError, CreateComponentConstraintsRequest,
-    CreateComponentConstraintsResponse, CreateComponentConstraintsSuccessResponse,
-    CreateComponentRequest, CreateComponentRequestHeader, CreateComponentResponse,
-    DownloadComponentRequest, DownloadComponentResponse, GetComponentMetadataAllVersionsResponse,
+    set_capability_policy_response, set_oplog_policy_response, uninstall_plugin_response,
+    update_component_request, update_component_response, update_installed_plugin_response,
+    ComponentError, CreateComponentConstraintsRequest, CreateComponentConstraintsResponse,
+    CreateComponentConstraintsSuccessResponse, CreateComponentRequest,
+    CreateComponentRequestHeader, CreateComponentResponse, DownloadComponentRequest,
+    DownloadComponentResponse, GetComponentMetadataAllVersionsResponse,
     GetComponentMetadataResponse, GetComponentMetadataSuccessResponse, GetComponentRequest,
     GetComponentSuccessResponse, GetComponentsRequest, GetComponentsResponse,
     GetComponentsSuccessResponse, GetInstalledPluginsRequest, GetInstalledPluginsResponse,
     GetInstalledPluginsSuccessResponse, GetLatestComponentRequest, GetVersionedComponentRequest,
     InstallPluginRequest, InstallPluginResponse, InstallPluginSuccessResponse,
-    UninstallPluginRequest, UninstallPluginResponse, UpdateComponentRequest,
-    UpdateComponentRequestHeader, UpdateComponentResponse, UpdateInstalledPluginRequest,
-    UpdateInstalledPluginResponse,
+    SetCapabilityPolicyRequest, SetCapabilityPolicyResponse, SetOplogPolicyRequest,
+    SetOplogPolicyResponse, UninstallPluginRequest, UninstallPluginResponse,
+    UpdateComponentRequest, UpdateComponentRequestHeader, UpdateComponentResponse,
+    UpdateInstalledPluginRequest, UpdateInstalledPluginResponse,
 };
 use golem_api_grpc::proto::golem::component::ComponentConstraints as ComponentConstraintsProto;
 use golem_api_grpc::proto::golem::component::FunctionConstraintCollection as FunctionConstraintCollectionProto;
@@ -47,6 +49,7 @@ use golem_api_grpc::proto::golem::component::{Component, PluginInstallation};
 use golem_common::grpc::{proto_component_id_string, proto_plugin_installation_id_string};
 use golem_common::model::component::DefaultComponentOwner;
 use golem_common::model::component_constraint::FunctionConstraintCollection;
+use golem_common::model::component_metadata::{CapabilityPolicy, OplogPolicy};
 use golem_common::model::plugin::{
     DefaultPluginOwner, DefaultPluginScope, PluginInstallationCreation, PluginInstallationUpdate,
 };
@@ -374,6 +377,44 @@ impl ComponentGrpcApi {
 
         Ok(())
     }
+
+    async fn set_oplog_policy(
+        &self,
+        request: SetOplogPolicyRequest,
+    ) -> Result<Component, ComponentError> {
+        let component_id = Self::require_component_id(&request.component_id)?;
+
+        let result = self
+            .component_service
+            .set_oplog_policy(
+                &component_id,
+                request.oplog_policy.map(OplogPolicy::from),
+                &DefaultComponentOwner,
+            )
+            .await?;
+
+        Ok(result.into())
+    }
+
+    async fn set_capability_policy(
+        &self,
+        request: SetCapabilityPolicyRequest,
+    ) -> Result<Component, ComponentError> {
+        let component_id = Self::require_component_id(&request.component_id)?;
+
+        let capability_policy = request
+            .capability_policy
+            .map(CapabilityPolicy::try_from)
+            .transpose()
+            .map_err(|err| bad_request_error(&format!("Invalid capability policy: {err}")))?;
+
+        let result = self
+            .component_service
+            .set_capability_policy(&component_id, capability_policy, &DefaultComponentOwner)
+            .await?;
+
+        Ok(result.into())
+    }
 }
 
 #[async_trait]
@@ -856,4 +897,60 @@ impl ComponentService for ComponentGrpcApi {
             result: Some(response),
         }))
     }
+
+    async fn set_oplog_policy(
+        &self,
+        request: Request<SetOplogPolicyRequest>,
+    ) -> Result<Response<SetOplogPolicyResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "set_oplog_policy",
+            component_id = proto_component_id_string(&request.component_id),
+        );
+
+        let response = match self
+            .set_oplog_policy(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(component) => record.succeed(set_oplog_policy_response::Result::Success(component)),
+            Err(error) => record.fail(
+                set_oplog_policy_response::Result::Error(error.clone()),
+                &ComponentTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(SetOplogPolicyResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn set_capability_policy(
+        &self,
+        request: Request<SetCapabilityPolicyRequest>,
+    ) -> Result<Response<SetCapabilityPolicyResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "set_capability_policy",
+            component_id = proto_component_id_string(&request.component_id),
+        );
+
+        let response = match self
+            .set_capability_policy(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(component) => {
+                record.succeed(set_capability_policy_response::Result::Success(component))
+            }
+            Err(error) => record.fail(
+                set_capability_policy_response::Result::Error(error.clone()),
+                &ComponentTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(SetCapabilityPolicyResponse {
+            result: Some(response),
+        }))
+    }
 }
@@ -14,11 +14,13 @@
 
 use crate::grpcapi::component::ComponentGrpcApi;
 use crate::grpcapi::plugin::PluginGrpcApi;
+use crate::grpcapi::secret::SecretGrpcApi;
 use crate::service::Services;
 use futures_util::TryFutureExt;
 use golem_api_grpc::proto;
 use golem_api_grpc::proto::golem::component::v1::component_service_server::ComponentServiceServer;
 use golem_api_grpc::proto::golem::component::v1::plugin_service_server::PluginServiceServer;
+use golem_api_grpc::proto::golem::component::v1::secret_service_server::SecretServiceServer;
 use std::net::SocketAddr;
 use tokio::net::TcpListener;
 use tokio::task::JoinSet;
@@ -28,10 +30,12 @@ use tonic::transport::Server;
 use tracing::Instrument;
 mod component;
 mod plugin;
+mod secret;
 
 pub async fn start_grpc_server(
     addr: SocketAddr,
     services: Services,
+    resolver_access_token: Option<String>,
     join_set: &mut JoinSet<Result<(), anyhow::Error>>,
 ) -> anyhow::Result<u16> {
     let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
@@ -68,6 +72,14 @@ pub async fn start_grpc_server(
                     .accept_compressed(CompressionEncoding::Gzip)
                     .send_compressed(CompressionEncoding::Gzip),
                 )
+                .add_service(
+                    SecretServiceServer::new(SecretGrpcApi {
+                        secret_service: services.secret_service.clone(),
+                        resolver_access_token,
+                    })
+                    .accept_compressed(CompressionEncoding::Gzip)
+                    .send_compressed(CompressionEncoding::Gzip),
+                )
                 .serve_with_incoming(TcpListenerStream::new(listener))
                 .map_err(anyhow::Error::from)
                 .await
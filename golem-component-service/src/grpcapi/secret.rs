@@ -0,0 +1,112 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::grpcapi::component::bad_request_error;
+use async_trait::async_trait;
+use golem_api_grpc::proto::golem::common::ErrorBody;
+use golem_api_grpc::proto::golem::component::v1::{
+    component_error, resolve_secrets_response, ComponentError, ResolveSecretsRequest,
+    ResolveSecretsResponse, ResolveSecretsSuccessResponse,
+};
+use golem_common::grpc::proto_component_id_string;
+use golem_common::model::component::DefaultComponentOwner;
+use golem_common::model::ComponentId;
+use golem_common::recorded_grpc_api_request;
+use golem_component_service_base::api::common::ComponentTraceErrorKind;
+use golem_component_service_base::service::secret::SecretService;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tonic::metadata::MetadataMap;
+use tonic::{Request, Response, Status};
+use tracing::Instrument;
+
+pub struct SecretGrpcApi {
+    pub secret_service: Arc<dyn SecretService<DefaultComponentOwner> + Sync + Send>,
+    /// Token the worker executors authorize with, see `SecretsConfig::resolver_access_token`
+    pub resolver_access_token: Option<String>,
+}
+
+impl SecretGrpcApi {
+    fn authorize(&self, metadata: &MetadataMap) -> Result<(), ComponentError> {
+        let token = metadata
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        match (&self.resolver_access_token, token) {
+            (Some(expected), Some(token)) if expected.eq_ignore_ascii_case(token) => Ok(()),
+            _ => Err(ComponentError {
+                error: Some(component_error::Error::Unauthorized(ErrorBody {
+                    error: "Only the worker executors can resolve the values of secrets"
+                        .to_string(),
+                })),
+            }),
+        }
+    }
+
+    async fn resolve_secrets(
+        &self,
+        metadata: &MetadataMap,
+        request: &ResolveSecretsRequest,
+    ) -> Result<HashMap<String, String>, ComponentError> {
+        self.authorize(metadata)?;
+
+        let component_id: ComponentId = request
+            .component_id
+            .clone()
+            .ok_or_else(|| bad_request_error("Missing component id"))?
+            .try_into()
+            .map_err(|err| bad_request_error(&format!("Invalid component id: {err}")))?;
+
+        let values = self
+            .secret_service
+            .resolve(&component_id, &request.names)
+            .await?;
+
+        Ok(values)
+    }
+}
+
+#[async_trait]
+impl golem_api_grpc::proto::golem::component::v1::secret_service_server::SecretService
+    for SecretGrpcApi
+{
+    async fn resolve_secrets(
+        &self,
+        request: Request<ResolveSecretsRequest>,
+    ) -> Result<Response<ResolveSecretsResponse>, Status> {
+        let (metadata, _, request) = request.into_parts();
+        let record = recorded_grpc_api_request!(
+            "resolve_secrets",
+            component_id = proto_component_id_string(&request.component_id),
+        );
+
+        let response = match self
+            .resolve_secrets(&metadata, &request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(values) => record.succeed(resolve_secrets_response::Result::Success(
+                ResolveSecretsSuccessResponse { values },
+            )),
+            Err(error) => record.fail(
+                resolve_secrets_response::Result::Error(error.clone()),
+                &ComponentTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(ResolveSecretsResponse {
+            result: Some(response),
+        }))
+    }
+}
@@ -18,6 +18,7 @@ use crate::service::Services;
 use anyhow::{anyhow, Context};
 use golem_common::config::DbConfig;
 use golem_common::golem_version;
+use golem_component_service_base::config::ComponentGcConfig;
 use golem_service_base::db;
 use golem_service_base::migration::Migrations;
 use poem::listener::Acceptor;
@@ -28,7 +29,7 @@ use poem_openapi::OpenApiService;
 use prometheus::Registry;
 use std::net::{Ipv4Addr, SocketAddrV4};
 use tokio::task::JoinSet;
-use tracing::{info, Instrument};
+use tracing::{info, warn, Instrument};
 
 pub mod api;
 pub mod config;
@@ -94,6 +95,7 @@ impl ComponentService {
     ) -> Result<RunDetails, anyhow::Error> {
         let grpc_port = self.start_grpc_server(join_set).await?;
         let http_port = self.start_http_server(join_set).await?;
+        self.start_component_gc(join_set);
         Ok(RunDetails {
             http_port,
             grpc_port,
@@ -111,6 +113,7 @@ impl ComponentService {
         grpcapi::start_grpc_server(
             SocketAddrV4::new(Ipv4Addr::new(0, 0, 0, 0), self.config.grpc_port).into(),
             self.services.clone(),
+            self.config.secrets.resolver_access_token.clone(),
             join_set,
         )
         .await
@@ -146,4 +149,25 @@ impl ComponentService {
 
         Ok(port)
     }
+
+    fn start_component_gc(&self, join_set: &mut JoinSet<Result<(), anyhow::Error>>) {
+        let ComponentGcConfig::Enabled(config) = &self.config.retention.gc else {
+            return;
+        };
+        let component_gc_service = self.services.component_gc_service.clone();
+        let gc_interval = config.interval;
+
+        join_set.spawn(
+            async move {
+                let mut interval = tokio::time::interval(gc_interval);
+                loop {
+                    interval.tick().await;
+                    if let Err(err) = component_gc_service.collect_garbage().await {
+                        warn!("Failed to collect component garbage: {err}");
+                    }
+                }
+            }
+            .in_current_span(),
+        );
+    }
 }
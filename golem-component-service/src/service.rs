@@ -22,16 +22,25 @@ use golem_component_service_base::repo::component::{
     ComponentRepo, DbComponentRepo, LoggedComponentRepo,
 };
 use golem_component_service_base::repo::plugin::{DbPluginRepo, LoggedPluginRepo, PluginRepo};
+use golem_component_service_base::repo::secret::{DbSecretRepo, LoggedSecretRepo, SecretRepo};
 use golem_component_service_base::service::component::{ComponentService, ComponentServiceDefault};
 use golem_component_service_base::service::component_compilation::{
     ComponentCompilationService, ComponentCompilationServiceDefault,
     ComponentCompilationServiceDisabled,
 };
+use golem_component_service_base::service::component_gc::{
+    ComponentGcService, ComponentGcServiceDefault,
+};
 use golem_component_service_base::service::component_object_store;
 use golem_component_service_base::service::component_object_store::{
     ComponentObjectStore, LoggedComponentObjectStore,
 };
+use golem_component_service_base::service::component_references::ComponentReferenceServiceDefault;
+use golem_component_service_base::service::component_transformer::WasmComponentTransformer;
 use golem_component_service_base::service::plugin::{PluginService, PluginServiceDefault};
+use golem_component_service_base::service::secret::{
+    SecretEncryption, SecretService, SecretServiceDefault,
+};
 use golem_service_base::config::BlobStorageConfig;
 use golem_service_base::db;
 use golem_service_base::service::initial_component_files::InitialComponentFilesService;
@@ -46,11 +55,13 @@ pub struct Services {
     pub compilation_service: Arc<dyn ComponentCompilationService + Sync + Send>,
     pub plugin_service:
         Arc<dyn PluginService<DefaultPluginOwner, DefaultPluginScope> + Send + Sync>,
+    pub secret_service: Arc<dyn SecretService<DefaultComponentOwner> + Send + Sync>,
+    pub component_gc_service: Arc<dyn ComponentGcService<DefaultComponentOwner> + Send + Sync>,
 }
 
 impl Services {
     pub async fn new(config: &ComponentServiceConfig) -> Result<Services, String> {
-        let (component_repo, plugin_repo) = match &config.db {
+        let (component_repo, plugin_repo, secret_repo) = match &config.db {
             DbConfig::Postgres(db_config) => {
                 let db_pool = db::create_postgres_pool(db_config)
                     .await
@@ -65,7 +76,10 @@ impl Services {
                 > = Arc::new(LoggedPluginRepo::new(DbPluginRepo::new(
                     db_pool.clone().into(),
                 )));
-                (component_repo, plugin_repo)
+                let secret_repo: Arc<dyn SecretRepo + Sync + Send> = Arc::new(
+                    LoggedSecretRepo::new(DbSecretRepo::new(db_pool.clone().into())),
+                );
+                (component_repo, plugin_repo, secret_repo)
             }
             DbConfig::Sqlite(db_config) => {
                 let db_pool = db::create_sqlite_pool(db_config)
@@ -80,7 +94,10 @@ impl Services {
                 > = Arc::new(LoggedPluginRepo::new(DbPluginRepo::new(
                     db_pool.clone().into(),
                 )));
-                (component_repo, plugin_repo)
+                let secret_repo: Arc<dyn SecretRepo + Sync + Send> = Arc::new(
+                    LoggedSecretRepo::new(DbSecretRepo::new(db_pool.clone().into())),
+                );
+                (component_repo, plugin_repo, secret_repo)
             }
         };
 
@@ -141,6 +158,11 @@ impl Services {
             dyn PluginService<DefaultPluginOwner, DefaultPluginScope> + Sync + Send,
         > = Arc::new(PluginServiceDefault::new(plugin_repo));
 
+        let wasm_transformer = Arc::new(
+            WasmComponentTransformer::new(config.wasm_transformer.clone())
+                .map_err(|e| format!("Failed to create WASM component transformer: {}", e))?,
+        );
+
         let component_service: Arc<dyn ComponentService<DefaultComponentOwner> + Sync + Send> =
             Arc::new(ComponentServiceDefault::new(
                 component_repo.clone(),
@@ -148,12 +170,33 @@ impl Services {
                 compilation_service.clone(),
                 initial_component_files_service.clone(),
                 plugin_service.clone(),
+                wasm_transformer,
+            ));
+
+        let secret_service: Arc<dyn SecretService<DefaultComponentOwner> + Sync + Send> =
+            Arc::new(SecretServiceDefault::new(
+                secret_repo,
+                component_service.clone(),
+                SecretEncryption::new(&config.secrets)?,
+            ));
+
+        let component_gc_service: Arc<dyn ComponentGcService<DefaultComponentOwner> + Sync + Send> =
+            Arc::new(ComponentGcServiceDefault::new(
+                component_repo.clone(),
+                object_store.clone(),
+                blob_storage.clone(),
+                Arc::new(ComponentReferenceServiceDefault::new(
+                    config.retention.worker_service_uri(),
+                )),
+                config.retention.clone(),
             ));
 
         Ok(Services {
             component_service,
             compilation_service,
             plugin_service,
+            secret_service,
+            component_gc_service,
         })
     }
 }
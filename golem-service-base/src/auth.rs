@@ -21,6 +21,18 @@ impl IntoIterator for EmptyAuthCtx {
     }
 }
 
+// The authenticated principal performing a request, as recorded in audit trails
+pub trait HasPrincipal {
+    fn principal(&self) -> String;
+}
+
+// Without authentication every request is performed by the default account
+impl HasPrincipal for EmptyAuthCtx {
+    fn principal(&self) -> String {
+        AccountId::placeholder().to_string()
+    }
+}
+
 #[derive(
     Default,
     Debug,
@@ -75,6 +75,8 @@ pub struct S3BlobStorageConfig {
     pub compressed_oplog_buckets: Vec<String>,
     pub use_minio_credentials: bool,
     pub initial_component_files_bucket: String,
+    pub worker_volumes_bucket: String,
+    pub oplog_export_bucket: String,
 }
 
 impl Default for S3BlobStorageConfig {
@@ -90,6 +92,8 @@ impl Default for S3BlobStorageConfig {
             compressed_oplog_buckets: vec!["oplog-archive-1".to_string()],
             use_minio_credentials: false,
             initial_component_files_bucket: "golem-initial-component-files".to_string(),
+            worker_volumes_bucket: "golem-worker-volumes".to_string(),
+            oplog_export_bucket: "golem-oplog-export".to_string(),
         }
     }
 }
@@ -14,22 +14,23 @@
 
 use bincode::{Decode, Encode};
 use golem_api_grpc::proto::golem::worker::OplogEntryWithIndex;
+use golem_common::config::RetryConfig;
 use golem_common::model::component_metadata::ComponentMetadata;
 use golem_common::model::oplog::OplogIndex;
 use golem_common::model::plugin::PluginInstallation;
-use golem_common::model::public_oplog::{OplogCursor, PublicOplogEntry};
+use golem_common::model::public_oplog::{OplogCursor, PublicOplogEntry, PublicRetryConfig};
 use golem_common::model::{AccountId, PluginInstallationId};
 use golem_common::model::{
     ComponentFilePermissions, ComponentFileSystemNode, ComponentFileSystemNodeDetails, ComponentId,
-    ComponentType, ComponentVersion, InitialComponentFile, PromiseId, ScanCursor, ShardId,
-    Timestamp, WorkerFilter, WorkerId, WorkerStatus,
+    ComponentType, ComponentVersion, InitialComponentFile, InvocationOptions, PromiseId,
+    ScanCursor, ShardId, Timestamp, WorkerFilter, WorkerId, WorkerStatus,
 };
 use golem_common::SafeDisplay;
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use poem_openapi::{Enum, NewType, Object, Union};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 use std::{collections::HashMap, fmt::Display, fmt::Formatter};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
@@ -37,6 +38,9 @@ pub struct WorkerCreationRequest {
     pub name: String,
     pub args: Vec<String>,
     pub env: HashMap<String, String>,
+    #[serde(default)]
+    #[oai(default)]
+    pub labels: HashMap<String, String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
@@ -1047,9 +1051,49 @@ impl From<crate::model::GolemErrorShardingNotReady>
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object, thiserror::Error)]
+#[error("Invocation timed out")]
+pub struct GolemErrorInvocationTimedOut {}
+
+impl SafeDisplay for GolemErrorInvocationTimedOut {
+    fn to_safe_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::worker::v1::InvocationTimedOut>
+    for crate::model::GolemErrorInvocationTimedOut
+{
+    fn from(_value: golem_api_grpc::proto::golem::worker::v1::InvocationTimedOut) -> Self {
+        Self {}
+    }
+}
+
+impl From<crate::model::GolemErrorInvocationTimedOut>
+    for golem_api_grpc::proto::golem::worker::v1::InvocationTimedOut
+{
+    fn from(_value: crate::model::GolemErrorInvocationTimedOut) -> Self {
+        Self {}
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
 pub struct InvokeParameters {
     pub params: Vec<TypeAnnotatedValue>,
+    /// Retry policy used for this invocation instead of the worker's retry policy
+    pub retry_policy: Option<PublicRetryConfig>,
+    /// The invocation fails with a timeout error if it does not complete within this duration
+    #[serde(default, with = "humantime_serde")]
+    pub timeout: Option<Duration>,
+}
+
+impl InvokeParameters {
+    pub fn invocation_options(&self) -> InvocationOptions {
+        InvocationOptions::with_timeout(
+            self.retry_policy.clone().map(RetryConfig::from),
+            self.timeout,
+        )
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
@@ -1073,6 +1117,23 @@ pub struct ActivatePluginResponse {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
 pub struct DeactivatePluginResponse {}
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct UpdateWorkerLabelsRequest {
+    pub labels: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct UpdateWorkerLabelsResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct PutFileResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct DeleteFileResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct MakeDirectoryResponse {}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
@@ -1206,6 +1267,49 @@ pub struct UpdateWorkerRequest {
     pub target_version: ComponentVersion,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct DryRunUpdateWorkerRequest {
+    pub target_version: ComponentVersion,
+}
+
+/// Outcome of replaying a worker against a new component version in a sandbox
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct UpdateDryRunResult {
+    pub target_version: ComponentVersion,
+    pub succeeded: bool,
+    pub replayed_until: OplogIndex,
+    pub diverged_at: Option<OplogIndex>,
+    pub error: Option<String>,
+}
+
+impl From<golem_api_grpc::proto::golem::worker::UpdateDryRunResult> for UpdateDryRunResult {
+    fn from(value: golem_api_grpc::proto::golem::worker::UpdateDryRunResult) -> Self {
+        Self {
+            target_version: value.target_version,
+            succeeded: value.succeeded,
+            replayed_until: OplogIndex::from_u64(value.replayed_until),
+            diverged_at: value.diverged_at.map(OplogIndex::from_u64),
+            error: value.error,
+        }
+    }
+}
+
+impl From<UpdateDryRunResult> for golem_api_grpc::proto::golem::worker::UpdateDryRunResult {
+    fn from(value: UpdateDryRunResult) -> Self {
+        Self {
+            target_version: value.target_version,
+            succeeded: value.succeeded,
+            replayed_until: value.replayed_until.into(),
+            diverged_at: value.diverged_at.map(|idx| idx.into()),
+            error: value.error,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
 pub struct WorkersMetadataRequest {
     pub filter: Option<WorkerFilter>,
@@ -1238,6 +1342,7 @@ pub struct WorkerMetadata {
     pub total_linear_memory_size: u64,
     pub owned_resources: HashMap<u64, ResourceMetadata>,
     pub active_plugins: HashSet<PluginInstallationId>,
+    pub labels: HashMap<String, String>,
 }
 
 impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerMetadata> for WorkerMetadata {
@@ -1273,6 +1378,7 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerMetadata> for WorkerMet
                 .into_iter()
                 .map(|id| id.try_into())
                 .collect::<Result<HashSet<_>, _>>()?,
+            labels: value.labels,
         })
     }
 }
@@ -1303,6 +1409,7 @@ impl From<WorkerMetadata> for golem_api_grpc::proto::golem::worker::WorkerMetada
                 .into_iter()
                 .map(|id| id.into())
                 .collect(),
+            labels: value.labels,
         }
     }
 }
@@ -1530,6 +1637,8 @@ pub enum GolemError {
     InitialComponentFileDownloadFailed(GolemErrorInitialComponentFileDownloadFailed),
     #[error(transparent)]
     FileSystemError(GolemErrorFileSystemError),
+    #[error(transparent)]
+    InvocationTimedOut(GolemErrorInvocationTimedOut),
 }
 
 impl SafeDisplay for GolemError {
@@ -1560,6 +1669,7 @@ impl SafeDisplay for GolemError {
             GolemError::ShardingNotReady(inner) => inner.to_safe_string(),
             GolemError::InitialComponentFileDownloadFailed(inner) => inner.to_safe_string(),
             GolemError::FileSystemError(inner) => inner.to_safe_string(),
+            GolemError::InvocationTimedOut(inner) => inner.to_safe_string(),
         }
     }
 }
@@ -1650,6 +1760,9 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::v1::WorkerExecutionError> for
             Some(golem_api_grpc::proto::golem::worker::v1::worker_execution_error::Error::FileSystemError(err)) => {
                 Ok(GolemError::FileSystemError(err.into()))
             }
+            Some(golem_api_grpc::proto::golem::worker::v1::worker_execution_error::Error::InvocationTimedOut(err)) => {
+                Ok(GolemError::InvocationTimedOut(err.into()))
+            }
             None => Err("Missing field: error".to_string()),
         }
     }
@@ -1741,6 +1854,9 @@ impl From<GolemError> for golem_api_grpc::proto::golem::worker::v1::worker_execu
             GolemError::FileSystemError(err) => {
                 golem_api_grpc::proto::golem::worker::v1::worker_execution_error::Error::FileSystemError(err.into())
             }
+            GolemError::InvocationTimedOut(err) => {
+                golem_api_grpc::proto::golem::worker::v1::worker_execution_error::Error::InvocationTimedOut(err.into())
+            }
         }
     }
 }
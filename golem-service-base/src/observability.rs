@@ -18,14 +18,31 @@ use tokio::net::{TcpListener, ToSocketAddrs};
 use tokio::task::JoinSet;
 use tokio_stream::wrappers::TcpListenerStream;
 use tracing::{info, Instrument};
+use warp::filters::BoxedFilter;
 use warp::hyper::Body;
-use warp::Filter;
+use warp::{Filter, Rejection};
 
 pub async fn start_health_and_metrics_server(
     addr: impl ToSocketAddrs,
     registry: Registry,
     body_message: &'static str,
     join_set: &mut JoinSet<Result<(), anyhow::Error>>,
+) -> Result<u16, anyhow::Error> {
+    let no_routes = warp::any()
+        .and_then(|| async { Err::<Response<Body>, Rejection>(warp::reject::not_found()) })
+        .boxed();
+
+    start_health_and_metrics_server_with_routes(addr, registry, body_message, no_routes, join_set)
+        .await
+}
+
+/// Starts the health and metrics server, additionally serving the given routes on the same port
+pub async fn start_health_and_metrics_server_with_routes(
+    addr: impl ToSocketAddrs,
+    registry: Registry,
+    body_message: &'static str,
+    routes: BoxedFilter<(Response<Body>,)>,
+    join_set: &mut JoinSet<Result<(), anyhow::Error>>,
 ) -> Result<u16, anyhow::Error> {
     let healthcheck = warp::path!("healthcheck").map(move || {
         Response::builder()
@@ -41,7 +58,7 @@ pub async fn start_health_and_metrics_server(
 
     join_set.spawn(
         async move {
-            warp::serve(healthcheck.or(metrics))
+            warp::serve(healthcheck.or(metrics).or(routes))
                 .run_incoming(TcpListenerStream::new(listener))
                 .await;
             Ok(())
@@ -91,6 +91,24 @@ impl FileSystemBlobStorage {
                 result.push("initial_component_files");
                 result.push(account_id.to_string());
             }
+            BlobStorageNamespace::WorkerVolume {
+                account_id,
+                component_id,
+                volume_name,
+            } => {
+                result.push("worker_volume");
+                result.push(account_id.to_string());
+                result.push(component_id.to_string());
+                result.push(volume_name);
+            }
+            BlobStorageNamespace::OplogExport {
+                account_id,
+                component_id,
+            } => {
+                result.push("oplog_export");
+                result.push(account_id.to_string());
+                result.push(component_id.to_string());
+            }
         }
 
         result.push(path);
@@ -358,6 +358,15 @@ pub enum BlobStorageNamespace {
         component_id: ComponentId,
         level: usize,
     },
+    WorkerVolume {
+        account_id: AccountId,
+        component_id: ComponentId,
+        volume_name: String,
+    },
+    OplogExport {
+        account_id: AccountId,
+        component_id: ComponentId,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -81,6 +81,8 @@ impl S3BlobStorage {
             BlobStorageNamespace::InitialComponentFiles { .. } => {
                 &self.config.initial_component_files_bucket
             }
+            BlobStorageNamespace::WorkerVolume { .. } => &self.config.worker_volumes_bucket,
+            BlobStorageNamespace::OplogExport { .. } => &self.config.oplog_export_bucket,
         }
     }
 
@@ -144,6 +146,43 @@ impl S3BlobStorage {
                         .to_path_buf()
                 }
             }
+            BlobStorageNamespace::WorkerVolume {
+                account_id,
+                component_id,
+                volume_name,
+            } => {
+                let account_id_string = account_id.to_string();
+                let component_id_string = component_id.to_string();
+                if self.config.object_prefix.is_empty() {
+                    Path::new(&account_id_string)
+                        .join(component_id_string)
+                        .join(volume_name)
+                        .to_path_buf()
+                } else {
+                    Path::new(&self.config.object_prefix)
+                        .join(account_id_string)
+                        .join(component_id_string)
+                        .join(volume_name)
+                        .to_path_buf()
+                }
+            }
+            BlobStorageNamespace::OplogExport {
+                account_id,
+                component_id,
+            } => {
+                let account_id_string = account_id.to_string();
+                let component_id_string = component_id.to_string();
+                if self.config.object_prefix.is_empty() {
+                    Path::new(&account_id_string)
+                        .join(component_id_string)
+                        .to_path_buf()
+                } else {
+                    Path::new(&self.config.object_prefix)
+                        .join(account_id_string)
+                        .join(component_id_string)
+                        .to_path_buf()
+                }
+            }
         }
     }
 
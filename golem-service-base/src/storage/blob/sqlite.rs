@@ -79,6 +79,18 @@ impl SqliteBlobStorage {
             BlobStorageNamespace::InitialComponentFiles { account_id } => {
                 format!("initial_component_files-{}", account_id.value)
             }
+            BlobStorageNamespace::WorkerVolume {
+                account_id,
+                component_id,
+                volume_name,
+            } => format!(
+                "worker_volume-{}-{}-{}",
+                account_id.value, component_id, volume_name
+            ),
+            BlobStorageNamespace::OplogExport {
+                account_id,
+                component_id,
+            } => format!("oplog_export-{}-{}", account_id.value, component_id),
         }
     }
 
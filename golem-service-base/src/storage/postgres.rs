@@ -0,0 +1,197 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use golem_common::config::DbPostgresConfig;
+use golem_common::metrics::db::{record_db_failure, record_db_success};
+use sqlx::migrate::Migrator;
+use sqlx::postgres::{PgArguments, PgConnectOptions, PgQueryResult, PgRow};
+use sqlx::query::{Query, QueryAs};
+use sqlx::{Connection, Error, Executor, FromRow, IntoArguments, PgConnection, Postgres};
+use std::time::Instant;
+
+use crate::db::create_postgres_pool;
+
+#[derive(Clone, Debug)]
+pub struct PostgresPool {
+    pool: sqlx::PgPool,
+}
+
+impl PostgresPool {
+    pub async fn new(pool: sqlx::PgPool) -> Result<Self, anyhow::Error> {
+        Ok(Self { pool })
+    }
+
+    /// Connects to the configured database, creating the configured schema if it does not exist yet
+    pub async fn configured(config: &DbPostgresConfig) -> Result<Self, anyhow::Error> {
+        let schema = config.schema.clone().unwrap_or("public".to_string());
+        let options = PgConnectOptions::new()
+            .host(config.host.as_str())
+            .port(config.port)
+            .database(config.database.as_str())
+            .username(config.username.as_str())
+            .password(config.password.as_str());
+        let mut conn = PgConnection::connect_with(&options).await?;
+        conn.execute(sqlx::query(&format!(
+            "CREATE SCHEMA IF NOT EXISTS {schema};"
+        )))
+        .await?;
+        let _ = conn.close().await;
+
+        let pool = create_postgres_pool(config).await?;
+        PostgresPool::new(pool).await
+    }
+
+    pub async fn execute<'a>(
+        &self,
+        query: Query<'a, Postgres, PgArguments>,
+    ) -> Result<PgQueryResult, String> {
+        query
+            .execute(&self.pool)
+            .await
+            .map_err(|err| err.to_string())
+    }
+
+    /// Applies the migrations not applied yet to the database
+    pub async fn migrate(&self, migrator: &Migrator) -> Result<(), String> {
+        migrator
+            .run(&self.pool)
+            .await
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn with(&self, svc_name: &'static str, api_name: &'static str) -> PostgresLabelledApi {
+        PostgresLabelledApi {
+            svc_name,
+            api_name,
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+pub struct PostgresLabelledTransaction {
+    tx: sqlx::Transaction<'static, Postgres>,
+    start: Instant,
+}
+
+impl PostgresLabelledTransaction {
+    pub async fn execute<'a>(
+        &mut self,
+        query: Query<'a, Postgres, PgArguments>,
+    ) -> Result<PgQueryResult, String> {
+        query
+            .execute(&mut *self.tx)
+            .await
+            .map_err(|err| err.to_string())
+    }
+
+    async fn commit(self) -> Result<(), Error> {
+        self.tx.commit().await
+    }
+}
+
+pub struct PostgresLabelledApi {
+    svc_name: &'static str,
+    api_name: &'static str,
+    pool: sqlx::PgPool,
+}
+
+impl PostgresLabelledApi {
+    pub async fn execute<'a>(
+        &self,
+        query: Query<'a, Postgres, PgArguments>,
+    ) -> Result<PgQueryResult, String> {
+        let start = Instant::now();
+        self.record(start, query.execute(&self.pool).await)
+    }
+
+    pub async fn fetch_optional<'a, A>(
+        &self,
+        query: Query<'a, Postgres, A>,
+    ) -> Result<Option<PgRow>, String>
+    where
+        A: 'a + IntoArguments<'a, Postgres>,
+    {
+        let start = Instant::now();
+        self.record(start, query.fetch_optional(&self.pool).await)
+    }
+
+    pub async fn fetch_optional_as<'a, O, A>(
+        &self,
+        query_as: QueryAs<'a, Postgres, O, A>,
+    ) -> Result<Option<O>, String>
+    where
+        A: 'a + IntoArguments<'a, Postgres>,
+        O: 'a + Send + Unpin + for<'r> FromRow<'r, PgRow>,
+    {
+        let start = Instant::now();
+        self.record(start, query_as.fetch_optional(&self.pool).await)
+    }
+
+    pub async fn fetch_all<'a, O, A>(
+        &self,
+        query_as: QueryAs<'a, Postgres, O, A>,
+    ) -> Result<Vec<O>, String>
+    where
+        A: 'a + IntoArguments<'a, Postgres>,
+        O: 'a + Send + Unpin + for<'r> FromRow<'r, PgRow>,
+    {
+        let start = Instant::now();
+        self.record(start, query_as.fetch_all(&self.pool).await)
+    }
+
+    pub async fn begin(&self) -> Result<PostgresLabelledTransaction, String> {
+        let tx = self.pool.begin().await.map_err(|err| err.to_string())?;
+        Ok(PostgresLabelledTransaction {
+            tx,
+            start: Instant::now(),
+        })
+    }
+
+    pub async fn commit(&self, tx: PostgresLabelledTransaction) -> Result<(), String> {
+        let start = tx.start;
+        let result = tx.commit().await;
+        self.record(start, result)
+    }
+
+    fn record<R>(&self, start: Instant, result: Result<R, Error>) -> Result<R, String> {
+        let end = Instant::now();
+        match result {
+            Ok(result) => {
+                record_db_success(
+                    "postgres",
+                    self.svc_name,
+                    self.api_name,
+                    end.duration_since(start),
+                );
+                Ok(result)
+            }
+            Err(err) => {
+                record_db_failure("postgres", self.svc_name, self.api_name);
+                Err(err.to_string())
+            }
+        }
+    }
+}
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct DBValue {
+    value: Vec<u8>,
+}
+
+impl DBValue {
+    pub fn into_bytes(self) -> Bytes {
+        Bytes::from(self.value)
+    }
+}
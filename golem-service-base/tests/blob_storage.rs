@@ -781,6 +781,18 @@ async fn create_buckets(host_port: u16, config: &S3BlobStorageConfig) {
     for bucket in &config.compressed_oplog_buckets {
         client.create_bucket().bucket(bucket).send().await.unwrap();
     }
+    client
+        .create_bucket()
+        .bucket(&config.worker_volumes_bucket)
+        .send()
+        .await
+        .unwrap();
+    client
+        .create_bucket()
+        .bucket(&config.oplog_export_bucket)
+        .send()
+        .await
+        .unwrap();
 }
 
 pub(crate) fn compilation_cache() -> BlobStorageNamespace {
@@ -797,6 +809,25 @@ pub(crate) fn compressed_oplog() -> BlobStorageNamespace {
     }
 }
 
+pub(crate) fn worker_volume() -> BlobStorageNamespace {
+    BlobStorageNamespace::WorkerVolume {
+        account_id: AccountId {
+            value: "test-account".to_string(),
+        },
+        component_id: ComponentId(Uuid::new_v4()),
+        volume_name: "data".to_string(),
+    }
+}
+
+pub(crate) fn oplog_export() -> BlobStorageNamespace {
+    BlobStorageNamespace::OplogExport {
+        account_id: AccountId {
+            value: "test-account".to_string(),
+        },
+        component_id: ComponentId(Uuid::new_v4()),
+    }
+}
+
 pub(crate) async fn sqlite() -> impl GetBlobStorage {
     let sqlx_pool_sqlite = SqlitePoolOptions::new()
         .max_connections(10)
@@ -864,3 +895,55 @@ test_blob_storage!(
     crate::blob_storage::sqlite,
     crate::blob_storage::compressed_oplog
 );
+
+test_blob_storage!(
+    in_memory_wv,
+    crate::blob_storage::in_memory,
+    crate::blob_storage::worker_volume
+);
+test_blob_storage!(
+    filesystem_wv,
+    crate::blob_storage::fs,
+    crate::blob_storage::worker_volume
+);
+test_blob_storage!(
+    s3_no_prefix_wv,
+    crate::blob_storage::s3,
+    crate::blob_storage::worker_volume
+);
+test_blob_storage!(
+    s3_prefixed_wv,
+    crate::blob_storage::s3_prefixed,
+    crate::blob_storage::worker_volume
+);
+test_blob_storage!(
+    sqlite_wv,
+    crate::blob_storage::sqlite,
+    crate::blob_storage::worker_volume
+);
+
+test_blob_storage!(
+    in_memory_oe,
+    crate::blob_storage::in_memory,
+    crate::blob_storage::oplog_export
+);
+test_blob_storage!(
+    filesystem_oe,
+    crate::blob_storage::fs,
+    crate::blob_storage::oplog_export
+);
+test_blob_storage!(
+    s3_no_prefix_oe,
+    crate::blob_storage::s3,
+    crate::blob_storage::oplog_export
+);
+test_blob_storage!(
+    s3_prefixed_oe,
+    crate::blob_storage::s3_prefixed,
+    crate::blob_storage::oplog_export
+);
+test_blob_storage!(
+    sqlite_oe,
+    crate::blob_storage::sqlite,
+    crate::blob_storage::oplog_export
+);
@@ -72,11 +72,37 @@ impl Pod {
     pub fn from_register_request(
         source_ip: IpAddr,
         request: golem::shardmanager::v1::RegisterRequest,
+    ) -> Result<Self, ShardManagerError> {
+        Self::resolve(
+            source_ip,
+            request.host,
+            request.port as u16,
+            request.pod_name,
+        )
+    }
+
+    pub fn from_unregister_request(
+        source_ip: IpAddr,
+        request: golem::shardmanager::v1::UnregisterRequest,
+    ) -> Result<Self, ShardManagerError> {
+        Self::resolve(
+            source_ip,
+            request.host,
+            request.port as u16,
+            request.pod_name,
+        )
+    }
+
+    fn resolve(
+        source_ip: IpAddr,
+        host: String,
+        port: u16,
+        pod_name: Option<String>,
     ) -> Result<Self, ShardManagerError> {
         let pod = Pod {
-            host: request.host,
-            port: request.port as u16,
-            pod_name: request.pod_name,
+            host,
+            port,
+            pod_name,
             ip: source_ip,
         };
 
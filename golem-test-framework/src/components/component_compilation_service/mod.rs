@@ -26,7 +26,7 @@ use tonic::transport::Channel;
 use tracing::Level;
 
 use crate::components::component_service::ComponentService;
-use crate::components::{wait_for_startup_grpc, EnvVarBuilder, GolemEnvVars};
+use crate::components::{wait_for_startup_grpc, EnvVarBuilder, ExtendedGolemEnvVars, GolemEnvVars};
 use golem_api_grpc::proto::golem::componentcompilation::v1::component_compilation_service_client::ComponentCompilationServiceClient;
 use golem_common::model::ComponentId;
 
@@ -144,3 +144,23 @@ impl ComponentCompilationServiceEnvVars for GolemEnvVars {
             .build()
     }
 }
+
+#[async_trait]
+impl ComponentCompilationServiceEnvVars for ExtendedGolemEnvVars {
+    async fn env_vars(
+        &self,
+        http_port: u16,
+        grpc_port: u16,
+        component_service: Arc<dyn ComponentService + Send + Sync + 'static>,
+        verbosity: Level,
+    ) -> HashMap<String, String> {
+        EnvVarBuilder::default()
+            .with_all(
+                GolemEnvVars()
+                    .env_vars(http_port, grpc_port, component_service, verbosity)
+                    .await,
+            )
+            .with_all(self.extra.clone())
+            .build()
+    }
+}
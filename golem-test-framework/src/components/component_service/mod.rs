@@ -506,6 +506,15 @@ impl ComponentServiceEnvVars for GolemEnvVars {
                 "GOLEM__BLOB_STORAGE__CONFIG__ROOT",
                 "/tmp/ittest-local-object-store/golem",
             )
+            // Fixed key, so the secrets created by the tests survive restarts of the service
+            .with_str(
+                "GOLEM__SECRETS__ENCRYPTION_KEY",
+                "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+            )
+            .with_str(
+                "GOLEM__SECRETS__RESOLVER_ACCESS_TOKEN",
+                "2A354594-7A63-4091-A46B-CC58D379F677",
+            )
             .with("GOLEM__GRPC_PORT", grpc_port.to_string())
             .with("GOLEM__HTTP_PORT", http_port.to_string())
             .with_all(rdb.info().env("golem_component"));
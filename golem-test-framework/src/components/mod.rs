@@ -204,3 +204,15 @@ impl EnvVarBuilder {
 
 #[derive(Debug, Clone, Copy)]
 pub struct GolemEnvVars();
+
+/// Golem's default environment variables, extended with (or overridden by) additional ones
+#[derive(Debug, Clone)]
+pub struct ExtendedGolemEnvVars {
+    extra: HashMap<String, String>,
+}
+
+impl ExtendedGolemEnvVars {
+    pub fn new(extra: HashMap<String, String>) -> Self {
+        Self { extra }
+    }
+}
@@ -27,7 +27,7 @@ use crate::components::component_service::ComponentService;
 use crate::components::redis::Redis;
 use crate::components::shard_manager::ShardManager;
 use crate::components::worker_service::WorkerService;
-use crate::components::{wait_for_startup_grpc, EnvVarBuilder, GolemEnvVars};
+use crate::components::{wait_for_startup_grpc, EnvVarBuilder, ExtendedGolemEnvVars, GolemEnvVars};
 
 pub mod docker;
 pub mod k8s;
@@ -191,3 +191,34 @@ impl WorkerExecutorEnvVars for GolemEnvVars {
             .build()
     }
 }
+
+#[async_trait]
+impl WorkerExecutorEnvVars for ExtendedGolemEnvVars {
+    async fn env_vars(
+        &self,
+        http_port: u16,
+        grpc_port: u16,
+        component_service: Arc<dyn ComponentService + Send + Sync + 'static>,
+        shard_manager: Arc<dyn ShardManager + Send + Sync + 'static>,
+        worker_service: Arc<dyn WorkerService + Send + Sync + 'static>,
+        redis: Arc<dyn Redis + Send + Sync + 'static>,
+        verbosity: Level,
+    ) -> HashMap<String, String> {
+        EnvVarBuilder::default()
+            .with_all(
+                GolemEnvVars()
+                    .env_vars(
+                        http_port,
+                        grpc_port,
+                        component_service,
+                        shard_manager,
+                        worker_service,
+                        redis,
+                        verbosity,
+                    )
+                    .await,
+            )
+            .with_all(self.extra.clone())
+            .build()
+    }
+}
@@ -23,20 +23,22 @@ use async_trait::async_trait;
 use golem_api_grpc::proto::golem::common::{Empty, ResourceLimits};
 use golem_api_grpc::proto::golem::worker::v1::worker_service_client::WorkerServiceClient;
 use golem_api_grpc::proto::golem::worker::v1::{
-    ConnectWorkerRequest, DeleteWorkerRequest, DeleteWorkerResponse, GetFileContentsRequest,
-    GetOplogRequest, GetOplogResponse, GetOplogSuccessResponse, GetWorkerMetadataRequest,
-    GetWorkerMetadataResponse, InterruptWorkerRequest, InterruptWorkerResponse,
-    InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest,
-    InvokeAndAwaitResponse, InvokeJsonRequest, InvokeRequest, InvokeResponse,
-    LaunchNewWorkerRequest, LaunchNewWorkerResponse, LaunchNewWorkerSuccessResponse,
-    ListDirectoryRequest, ListDirectoryResponse, ListDirectorySuccessResponse, ResumeWorkerRequest,
-    ResumeWorkerResponse, SearchOplogRequest, SearchOplogResponse, SearchOplogSuccessResponse,
-    UpdateWorkerRequest, UpdateWorkerResponse, WorkerError,
+    ConnectWorkerRequest, DeleteFileRequest, DeleteFileResponse, DeleteWorkerRequest,
+    DeleteWorkerResponse, GetFileContentsRequest, GetOplogRequest, GetOplogResponse,
+    GetOplogSuccessResponse, GetWorkerMetadataRequest, GetWorkerMetadataResponse,
+    InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest,
+    InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeJsonRequest,
+    InvokeRequest, InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
+    LaunchNewWorkerSuccessResponse, ListDirectoryRequest, ListDirectoryResponse,
+    ListDirectorySuccessResponse, MakeDirectoryRequest, MakeDirectoryResponse, PutFileRequest,
+    PutFileResponse, ResumeWorkerRequest, ResumeWorkerResponse, SearchOplogRequest,
+    SearchOplogResponse, SearchOplogSuccessResponse, UpdateWorkerRequest, UpdateWorkerResponse,
+    WorkerError,
 };
 use golem_api_grpc::proto::golem::worker::{InvokeResult, LogEvent, WorkerId};
 use golem_api_grpc::proto::golem::workerexecutor::v1::CreateWorkerRequest;
 use golem_api_grpc::proto::golem::{worker, workerexecutor};
-use golem_common::model::AccountId;
+use golem_common::model::{AccountId, InvocationOptions};
 use tonic::transport::Channel;
 use tonic::Streaming;
 
@@ -108,6 +110,7 @@ impl WorkerService for ForwardingWorkerService {
                     component_version: latest_component_version,
                     args: request.args.clone(),
                     env: request.env.clone(),
+                    labels: request.labels.clone(),
                     account_id: Some(
                         AccountId {
                             value: "test-account".to_string(),
@@ -294,6 +297,11 @@ impl WorkerService for ForwardingWorkerService {
                         max_memory_per_worker: i64::MAX,
                     }),
                     context: request.context.clone(),
+                    options: request
+                        .invoke_parameters
+                        .as_ref()
+                        .and_then(|params| InvocationOptions::try_from(params).ok())
+                        .map(|options| options.into()),
                 })
                 .await;
 
@@ -359,6 +367,11 @@ impl WorkerService for ForwardingWorkerService {
                         max_memory_per_worker: i64::MAX,
                     }),
                     context: request.context.clone(),
+                    options: request
+                        .invoke_parameters
+                        .as_ref()
+                        .and_then(|params| InvocationOptions::try_from(params).ok())
+                        .map(|options| options.into()),
                 })
                 .await;
 
@@ -810,6 +823,146 @@ impl WorkerService for ForwardingWorkerService {
         Ok(Bytes::from(bytes))
     }
 
+    async fn put_file(&self, request: PutFileRequest) -> crate::Result<PutFileResponse> {
+        let result = self
+            .worker_executor
+            .client()
+            .await?
+            .put_file(workerexecutor::v1::PutFileRequest {
+                worker_id: request.worker_id,
+                account_id: Some(
+                    AccountId {
+                        value: "test-account".to_string(),
+                    }
+                    .into(),
+                ),
+                account_limits: Some(ResourceLimits {
+                    available_fuel: i64::MAX,
+                    max_memory_per_worker: i64::MAX,
+                }),
+                file_path: request.file_path,
+                content: request.content,
+            })
+            .await?
+            .into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor put-file call"
+            )),
+            Some(workerexecutor::v1::put_file_response::Result::Success(empty)) => {
+                Ok(PutFileResponse {
+                    result: Some(worker::v1::put_file_response::Result::Success(empty)),
+                })
+            }
+            Some(workerexecutor::v1::put_file_response::Result::Failure(error)) => {
+                Ok(PutFileResponse {
+                    result: Some(worker::v1::put_file_response::Result::Error(WorkerError {
+                        error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                    })),
+                })
+            }
+            Some(_) => Err(anyhow!(
+                "Unsupported response from golem-worker-executor put-file call"
+            )),
+        }
+    }
+
+    async fn delete_file(&self, request: DeleteFileRequest) -> crate::Result<DeleteFileResponse> {
+        let result = self
+            .worker_executor
+            .client()
+            .await?
+            .delete_file(workerexecutor::v1::DeleteFileRequest {
+                worker_id: request.worker_id,
+                account_id: Some(
+                    AccountId {
+                        value: "test-account".to_string(),
+                    }
+                    .into(),
+                ),
+                account_limits: Some(ResourceLimits {
+                    available_fuel: i64::MAX,
+                    max_memory_per_worker: i64::MAX,
+                }),
+                file_path: request.file_path,
+            })
+            .await?
+            .into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor delete-file call"
+            )),
+            Some(workerexecutor::v1::delete_file_response::Result::Success(empty)) => {
+                Ok(DeleteFileResponse {
+                    result: Some(worker::v1::delete_file_response::Result::Success(empty)),
+                })
+            }
+            Some(workerexecutor::v1::delete_file_response::Result::Failure(error)) => {
+                Ok(DeleteFileResponse {
+                    result: Some(worker::v1::delete_file_response::Result::Error(
+                        WorkerError {
+                            error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                        },
+                    )),
+                })
+            }
+            Some(_) => Err(anyhow!(
+                "Unsupported response from golem-worker-executor delete-file call"
+            )),
+        }
+    }
+
+    async fn make_directory(
+        &self,
+        request: MakeDirectoryRequest,
+    ) -> crate::Result<MakeDirectoryResponse> {
+        let result = self
+            .worker_executor
+            .client()
+            .await?
+            .make_directory(workerexecutor::v1::MakeDirectoryRequest {
+                worker_id: request.worker_id,
+                account_id: Some(
+                    AccountId {
+                        value: "test-account".to_string(),
+                    }
+                    .into(),
+                ),
+                account_limits: Some(ResourceLimits {
+                    available_fuel: i64::MAX,
+                    max_memory_per_worker: i64::MAX,
+                }),
+                path: request.path,
+            })
+            .await?
+            .into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor make-directory call"
+            )),
+            Some(workerexecutor::v1::make_directory_response::Result::Success(empty)) => {
+                Ok(MakeDirectoryResponse {
+                    result: Some(worker::v1::make_directory_response::Result::Success(empty)),
+                })
+            }
+            Some(workerexecutor::v1::make_directory_response::Result::Failure(error)) => {
+                Ok(MakeDirectoryResponse {
+                    result: Some(worker::v1::make_directory_response::Result::Error(
+                        WorkerError {
+                            error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                        },
+                    )),
+                })
+            }
+            Some(_) => Err(anyhow!(
+                "Unsupported response from golem-worker-executor make-directory call"
+            )),
+        }
+    }
+
     fn private_host(&self) -> String {
         panic!("No real golem-worker-service, forwarding requests to worker-executor");
     }
@@ -26,14 +26,16 @@ use tracing::Level;
 use anyhow::anyhow;
 use golem_api_grpc::proto::golem::worker::v1::worker_service_client::WorkerServiceClient;
 use golem_api_grpc::proto::golem::worker::v1::{
-    get_file_contents_response, ConnectWorkerRequest, DeleteWorkerRequest, DeleteWorkerResponse,
-    GetFileContentsRequest, GetOplogRequest, GetOplogResponse, GetWorkerMetadataRequest,
-    GetWorkerMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse,
-    InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest,
-    InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeJsonRequest,
-    InvokeRequest, InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
-    ListDirectoryRequest, ListDirectoryResponse, ResumeWorkerRequest, ResumeWorkerResponse,
-    SearchOplogRequest, SearchOplogResponse, UpdateWorkerRequest, UpdateWorkerResponse,
+    get_file_contents_response, ConnectWorkerRequest, DeleteFileRequest, DeleteFileResponse,
+    DeleteWorkerRequest, DeleteWorkerResponse, GetFileContentsRequest, GetOplogRequest,
+    GetOplogResponse, GetWorkerMetadataRequest, GetWorkerMetadataResponse,
+    GetWorkersMetadataRequest, GetWorkersMetadataResponse, InterruptWorkerRequest,
+    InterruptWorkerResponse, InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse,
+    InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeJsonRequest, InvokeRequest,
+    InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse, ListDirectoryRequest,
+    ListDirectoryResponse, MakeDirectoryRequest, MakeDirectoryResponse, PutFileRequest,
+    PutFileResponse, ResumeWorkerRequest, ResumeWorkerResponse, SearchOplogRequest,
+    SearchOplogResponse, UpdateWorkerRequest, UpdateWorkerResponse,
 };
 use golem_api_grpc::proto::golem::worker::LogEvent;
 
@@ -241,6 +243,31 @@ pub trait WorkerService {
         Ok(Bytes::from(bytes))
     }
 
+    async fn put_file(&self, request: PutFileRequest) -> crate::Result<PutFileResponse> {
+        Ok(self.client().await?.put_file(request).await?.into_inner())
+    }
+
+    async fn delete_file(&self, request: DeleteFileRequest) -> crate::Result<DeleteFileResponse> {
+        Ok(self
+            .client()
+            .await?
+            .delete_file(request)
+            .await?
+            .into_inner())
+    }
+
+    async fn make_directory(
+        &self,
+        request: MakeDirectoryRequest,
+    ) -> crate::Result<MakeDirectoryResponse> {
+        Ok(self
+            .client()
+            .await?
+            .make_directory(request)
+            .await?
+            .into_inner())
+    }
+
     fn private_host(&self) -> String;
     fn private_http_port(&self) -> u16;
     fn private_grpc_port(&self) -> u16;
@@ -64,8 +64,9 @@ use crate::components::worker_service::k8s::K8sWorkerService;
 use crate::components::worker_service::provided::ProvidedWorkerService;
 use crate::components::worker_service::spawned::SpawnedWorkerService;
 use crate::components::worker_service::WorkerService;
+use crate::components::ExtendedGolemEnvVars;
 use crate::config::{TestDependencies, TestService};
-use crate::dsl::benchmark::{BenchmarkConfig, RunConfig};
+use crate::dsl::benchmark::{BenchmarkConfig, RunConfig, WorkerExecutorVariant};
 
 /// Test dependencies created from command line arguments
 ///
@@ -112,6 +113,10 @@ pub struct CliParams {
 
     #[arg(long, default_value = "false")]
     pub keep_containers: bool,
+
+    /// The worker executor configuration of the currently running benchmark
+    #[arg(skip)]
+    pub worker_executor_variant: WorkerExecutorVariant,
 }
 
 impl CliParams {
@@ -165,16 +170,44 @@ impl CliParams {
             .sorted()
             .collect::<Vec<_>>();
 
+        let worker_executor: Vec<WorkerExecutorVariant> = match self.mode {
+            TestMode::Spawned { .. } => self
+                .benchmark_config
+                .instance_pre_cache
+                .iter()
+                .copied()
+                .unique()
+                .cartesian_product(
+                    self.benchmark_config
+                        .pooling_allocator
+                        .iter()
+                        .copied()
+                        .unique(),
+                )
+                .map(
+                    |(instance_pre_cache, pooling_allocator)| WorkerExecutorVariant {
+                        instance_pre_cache,
+                        pooling_allocator,
+                    },
+                )
+                .sorted()
+                .collect(),
+            _ => vec![WorkerExecutorVariant::default()],
+        };
+
         let mut res = Vec::new();
 
         for cluster_size in cluster_size {
-            for &size in &size {
-                for &length in &length {
-                    res.push(RunConfig {
-                        cluster_size,
-                        size,
-                        length,
-                    })
+            for &worker_executor in &worker_executor {
+                for &size in &size {
+                    for &length in &length {
+                        res.push(RunConfig {
+                            cluster_size,
+                            size,
+                            length,
+                            worker_executor,
+                        })
+                    }
                 }
             }
         }
@@ -515,7 +548,12 @@ impl CliTestDependencies {
                 let component_compilation_service: Arc<
                     dyn ComponentCompilationService + Send + Sync + 'static,
                 > = Arc::new(
-                    SpawnedComponentCompilationService::new(
+                    SpawnedComponentCompilationService::new_base(
+                        Box::new(ExtendedGolemEnvVars::new(
+                            params
+                                .worker_executor_variant
+                                .compilation_service_env_vars(),
+                        )),
                         &build_root.join("golem-component-compilation-service"),
                         &workspace_root.join("golem-component-compilation-service"),
                         component_compilation_service_http_port,
@@ -580,7 +618,10 @@ impl CliTestDependencies {
         );
         let worker_executor_cluster: Arc<dyn WorkerExecutorCluster + Send + Sync + 'static> =
             Arc::new(
-                SpawnedWorkerExecutorCluster::new(
+                SpawnedWorkerExecutorCluster::new_base(
+                    Arc::new(ExtendedGolemEnvVars::new(
+                        params.worker_executor_variant.worker_executor_env_vars(),
+                    )),
                     cluster_size,
                     worker_executor_base_http_port,
                     worker_executor_base_grpc_port,
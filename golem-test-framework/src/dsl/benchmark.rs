@@ -53,6 +53,20 @@ pub struct BenchmarkConfig {
     /// Total number of runs is multiplication for number of different cluster sizes, sizes and lengths.
     #[arg(long, default_values_t = [100])]
     pub length: Vec<usize>,
+
+    /// Whether the worker executors cache the pre-instantiated components. Can be repeated to compare
+    /// cached and uncached runs.
+    ///
+    /// Only applicable to spawned cluster.
+    #[arg(long, default_values_t = [true])]
+    pub instance_pre_cache: Vec<bool>,
+
+    /// Whether the worker executors use the pooling instance allocator instead of the on-demand one.
+    /// Can be repeated to compare the two allocators.
+    ///
+    /// Only applicable to spawned cluster.
+    #[arg(long, default_values_t = [false])]
+    pub pooling_allocator: Vec<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
@@ -60,6 +74,82 @@ pub struct RunConfig {
     pub cluster_size: usize,
     pub size: usize,
     pub length: usize,
+    #[serde(default)]
+    pub worker_executor: WorkerExecutorVariant,
+}
+
+/// Configuration of the worker executors which can be varied between the runs of a benchmark
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WorkerExecutorVariant {
+    pub instance_pre_cache: bool,
+    pub pooling_allocator: bool,
+}
+
+impl WorkerExecutorVariant {
+    /// Environment variables to configure the worker executors with
+    pub fn worker_executor_env_vars(&self) -> HashMap<String, String> {
+        let mut env_vars = HashMap::new();
+        env_vars.insert(
+            "GOLEM__COMPONENT_CACHE__CACHE_INSTANCE_PRE".to_string(),
+            self.instance_pre_cache.to_string(),
+        );
+        env_vars.extend(self.instance_allocator_env_vars("GOLEM__LIMITS__INSTANCE_ALLOCATOR"));
+        env_vars
+    }
+
+    /// Environment variables to configure the component compilation service with, so it produces
+    /// compiled components loadable by the worker executors
+    pub fn compilation_service_env_vars(&self) -> HashMap<String, String> {
+        self.instance_allocator_env_vars("GOLEM__INSTANCE_ALLOCATOR")
+    }
+
+    fn instance_allocator_env_vars(&self, prefix: &str) -> HashMap<String, String> {
+        let mut env_vars = HashMap::new();
+        if self.pooling_allocator {
+            env_vars.insert(format!("{prefix}__TYPE"), "Pooling".to_string());
+            for (name, value) in [
+                ("TOTAL_COMPONENT_INSTANCES", 1000),
+                ("TOTAL_CORE_INSTANCES", 5000),
+                ("TOTAL_MEMORIES", 5000),
+                ("TOTAL_TABLES", 5000),
+                ("MAX_MEMORIES_PER_COMPONENT", 10),
+                ("MAX_TABLES_PER_COMPONENT", 10),
+            ] {
+                env_vars.insert(format!("{prefix}__CONFIG__{name}"), value.to_string());
+            }
+        } else {
+            env_vars.insert(format!("{prefix}__TYPE"), "OnDemand".to_string());
+        }
+        env_vars
+    }
+}
+
+impl Default for WorkerExecutorVariant {
+    fn default() -> Self {
+        Self {
+            instance_pre_cache: true,
+            pooling_allocator: false,
+        }
+    }
+}
+
+impl Display for WorkerExecutorVariant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, {}",
+            if self.instance_pre_cache {
+                "cached"
+            } else {
+                "uncached"
+            },
+            if self.pooling_allocator {
+                "pooling"
+            } else {
+                "on-demand"
+            }
+        )
+    }
 }
 
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -268,6 +358,9 @@ pub struct RunConfigView {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     size: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    worker_executor: Option<WorkerExecutorVariant>,
 }
 
 impl PartialOrd<Self> for RunConfigView {
@@ -278,11 +371,18 @@ impl PartialOrd<Self> for RunConfigView {
 
 impl Ord for RunConfigView {
     fn cmp(&self, other: &Self) -> Ordering {
-        (self.cluster_size, self.length, self.size).cmp(&(
-            other.cluster_size,
-            other.length,
-            other.size,
-        ))
+        (
+            self.cluster_size,
+            self.worker_executor,
+            self.length,
+            self.size,
+        )
+            .cmp(&(
+                other.cluster_size,
+                other.worker_executor,
+                other.length,
+                other.size,
+            ))
     }
 }
 
@@ -367,6 +467,7 @@ impl Display for BenchmarkResultView {
                     .as_ref()
                     .expect("Config expected for multiple results");
                 let show_cluster_size = first_config.cluster_size.is_some();
+                let show_worker_executor = first_config.worker_executor.is_some();
                 let show_size = first_config.size.is_some();
                 let show_length = first_config.length.is_some();
                 let show_duration = items.iter().any(|i| i.duration.is_some());
@@ -376,6 +477,9 @@ impl Display for BenchmarkResultView {
                 if show_cluster_size {
                     title.push("Cluster size".cell().bold(true));
                 }
+                if show_worker_executor {
+                    title.push("Worker executor".cell().bold(true));
+                }
                 if show_length {
                     title.push("Length".cell().bold(true));
                 }
@@ -406,6 +510,17 @@ impl Display for BenchmarkResultView {
                                 .justify(Justify::Right),
                         );
                     }
+                    if show_worker_executor {
+                        record.push(
+                            item.config
+                                .as_ref()
+                                .unwrap()
+                                .worker_executor
+                                .unwrap()
+                                .to_string()
+                                .cell(),
+                        );
+                    }
                     if show_length {
                         record.push(
                             item.config
@@ -494,7 +609,8 @@ impl BenchmarkResult {
         let show_cluster_size = self.runs.iter().map(|c| c.cluster_size).unique().count() > 1;
         let show_size = self.runs.iter().map(|c| c.size).unique().count() > 1;
         let show_length = self.runs.iter().map(|c| c.length).unique().count() > 1;
-        let show_config = show_cluster_size || show_size || show_length;
+        let show_worker_executor = self.runs.iter().map(|c| c.worker_executor).unique().count() > 1;
+        let show_config = show_cluster_size || show_size || show_length || show_worker_executor;
 
         let mut all_keys = Vec::new();
         for (_, res) in &self.results {
@@ -516,6 +632,11 @@ impl BenchmarkResult {
                     },
                     size: if show_size { Some(conf.size) } else { None },
                     length: if show_length { Some(conf.length) } else { None },
+                    worker_executor: if show_worker_executor {
+                        Some(conf.worker_executor)
+                    } else {
+                        None
+                    },
                 };
 
                 let item = BenchmarkResultItemView {
@@ -773,20 +894,30 @@ impl<B: Benchmark> BenchmarkApi for B {
 
         let mut results = Vec::new();
 
+        // The benchmark context owns the cluster, so it has to be recreated for every cluster size
+        // and worker executor variant
         let groups = runs
             .iter()
-            .chunk_by(|r| r.cluster_size)
+            .chunk_by(|r| (r.cluster_size, r.worker_executor))
             .into_iter()
-            .map(|(cluster_size, group)| (cluster_size, group.collect::<Vec<_>>()))
+            .map(|(key, group)| (key, group.collect::<Vec<_>>()))
             .collect::<Vec<_>>();
 
-        for (cluster_size, runs) in groups {
-            let span =
-                tracing::info_span!("benchmark", name = B::name(), cluster_size = cluster_size);
+        for ((cluster_size, worker_executor), runs) in groups {
+            let span = tracing::info_span!(
+                "benchmark",
+                name = B::name(),
+                cluster_size = cluster_size,
+                worker_executor = worker_executor.to_string()
+            );
             let _enter = span.enter();
 
             info!("Creating benchmark context");
-            let context = B::create_benchmark_context(params.clone(), cluster_size)
+            let context_params = CliParams {
+                worker_executor_variant: worker_executor,
+                ..params.clone()
+            };
+            let context = B::create_benchmark_context(context_params, cluster_size)
                 .instrument(span.clone())
                 .await;
 
@@ -821,6 +952,7 @@ mod tests {
 
     use crate::dsl::benchmark::{
         BenchmarkResult, BenchmarkRunResult, CountResult, DurationResult, ResultKey, RunConfig,
+        WorkerExecutorVariant,
     };
     use std::collections::HashMap;
     use std::time::Duration;
@@ -831,11 +963,16 @@ mod tests {
             cluster_size: 1,
             size: 10,
             length: 20,
+            worker_executor: WorkerExecutorVariant::default(),
         };
         let rc2 = RunConfig {
             cluster_size: 5,
             size: 100,
             length: 20,
+            worker_executor: WorkerExecutorVariant {
+                instance_pre_cache: false,
+                pooling_allocator: true,
+            },
         };
 
         let mut dr1 = HashMap::new();
@@ -21,24 +21,27 @@ use bytes::Bytes;
 use golem_api_grpc::proto::golem::worker::update_record::Update;
 use golem_api_grpc::proto::golem::worker::v1::worker_error::Error;
 use golem_api_grpc::proto::golem::worker::v1::{
-    get_oplog_response, get_worker_metadata_response, get_workers_metadata_response,
-    interrupt_worker_response, invoke_and_await_json_response, invoke_and_await_response,
-    invoke_response, launch_new_worker_response, list_directory_response, resume_worker_response,
+    delete_file_response, get_oplog_response, get_worker_metadata_response,
+    get_workers_metadata_response, interrupt_worker_response, invoke_and_await_json_response,
+    invoke_and_await_response, invoke_response, launch_new_worker_response,
+    list_directory_response, make_directory_response, put_file_response, resume_worker_response,
     search_oplog_response, update_worker_response, worker_execution_error, ConnectWorkerRequest,
-    DeleteWorkerRequest, GetFileContentsRequest, GetOplogRequest, GetWorkerMetadataRequest,
-    GetWorkersMetadataRequest, GetWorkersMetadataSuccessResponse, InterruptWorkerRequest,
-    InterruptWorkerResponse, InvokeAndAwaitJsonRequest, InvokeAndAwaitRequest, InvokeRequest,
-    LaunchNewWorkerRequest, ListDirectoryRequest, ResumeWorkerRequest, SearchOplogRequest,
+    DeleteFileRequest, DeleteWorkerRequest, GetFileContentsRequest, GetOplogRequest,
+    GetWorkerMetadataRequest, GetWorkersMetadataRequest, GetWorkersMetadataSuccessResponse,
+    InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest,
+    InvokeAndAwaitRequest, InvokeRequest, LaunchNewWorkerRequest, ListDirectoryRequest,
+    MakeDirectoryRequest, PutFileRequest, ResumeWorkerRequest, SearchOplogRequest,
     UpdateWorkerRequest, UpdateWorkerResponse, WorkerError, WorkerExecutionError,
 };
 use golem_api_grpc::proto::golem::worker::{
     log_event, InvokeParameters, LogEvent, StdErrLog, StdOutLog, UpdateMode,
 };
+use golem_common::config::RetryConfig;
 use golem_common::model::oplog::{
     OplogIndex, TimestampedUpdateDescription, UpdateDescription, WorkerResourceId,
 };
 use golem_common::model::plugin::{DefaultPluginOwner, DefaultPluginScope, PluginDefinition};
-use golem_common::model::public_oplog::PublicOplogEntry;
+use golem_common::model::public_oplog::{PublicOplogEntry, PublicRetryConfig};
 use golem_common::model::regions::DeletedRegions;
 use golem_common::model::{
     AccountId, PluginInstallationId, WorkerStatus, WorkerStatusRecordExtensions,
@@ -51,7 +54,7 @@ use golem_common::model::{
 };
 use golem_service_base::model::PublicOplogEntryWithIndex;
 use golem_wasm_rpc::Value;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
 use std::time::{Duration, Instant};
 use tokio::select;
@@ -187,6 +190,15 @@ pub trait TestDsl {
         function_name: &str,
         params: Vec<Value>,
     ) -> crate::Result<Result<Vec<Value>, Error>>;
+    async fn invoke_and_await_custom_with_options(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        idempotency_key: &IdempotencyKey,
+        function_name: &str,
+        params: Vec<Value>,
+        retry_policy: Option<RetryConfig>,
+        timeout: Option<Duration>,
+    ) -> crate::Result<Result<Vec<Value>, Error>>;
     async fn invoke_and_await_json(
         &self,
         worker_id: impl Into<TargetWorkerId> + Send + Sync,
@@ -239,6 +251,25 @@ pub trait TestDsl {
         path: &str,
     ) -> crate::Result<Bytes>;
 
+    async fn put_file(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        path: &str,
+        content: Vec<u8>,
+    ) -> crate::Result<()>;
+
+    async fn delete_file(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        path: &str,
+    ) -> crate::Result<()>;
+
+    async fn make_directory(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        path: &str,
+    ) -> crate::Result<()>;
+
     async fn create_plugin(
         &self,
         definition: PluginDefinition<DefaultPluginOwner, DefaultPluginScope>,
@@ -400,6 +431,7 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
                 name: name.to_string(),
                 args,
                 env,
+                labels: HashMap::new(),
             })
             .await?;
 
@@ -510,6 +542,8 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
                 function: function_name.to_string(),
                 invoke_parameters: Some(InvokeParameters {
                     params: params.into_iter().map(|v| v.into()).collect(),
+                    retry_policy: None,
+                    timeout_millis: None,
                 }),
                 context: None,
             })
@@ -543,6 +577,8 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
                 function: function_name.to_string(),
                 invoke_parameters: Some(InvokeParameters {
                     params: params.into_iter().map(|v| v.into()).collect(),
+                    retry_policy: None,
+                    timeout_millis: None,
                 }),
                 context: None,
             })
@@ -609,6 +645,27 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
         idempotency_key: &IdempotencyKey,
         function_name: &str,
         params: Vec<Value>,
+    ) -> crate::Result<Result<Vec<Value>, Error>> {
+        TestDsl::invoke_and_await_custom_with_options(
+            self,
+            worker_id,
+            idempotency_key,
+            function_name,
+            params,
+            None,
+            None,
+        )
+        .await
+    }
+
+    async fn invoke_and_await_custom_with_options(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        idempotency_key: &IdempotencyKey,
+        function_name: &str,
+        params: Vec<Value>,
+        retry_policy: Option<RetryConfig>,
+        timeout: Option<Duration>,
     ) -> crate::Result<Result<Vec<Value>, Error>> {
         let target_worker_id: TargetWorkerId = worker_id.into();
         let invoke_response = self
@@ -619,6 +676,8 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
                 function: function_name.to_string(),
                 invoke_parameters: Some(InvokeParameters {
                     params: params.into_iter().map(|v| v.into()).collect(),
+                    retry_policy: retry_policy.map(|policy| PublicRetryConfig::from(policy).into()),
+                    timeout_millis: timeout.map(|timeout| timeout.as_millis() as u64),
                 }),
                 context: None,
             })
@@ -1041,6 +1100,80 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
             .await
     }
 
+    async fn put_file(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        path: &str,
+        content: Vec<u8>,
+    ) -> crate::Result<()> {
+        let target_worker_id: TargetWorkerId = worker_id.into();
+
+        let response = self
+            .worker_service()
+            .put_file(PutFileRequest {
+                worker_id: Some(target_worker_id.into()),
+                file_path: path.to_string(),
+                content,
+            })
+            .await?;
+
+        match response.result {
+            None => Err(anyhow!("No response from put_file")),
+            Some(put_file_response::Result::Success(_)) => Ok(()),
+            Some(put_file_response::Result::Error(error)) => {
+                Err(anyhow!("Failed to put file: {error:?}"))
+            }
+        }
+    }
+
+    async fn delete_file(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        path: &str,
+    ) -> crate::Result<()> {
+        let target_worker_id: TargetWorkerId = worker_id.into();
+
+        let response = self
+            .worker_service()
+            .delete_file(DeleteFileRequest {
+                worker_id: Some(target_worker_id.into()),
+                file_path: path.to_string(),
+            })
+            .await?;
+
+        match response.result {
+            None => Err(anyhow!("No response from delete_file")),
+            Some(delete_file_response::Result::Success(_)) => Ok(()),
+            Some(delete_file_response::Result::Error(error)) => {
+                Err(anyhow!("Failed to delete file: {error:?}"))
+            }
+        }
+    }
+
+    async fn make_directory(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        path: &str,
+    ) -> crate::Result<()> {
+        let target_worker_id: TargetWorkerId = worker_id.into();
+
+        let response = self
+            .worker_service()
+            .make_directory(MakeDirectoryRequest {
+                worker_id: Some(target_worker_id.into()),
+                path: path.to_string(),
+            })
+            .await?;
+
+        match response.result {
+            None => Err(anyhow!("No response from make_directory")),
+            Some(make_directory_response::Result::Success(_)) => Ok(()),
+            Some(make_directory_response::Result::Error(error)) => {
+                Err(anyhow!("Failed to make directory: {error:?}"))
+            }
+        }
+    }
+
     async fn create_plugin(
         &self,
         definition: PluginDefinition<DefaultPluginOwner, DefaultPluginScope>,
@@ -1161,6 +1294,7 @@ pub fn log_event_to_string(event: &LogEvent) -> String {
         Some(log_event::Event::Log(log)) => log.message.clone(),
         Some(log_event::Event::InvocationFinished(_)) => "".to_string(),
         Some(log_event::Event::InvocationStarted(_)) => "".to_string(),
+        Some(log_event::Event::WorkerFailed(failed)) => failed.error.clone(),
         None => std::panic!("Unexpected event type"),
     }
 }
@@ -1294,6 +1428,9 @@ pub fn worker_error_message(error: &Error) -> String {
                 worker_execution_error::Error::FileSystemError(error) => {
                     format!("File system error: {}", error.reason)
                 }
+                worker_execution_error::Error::InvocationTimedOut(_error) => {
+                    "Invocation timed out".to_string()
+                }
             },
         },
     }
@@ -1410,7 +1547,7 @@ pub fn to_worker_metadata(
                         )
                     })
                     .collect(),
-                extensions: WorkerStatusRecordExtensions::Extension1 {
+                extensions: WorkerStatusRecordExtensions::Extension2 {
                     active_plugins: HashSet::from_iter(
                         metadata
                             .active_plugins
@@ -1418,6 +1555,7 @@ pub fn to_worker_metadata(
                             .cloned()
                             .map(|id| id.try_into().expect("invalid plugin installation id")),
                     ),
+                    labels: BTreeMap::from_iter(metadata.labels.clone()),
                 },
             },
             parent: None,
@@ -1533,6 +1671,14 @@ pub trait TestDslUnsafe {
         function_name: &str,
         params: Vec<Value>,
     ) -> Result<Vec<Value>, Error>;
+    async fn invoke_and_await_with_options(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        function_name: &str,
+        params: Vec<Value>,
+        retry_policy: Option<RetryConfig>,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<Value>, Error>;
     async fn invoke_and_await_json(
         &self,
         worker_id: impl Into<TargetWorkerId> + Send + Sync,
@@ -1574,6 +1720,14 @@ pub trait TestDslUnsafe {
         worker_id: impl Into<TargetWorkerId> + Send + Sync,
         path: &str,
     ) -> Bytes;
+    async fn put_file(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        path: &str,
+        content: Vec<u8>,
+    );
+    async fn delete_file(&self, worker_id: impl Into<TargetWorkerId> + Send + Sync, path: &str);
+    async fn make_directory(&self, worker_id: impl Into<TargetWorkerId> + Send + Sync, path: &str);
 
     async fn create_plugin(
         &self,
@@ -1752,6 +1906,27 @@ impl<T: TestDsl + Sync> TestDslUnsafe for T {
             .expect("Failed to invoke function")
     }
 
+    async fn invoke_and_await_with_options(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        function_name: &str,
+        params: Vec<Value>,
+        retry_policy: Option<RetryConfig>,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<Value>, Error> {
+        <T as TestDsl>::invoke_and_await_custom_with_options(
+            self,
+            worker_id,
+            &IdempotencyKey::fresh(),
+            function_name,
+            params,
+            retry_policy,
+            timeout,
+        )
+        .await
+        .expect("Failed to invoke function")
+    }
+
     async fn invoke_and_await_json(
         &self,
         worker_id: impl Into<TargetWorkerId> + Send + Sync,
@@ -1867,6 +2042,26 @@ impl<T: TestDsl + Sync> TestDslUnsafe for T {
             .await
             .expect("Failed to get file contents")
     }
+    async fn put_file(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        path: &str,
+        content: Vec<u8>,
+    ) {
+        <T as TestDsl>::put_file(self, worker_id, path, content)
+            .await
+            .expect("Failed to put file")
+    }
+    async fn delete_file(&self, worker_id: impl Into<TargetWorkerId> + Send + Sync, path: &str) {
+        <T as TestDsl>::delete_file(self, worker_id, path)
+            .await
+            .expect("Failed to delete file")
+    }
+    async fn make_directory(&self, worker_id: impl Into<TargetWorkerId> + Send + Sync, path: &str) {
+        <T as TestDsl>::make_directory(self, worker_id, path)
+            .await
+            .expect("Failed to make directory")
+    }
 
     async fn create_plugin(
         &self,
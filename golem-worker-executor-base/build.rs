@@ -6,15 +6,43 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let golem_wit_root = find_package_root("golem-wit");
     let out_dir = var_os("OUT_DIR").unwrap();
     let target_file = Path::new(&out_dir).join("preview2_mod.rs");
+    let wit_dir = Path::new(&out_dir).join("wit");
 
-    std::fs::write(target_file.clone(), preview2_mod_gen(&golem_wit_root)).unwrap();
+    merge_wit(Path::new(&golem_wit_root), &wit_dir)?;
+    std::fs::write(target_file.clone(), preview2_mod_gen(&wit_dir)).unwrap();
 
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed=wit");
 
     Ok(())
 }
 
+/// Combines the WIT packages of `golem-wit` with the ones in `wit/deps`, which define the newer
+/// versions of the Golem interfaces implemented by the executor and the interfaces implemented by
+/// plugin components
+fn merge_wit(golem_wit_root: &Path, target: &Path) -> std::io::Result<()> {
+    if target.exists() {
+        std::fs::remove_dir_all(target)?;
+    }
+    copy_dir(&golem_wit_root.join("wit"), target)?;
+    copy_dir(Path::new("wit/deps"), &target.join("deps"))
+}
+
+fn copy_dir(source: &Path, target: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(target)?;
+    for entry in std::fs::read_dir(source)? {
+        let entry = entry?;
+        let target = target.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
 fn find_package_root(name: &str) -> String {
     let metadata = MetadataCommand::new()
         .manifest_path("./Cargo.toml")
@@ -50,12 +78,8 @@ fn find_package_root(name: &str) -> String {
     package.manifest_path.parent().unwrap().to_string()
 }
 
-fn preview2_mod_gen(golem_wit_path: &str) -> String {
-    let golem_wit_path = Path::new(golem_wit_path)
-        .join("wit")
-        .to_str()
-        .map(|s| s.to_string())
-        .unwrap();
+fn preview2_mod_gen(wit_path: &Path) -> String {
+    let golem_wit_path = wit_path.to_str().map(|s| s.to_string()).unwrap();
     format!(
         r#"wasmtime::component::bindgen!({{
         path: r"{golem_wit_path}",
@@ -63,6 +87,10 @@ fn preview2_mod_gen(golem_wit_path: &str) -> String {
           import golem:api/host@0.2.0;
           import golem:api/host@1.1.0;
           import golem:api/oplog@1.1.0;
+          import golem:api/host@1.2.0;
+          import golem:api/oplog@1.2.0;
+          import golem:api/rpc@1.2.0;
+          import golem:api/topics@1.2.0;
 
           import wasi:blobstore/blobstore;
           import wasi:blobstore/container;
@@ -92,7 +120,9 @@ fn preview2_mod_gen(golem_wit_path: &str) -> String {
             "wasi:keyvalue/types/outgoing-value": super::durable_host::keyvalue::types::OutgoingValueEntry,
             "golem:api/host/get-workers": super::durable_host::golem::GetWorkersEntry,
             "golem:api/oplog/get-oplog": super::durable_host::golem::v11::GetOplogEntry,
-            "golem:api/oplog/search-oplog": super::durable_host::golem::v11::SearchOplogEntry
+            "golem:api/oplog/search-oplog": super::durable_host::golem::v11::SearchOplogEntry,
+            "golem:rpc/types/wasm-rpc": golem_wasm_rpc::WasmRpcEntry,
+            "golem:rpc/types/future-invoke-result": golem_wasm_rpc::FutureInvokeResultEntry
         }},
         skip_mut_forwarding_impls: true,
     }});
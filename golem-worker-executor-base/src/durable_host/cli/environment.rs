@@ -26,14 +26,22 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
     async fn get_environment(&mut self) -> anyhow::Result<Vec<(String, String)>> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("cli::environment", "get_environment");
-        Durability::<Ctx, (), Vec<(String, String)>, SerializableError>::wrap(
+        // Secret values are redacted before getting persisted in the oplog, and are filled in
+        // again both in live and in replay mode
+        let env = Durability::<Ctx, (), Vec<(String, String)>, SerializableError>::wrap(
             self,
             WrappedFunctionType::ReadLocal,
             "golem_environment::get_environment",
             (),
-            |ctx| Box::pin(async { Host::get_environment(&mut ctx.as_wasi_view()).await }),
+            |ctx| {
+                Box::pin(async {
+                    let env = Host::get_environment(&mut ctx.as_wasi_view()).await?;
+                    Ok(ctx.secrets.redact_env(env))
+                })
+            },
         )
-        .await
+        .await?;
+        Ok(self.secrets.unredact_env(env))
     }
 
     async fn get_arguments(&mut self) -> anyhow::Result<Vec<String>> {
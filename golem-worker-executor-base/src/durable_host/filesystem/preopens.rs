@@ -20,6 +20,7 @@ use crate::durable_host::serialized::SerializableError;
 use crate::durable_host::{Durability, DurableWorkerCtx};
 use crate::metrics::wasm::record_host_function_call;
 use crate::workerctx::WorkerCtx;
+use golem_common::model::component_metadata::HostCapability;
 use golem_common::model::oplog::WrappedFunctionType;
 use wasmtime_wasi::bindings::filesystem::preopens::{Descriptor, Host};
 
@@ -29,7 +30,13 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("cli_base::preopens", "get_directories");
 
-        let current_dirs1 = Host::get_directories(&mut self.as_wasi_view()).await?;
+        // Without pre-opened directories the worker cannot access any file. The empty result is
+        // recorded, so replaying it does not depend on the capability policy.
+        let current_dirs1 = if self.is_denied(HostCapability::FileSystem) {
+            vec![]
+        } else {
+            Host::get_directories(&mut self.as_wasi_view()).await?
+        };
         let current_dirs2 = Host::get_directories(&mut self.as_wasi_view()).await?;
         Durability::<Ctx, (), Vec<String>, SerializableError>::custom_wrap(
             self,
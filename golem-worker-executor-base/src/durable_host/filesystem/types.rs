@@ -57,7 +57,9 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
     ) -> Result<Resource<OutputStream>, FsError> {
         self.fail_if_read_only(&fd)?;
         record_host_function_call("filesystem::types::descriptor", "write_via_stream");
-        HostDescriptor::write_via_stream(&mut self.as_wasi_view(), fd, offset)
+        let stream = HostDescriptor::write_via_stream(&mut self.as_wasi_view(), fd, offset)?;
+        self.register_file_output_stream(&stream);
+        Ok(stream)
     }
 
     fn append_via_stream(
@@ -65,7 +67,9 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
         self_: Resource<Descriptor>,
     ) -> Result<Resource<OutputStream>, FsError> {
         record_host_function_call("filesystem::types::descriptor", "append_via_stream");
-        HostDescriptor::append_via_stream(&mut self.as_wasi_view(), self_)
+        let stream = HostDescriptor::append_via_stream(&mut self.as_wasi_view(), self_)?;
+        self.register_file_output_stream(&stream);
+        Ok(stream)
     }
 
     async fn advise(
@@ -127,6 +131,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "set_size");
+        self.mark_volumes_dirty();
 
         HostDescriptor::set_size(&mut self.as_wasi_view(), fd, size).await
     }
@@ -144,6 +149,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "set_times");
+        self.mark_volumes_dirty();
 
         HostDescriptor::set_times(
             &mut self.as_wasi_view(),
@@ -181,6 +187,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "write");
+        self.mark_volumes_dirty();
         HostDescriptor::write(&mut self.as_wasi_view(), fd, buffer, offset).await
     }
 
@@ -226,6 +233,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "create_directory_at");
+        self.mark_volumes_dirty();
         HostDescriptor::create_directory_at(&mut self.as_wasi_view(), self_, path).await
     }
 
@@ -357,6 +365,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "set_times_at");
+        self.mark_volumes_dirty();
         HostDescriptor::set_times_at(
             &mut self.as_wasi_view(),
             fd,
@@ -381,6 +390,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "link_at");
+        self.mark_volumes_dirty();
         HostDescriptor::link_at(
             &mut self.as_wasi_view(),
             self_,
@@ -405,6 +415,9 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "open_at");
+        if open_flags.intersects(OpenFlags::CREATE | OpenFlags::TRUNCATE) {
+            self.mark_volumes_dirty();
+        }
         HostDescriptor::open_at(
             &mut self.as_wasi_view(),
             self_,
@@ -439,6 +452,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "remove_directory_at");
+        self.mark_volumes_dirty();
         HostDescriptor::remove_directory_at(&mut self.as_wasi_view(), self_, path.clone()).await
     }
 
@@ -457,6 +471,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "rename_at");
+        self.mark_volumes_dirty();
         HostDescriptor::rename_at(
             &mut self.as_wasi_view(),
             old_fd,
@@ -480,6 +495,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "symlink_at");
+        self.mark_volumes_dirty();
         HostDescriptor::symlink_at(&mut self.as_wasi_view(), fd, old_path, new_path.clone()).await
     }
 
@@ -495,6 +511,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "unlink_file_at");
+        self.mark_volumes_dirty();
         HostDescriptor::unlink_file_at(&mut self.as_wasi_view(), fd, path.clone()).await
     }
 
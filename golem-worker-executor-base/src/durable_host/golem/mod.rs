@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod v11;
+pub mod v12;
 
 use anyhow::anyhow;
 use async_trait::async_trait;
@@ -65,36 +66,8 @@ impl<Ctx: WorkerCtx> HostGetWorkers for DurableWorkerCtx<Ctx> {
     ) -> anyhow::Result<Option<Vec<WorkerMetadata>>> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("golem::api::get-workers", "get-next");
-        let (component_id, filter, count, precise, cursor) = self
-            .as_wasi_view()
-            .table()
-            .get::<GetWorkersEntry>(&self_)
-            .map(|e| {
-                (
-                    e.component_id.clone(),
-                    e.filter.clone(),
-                    e.count,
-                    e.precise,
-                    e.next_cursor.clone(),
-                )
-            })?;
-
-        if let Some(cursor) = cursor {
-            let (new_cursor, workers) = self
-                .state
-                .get_workers(&component_id, filter, cursor, count, precise)
-                .await?;
-
-            let _ = self
-                .as_wasi_view()
-                .table()
-                .get_mut::<GetWorkersEntry>(&self_)
-                .map(|e| e.set_next_cursor(new_cursor))?;
-
-            Ok(Some(workers.into_iter().map(|w| w.into()).collect()))
-        } else {
-            Ok(None)
-        }
+        let workers = self.get_next_workers(&self_).await?;
+        Ok(workers.map(|workers| workers.into_iter().map(|w| w.into()).collect()))
     }
 
     fn drop(&mut self, rep: Resource<GetWorkersEntry>) -> anyhow::Result<()> {
@@ -538,7 +511,55 @@ impl<Ctx: WorkerCtx> golem::api0_2_0::host::Host for DurableWorkerCtx<Ctx> {
     ) -> anyhow::Result<Option<WorkerMetadata>> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("golem::api", "get_worker_metadata");
-        let worker_id: WorkerId = worker_id.into();
+        let metadata = self.get_latest_worker_metadata(worker_id.into()).await?;
+        Ok(metadata.map(|metadata| metadata.into()))
+    }
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Fetches the next page of a `get-workers` resource, shared by all versions of the host
+    /// interface
+    pub(crate) async fn get_next_workers(
+        &mut self,
+        self_: &Resource<GetWorkersEntry>,
+    ) -> anyhow::Result<Option<Vec<golem_common::model::WorkerMetadata>>> {
+        let (component_id, filter, count, precise, cursor) = self
+            .as_wasi_view()
+            .table()
+            .get::<GetWorkersEntry>(self_)
+            .map(|e| {
+                (
+                    e.component_id.clone(),
+                    e.filter.clone(),
+                    e.count,
+                    e.precise,
+                    e.next_cursor.clone(),
+                )
+            })?;
+
+        if let Some(cursor) = cursor {
+            let (new_cursor, workers) = self
+                .state
+                .get_workers(&component_id, filter, cursor, count, precise)
+                .await?;
+
+            let _ = self
+                .as_wasi_view()
+                .table()
+                .get_mut::<GetWorkersEntry>(self_)
+                .map(|e| e.set_next_cursor(new_cursor))?;
+
+            Ok(Some(workers))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Gets the metadata of a worker of the same account, with its status recomputed from the oplog
+    pub(crate) async fn get_latest_worker_metadata(
+        &mut self,
+        worker_id: WorkerId,
+    ) -> anyhow::Result<Option<golem_common::model::WorkerMetadata>> {
         let owned_worker_id = OwnedWorkerId::new(&self.owned_worker_id.account_id, &worker_id);
         let metadata = self.state.worker_service.get(&owned_worker_id).await;
 
@@ -550,11 +571,10 @@ impl<Ctx: WorkerCtx> golem::api0_2_0::host::Host for DurableWorkerCtx<Ctx> {
                     &Some(metadata.clone()),
                 )
                 .await?;
-                let updated_metadata = golem_common::model::WorkerMetadata {
+                Ok(Some(golem_common::model::WorkerMetadata {
                     last_known_status,
                     ..metadata
-                };
-                Ok(Some(updated_metadata.into()))
+                }))
             }
             None => Ok(None),
         }
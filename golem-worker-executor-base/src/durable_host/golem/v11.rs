@@ -16,7 +16,7 @@ use crate::durable_host::golem::GetWorkersEntry;
 use crate::durable_host::DurableWorkerCtx;
 use crate::metrics::wasm::record_host_function_call;
 use crate::model::public_oplog::{
-    find_component_version_at, get_public_oplog_chunk, search_public_oplog,
+    find_component_version_at, get_public_oplog_chunk, search_public_oplog, PublicOplogEntry,
 };
 use crate::preview2::golem;
 use crate::preview2::golem::api0_2_0::host::GetWorkers;
@@ -214,39 +214,8 @@ impl<Ctx: WorkerCtx> HostGetOplog for DurableWorkerCtx<Ctx> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("golem::api::get-oplog", "get-next");
 
-        let component_service = self.state.component_service.clone();
-        let oplog_service = self.state.oplog_service();
-        let plugins = self.state.plugins();
-
-        let entry = self.as_wasi_view().table().get(&self_)?.clone();
-
-        let chunk = get_public_oplog_chunk(
-            component_service,
-            oplog_service,
-            plugins,
-            &entry.owned_worker_id,
-            entry.current_component_version,
-            entry.next_oplog_index,
-            entry.page_size,
-        )
-        .await
-        .map_err(|msg| anyhow!(msg))?;
-
-        if chunk.next_oplog_index != entry.next_oplog_index {
-            self.as_wasi_view()
-                .table()
-                .get_mut(&self_)?
-                .update(chunk.next_oplog_index, chunk.current_component_version);
-            Ok(Some(
-                chunk
-                    .entries
-                    .into_iter()
-                    .map(|entry| entry.into())
-                    .collect(),
-            ))
-        } else {
-            Ok(None)
-        }
+        let entries = self.get_next_oplog_entries(&self_).await?;
+        Ok(entries.map(|entries| entries.into_iter().map(|entry| entry.into()).collect()))
     }
 
     fn drop(&mut self, rep: Resource<GetOplogEntry>) -> anyhow::Result<()> {
@@ -320,44 +289,17 @@ impl<Ctx: WorkerCtx> HostSearchOplog for DurableWorkerCtx<Ctx> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("golem::api::search-oplog", "get-next");
 
-        let component_service = self.state.component_service.clone();
-        let oplog_service = self.state.oplog_service();
-        let plugins = self.state.plugins();
-
-        let entry = self.as_wasi_view().table().get(&self_)?.clone();
-
-        let chunk = search_public_oplog(
-            component_service,
-            oplog_service,
-            plugins,
-            &entry.owned_worker_id,
-            entry.current_component_version,
-            entry.next_oplog_index,
-            entry.page_size,
-            &entry.query,
-        )
-        .await
-        .map_err(|msg| anyhow!(msg))?;
-
-        if chunk.next_oplog_index != entry.next_oplog_index {
-            self.as_wasi_view()
-                .table()
-                .get_mut(&self_)?
-                .update(chunk.next_oplog_index, chunk.current_component_version);
-            Ok(Some(
-                chunk
-                    .entries
-                    .into_iter()
-                    .map(|(idx, entry)| {
-                        let idx: golem::api1_1_0::oplog::OplogIndex = idx.into();
-                        let entry: OplogEntry = entry.into();
-                        (idx, entry)
-                    })
-                    .collect(),
-            ))
-        } else {
-            Ok(None)
-        }
+        let entries = self.search_next_oplog_entries(&self_).await?;
+        Ok(entries.map(|entries| {
+            entries
+                .into_iter()
+                .map(|(idx, entry)| {
+                    let idx: golem::api1_1_0::oplog::OplogIndex = idx.into();
+                    let entry: OplogEntry = entry.into();
+                    (idx, entry)
+                })
+                .collect()
+        }))
     }
 
     fn drop(&mut self, rep: Resource<SearchOplog>) -> anyhow::Result<()> {
@@ -406,6 +348,79 @@ impl SearchOplogEntry {
 #[async_trait]
 impl<Ctx: WorkerCtx> OplogHost for DurableWorkerCtx<Ctx> {}
 
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Reads the next page of a `get-oplog` resource, shared by all versions of the oplog interface
+    pub(crate) async fn get_next_oplog_entries(
+        &mut self,
+        self_: &Resource<GetOplogEntry>,
+    ) -> anyhow::Result<Option<Vec<PublicOplogEntry>>> {
+        let component_service = self.state.component_service.clone();
+        let oplog_service = self.state.oplog_service();
+        let plugins = self.state.plugins();
+
+        let entry = self.as_wasi_view().table().get(self_)?.clone();
+
+        let chunk = get_public_oplog_chunk(
+            component_service,
+            oplog_service,
+            plugins,
+            &entry.owned_worker_id,
+            entry.current_component_version,
+            entry.next_oplog_index,
+            entry.page_size,
+        )
+        .await
+        .map_err(|msg| anyhow!(msg))?;
+
+        if chunk.next_oplog_index != entry.next_oplog_index {
+            self.as_wasi_view()
+                .table()
+                .get_mut(self_)?
+                .update(chunk.next_oplog_index, chunk.current_component_version);
+            Ok(Some(chunk.entries))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads the next page of a `search-oplog` resource, shared by all versions of the oplog
+    /// interface
+    pub(crate) async fn search_next_oplog_entries(
+        &mut self,
+        self_: &Resource<SearchOplogEntry>,
+    ) -> anyhow::Result<Option<Vec<(golem_common::model::oplog::OplogIndex, PublicOplogEntry)>>>
+    {
+        let component_service = self.state.component_service.clone();
+        let oplog_service = self.state.oplog_service();
+        let plugins = self.state.plugins();
+
+        let entry = self.as_wasi_view().table().get(self_)?.clone();
+
+        let chunk = search_public_oplog(
+            component_service,
+            oplog_service,
+            plugins,
+            &entry.owned_worker_id,
+            entry.current_component_version,
+            entry.next_oplog_index,
+            entry.page_size,
+            &entry.query,
+        )
+        .await
+        .map_err(|msg| anyhow!(msg))?;
+
+        if chunk.next_oplog_index != entry.next_oplog_index {
+            self.as_wasi_view()
+                .table()
+                .get_mut(self_)?
+                .update(chunk.next_oplog_index, chunk.current_component_version);
+            Ok(Some(chunk.entries))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 #[async_trait]
 impl<Ctx: WorkerCtx> HostGetWorkers for &mut DurableWorkerCtx<Ctx> {
     async fn new(
@@ -0,0 +1,513 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::golem::v11::{GetOplogEntry, SearchOplogEntry};
+use crate::durable_host::golem::GetWorkersEntry;
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::metrics::wasm::record_host_function_call;
+use crate::preview2::golem;
+use crate::preview2::golem::api1_2_0::host::{
+    ComponentId, ComponentVersion, Host, HostGetWorkers, OplogIndex, PersistenceLevel, PromiseId,
+    RetryPolicy, UpdateMode, Uuid, WorkerAllFilter, WorkerAnyFilter, WorkerId, WorkerMetadata,
+    WorkerPropertyFilter,
+};
+use crate::preview2::golem::api1_2_0::oplog::{
+    Host as OplogHost, HostGetOplog, HostSearchOplog, OplogEntry,
+};
+use crate::preview2::golem::api1_2_0::topics::{Host as TopicsHost, WitValue};
+use crate::workerctx::{InvocationManagement, WorkerCtx};
+use async_trait::async_trait;
+use golem_common::model::oplog::WrappedFunctionType;
+use golem_common::model::IdempotencyKey;
+use wasmtime::component::Resource;
+use wasmtime_wasi::WasiView;
+
+#[async_trait]
+impl<Ctx: WorkerCtx> HostGetWorkers for DurableWorkerCtx<Ctx> {
+    async fn new(
+        &mut self,
+        component_id: ComponentId,
+        filter: Option<WorkerAnyFilter>,
+        precise: bool,
+    ) -> anyhow::Result<Resource<GetWorkersEntry>> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::api::get-workers", "new");
+        let entry = GetWorkersEntry::new(component_id.into(), filter.map(|f| f.into()), precise);
+        let resource = self.as_wasi_view().table().push(entry)?;
+        Ok(resource)
+    }
+
+    async fn get_next(
+        &mut self,
+        self_: Resource<GetWorkersEntry>,
+    ) -> anyhow::Result<Option<Vec<WorkerMetadata>>> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::api::get-workers", "get-next");
+        let workers = self.get_next_workers(&self_).await?;
+        Ok(workers.map(|workers| workers.into_iter().map(|w| w.into()).collect()))
+    }
+
+    fn drop(&mut self, rep: Resource<GetWorkersEntry>) -> anyhow::Result<()> {
+        record_host_function_call("golem::api::get-workers", "drop");
+        self.as_wasi_view().table().delete::<GetWorkersEntry>(rep)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn create_promise(&mut self) -> anyhow::Result<PromiseId> {
+        golem::api1_1_0::host::Host::create_promise(self).await
+    }
+
+    async fn await_promise(&mut self, promise_id: PromiseId) -> anyhow::Result<Vec<u8>> {
+        golem::api1_1_0::host::Host::await_promise(self, promise_id).await
+    }
+
+    async fn complete_promise(
+        &mut self,
+        promise_id: PromiseId,
+        data: Vec<u8>,
+    ) -> anyhow::Result<bool> {
+        golem::api1_1_0::host::Host::complete_promise(self, promise_id, data).await
+    }
+
+    async fn delete_promise(&mut self, promise_id: PromiseId) -> anyhow::Result<()> {
+        golem::api1_1_0::host::Host::delete_promise(self, promise_id).await
+    }
+
+    async fn get_oplog_index(&mut self) -> anyhow::Result<OplogIndex> {
+        golem::api1_1_0::host::Host::get_oplog_index(self).await
+    }
+
+    async fn set_oplog_index(&mut self, oplog_idx: OplogIndex) -> anyhow::Result<()> {
+        golem::api1_1_0::host::Host::set_oplog_index(self, oplog_idx).await
+    }
+
+    async fn oplog_commit(&mut self, replicas: u8) -> anyhow::Result<()> {
+        golem::api1_1_0::host::Host::oplog_commit(self, replicas).await
+    }
+
+    async fn mark_begin_operation(&mut self) -> anyhow::Result<OplogIndex> {
+        golem::api1_1_0::host::Host::mark_begin_operation(self).await
+    }
+
+    async fn mark_end_operation(&mut self, begin: OplogIndex) -> anyhow::Result<()> {
+        golem::api1_1_0::host::Host::mark_end_operation(self, begin).await
+    }
+
+    async fn get_retry_policy(&mut self) -> anyhow::Result<RetryPolicy> {
+        golem::api1_1_0::host::Host::get_retry_policy(self).await
+    }
+
+    async fn set_retry_policy(&mut self, new_retry_policy: RetryPolicy) -> anyhow::Result<()> {
+        golem::api1_1_0::host::Host::set_retry_policy(self, new_retry_policy).await
+    }
+
+    async fn get_oplog_persistence_level(&mut self) -> anyhow::Result<PersistenceLevel> {
+        golem::api1_1_0::host::Host::get_oplog_persistence_level(self).await
+    }
+
+    async fn set_oplog_persistence_level(
+        &mut self,
+        new_persistence_level: PersistenceLevel,
+    ) -> anyhow::Result<()> {
+        golem::api1_1_0::host::Host::set_oplog_persistence_level(self, new_persistence_level).await
+    }
+
+    async fn get_idempotence_mode(&mut self) -> anyhow::Result<bool> {
+        golem::api1_1_0::host::Host::get_idempotence_mode(self).await
+    }
+
+    async fn set_idempotence_mode(&mut self, idempotent: bool) -> anyhow::Result<()> {
+        golem::api1_1_0::host::Host::set_idempotence_mode(self, idempotent).await
+    }
+
+    async fn generate_idempotency_key(&mut self) -> anyhow::Result<Uuid> {
+        golem::api1_1_0::host::Host::generate_idempotency_key(self).await
+    }
+
+    async fn update_worker(
+        &mut self,
+        worker_id: WorkerId,
+        target_version: ComponentVersion,
+        mode: UpdateMode,
+    ) -> anyhow::Result<()> {
+        golem::api1_1_0::host::Host::update_worker(self, worker_id, target_version, mode).await
+    }
+
+    async fn get_self_metadata(&mut self) -> anyhow::Result<WorkerMetadata> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::api", "get_self_metadata");
+        let metadata = self.public_state.worker().get_metadata().await?;
+        Ok(metadata.into())
+    }
+
+    async fn get_worker_metadata(
+        &mut self,
+        worker_id: WorkerId,
+    ) -> anyhow::Result<Option<WorkerMetadata>> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::api", "get_worker_metadata");
+        let metadata = self.get_latest_worker_metadata(worker_id.into()).await?;
+        Ok(metadata.map(|metadata| metadata.into()))
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> HostGetOplog for DurableWorkerCtx<Ctx> {
+    async fn new(
+        &mut self,
+        worker_id: golem::api1_2_0::oplog::WorkerId,
+        start: golem::api1_2_0::oplog::OplogIndex,
+    ) -> anyhow::Result<Resource<GetOplogEntry>> {
+        golem::api1_1_0::oplog::HostGetOplog::new(self, worker_id, start).await
+    }
+
+    async fn get_next(
+        &mut self,
+        self_: Resource<GetOplogEntry>,
+    ) -> anyhow::Result<Option<Vec<OplogEntry>>> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::api::get-oplog", "get-next");
+
+        let entries = self.get_next_oplog_entries(&self_).await?;
+        Ok(entries.map(|entries| entries.into_iter().map(|entry| entry.into()).collect()))
+    }
+
+    fn drop(&mut self, rep: Resource<GetOplogEntry>) -> anyhow::Result<()> {
+        golem::api1_1_0::oplog::HostGetOplog::drop(self, rep)
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> HostSearchOplog for DurableWorkerCtx<Ctx> {
+    async fn new(
+        &mut self,
+        worker_id: golem::api1_2_0::oplog::WorkerId,
+        text: String,
+    ) -> anyhow::Result<Resource<SearchOplogEntry>> {
+        golem::api1_1_0::oplog::HostSearchOplog::new(self, worker_id, text).await
+    }
+
+    async fn get_next(
+        &mut self,
+        self_: Resource<SearchOplogEntry>,
+    ) -> anyhow::Result<Option<Vec<(golem::api1_2_0::oplog::OplogIndex, OplogEntry)>>> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::api::search-oplog", "get-next");
+
+        let entries = self.search_next_oplog_entries(&self_).await?;
+        Ok(entries.map(|entries| {
+            entries
+                .into_iter()
+                .map(|(idx, entry)| {
+                    let idx: golem::api1_2_0::oplog::OplogIndex = idx.into();
+                    let entry: OplogEntry = entry.into();
+                    (idx, entry)
+                })
+                .collect()
+        }))
+    }
+
+    fn drop(&mut self, rep: Resource<SearchOplogEntry>) -> anyhow::Result<()> {
+        golem::api1_1_0::oplog::HostSearchOplog::drop(self, rep)
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> OplogHost for DurableWorkerCtx<Ctx> {}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> TopicsHost for DurableWorkerCtx<Ctx> {
+    async fn publish(
+        &mut self,
+        topic: String,
+        message: WitValue,
+    ) -> anyhow::Result<Result<String, String>> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::api::topics", "publish");
+
+        // Derived from the invocation and the oplog position, so a retried call publishes the
+        // message with the same id and the worker service stores it only once
+        let current_idempotency_key = self
+            .get_current_idempotency_key()
+            .await
+            .unwrap_or(IdempotencyKey::fresh());
+        let oplog_index = self.state.current_oplog_index().await;
+        let key = IdempotencyKey::derived(&current_idempotency_key, oplog_index);
+        let message_id = uuid::Uuid::parse_str(&key.value)?; // this is guaranteed to be a uuid
+
+        let owned_worker_id = self.owned_worker_id.clone();
+        let message: golem_wasm_rpc::WitValue = message.into();
+        let result = Durability::<Ctx, (String, String), (), SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem::api::topics::publish",
+            (topic.clone(), message_id.to_string()),
+            |ctx| {
+                Box::pin(async move {
+                    ctx.state
+                        .worker_proxy
+                        .publish_to_topic(&owned_worker_id, topic, message_id, message)
+                        .await
+                })
+            },
+        )
+        .await;
+
+        match result {
+            Ok(()) => Ok(Ok(message_id.to_string())),
+            Err(err) => Ok(Err(err.to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> HostGetWorkers for &mut DurableWorkerCtx<Ctx> {
+    async fn new(
+        &mut self,
+        component_id: ComponentId,
+        filter: Option<WorkerAnyFilter>,
+        precise: bool,
+    ) -> anyhow::Result<Resource<GetWorkersEntry>> {
+        HostGetWorkers::new(*self, component_id, filter, precise).await
+    }
+
+    async fn get_next(
+        &mut self,
+        self_: Resource<GetWorkersEntry>,
+    ) -> anyhow::Result<Option<Vec<WorkerMetadata>>> {
+        HostGetWorkers::get_next(*self, self_).await
+    }
+
+    fn drop(&mut self, rep: Resource<GetWorkersEntry>) -> anyhow::Result<()> {
+        HostGetWorkers::drop(*self, rep)
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for &mut DurableWorkerCtx<Ctx> {
+    async fn create_promise(&mut self) -> anyhow::Result<PromiseId> {
+        Host::create_promise(*self).await
+    }
+
+    async fn await_promise(&mut self, promise_id: PromiseId) -> anyhow::Result<Vec<u8>> {
+        Host::await_promise(*self, promise_id).await
+    }
+
+    async fn complete_promise(
+        &mut self,
+        promise_id: PromiseId,
+        data: Vec<u8>,
+    ) -> anyhow::Result<bool> {
+        Host::complete_promise(*self, promise_id, data).await
+    }
+
+    async fn delete_promise(&mut self, promise_id: PromiseId) -> anyhow::Result<()> {
+        Host::delete_promise(*self, promise_id).await
+    }
+
+    async fn get_oplog_index(&mut self) -> anyhow::Result<OplogIndex> {
+        Host::get_oplog_index(*self).await
+    }
+
+    async fn set_oplog_index(&mut self, oplog_idx: OplogIndex) -> anyhow::Result<()> {
+        Host::set_oplog_index(*self, oplog_idx).await
+    }
+
+    async fn oplog_commit(&mut self, replicas: u8) -> anyhow::Result<()> {
+        Host::oplog_commit(*self, replicas).await
+    }
+
+    async fn mark_begin_operation(&mut self) -> anyhow::Result<OplogIndex> {
+        Host::mark_begin_operation(*self).await
+    }
+
+    async fn mark_end_operation(&mut self, begin: OplogIndex) -> anyhow::Result<()> {
+        Host::mark_end_operation(*self, begin).await
+    }
+
+    async fn get_retry_policy(&mut self) -> anyhow::Result<RetryPolicy> {
+        Host::get_retry_policy(*self).await
+    }
+
+    async fn set_retry_policy(&mut self, new_retry_policy: RetryPolicy) -> anyhow::Result<()> {
+        Host::set_retry_policy(*self, new_retry_policy).await
+    }
+
+    async fn get_oplog_persistence_level(&mut self) -> anyhow::Result<PersistenceLevel> {
+        Host::get_oplog_persistence_level(*self).await
+    }
+
+    async fn set_oplog_persistence_level(
+        &mut self,
+        new_persistence_level: PersistenceLevel,
+    ) -> anyhow::Result<()> {
+        Host::set_oplog_persistence_level(*self, new_persistence_level).await
+    }
+
+    async fn get_idempotence_mode(&mut self) -> anyhow::Result<bool> {
+        Host::get_idempotence_mode(*self).await
+    }
+
+    async fn set_idempotence_mode(&mut self, idempotent: bool) -> anyhow::Result<()> {
+        Host::set_idempotence_mode(*self, idempotent).await
+    }
+
+    async fn generate_idempotency_key(&mut self) -> anyhow::Result<Uuid> {
+        Host::generate_idempotency_key(*self).await
+    }
+
+    async fn update_worker(
+        &mut self,
+        worker_id: WorkerId,
+        target_version: ComponentVersion,
+        mode: UpdateMode,
+    ) -> anyhow::Result<()> {
+        Host::update_worker(*self, worker_id, target_version, mode).await
+    }
+
+    async fn get_self_metadata(&mut self) -> anyhow::Result<WorkerMetadata> {
+        Host::get_self_metadata(*self).await
+    }
+
+    async fn get_worker_metadata(
+        &mut self,
+        worker_id: WorkerId,
+    ) -> anyhow::Result<Option<WorkerMetadata>> {
+        Host::get_worker_metadata(*self, worker_id).await
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> HostGetOplog for &mut DurableWorkerCtx<Ctx> {
+    async fn new(
+        &mut self,
+        worker_id: golem::api1_2_0::oplog::WorkerId,
+        start: golem::api1_2_0::oplog::OplogIndex,
+    ) -> anyhow::Result<Resource<GetOplogEntry>> {
+        HostGetOplog::new(*self, worker_id, start).await
+    }
+
+    async fn get_next(
+        &mut self,
+        self_: Resource<GetOplogEntry>,
+    ) -> anyhow::Result<Option<Vec<OplogEntry>>> {
+        HostGetOplog::get_next(*self, self_).await
+    }
+
+    fn drop(&mut self, rep: Resource<GetOplogEntry>) -> anyhow::Result<()> {
+        HostGetOplog::drop(*self, rep)
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> HostSearchOplog for &mut DurableWorkerCtx<Ctx> {
+    async fn new(
+        &mut self,
+        worker_id: golem::api1_2_0::oplog::WorkerId,
+        text: String,
+    ) -> anyhow::Result<Resource<SearchOplogEntry>> {
+        HostSearchOplog::new(*self, worker_id, text).await
+    }
+
+    async fn get_next(
+        &mut self,
+        self_: Resource<SearchOplogEntry>,
+    ) -> anyhow::Result<Option<Vec<(golem::api1_2_0::oplog::OplogIndex, OplogEntry)>>> {
+        HostSearchOplog::get_next(*self, self_).await
+    }
+
+    fn drop(&mut self, rep: Resource<SearchOplogEntry>) -> anyhow::Result<()> {
+        HostSearchOplog::drop(*self, rep)
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> OplogHost for &mut DurableWorkerCtx<Ctx> {}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> TopicsHost for &mut DurableWorkerCtx<Ctx> {
+    async fn publish(
+        &mut self,
+        topic: String,
+        message: WitValue,
+    ) -> anyhow::Result<Result<String, String>> {
+        TopicsHost::publish(*self, topic, message).await
+    }
+}
+
+impl From<golem_common::model::WorkerMetadata> for WorkerMetadata {
+    fn from(value: golem_common::model::WorkerMetadata) -> Self {
+        let labels = value
+            .last_known_status
+            .labels()
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        Self {
+            worker_id: value.worker_id.into(),
+            args: value.args,
+            env: value.env,
+            status: golem::api0_2_0::host::WorkerStatus::from(value.last_known_status.status)
+                .into(),
+            component_version: value.last_known_status.component_version,
+            retry_count: 0,
+            labels,
+        }
+    }
+}
+
+impl From<WorkerPropertyFilter> for golem_common::model::WorkerFilter {
+    fn from(filter: WorkerPropertyFilter) -> Self {
+        match filter {
+            WorkerPropertyFilter::Name(filter) => {
+                golem::api0_2_0::host::WorkerPropertyFilter::Name(filter.into()).into()
+            }
+            WorkerPropertyFilter::Status(filter) => {
+                golem::api0_2_0::host::WorkerPropertyFilter::Status(filter.into()).into()
+            }
+            WorkerPropertyFilter::Version(filter) => {
+                golem::api0_2_0::host::WorkerPropertyFilter::Version(filter.into()).into()
+            }
+            WorkerPropertyFilter::CreatedAt(filter) => {
+                golem::api0_2_0::host::WorkerPropertyFilter::CreatedAt(filter.into()).into()
+            }
+            WorkerPropertyFilter::Env(filter) => {
+                golem::api0_2_0::host::WorkerPropertyFilter::Env(filter.into()).into()
+            }
+            WorkerPropertyFilter::Label(filter) => golem_common::model::WorkerFilter::new_label(
+                filter.name,
+                golem::api0_2_0::host::StringFilterComparator::from(filter.comparator).into(),
+                filter.value,
+            ),
+        }
+    }
+}
+
+impl From<WorkerAllFilter> for golem_common::model::WorkerFilter {
+    fn from(filter: WorkerAllFilter) -> Self {
+        let filters = filter.filters.into_iter().map(|f| f.into()).collect();
+        golem_common::model::WorkerFilter::new_and(filters)
+    }
+}
+
+impl From<WorkerAnyFilter> for golem_common::model::WorkerFilter {
+    fn from(filter: WorkerAnyFilter) -> Self {
+        let filters = filter.filters.into_iter().map(|f| f.into()).collect();
+        golem_common::model::WorkerFilter::new_or(filters)
+    }
+}
@@ -0,0 +1,484 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use golem_common::model::component_metadata::{CapabilityPolicy, EgressPolicy, HostCapability};
+use golem_common::model::secret::SecretReference;
+use http::header::CONTENT_LENGTH;
+use http::{HeaderName, HeaderValue};
+use http_body::{Body, Frame, SizeHint};
+use http_body_util::combinators::BoxBody;
+use tracing::warn;
+use wasmtime_wasi_http::bindings::http::types::{DnsErrorPayload, ErrorCode};
+use wasmtime_wasi_http::body::HyperOutgoingBody;
+use wasmtime_wasi_http::types::OutgoingRequestConfig;
+
+/// The egress rules applying to the outgoing HTTP requests of a worker
+pub struct EgressRules<'a> {
+    pub capability_policy: Option<&'a CapabilityPolicy>,
+    /// Egress policies in increasing order of precedence for the injected headers
+    pub policies: Vec<&'a EgressPolicy>,
+    /// Resolved secret values by name, for the injected headers referring to secrets
+    pub secrets: &'a HashMap<String, String>,
+}
+
+impl<'a> EgressRules<'a> {
+    pub fn new(
+        capability_policy: Option<&'a CapabilityPolicy>,
+        component_policy: Option<&'a EgressPolicy>,
+        account_policy: Option<&'a EgressPolicy>,
+        secrets: &'a HashMap<String, String>,
+    ) -> Self {
+        Self {
+            capability_policy,
+            policies: component_policy.into_iter().chain(account_policy).collect(),
+            secrets,
+        }
+    }
+
+    /// Checks the request against the rules and applies the limits, timeouts and injected headers.
+    ///
+    /// Returns the error code to be reported to the guest if the request is not allowed. Rules on
+    /// the resolved addresses of the host are not checked here, see [`EgressRules::address_check`].
+    pub fn apply(
+        &self,
+        mut request: hyper::Request<HyperOutgoingBody>,
+        mut config: OutgoingRequestConfig,
+    ) -> Result<(hyper::Request<HyperOutgoingBody>, OutgoingRequestConfig), ErrorCode> {
+        let (host, port) = destination(&request, &config);
+
+        if let Some(capability_policy) = self.capability_policy {
+            if capability_policy.denies(HostCapability::Http) {
+                warn!("Outgoing HTTP request to {host} denied by the capability policy");
+                return Err(ErrorCode::HttpRequestDenied);
+            }
+            if !capability_policy.allows_http_host(&host) {
+                warn!("Outgoing HTTP request to {host} denied by the capability policy");
+                return Err(ErrorCode::HttpRequestDenied);
+            }
+        }
+        let is_ip = host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+            .is_ok();
+        // Host names not allowed by name may still be allowed by the addresses they resolve to
+        if !self.policies.iter().all(|policy| {
+            policy.allows_destination(&host, port) || (!is_ip && policy.checks_addresses())
+        }) {
+            warn!("Outgoing HTTP request to {host}:{port} denied by the egress policy");
+            return Err(ErrorCode::HttpRequestDenied);
+        }
+
+        if let Some(max_body_size) = self
+            .policies
+            .iter()
+            .filter_map(|policy| policy.max_request_body_size)
+            .min()
+        {
+            let content_length = request
+                .headers()
+                .get(CONTENT_LENGTH)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok());
+            if let Some(content_length) = content_length {
+                if content_length > max_body_size {
+                    return Err(ErrorCode::HttpRequestBodySize(Some(content_length)));
+                }
+            }
+            request = request.map(|body| {
+                BoxBody::new(LimitedBody {
+                    inner: body,
+                    remaining: max_body_size,
+                })
+            });
+        }
+
+        if let Some(timeout) = self
+            .policies
+            .iter()
+            .filter_map(|policy| policy.timeout())
+            .min()
+        {
+            config.connect_timeout = config.connect_timeout.min(timeout);
+            config.first_byte_timeout = config.first_byte_timeout.min(timeout);
+            config.between_bytes_timeout = config.between_bytes_timeout.min(timeout);
+        }
+
+        for header in self
+            .policies
+            .iter()
+            .flat_map(|policy| policy.injected_headers.iter())
+        {
+            let value = match SecretReference::parse(&header.value) {
+                Some(name) => match self.secrets.get(name) {
+                    Some(value) => value,
+                    None => {
+                        warn!(
+                            "Skipping injected header {} using unknown secret {name}",
+                            header.name
+                        );
+                        continue;
+                    }
+                },
+                None => &header.value,
+            };
+            match (
+                HeaderName::from_bytes(header.name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                (Ok(name), Ok(value)) => {
+                    request.headers_mut().insert(name, value);
+                }
+                _ => {
+                    warn!("Skipping invalid injected header {}", header.name);
+                }
+            }
+        }
+
+        Ok((request, config))
+    }
+
+    /// Returns the check to be done on the addresses the host of the request resolves to, if
+    /// any policy has address rules and the host is not an IP address already checked by
+    /// [`EgressRules::apply`].
+    pub fn address_check(
+        &self,
+        request: &hyper::Request<HyperOutgoingBody>,
+        config: &OutgoingRequestConfig,
+    ) -> Option<AddressCheck> {
+        let (host, port) = destination(request, config);
+        let policies = self
+            .policies
+            .iter()
+            .filter(|policy| policy.checks_addresses())
+            .map(|policy| (*policy).clone())
+            .collect::<Vec<_>>();
+        if policies.is_empty() || host.parse::<IpAddr>().is_ok() || host.starts_with('[') {
+            None
+        } else {
+            Some(AddressCheck {
+                host,
+                port,
+                policies,
+            })
+        }
+    }
+}
+
+/// Checks the addresses the host of a request resolves to against the egress policies, so a
+/// host name resolving into a denied block cannot bypass them. Owned, so it can be moved into the
+/// task sending the request.
+///
+/// The connection resolves the host name again, relying on the system resolver returning the
+/// same addresses shortly after the check.
+pub struct AddressCheck {
+    host: String,
+    port: u16,
+    policies: Vec<EgressPolicy>,
+}
+
+impl AddressCheck {
+    pub async fn run(&self) -> Result<(), ErrorCode> {
+        let addresses = tokio::net::lookup_host((self.host.as_str(), self.port))
+            .await
+            .map_err(|_| {
+                ErrorCode::DnsError(DnsErrorPayload {
+                    rcode: Some("address not available".to_string()),
+                    info_code: Some(0),
+                })
+            })?
+            .map(|address| address.ip())
+            .collect::<Vec<_>>();
+        self.check(&addresses)
+    }
+
+    fn check(&self, addresses: &[IpAddr]) -> Result<(), ErrorCode> {
+        if self
+            .policies
+            .iter()
+            .all(|policy| policy.allows_addresses(&self.host, addresses, self.port))
+        {
+            Ok(())
+        } else {
+            warn!(
+                "Outgoing HTTP request to {}:{} denied by the egress policy, as it resolves to {addresses:?}",
+                self.host, self.port
+            );
+            Err(ErrorCode::HttpRequestDenied)
+        }
+    }
+}
+
+fn destination(
+    request: &hyper::Request<HyperOutgoingBody>,
+    config: &OutgoingRequestConfig,
+) -> (String, u16) {
+    let host = request.uri().host().unwrap_or_default().to_string();
+    let port = request
+        .uri()
+        .port_u16()
+        .unwrap_or(if config.use_tls { 443 } else { 80 });
+    (host, port)
+}
+
+/// Request body failing with `HTTP-request-body-size` when it exceeds the configured limit
+struct LimitedBody {
+    inner: HyperOutgoingBody,
+    remaining: u64,
+}
+
+impl Body for LimitedBody {
+    type Data = Bytes;
+    type Error = ErrorCode;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        match Pin::new(&mut self.inner).poll_frame(cx) {
+            Poll::Ready(Some(Ok(frame))) => {
+                if let Some(data) = frame.data_ref() {
+                    let size = data.len() as u64;
+                    if size > self.remaining {
+                        return Poll::Ready(Some(Err(ErrorCode::HttpRequestBodySize(None))));
+                    }
+                    self.remaining -= size;
+                }
+                Poll::Ready(Some(Ok(frame)))
+            }
+            other => other,
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use golem_common::model::component_metadata::{
+        CapabilityPolicy, EgressDestination, EgressHeader, EgressPolicy,
+    };
+    use http_body_util::{BodyExt, Full};
+    use wasmtime_wasi_http::bindings::http::types::ErrorCode;
+    use wasmtime_wasi_http::body::HyperOutgoingBody;
+    use wasmtime_wasi_http::types::OutgoingRequestConfig;
+
+    use crate::durable_host::http::egress::EgressRules;
+
+    fn request(uri: &str, body: &'static str) -> hyper::Request<HyperOutgoingBody> {
+        hyper::Request::builder()
+            .uri(uri)
+            .header("x-api-key", "from-worker")
+            .body(
+                Full::new(bytes::Bytes::from_static(body.as_bytes()))
+                    .map_err(|_| ErrorCode::InternalError(None))
+                    .boxed(),
+            )
+            .unwrap()
+    }
+
+    fn config(use_tls: bool) -> OutgoingRequestConfig {
+        OutgoingRequestConfig {
+            use_tls,
+            connect_timeout: Duration::from_secs(600),
+            first_byte_timeout: Duration::from_secs(600),
+            between_bytes_timeout: Duration::from_secs(600),
+        }
+    }
+
+    fn policy() -> EgressPolicy {
+        EgressPolicy {
+            allowed_destinations: vec![EgressDestination {
+                host: "*.example.com".to_string(),
+                ports: vec![443],
+            }],
+            max_request_body_size: Some(4),
+            timeout_millis: Some(1000),
+            injected_headers: vec![EgressHeader {
+                name: "x-api-key".to_string(),
+                value: "from-component".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn denies_destinations() {
+        let policy = policy();
+        let secrets = HashMap::new();
+        let rules = EgressRules::new(None, Some(&policy), None, &secrets);
+
+        assert!(rules
+            .apply(request("https://api.example.com/", ""), config(true))
+            .is_ok());
+        assert!(matches!(
+            rules.apply(request("http://api.example.com/", ""), config(false)),
+            Err(ErrorCode::HttpRequestDenied)
+        ));
+        assert!(matches!(
+            rules.apply(request("https://golem.cloud/", ""), config(true)),
+            Err(ErrorCode::HttpRequestDenied)
+        ));
+
+        let capability_policy = CapabilityPolicy {
+            denied: vec![],
+            allowed_http_hosts: Some(vec!["other.example.com".to_string()]),
+        };
+        let rules = EgressRules::new(Some(&capability_policy), Some(&policy), None, &secrets);
+        assert!(matches!(
+            rules.apply(request("https://api.example.com/", ""), config(true)),
+            Err(ErrorCode::HttpRequestDenied)
+        ));
+    }
+
+    #[test]
+    fn applies_timeouts_and_headers() {
+        let component_policy = policy();
+        let account_policy = EgressPolicy {
+            timeout_millis: Some(500),
+            injected_headers: vec![EgressHeader {
+                name: "x-api-key".to_string(),
+                value: "from-account".to_string(),
+            }],
+            ..EgressPolicy::default()
+        };
+        let secrets = HashMap::new();
+        let rules = EgressRules::new(
+            None,
+            Some(&component_policy),
+            Some(&account_policy),
+            &secrets,
+        );
+
+        let (request, config) = rules
+            .apply(request("https://api.example.com/", ""), config(true))
+            .unwrap();
+        assert_eq!(config.connect_timeout, Duration::from_millis(500));
+        assert_eq!(config.first_byte_timeout, Duration::from_millis(500));
+        assert_eq!(config.between_bytes_timeout, Duration::from_millis(500));
+        assert_eq!(request.headers()["x-api-key"], "from-account");
+    }
+
+    #[test]
+    fn injects_secret_headers() {
+        let policy = EgressPolicy {
+            injected_headers: vec![
+                EgressHeader {
+                    name: "authorization".to_string(),
+                    value: "${secret:api-token}".to_string(),
+                },
+                EgressHeader {
+                    name: "x-api-key".to_string(),
+                    value: "${secret:unknown}".to_string(),
+                },
+            ],
+            ..EgressPolicy::default()
+        };
+        let secrets = HashMap::from([("api-token".to_string(), "Bearer s3cr3t".to_string())]);
+        let rules = EgressRules::new(None, Some(&policy), None, &secrets);
+
+        let (request, _) = rules
+            .apply(request("https://api.example.com/", ""), config(true))
+            .unwrap();
+        assert_eq!(request.headers()["authorization"], "Bearer s3cr3t");
+        assert_eq!(request.headers()["x-api-key"], "from-worker");
+    }
+
+    #[test]
+    fn checks_resolved_addresses() {
+        let address_policy = EgressPolicy {
+            allowed_destinations: vec![
+                EgressDestination {
+                    host: "*.example.com".to_string(),
+                    ports: vec![],
+                },
+                EgressDestination {
+                    host: "10.0.0.0/8".to_string(),
+                    ports: vec![],
+                },
+            ],
+            denied_addresses: vec!["169.254.0.0/16".to_string()],
+            ..EgressPolicy::default()
+        };
+        let secrets = HashMap::new();
+        let rules = EgressRules::new(None, Some(&address_policy), None, &secrets);
+
+        // Host names not matching by name are only decided after resolving them
+        let internal = request("https://db.internal/", "");
+        let check = rules.address_check(&internal, &config(true)).unwrap();
+        assert!(rules.apply(internal, config(true)).is_ok());
+        assert!(check.check(&["10.1.2.3".parse().unwrap()]).is_ok());
+        assert!(matches!(
+            check.check(&["93.184.215.14".parse().unwrap()]),
+            Err(ErrorCode::HttpRequestDenied)
+        ));
+
+        let public = request("https://api.example.com/", "");
+        let check = rules.address_check(&public, &config(true)).unwrap();
+        assert!(check.check(&["93.184.215.14".parse().unwrap()]).is_ok());
+        assert!(matches!(
+            check.check(&["169.254.169.254".parse().unwrap()]),
+            Err(ErrorCode::HttpRequestDenied)
+        ));
+
+        // Literal addresses are checked without resolving them
+        let literal = request("http://169.254.169.254/", "");
+        assert!(rules.address_check(&literal, &config(false)).is_none());
+        assert!(matches!(
+            rules.apply(literal, config(false)),
+            Err(ErrorCode::HttpRequestDenied)
+        ));
+
+        let name_only = policy();
+        let rules = EgressRules::new(None, Some(&name_only), None, &secrets);
+        let public = request("https://api.example.com/", "");
+        assert!(rules.address_check(&public, &config(true)).is_none());
+    }
+
+    #[test]
+    async fn limits_request_body_size() {
+        let policy = policy();
+        let secrets = HashMap::new();
+        let rules = EgressRules::new(None, Some(&policy), None, &secrets);
+
+        let (small, _) = rules
+            .apply(request("https://api.example.com/", "abc"), config(true))
+            .unwrap();
+        assert!(small.into_body().collect().await.is_ok());
+
+        let (large, _) = rules
+            .apply(request("https://api.example.com/", "abcdef"), config(true))
+            .unwrap();
+        assert!(matches!(
+            large.into_body().collect().await,
+            Err(ErrorCode::HttpRequestBodySize(None))
+        ));
+    }
+}
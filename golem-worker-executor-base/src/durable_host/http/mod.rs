@@ -18,6 +18,9 @@ use crate::workerctx::WorkerCtx;
 use golem_common::model::oplog::WrappedFunctionType;
 use tracing::warn;
 
+/// Egress rules enforced on the outgoing HTTP requests
+pub mod egress;
+
 pub mod outgoing_http;
 
 /// Serializable response data structures to be stored in the oplog
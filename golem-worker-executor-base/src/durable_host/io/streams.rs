@@ -195,6 +195,7 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
     ) -> Result<(), StreamError> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("io::streams::output_stream", "write");
+        self.mark_volumes_dirty_if_file_stream(&self_);
 
         let output = self.table().get(&self_)?;
         let event = if output.as_any().downcast_ref::<ManagedStdOut>().is_some() {
@@ -249,6 +250,7 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
     ) -> Result<(), StreamError> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("io::streams::output_stream", "write_zeroeas");
+        self.mark_volumes_dirty_if_file_stream(&self_);
         HostOutputStream::write_zeroes(&mut self.as_wasi_view(), self_, len).await
     }
 
@@ -262,6 +264,7 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
             "io::streams::output_stream",
             "blocking_write_zeroes_and_flush",
         );
+        self.mark_volumes_dirty_if_file_stream(&self_);
         HostOutputStream::blocking_write_zeroes_and_flush(&mut self.as_wasi_view(), self_, len)
             .await
     }
@@ -274,6 +277,7 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
     ) -> Result<u64, StreamError> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("io::streams::output_stream", "splice");
+        self.mark_volumes_dirty_if_file_stream(&self_);
         HostOutputStream::splice(&mut self.as_wasi_view(), self_, src, len).await
     }
 
@@ -285,11 +289,13 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
     ) -> Result<u64, StreamError> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("io::streams::output_stream", "blocking_splice");
+        self.mark_volumes_dirty_if_file_stream(&self_);
         HostOutputStream::blocking_splice(&mut self.as_wasi_view(), self_, src, len).await
     }
 
     fn drop(&mut self, rep: Resource<OutputStream>) -> anyhow::Result<()> {
         record_host_function_call("io::streams::output_stream", "drop");
+        self.file_output_streams.remove(&rep.rep());
         HostOutputStream::drop(&mut self.as_wasi_view(), rep)
     }
 }
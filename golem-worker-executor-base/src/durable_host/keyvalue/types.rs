@@ -24,6 +24,7 @@ use crate::preview2::wasi::keyvalue::types::{
 use crate::workerctx::WorkerCtx;
 use async_trait::async_trait;
 use bytes::Bytes;
+use golem_common::model::component_metadata::HostCapability;
 use wasmtime::component::Resource;
 use wasmtime_wasi::{
     HostInputStream, HostOutputStream, InputStream, StreamResult, Subscribe, WasiView,
@@ -37,6 +38,7 @@ impl<Ctx: WorkerCtx> HostBucket for DurableWorkerCtx<Ctx> {
     ) -> anyhow::Result<Result<Resource<BucketEntry>, Resource<Error>>> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("keyvalue::types::bucket", "open");
+        self.fail_if_denied(HostCapability::KeyValue)?;
         let bucket = self.as_wasi_view().table().push(BucketEntry::new(name))?;
         Ok(Ok(bucket))
     }
@@ -15,6 +15,7 @@
 // WASI Host implementation for Golem, delegating to the core WASI implementation (wasmtime_wasi)
 // implementing the Golem specific instrumentation on top of it.
 
+use crate::durable_host::http::egress::EgressRules;
 use crate::durable_host::http::serialized::SerializableHttpRequest;
 use crate::durable_host::io::{ManagedStdErr, ManagedStdIn, ManagedStdOut};
 use crate::durable_host::replay_state::ReplayState;
@@ -24,9 +25,11 @@ use crate::error::GolemError;
 use crate::function_result_interpreter::interpret_function_results;
 use crate::invocation::{invoke_worker, InvokeResult};
 use crate::metrics::wasm::{record_number_of_replayed_functions, record_resume_worker};
+use crate::metrics::workers::record_failed_worker;
 use crate::model::{
-    CurrentResourceLimits, ExecutionStatus, InterruptKind, LastError, ListDirectoryResult,
-    PersistenceLevel, ReadFileResult, TrapType, WorkerConfig,
+    CurrentResourceLimits, DeleteFileResult, ExecutionStatus, InterruptKind, LastError,
+    ListDirectoryResult, MakeDirectoryResult, PersistenceLevel, PutFileResult, ReadFileResult,
+    TrapType, WorkerConfig,
 };
 use crate::services::blob_store::BlobStoreService;
 use crate::services::component::{ComponentMetadata, ComponentService};
@@ -38,6 +41,7 @@ use crate::services::plugins::Plugins;
 use crate::services::promise::PromiseService;
 use crate::services::rpc::Rpc;
 use crate::services::scheduler::SchedulerService;
+use crate::services::volume::{VolumeService, VolumeSnapshot};
 use crate::services::worker::WorkerService;
 use crate::services::worker_event::WorkerEventService;
 use crate::services::worker_proxy::WorkerProxy;
@@ -47,8 +51,9 @@ use crate::wasi_host;
 use crate::worker::{calculate_last_known_status, is_worker_error_retriable};
 use crate::worker::{RetryDecision, Worker};
 use crate::workerctx::{
-    ExternalOperations, FileSystemReading, IndexedResourceStore, InvocationHooks,
-    InvocationManagement, PublicWorkerIo, StatusManagement, UpdateManagement, WorkerCtx,
+    ExternalOperations, FileSystemReading, FileSystemWriting, IndexedResourceStore,
+    InvocationHooks, InvocationManagement, PublicWorkerIo, StatusManagement, UpdateManagement,
+    WorkerCtx,
 };
 use anyhow::anyhow;
 use async_trait::async_trait;
@@ -60,27 +65,32 @@ use futures_util::TryFutureExt;
 use futures_util::TryStreamExt;
 use golem_common::config::RetryConfig;
 use golem_common::model::component::ComponentOwner;
+use golem_common::model::component_metadata::{
+    ComponentVolume, EgressPolicy, FailedWorkerAction, HostCapability,
+};
 use golem_common::model::oplog::{
     IndexedResourceKey, LogLevel, OplogEntry, OplogIndex, UpdateDescription, WorkerError,
     WorkerResourceId, WrappedFunctionType,
 };
 use golem_common::model::plugin::{PluginOwner, PluginScope};
 use golem_common::model::regions::{DeletedRegions, OplogRegion};
+use golem_common::model::secret::SecretReference;
 use golem_common::model::{exports, PluginInstallationId};
 use golem_common::model::{
     AccountId, ComponentFilePath, ComponentFilePermissions, ComponentFileSystemNode,
     ComponentFileSystemNodeDetails, ComponentId, ComponentType, ComponentVersion,
-    FailedUpdateRecord, IdempotencyKey, InitialComponentFile, OwnedWorkerId, ScanCursor,
-    ScheduledAction, SuccessfulUpdateRecord, Timestamp, WorkerEvent, WorkerFilter, WorkerId,
-    WorkerMetadata, WorkerResourceDescription, WorkerStatus, WorkerStatusRecord,
+    FailedUpdateRecord, IdempotencyKey, InitialComponentFile, InvocationOptions, OwnedWorkerId,
+    ScanCursor, ScheduledAction, SuccessfulUpdateRecord, Timestamp, WorkerEvent, WorkerFilter,
+    WorkerId, WorkerMetadata, WorkerResourceDescription, WorkerStatus, WorkerStatusRecord,
 };
 use golem_common::retries::get_delay;
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::wasmtime::ResourceStore;
 use golem_wasm_rpc::{Uri, Value};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
+use std::net::{IpAddr, SocketAddr};
 use std::ops::Add;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, RwLock, Weak};
@@ -92,12 +102,14 @@ use tracing::{debug, info, span, warn, Instrument, Level};
 use wasmtime::component::{Instance, Resource, ResourceAny};
 use wasmtime::{AsContext, AsContextMut};
 use wasmtime_wasi::bindings::filesystem::preopens::Descriptor;
+use wasmtime_wasi::bindings::io::streams::OutputStream;
 use wasmtime_wasi::{
     FsResult, I32Exit, ResourceTable, ResourceTableError, Stderr, Stdout, WasiCtx, WasiView,
 };
 use wasmtime_wasi_http::body::HyperOutgoingBody;
 use wasmtime_wasi_http::types::{
-    default_send_request, HostFutureIncomingResponse, OutgoingRequestConfig,
+    default_send_request, default_send_request_handler, HostFutureIncomingResponse,
+    OutgoingRequestConfig,
 };
 use wasmtime_wasi_http::{HttpResult, WasiHttpCtx, WasiHttpView};
 
@@ -134,6 +146,33 @@ pub struct DurableWorkerCtx<Ctx: WorkerCtx> {
     _used_files: Vec<FileUseToken>,
     read_only_paths: Arc<RwLock<HashSet<PathBuf>>>,
     execution_status: Arc<RwLock<ExecutionStatus>>,
+    volume_service: Arc<VolumeService>,
+    mounted_volumes: Vec<MountedVolume>,
+    /// Set when the worker's file system got modified since the volumes were last synchronized
+    volumes_dirty: bool,
+    /// Resource ids of the output streams writing to files, to detect volume changes made through them
+    file_output_streams: HashSet<u32>,
+    secrets: ResolvedSecrets,
+    /// Host names by the addresses they resolved to through `wasi:sockets`, to check socket
+    /// connections against the egress rules of the host names
+    resolved_hosts: HashMap<IpAddr, String>,
+}
+
+/// The secrets used by a worker, resolved when the worker gets loaded. They must never be written
+/// to the oplog or the worker's metadata.
+#[derive(Default)]
+struct ResolvedSecrets {
+    /// Secret values by secret name
+    values: HashMap<String, String>,
+    /// Secret names by the name of the environment variable they are exposed in
+    env_vars: BTreeMap<String, String>,
+}
+
+/// A persistent volume mounted into the worker's file system
+struct MountedVolume {
+    volume: ComponentVolume,
+    local_dir: PathBuf,
+    snapshot: VolumeSnapshot,
 }
 
 impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
@@ -159,6 +198,7 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         worker_config: WorkerConfig,
         execution_status: Arc<RwLock<ExecutionStatus>>,
         file_loader: Arc<FileLoader>,
+        volume_service: Arc<VolumeService>,
         plugins: Arc<
             dyn Plugins<<Ctx::ComponentOwner as ComponentOwner>::PluginOwner, Ctx::PluginScope>
                 + Send
@@ -186,15 +226,33 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         )
         .await?;
 
+        let mounted_volumes = mount_volumes(
+            &volume_service,
+            &owned_worker_id,
+            temp_dir.path(),
+            &component_metadata.volumes,
+        )
+        .await?;
+
         let stdin = ManagedStdIn::disabled();
         let stdout = ManagedStdOut::from_stdout(Stdout);
         let stderr = ManagedStdErr::from_stderr(Stderr);
 
         let last_oplog_index = oplog.current_oplog_index().await;
 
+        let secrets = resolve_secrets(
+            &component_service,
+            &owned_worker_id,
+            &component_metadata,
+            &worker_config.env,
+            config.egress.account_policy(&owned_worker_id.account_id),
+        )
+        .await?;
+        let env = secrets.apply_to_env(&worker_config.env);
+
         let (wasi, table) = wasi_host::create_context(
             &worker_config.args,
-            &worker_config.env,
+            &env,
             temp_dir.path().to_path_buf(),
             stdin,
             stdout,
@@ -240,6 +298,12 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
             _used_files: file_use_tokens,
             read_only_paths: Arc::new(RwLock::new(read_only_paths)),
             execution_status,
+            volume_service,
+            mounted_volumes,
+            volumes_dirty: false,
+            file_output_streams: HashSet::new(),
+            secrets,
+            resolved_hosts: HashMap::new(),
         })
     }
 
@@ -269,6 +333,33 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         }
     }
 
+    /// Checks whether the given path is a read-only initial file, or is located below one
+    fn is_read_only_path(&self, path: &Path) -> bool {
+        let read_only_paths = self
+            .read_only_paths
+            .read()
+            .expect("There should be no writers to read_only_paths");
+        path.ancestors()
+            .any(|ancestor| read_only_paths.contains(ancestor))
+    }
+
+    /// Marks the mounted volumes as changed, so they get written back at the next commit point
+    fn mark_volumes_dirty(&mut self) {
+        if !self.mounted_volumes.is_empty() {
+            self.volumes_dirty = true;
+        }
+    }
+
+    fn register_file_output_stream(&mut self, stream: &Resource<OutputStream>) {
+        self.file_output_streams.insert(stream.rep());
+    }
+
+    fn mark_volumes_dirty_if_file_stream(&mut self, stream: &Resource<OutputStream>) {
+        if self.file_output_streams.contains(&stream.rep()) {
+            self.mark_volumes_dirty();
+        }
+    }
+
     fn fail_if_read_only(&mut self, fd: &Resource<Descriptor>) -> FsResult<()> {
         if self.is_read_only(fd)? {
             Err(wasmtime_wasi::bindings::filesystem::types::ErrorCode::NotPermitted.into())
@@ -292,6 +383,58 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         &self.state.component_metadata
     }
 
+    /// Checks whether the component's capability policy denies the given capability, for the
+    /// entry points which are not linked to trapping stubs (see `wasi_host::capabilities`).
+    ///
+    /// The policy is pinned to the worker's component version, so checks of entry points which are
+    /// not persisted are repeated with the same outcome during replay. Entry points wrapped by
+    /// `Durability` do the check in their live branch, which records the outcome.
+    fn is_denied(&self, capability: HostCapability) -> bool {
+        self.component_metadata()
+            .capability_policy
+            .as_ref()
+            .is_some_and(|policy| policy.denies(capability))
+    }
+
+    fn record_resolved_addresses(&mut self, name: &str, addresses: &[IpAddr]) {
+        for address in addresses {
+            self.resolved_hosts.insert(*address, name.to_string());
+        }
+    }
+
+    /// Checks a socket connection against the egress policies of the component and the account.
+    /// Addresses resolved through `wasi:sockets` are checked together with their host name.
+    fn allows_socket_address(&self, address: SocketAddr) -> bool {
+        let ip = address.ip();
+        let host = self
+            .resolved_hosts
+            .get(&ip)
+            .cloned()
+            .unwrap_or_else(|| ip.to_string());
+        self.component_metadata()
+            .egress_policy
+            .iter()
+            .chain(
+                self.state
+                    .config
+                    .egress
+                    .account_policy(&self.owned_worker_id.account_id),
+            )
+            .all(|policy| policy.allows_addresses(&host, &[ip], address.port()))
+    }
+
+    /// Traps if the component's capability policy denies the given capability
+    fn fail_if_denied(&self, capability: HostCapability) -> anyhow::Result<()> {
+        if self.is_denied(capability) {
+            Err(anyhow!(
+                "The {capability} capability is denied by the capability policy of component {}",
+                self.owned_worker_id.component_id()
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn is_exit(error: &anyhow::Error) -> Option<i32> {
         error
             .root_cause()
@@ -377,6 +520,161 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         }
     }
 
+    /// Applies the component's failure policy to the worker which just failed permanently by
+    /// notifying the dead-letter handler and deleting or reverting the worker.
+    ///
+    /// Returns true if the failed invocation got reverted, in which case the worker has to be
+    /// restarted instead of staying in the failed state.
+    async fn on_worker_failed(&mut self, error: &WorkerError) -> bool {
+        let failure_policy = self
+            .component_metadata()
+            .failure_policy
+            .clone()
+            .unwrap_or_default();
+        let error = error.to_string(&self.public_state.event_service.get_last_invocation_errors());
+        let (function_name, idempotency_key) = match &self.state.current_invocation {
+            Some((_, function_name)) => (
+                Some(function_name.clone()),
+                self.state.get_current_idempotency_key(),
+            ),
+            None => (None, None),
+        };
+
+        warn!(
+            action = failure_policy.action.to_string(),
+            "Worker failed permanently: {error}"
+        );
+        record_failed_worker(&failure_policy.action.to_string());
+        self.public_state.event_service.emit_event(
+            WorkerEvent::worker_failed(&error, function_name.as_deref(), idempotency_key.as_ref()),
+            true,
+        );
+
+        if let Some(handler) = &failure_policy.dead_letter_handler {
+            // A failing dead-letter worker does not notify itself to avoid a loop
+            if handler.worker_id != *self.worker_id() {
+                let optional_string = |value: Option<String>| {
+                    Value::Option(value.map(|value| Box::new(Value::String(value))))
+                };
+                let params = vec![
+                    Value::String(self.worker_id().to_string()).into(),
+                    Value::String(error.clone()).into(),
+                    optional_string(function_name).into(),
+                    optional_string(idempotency_key.as_ref().map(|key| key.to_string())).into(),
+                ];
+                let handler_idempotency_key = IdempotencyKey::derived(
+                    &idempotency_key.unwrap_or_else(IdempotencyKey::fresh),
+                    self.state.oplog.current_oplog_index().await,
+                );
+                if let Err(err) = self
+                    .rpc()
+                    .invoke(
+                        &OwnedWorkerId::new(&self.owned_worker_id.account_id, &handler.worker_id),
+                        Some(handler_idempotency_key),
+                        handler.function_name.clone(),
+                        params,
+                        self.worker_id(),
+                        &[],
+                        &[],
+                    )
+                    .await
+                {
+                    warn!(
+                        handler_worker_id = handler.worker_id.to_string(),
+                        "Failed to invoke the dead-letter handler: {err}"
+                    );
+                }
+            }
+        }
+
+        match failure_policy.action {
+            FailedWorkerAction::Keep => false,
+            FailedWorkerAction::Delete => {
+                debug!("Scheduling deletion of failed worker");
+                let last_oplog_index = self.state.oplog.current_oplog_index().await;
+                self.state
+                    .scheduler_service
+                    .schedule(
+                        Utc::now().add(failure_policy.delete_after()),
+                        ScheduledAction::DeleteFailedWorker {
+                            owned_worker_id: self.owned_worker_id.clone(),
+                            last_oplog_index,
+                        },
+                    )
+                    .await;
+                false
+            }
+            FailedWorkerAction::Revert => {
+                let reverted = self.revert_current_invocation().await;
+                if !reverted {
+                    warn!("Cannot revert worker which did not fail in an invocation");
+                }
+                reverted
+            }
+        }
+    }
+
+    /// Reverts the ongoing invocation by jumping over its oplog region.
+    ///
+    /// Returns false if there is no ongoing invocation to revert.
+    async fn revert_current_invocation(&mut self) -> bool {
+        match self.state.current_invocation.take() {
+            Some((invocation_start, _)) => {
+                // Deleting the oplog region of the invocation, including the entries added
+                // after it and the jump entry itself
+                let jump = OplogRegion {
+                    start: invocation_start,
+                    end: self.state.oplog.current_oplog_index().await.next(),
+                };
+                debug!("Reverting invocation by jumping over {jump}");
+                self.state
+                    .replay_state
+                    .add_deleted_region(jump.clone())
+                    .await;
+                self.state
+                    .oplog
+                    .add_and_commit(OplogEntry::jump(jump))
+                    .await;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Abandons the current invocation after its deadline has passed.
+    ///
+    /// If the invocation has already started, its changes are reverted and the worker has to be
+    /// restarted from its previous state. The invocation's result is recorded as timed out.
+    async fn on_invocation_timed_out(&mut self) -> RetryDecision {
+        let reverted = self.revert_current_invocation().await;
+
+        if let Some(idempotency_key) = self.state.get_current_idempotency_key() {
+            warn!("Invocation {idempotency_key} timed out");
+            let oplog_idx = self
+                .state
+                .oplog
+                .add_and_commit(OplogEntry::invocation_timed_out(idempotency_key.clone()))
+                .await;
+            self.public_state
+                .worker()
+                .store_invocation_timeout(&idempotency_key, oplog_idx)
+                .await;
+        }
+        self.state.current_invocation_options = InvocationOptions::default();
+
+        if self.state.is_live() {
+            self.sync_volumes().await;
+        }
+
+        if reverted {
+            self.store_worker_status(WorkerStatus::Running).await;
+            RetryDecision::Immediate
+        } else {
+            self.store_worker_status(WorkerStatus::Idle).await;
+            RetryDecision::None
+        }
+    }
+
     fn get_recovery_decision_on_trap(
         retry_config: &RetryConfig,
         previous_tries: u64,
@@ -673,6 +971,87 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> DurableWorkerCtx<Ctx> {
             }
         }
     }
+
+    /// Reapplies the filesystem changes made through the worker API that were read from the
+    /// oplog during replay
+    async fn apply_replayed_file_system_changes(&mut self) -> Result<(), GolemError> {
+        let changes = self.state.replay_state.take_file_system_changes().await;
+        for change in changes {
+            match change {
+                OplogEntry::PutFile { path, content, .. } => {
+                    debug!("Replaying writing file {path}");
+                    let content =
+                        self.state
+                            .oplog
+                            .download_payload(&content)
+                            .await
+                            .map_err(|err| GolemError::FileSystemError {
+                                path: path.to_string(),
+                                reason: format!("Failed to download file contents: {err}"),
+                            })?;
+                    self.put_file(&path, &content).await?;
+                }
+                OplogEntry::DeleteFile { path, .. } => {
+                    debug!("Replaying deleting file {path}");
+                    self.delete_file(&path).await?;
+                }
+                OplogEntry::MakeDirectory { path, .. } => {
+                    debug!("Replaying creating directory {path}");
+                    self.make_directory(&path).await?;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Discards the local changes of the mounted volumes, including the ones made while replaying
+    /// the oplog, by restoring their stored state
+    async fn restore_volumes(&mut self) -> Result<(), GolemError> {
+        for mounted in &mut self.mounted_volumes {
+            mounted.snapshot = self
+                .volume_service
+                .restore(
+                    &self.owned_worker_id.account_id,
+                    &self.owned_worker_id.worker_id.component_id,
+                    &mounted.volume.name,
+                    &mounted.local_dir,
+                )
+                .await?;
+        }
+        self.volumes_dirty = false;
+        Ok(())
+    }
+
+    /// Writes back the local changes of the mounted volumes, if the file system was modified since
+    /// the last synchronization. Failures are only logged, the changes are retried at the next
+    /// commit point.
+    async fn sync_volumes(&mut self) {
+        if !self.volumes_dirty {
+            return;
+        }
+        let mut failed = false;
+        for mounted in &mut self.mounted_volumes {
+            if let Err(err) = self
+                .volume_service
+                .sync(
+                    &self.owned_worker_id.account_id,
+                    &self.owned_worker_id.worker_id.component_id,
+                    &mounted.volume.name,
+                    &mounted.local_dir,
+                    &mut mounted.snapshot,
+                )
+                .await
+            {
+                warn!(
+                    "Failed to synchronize volume {} of worker {}: {err}",
+                    mounted.volume.name, self.owned_worker_id.worker_id
+                );
+                failed = true;
+            }
+        }
+        self.volumes_dirty = failed;
+    }
 }
 
 #[async_trait]
@@ -685,6 +1064,14 @@ impl<Ctx: WorkerCtx> InvocationManagement for DurableWorkerCtx<Ctx> {
         self.state.get_current_idempotency_key()
     }
 
+    async fn set_current_invocation_options(&mut self, options: InvocationOptions) {
+        self.state.current_invocation_options = options;
+    }
+
+    fn is_invocation_deadline_exceeded(&self) -> bool {
+        self.state.is_live() && self.state.current_invocation_options.is_deadline_exceeded()
+    }
+
     fn is_live(&self) -> bool {
         self.state.is_live()
     }
@@ -692,6 +1079,10 @@ impl<Ctx: WorkerCtx> InvocationManagement for DurableWorkerCtx<Ctx> {
     fn is_replay(&self) -> bool {
         self.state.is_replay()
     }
+
+    fn last_replayed_index(&self) -> OplogIndex {
+        self.state.replay_state.last_replayed_index()
+    }
 }
 
 #[async_trait]
@@ -796,16 +1187,64 @@ impl<Ctx: WorkerCtx> StatusManagement for DurableWorkerCtx<Ctx> {
             || status == WorkerStatus::Exited)
             && self.component_metadata().component_type == ComponentType::Durable
         {
+            let oplog_policy = self.component_metadata().oplog_policy.as_ref();
+            let archive_interval = oplog_policy
+                .and_then(|policy| policy.archive_interval())
+                .unwrap_or(self.state.config.oplog.archive_interval);
+            let last_oplog_index = self.public_state.oplog.current_oplog_index().await;
+
             debug!("Scheduling oplog archive");
-            let at = Utc::now().add(self.state.config.oplog.archive_interval);
+            let at = Utc::now().add(archive_interval);
             self.state
                 .scheduler_service
                 .schedule(
                     at,
                     ScheduledAction::ArchiveOplog {
                         owned_worker_id: self.owned_worker_id.clone(),
-                        last_oplog_index: self.public_state.oplog.current_oplog_index().await,
-                        next_after: self.state.config.oplog.archive_interval,
+                        last_oplog_index,
+                        next_after: archive_interval,
+                    },
+                )
+                .await;
+
+            if status == WorkerStatus::Exited {
+                if let Some(ttl) = oplog_policy.and_then(|policy| policy.exited_worker_ttl()) {
+                    debug!("Scheduling deletion of exited worker");
+                    self.state
+                        .scheduler_service
+                        .schedule(
+                            Utc::now().add(ttl),
+                            ScheduledAction::DeleteExitedWorker {
+                                owned_worker_id: self.owned_worker_id.clone(),
+                                last_oplog_index,
+                            },
+                        )
+                        .await;
+                }
+            }
+        } else if status == WorkerStatus::Idle
+            && self.component_metadata().component_type == ComponentType::Ephemeral
+            && self.state.config.oplog.compaction_enabled
+        {
+            // Ephemeral oplogs are written directly to the last layer, so the scheduled archiving
+            // only compacts them
+            let archive_interval = self
+                .component_metadata()
+                .oplog_policy
+                .as_ref()
+                .and_then(|policy| policy.archive_interval())
+                .unwrap_or(self.state.config.oplog.archive_interval);
+            let last_oplog_index = self.public_state.oplog.current_oplog_index().await;
+
+            debug!("Scheduling oplog compaction");
+            self.state
+                .scheduler_service
+                .schedule(
+                    Utc::now().add(archive_interval),
+                    ScheduledAction::ArchiveOplog {
+                        owned_worker_id: self.owned_worker_id.clone(),
+                        last_oplog_index,
+                        next_after: archive_interval,
                     },
                 )
                 .await;
@@ -842,6 +1281,7 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
                     self.get_current_idempotency_key().await.ok_or(anyhow!(
                         "No active invocation key is associated with the worker"
                     ))?,
+                    self.state.current_invocation_options.clone(),
                 )
                 .await
                 .unwrap_or_else(|err| {
@@ -851,17 +1291,26 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
                     )
                 });
             self.state.oplog.commit(CommitLevel::Always).await;
+            let invocation_start = self.state.oplog.current_oplog_index().await;
+            self.state
+                .set_current_invocation(invocation_start, full_function_name);
         }
         Ok(())
     }
 
     async fn on_invocation_failure(&mut self, trap_type: &TrapType) -> RetryDecision {
+        if let TrapType::Error(WorkerError::InvocationTimedOut) = trap_type {
+            return self.on_invocation_timed_out().await;
+        }
+
         let previous_tries = self.state.trailing_error_count().await;
         let default_retry_config = &self.state.config.retry;
         let retry_config = self
             .state
-            .overridden_retry_policy
+            .current_invocation_options
+            .retry_policy
             .as_ref()
+            .or(self.state.overridden_retry_policy.as_ref())
             .unwrap_or(default_retry_config)
             .clone();
         let decision =
@@ -902,6 +1351,24 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
             None
         };
 
+        let (updated_worker_status, decision) = match trap_type {
+            TrapType::Error(error)
+                if updated_worker_status == WorkerStatus::Failed && self.state.is_live() =>
+            {
+                if self.on_worker_failed(error).await {
+                    // The failed invocation got reverted, restarting the worker from its previous state
+                    (WorkerStatus::Running, RetryDecision::Immediate)
+                } else {
+                    (updated_worker_status, decision)
+                }
+            }
+            _ => (updated_worker_status, decision),
+        };
+
+        if self.state.is_live() {
+            self.sync_volumes().await;
+        }
+
         self.store_worker_status(updated_worker_status.clone())
             .await;
 
@@ -930,6 +1397,8 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
         output: TypeAnnotatedValue,
     ) -> Result<(), GolemError> {
         let is_live_after = self.state.is_live();
+        self.state.current_invocation = None;
+        self.state.current_invocation_options = InvocationOptions::default();
 
         if is_live_after {
             if self.state.snapshotting_mode.is_none() {
@@ -941,6 +1410,7 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
                         panic!("could not encode function result for {full_function_name}: {err}")
                     });
                 self.state.oplog.commit(CommitLevel::Always).await;
+                self.sync_volumes().await;
                 let oplog_idx = self.state.oplog.current_oplog_index().await;
 
                 if let Some(idempotency_key) = self.state.get_current_idempotency_key() {
@@ -1229,10 +1699,19 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
                         .replay_state
                         .get_oplog_entry_exported_function_invoked()
                         .await;
+                    if let Err(err) = store
+                        .as_context_mut()
+                        .data_mut()
+                        .durable_ctx_mut()
+                        .apply_replayed_file_system_changes()
+                        .await
+                    {
+                        break Err(err);
+                    }
                     match oplog_entry {
                         Err(error) => break Err(error),
                         Ok(None) => break Ok(RetryDecision::None),
-                        Ok(Some((function_name, function_input, idempotency_key))) => {
+                        Ok(Some((function_name, function_input, idempotency_key, options))) => {
                             debug!("Replaying function {function_name}");
                             let span = span!(Level::INFO, "replaying", function = function_name);
                             store
@@ -1240,6 +1719,24 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
                                 .data_mut()
                                 .set_current_idempotency_key(idempotency_key)
                                 .await;
+                            store
+                                .as_context_mut()
+                                .data_mut()
+                                .set_current_invocation_options(options)
+                                .await;
+                            let invocation_start = store
+                                .as_context_mut()
+                                .data_mut()
+                                .durable_ctx_mut()
+                                .state
+                                .replay_state
+                                .last_replayed_index();
+                            store
+                                .as_context_mut()
+                                .data_mut()
+                                .durable_ctx_mut()
+                                .state
+                                .set_current_invocation(invocation_start, &function_name);
 
                             let full_function_name = function_name.to_string();
                             let invoke_result = invoke_worker(
@@ -1383,12 +1880,34 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
                         }
                     }
                 } else {
+                    // Applying the changes read together with the last replayed invocation
+                    if let Err(err) = store
+                        .as_context_mut()
+                        .data_mut()
+                        .durable_ctx_mut()
+                        .apply_replayed_file_system_changes()
+                        .await
+                    {
+                        break Err(err);
+                    }
                     break Ok(RetryDecision::None);
                 }
             };
             record_resume_worker(start.elapsed());
             record_number_of_replayed_functions(count);
 
+            // Volumes are durable by themselves, so the writes performed while replaying are discarded
+            let result = match result {
+                Ok(decision) => store
+                    .as_context_mut()
+                    .data_mut()
+                    .durable_ctx_mut()
+                    .restore_volumes()
+                    .await
+                    .map(|_| decision),
+                Err(err) => Err(err),
+            };
+
             let final_decision = Self::finalize_pending_update(&result, instance, store).await;
 
             // The update finalization has the right to override the Err result with an explicit retry request
@@ -1608,6 +2127,153 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> FileSystemReading for DurableWo
     }
 }
 
+#[async_trait]
+impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> FileSystemWriting for DurableWorkerCtx<Ctx> {
+    async fn put_file(
+        &mut self,
+        path: &ComponentFilePath,
+        content: &[u8],
+    ) -> Result<PutFileResult, GolemError> {
+        let root = self._temp_dir.path();
+        let target = root.join(PathBuf::from(path.to_rel_string()));
+
+        if self.is_read_only_path(&target) {
+            return Ok(PutFileResult::ReadOnly);
+        }
+
+        let exists =
+            tokio::fs::try_exists(&target)
+                .await
+                .map_err(|e| GolemError::FileSystemError {
+                    path: path.to_string(),
+                    reason: format!("Failed to check whether file exists: {e}"),
+                })?;
+        if exists {
+            let metadata =
+                tokio::fs::metadata(&target)
+                    .await
+                    .map_err(|e| GolemError::FileSystemError {
+                        path: path.to_string(),
+                        reason: format!("Failed to get metadata: {e}"),
+                    })?;
+            if !metadata.is_file() {
+                return Ok(PutFileResult::NotAFile);
+            };
+        } else if let Some(parent) = target.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| GolemError::FileSystemError {
+                    path: path.to_string(),
+                    reason: format!("Failed to create parent directories: {e}"),
+                })?;
+        }
+
+        tokio::fs::write(&target, content)
+            .await
+            .map_err(|e| GolemError::FileSystemError {
+                path: path.to_string(),
+                reason: format!("Failed to write file: {e}"),
+            })?;
+        self.mark_volumes_dirty();
+
+        Ok(PutFileResult::Ok)
+    }
+
+    async fn delete_file(
+        &mut self,
+        path: &ComponentFilePath,
+    ) -> Result<DeleteFileResult, GolemError> {
+        let root = self._temp_dir.path();
+        let target = root.join(PathBuf::from(path.to_rel_string()));
+
+        if self.is_read_only_path(&target) {
+            return Ok(DeleteFileResult::ReadOnly);
+        }
+
+        let exists =
+            tokio::fs::try_exists(&target)
+                .await
+                .map_err(|e| GolemError::FileSystemError {
+                    path: path.to_string(),
+                    reason: format!("Failed to check whether file exists: {e}"),
+                })?;
+        if !exists {
+            return Ok(DeleteFileResult::NotFound);
+        };
+
+        let metadata =
+            tokio::fs::metadata(&target)
+                .await
+                .map_err(|e| GolemError::FileSystemError {
+                    path: path.to_string(),
+                    reason: format!("Failed to get metadata: {e}"),
+                })?;
+
+        if metadata.is_dir() {
+            let mut entries =
+                tokio::fs::read_dir(&target)
+                    .await
+                    .map_err(|e| GolemError::FileSystemError {
+                        path: path.to_string(),
+                        reason: format!("Failed to list directory: {e}"),
+                    })?;
+            if entries.next_entry().await?.is_some() {
+                return Ok(DeleteFileResult::DirectoryNotEmpty);
+            }
+
+            tokio::fs::remove_dir(&target)
+                .await
+                .map_err(|e| GolemError::FileSystemError {
+                    path: path.to_string(),
+                    reason: format!("Failed to delete directory: {e}"),
+                })?;
+        } else {
+            tokio::fs::remove_file(&target)
+                .await
+                .map_err(|e| GolemError::FileSystemError {
+                    path: path.to_string(),
+                    reason: format!("Failed to delete file: {e}"),
+                })?;
+        }
+        self.mark_volumes_dirty();
+
+        Ok(DeleteFileResult::Ok)
+    }
+
+    async fn make_directory(
+        &mut self,
+        path: &ComponentFilePath,
+    ) -> Result<MakeDirectoryResult, GolemError> {
+        let root = self._temp_dir.path();
+        let target = root.join(PathBuf::from(path.to_rel_string()));
+
+        if self.is_read_only_path(&target) {
+            return Ok(MakeDirectoryResult::ReadOnly);
+        }
+
+        let exists =
+            tokio::fs::try_exists(&target)
+                .await
+                .map_err(|e| GolemError::FileSystemError {
+                    path: path.to_string(),
+                    reason: format!("Failed to check whether file exists: {e}"),
+                })?;
+        if exists {
+            return Ok(MakeDirectoryResult::AlreadyExists);
+        };
+
+        tokio::fs::create_dir_all(&target)
+            .await
+            .map_err(|e| GolemError::FileSystemError {
+                path: path.to_string(),
+                reason: format!("Failed to create directory: {e}"),
+            })?;
+        self.mark_volumes_dirty();
+
+        Ok(MakeDirectoryResult::Ok)
+    }
+}
+
 async fn last_error_and_retry_count<T: HasOplogService + HasConfig>(
     this: &T,
     owned_worker_id: &OwnedWorkerId,
@@ -1711,7 +2377,8 @@ pub(crate) async fn recover_stderr_logs<T: HasOplogService + HasConfig>(
                     break;
                 }
             }
-            Some((_, OplogEntry::ExportedFunctionInvoked { .. })) => break,
+            Some((_, OplogEntry::ExportedFunctionInvokedV1 { .. }))
+            | Some((_, OplogEntry::ExportedFunctionInvoked { .. })) => break,
             _ => {}
         }
         if idx > OplogIndex::INITIAL {
@@ -1759,6 +2426,10 @@ pub struct PrivateDurableWorkerState<Owner: PluginOwner, Scope: PluginScope> {
     config: Arc<GolemConfig>,
     owned_worker_id: OwnedWorkerId,
     current_idempotency_key: Option<IdempotencyKey>,
+    /// Oplog index of the `ExportedFunctionInvoked` entry and the function name of the ongoing invocation
+    current_invocation: Option<(OplogIndex, String)>,
+    /// Retry policy and deadline of the ongoing invocation
+    current_invocation_options: InvocationOptions,
     rpc: Arc<dyn Rpc + Send + Sync>,
     worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
     resources: HashMap<WorkerResourceId, ResourceAny>,
@@ -1826,6 +2497,8 @@ impl<Owner: PluginOwner, Scope: PluginScope> PrivateDurableWorkerState<Owner, Sc
             config,
             owned_worker_id,
             current_idempotency_key: None,
+            current_invocation: None,
+            current_invocation_options: InvocationOptions::default(),
             rpc,
             worker_proxy,
             resources: HashMap::new(),
@@ -2034,6 +2707,10 @@ impl<Owner: PluginOwner, Scope: PluginScope> PrivateDurableWorkerState<Owner, Sc
         self.current_idempotency_key = Some(invocation_key);
     }
 
+    pub fn set_current_invocation(&mut self, start: OplogIndex, function_name: &str) {
+        self.current_invocation = Some((start, function_name.to_string()));
+    }
+
     /// Counts the number of Error entries that are at the end of the oplog. This equals to the number of retries that have been attempted.
     /// It also returns the last error stored in these entries.
     pub async fn trailing_error_count(&self) -> u64 {
@@ -2212,7 +2889,33 @@ impl<Ctx: WorkerCtx> WasiHttpView for DurableWorkerCtxWasiHttpView<'_, Ctx> {
             // or poll the response future.
             Ok(HostFutureIncomingResponse::deferred(request, config))
         } else {
-            Ok(default_send_request(request, config))
+            // Denied requests are reported through the response future, so the error code gets
+            // recorded in the oplog when the guest awaits the response
+            let component_metadata = self.0.component_metadata();
+            let rules = EgressRules::new(
+                component_metadata.capability_policy.as_ref(),
+                component_metadata.egress_policy.as_ref(),
+                self.0
+                    .state
+                    .config
+                    .egress
+                    .account_policy(&self.0.owned_worker_id.account_id),
+                &self.0.secrets.values,
+            );
+            let address_check = rules.address_check(&request, &config);
+            match (rules.apply(request, config), address_check) {
+                (Ok((request, config)), None) => Ok(default_send_request(request, config)),
+                (Ok((request, config)), Some(address_check)) => {
+                    let handle = wasmtime_wasi::runtime::spawn(async move {
+                        match address_check.run().await {
+                            Ok(()) => Ok(default_send_request_handler(request, config).await),
+                            Err(error_code) => Ok(Err(error_code)),
+                        }
+                    });
+                    Ok(HostFutureIncomingResponse::pending(handle))
+                }
+                (Err(error_code), _) => Ok(HostFutureIncomingResponse::ready(Ok(Err(error_code)))),
+            }
         }
     }
 }
@@ -2260,6 +2963,131 @@ async fn prepare_filesystem(
     Ok((file_use_tokens, read_only_files))
 }
 
+async fn mount_volumes(
+    volume_service: &VolumeService,
+    owned_worker_id: &OwnedWorkerId,
+    root: &Path,
+    volumes: &[ComponentVolume],
+) -> Result<Vec<MountedVolume>, GolemError> {
+    let mut mounted_volumes = Vec::with_capacity(volumes.len());
+    for volume in volumes {
+        let local_dir = root.join(PathBuf::from(volume.path.to_rel_string()));
+        debug!("Mounting volume {} to {}", volume.name, local_dir.display());
+        tokio::fs::create_dir_all(&local_dir)
+            .await
+            .map_err(|err| GolemError::FileSystemError {
+                path: volume.path.to_string(),
+                reason: format!(
+                    "Failed to create mount point of volume {}: {err}",
+                    volume.name
+                ),
+            })?;
+        let snapshot = volume_service
+            .restore(
+                &owned_worker_id.account_id,
+                &owned_worker_id.worker_id.component_id,
+                &volume.name,
+                &local_dir,
+            )
+            .await?;
+        mounted_volumes.push(MountedVolume {
+            volume: volume.clone(),
+            local_dir,
+            snapshot,
+        });
+    }
+    Ok(mounted_volumes)
+}
+
+/// Resolves the secrets referenced by the component, the worker's environment variables and the
+/// injected headers of the egress policies
+async fn resolve_secrets(
+    component_service: &Arc<dyn ComponentService + Send + Sync>,
+    owned_worker_id: &OwnedWorkerId,
+    component_metadata: &ComponentMetadata,
+    env: &[(String, String)],
+    account_egress_policy: Option<&EgressPolicy>,
+) -> Result<ResolvedSecrets, GolemError> {
+    let mut env_vars = BTreeMap::new();
+    for secret in &component_metadata.secrets {
+        env_vars.insert(secret.env_var.clone(), secret.name.clone());
+    }
+    for (key, value) in env {
+        match SecretReference::parse(value) {
+            Some(name) => {
+                env_vars.insert(key.clone(), name.to_string());
+            }
+            None => {
+                env_vars.remove(key);
+            }
+        }
+    }
+
+    let header_references = component_metadata
+        .egress_policy
+        .iter()
+        .chain(account_egress_policy)
+        .flat_map(|policy| policy.injected_headers.iter())
+        .filter_map(|header| SecretReference::parse(&header.value));
+
+    let names = env_vars
+        .values()
+        .map(|name| name.as_str())
+        .chain(header_references)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+
+    let values = component_service
+        .resolve_secrets(&owned_worker_id.worker_id.component_id, &names)
+        .await?;
+
+    Ok(ResolvedSecrets { values, env_vars })
+}
+
+impl ResolvedSecrets {
+    /// Builds the environment of the worker, exposing the component's secrets and replacing the
+    /// secret references in the worker's own variables. Variables defined by the worker take
+    /// precedence over the component's secrets.
+    fn apply_to_env(&self, env: &[(String, String)]) -> Vec<(String, String)> {
+        let component_vars = self
+            .env_vars
+            .keys()
+            .filter(|key| !env.iter().any(|(k, _)| k == *key))
+            .map(|key| (key.clone(), String::new()));
+        self.unredact_env(component_vars.chain(env.iter().cloned()).collect())
+    }
+
+    /// Replaces the values of the variables holding secrets with references to them, so they can
+    /// be persisted
+    fn redact_env(&self, env: Vec<(String, String)>) -> Vec<(String, String)> {
+        env.into_iter()
+            .map(|(key, value)| match self.env_vars.get(&key) {
+                Some(name) => (key, format!("${{secret:{name}}}")),
+                None => (key, value),
+            })
+            .collect()
+    }
+
+    /// Fills in the values of the variables redacted by `redact_env`
+    fn unredact_env(&self, env: Vec<(String, String)>) -> Vec<(String, String)> {
+        env.into_iter()
+            .map(|(key, value)| match self.value_of(&key) {
+                Some(secret) => (key, secret.to_string()),
+                None => (key, value),
+            })
+            .collect()
+    }
+
+    fn value_of(&self, env_var: &str) -> Option<&str> {
+        self.env_vars
+            .get(env_var)
+            .and_then(|name| self.values.get(name))
+            .map(|value| value.as_str())
+    }
+}
+
 /// Helper macro for expecting a given type of OplogEntry as the next entry in the oplog during
 /// replay, while skipping hint entries.
 /// The macro expression's type is `Result<OplogEntry, GolemError>` and it fails if the next non-hint
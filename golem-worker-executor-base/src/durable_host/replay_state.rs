@@ -16,7 +16,7 @@ use crate::error::GolemError;
 use crate::services::oplog::{Oplog, OplogOps, OplogService};
 use golem_common::model::oplog::{AtomicOplogIndex, LogLevel, OplogEntry, OplogIndex};
 use golem_common::model::regions::{DeletedRegions, OplogRegion};
-use golem_common::model::{IdempotencyKey, OwnedWorkerId};
+use golem_common::model::{IdempotencyKey, InvocationOptions, OwnedWorkerId};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::Value;
 use metrohash::MetroHash128;
@@ -45,6 +45,8 @@ struct InternalReplayState {
     pub next_deleted_region: Option<OplogRegion>,
     /// Hashes of log entries persisted since the last read non-hint oplog entry
     pub log_hashes: HashSet<(u64, u64)>,
+    /// Filesystem changes made from outside of the worker, read but not applied yet
+    pub file_system_changes: Vec<OplogEntry>,
 }
 
 impl ReplayState {
@@ -66,6 +68,7 @@ impl ReplayState {
                 deleted_regions,
                 next_deleted_region,
                 log_hashes: HashSet::new(),
+                file_system_changes: Vec::new(),
             })),
             has_seen_logs: Arc::new(AtomicBool::new(false)),
         };
@@ -187,9 +190,22 @@ impl ReplayState {
         let oplog_entry = oplog_entries.into_iter().next().unwrap();
         self.move_replay_idx(read_idx).await;
 
+        if oplog_entry.is_file_system_change() {
+            let mut internal = self.internal.write().await;
+            internal.file_system_changes.push(oplog_entry.clone());
+        }
+
         oplog_entry
     }
 
+    /// Takes the filesystem changing entries read since the last call, in the order they were
+    /// recorded. These have to be reapplied to the worker's filesystem before replaying the next
+    /// invocation.
+    pub async fn take_file_system_changes(&self) -> Vec<OplogEntry> {
+        let mut internal = self.internal.write().await;
+        std::mem::take(&mut internal.file_system_changes)
+    }
+
     async fn move_replay_idx(&mut self, new_idx: OplogIndex) {
         self.last_replayed_index.set(new_idx);
         self.get_out_of_deleted_region().await;
@@ -235,16 +251,25 @@ impl ReplayState {
 
     pub async fn get_oplog_entry_exported_function_invoked(
         &mut self,
-    ) -> Result<Option<(String, Vec<Value>, IdempotencyKey)>, GolemError> {
+    ) -> Result<Option<(String, Vec<Value>, IdempotencyKey, InvocationOptions)>, GolemError> {
         loop {
             if self.is_replay() {
                 let (_, oplog_entry) = self.get_oplog_entry().await;
                 match &oplog_entry {
-                    OplogEntry::ExportedFunctionInvoked {
+                    OplogEntry::ExportedFunctionInvokedV1 {
+                        function_name,
+                        idempotency_key,
+                        ..
+                    }
+                    | OplogEntry::ExportedFunctionInvoked {
                         function_name,
                         idempotency_key,
                         ..
                     } => {
+                        let options = match &oplog_entry {
+                            OplogEntry::ExportedFunctionInvoked { options, .. } => options.clone(),
+                            _ => InvocationOptions::default(),
+                        };
                         let request: Vec<golem_wasm_rpc::protobuf::Val> = self
                             .oplog
                             .get_payload_of_entry(&oplog_entry)
@@ -262,6 +287,7 @@ impl ReplayState {
                             function_name.to_string(),
                             request,
                             idempotency_key.clone(),
+                            options,
                         )));
                     }
                     entry if entry.is_hint() => {}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use async_trait::async_trait;
+use std::net::IpAddr;
 use wasmtime::component::Resource;
 
 use crate::durable_host::serialized::{SerializableError, SerializableIpAddresses};
@@ -20,6 +21,7 @@ use crate::durable_host::{Durability, DurableWorkerCtx};
 use crate::error::GolemError;
 use crate::metrics::wasm::record_host_function_call;
 use crate::workerctx::WorkerCtx;
+use golem_common::model::component_metadata::HostCapability;
 use golem_common::model::oplog::WrappedFunctionType;
 use wasmtime_wasi::bindings::sockets::ip_name_lookup::{
     Host, HostResolveAddressStream, IpAddress, Network, Pollable, ResolveAddressStream,
@@ -67,6 +69,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("sockets::ip_name_lookup", "resolve_addresses");
 
+        let host = name.clone();
         let addresses: Result<Vec<IpAddress>, SocketError> =
             Durability::<Ctx, String, SerializableIpAddresses, SerializableError>::wrap(
                 self,
@@ -74,12 +77,26 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
                 "sockets::ip_name_lookup::resolve_addresses",
                 name.clone(),
                 |ctx| {
-                    Box::pin(async move { resolve_and_drain_addresses(ctx, network, name).await })
+                    Box::pin(async move {
+                        // Checked when live, so the denial gets recorded like any other result
+                        if ctx.is_denied(HostCapability::Sockets) {
+                            return Err(ErrorCode::AccessDenied.into());
+                        }
+                        resolve_and_drain_addresses(ctx, network, name).await
+                    })
                 },
             )
             .await;
+        let addresses = addresses?;
+        self.record_resolved_addresses(
+            &host,
+            &addresses
+                .iter()
+                .map(|address| IpAddr::from(*address))
+                .collect::<Vec<_>>(),
+        );
 
-        let stream = ResolveAddressStream::Done(Ok(addresses?.into_iter()));
+        let stream = ResolveAddressStream::Done(Ok(addresses.into_iter()));
         Ok(self.table().push(stream)?)
     }
 }
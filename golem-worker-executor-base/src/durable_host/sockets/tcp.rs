@@ -13,11 +13,13 @@
 // limitations under the License.
 
 use async_trait::async_trait;
+use std::net::SocketAddr;
 use wasmtime::component::Resource;
 
 use crate::durable_host::DurableWorkerCtx;
 use crate::metrics::wasm::record_host_function_call;
 use crate::workerctx::WorkerCtx;
+use wasmtime_wasi::bindings::sockets::network::ErrorCode;
 use wasmtime_wasi::bindings::sockets::tcp::{
     Duration, Host, HostTcpSocket, InputStream, IpAddressFamily, IpSocketAddress, Network,
     OutputStream, Pollable, ShutdownType, TcpSocket,
@@ -48,6 +50,9 @@ impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
         remote_address: IpSocketAddress,
     ) -> Result<(), SocketError> {
         record_host_function_call("sockets::tcp", "start_connect");
+        if !self.allows_socket_address(SocketAddr::from(remote_address)) {
+            return Err(ErrorCode::AccessDenied.into());
+        }
         HostTcpSocket::start_connect(&mut self.as_wasi_view(), self_, network, remote_address)
     }
 
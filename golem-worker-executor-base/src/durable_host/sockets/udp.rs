@@ -13,11 +13,13 @@
 // limitations under the License.
 
 use async_trait::async_trait;
+use std::net::SocketAddr;
 use wasmtime::component::Resource;
 
 use crate::durable_host::DurableWorkerCtx;
 use crate::metrics::wasm::record_host_function_call;
 use crate::workerctx::WorkerCtx;
+use wasmtime_wasi::bindings::sockets::network::ErrorCode;
 use wasmtime_wasi::bindings::sockets::udp::{
     Host, HostIncomingDatagramStream, HostOutgoingDatagramStream, HostUdpSocket, IncomingDatagram,
     IncomingDatagramStream, IpAddressFamily, IpSocketAddress, Network, OutgoingDatagram,
@@ -53,6 +55,11 @@ impl<Ctx: WorkerCtx> HostUdpSocket for DurableWorkerCtx<Ctx> {
         SocketError,
     > {
         record_host_function_call("sockets::udp", "stream");
+        if let Some(remote_address) = remote_address {
+            if !self.allows_socket_address(SocketAddr::from(remote_address)) {
+                return Err(ErrorCode::AccessDenied.into());
+            }
+        }
         HostUdpSocket::stream(&mut self.as_wasi_view(), self_, remote_address)
     }
 
@@ -166,6 +173,13 @@ impl<Ctx: WorkerCtx> HostOutgoingDatagramStream for DurableWorkerCtx<Ctx> {
         datagrams: Vec<OutgoingDatagram>,
     ) -> Result<u64, SocketError> {
         record_host_function_call("sockets::udp", "send");
+        if datagrams.iter().any(|datagram| {
+            datagram.remote_address.is_some_and(|remote_address| {
+                !self.allows_socket_address(SocketAddr::from(remote_address))
+            })
+        }) {
+            return Err(ErrorCode::AccessDenied.into());
+        }
         HostOutgoingDatagramStream::send(&mut self.as_wasi_view(), self_, datagrams)
     }
 
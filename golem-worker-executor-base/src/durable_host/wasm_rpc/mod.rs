@@ -23,12 +23,15 @@ use crate::error::GolemError;
 use crate::get_oplog_entry;
 use crate::metrics::wasm::record_host_function_call;
 use crate::model::PersistenceLevel;
+use crate::preview2::golem::api1_2_0::rpc as rpc1_2_0;
 use crate::services::component::ComponentService;
 use crate::services::oplog::{CommitLevel, OplogOps};
 use crate::services::rpc::{RpcDemand, RpcError};
+use crate::services::{HasActiveWorkers, HasWorker};
 use crate::workerctx::{InvocationManagement, WorkerCtx};
 use anyhow::anyhow;
 use async_trait::async_trait;
+use golem_common::model::component_metadata::HostCapability;
 use golem_common::model::exports::function_by_name;
 use golem_common::model::oplog::{OplogEntry, WrappedFunctionType};
 use golem_common::model::{
@@ -45,6 +48,7 @@ use golem_wasm_rpc::{
 use std::any::Any;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{error, warn};
 use uuid::Uuid;
 use wasmtime::component::Resource;
@@ -57,6 +61,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
     async fn new(&mut self, location: Uri) -> anyhow::Result<Resource<WasmRpcEntry>> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("golem::rpc::wasm-rpc", "new");
+        self.fail_if_denied(HostCapability::Rpc)?;
 
         match location.parse_as_golem_urn() {
             Some((remote_worker_id, None)) => {
@@ -88,6 +93,19 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
         function_params: Vec<WitValue>,
     ) -> anyhow::Result<Result<WitValue, golem_wasm_rpc::RpcError>> {
         record_host_function_call("golem::rpc::wasm-rpc", "invoke-and-await");
+        let result = self
+            .invoke_and_await_with_timeout(self_, function_name, function_params, None)
+            .await?;
+        Ok(result.map_err(|err| err.into()))
+    }
+
+    async fn invoke(
+        &mut self,
+        self_: Resource<WasmRpcEntry>,
+        function_name: String,
+        function_params: Vec<WitValue>,
+    ) -> anyhow::Result<Result<(), golem_wasm_rpc::RpcError>> {
+        record_host_function_call("golem::rpc::wasm-rpc", "invoke");
         let args = self.get_arguments().await?;
         let env = self.get_environment().await?;
 
@@ -107,7 +125,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
         let uuid = Durability::<Ctx, (), (u64, u64), SerializableError>::custom_wrap(
             self,
             WrappedFunctionType::ReadLocal,
-            "golem::rpc::wasm-rpc::invoke-and-await idempotency key",
+            "golem::rpc::wasm-rpc::invoke-and-await idempotency key", // NOTE: must keep invoke-and-await in the name for compatibility with Golem 1.0
             (),
             |_ctx| {
                 Box::pin(async move {
@@ -124,16 +142,10 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
         .await?;
         let idempotency_key = IdempotencyKey::from_uuid(uuid);
 
-        // NOTE: Could be Durability::<Ctx, SerializableInvokeRequest, TypeAnnotatedValue, SerializableError>::wrap but need to support old WitValue values during recovery
-        let result: Result<WitValue, RpcError> = Durability::<
-            Ctx,
-            SerializableInvokeRequest,
-            TypeAnnotatedValue,
-            SerializableError,
-        >::full_custom_wrap(
+        let result = Durability::<Ctx, SerializableInvokeRequest, (), SerializableError>::wrap(
             self,
             WrappedFunctionType::WriteRemote,
-            "golem::rpc::wasm-rpc::invoke-and-await",
+            "golem::rpc::wasm-rpc::invoke",
             SerializableInvokeRequest {
                 remote_worker_id: remote_worker_id.worker_id(),
                 idempotency_key: idempotency_key.clone(),
@@ -150,7 +162,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
             |ctx| {
                 Box::pin(async move {
                     ctx.rpc()
-                        .invoke_and_await(
+                        .invoke(
                             &remote_worker_id,
                             Some(idempotency_key),
                             function_name,
@@ -162,63 +174,48 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                         .await
                 })
             },
-            |_, typed_value| Ok(typed_value.clone()),
-            |_, typed_value| {
-                typed_value
-                    .clone()
-                    .try_into()
-                    .map_err(|s: String| RpcError::ProtocolError { details: s })
-            },
-            |_, oplog, entry| {
-                Box::pin(async move {
-                    match entry {
-                        OplogEntry::ImportedFunctionInvokedV1 { .. } => {
-                            // Legacy oplog entry, used WitValue in its payload
-                            let wit_value = DurableWorkerCtx::<Ctx>::default_load::<
-                                WitValue,
-                                SerializableError,
-                            >(oplog, entry)
-                            .await;
-                            wit_value.map_err(|err| err.into())
-                        }
-                        OplogEntry::ImportedFunctionInvoked { .. } => {
-                            // New oplog entry, uses TypeAnnotatedValue in its payload
-                            let typed_value = DurableWorkerCtx::<Ctx>::try_default_load::<
-                                TypeAnnotatedValue,
-                                SerializableError,
-                            >(oplog.clone(), entry)
-                            .await;
-                            match typed_value {
-                                Ok(Ok(typed_value)) => typed_value
-                                    .try_into()
-                                    .map_err(|s: String| RpcError::ProtocolError { details: s }),
-                                Ok(Err(err)) => Err(err.into()),
-                                Err(err) => Err(err.into()),
-                            }
-                        }
-                        _ => unreachable!(),
-                    }
-                })
-            },
         )
         .await;
 
         match result {
-            Ok(wit_value) => Ok(Ok(wit_value)),
+            Ok(result) => Ok(Ok(result)),
             Err(err) => {
-                error!("RPC error: {err}");
+                error!("RPC error for: {err}");
                 Ok(Err(err.into()))
             }
         }
     }
 
-    async fn invoke(
+    async fn async_invoke_and_await(
+        &mut self,
+        this: Resource<WasmRpcEntry>,
+        function_name: String,
+        function_params: Vec<WitValue>,
+    ) -> anyhow::Result<Resource<FutureInvokeResult>> {
+        record_host_function_call("golem::rpc::wasm-rpc", "async-invoke-and-await");
+        self.async_invoke_and_await_with_timeout(this, function_name, function_params, None)
+            .await
+    }
+
+    fn drop(&mut self, rep: Resource<WasmRpcEntry>) -> anyhow::Result<()> {
+        record_host_function_call("golem::rpc::wasm-rpc", "drop");
+
+        let _ = self.table().delete(rep)?;
+        Ok(())
+    }
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Invokes a remote function and awaits its result, failing with `RpcError::Timeout` if it does not
+    /// arrive within `timeout`, or the configured default RPC timeout if not given
+    async fn invoke_and_await_with_timeout(
         &mut self,
         self_: Resource<WasmRpcEntry>,
         function_name: String,
         function_params: Vec<WitValue>,
-    ) -> anyhow::Result<Result<(), golem_wasm_rpc::RpcError>> {
-        record_host_function_call("golem::rpc::wasm-rpc", "invoke");
+        timeout: Option<Duration>,
+    ) -> anyhow::Result<Result<WitValue, RpcError>> {
+        let timeout = timeout.or(self.state.config.rpc.default_timeout);
         let args = self.get_arguments().await?;
         let env = self.get_environment().await?;
 
@@ -238,7 +235,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
         let uuid = Durability::<Ctx, (), (u64, u64), SerializableError>::custom_wrap(
             self,
             WrappedFunctionType::ReadLocal,
-            "golem::rpc::wasm-rpc::invoke-and-await idempotency key", // NOTE: must keep invoke-and-await in the name for compatibility with Golem 1.0
+            "golem::rpc::wasm-rpc::invoke-and-await idempotency key",
             (),
             |_ctx| {
                 Box::pin(async move {
@@ -255,10 +252,19 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
         .await?;
         let idempotency_key = IdempotencyKey::from_uuid(uuid);
 
-        let result = Durability::<Ctx, SerializableInvokeRequest, (), SerializableError>::wrap(
+        let active_workers = self.public_state.worker().active_workers();
+        let owned_worker_id = self.owned_worker_id.clone();
+
+        // NOTE: Could be Durability::<Ctx, SerializableInvokeRequest, TypeAnnotatedValue, SerializableError>::wrap but need to support old WitValue values during recovery
+        let result: Result<WitValue, RpcError> = Durability::<
+            Ctx,
+            SerializableInvokeRequest,
+            TypeAnnotatedValue,
+            SerializableError,
+        >::full_custom_wrap(
             self,
             WrappedFunctionType::WriteRemote,
-            "golem::rpc::wasm-rpc::invoke",
+            "golem::rpc::wasm-rpc::invoke-and-await",
             SerializableInvokeRequest {
                 remote_worker_id: remote_worker_id.worker_id(),
                 idempotency_key: idempotency_key.clone(),
@@ -274,8 +280,11 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
             },
             |ctx| {
                 Box::pin(async move {
+                    // The remote worker may be kept from starting by a concurrency limit this
+                    // worker counts against
+                    let _blocked = active_workers.blocked(&owned_worker_id);
                     ctx.rpc()
-                        .invoke(
+                        .invoke_and_await(
                             &remote_worker_id,
                             Some(idempotency_key),
                             function_name,
@@ -283,29 +292,72 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                             ctx.worker_id(),
                             &args,
                             &env,
+                            timeout,
                         )
                         .await
                 })
             },
+            |_, typed_value| Ok(typed_value.clone()),
+            |_, typed_value| {
+                typed_value
+                    .clone()
+                    .try_into()
+                    .map_err(|s: String| RpcError::ProtocolError { details: s })
+            },
+            |_, oplog, entry| {
+                Box::pin(async move {
+                    match entry {
+                        OplogEntry::ImportedFunctionInvokedV1 { .. } => {
+                            // Legacy oplog entry, used WitValue in its payload
+                            let wit_value = DurableWorkerCtx::<Ctx>::default_load::<
+                                WitValue,
+                                SerializableError,
+                            >(oplog, entry)
+                            .await;
+                            wit_value.map_err(|err| err.into())
+                        }
+                        OplogEntry::ImportedFunctionInvoked { .. } => {
+                            // New oplog entry, uses TypeAnnotatedValue in its payload
+                            let typed_value = DurableWorkerCtx::<Ctx>::try_default_load::<
+                                TypeAnnotatedValue,
+                                SerializableError,
+                            >(oplog.clone(), entry)
+                            .await;
+                            match typed_value {
+                                Ok(Ok(typed_value)) => typed_value
+                                    .try_into()
+                                    .map_err(|s: String| RpcError::ProtocolError { details: s }),
+                                Ok(Err(err)) => Err(err.into()),
+                                Err(err) => Err(err.into()),
+                            }
+                        }
+                        _ => unreachable!(),
+                    }
+                })
+            },
         )
         .await;
 
         match result {
-            Ok(result) => Ok(Ok(result)),
+            Ok(wit_value) => Ok(Ok(wit_value)),
             Err(err) => {
-                error!("RPC error for: {err}");
-                Ok(Err(err.into()))
+                error!("RPC error: {err}");
+                Ok(Err(err))
             }
         }
     }
 
-    async fn async_invoke_and_await(
+    /// Starts a remote invocation without awaiting it. The returned future completes with
+    /// `RpcError::Timeout` if the result does not arrive within `timeout`, or the configured default
+    /// RPC timeout if not given
+    async fn async_invoke_and_await_with_timeout(
         &mut self,
         this: Resource<WasmRpcEntry>,
         function_name: String,
         function_params: Vec<WitValue>,
+        timeout: Option<Duration>,
     ) -> anyhow::Result<Resource<FutureInvokeResult>> {
-        record_host_function_call("golem::rpc::wasm-rpc", "async-invoke-and-await");
+        let timeout = timeout.or(self.state.config.rpc.default_timeout);
         let args = self.get_arguments().await?;
         let env = self.get_environment().await?;
 
@@ -372,6 +424,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                         &worker_id,
                         &args,
                         &env,
+                        timeout,
                     )
                     .await)
             });
@@ -390,6 +443,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                     function_name,
                     function_params,
                     idempotency_key,
+                    timeout,
                 }),
             })?;
             Ok(fut)
@@ -412,88 +466,11 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
         result
     }
 
-    fn drop(&mut self, rep: Resource<WasmRpcEntry>) -> anyhow::Result<()> {
-        record_host_function_call("golem::rpc::wasm-rpc", "drop");
-
-        let _ = self.table().delete(rep)?;
-        Ok(())
-    }
-}
-
-impl From<RpcError> for golem_wasm_rpc::RpcError {
-    fn from(value: RpcError) -> Self {
-        match value {
-            RpcError::ProtocolError { details } => golem_wasm_rpc::RpcError::ProtocolError(details),
-            RpcError::Denied { details } => golem_wasm_rpc::RpcError::Denied(details),
-            RpcError::NotFound { details } => golem_wasm_rpc::RpcError::NotFound(details),
-            RpcError::RemoteInternalError { details } => {
-                golem_wasm_rpc::RpcError::RemoteInternalError(details)
-            }
-        }
-    }
-}
-
-#[allow(clippy::large_enum_variant)]
-enum FutureInvokeResultState {
-    Pending {
-        request: SerializableInvokeRequest,
-        handle: AbortOnDropJoinHandle<Result<Result<TypeAnnotatedValue, RpcError>, anyhow::Error>>,
-    },
-    Completed {
-        request: SerializableInvokeRequest,
-        result: Result<Result<TypeAnnotatedValue, RpcError>, anyhow::Error>,
-    },
-    Deferred {
-        remote_worker_id: OwnedWorkerId,
-        self_worker_id: WorkerId,
-        args: Vec<String>,
-        env: Vec<(String, String)>,
-        function_name: String,
-        function_params: Vec<WitValue>,
-        idempotency_key: IdempotencyKey,
-    },
-    Consumed {
-        request: SerializableInvokeRequest,
-    },
-}
-
-#[async_trait]
-impl SubscribeAny for FutureInvokeResultState {
-    async fn ready(&mut self) {
-        if let Self::Pending { handle, request } = self {
-            *self = Self::Completed {
-                result: handle.await,
-                request: request.clone(),
-            };
-        }
-    }
-
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn Any {
-        self
-    }
-}
-
-#[async_trait]
-impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
-    async fn subscribe(
+    async fn get_future_invoke_result(
         &mut self,
         this: Resource<FutureInvokeResult>,
-    ) -> anyhow::Result<Resource<Pollable>> {
+    ) -> anyhow::Result<Option<Result<WitValue, RpcError>>> {
         let _permit = self.begin_async_host_function().await?;
-        record_host_function_call("golem::rpc::future-invoke-result", "subscribe");
-        subscribe(self.table(), this, None)
-    }
-
-    async fn get(
-        &mut self,
-        this: Resource<FutureInvokeResult>,
-    ) -> anyhow::Result<Option<Result<WitValue, golem_wasm_rpc::RpcError>>> {
-        let _permit = self.begin_async_host_function().await?;
-        record_host_function_call("golem::rpc::future-invoke-result", "get");
         let rpc = self.rpc();
         let component_service = self.state.component_service.clone();
 
@@ -521,6 +498,23 @@ impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
                 FutureInvokeResultState::Pending { request, .. } => {
                     (Ok(None), request.clone(), SerializableInvokeResult::Pending)
                 }
+                FutureInvokeResultState::Cancelled { request } => {
+                    let request = request.clone();
+                    let error = RpcError::Cancelled {
+                        details: format!(
+                            "invocation {} of worker {} has been cancelled",
+                            request.idempotency_key, request.remote_worker_id
+                        ),
+                    };
+                    *entry = FutureInvokeResultState::Consumed {
+                        request: request.clone(),
+                    };
+                    (
+                        Ok(Some(Err(error.clone()))),
+                        request,
+                        SerializableInvokeResult::Completed(Err(error)),
+                    )
+                }
                 FutureInvokeResultState::Completed { request, .. } => {
                     let request = request.clone();
                     let result =
@@ -533,7 +527,7 @@ impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
                                 SerializableInvokeResult::Completed(Ok(result)),
                             ),
                             Ok(Err(rpc_error)) => (
-                                Ok(Some(Err(rpc_error.clone().into()))),
+                                Ok(Some(Err(rpc_error.clone()))),
                                 request,
                                 SerializableInvokeResult::Completed(Err(rpc_error)),
                             ),
@@ -562,6 +556,7 @@ impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
                             function_name,
                             function_params,
                             idempotency_key,
+                            timeout,
                         } = request
                         else {
                             return Err(anyhow!("unexpected incoming response state".to_string()));
@@ -575,6 +570,7 @@ impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
                                 &self_worker_id,
                                 &args,
                                 &env,
+                                timeout,
                             )
                             .await)
                     });
@@ -691,7 +687,7 @@ impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
                             let wit_value = tav.try_into().map_err(|s: String| anyhow!(s))?;
                             Ok(Some(Ok(wit_value)))
                         }
-                        Err(error) => Ok(Some(Err(error.into()))),
+                        Err(error) => Ok(Some(Err(error))),
                     },
                     SerializableInvokeResult::Failed(error) => Err(error.into()),
                 }
@@ -730,7 +726,7 @@ impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
                     SerializableInvokeResultV1::Pending => Ok(None),
                     SerializableInvokeResultV1::Completed(result) => match result {
                         Ok(wit_value) => Ok(Some(Ok(wit_value))),
-                        Err(error) => Ok(Some(Err(error.into()))),
+                        Err(error) => Ok(Some(Err(error))),
                     },
                     SerializableInvokeResultV1::Failed(error) => Err(error.into()),
                 }
@@ -738,6 +734,185 @@ impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
         }
     }
 
+    /// Cancels a pending asynchronous invocation on the remote worker. The outcome is recorded in the
+    /// oplog, so the same future gets cancelled again when the worker is recovered.
+    async fn cancel_future_invoke_result(
+        &mut self,
+        this: Resource<FutureInvokeResult>,
+    ) -> anyhow::Result<bool> {
+        let _permit = self.begin_async_host_function().await?;
+        let component_service = self.state.component_service.clone();
+        let account_id = self.owned_worker_id.account_id.clone();
+
+        let entry = self.table().get(&this)?;
+        let state = entry
+            .payload
+            .as_any()
+            .downcast_ref::<FutureInvokeResultState>()
+            .unwrap();
+
+        let (request, cancellable) = match state {
+            FutureInvokeResultState::Pending { request, .. } => (request.clone(), true),
+            FutureInvokeResultState::Deferred {
+                remote_worker_id,
+                function_name,
+                function_params,
+                idempotency_key,
+                ..
+            } => {
+                let remote_worker_id = remote_worker_id.clone();
+                let function_name = function_name.clone();
+                let function_params = function_params.clone();
+                let idempotency_key = idempotency_key.clone();
+                let request = SerializableInvokeRequest {
+                    remote_worker_id: remote_worker_id.worker_id(),
+                    idempotency_key,
+                    function_name: function_name.clone(),
+                    function_params: try_get_typed_parameters(
+                        component_service,
+                        &remote_worker_id.account_id,
+                        &remote_worker_id.worker_id.component_id,
+                        &function_name,
+                        &function_params,
+                    )
+                    .await,
+                };
+                (request, true)
+            }
+            FutureInvokeResultState::Completed { request, .. }
+            | FutureInvokeResultState::Consumed { request }
+            | FutureInvokeResultState::Cancelled { request } => (request.clone(), false),
+        };
+
+        let remote_worker_id = OwnedWorkerId::new(&account_id, &request.remote_worker_id);
+        let idempotency_key = request.idempotency_key.clone();
+        let result = Durability::<Ctx, SerializableInvokeRequest, bool, SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem::rpc::future-invoke-result::cancel",
+            request.clone(),
+            |ctx| {
+                Box::pin(async move {
+                    if cancellable {
+                        ctx.rpc()
+                            .cancel_invocation(&remote_worker_id, &idempotency_key)
+                            .await
+                    } else {
+                        Ok(false)
+                    }
+                })
+            },
+        )
+        .await;
+
+        match result {
+            Ok(true) => {
+                let entry = self.table().get_mut(&this)?;
+                let state = entry
+                    .payload
+                    .as_any_mut()
+                    .downcast_mut::<FutureInvokeResultState>()
+                    .unwrap();
+                *state = FutureInvokeResultState::Cancelled { request };
+                Ok(true)
+            }
+            Ok(false) => Ok(false),
+            Err(err) => {
+                warn!(
+                    "Failed to cancel invocation {} of worker {}: {err}",
+                    request.idempotency_key, request.remote_worker_id
+                );
+                Ok(false)
+            }
+        }
+    }
+}
+
+impl From<RpcError> for golem_wasm_rpc::RpcError {
+    fn from(value: RpcError) -> Self {
+        match value {
+            RpcError::ProtocolError { details } => golem_wasm_rpc::RpcError::ProtocolError(details),
+            RpcError::Denied { details } => golem_wasm_rpc::RpcError::Denied(details),
+            RpcError::NotFound { details } => golem_wasm_rpc::RpcError::NotFound(details),
+            RpcError::RemoteInternalError { details } => {
+                golem_wasm_rpc::RpcError::RemoteInternalError(details)
+            }
+            // golem:rpc/types@0.1.0 has no dedicated cases for these, golem:api/rpc@1.2.0 reports them separately
+            RpcError::Timeout { .. } | RpcError::Cancelled { .. } => {
+                golem_wasm_rpc::RpcError::RemoteInternalError(value.to_string())
+            }
+        }
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+enum FutureInvokeResultState {
+    Pending {
+        request: SerializableInvokeRequest,
+        handle: AbortOnDropJoinHandle<Result<Result<TypeAnnotatedValue, RpcError>, anyhow::Error>>,
+    },
+    Completed {
+        request: SerializableInvokeRequest,
+        result: Result<Result<TypeAnnotatedValue, RpcError>, anyhow::Error>,
+    },
+    Deferred {
+        remote_worker_id: OwnedWorkerId,
+        self_worker_id: WorkerId,
+        args: Vec<String>,
+        env: Vec<(String, String)>,
+        function_name: String,
+        function_params: Vec<WitValue>,
+        idempotency_key: IdempotencyKey,
+        timeout: Option<Duration>,
+    },
+    Consumed {
+        request: SerializableInvokeRequest,
+    },
+    Cancelled {
+        request: SerializableInvokeRequest,
+    },
+}
+
+#[async_trait]
+impl SubscribeAny for FutureInvokeResultState {
+    async fn ready(&mut self) {
+        if let Self::Pending { handle, request } = self {
+            *self = Self::Completed {
+                result: handle.await,
+                request: request.clone(),
+            };
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
+    async fn subscribe(
+        &mut self,
+        this: Resource<FutureInvokeResult>,
+    ) -> anyhow::Result<Resource<Pollable>> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::rpc::future-invoke-result", "subscribe");
+        subscribe(self.table(), this, None)
+    }
+
+    async fn get(
+        &mut self,
+        this: Resource<FutureInvokeResult>,
+    ) -> anyhow::Result<Option<Result<WitValue, golem_wasm_rpc::RpcError>>> {
+        record_host_function_call("golem::rpc::future-invoke-result", "get");
+        let result = self.get_future_invoke_result(this).await?;
+        Ok(result.map(|result| result.map_err(|err| err.into())))
+    }
+
     fn drop(&mut self, this: Resource<FutureInvokeResult>) -> anyhow::Result<()> {
         record_host_function_call("golem::rpc::future-invoke-result", "drop");
         let _ = self.table().delete(this)?;
@@ -748,6 +923,107 @@ impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
 #[async_trait]
 impl<Ctx: WorkerCtx> golem_wasm_rpc::Host for DurableWorkerCtx<Ctx> {}
 
+#[async_trait]
+impl<Ctx: WorkerCtx> rpc1_2_0::Host for DurableWorkerCtx<Ctx> {
+    async fn invoke_and_await(
+        &mut self,
+        rpc: Resource<WasmRpcEntry>,
+        function_name: String,
+        function_params: Vec<rpc1_2_0::WitValue>,
+        timeout: Option<rpc1_2_0::Duration>,
+    ) -> anyhow::Result<Result<rpc1_2_0::WitValue, rpc1_2_0::RpcError>> {
+        record_host_function_call("golem::api::rpc", "invoke-and-await");
+        let result = self
+            .invoke_and_await_with_timeout(
+                rpc,
+                function_name,
+                function_params.into_iter().map(|p| p.into()).collect(),
+                timeout.map(Duration::from_nanos),
+            )
+            .await?;
+        Ok(result.map(|value| value.into()).map_err(|err| err.into()))
+    }
+
+    async fn async_invoke_and_await(
+        &mut self,
+        rpc: Resource<WasmRpcEntry>,
+        function_name: String,
+        function_params: Vec<rpc1_2_0::WitValue>,
+        timeout: Option<rpc1_2_0::Duration>,
+    ) -> anyhow::Result<Resource<FutureInvokeResult>> {
+        record_host_function_call("golem::api::rpc", "async-invoke-and-await");
+        self.async_invoke_and_await_with_timeout(
+            rpc,
+            function_name,
+            function_params.into_iter().map(|p| p.into()).collect(),
+            timeout.map(Duration::from_nanos),
+        )
+        .await
+    }
+
+    async fn get_result(
+        &mut self,
+        future: Resource<FutureInvokeResult>,
+    ) -> anyhow::Result<Option<Result<rpc1_2_0::WitValue, rpc1_2_0::RpcError>>> {
+        record_host_function_call("golem::api::rpc", "get-result");
+        let result = self.get_future_invoke_result(future).await?;
+        Ok(result.map(|result| result.map(|value| value.into()).map_err(|err| err.into())))
+    }
+
+    async fn cancel(&mut self, future: Resource<FutureInvokeResult>) -> anyhow::Result<bool> {
+        record_host_function_call("golem::api::rpc", "cancel");
+        self.cancel_future_invoke_result(future).await
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> rpc1_2_0::Host for &mut DurableWorkerCtx<Ctx> {
+    async fn invoke_and_await(
+        &mut self,
+        rpc: Resource<WasmRpcEntry>,
+        function_name: String,
+        function_params: Vec<rpc1_2_0::WitValue>,
+        timeout: Option<rpc1_2_0::Duration>,
+    ) -> anyhow::Result<Result<rpc1_2_0::WitValue, rpc1_2_0::RpcError>> {
+        rpc1_2_0::Host::invoke_and_await(*self, rpc, function_name, function_params, timeout).await
+    }
+
+    async fn async_invoke_and_await(
+        &mut self,
+        rpc: Resource<WasmRpcEntry>,
+        function_name: String,
+        function_params: Vec<rpc1_2_0::WitValue>,
+        timeout: Option<rpc1_2_0::Duration>,
+    ) -> anyhow::Result<Resource<FutureInvokeResult>> {
+        rpc1_2_0::Host::async_invoke_and_await(*self, rpc, function_name, function_params, timeout)
+            .await
+    }
+
+    async fn get_result(
+        &mut self,
+        future: Resource<FutureInvokeResult>,
+    ) -> anyhow::Result<Option<Result<rpc1_2_0::WitValue, rpc1_2_0::RpcError>>> {
+        rpc1_2_0::Host::get_result(*self, future).await
+    }
+
+    async fn cancel(&mut self, future: Resource<FutureInvokeResult>) -> anyhow::Result<bool> {
+        rpc1_2_0::Host::cancel(*self, future).await
+    }
+}
+
+impl From<RpcError> for rpc1_2_0::RpcError {
+    fn from(value: RpcError) -> Self {
+        match value {
+            RpcError::ProtocolError { details } => Self::ProtocolError(details),
+            RpcError::Denied { details } => Self::Denied(details),
+            RpcError::NotFound { details } => Self::NotFound(details),
+            RpcError::RemoteInternalError { details } => Self::RemoteInternalError(details),
+            RpcError::Timeout { details } => Self::Timeout(details),
+            RpcError::Cancelled { details } => Self::Cancelled(details),
+        }
+    }
+}
+
 async fn generate_unique_local_worker_id<Ctx: WorkerCtx>(
     ctx: &mut DurableWorkerCtx<Ctx>,
     remote_worker_id: TargetWorkerId,
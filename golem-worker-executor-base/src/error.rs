@@ -105,6 +105,7 @@ pub enum GolemError {
         path: String,
         reason: String,
     },
+    InvocationTimedOut,
 }
 
 impl GolemError {
@@ -286,6 +287,9 @@ impl Display for GolemError {
                     "Failed to access file in worker filesystem {path}: {reason}"
                 )
             }
+            GolemError::InvocationTimedOut => {
+                write!(f, "Invocation timed out")
+            }
         }
     }
 }
@@ -322,6 +326,7 @@ impl Error for GolemError {
             GolemError::Unknown { .. } => "Unknown error",
             GolemError::ShardingNotReady => "Sharding not ready",
             GolemError::FileSystemError { .. } => "File system error",
+            GolemError::InvocationTimedOut => "Invocation timed out",
         }
     }
 }
@@ -358,6 +363,7 @@ impl TraceErrorKind for GolemError {
             GolemError::Unknown { .. } => "Unknown",
             GolemError::ShardingNotReady => "ShardingNotReady",
             GolemError::FileSystemError { .. } => "FileSystemError",
+            GolemError::InvocationTimedOut => "InvocationTimedOut",
         }
     }
 }
@@ -635,6 +641,13 @@ impl From<GolemError> for golem::worker::v1::WorkerExecutionError {
                     ),
                 ),
             },
+            GolemError::InvocationTimedOut => golem::worker::v1::WorkerExecutionError {
+                error: Some(
+                    golem::worker::v1::worker_execution_error::Error::InvocationTimedOut(
+                        golem::worker::v1::InvocationTimedOut {},
+                    ),
+                ),
+            },
         }
     }
 }
@@ -815,6 +828,9 @@ impl TryFrom<golem::worker::v1::WorkerExecutionError> for GolemError {
                 path: file_system_error.path,
                 reason: file_system_error.reason,
             }),
+            Some(golem::worker::v1::worker_execution_error::Error::InvocationTimedOut(_)) => {
+                Ok(GolemError::InvocationTimedOut)
+            }
         }
     }
 }
@@ -21,13 +21,17 @@ use golem_api_grpc::proto::golem::common::ResourceLimits as GrpcResourceLimits;
 use golem_api_grpc::proto::golem::worker::{Cursor, ResourceMetadata, UpdateMode};
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_server::WorkerExecutor;
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
-    ActivatePluginRequest, ActivatePluginResponse, ConnectWorkerRequest, DeactivatePluginRequest,
-    DeactivatePluginResponse, DeleteWorkerRequest, GetFileContentsRequest, GetFileContentsResponse,
-    GetOplogRequest, GetOplogResponse, GetRunningWorkersMetadataRequest,
+    ActivatePluginRequest, ActivatePluginResponse, CancelInvocationRequest,
+    CancelInvocationResponse, ConnectWorkerRequest, DeactivatePluginRequest,
+    DeactivatePluginResponse, DeleteFileRequest, DeleteFileResponse, DeleteWorkerRequest,
+    DryRunUpdateWorkerRequest, DryRunUpdateWorkerResponse, GetFileContentsRequest,
+    GetFileContentsResponse, GetOplogRequest, GetOplogResponse, GetRunningWorkersMetadataRequest,
     GetRunningWorkersMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse,
     InvokeAndAwaitWorkerRequest, InvokeAndAwaitWorkerResponseTyped, InvokeAndAwaitWorkerSuccess,
-    ListDirectoryRequest, ListDirectoryResponse, SearchOplogRequest, SearchOplogResponse,
-    UpdateWorkerRequest, UpdateWorkerResponse,
+    ListDirectoryRequest, ListDirectoryResponse, MakeDirectoryRequest, MakeDirectoryResponse,
+    PutFileRequest, PutFileResponse, SearchOplogRequest, SearchOplogResponse,
+    UpdateWorkerLabelsRequest, UpdateWorkerLabelsResponse, UpdateWorkerRequest,
+    UpdateWorkerResponse,
 };
 use golem_common::grpc::{
     proto_account_id_string, proto_component_id_string, proto_idempotency_key_string,
@@ -35,14 +39,15 @@ use golem_common::grpc::{
     proto_worker_id_string,
 };
 use golem_common::metrics::api::record_new_grpc_api_active_stream;
-use golem_common::model::oplog::{OplogIndex, UpdateDescription};
+use golem_common::model::oplog::{OplogEntry, OplogIndex, UpdateDescription};
 use golem_common::model::{
-    AccountId, ComponentFilePath, ComponentId, ComponentType, IdempotencyKey, OwnedWorkerId,
-    PluginInstallationId, ScanCursor, ShardId, TargetWorkerId, TimestampedWorkerInvocation,
-    WorkerEvent, WorkerFilter, WorkerId, WorkerInvocation, WorkerMetadata, WorkerStatus,
-    WorkerStatusRecord,
+    AccountId, ComponentFilePath, ComponentId, ComponentType, IdempotencyKey, InvocationOptions,
+    OwnedWorkerId, PluginInstallationId, ScanCursor, ShardId, TargetWorkerId,
+    TimestampedWorkerInvocation, WorkerEvent, WorkerFilter, WorkerId, WorkerInvocation,
+    WorkerMetadata, WorkerStatus, WorkerStatusRecord,
 };
 use golem_common::{model as common_model, recorded_grpc_api_request};
+use golem_service_base::model::UpdateDryRunResult;
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::protobuf::Val;
 use std::cmp::min;
@@ -63,8 +68,13 @@ use wasmtime::Error;
 use crate::model::public_oplog::{
     find_component_version_at, get_public_oplog_chunk, search_public_oplog,
 };
-use crate::model::{InterruptKind, LastError, ListDirectoryResult, ReadFileResult};
+use crate::model::{
+    DeleteFileResult, InterruptKind, InvocationPriority, LastError, ListDirectoryResult,
+    MakeDirectoryResult, PutFileResult, ReadFileResult,
+};
 use crate::services::events::Event;
+use crate::services::oplog::SandboxOplogService;
+use crate::services::worker::{DefaultWorkerService, WorkerService};
 use crate::services::worker_activator::{DefaultWorkerActivator, LazyWorkerActivator};
 use crate::services::worker_event::WorkerEventReceiver;
 use crate::services::{
@@ -72,6 +82,8 @@ use crate::services::{
     HasPromiseService, HasRunningWorkerEnumerationService, HasShardManagerService, HasShardService,
     HasWorkerEnumerationService, HasWorkerService, UsesAllDeps,
 };
+use crate::shutdown::ExecutorLifecycle;
+use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
 use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
 use tokio;
@@ -141,6 +153,7 @@ pub struct WorkerExecutorImpl<
 > {
     /// Reference to all the initialized services
     services: Svcs,
+    lifecycle: Arc<ExecutorLifecycle>,
     ctx: PhantomData<Ctx>,
 }
 
@@ -150,6 +163,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
     fn clone(&self) -> Self {
         Self {
             services: self.services.clone(),
+            lifecycle: self.lifecycle.clone(),
             ctx: PhantomData,
         }
     }
@@ -164,10 +178,12 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
     pub async fn new(
         services: Svcs,
         lazy_worker_activator: Arc<LazyWorkerActivator<Ctx>>,
+        lifecycle: Arc<ExecutorLifecycle>,
         port: u16,
     ) -> Result<Self, Error> {
         let worker_executor = WorkerExecutorImpl {
             services: services.clone(),
+            lifecycle,
             ctx: PhantomData,
         };
         let worker_activator = Arc::new(DefaultWorkerActivator::new(services));
@@ -191,6 +207,8 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
 
         Ctx::on_shard_assignment_changed(&worker_executor).await?;
 
+        worker_executor.lifecycle.set_serving();
+
         Ok(worker_executor)
     }
 
@@ -236,7 +254,12 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         &self,
         worker_id: &WorkerId,
     ) -> Result<(), GolemError> {
-        self.shard_service().check_worker(worker_id)
+        if self.lifecycle.is_draining() {
+            // The worker service retries on this error, and by then the shard is reassigned
+            Err(GolemError::ShardingNotReady)
+        } else {
+            self.shard_service().check_worker(worker_id)
+        }
     }
 
     async fn create_worker_internal(
@@ -284,6 +307,12 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         )
         .await?;
 
+        if !request.labels.is_empty() {
+            worker
+                .update_labels(request.labels.into_iter().collect())
+                .await?;
+        }
+
         let mut subscription = self.events().subscribe();
         Worker::start_if_needed(worker.clone()).await?;
         if worker.is_loading() {
@@ -590,7 +619,9 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
     ) -> Result<TypeAnnotatedValue, GolemError> {
         let full_function_name = request.name();
 
-        let worker = self.get_or_create(request).await?;
+        let worker = self
+            .get_or_create(request, InvocationPriority::Interactive)
+            .await?;
 
         let idempotency_key = request
             .idempotency_key()?
@@ -604,8 +635,10 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             .collect::<Result<Vec<_>, _>>()
             .map_err(|msg| GolemError::ValueMismatch { details: msg })?;
 
+        let options = request.options()?;
+
         let values = worker
-            .invoke_and_await(idempotency_key, full_function_name, function_input)
+            .invoke_and_await(idempotency_key, full_function_name, function_input, options)
             .await?;
 
         Ok(values)
@@ -614,9 +647,10 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
     async fn get_or_create<Req: CanStartWorker>(
         &self,
         request: &Req,
+        priority: InvocationPriority,
     ) -> Result<Arc<Worker<Ctx>>, GolemError> {
         let worker = self.get_or_create_pending(request).await?;
-        Worker::start_if_needed(worker.clone()).await?;
+        Worker::start_if_needed_with_priority(worker.clone(), priority).await?;
         Ok(worker)
     }
 
@@ -671,7 +705,9 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
     ) -> Result<(), GolemError> {
         let full_function_name = request.name();
 
-        let worker = self.get_or_create(request).await?;
+        let worker = self
+            .get_or_create(request, InvocationPriority::Background)
+            .await?;
 
         let idempotency_key = request
             .idempotency_key()?
@@ -684,8 +720,10 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             .collect::<Result<Vec<_>, _>>()
             .map_err(|msg| GolemError::ValueMismatch { details: msg })?;
 
+        let options = request.options()?;
+
         worker
-            .invoke(idempotency_key, full_function_name, function_input)
+            .invoke(idempotency_key, full_function_name, function_input, options)
             .await?;
 
         Ok(())
@@ -1003,6 +1041,159 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         Ok(())
     }
 
+    async fn dry_run_update_worker_internal(
+        &self,
+        request: DryRunUpdateWorkerRequest,
+    ) -> Result<UpdateDryRunResult, GolemError> {
+        let worker_id = request
+            .worker_id
+            .clone()
+            .ok_or(GolemError::invalid_request("worker_id not found"))?;
+        let worker_id: WorkerId = worker_id.try_into().map_err(GolemError::invalid_request)?;
+
+        self.ensure_worker_belongs_to_this_executor(&worker_id)?;
+
+        let account_id = request
+            .account_id
+            .clone()
+            .ok_or(GolemError::invalid_request("account_id not found"))?;
+        let account_id: AccountId = account_id.into();
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+        let metadata = self
+            .worker_service()
+            .get(&owned_worker_id)
+            .await
+            .ok_or(GolemError::worker_not_found(worker_id.clone()))?;
+
+        if metadata.last_known_status.component_version == request.target_version {
+            return Err(GolemError::invalid_request(
+                "Worker is already at the target version",
+            ));
+        }
+
+        let component_metadata = self
+            .component_service()
+            .get_metadata(
+                &account_id,
+                &worker_id.component_id,
+                Some(metadata.last_known_status.component_version),
+            )
+            .await?;
+        if component_metadata.component_type == ComponentType::Ephemeral {
+            return Err(GolemError::invalid_request(
+                "Ephemeral workers cannot be updated",
+            ));
+        }
+        self.component_service()
+            .get_metadata(
+                &account_id,
+                &worker_id.component_id,
+                Some(request.target_version),
+            )
+            .await?;
+
+        // The worker is replayed with its own dependencies, reading the real oplog up to the
+        // last completed invocation, but keeping every new oplog entry and status in memory
+        let replayed_until =
+            SandboxOplogService::replay_boundary(&self.oplog_service(), &owned_worker_id).await;
+        let oplog_service = Arc::new(SandboxOplogService::new(
+            self.oplog_service(),
+            owned_worker_id.clone(),
+            replayed_until,
+            vec![OplogEntry::pending_update(UpdateDescription::Automatic {
+                target_version: request.target_version,
+            })],
+        ));
+        let worker_service = Arc::new(DefaultWorkerService::new(
+            Arc::new(InMemoryKeyValueStorage::new()),
+            self.shard_service(),
+            oplog_service.clone(),
+        ));
+        // The sandbox starts from the worker's stored status, unless it already covers the last,
+        // unfinished invocation which is not replayed. In that case the status is recalculated
+        // from the replayed part of the oplog.
+        if metadata.last_known_status.oplog_idx <= replayed_until {
+            worker_service
+                .update_status(
+                    &owned_worker_id,
+                    &metadata.last_known_status,
+                    component_metadata.component_type,
+                )
+                .await;
+        }
+        let sandbox = self.all().sandboxed(worker_service, oplog_service.clone());
+
+        let worker = Arc::new(
+            Worker::new(
+                &sandbox,
+                owned_worker_id.clone(),
+                Some(metadata.args.clone()),
+                Some(metadata.env.clone()),
+                Some(metadata.last_known_status.component_version),
+                metadata.parent.clone(),
+            )
+            .await?,
+        );
+        let _permit = self
+            .active_workers()
+            .acquire(worker.memory_requirement().await?)
+            .await;
+
+        debug!("Replaying worker in a sandbox");
+        let (result, replay_stopped_at) = Worker::replay_in_sandbox(worker).await?;
+
+        let mut succeeded = false;
+        let mut update_failure = None;
+        let mut last_error = None;
+        for entry in oplog_service.added_entries() {
+            match entry {
+                OplogEntry::SuccessfulUpdate { target_version, .. }
+                    if target_version == request.target_version =>
+                {
+                    succeeded = true;
+                }
+                OplogEntry::FailedUpdate {
+                    target_version,
+                    details,
+                    ..
+                } if target_version == request.target_version => {
+                    update_failure = details;
+                }
+                OplogEntry::Error { error, .. } => {
+                    last_error = Some(error.to_string(""));
+                }
+                _ => {}
+            }
+        }
+
+        let error = if succeeded {
+            None
+        } else {
+            Some(
+                update_failure
+                    .or(last_error)
+                    .or(result.err().map(|err| err.to_string()))
+                    .unwrap_or_else(|| "The update was not attempted".to_string()),
+            )
+        };
+        // The new version diverged if the update failed before replaying the whole recorded
+        // history. Failures after that, such as a failing snapshot load, are not divergences.
+        let diverged_at = if error.is_some() {
+            replay_stopped_at.filter(|index| *index != OplogIndex::NONE)
+        } else {
+            None
+        };
+
+        Ok(UpdateDryRunResult {
+            target_version: request.target_version,
+            succeeded,
+            replayed_until,
+            diverged_at,
+            error,
+        })
+    }
+
     async fn connect_worker_internal(
         &self,
         request: ConnectWorkerRequest,
@@ -1228,7 +1419,9 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         let path = ComponentFilePath::from_abs_str(&request.path)
             .map_err(|e| GolemError::invalid_request(format!("Invalid path: {}", e)))?;
 
-        let worker = self.get_or_create(&request).await?;
+        let worker = self
+            .get_or_create(&request, InvocationPriority::Interactive)
+            .await?;
 
         let result = worker.list_directory(path).await?;
 
@@ -1261,6 +1454,117 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         Ok(response)
     }
 
+    async fn put_file_internal(
+        &self,
+        request: PutFileRequest,
+    ) -> Result<PutFileResponse, GolemError> {
+        let path = ComponentFilePath::from_abs_str(&request.file_path)
+            .map_err(|e| GolemError::invalid_request(format!("Invalid path: {}", e)))?;
+
+        let worker = self
+            .get_or_create(&request, InvocationPriority::Interactive)
+            .await?;
+
+        let result = worker.put_file(path, request.content).await?;
+
+        let result = match result {
+            PutFileResult::Ok => golem::workerexecutor::v1::put_file_response::Result::Success(
+                golem::common::Empty {},
+            ),
+            PutFileResult::NotAFile => {
+                golem::workerexecutor::v1::put_file_response::Result::NotAFile(
+                    golem::common::Empty {},
+                )
+            }
+            PutFileResult::ReadOnly => {
+                golem::workerexecutor::v1::put_file_response::Result::ReadOnly(
+                    golem::common::Empty {},
+                )
+            }
+        };
+
+        Ok(PutFileResponse {
+            result: Some(result),
+        })
+    }
+
+    async fn delete_file_internal(
+        &self,
+        request: DeleteFileRequest,
+    ) -> Result<DeleteFileResponse, GolemError> {
+        let path = ComponentFilePath::from_abs_str(&request.file_path)
+            .map_err(|e| GolemError::invalid_request(format!("Invalid path: {}", e)))?;
+
+        let worker = self
+            .get_or_create(&request, InvocationPriority::Interactive)
+            .await?;
+
+        let result = worker.delete_file(path).await?;
+
+        let result = match result {
+            DeleteFileResult::Ok => {
+                golem::workerexecutor::v1::delete_file_response::Result::Success(
+                    golem::common::Empty {},
+                )
+            }
+            DeleteFileResult::NotFound => {
+                golem::workerexecutor::v1::delete_file_response::Result::NotFound(
+                    golem::common::Empty {},
+                )
+            }
+            DeleteFileResult::ReadOnly => {
+                golem::workerexecutor::v1::delete_file_response::Result::ReadOnly(
+                    golem::common::Empty {},
+                )
+            }
+            DeleteFileResult::DirectoryNotEmpty => {
+                golem::workerexecutor::v1::delete_file_response::Result::DirectoryNotEmpty(
+                    golem::common::Empty {},
+                )
+            }
+        };
+
+        Ok(DeleteFileResponse {
+            result: Some(result),
+        })
+    }
+
+    async fn make_directory_internal(
+        &self,
+        request: MakeDirectoryRequest,
+    ) -> Result<MakeDirectoryResponse, GolemError> {
+        let path = ComponentFilePath::from_abs_str(&request.path)
+            .map_err(|e| GolemError::invalid_request(format!("Invalid path: {}", e)))?;
+
+        let worker = self
+            .get_or_create(&request, InvocationPriority::Interactive)
+            .await?;
+
+        let result = worker.make_directory(path).await?;
+
+        let result = match result {
+            MakeDirectoryResult::Ok => {
+                golem::workerexecutor::v1::make_directory_response::Result::Success(
+                    golem::common::Empty {},
+                )
+            }
+            MakeDirectoryResult::AlreadyExists => {
+                golem::workerexecutor::v1::make_directory_response::Result::AlreadyExists(
+                    golem::common::Empty {},
+                )
+            }
+            MakeDirectoryResult::ReadOnly => {
+                golem::workerexecutor::v1::make_directory_response::Result::ReadOnly(
+                    golem::common::Empty {},
+                )
+            }
+        };
+
+        Ok(MakeDirectoryResponse {
+            result: Some(result),
+        })
+    }
+
     async fn get_file_contents_internal(
         &self,
         request: GetFileContentsRequest,
@@ -1268,7 +1572,9 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         let path = ComponentFilePath::from_abs_str(&request.file_path)
             .map_err(|e| GolemError::invalid_request(format!("Invalid path: {}", e)))?;
 
-        let worker = self.get_or_create(&request).await?;
+        let worker = self
+            .get_or_create(&request, InvocationPriority::Interactive)
+            .await?;
 
         let result = worker.read_file(path).await?;
 
@@ -1486,6 +1792,72 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
+    async fn update_worker_labels_internal(
+        &self,
+        request: UpdateWorkerLabelsRequest,
+    ) -> Result<(), GolemError> {
+        let worker_id = request
+            .worker_id
+            .ok_or(GolemError::invalid_request("worker_id not found"))?;
+        let worker_id: WorkerId = worker_id.try_into().map_err(GolemError::invalid_request)?;
+
+        let account_id = request
+            .account_id
+            .ok_or(GolemError::invalid_request("account_id not found"))?;
+        let account_id: AccountId = account_id.into();
+
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+        self.ensure_worker_belongs_to_this_executor(&worker_id)?;
+
+        match self.worker_service().get(&owned_worker_id).await {
+            Some(_) => {
+                let worker =
+                    Worker::get_or_create_suspended(self, &owned_worker_id, None, None, None, None)
+                        .await?;
+                worker
+                    .update_labels(request.labels.into_iter().collect())
+                    .await?;
+                Ok(())
+            }
+            None => Err(GolemError::worker_not_found(worker_id)),
+        }
+    }
+
+    async fn cancel_invocation_internal(
+        &self,
+        request: CancelInvocationRequest,
+    ) -> Result<bool, GolemError> {
+        let worker_id = request
+            .worker_id
+            .ok_or(GolemError::invalid_request("worker_id not found"))?;
+        let worker_id: WorkerId = worker_id.try_into().map_err(GolemError::invalid_request)?;
+
+        let account_id = request
+            .account_id
+            .ok_or(GolemError::invalid_request("account_id not found"))?;
+        let account_id: AccountId = account_id.into();
+
+        let idempotency_key: IdempotencyKey = request
+            .idempotency_key
+            .ok_or(GolemError::invalid_request("idempotency_key not found"))?
+            .into();
+
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+        self.ensure_worker_belongs_to_this_executor(&worker_id)?;
+
+        match self.worker_service().get(&owned_worker_id).await {
+            Some(_) => {
+                let worker =
+                    Worker::get_or_create_suspended(self, &owned_worker_id, None, None, None, None)
+                        .await?;
+                worker.cancel_invocation(idempotency_key).await
+            }
+            None => Err(GolemError::worker_not_found(worker_id)),
+        }
+    }
+
     fn create_proto_metadata(
         metadata: WorkerMetadata,
         latest_status: WorkerStatusRecord,
@@ -1576,6 +1948,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             total_linear_memory_size: metadata.last_known_status.total_linear_memory_size,
             owned_resources,
             active_plugins: active_plugins.into_iter().map(|id| id.into()).collect(),
+            labels: HashMap::from_iter(latest_status.labels().clone()),
         }
     }
 }
@@ -2170,6 +2543,42 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
+    async fn dry_run_update_worker(
+        &self,
+        request: Request<DryRunUpdateWorkerRequest>,
+    ) -> Result<Response<DryRunUpdateWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "dry_run_update_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            target_version = request.target_version,
+        );
+
+        match self
+            .dry_run_update_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(result) => record.succeed(Ok(Response::new(DryRunUpdateWorkerResponse {
+                result: Some(
+                    golem::workerexecutor::v1::dry_run_update_worker_response::Result::Success(
+                        result.into(),
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(DryRunUpdateWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::dry_run_update_worker_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
     async fn get_oplog(
         &self,
         request: Request<GetOplogRequest>,
@@ -2297,6 +2706,93 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         Ok(Response::new(stream))
     }
 
+    async fn put_file(&self, request: Request<PutFileRequest>) -> ResponseResult<PutFileResponse> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "put_file",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            path = request.file_path,
+        );
+
+        let result = self
+            .put_file_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok(response) => record.succeed(Ok(Response::new(response))),
+            Err(err) => record.fail(
+                Ok(Response::new(PutFileResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::put_file_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn delete_file(
+        &self,
+        request: Request<DeleteFileRequest>,
+    ) -> ResponseResult<DeleteFileResponse> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "delete_file",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            path = request.file_path,
+        );
+
+        let result = self
+            .delete_file_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok(response) => record.succeed(Ok(Response::new(response))),
+            Err(err) => record.fail(
+                Ok(Response::new(DeleteFileResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::delete_file_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn make_directory(
+        &self,
+        request: Request<MakeDirectoryRequest>,
+    ) -> ResponseResult<MakeDirectoryResponse> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "make_directory",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            path = request.path,
+        );
+
+        let result = self
+            .make_directory_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok(response) => record.succeed(Ok(Response::new(response))),
+            Err(err) => record.fail(
+                Ok(Response::new(MakeDirectoryResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::make_directory_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
     async fn activate_plugin(
         &self,
         request: Request<ActivatePluginRequest>,
@@ -2370,6 +2866,79 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             ),
         }
     }
+
+    async fn update_worker_labels(
+        &self,
+        request: Request<UpdateWorkerLabelsRequest>,
+    ) -> Result<Response<UpdateWorkerLabelsResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "update_worker_labels",
+            worker_id = proto_worker_id_string(&request.worker_id)
+        );
+
+        let result = self
+            .update_worker_labels_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(_) => record.succeed(Ok(Response::new(UpdateWorkerLabelsResponse {
+                result: Some(
+                    golem::workerexecutor::v1::update_worker_labels_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(UpdateWorkerLabelsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::update_worker_labels_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn cancel_invocation(
+        &self,
+        request: Request<CancelInvocationRequest>,
+    ) -> Result<Response<CancelInvocationResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "cancel_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+        );
+
+        let result = self
+            .cancel_invocation_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(cancelled) => record.succeed(Ok(Response::new(CancelInvocationResponse {
+                result: Some(
+                    golem::workerexecutor::v1::cancel_invocation_response::Result::Success(
+                        cancelled,
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(CancelInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::cancel_invocation_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
 }
 
 trait CanStartWorker {
@@ -2385,6 +2954,7 @@ trait GrpcInvokeRequest: CanStartWorker {
     fn input(&self) -> Vec<Val>;
     fn idempotency_key(&self) -> Result<Option<IdempotencyKey>, GolemError>;
     fn name(&self) -> String;
+    fn options(&self) -> Result<InvocationOptions, GolemError>;
 }
 
 impl CanStartWorker for golem::workerexecutor::v1::ListDirectoryRequest {
@@ -2455,6 +3025,108 @@ impl CanStartWorker for golem::workerexecutor::v1::GetFileContentsRequest {
     }
 }
 
+impl CanStartWorker for golem::workerexecutor::v1::PutFileRequest {
+    fn account_id(&self) -> Result<AccountId, GolemError> {
+        Ok(self
+            .account_id
+            .clone()
+            .ok_or(GolemError::invalid_request("account_id not found"))?
+            .into())
+    }
+
+    fn account_limits(&self) -> Option<GrpcResourceLimits> {
+        self.account_limits.clone()
+    }
+
+    fn worker_id(&self) -> Result<common_model::TargetWorkerId, GolemError> {
+        self.worker_id
+            .clone()
+            .ok_or(GolemError::invalid_request("worker_id not found"))?
+            .try_into()
+            .map_err(GolemError::invalid_request)
+    }
+
+    fn args(&self) -> Option<Vec<String>> {
+        None
+    }
+
+    fn env(&self) -> Option<Vec<(String, String)>> {
+        None
+    }
+
+    fn parent(&self) -> Option<WorkerId> {
+        None
+    }
+}
+
+impl CanStartWorker for golem::workerexecutor::v1::DeleteFileRequest {
+    fn account_id(&self) -> Result<AccountId, GolemError> {
+        Ok(self
+            .account_id
+            .clone()
+            .ok_or(GolemError::invalid_request("account_id not found"))?
+            .into())
+    }
+
+    fn account_limits(&self) -> Option<GrpcResourceLimits> {
+        self.account_limits.clone()
+    }
+
+    fn worker_id(&self) -> Result<common_model::TargetWorkerId, GolemError> {
+        self.worker_id
+            .clone()
+            .ok_or(GolemError::invalid_request("worker_id not found"))?
+            .try_into()
+            .map_err(GolemError::invalid_request)
+    }
+
+    fn args(&self) -> Option<Vec<String>> {
+        None
+    }
+
+    fn env(&self) -> Option<Vec<(String, String)>> {
+        None
+    }
+
+    fn parent(&self) -> Option<WorkerId> {
+        None
+    }
+}
+
+impl CanStartWorker for golem::workerexecutor::v1::MakeDirectoryRequest {
+    fn account_id(&self) -> Result<AccountId, GolemError> {
+        Ok(self
+            .account_id
+            .clone()
+            .ok_or(GolemError::invalid_request("account_id not found"))?
+            .into())
+    }
+
+    fn account_limits(&self) -> Option<GrpcResourceLimits> {
+        self.account_limits.clone()
+    }
+
+    fn worker_id(&self) -> Result<common_model::TargetWorkerId, GolemError> {
+        self.worker_id
+            .clone()
+            .ok_or(GolemError::invalid_request("worker_id not found"))?
+            .try_into()
+            .map_err(GolemError::invalid_request)
+    }
+
+    fn args(&self) -> Option<Vec<String>> {
+        None
+    }
+
+    fn env(&self) -> Option<Vec<(String, String)>> {
+        None
+    }
+
+    fn parent(&self) -> Option<WorkerId> {
+        None
+    }
+}
+
 impl CanStartWorker for golem::workerexecutor::v1::InvokeWorkerRequest {
     fn account_id(&self) -> Result<AccountId, GolemError> {
         Ok(self
@@ -2507,6 +3179,15 @@ impl GrpcInvokeRequest for golem::workerexecutor::v1::InvokeWorkerRequest {
     fn name(&self) -> String {
         self.name.clone()
     }
+
+    fn options(&self) -> Result<InvocationOptions, GolemError> {
+        self.options
+            .clone()
+            .map(InvocationOptions::try_from)
+            .transpose()
+            .map_err(GolemError::invalid_request)
+            .map(Option::unwrap_or_default)
+    }
 }
 
 impl CanStartWorker for golem::workerexecutor::v1::InvokeAndAwaitWorkerRequest {
@@ -2561,6 +3242,15 @@ impl GrpcInvokeRequest for golem::workerexecutor::v1::InvokeAndAwaitWorkerReques
     fn name(&self) -> String {
         self.name.clone()
     }
+
+    fn options(&self) -> Result<InvocationOptions, GolemError> {
+        self.options
+            .clone()
+            .map(InvocationOptions::try_from)
+            .transpose()
+            .map_err(GolemError::invalid_request)
+            .map(Option::unwrap_or_default)
+    }
 }
 
 pub trait UriBackConversion {
@@ -2617,6 +3307,9 @@ impl Stream for WorkerEventStream {
                 WorkerEvent::InvocationFinished { .. } => {
                     Poll::Ready(Some(Ok(event.try_into().unwrap())))
                 }
+                WorkerEvent::WorkerFailed { .. } => {
+                    Poll::Ready(Some(Ok(event.try_into().unwrap())))
+                }
             },
             Poll::Ready(Some(Err(BroadcastStreamRecvError::Lagged(n)))) => Poll::Ready(Some(Err(
                 Status::data_loss(format!("Lagged by {} events", n)),
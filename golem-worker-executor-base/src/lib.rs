@@ -21,6 +21,7 @@ pub mod metrics;
 pub mod model;
 pub mod preview2;
 pub mod services;
+pub mod shutdown;
 pub mod storage;
 pub mod wasi_host;
 pub mod worker;
@@ -32,13 +33,16 @@ test_r::enable!();
 use crate::grpc::WorkerExecutorImpl;
 use crate::services::active_workers::ActiveWorkers;
 use crate::services::blob_store::{BlobStoreService, DefaultBlobStoreService};
-use crate::services::component::ComponentService;
+use crate::services::component::{ComponentService, InstancePreCache};
 use crate::services::events::Events;
-use crate::services::golem_config::{GolemConfig, IndexedStorageConfig, KeyValueStorageConfig};
+use crate::services::golem_config::{
+    GolemConfig, IndexedStorageConfig, InstanceAllocatorConfig, KeyValueStorageConfig,
+};
 use crate::services::key_value::{DefaultKeyValueService, KeyValueService};
 use crate::services::oplog::plugin::{
     ForwardingOplogService, OplogProcessorPlugin, PerExecutorOplogProcessorPlugin,
 };
+use crate::services::oplog::sink::OplogSinks;
 use crate::services::oplog::{
     BlobOplogArchiveService, CompressedOplogArchiveService, MultiLayerOplogService,
     OplogArchiveService, OplogService, PrimaryOplogService,
@@ -56,15 +60,20 @@ use crate::services::worker_enumeration::{
 };
 use crate::services::worker_proxy::{RemoteWorkerProxy, WorkerProxy};
 use crate::services::{component, shard_manager, All};
+use crate::shutdown::{graceful_shutdown, lifecycle_routes, ExecutorLifecycle, ShutdownHandle};
+use crate::storage::indexed::postgres::PostgresIndexedStorage;
 use crate::storage::indexed::redis::RedisIndexedStorage;
 use crate::storage::indexed::sqlite::SqliteIndexedStorage;
 use crate::storage::indexed::IndexedStorage;
 use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
+use crate::storage::keyvalue::postgres::PostgresKeyValueStorage;
 use crate::storage::keyvalue::redis::RedisKeyValueStorage;
 use crate::storage::keyvalue::KeyValueStorage;
+use crate::wasi_host::capabilities::{link_denied_capabilities, linked_capability_sets};
 use crate::workerctx::WorkerCtx;
 use anyhow::anyhow;
 use async_trait::async_trait;
+use gethostname::gethostname;
 use golem_api_grpc::proto;
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_server::WorkerExecutorServer;
 use golem_common::golem_version;
@@ -75,11 +84,14 @@ use golem_service_base::service::initial_component_files::InitialComponentFilesS
 use golem_service_base::storage::blob::s3::S3BlobStorage;
 use golem_service_base::storage::blob::sqlite::SqliteBlobStorage;
 use golem_service_base::storage::blob::BlobStorage;
+use golem_service_base::storage::postgres::PostgresPool;
 use golem_service_base::storage::sqlite::SqlitePool;
 use humansize::{ISizeFormatter, BINARY};
 use nonempty_collections::NEVec;
 use prometheus::Registry;
 use services::file_loader::FileLoader;
+use services::volume::VolumeService;
+use std::collections::HashMap;
 use std::sync::Arc;
 use storage::keyvalue::sqlite::SqliteKeyValueStorage;
 use tokio::net::TcpListener;
@@ -91,13 +103,16 @@ use tonic::transport::Server;
 use tracing::{info, Instrument};
 use uuid::Uuid;
 use wasmtime::component::Linker;
-use wasmtime::{Config, Engine, WasmBacktraceDetails};
+use wasmtime::{
+    Config, Engine, InstanceAllocationStrategy, PoolingAllocationConfig, WasmBacktraceDetails,
+};
 
 const VERSION: &str = golem_version!();
 
 pub struct RunDetails {
     pub http_port: u16,
     pub grpc_port: u16,
+    pub shutdown: ShutdownHandle,
 }
 
 /// The Bootstrap trait should be implemented by all Worker Executors to customize the initialization
@@ -130,6 +145,7 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
         active_workers: Arc<ActiveWorkers<Ctx>>,
         engine: Arc<Engine>,
         linker: Arc<Linker<Ctx>>,
+        instance_pre_cache: Arc<InstancePreCache<Ctx>>,
         runtime: Handle,
         component_service: Arc<dyn ComponentService + Send + Sync>,
         shard_manager_service: Arc<dyn ShardManagerService + Send + Sync>,
@@ -147,6 +163,7 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         events: Arc<Events>,
         file_loader: Arc<FileLoader>,
+        volume_service: Arc<VolumeService>,
         plugins: Arc<
             dyn Plugins<<Ctx::ComponentOwner as ComponentOwner>::PluginOwner, Ctx::PluginScope>
                 + Send
@@ -202,17 +219,22 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
             .register_encoded_file_descriptor_set(proto::FILE_DESCRIPTOR_SET)
             .build()?;
 
-        let http_port = golem_service_base::observability::start_health_and_metrics_server(
-            golem_config.http_addr()?,
-            prometheus_registry,
-            "Worker executor is running",
-            join_set,
-        )
-        .await?;
+        let lifecycle = Arc::new(ExecutorLifecycle::new());
 
-        let (redis, sqlite, key_value_storage): (
+        let http_port =
+            golem_service_base::observability::start_health_and_metrics_server_with_routes(
+                golem_config.http_addr()?,
+                prometheus_registry,
+                "Worker executor is running",
+                lifecycle_routes(lifecycle.clone()),
+                join_set,
+            )
+            .await?;
+
+        let (redis, sqlite, postgres, key_value_storage): (
             Option<RedisPool>,
             Option<SqlitePool>,
+            Option<PostgresPool>,
             Arc<dyn KeyValueStorage + Send + Sync>,
         ) = match &golem_config.key_value_storage {
             KeyValueStorageConfig::Redis(redis) => {
@@ -222,11 +244,11 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                     .map_err(|err| anyhow!(err))?;
                 let key_value_storage: Arc<dyn KeyValueStorage + Send + Sync> =
                     Arc::new(RedisKeyValueStorage::new(pool.clone()));
-                (Some(pool), None, key_value_storage)
+                (Some(pool), None, None, key_value_storage)
             }
             KeyValueStorageConfig::InMemory => {
                 info!("Using in-memory key-value storage");
-                (None, None, Arc::new(InMemoryKeyValueStorage::new()))
+                (None, None, None, Arc::new(InMemoryKeyValueStorage::new()))
             }
             KeyValueStorageConfig::Sqlite(sqlite) => {
                 info!("Using Sqlite for key-value storage at {}", sqlite.database);
@@ -238,7 +260,22 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                         .await
                         .map_err(|err| anyhow!(err))?,
                 );
-                (None, Some(pool), key_value_storage)
+                (None, Some(pool), None, key_value_storage)
+            }
+            KeyValueStorageConfig::Postgres(postgres) => {
+                info!(
+                    "Using Postgres for key-value storage at {}:{}/{}",
+                    postgres.host, postgres.port, postgres.database
+                );
+                let pool = PostgresPool::configured(postgres)
+                    .await
+                    .map_err(|err| anyhow!(err))?;
+                let key_value_storage: Arc<dyn KeyValueStorage + Send + Sync> = Arc::new(
+                    PostgresKeyValueStorage::new(pool.clone())
+                        .await
+                        .map_err(|err| anyhow!(err))?,
+                );
+                (None, None, Some(pool), key_value_storage)
             }
         };
 
@@ -278,6 +315,31 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                             .map_err(|err| anyhow!(err))?,
                     )
                 }
+                IndexedStorageConfig::KVStorePostgres => {
+                    info!("Using the same Postgres for indexed-storage");
+                    let postgres = postgres.clone().ok_or_else(|| {
+                        anyhow!("KVStorePostgres requires Postgres key-value storage")
+                    })?;
+                    Arc::new(
+                        PostgresIndexedStorage::new(postgres)
+                            .await
+                            .map_err(|err| anyhow!(err))?,
+                    )
+                }
+                IndexedStorageConfig::Postgres(postgres) => {
+                    info!(
+                        "Using Postgres for indexed storage at {}:{}/{}",
+                        postgres.host, postgres.port, postgres.database
+                    );
+                    let pool = PostgresPool::configured(postgres)
+                        .await
+                        .map_err(|err| anyhow!(err))?;
+                    Arc::new(
+                        PostgresIndexedStorage::new(pool)
+                            .await
+                            .map_err(|err| anyhow!(err))?,
+                    )
+                }
                 IndexedStorageConfig::InMemory => {
                     info!("Using in-memory indexed storage");
                     Arc::new(storage::indexed::memory::InMemoryIndexedStorage::new())
@@ -331,6 +393,7 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
             Arc::new(InitialComponentFilesService::new(blob_storage.clone()));
 
         let file_loader = Arc::new(FileLoader::new(initial_files_service.clone())?);
+        let volume_service = Arc::new(VolumeService::new(blob_storage.clone()));
         let (plugins, plugins_observations) = self.create_plugins(&golem_config);
 
         let component_service = component::configured(
@@ -394,13 +457,27 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
 
         let active_workers = self.create_active_workers(&golem_config);
 
+        let mut queue_metrics_interval =
+            tokio::time::interval(golem_config.concurrency.queue_metrics_interval);
+        let active_workers_ref = active_workers.clone();
+        join_set.spawn(
+            async move {
+                loop {
+                    queue_metrics_interval.tick().await;
+                    active_workers_ref.record_queue_metrics();
+                }
+            }
+            .in_current_span(),
+        );
+
         let running_worker_enumeration_service = Arc::new(
             RunningWorkerEnumerationServiceDefault::new(active_workers.clone()),
         );
 
         let shard_manager_service = shard_manager::configured(&golem_config.shard_manager_service);
 
-        let config = self.create_wasmtime_config();
+        let mut config = self.create_wasmtime_config();
+        configure_instance_allocator(&mut config, &golem_config.limits.instance_allocator);
         let engine = Arc::new(Engine::new(&config)?);
         let linker = self.create_wasmtime_linker(&engine)?;
 
@@ -417,6 +494,21 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
         );
 
         let linker = Arc::new(linker);
+        let mut linkers = HashMap::new();
+        for denied in linked_capability_sets() {
+            let linker = if denied.is_empty() {
+                linker.clone()
+            } else {
+                let mut restricted_linker = self.create_wasmtime_linker(&engine)?;
+                link_denied_capabilities(&mut restricted_linker, &denied)?;
+                Arc::new(restricted_linker)
+            };
+            linkers.insert(denied, linker);
+        }
+        let instance_pre_cache = Arc::new(InstancePreCache::new(
+            linkers,
+            &golem_config.component_cache,
+        ));
 
         let key_value_service = Arc::new(DefaultKeyValueService::new(key_value_storage.clone()));
 
@@ -435,11 +527,19 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
             golem_config.limits.invocation_result_broadcast_capacity,
         ));
 
+        let oplog_sinks = Arc::new(OplogSinks::new(
+            blob_storage.clone(),
+            key_value_storage.clone(),
+            component_service.clone(),
+            golem_config.retry.clone(),
+        ));
+
         let oplog_processor_plugin = Arc::new(PerExecutorOplogProcessorPlugin::new(
             component_service.clone(),
             shard_service.clone(),
             lazy_worker_activator.clone(),
             plugins.clone(),
+            oplog_sinks,
         ));
 
         let oplog_service: Arc<dyn OplogService + Send + Sync> =
@@ -468,6 +568,7 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
             Arc::new(lazy_worker_activator.clone() as Arc<dyn WorkerActivator<Ctx> + Send + Sync>),
             oplog_service.clone(),
             worker_service.clone(),
+            golem_config.oplog.clone(),
             golem_config.scheduler.refresh_interval,
         );
 
@@ -476,6 +577,7 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                 active_workers,
                 engine,
                 linker,
+                instance_pre_cache,
                 runtime.clone(),
                 component_service,
                 shard_manager_service,
@@ -493,6 +595,7 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                 worker_proxy,
                 events,
                 file_loader,
+                volume_service,
                 plugins,
                 oplog_processor_plugin,
             )
@@ -503,9 +606,36 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
         let listener = TcpListener::bind(addr).await?;
         let grpc_port = listener.local_addr()?.port();
 
-        let worker_executor =
-            WorkerExecutorImpl::<Ctx, All<Ctx>>::new(services, lazy_worker_activator, grpc_port)
-                .await?;
+        let worker_executor = WorkerExecutorImpl::<Ctx, All<Ctx>>::new(
+            services.clone(),
+            lazy_worker_activator,
+            lifecycle.clone(),
+            grpc_port,
+        )
+        .await?;
+
+        let shutdown = ShutdownHandle::new();
+        if golem_config.graceful_shutdown.enabled {
+            let drain_timeout = golem_config.graceful_shutdown.drain_timeout;
+            let shutdown = shutdown.clone();
+            join_set.spawn(
+                async move {
+                    shutdown.requested().await;
+                    let host = gethostname().to_string_lossy().to_string();
+                    graceful_shutdown::<Ctx, _>(
+                        &services,
+                        &lifecycle,
+                        host,
+                        grpc_port,
+                        drain_timeout,
+                    )
+                    .await?;
+                    shutdown.complete();
+                    Ok(())
+                }
+                .in_current_span(),
+            );
+        }
 
         let service = WorkerExecutorServer::new(worker_executor)
             .accept_compressed(CompressionEncoding::Gzip)
@@ -530,6 +660,23 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
         Ok(RunDetails {
             http_port,
             grpc_port,
+            shutdown,
         })
     }
 }
+
+/// Applies the configured instance allocation strategy to a wasmtime configuration
+pub fn configure_instance_allocator(config: &mut Config, allocator: &InstanceAllocatorConfig) {
+    if let InstanceAllocatorConfig::Pooling(pooling) = allocator {
+        info!("Using the pooling instance allocator: {pooling:?}");
+        let mut pooling_config = PoolingAllocationConfig::default();
+        pooling_config
+            .total_component_instances(pooling.total_component_instances)
+            .total_core_instances(pooling.total_core_instances)
+            .total_memories(pooling.total_memories)
+            .total_tables(pooling.total_tables)
+            .max_memories_per_component(pooling.max_memories_per_component)
+            .max_tables_per_component(pooling.max_tables_per_component);
+        config.allocation_strategy(InstanceAllocationStrategy::Pooling(pooling_config));
+    }
+}
@@ -117,6 +117,12 @@ pub mod workers {
             &["api"]
         )
         .unwrap();
+        static ref FAILED_WORKERS_TOTAL: CounterVec = register_counter_vec!(
+            "failed_workers_total",
+            "Number of workers failed permanently, by the action taken by the failure policy",
+            &["action"]
+        )
+        .unwrap();
     }
 
     pub fn record_worker_call(api_name: &'static str) {
@@ -124,6 +130,63 @@ pub mod workers {
             .with_label_values(&[api_name])
             .inc();
     }
+
+    pub fn record_failed_worker(action: &str) {
+        FAILED_WORKERS_TOTAL.with_label_values(&[action]).inc();
+    }
+}
+
+pub mod concurrency {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use lazy_static::lazy_static;
+    use prometheus::*;
+
+    use golem_common::metrics::DEFAULT_TIME_BUCKETS;
+
+    lazy_static! {
+        static ref INVOCATION_QUEUE_LENGTH: IntGaugeVec = register_int_gauge_vec!(
+            "invocation_queue_length",
+            "Number of pending invocations of the active workers, by component",
+            &["component_id"]
+        )
+        .unwrap();
+        static ref WAITING_WORKERS: IntGaugeVec = register_int_gauge_vec!(
+            "waiting_workers",
+            "Number of workers waiting to be started because of a concurrency limit, by component",
+            &["component_id"]
+        )
+        .unwrap();
+        static ref WORKER_ADMISSION_TIME_SECONDS: HistogramVec = register_histogram_vec!(
+            "worker_admission_time_seconds",
+            "Time a worker waited to be started because of a concurrency limit, by priority",
+            &["priority"],
+            DEFAULT_TIME_BUCKETS.to_vec()
+        )
+        .unwrap();
+    }
+
+    pub fn record_invocation_queue_lengths(lengths: HashMap<String, usize>) {
+        INVOCATION_QUEUE_LENGTH.reset();
+        for (component_id, length) in lengths {
+            INVOCATION_QUEUE_LENGTH
+                .with_label_values(&[&component_id])
+                .set(length as i64);
+        }
+    }
+
+    pub fn record_waiting_workers(component_id: &str, count: usize) {
+        WAITING_WORKERS
+            .with_label_values(&[component_id])
+            .set(count as i64);
+    }
+
+    pub fn record_worker_admission_time(priority: &str, duration: Duration) {
+        WORKER_ADMISSION_TIME_SECONDS
+            .with_label_values(&[priority])
+            .observe(duration.as_secs_f64());
+    }
 }
 
 pub mod promises {
@@ -285,6 +348,25 @@ pub mod oplog {
             golem_common::metrics::DEFAULT_TIME_BUCKETS.to_vec()
         )
         .unwrap();
+        static ref SCHEDULED_COMPACTION_TIME: HistogramVec = register_histogram_vec!(
+            "oplog_scheduled_compaction",
+            "Time taken to compact the archived oplog of a worker",
+            &["mode"],
+            golem_common::metrics::DEFAULT_TIME_BUCKETS.to_vec()
+        )
+        .unwrap();
+        static ref COMPACTED_ENTRIES_TOTAL: CounterVec = register_counter_vec!(
+            "oplog_compacted_entries_total",
+            "Number of archived oplog entries compacted",
+            &["mode"]
+        )
+        .unwrap();
+        static ref COMPACTED_BYTES_TOTAL: CounterVec = register_counter_vec!(
+            "oplog_compacted_bytes_total",
+            "Number of bytes saved by compacting archived oplogs",
+            &["mode"]
+        )
+        .unwrap();
     }
 
     pub fn record_oplog_call(api_name: &'static str) {
@@ -300,4 +382,21 @@ pub mod oplog {
             })
             .observe(duration.as_secs_f64());
     }
+
+    pub fn record_scheduled_compaction(
+        duration: std::time::Duration,
+        stats: &crate::services::oplog::OplogCompactionStats,
+        dry_run: bool,
+    ) {
+        let mode = if dry_run { "dry_run" } else { "compact" };
+        SCHEDULED_COMPACTION_TIME
+            .with_label_values(&[mode])
+            .observe(duration.as_secs_f64());
+        COMPACTED_ENTRIES_TOTAL
+            .with_label_values(&[mode])
+            .inc_by(stats.compacted_entries as f64);
+        COMPACTED_BYTES_TOTAL
+            .with_label_values(&[mode])
+            .inc_by(stats.size_before.saturating_sub(stats.size_after) as f64);
+    }
 }
@@ -251,6 +251,9 @@ impl TrapType {
                             Some(GolemError::InvalidRequest { details }) => {
                                 TrapType::Error(WorkerError::InvalidRequest(details.clone()))
                             }
+                            Some(GolemError::InvocationTimedOut) => {
+                                TrapType::Error(WorkerError::InvocationTimedOut)
+                            }
                             _ => TrapType::Error(WorkerError::Unknown(format!("{:#}", error))),
                         },
                     },
@@ -266,6 +269,7 @@ impl TrapType {
             }
             TrapType::Error(error) => match error {
                 WorkerError::InvalidRequest(msg) => Some(GolemError::invalid_request(msg.clone())),
+                WorkerError::InvocationTimedOut => Some(GolemError::InvocationTimedOut),
                 _ => Some(GolemError::runtime(error.to_string(error_logs))),
             },
             TrapType::Exit => Some(GolemError::runtime("Process exited")),
@@ -335,6 +339,28 @@ impl From<PersistenceLevel> for crate::preview2::golem::api0_2_0::host::Persiste
     }
 }
 
+/// Priority of an invocation. Workers waiting to be started at one of the concurrency limits are
+/// admitted by the highest priority of their invocations, and queued invocations of a worker are
+/// executed by priority. Higher priorities go first.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InvocationPriority {
+    /// Nobody is waiting for the result: `invoke`, scheduled invocations and recovery
+    #[default]
+    Background,
+    /// A caller is waiting for the result: `invoke-and-await` (including API gateway calls) and
+    /// file system access
+    Interactive,
+}
+
+impl Display for InvocationPriority {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvocationPriority::Background => write!(f, "background"),
+            InvocationPriority::Interactive => write!(f, "interactive"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum LookupResult {
     New,
@@ -356,6 +382,28 @@ pub enum ReadFileResult {
     NotAFile,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PutFileResult {
+    Ok,
+    NotAFile,
+    ReadOnly,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeleteFileResult {
+    Ok,
+    NotFound,
+    ReadOnly,
+    DirectoryNotEmpty,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MakeDirectoryResult {
+    Ok,
+    AlreadyExists,
+    ReadOnly,
+}
+
 #[cfg(test)]
 mod tests {
     use test_r::test;
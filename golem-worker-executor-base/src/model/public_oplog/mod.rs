@@ -41,14 +41,15 @@ use golem_common::model::lucene::Query;
 use golem_common::model::oplog::{OplogEntry, OplogIndex, UpdateDescription};
 use golem_common::model::plugin::{PluginOwner, PluginScope};
 use golem_common::model::public_oplog::{
-    ActivatePluginParameters, ChangeRetryPolicyParameters, CreateParameters,
-    DeactivatePluginParameters, DescribeResourceParameters, EndRegionParameters, ErrorParameters,
-    ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
-    ExportedFunctionParameters, FailedUpdateParameters, GrowMemoryParameters,
-    ImportedFunctionInvokedParameters, JumpParameters, LogParameters, ManualUpdateParameters,
-    PendingUpdateParameters, PendingWorkerInvocationParameters, PublicOplogEntry,
-    PublicUpdateDescription, PublicWorkerInvocation, ResourceParameters,
-    SnapshotBasedUpdateParameters, SuccessfulUpdateParameters, TimestampParameter,
+    ActivatePluginParameters, CancelPendingInvocationParameters, ChangeRetryPolicyParameters,
+    CreateParameters, DeactivatePluginParameters, DescribeResourceParameters, EndRegionParameters,
+    ErrorParameters, ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
+    ExportedFunctionParameters, FailedUpdateParameters, FilePathParameters, GrowMemoryParameters,
+    ImportedFunctionInvokedParameters, InvocationTimedOutParameters, JumpParameters, LogParameters,
+    ManualUpdateParameters, PendingUpdateParameters, PendingWorkerInvocationParameters,
+    PublicOplogEntry, PublicUpdateDescription, PublicWorkerInvocation, PutFileParameters,
+    ResourceParameters, SnapshotBasedUpdateParameters, SuccessfulUpdateParameters,
+    TimestampParameter, UpdateLabelsParameters,
 };
 use golem_common::model::{
     ComponentId, ComponentVersion, Empty, IdempotencyKey, OwnedWorkerId, PromiseId, ShardId,
@@ -346,11 +347,18 @@ impl<Owner: PluginOwner, Scope: PluginScope> PublicOplogEntryOps<Owner, Scope>
                     },
                 ))
             }
-            OplogEntry::ExportedFunctionInvoked {
+            OplogEntry::ExportedFunctionInvokedV1 {
                 timestamp,
                 function_name,
                 request,
                 idempotency_key,
+            }
+            | OplogEntry::ExportedFunctionInvoked {
+                timestamp,
+                function_name,
+                request,
+                idempotency_key,
+                ..
             } => {
                 let payload_bytes = oplog_service
                     .download_payload(owned_worker_id, &request)
@@ -475,10 +483,16 @@ impl<Owner: PluginOwner, Scope: PluginScope> PublicOplogEntryOps<Owner, Scope>
                 invocation,
             } => {
                 let invocation = match invocation {
-                    WorkerInvocation::ExportedFunction {
+                    WorkerInvocation::ExportedFunctionV1 {
                         idempotency_key,
                         full_function_name,
                         function_input,
+                    }
+                    | WorkerInvocation::ExportedFunction {
+                        idempotency_key,
+                        full_function_name,
+                        function_input,
+                        ..
                     } => {
                         let metadata = components
                             .get_metadata(
@@ -720,6 +734,56 @@ impl<Owner: PluginOwner, Scope: PluginScope> PublicOplogEntryOps<Owner, Scope>
                     },
                 ))
             }
+            OplogEntry::UpdateLabels { timestamp, labels } => {
+                Ok(PublicOplogEntry::UpdateLabels(UpdateLabelsParameters {
+                    timestamp,
+                    labels,
+                }))
+            }
+            OplogEntry::PutFile {
+                timestamp,
+                path,
+                content,
+            } => {
+                let content_bytes = oplog_service
+                    .download_payload(owned_worker_id, &content)
+                    .await?;
+                Ok(PublicOplogEntry::PutFile(PutFileParameters {
+                    timestamp,
+                    path: path.to_string(),
+                    size: content_bytes.len() as u64,
+                }))
+            }
+            OplogEntry::DeleteFile { timestamp, path } => {
+                Ok(PublicOplogEntry::DeleteFile(FilePathParameters {
+                    timestamp,
+                    path: path.to_string(),
+                }))
+            }
+            OplogEntry::MakeDirectory { timestamp, path } => {
+                Ok(PublicOplogEntry::MakeDirectory(FilePathParameters {
+                    timestamp,
+                    path: path.to_string(),
+                }))
+            }
+            OplogEntry::CancelPendingInvocation {
+                timestamp,
+                idempotency_key,
+            } => Ok(PublicOplogEntry::CancelPendingInvocation(
+                CancelPendingInvocationParameters {
+                    timestamp,
+                    idempotency_key,
+                },
+            )),
+            OplogEntry::InvocationTimedOut {
+                timestamp,
+                idempotency_key,
+            } => Ok(PublicOplogEntry::InvocationTimedOut(
+                InvocationTimedOutParameters {
+                    timestamp,
+                    idempotency_key,
+                },
+            )),
         }
     }
 }
@@ -1437,6 +1501,14 @@ impl IntoValue for RpcError {
                 case_idx: 3,
                 case_value: Some(Box::new(details.into_value())),
             },
+            RpcError::Timeout { details } => Value::Variant {
+                case_idx: 4,
+                case_value: Some(Box::new(details.into_value())),
+            },
+            RpcError::Cancelled { details } => Value::Variant {
+                case_idx: 5,
+                case_value: Some(Box::new(details.into_value())),
+            },
         }
     }
 
@@ -1459,6 +1531,14 @@ impl IntoValue for RpcError {
                     name: "RemoteInternalError".to_string(),
                     typ: Some(String::get_type()),
                 },
+                NameOptionTypePair {
+                    name: "Timeout".to_string(),
+                    typ: Some(String::get_type()),
+                },
+                NameOptionTypePair {
+                    name: "Cancelled".to_string(),
+                    typ: Some(String::get_type()),
+                },
             ],
         })
     }
@@ -1946,6 +2026,10 @@ impl IntoValue for GolemError {
                         reason.into_value(),
                     ]))),
                 },
+                GolemError::InvocationTimedOut => Value::Variant {
+                    case_idx: 25,
+                    case_value: None,
+                },
             }
         }
         into_value(self, true)
@@ -2043,6 +2127,11 @@ impl IntoValue for GolemError {
                     "InitialComponentFileDownloadFailed",
                     record(vec![field("path", str()), field("reason", str())]),
                 ),
+                case(
+                    "FileSystemError",
+                    record(vec![field("path", str()), field("reason", str())]),
+                ),
+                unit_case("InvocationTimedOut"),
             ])
         }
         get_type(true)
@@ -14,17 +14,19 @@
 
 use crate::model::public_oplog::{PublicOplogEntry, PublicUpdateDescription};
 use crate::preview2::golem::api1_1_0::oplog;
+use crate::preview2::golem::api1_2_0::oplog as oplog1_2_0;
 use crate::preview2::wasi::clocks::wall_clock::Datetime;
 use golem_common::model::public_oplog::{
-    ActivatePluginParameters, ChangeRetryPolicyParameters, CreateParameters,
-    DeactivatePluginParameters, DescribeResourceParameters, EndRegionParameters, ErrorParameters,
-    ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
-    ExportedFunctionParameters, FailedUpdateParameters, GrowMemoryParameters,
-    ImportedFunctionInvokedParameters, JumpParameters, LogParameters, ManualUpdateParameters,
-    PendingUpdateParameters, PendingWorkerInvocationParameters, PluginInstallationDescription,
-    PublicRetryConfig, PublicWorkerInvocation, PublicWrappedFunctionType, ResourceParameters,
+    ActivatePluginParameters, CancelPendingInvocationParameters, ChangeRetryPolicyParameters,
+    CreateParameters, DeactivatePluginParameters, DescribeResourceParameters, EndRegionParameters,
+    ErrorParameters, ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
+    ExportedFunctionParameters, FailedUpdateParameters, FilePathParameters, GrowMemoryParameters,
+    ImportedFunctionInvokedParameters, InvocationTimedOutParameters, JumpParameters, LogParameters,
+    ManualUpdateParameters, PendingUpdateParameters, PendingWorkerInvocationParameters,
+    PluginInstallationDescription, PublicRetryConfig, PublicWorkerInvocation,
+    PublicWrappedFunctionType, PutFileParameters, ResourceParameters,
     SnapshotBasedUpdateParameters, SuccessfulUpdateParameters, TimestampParameter,
-    WriteRemoteBatchedParameters,
+    UpdateLabelsParameters, WriteRemoteBatchedParameters,
 };
 use golem_common::model::Timestamp;
 
@@ -239,6 +241,121 @@ impl From<PublicOplogEntry> for oplog::OplogEntry {
                 timestamp: timestamp.into(),
                 plugin: plugin.into(),
             }),
+            // golem:api/oplog@1.1.0 has no case for label changes. They are reported as no-op
+            // entries, which keeps the oplog indices of the following entries aligned, and carry
+            // no labels; guests see the labels through the 1.2.0 conversion below
+            PublicOplogEntry::UpdateLabels(UpdateLabelsParameters { timestamp, .. }) => {
+                Self::NoOp(timestamp.into())
+            }
+            PublicOplogEntry::PutFile(PutFileParameters {
+                timestamp,
+                path,
+                size,
+            }) => Self::Log(oplog::LogParameters {
+                timestamp: timestamp.into(),
+                level: oplog::LogLevel::Info,
+                context: "put-file".to_string(),
+                message: format!("{path} ({size} bytes)"),
+            }),
+            PublicOplogEntry::DeleteFile(FilePathParameters { timestamp, path }) => {
+                Self::Log(oplog::LogParameters {
+                    timestamp: timestamp.into(),
+                    level: oplog::LogLevel::Info,
+                    context: "delete-file".to_string(),
+                    message: path,
+                })
+            }
+            PublicOplogEntry::MakeDirectory(FilePathParameters { timestamp, path }) => {
+                Self::Log(oplog::LogParameters {
+                    timestamp: timestamp.into(),
+                    level: oplog::LogLevel::Info,
+                    context: "make-directory".to_string(),
+                    message: path,
+                })
+            }
+            // golem:api/oplog@1.1.0 has no dedicated case for cancelled invocations; see the 1.2.0
+            // conversion below
+            PublicOplogEntry::CancelPendingInvocation(CancelPendingInvocationParameters {
+                timestamp,
+                idempotency_key,
+            }) => Self::Log(oplog::LogParameters {
+                timestamp: timestamp.into(),
+                level: oplog::LogLevel::Info,
+                context: "cancel-pending-invocation".to_string(),
+                message: idempotency_key.value,
+            }),
+            PublicOplogEntry::InvocationTimedOut(InvocationTimedOutParameters {
+                timestamp,
+                idempotency_key,
+            }) => Self::Log(oplog::LogParameters {
+                timestamp: timestamp.into(),
+                level: oplog::LogLevel::Warn,
+                context: "invocation-timed-out".to_string(),
+                message: idempotency_key.value,
+            }),
+        }
+    }
+}
+
+impl From<PublicOplogEntry> for oplog1_2_0::OplogEntry {
+    fn from(value: PublicOplogEntry) -> Self {
+        match value {
+            PublicOplogEntry::UpdateLabels(UpdateLabelsParameters { timestamp, labels }) => {
+                Self::UpdateLabels(oplog1_2_0::UpdateLabelsParameters {
+                    timestamp: timestamp.into(),
+                    labels: labels.into_iter().collect(),
+                })
+            }
+            PublicOplogEntry::CancelPendingInvocation(CancelPendingInvocationParameters {
+                timestamp,
+                idempotency_key,
+            }) => Self::CancelPendingInvocation(oplog1_2_0::CancelPendingInvocationParameters {
+                timestamp: timestamp.into(),
+                idempotency_key: idempotency_key.value,
+            }),
+            other => oplog::OplogEntry::from(other).into(),
+        }
+    }
+}
+
+impl From<oplog::OplogEntry> for oplog1_2_0::OplogEntry {
+    fn from(value: oplog::OplogEntry) -> Self {
+        match value {
+            oplog::OplogEntry::Create(params) => Self::Create(params),
+            oplog::OplogEntry::ImportedFunctionInvoked(params) => {
+                Self::ImportedFunctionInvoked(params)
+            }
+            oplog::OplogEntry::ExportedFunctionInvoked(params) => {
+                Self::ExportedFunctionInvoked(params)
+            }
+            oplog::OplogEntry::ExportedFunctionCompleted(params) => {
+                Self::ExportedFunctionCompleted(params)
+            }
+            oplog::OplogEntry::Suspend(timestamp) => Self::Suspend(timestamp),
+            oplog::OplogEntry::Error(params) => Self::Error(params),
+            oplog::OplogEntry::NoOp(timestamp) => Self::NoOp(timestamp),
+            oplog::OplogEntry::Jump(params) => Self::Jump(params),
+            oplog::OplogEntry::Interrupted(timestamp) => Self::Interrupted(timestamp),
+            oplog::OplogEntry::Exited(timestamp) => Self::Exited(timestamp),
+            oplog::OplogEntry::ChangeRetryPolicy(params) => Self::ChangeRetryPolicy(params),
+            oplog::OplogEntry::BeginAtomicRegion(timestamp) => Self::BeginAtomicRegion(timestamp),
+            oplog::OplogEntry::EndAtomicRegion(params) => Self::EndAtomicRegion(params),
+            oplog::OplogEntry::BeginRemoteWrite(timestamp) => Self::BeginRemoteWrite(timestamp),
+            oplog::OplogEntry::EndRemoteWrite(params) => Self::EndRemoteWrite(params),
+            oplog::OplogEntry::PendingWorkerInvocation(params) => {
+                Self::PendingWorkerInvocation(params)
+            }
+            oplog::OplogEntry::PendingUpdate(params) => Self::PendingUpdate(params),
+            oplog::OplogEntry::SuccessfulUpdate(params) => Self::SuccessfulUpdate(params),
+            oplog::OplogEntry::FailedUpdate(params) => Self::FailedUpdate(params),
+            oplog::OplogEntry::GrowMemory(params) => Self::GrowMemory(params),
+            oplog::OplogEntry::CreateResource(params) => Self::CreateResource(params),
+            oplog::OplogEntry::DropResource(params) => Self::DropResource(params),
+            oplog::OplogEntry::DescribeResource(params) => Self::DescribeResource(params),
+            oplog::OplogEntry::Log(params) => Self::Log(params),
+            oplog::OplogEntry::Restart(timestamp) => Self::Restart(timestamp),
+            oplog::OplogEntry::ActivatePlugin(params) => Self::ActivatePlugin(params),
+            oplog::OplogEntry::DeactivatePlugin(params) => Self::DeactivatePlugin(params),
         }
     }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore, TryAcquireError};
@@ -22,7 +23,12 @@ use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode,
 use golem_common::model::{OwnedWorkerId, WorkerId};
 
 use crate::error::GolemError;
-use crate::services::golem_config::MemoryConfig;
+use crate::metrics::concurrency::record_invocation_queue_lengths;
+use crate::model::InvocationPriority;
+use crate::services::golem_config::GolemConfig;
+use crate::services::worker_concurrency::{
+    BlockedWorker, ConcurrencyScope, WorkerConcurrencyLimiter, WorkerConcurrencyPermit,
+};
 use crate::services::HasAll;
 use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
@@ -33,10 +39,12 @@ pub struct ActiveWorkers<Ctx: WorkerCtx> {
     worker_memory: Arc<Semaphore>,
     priority_allocation_lock: Arc<Mutex<()>>,
     acquire_retry_delay: Duration,
+    concurrency_limiter: WorkerConcurrencyLimiter,
 }
 
 impl<Ctx: WorkerCtx> ActiveWorkers<Ctx> {
-    pub fn new(memory_config: &MemoryConfig) -> Self {
+    pub fn new(golem_config: &GolemConfig) -> Self {
+        let memory_config = &golem_config.memory;
         let worker_memory_size = memory_config.worker_memory();
         Self {
             workers: Cache::new(
@@ -48,6 +56,7 @@ impl<Ctx: WorkerCtx> ActiveWorkers<Ctx> {
             worker_memory: Arc::new(Semaphore::new(worker_memory_size)),
             acquire_retry_delay: memory_config.acquire_retry_delay,
             priority_allocation_lock: Arc::new(Mutex::new(())),
+            concurrency_limiter: WorkerConcurrencyLimiter::new(&golem_config.concurrency),
         }
     }
 
@@ -95,6 +104,50 @@ impl<Ctx: WorkerCtx> ActiveWorkers<Ctx> {
         self.workers.iter()
     }
 
+    /// Waits until the worker fits into the concurrency limits, stopping idle workers which
+    /// count against the same limit if needed.
+    pub async fn acquire_concurrency(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        priority: InvocationPriority,
+    ) -> WorkerConcurrencyPermit {
+        let acquire = self.concurrency_limiter.acquire(owned_worker_id, priority);
+        tokio::pin!(acquire);
+
+        loop {
+            tokio::select! {
+                permit = &mut acquire => break permit,
+                _ = tokio::time::sleep(self.acquire_retry_delay) => {
+                    if let Some(scope) = self.concurrency_limiter.blocking_scope(owned_worker_id) {
+                        debug!("Worker {owned_worker_id} is waiting for the {scope:?} concurrency limit, trying to stop an idle worker");
+                        self.try_stop_idle_worker(owned_worker_id, scope).await;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Stops counting the worker against the concurrency limits until the returned guard is
+    /// dropped, see [`WorkerConcurrencyLimiter::blocked`]
+    pub fn blocked(&self, owned_worker_id: &OwnedWorkerId) -> BlockedWorker {
+        self.concurrency_limiter.blocked(owned_worker_id)
+    }
+
+    pub fn raise_priority(&self, worker_id: &WorkerId, priority: InvocationPriority) {
+        self.concurrency_limiter.raise_priority(worker_id, priority);
+    }
+
+    /// Records the number of pending invocations of the active workers, by component
+    pub fn record_queue_metrics(&self) {
+        let mut lengths = HashMap::new();
+        for (worker_id, worker) in self.workers.iter() {
+            *lengths
+                .entry(worker_id.component_id.to_string())
+                .or_default() += worker.pending_invocation_count();
+        }
+        record_invocation_queue_lengths(lengths);
+    }
+
     pub async fn acquire(&self, memory: u64) -> OwnedSemaphorePermit {
         let mem32: u32 = memory
             .try_into()
@@ -175,6 +228,36 @@ impl<Ctx: WorkerCtx> ActiveWorkers<Ctx> {
         }
     }
 
+    /// Stops the idle worker which has been idle for the longest time among the ones counting
+    /// against the given concurrency limit
+    async fn try_stop_idle_worker(&self, owned_worker_id: &OwnedWorkerId, scope: ConcurrencyScope) {
+        let mut possibilities = Vec::new();
+        for (worker_id, worker) in self.workers.iter() {
+            let same_scope = match scope {
+                ConcurrencyScope::Executor => true,
+                ConcurrencyScope::Component => {
+                    worker_id.component_id == owned_worker_id.worker_id.component_id
+                }
+                ConcurrencyScope::Account => {
+                    worker.owned_worker_id().account_id == owned_worker_id.account_id
+                }
+            };
+            if same_scope && worker.is_currently_idle_but_running() {
+                let last_changed = worker.last_execution_state_change().await;
+                possibilities.push((worker_id, worker, last_changed));
+            }
+        }
+
+        possibilities.sort_by_key(|(_worker_id, _worker, last_changed)| last_changed.to_millis());
+
+        for (worker_id, worker, _) in possibilities {
+            if worker.stop_if_idle().await {
+                debug!("Stopped {worker_id} to make room for {owned_worker_id}");
+                break;
+            }
+        }
+    }
+
     async fn try_free_up_memory(&self, memory: u64) -> bool {
         let current_avail = self.worker_memory.available_permits();
         let needed = memory.saturating_sub(current_avail as u64);
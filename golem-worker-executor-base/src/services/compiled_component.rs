@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use sha2::{Digest, Sha256};
 use tokio::time::Instant;
-use tracing::debug;
+use tracing::{debug, warn};
 use wasmtime::component::Component;
 
 use golem_common::model::ComponentId;
@@ -25,9 +27,12 @@ use golem_common::model::ComponentId;
 use crate::error::GolemError;
 use crate::services::golem_config::CompiledComponentServiceConfig;
 use crate::Engine;
-use golem_service_base::storage::blob::{BlobStorage, BlobStorageNamespace};
+use golem_service_base::storage::blob::{BlobStorage, BlobStorageNamespace, ExistsResult};
 
 /// Service for storing compiled native binaries of WebAssembly components
+///
+/// Compiled binaries are only loadable by an engine with the same wasmtime version and compatible
+/// configuration, so every artifact is stored under the fingerprint of the engine that produced it.
 #[async_trait]
 pub trait CompiledComponentService {
     async fn get(
@@ -41,7 +46,39 @@ pub trait CompiledComponentService {
         component_id: &ComponentId,
         component_version: u64,
         component: &Component,
+        engine: &Engine,
     ) -> Result<(), GolemError>;
+    async fn exists(
+        &self,
+        component_id: &ComponentId,
+        component_version: u64,
+        engine: &Engine,
+    ) -> Result<bool, GolemError>;
+}
+
+/// Returns a fingerprint of the engine's wasmtime version and the configuration settings affecting
+/// the compatibility of precompiled components
+///
+/// The fingerprint is part of the stored artifacts' keys, so it has to be stable between builds.
+/// Instead of the standard library's hashers, which make no such guarantee, it is the SHA-256 digest
+/// of the bytes wasmtime writes when hashing the compatibility settings.
+pub fn engine_fingerprint(engine: &Engine) -> String {
+    let mut hasher = Sha256Hasher(Sha256::new());
+    engine.precompile_compatibility_hash().hash(&mut hasher);
+    hex::encode(&hasher.0.finalize()[..8])
+}
+
+struct Sha256Hasher(Sha256);
+
+impl Hasher for Sha256Hasher {
+    fn finish(&self) -> u64 {
+        let digest = self.0.clone().finalize();
+        u64::from_be_bytes(digest[..8].try_into().unwrap())
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
 }
 
 pub struct DefaultCompiledComponentService {
@@ -53,8 +90,11 @@ impl DefaultCompiledComponentService {
         Self { blob_storage }
     }
 
-    fn key(component_id: &ComponentId, component_version: u64) -> PathBuf {
-        Path::new(&component_id.to_string()).join(format!("{component_version}.cwasm"))
+    fn key(component_id: &ComponentId, component_version: u64, engine: &Engine) -> PathBuf {
+        Path::new(&component_id.to_string()).join(format!(
+            "{component_version}-{}.cwasm",
+            engine_fingerprint(engine)
+        ))
     }
 }
 
@@ -72,21 +112,24 @@ impl CompiledComponentService for DefaultCompiledComponentService {
                 "compiled_component",
                 "get",
                 BlobStorageNamespace::CompilationCache,
-                &Self::key(component_id, component_version),
+                &Self::key(component_id, component_version, engine),
             )
             .await
         {
             Ok(None) => Ok(None),
             Ok(Some(bytes)) => {
                 let start = Instant::now();
-                let component = unsafe {
-                    Component::deserialize(engine, &bytes).map_err(|err| {
-                        GolemError::component_download_failed(
-                            component_id.clone(),
-                            component_version,
-                            format!("Could not deserialize compiled component: {}", err),
-                        )
-                    })?
+                let component = match unsafe { Component::deserialize(engine, &bytes) } {
+                    Ok(component) => component,
+                    Err(err) => {
+                        // Treating an incompatible or corrupted artifact as missing, so the
+                        // component gets recompiled and the stored artifact overwritten
+                        warn!(
+                            "Could not deserialize compiled component {}/{}: {}",
+                            component_id, component_version, err
+                        );
+                        return Ok(None);
+                    }
                 };
                 let end = Instant::now();
 
@@ -112,6 +155,7 @@ impl CompiledComponentService for DefaultCompiledComponentService {
         component_id: &ComponentId,
         component_version: u64,
         component: &Component,
+        engine: &Engine,
     ) -> Result<(), GolemError> {
         let bytes = component
             .serialize()
@@ -121,7 +165,7 @@ impl CompiledComponentService for DefaultCompiledComponentService {
                 "compiled_component",
                 "put",
                 BlobStorageNamespace::CompilationCache,
-                &Self::key(component_id, component_version),
+                &Self::key(component_id, component_version, engine),
                 &bytes,
             )
             .await
@@ -133,6 +177,30 @@ impl CompiledComponentService for DefaultCompiledComponentService {
                 )
             })
     }
+
+    async fn exists(
+        &self,
+        component_id: &ComponentId,
+        component_version: u64,
+        engine: &Engine,
+    ) -> Result<bool, GolemError> {
+        self.blob_storage
+            .exists(
+                "compiled_component",
+                "exists",
+                BlobStorageNamespace::CompilationCache,
+                &Self::key(component_id, component_version, engine),
+            )
+            .await
+            .map(|result| result == ExistsResult::File)
+            .map_err(|err| {
+                GolemError::component_download_failed(
+                    component_id.clone(),
+                    component_version,
+                    format!("Could not check compiled component: {err}"),
+                )
+            })
+    }
 }
 
 pub fn configured(
@@ -179,7 +247,82 @@ impl CompiledComponentService for CompiledComponentServiceDisabled {
         _component_id: &ComponentId,
         _component_version: u64,
         _component: &Component,
+        _engine: &Engine,
     ) -> Result<(), GolemError> {
         Ok(())
     }
+
+    async fn exists(
+        &self,
+        _component_id: &ComponentId,
+        _component_version: u64,
+        _engine: &Engine,
+    ) -> Result<bool, GolemError> {
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::{engine_fingerprint, CompiledComponentService, DefaultCompiledComponentService};
+    use golem_common::model::ComponentId;
+    use golem_service_base::storage::blob::memory::InMemoryBlobStorage;
+    use std::sync::Arc;
+    use uuid::Uuid;
+    use wasmtime::component::Component;
+    use wasmtime::{Config, Engine};
+
+    fn engine(consume_fuel: bool) -> Engine {
+        let mut config = Config::default();
+        config.wasm_component_model(true);
+        config.async_support(true);
+        config.consume_fuel(consume_fuel);
+        Engine::new(&config).unwrap()
+    }
+
+    #[test]
+    fn engine_fingerprint_is_deterministic() {
+        let fingerprint = engine_fingerprint(&engine(true));
+
+        assert_eq!(fingerprint, engine_fingerprint(&engine(true)));
+        assert_eq!(fingerprint.len(), 16);
+    }
+
+    #[test]
+    fn engine_fingerprint_depends_on_the_compatibility_settings() {
+        assert_ne!(
+            engine_fingerprint(&engine(true)),
+            engine_fingerprint(&engine(false))
+        );
+    }
+
+    #[test]
+    async fn compiled_components_are_only_visible_to_compatible_engines() {
+        let service = DefaultCompiledComponentService::new(Arc::new(InMemoryBlobStorage::new()));
+        let component_id = ComponentId(Uuid::new_v4());
+        let engine1 = engine(true);
+        let engine2 = engine(false);
+
+        let component = Component::new(&engine1, "(component)").unwrap();
+        service
+            .put(&component_id, 1, &component, &engine1)
+            .await
+            .unwrap();
+
+        assert!(service.exists(&component_id, 1, &engine1).await.unwrap());
+        assert!(service
+            .get(&component_id, 1, &engine1)
+            .await
+            .unwrap()
+            .is_some());
+        assert!(!service.exists(&component_id, 1, &engine2).await.unwrap());
+        assert!(service
+            .get(&component_id, 1, &engine2)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(!service.exists(&component_id, 2, &engine1).await.unwrap());
+    }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -25,19 +26,27 @@ use crate::services::golem_config::{
     CompiledComponentServiceConfig, ComponentCacheConfig, ComponentServiceConfig,
 };
 use crate::services::plugins::PluginsObservations;
+use crate::wasi_host::capabilities::linked_denied_capabilities;
+use crate::workerctx::WorkerCtx;
 use async_trait::async_trait;
 use futures_util::TryStreamExt;
 use golem_api_grpc::proto::golem::component::v1::component_service_client::ComponentServiceClient;
+use golem_api_grpc::proto::golem::component::v1::secret_service_client::SecretServiceClient;
 use golem_api_grpc::proto::golem::component::v1::{
-    download_component_response, get_component_metadata_response, ComponentError,
-    DownloadComponentRequest, GetLatestComponentRequest, GetVersionedComponentRequest,
+    download_component_response, get_component_metadata_response, resolve_secrets_response,
+    ComponentError, DownloadComponentRequest, GetLatestComponentRequest,
+    GetVersionedComponentRequest, ResolveSecretsRequest,
 };
 use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode, SimpleCache};
 use golem_common::client::{GrpcClient, GrpcClientConfig};
 use golem_common::config::RetryConfig;
 use golem_common::metrics::external_calls::record_external_call_response_size_bytes;
-use golem_common::model::component_metadata::LinearMemory;
+use golem_common::model::component_metadata::{
+    CapabilityPolicy, ComponentVolume, EgressPolicy, FailurePolicy, HostCapability, LinearMemory,
+    OplogPolicy,
+};
 use golem_common::model::plugin::PluginInstallation;
+use golem_common::model::secret::SecretReference;
 use golem_common::model::{
     AccountId, ComponentId, ComponentType, ComponentVersion, InitialComponentFile,
 };
@@ -52,7 +61,7 @@ use tonic::codec::CompressionEncoding;
 use tonic::transport::Channel;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
-use wasmtime::component::Component;
+use wasmtime::component::{Component, InstancePre, Linker};
 use wasmtime::Engine;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,6 +74,18 @@ pub struct ComponentMetadata {
     pub component_type: ComponentType,
     pub files: Vec<InitialComponentFile>,
     pub plugin_installations: Vec<PluginInstallation>,
+    #[serde(default)]
+    pub oplog_policy: Option<OplogPolicy>,
+    #[serde(default)]
+    pub volumes: Vec<ComponentVolume>,
+    #[serde(default)]
+    pub capability_policy: Option<CapabilityPolicy>,
+    #[serde(default)]
+    pub egress_policy: Option<EgressPolicy>,
+    #[serde(default)]
+    pub secrets: Vec<SecretReference>,
+    #[serde(default)]
+    pub failure_policy: Option<FailurePolicy>,
 }
 
 /// Service for downloading a specific Golem component from the Golem Component API
@@ -84,6 +105,13 @@ pub trait ComponentService {
         component_id: &ComponentId,
         forced_version: Option<ComponentVersion>,
     ) -> Result<ComponentMetadata, GolemError>;
+
+    /// Gets the values of the given secrets of the component's owner. The values are not cached.
+    async fn resolve_secrets(
+        &self,
+        component_id: &ComponentId,
+        names: &[String],
+    ) -> Result<HashMap<String, String>, GolemError>;
 }
 
 pub async fn configured(
@@ -135,6 +163,7 @@ pub struct ComponentServiceGrpc {
     retry_config: RetryConfig,
     compiled_component_service: Arc<dyn CompiledComponentService + Send + Sync>,
     client: GrpcClient<ComponentServiceClient<Channel>>,
+    secret_client: GrpcClient<SecretServiceClient<Channel>>,
     plugin_observations: Arc<dyn PluginsObservations + Send + Sync>,
 }
 
@@ -173,6 +202,19 @@ impl ComponentServiceGrpc {
                     ..Default::default() // TODO
                 },
             ),
+            secret_client: GrpcClient::new(
+                "secret_service",
+                move |channel| {
+                    SecretServiceClient::new(channel)
+                        .send_compressed(CompressionEncoding::Gzip)
+                        .accept_compressed(CompressionEncoding::Gzip)
+                },
+                endpoint.as_http_02(),
+                GrpcClientConfig {
+                    retries_on_unavailable: retry_config.clone(),
+                    ..Default::default()
+                },
+            ),
             plugin_observations,
         }
     }
@@ -227,8 +269,9 @@ impl ComponentService for ComponentServiceGrpc {
 
                             let start = Instant::now();
                             let component_id_clone2 = component_id_clone.clone();
+                            let engine_clone = engine.clone();
                             let component = spawn_blocking(move || {
-                                Component::from_binary(&engine, &bytes).map_err(|e| {
+                                Component::from_binary(&engine_clone, &bytes).map_err(|e| {
                                     GolemError::ComponentParseFailed {
                                         component_id: component_id_clone2,
                                         component_version,
@@ -249,7 +292,7 @@ impl ComponentService for ComponentServiceGrpc {
                             );
 
                             let result = compiled_component_service
-                                .put(&component_id_clone, component_version, &component)
+                                .put(&component_id_clone, component_version, &component, &engine)
                                 .await;
 
                             match result {
@@ -342,6 +385,63 @@ impl ComponentService for ComponentServiceGrpc {
             }
         }
     }
+
+    async fn resolve_secrets(
+        &self,
+        component_id: &ComponentId,
+        names: &[String],
+    ) -> Result<HashMap<String, String>, GolemError> {
+        if names.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        with_retries(
+            "components",
+            "resolve_secrets",
+            Some(component_id.to_string()),
+            &self.retry_config,
+            &(
+                self.secret_client.clone(),
+                component_id.clone(),
+                names.to_vec(),
+                self.access_token,
+            ),
+            |(client, component_id, names, access_token)| {
+                Box::pin(async move {
+                    let response = client
+                        .call("resolve_secrets", move |client| {
+                            let request = authorised_grpc_request(
+                                ResolveSecretsRequest {
+                                    component_id: Some(component_id.clone().into()),
+                                    names: names.clone(),
+                                },
+                                access_token,
+                            );
+                            Box::pin(client.resolve_secrets(request))
+                        })
+                        .await?
+                        .into_inner();
+
+                    match response.result {
+                        None => Err("Empty response".to_string().into()),
+                        Some(resolve_secrets_response::Result::Success(response)) => {
+                            Ok(response.values)
+                        }
+                        Some(resolve_secrets_response::Result::Error(error)) => {
+                            Err(GrpcError::Domain(error))
+                        }
+                    }
+                })
+            },
+            is_grpc_retriable::<ComponentError>,
+        )
+        .await
+        .map_err(|error| {
+            GolemError::runtime(format!(
+                "Failed to resolve the secrets of component {component_id}: {error}"
+            ))
+        })
+    }
 }
 
 async fn download_via_grpc(
@@ -480,6 +580,59 @@ async fn get_metadata_via_grpc(
                             metadata.memories.iter().map(|m| m.clone().into()).collect()
                         })
                         .unwrap_or_default(),
+                    oplog_policy: component
+                        .metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.oplog_policy.clone())
+                        .map(|policy| policy.into()),
+                    volumes: component
+                        .metadata
+                        .as_ref()
+                        .map(|metadata| {
+                            metadata
+                                .volumes
+                                .iter()
+                                .map(|volume| volume.clone().try_into())
+                                .collect::<Result<Vec<_>, _>>()
+                        })
+                        .unwrap_or_else(|| Ok(Vec::new()))
+                        .map_err(|_| {
+                            GrpcError::Unexpected("Failed to get the volumes".to_string())
+                        })?,
+                    capability_policy: component
+                        .metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.capability_policy.clone())
+                        .map(|policy| policy.try_into())
+                        .transpose()
+                        .map_err(|_| {
+                            GrpcError::Unexpected("Failed to get the capability policy".to_string())
+                        })?,
+                    egress_policy: component
+                        .metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.egress_policy.clone())
+                        .map(|policy| policy.into()),
+                    secrets: component
+                        .metadata
+                        .as_ref()
+                        .map(|metadata| {
+                            metadata
+                                .secrets
+                                .iter()
+                                .map(|secret| secret.clone().into())
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    failure_policy: component
+                        .metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.failure_policy.clone())
+                        .map(|policy| policy.try_into())
+                        .transpose()
+                        .map_err(|_| {
+                            GrpcError::Unexpected("Failed to get the failure policy".to_string())
+                        })?,
                     exports: component
                         .metadata
                         .map(|metadata| {
@@ -560,6 +713,103 @@ fn create_component_cache(
     )
 }
 
+/// Caches the result of pre-instantiating components with the executor's linker.
+///
+/// Pre-instantiation resolves and type-checks all the imports of a component, which only depends
+/// on the linker and the component itself, so it can be shared between all the instances created
+/// from the same component version.
+///
+/// Components whose capability policy denies linker enforced capabilities are pre-instantiated
+/// with a linker which has the entry points of those capabilities replaced by trapping stubs. The
+/// policy is part of the component version, so it does not need to be part of the cache key.
+pub struct InstancePreCache<Ctx: WorkerCtx> {
+    linkers: HashMap<BTreeSet<HostCapability>, Arc<Linker<Ctx>>>,
+    enabled: bool,
+    cache: Cache<ComponentKey, (), InstancePre<Ctx>, GolemError>,
+}
+
+impl<Ctx: WorkerCtx> InstancePreCache<Ctx> {
+    /// Creates the cache with a linker for each set of denied linker enforced capabilities, see
+    /// `wasi_host::capabilities::linked_capability_sets`
+    pub fn new(
+        linkers: HashMap<BTreeSet<HostCapability>, Arc<Linker<Ctx>>>,
+        cache_config: &ComponentCacheConfig,
+    ) -> Self {
+        Self {
+            linkers,
+            enabled: cache_config.cache_instance_pre,
+            cache: Cache::new(
+                Some(cache_config.max_capacity),
+                FullCacheEvictionMode::LeastRecentlyUsed(1),
+                BackgroundEvictionMode::OlderThan {
+                    ttl: cache_config.time_to_idle,
+                    period: Duration::from_secs(60),
+                },
+                "instance_pre",
+            ),
+        }
+    }
+
+    pub async fn get(
+        &self,
+        component_id: &ComponentId,
+        component_version: ComponentVersion,
+        component: &Component,
+        capability_policy: Option<&CapabilityPolicy>,
+    ) -> Result<InstancePre<Ctx>, GolemError> {
+        let denied = linked_denied_capabilities(capability_policy);
+        let linker =
+            self.linkers
+                .get(&denied)
+                .cloned()
+                .ok_or_else(|| GolemError::ComponentParseFailed {
+                    component_id: component_id.clone(),
+                    component_version,
+                    reason: format!(
+                        "No linker is available for the denied capabilities {denied:?}"
+                    ),
+                })?;
+
+        if !self.enabled {
+            return Self::instantiate_pre(
+                &linker,
+                component_id.clone(),
+                component_version,
+                component,
+            );
+        }
+
+        let key = ComponentKey {
+            component_id: component_id.clone(),
+            component_version,
+        };
+        let component = component.clone();
+        let component_id = component_id.clone();
+        self.cache
+            .get_or_insert_simple(&key, || {
+                Box::pin(async move {
+                    Self::instantiate_pre(&linker, component_id, component_version, &component)
+                })
+            })
+            .await
+    }
+
+    fn instantiate_pre(
+        linker: &Linker<Ctx>,
+        component_id: ComponentId,
+        component_version: ComponentVersion,
+        component: &Component,
+    ) -> Result<InstancePre<Ctx>, GolemError> {
+        linker
+            .instantiate_pre(component)
+            .map_err(|e| GolemError::ComponentParseFailed {
+                component_id,
+                component_version,
+                reason: format!("Failed to pre-instantiate component: {e}"),
+            })
+    }
+}
+
 fn create_component_metadata_cache(
     max_capacity: usize,
     time_to_idle: Duration,
@@ -712,7 +962,7 @@ impl ComponentServiceLocalFileSystem {
                             );
 
                             let result = compiled_component_service
-                                .put(&component_id, component_version, &component)
+                                .put(&component_id, component_version, &component, &engine)
                                 .await;
 
                             match result {
@@ -804,6 +1054,20 @@ impl ComponentService for ComponentServiceLocalFileSystem {
         self.get_metadata_from_path(&props_path, component_id, version)
             .await
     }
+
+    async fn resolve_secrets(
+        &self,
+        component_id: &ComponentId,
+        names: &[String],
+    ) -> Result<HashMap<String, String>, GolemError> {
+        if names.is_empty() {
+            Ok(HashMap::new())
+        } else {
+            Err(GolemError::runtime(format!(
+                "Cannot resolve the secrets of component {component_id}: secrets are not available with the local component service"
+            )))
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
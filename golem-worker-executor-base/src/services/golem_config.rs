@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
 use std::path::{Path, PathBuf};
 use std::time::Duration;
@@ -25,8 +26,11 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use golem_common::config::{
-    ConfigExample, ConfigLoader, DbSqliteConfig, HasConfigExamples, RedisConfig, RetryConfig,
+    ConfigExample, ConfigLoader, DbPostgresConfig, DbSqliteConfig, HasConfigExamples, RedisConfig,
+    RetryConfig,
 };
+use golem_common::model::component_metadata::EgressPolicy;
+use golem_common::model::{AccountId, ComponentId};
 use golem_common::tracing::TracingConfig;
 
 /// The shared global Golem configuration
@@ -46,10 +50,14 @@ pub struct GolemConfig {
     pub plugin_service: PluginServiceConfig,
     pub oplog: OplogConfig,
     pub suspend: SuspendConfig,
+    pub graceful_shutdown: GracefulShutdownConfig,
     pub active_workers: ActiveWorkersConfig,
     pub scheduler: SchedulerConfig,
     pub public_worker_api: WorkerServiceGrpcConfig,
     pub memory: MemoryConfig,
+    pub egress: EgressConfig,
+    pub concurrency: ConcurrencyConfig,
+    pub rpc: RpcConfig,
     pub grpc_address: String,
     pub port: u16,
     pub http_address: String,
@@ -67,10 +75,35 @@ pub struct Limits {
     #[serde(with = "humantime_serde")]
     pub epoch_interval: Duration,
     pub epoch_ticks: u64,
+    pub instance_allocator: InstanceAllocatorConfig,
+}
+
+/// Selects the wasmtime instance allocation strategy.
+///
+/// The pooling allocator preallocates slots for instances, memories and tables, which makes
+/// instantiation significantly cheaper at the cost of reserving virtual memory up front.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "config")]
+pub enum InstanceAllocatorConfig {
+    OnDemand,
+    Pooling(PoolingAllocatorConfig),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PoolingAllocatorConfig {
+    pub total_component_instances: u32,
+    pub total_core_instances: u32,
+    pub total_memories: u32,
+    pub total_tables: u32,
+    pub max_memories_per_component: u32,
+    pub max_tables_per_component: u32,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ComponentCacheConfig {
+    /// Caches the pre-instantiated components together with the compiled ones. Only meant to be
+    /// disabled for measuring the instantiation overhead.
+    pub cache_instance_pre: bool,
     pub max_capacity: usize,
     pub max_metadata_capacity: usize,
     #[serde(with = "humantime_serde")]
@@ -262,6 +295,21 @@ pub struct SuspendConfig {
     pub suspend_after: Duration,
 }
 
+/// Controls how the executor behaves when it receives a termination signal.
+///
+/// When enabled, the executor stops accepting new invocations, waits at most `drain_timeout`
+/// for the in-flight ones to finish, and then hands its shards back to the shard manager.
+///
+/// The whole shutdown has to finish before the process gets killed (on Kubernetes after the pod's
+/// `terminationGracePeriodSeconds`, 30 seconds by default), so `drain_timeout` has to be shorter
+/// than that, leaving time for interrupting the remaining workers and unregistering.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GracefulShutdownConfig {
+    pub enabled: bool,
+    #[serde(with = "humantime_serde")]
+    pub drain_timeout: Duration,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ActiveWorkersConfig {
     pub drop_when_full: f64,
@@ -275,6 +323,15 @@ pub struct SchedulerConfig {
     pub refresh_interval: Duration,
 }
 
+/// Worker-to-worker invocation settings
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RpcConfig {
+    /// Timeout of remote invocations which do not specify one with `golem:api/rpc@1.2.0`.
+    /// Remote invocations are awaited indefinitely if not set.
+    #[serde(default, with = "humantime_serde")]
+    pub default_timeout: Option<Duration>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OplogConfig {
     pub max_operations_before_commit: u64,
@@ -285,6 +342,12 @@ pub struct OplogConfig {
     pub entry_count_limit: u64,
     #[serde(with = "humantime_serde")]
     pub archive_interval: Duration,
+    /// Compact the archived layers of fully archived oplogs and the oplogs of idle ephemeral
+    /// workers, dropping the entries which are never going to be replayed again. Disabled by
+    /// default, as it rewrites the archives in place.
+    pub compaction_enabled: bool,
+    /// Only calculate and log the compaction statistics without modifying the archives
+    pub compaction_dry_run: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -292,6 +355,7 @@ pub struct OplogConfig {
 pub enum KeyValueStorageConfig {
     Redis(RedisConfig),
     Sqlite(DbSqliteConfig),
+    Postgres(DbPostgresConfig),
     InMemory,
 }
 
@@ -302,6 +366,8 @@ pub enum IndexedStorageConfig {
     Redis(RedisConfig),
     KVStoreSqlite,
     Sqlite(DbSqliteConfig),
+    KVStorePostgres,
+    Postgres(DbPostgresConfig),
     InMemory,
 }
 
@@ -315,6 +381,62 @@ pub struct MemoryConfig {
     pub oom_retry_config: RetryConfig,
 }
 
+/// Egress policies applied to the outgoing HTTP requests of every worker of an account,
+/// in addition to the egress policy of the component
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct EgressConfig {
+    /// Egress policies by account ID
+    pub accounts: HashMap<String, EgressPolicy>,
+}
+
+impl EgressConfig {
+    pub fn account_policy(&self, account_id: &AccountId) -> Option<&EgressPolicy> {
+        self.accounts.get(&account_id.value)
+    }
+}
+
+/// Optional limits on the number of workers running at the same time. Workers over a limit wait
+/// until one of the running workers in the same scope stops, idle workers are stopped to make room.
+///
+/// A worker blocked in a synchronous RPC call does not count against the limits while waiting for
+/// the result, so a caller cannot deadlock with the worker it is waiting for. Workers awaiting a
+/// promise are suspended and stopped. Workers polling the result of an asynchronous RPC call still
+/// count, as they keep executing.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConcurrencyConfig {
+    /// Maximum number of running workers of the executor
+    pub max_active_workers: Option<usize>,
+    /// Maximum number of running workers of a single component
+    pub max_active_workers_per_component: Option<usize>,
+    /// Maximum number of running workers of a single account
+    pub max_active_workers_per_account: Option<usize>,
+    /// Overrides of `max_active_workers_per_component` by component ID
+    pub components: HashMap<String, usize>,
+    #[serde(with = "humantime_serde")]
+    pub queue_metrics_interval: Duration,
+}
+
+impl ConcurrencyConfig {
+    pub fn component_limit(&self, component_id: &ComponentId) -> Option<usize> {
+        self.components
+            .get(&component_id.to_string())
+            .copied()
+            .or(self.max_active_workers_per_component)
+    }
+}
+
+impl Default for ConcurrencyConfig {
+    fn default() -> Self {
+        Self {
+            max_active_workers: None,
+            max_active_workers_per_component: None,
+            max_active_workers_per_account: None,
+            components: HashMap::new(),
+            queue_metrics_interval: Duration::from_secs(10),
+        }
+    }
+}
+
 impl MemoryConfig {
     pub fn total_system_memory(&self) -> u64 {
         self.system_memory_override.unwrap_or_else(|| {
@@ -352,10 +474,14 @@ impl Default for GolemConfig {
             plugin_service: PluginServiceConfig::default(),
             oplog: OplogConfig::default(),
             suspend: SuspendConfig::default(),
+            graceful_shutdown: GracefulShutdownConfig::default(),
             scheduler: SchedulerConfig::default(),
             active_workers: ActiveWorkersConfig::default(),
             public_worker_api: WorkerServiceGrpcConfig::default(),
             memory: MemoryConfig::default(),
+            egress: EgressConfig::default(),
+            concurrency: ConcurrencyConfig::default(),
+            rpc: RpcConfig::default(),
             grpc_address: "0.0.0.0".to_string(),
             port: 9000,
             http_address: "0.0.0.0".to_string(),
@@ -401,6 +527,26 @@ impl Default for Limits {
             fuel_to_borrow: 10000,
             epoch_interval: Duration::from_millis(10),
             epoch_ticks: 1,
+            instance_allocator: InstanceAllocatorConfig::default(),
+        }
+    }
+}
+
+impl Default for InstanceAllocatorConfig {
+    fn default() -> Self {
+        Self::OnDemand
+    }
+}
+
+impl Default for PoolingAllocatorConfig {
+    fn default() -> Self {
+        Self {
+            total_component_instances: 1000,
+            total_core_instances: 5000,
+            total_memories: 5000,
+            total_tables: 5000,
+            max_memories_per_component: 10,
+            max_tables_per_component: 10,
         }
     }
 }
@@ -408,6 +554,7 @@ impl Default for Limits {
 impl Default for ComponentCacheConfig {
     fn default() -> Self {
         Self {
+            cache_instance_pre: true,
             max_capacity: 32,
             max_metadata_capacity: 16384,
             time_to_idle: Duration::from_secs(12 * 60 * 60),
@@ -493,6 +640,8 @@ impl Default for OplogConfig {
             blob_storage_layers: 1,
             entry_count_limit: 1024,
             archive_interval: Duration::from_secs(60 * 60 * 24), // 24 hours
+            compaction_enabled: false,
+            compaction_dry_run: false,
         }
     }
 }
@@ -505,6 +654,15 @@ impl Default for SuspendConfig {
     }
 }
 
+impl Default for GracefulShutdownConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            drain_timeout: Duration::from_secs(20),
+        }
+    }
+}
+
 impl Default for ActiveWorkersConfig {
     fn default() -> Self {
         Self {
@@ -17,11 +17,14 @@ use std::sync::Arc;
 
 use crate::services::events::Events;
 use crate::services::plugins::Plugins;
+use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
 use crate::workerctx::WorkerCtx;
 use file_loader::FileLoader;
 use golem_common::model::component::ComponentOwner;
 use golem_common::model::plugin::{PluginOwner, PluginScope};
+use golem_service_base::storage::blob::memory::InMemoryBlobStorage;
 use tokio::runtime::Handle;
+use volume::VolumeService;
 
 pub mod active_workers;
 pub mod blob_store;
@@ -35,11 +38,14 @@ pub mod oplog;
 pub mod plugins;
 pub mod promise;
 pub mod rpc;
+pub mod sandbox;
 pub mod scheduler;
 pub mod shard;
 pub mod shard_manager;
+pub mod volume;
 pub mod worker;
 pub mod worker_activator;
+pub mod worker_concurrency;
 pub mod worker_enumeration;
 pub mod worker_event;
 pub mod worker_proxy;
@@ -85,9 +91,10 @@ pub trait HasPromiseService {
     fn promise_service(&self) -> Arc<dyn promise::PromiseService + Send + Sync>;
 }
 
-pub trait HasWasmtimeEngine<Ctx> {
+pub trait HasWasmtimeEngine<Ctx: WorkerCtx> {
     fn engine(&self) -> Arc<wasmtime::Engine>;
     fn linker(&self) -> Arc<wasmtime::component::Linker<Ctx>>;
+    fn instance_pre_cache(&self) -> Arc<component::InstancePreCache<Ctx>>;
     fn runtime(&self) -> Handle;
 }
 
@@ -139,6 +146,10 @@ pub trait HasFileLoader {
     fn file_loader(&self) -> Arc<FileLoader>;
 }
 
+pub trait HasVolumeService {
+    fn volume_service(&self) -> Arc<VolumeService>;
+}
+
 pub trait HasPlugins<Owner: PluginOwner, Scope: PluginScope> {
     fn plugins(&self) -> Arc<dyn Plugins<Owner, Scope> + Send + Sync>;
 }
@@ -168,6 +179,7 @@ pub trait HasAll<Ctx: WorkerCtx>:
     + HasShardManagerService
     + HasShardService
     + HasFileLoader
+    + HasVolumeService
     + HasPlugins<<Ctx::ComponentOwner as ComponentOwner>::PluginOwner, Ctx::PluginScope>
     + HasOplogProcessorPlugin
     + HasExtraDeps<Ctx>
@@ -196,6 +208,7 @@ impl<
             + HasShardManagerService
             + HasShardService
             + HasFileLoader
+            + HasVolumeService
             + HasPlugins<<Ctx::ComponentOwner as ComponentOwner>::PluginOwner, Ctx::PluginScope>
             + HasOplogProcessorPlugin
             + HasExtraDeps<Ctx>
@@ -210,6 +223,7 @@ pub struct All<Ctx: WorkerCtx> {
     active_workers: Arc<active_workers::ActiveWorkers<Ctx>>,
     engine: Arc<wasmtime::Engine>,
     linker: Arc<wasmtime::component::Linker<Ctx>>,
+    instance_pre_cache: Arc<component::InstancePreCache<Ctx>>,
     runtime: Handle,
     component_service: Arc<dyn component::ComponentService + Send + Sync>,
     shard_manager_service: Arc<dyn shard_manager::ShardManagerService + Send + Sync>,
@@ -229,6 +243,7 @@ pub struct All<Ctx: WorkerCtx> {
     worker_proxy: Arc<dyn worker_proxy::WorkerProxy + Send + Sync>,
     events: Arc<Events>,
     file_loader: Arc<FileLoader>,
+    volume_service: Arc<VolumeService>,
     plugins: Arc<
         dyn Plugins<<Ctx::ComponentOwner as ComponentOwner>::PluginOwner, Ctx::PluginScope>
             + Send
@@ -244,6 +259,7 @@ impl<Ctx: WorkerCtx> Clone for All<Ctx> {
             active_workers: self.active_workers.clone(),
             engine: self.engine.clone(),
             linker: self.linker.clone(),
+            instance_pre_cache: self.instance_pre_cache.clone(),
             runtime: self.runtime.clone(),
             component_service: self.component_service.clone(),
             shard_manager_service: self.shard_manager_service.clone(),
@@ -262,6 +278,7 @@ impl<Ctx: WorkerCtx> Clone for All<Ctx> {
             worker_proxy: self.worker_proxy.clone(),
             events: self.events.clone(),
             file_loader: self.file_loader.clone(),
+            volume_service: self.volume_service.clone(),
             plugins: self.plugins.clone(),
             oplog_processor_plugin: self.oplog_processor_plugin.clone(),
             extra_deps: self.extra_deps.clone(),
@@ -275,6 +292,7 @@ impl<Ctx: WorkerCtx> All<Ctx> {
         active_workers: Arc<active_workers::ActiveWorkers<Ctx>>,
         engine: Arc<wasmtime::Engine>,
         linker: Arc<wasmtime::component::Linker<Ctx>>,
+        instance_pre_cache: Arc<component::InstancePreCache<Ctx>>,
         runtime: Handle,
         component_service: Arc<dyn component::ComponentService + Send + Sync>,
         shard_manager_service: Arc<dyn shard_manager::ShardManagerService + Send + Sync>,
@@ -297,6 +315,7 @@ impl<Ctx: WorkerCtx> All<Ctx> {
         worker_proxy: Arc<dyn worker_proxy::WorkerProxy + Send + Sync>,
         events: Arc<Events>,
         file_loader: Arc<FileLoader>,
+        volume_service: Arc<VolumeService>,
         plugins: Arc<
             dyn Plugins<<Ctx::ComponentOwner as ComponentOwner>::PluginOwner, Ctx::PluginScope>
                 + Send
@@ -309,6 +328,7 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             active_workers,
             engine,
             linker,
+            instance_pre_cache,
             runtime,
             component_service,
             shard_manager_service,
@@ -327,6 +347,7 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             worker_proxy,
             events,
             file_loader,
+            volume_service,
             plugins,
             oplog_processor_plugin,
             extra_deps,
@@ -338,6 +359,7 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             this.active_workers(),
             this.engine(),
             this.linker(),
+            this.instance_pre_cache(),
             this.runtime(),
             this.component_service(),
             this.shard_manager_service(),
@@ -356,11 +378,50 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             this.worker_proxy(),
             this.events(),
             this.file_loader(),
+            this.volume_service(),
             this.plugins(),
             this.oplog_processor_plugin(),
             this.extra_deps(),
         )
     }
+
+    /// Creates a copy of the dependencies for running a worker in a sandbox. The worker is not
+    /// registered among the active workers, and its metadata and oplog are stored by the given
+    /// services.
+    ///
+    /// Key-value, blob store and promise state is kept in memory, volume changes are not written
+    /// back, and invoking other workers, scheduling actions and sending the oplog to plugins is
+    /// disabled. Outgoing HTTP requests and socket connections are not intercepted.
+    pub fn sandboxed(
+        &self,
+        worker_service: Arc<dyn worker::WorkerService + Send + Sync>,
+        oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
+    ) -> Self {
+        let key_value_storage = Arc::new(InMemoryKeyValueStorage::new());
+        Self {
+            active_workers: Arc::new(active_workers::ActiveWorkers::new(&self.golem_config)),
+            worker_service,
+            oplog_service,
+            promise_service: Arc::new(promise::DefaultPromiseService::new(
+                key_value_storage.clone(),
+            )),
+            key_value_service: Arc::new(key_value::DefaultKeyValueService::new(key_value_storage)),
+            blob_store_service: Arc::new(blob_store::DefaultBlobStoreService::new(Arc::new(
+                InMemoryBlobStorage::new(),
+            ))),
+            rpc: Arc::new(sandbox::SandboxRpc::new(self.rpc.clone())),
+            scheduler_service: Arc::new(sandbox::SandboxSchedulerService),
+            worker_proxy: Arc::new(sandbox::SandboxWorkerProxy),
+            events: Arc::new(Events::new(
+                self.golem_config
+                    .limits
+                    .invocation_result_broadcast_capacity,
+            )),
+            volume_service: Arc::new(self.volume_service.read_only()),
+            oplog_processor_plugin: Arc::new(sandbox::SandboxOplogProcessorPlugin),
+            ..self.clone()
+        }
+    }
 }
 
 /// Trait to be implemented by services using All to automatically get a HasXXX instance for each dependency
@@ -444,6 +505,10 @@ impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasWasmtimeEngine<Ctx> for T {
         self.all().linker.clone()
     }
 
+    fn instance_pre_cache(&self) -> Arc<component::InstancePreCache<Ctx>> {
+        self.all().instance_pre_cache.clone()
+    }
+
     fn runtime(&self) -> Handle {
         self.all().runtime.clone()
     }
@@ -503,6 +568,12 @@ impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasFileLoader for T {
     }
 }
 
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasVolumeService for T {
+    fn volume_service(&self) -> Arc<VolumeService> {
+        self.all().volume_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>>
     HasPlugins<<Ctx::ComponentOwner as ComponentOwner>::PluginOwner, Ctx::PluginScope> for T
 {
@@ -13,8 +13,11 @@
 // limitations under the License.
 
 use crate::error::GolemError;
+use crate::services::oplog::compaction::{OplogCompaction, OplogCompactionStats};
 use crate::services::oplog::multilayer::OplogArchive;
-use crate::services::oplog::{CompressedOplogChunk, OplogArchiveService};
+use crate::services::oplog::{
+    CompressedOplogArchiveService, CompressedOplogChunk, OplogArchiveService,
+};
 use async_lock::RwLockUpgradableReadGuard;
 use async_trait::async_trait;
 use evicting_cache_map::EvictingCacheMap;
@@ -26,6 +29,7 @@ use golem_service_base::storage::blob::{
 use std::cmp::min;
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicI32, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -213,6 +217,7 @@ struct BlobOplogArchive {
             fn(OplogIndex, OplogEntry) -> (),
         >,
     >,
+    compression_level: AtomicI32,
 }
 
 impl BlobOplogArchive {
@@ -236,6 +241,7 @@ impl BlobOplogArchive {
             created,
             entries,
             cache: RwLock::new(EvictingCacheMap::new()),
+            compression_level: AtomicI32::new(CompressedOplogArchiveService::ZSTD_LEVEL),
         }
     }
 
@@ -438,8 +444,11 @@ impl OplogArchive for BlobOplogArchive {
             let path = self.oplog_index_to_path(oplog_index);
 
             let chunk = chunk.into_iter().map(|(_, entry)| entry).collect();
-            let compressed_chunk = CompressedOplogChunk::compress(chunk)
-                .unwrap_or_else(|err| panic!("failed to compress oplog chunk: {err}"));
+            let compressed_chunk = CompressedOplogChunk::compress(
+                chunk,
+                self.compression_level.load(Ordering::Acquire),
+            )
+            .unwrap_or_else(|err| panic!("failed to compress oplog chunk: {err}"));
 
             let mut entries = self.entries.write().await;
             self.blob_storage.with(
@@ -552,4 +561,81 @@ impl OplogArchive for BlobOplogArchive {
     async fn get_last_index(&self) -> OplogIndex {
         self.current_oplog_index().await
     }
+
+    fn set_compression_level(&self, level: i32) {
+        self.compression_level.store(level, Ordering::Release);
+    }
+
+    fn is_blob_storage(&self) -> bool {
+        true
+    }
+
+    /// Each chunk is stored in a separate blob, so only the changed chunks are overwritten.
+    /// Every blob is replaced atomically, so an interrupted compaction leaves a valid archive.
+    async fn compact(
+        &self,
+        compaction: &OplogCompaction,
+        dry_run: bool,
+    ) -> Result<OplogCompactionStats, String> {
+        let owned_worker_id = &self.owned_worker_id;
+        let namespace = BlobStorageNamespace::CompressedOplog {
+            account_id: owned_worker_id.account_id(),
+            component_id: owned_worker_id.component_id(),
+            level: self.level,
+        };
+
+        let mut stats = OplogCompactionStats::default();
+        let entries = self.entries.read().await;
+        for last_idx in entries.keys() {
+            let path = self.oplog_index_to_path(*last_idx);
+            let chunk: CompressedOplogChunk = self
+                .blob_storage
+                .with("blob_oplog", "compact")
+                .get(namespace.clone(), &path)
+                .await
+                .and_then(|chunk| chunk.ok_or(format!("compressed chunk for {last_idx} not found")))
+                .map_err(|err| {
+                    format!("failed to read compressed oplog for worker {owned_worker_id} in blob storage: {err}")
+                })?;
+            stats.size_before += chunk.compressed_data.len() as u64;
+
+            let compacted = chunk
+                .compact(
+                    *last_idx,
+                    compaction,
+                    self.compression_level.load(Ordering::Acquire),
+                )
+                .map_err(|err| {
+                    format!("failed to compact compressed oplog chunk {last_idx} for worker {owned_worker_id}: {err}")
+                })?;
+            match compacted {
+                Some((compacted_chunk, compacted)) => {
+                    stats.compacted_entries += compacted.indices.len() as u64;
+                    stats.rewritten_chunks += 1;
+                    stats.size_after += compacted_chunk.compressed_data.len() as u64;
+                    stats.orphaned_payloads.extend(compacted.external_payloads);
+
+                    if !dry_run {
+                        self.blob_storage
+                            .with("blob_oplog", "compact")
+                            .put(namespace.clone(), &path, &compacted_chunk)
+                            .await
+                            .map_err(|err| {
+                                format!("failed to store compacted oplog chunk for worker {owned_worker_id} in blob storage: {err}")
+                            })?;
+
+                        let mut cache = self.cache.write().await;
+                        for idx in compacted.indices {
+                            cache.remove(&idx);
+                        }
+                    }
+                }
+                None => {
+                    stats.size_after += chunk.compressed_data.len() as u64;
+                }
+            }
+        }
+
+        Ok(stats)
+    }
 }
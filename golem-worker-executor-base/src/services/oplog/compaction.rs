@@ -0,0 +1,183 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+use std::ops::{AddAssign, RangeInclusive};
+
+use golem_common::model::oplog::{OplogEntry, OplogIndex, OplogPayload};
+use golem_common::model::WorkerStatusRecord;
+
+/// Describes which oplog entries of a worker are never going to be replayed again.
+///
+/// For durable workers these are the regions deleted by jumps. The same criteria apply
+/// regardless of the worker's status, as even the oplog of an exited worker can be replayed again
+/// (for example after reverting it). Ephemeral workers are never recovered, so none of their
+/// recorded imported function results get replayed.
+///
+/// History made obsolete by a snapshot is not covered: the snapshot loaded by a snapshot based
+/// update is not persisted, so recovering the updated worker still replays the entries before
+/// the update.
+///
+/// Within these regions, the recorded results of imported function calls are replaced by `NoOp`
+/// entries, keeping the oplog indices intact. Exported function invocations and their results are
+/// always kept, as they are needed for idempotency key based deduplication.
+#[derive(Debug, Clone)]
+pub struct OplogCompaction {
+    regions: Vec<RangeInclusive<OplogIndex>>,
+}
+
+impl OplogCompaction {
+    /// Determines the replay-irrelevant regions of a durable worker from its last known status.
+    /// Returns `None` if there is nothing to compact.
+    pub fn for_worker(status: &WorkerStatusRecord) -> Option<Self> {
+        let mut deleted_regions = status.deleted_regions.clone();
+        if deleted_regions.is_overridden() {
+            // Overrides (such as the one hiding the history before a pending snapshot based update)
+            // are temporary, the entries under them may still be replayed
+            deleted_regions.drop_override();
+        }
+
+        let regions = deleted_regions
+            .into_regions()
+            .map(|region| region.start..=region.end)
+            .collect::<Vec<_>>();
+
+        if regions.is_empty() {
+            None
+        } else {
+            Some(Self { regions })
+        }
+    }
+
+    /// Covers every entry of an ephemeral worker's oplog up to `last_idx`. Returns `None` if there
+    /// is nothing to compact.
+    pub fn for_ephemeral_worker(last_idx: OplogIndex) -> Option<Self> {
+        if last_idx > OplogIndex::INITIAL {
+            Some(Self {
+                regions: vec![OplogIndex::INITIAL.next()..=last_idx],
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn from_regions(regions: Vec<RangeInclusive<OplogIndex>>) -> Self {
+        Self { regions }
+    }
+
+    /// Returns the replacement of the entry at `idx` if it can be compacted
+    pub fn compact_entry(&self, idx: OplogIndex, entry: &OplogEntry) -> Option<OplogEntry> {
+        match entry {
+            OplogEntry::ImportedFunctionInvoked { timestamp, .. }
+            | OplogEntry::ImportedFunctionInvokedV1 { timestamp, .. }
+                if self.regions.iter().any(|region| region.contains(&idx)) =>
+            {
+                Some(OplogEntry::NoOp {
+                    timestamp: *timestamp,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Compacts a chunk of consecutive entries ending at `last_idx`. The result is empty if the
+    /// chunk was left unchanged.
+    pub fn compact_chunk(
+        &self,
+        last_idx: OplogIndex,
+        entries: &mut [OplogEntry],
+    ) -> CompactedEntries {
+        let first_idx = Into::<u64>::into(last_idx) + 1 - entries.len() as u64;
+        let mut compacted = CompactedEntries::default();
+        for (offset, entry) in entries.iter_mut().enumerate() {
+            let idx = OplogIndex::from_u64(first_idx + offset as u64);
+            if let Some(replacement) = self.compact_entry(idx, entry) {
+                let replaced = std::mem::replace(entry, replacement);
+                compacted.indices.push(idx);
+                compacted
+                    .external_payloads
+                    .extend(external_payloads(replaced));
+            }
+        }
+        compacted
+    }
+}
+
+/// The entries replaced by a compaction
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompactedEntries {
+    pub indices: Vec<OplogIndex>,
+    /// The payloads stored in blob storage which were only referenced by the replaced entries
+    pub external_payloads: Vec<OplogPayload>,
+}
+
+impl CompactedEntries {
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+}
+
+fn external_payloads(entry: OplogEntry) -> Vec<OplogPayload> {
+    let payloads = match entry {
+        OplogEntry::ImportedFunctionInvoked {
+            request, response, ..
+        } => vec![request, response],
+        OplogEntry::ImportedFunctionInvokedV1 { response, .. } => vec![response],
+        _ => vec![],
+    };
+    payloads
+        .into_iter()
+        .filter(|payload| matches!(payload, OplogPayload::External { .. }))
+        .collect()
+}
+
+/// Statistics of a compaction run. In dry-run mode these describe what would have been changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OplogCompactionStats {
+    /// Number of entries replaced by `NoOp` entries
+    pub compacted_entries: u64,
+    /// Number of archived chunks rewritten
+    pub rewritten_chunks: u64,
+    /// Total size of the compressed archived chunks before compaction, in bytes
+    pub size_before: u64,
+    /// Total size of the compressed archived chunks after compaction, in bytes
+    pub size_after: u64,
+    /// The payloads in blob storage referenced only by the replaced entries. They get deleted
+    /// once the rewritten chunks are stored.
+    pub orphaned_payloads: Vec<OplogPayload>,
+}
+
+impl AddAssign for OplogCompactionStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.compacted_entries += rhs.compacted_entries;
+        self.rewritten_chunks += rhs.rewritten_chunks;
+        self.size_before += rhs.size_before;
+        self.size_after += rhs.size_after;
+        self.orphaned_payloads.extend(rhs.orphaned_payloads);
+    }
+}
+
+impl Display for OplogCompactionStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} entries compacted in {} chunks, {} => {} bytes, {} orphaned payloads",
+            self.compacted_entries,
+            self.rewritten_chunks,
+            self.size_before,
+            self.size_after,
+            self.orphaned_payloads.len()
+        )
+    }
+}
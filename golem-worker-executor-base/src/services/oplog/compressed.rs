@@ -14,18 +14,21 @@
 
 use std::cmp::min;
 use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicI32, Ordering};
 use std::sync::Arc;
 
 use async_trait::async_trait;
 use bincode::{Decode, Encode};
 use evicting_cache_map::EvictingCacheMap;
 use tokio::sync::RwLock;
+use tracing::warn;
 
 use crate::error::GolemError;
 use golem_common::model::oplog::{OplogEntry, OplogIndex};
 use golem_common::model::{AccountId, ComponentId, OwnedWorkerId, ScanCursor, WorkerId};
 use golem_common::serialization::{deserialize, serialize};
 
+use crate::services::oplog::compaction::{CompactedEntries, OplogCompaction, OplogCompactionStats};
 use crate::services::oplog::multilayer::{OplogArchive, OplogArchiveService};
 use crate::services::oplog::PrimaryOplogService;
 use crate::storage::indexed::{IndexedStorage, IndexedStorageLabelledApi, IndexedStorageNamespace};
@@ -38,7 +41,8 @@ pub struct CompressedOplogArchiveService {
 
 impl CompressedOplogArchiveService {
     const CACHE_SIZE: usize = 4096;
-    const ZSTD_LEVEL: i32 = 0;
+    /// Default zstd compression level, used unless a component's oplog policy overrides it
+    pub const ZSTD_LEVEL: i32 = 0;
 
     pub fn new(indexed_storage: Arc<dyn IndexedStorage + Send + Sync>, level: usize) -> Self {
         Self {
@@ -63,13 +67,18 @@ impl OplogArchiveService for CompressedOplogArchiveService {
     }
 
     async fn delete(&self, owned_worker_id: &OwnedWorkerId) {
-        self.indexed_storage
-            .with("compressed_oplog", "delete")
-            .delete(IndexedStorageNamespace::CompressedOpLog { level: self.level }, &Self::compressed_oplog_key(&owned_worker_id.worker_id))
-            .await
-            .unwrap_or_else(|err| {
-                panic!("failed to drop compressed oplog for worker {owned_worker_id} in indexed storage: {err}")
-            });
+        for namespace in [
+            IndexedStorageNamespace::CompressedOpLog { level: self.level },
+            IndexedStorageNamespace::CompactedOpLog { level: self.level },
+        ] {
+            self.indexed_storage
+                .with("compressed_oplog", "delete")
+                .delete(namespace, &Self::compressed_oplog_key(&owned_worker_id.worker_id))
+                .await
+                .unwrap_or_else(|err| {
+                    panic!("failed to drop compressed oplog for worker {owned_worker_id} in indexed storage: {err}")
+                });
+        }
     }
 
     async fn read(
@@ -83,16 +92,23 @@ impl OplogArchiveService for CompressedOplogArchiveService {
     }
 
     async fn exists(&self, owned_worker_id: &OwnedWorkerId) -> bool {
-        self.indexed_storage
-            .with("compressed_oplog", "exists")
-            .exists(
-                IndexedStorageNamespace::CompressedOpLog { level: self.level },
-                &Self::compressed_oplog_key(&owned_worker_id.worker_id),
-            )
-            .await
-            .unwrap_or_else(|err| {
-                panic!("failed to check if compressed oplog exists for worker {owned_worker_id} in indexed storage: {err}")
-            })
+        for namespace in [
+            IndexedStorageNamespace::CompressedOpLog { level: self.level },
+            IndexedStorageNamespace::CompactedOpLog { level: self.level },
+        ] {
+            let exists = self
+                .indexed_storage
+                .with("compressed_oplog", "exists")
+                .exists(namespace, &Self::compressed_oplog_key(&owned_worker_id.worker_id))
+                .await
+                .unwrap_or_else(|err| {
+                    panic!("failed to check if compressed oplog exists for worker {owned_worker_id} in indexed storage: {err}")
+                });
+            if exists {
+                return true;
+            }
+        }
+        false
     }
 
     async fn scan_for_component(
@@ -129,16 +145,8 @@ impl OplogArchiveService for CompressedOplogArchiveService {
     }
 
     async fn get_last_index(&self, owned_worker_id: &OwnedWorkerId) -> OplogIndex {
-        let key = Self::compressed_oplog_key(&owned_worker_id.worker_id);
-        OplogIndex::from_u64(
-            self.indexed_storage
-                .with_entity("compressed_oplog", "current_oplog_index", "compressed_entry")
-                .last_id(IndexedStorageNamespace::CompressedOpLog { level: self.level }, &key)
-                .await
-                .unwrap_or_else(|err| {
-                    panic!("failed to get last entry from compressed oplog for worker {owned_worker_id} in indexed storage: {err}")
-                }).unwrap_or_default(),
-        )
+        let archive = self.open(owned_worker_id).await;
+        archive.get_last_index().await
     }
 }
 
@@ -157,6 +165,7 @@ pub struct CompressedOplogArchive {
         >,
     >,
     level: usize,
+    compression_level: AtomicI32,
 }
 
 impl CompressedOplogArchive {
@@ -172,20 +181,34 @@ impl CompressedOplogArchive {
             indexed_storage,
             cache: RwLock::new(EvictingCacheMap::new()),
             level,
+            compression_level: AtomicI32::new(CompressedOplogArchiveService::ZSTD_LEVEL),
         }
     }
 
+    fn namespace(&self) -> IndexedStorageNamespace {
+        IndexedStorageNamespace::CompressedOpLog { level: self.level }
+    }
+
+    fn staging_namespace(&self) -> IndexedStorageNamespace {
+        IndexedStorageNamespace::CompactedOpLog { level: self.level }
+    }
+
     async fn read_and_cache_chunk(&self, idx: OplogIndex) -> Result<Option<OplogIndex>, String> {
-        if let Some((last_idx, chunk)) = self
+        let mut found = self
             .indexed_storage
             .with_entity("compressed_oplog", "read", "compressed_entry")
-            .closest::<CompressedOplogChunk>(
-                IndexedStorageNamespace::CompressedOpLog { level: self.level },
-                &self.key,
-                idx.into(),
-            )
-            .await?
-        {
+            .closest::<CompressedOplogChunk>(self.namespace(), &self.key, idx.into())
+            .await?;
+        if found.is_none() {
+            // The original key may be partially rewritten by an ongoing or interrupted compaction
+            found = self
+                .indexed_storage
+                .with_entity("compressed_oplog", "read", "compressed_entry")
+                .closest::<CompressedOplogChunk>(self.staging_namespace(), &self.key, idx.into())
+                .await?;
+        }
+
+        if let Some((last_idx, chunk)) = found {
             let entries = chunk.decompress()?;
             let mut cache = self.cache.write().await;
 
@@ -200,6 +223,98 @@ impl CompressedOplogArchive {
             Ok(None)
         }
     }
+
+    /// The last chunk id of the layer, also considering the staging key of a compaction
+    async fn last_id(&self) -> Result<Option<u64>, String> {
+        let last_id = self
+            .indexed_storage
+            .with_entity("compressed_oplog", "last_id", "compressed_entry")
+            .last_id(self.namespace(), &self.key)
+            .await?;
+        let staged_last_id = self
+            .indexed_storage
+            .with_entity("compressed_oplog", "last_id", "compressed_entry")
+            .last_id(self.staging_namespace(), &self.key)
+            .await?;
+        Ok(last_id.max(staged_last_id))
+    }
+
+    async fn delete_key(&self, namespace: IndexedStorageNamespace) -> Result<(), String> {
+        let worker_id = &self.worker_id;
+        self.indexed_storage
+            .with("compressed_oplog", "compact")
+            .delete(namespace, &self.key)
+            .await
+            .map_err(|err| {
+                format!("failed to drop compressed oplog for worker {worker_id} in indexed storage: {err}")
+            })
+    }
+
+    /// Replaces the chunks of the original key with the staged ones
+    async fn replace_with_staging(
+        &self,
+        chunks: &[(u64, CompressedOplogChunk)],
+    ) -> Result<(), String> {
+        let worker_id = &self.worker_id;
+        self.delete_key(self.namespace()).await?;
+        for (id, chunk) in chunks {
+            self.indexed_storage
+                .with_entity("compressed_oplog", "compact", "compressed_entry")
+                .append(self.namespace(), &self.key, *id, chunk)
+                .await
+                .map_err(|err| {
+                    format!("failed to append compacted oplog chunk for worker {worker_id} in indexed storage: {err}")
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Finishes a compaction which got interrupted while copying the staged chunks over the
+    /// original key. If the staging key is not longer than the original one, the original key is
+    /// either intact or already fully rewritten, so the staging key is just dropped.
+    async fn recover_interrupted_compaction(&self) -> Result<(), String> {
+        let worker_id = &self.worker_id;
+        let staged_length = self
+            .indexed_storage
+            .with("compressed_oplog", "compact")
+            .length(self.staging_namespace(), &self.key)
+            .await?;
+        if staged_length == 0 {
+            return Ok(());
+        }
+
+        let length = self
+            .indexed_storage
+            .with("compressed_oplog", "compact")
+            .length(self.namespace(), &self.key)
+            .await?;
+        if staged_length > length {
+            warn!(
+                worker_id = worker_id.to_string(),
+                "Finishing interrupted compaction of compressed oplog"
+            );
+            let last_id = self
+                .indexed_storage
+                .with_entity("compressed_oplog", "compact", "compressed_entry")
+                .last_id(self.staging_namespace(), &self.key)
+                .await?
+                .unwrap_or_default();
+            let chunks: Vec<(u64, CompressedOplogChunk)> = self
+                .indexed_storage
+                .with_entity("compressed_oplog", "compact", "compressed_entry")
+                .read(self.staging_namespace(), &self.key, 0, last_id)
+                .await?;
+            self.replace_with_staging(&chunks).await?;
+
+            let mut cache = self.cache.write().await;
+            let cached = cache.iter().map(|(idx, _)| *idx).collect::<Vec<_>>();
+            for idx in cached {
+                cache.remove(&idx);
+            }
+        }
+
+        self.delete_key(self.staging_namespace()).await
+    }
 }
 
 /// Currently only the background-transfer fiber calls `append` and `drop_prefix` on oplog archives,
@@ -252,8 +367,7 @@ impl OplogArchive for CompressedOplogArchive {
                 // We allow to have a gap on the right side of the query - as we cannot guarantee
                 // that the 'n' parameter is exactly matches the available number of elements. However,
                 // there must not be any gaps in the middle.
-                if let Some(idx) = self.indexed_storage.with_entity("compressed_oplog", "read", "compressed_entry")
-                    .last_id(IndexedStorageNamespace::CompressedOpLog { level: self.level }, &self.key)
+                if let Some(idx) = self.last_id()
                     .await
                     .unwrap_or_else(|err| {
                         panic!("failed to get first entry from compressed oplog for worker {worker_id} in indexed storage: {err}")
@@ -275,6 +389,9 @@ impl OplogArchive for CompressedOplogArchive {
     async fn append(&self, chunk: Vec<(OplogIndex, OplogEntry)>) {
         if !chunk.is_empty() {
             let worker_id = &self.worker_id;
+            self.recover_interrupted_compaction().await.unwrap_or_else(|err| {
+                panic!("failed to recover compressed oplog for worker {worker_id} in indexed storage: {err}")
+            });
 
             let mut cache = self.cache.write().await;
             for (idx, entry) in &chunk {
@@ -283,8 +400,11 @@ impl OplogArchive for CompressedOplogArchive {
 
             let last_id = chunk.last().unwrap().0;
             let chunk = chunk.into_iter().map(|(_, entry)| entry).collect();
-            let compressed_chunk = CompressedOplogChunk::compress(chunk)
-                .unwrap_or_else(|err| panic!("failed to compress oplog chunk: {err}"));
+            let compressed_chunk = CompressedOplogChunk::compress(
+                chunk,
+                self.compression_level.load(Ordering::Acquire),
+            )
+            .unwrap_or_else(|err| panic!("failed to compress oplog chunk: {err}"));
 
             self.indexed_storage
                 .with_entity("compressed_oplog", "append", "compressed_entry")
@@ -304,9 +424,7 @@ impl OplogArchive for CompressedOplogArchive {
     async fn current_oplog_index(&self) -> OplogIndex {
         let worker_id = &self.worker_id;
         OplogIndex::from_u64(
-            self.indexed_storage
-                .with_entity("compressed_oplog", "current_oplog_index", "compressed_entry")
-                .last_id(IndexedStorageNamespace::CompressedOpLog { level: self.level }, &self.key)
+            self.last_id()
                 .await
                 .unwrap_or_else(|err| {
                     panic!("failed to get the last entry from compressed oplog for worker {worker_id} in indexed storage: {err}")
@@ -315,6 +433,11 @@ impl OplogArchive for CompressedOplogArchive {
     }
 
     async fn drop_prefix(&self, last_dropped_id: OplogIndex) {
+        let worker_id = &self.worker_id;
+        self.recover_interrupted_compaction().await.unwrap_or_else(|err| {
+            panic!("failed to recover compressed oplog for worker {worker_id} in indexed storage: {err}")
+        });
+
         let mut cache = self.cache.write().await;
 
         let idx_to_evict = cache
@@ -327,7 +450,6 @@ impl OplogArchive for CompressedOplogArchive {
             cache.remove(&idx);
         }
 
-        let worker_id = &self.worker_id;
         self.indexed_storage.with("compressed_oplog", "drop_prefix")
             .drop_prefix(IndexedStorageNamespace::CompressedOpLog { level: self.level }, &self.key, last_dropped_id.into())
             .await
@@ -346,21 +468,114 @@ impl OplogArchive for CompressedOplogArchive {
     }
 
     async fn length(&self) -> u64 {
-        self.indexed_storage
-            .with("compressed_oplog", "length")
-            .length(
-                IndexedStorageNamespace::CompressedOpLog { level: self.level },
-                &self.key,
-            )
-            .await
-            .unwrap_or_else(|err| {
-                panic!("failed to get compressed oplog length from indexed storage: {err}")
-            })
+        let mut length = 0;
+        for namespace in [self.namespace(), self.staging_namespace()] {
+            length = length.max(
+                self.indexed_storage
+                    .with("compressed_oplog", "length")
+                    .length(namespace, &self.key)
+                    .await
+                    .unwrap_or_else(|err| {
+                        panic!("failed to get compressed oplog length from indexed storage: {err}")
+                    }),
+            );
+        }
+        length
     }
 
     async fn get_last_index(&self) -> OplogIndex {
         self.current_oplog_index().await
     }
+
+    fn set_compression_level(&self, level: i32) {
+        self.compression_level.store(level, Ordering::Release);
+    }
+
+    /// Indexed storage only supports appending, so if any chunk changes the whole key is
+    /// rewritten. The compacted chunks are first written to a staging key, and only then copied
+    /// over the original one. While the staging key exists, reads fall back to it for the chunks
+    /// missing from the original key, and an interrupted copy is finished before the next write.
+    async fn compact(
+        &self,
+        compaction: &OplogCompaction,
+        dry_run: bool,
+    ) -> Result<OplogCompactionStats, String> {
+        let worker_id = &self.worker_id;
+
+        if !dry_run {
+            self.recover_interrupted_compaction().await?;
+        }
+
+        let mut stats = OplogCompactionStats::default();
+        let last_id = self
+            .indexed_storage
+            .with_entity("compressed_oplog", "compact", "compressed_entry")
+            .last_id(self.namespace(), &self.key)
+            .await
+            .map_err(|err| {
+                format!("failed to get the last entry from compressed oplog for worker {worker_id} in indexed storage: {err}")
+            })?;
+        let Some(last_id) = last_id else {
+            return Ok(stats);
+        };
+
+        let chunks: Vec<(u64, CompressedOplogChunk)> = self
+            .indexed_storage
+            .with_entity("compressed_oplog", "compact", "compressed_entry")
+            .read(self.namespace(), &self.key, 0, last_id)
+            .await
+            .map_err(|err| {
+                format!("failed to read compressed oplog for worker {worker_id} in indexed storage: {err}")
+            })?;
+
+        let level = self.compression_level.load(Ordering::Acquire);
+        let mut compacted_indices = Vec::new();
+        let mut rewritten = Vec::with_capacity(chunks.len());
+        for (id, chunk) in chunks {
+            stats.size_before += chunk.compressed_data.len() as u64;
+            let chunk = match chunk
+                .compact(OplogIndex::from_u64(id), compaction, level)
+                .map_err(|err| {
+                    format!("failed to compact compressed oplog chunk {id} for worker {worker_id}: {err}")
+                })? {
+                Some((compacted_chunk, compacted)) => {
+                    stats.compacted_entries += compacted.indices.len() as u64;
+                    stats.rewritten_chunks += 1;
+                    stats.orphaned_payloads.extend(compacted.external_payloads);
+                    compacted_indices.extend(compacted.indices);
+                    compacted_chunk
+                }
+                None => chunk,
+            };
+            stats.size_after += chunk.compressed_data.len() as u64;
+            rewritten.push((id, chunk));
+        }
+
+        if !dry_run && stats.rewritten_chunks > 0 {
+            self.delete_key(self.staging_namespace()).await?;
+            for (id, chunk) in &rewritten {
+                self.indexed_storage
+                    .with_entity("compressed_oplog", "compact", "compressed_entry")
+                    .append(self.staging_namespace(), &self.key, *id, chunk)
+                    .await
+                    .map_err(|err| {
+                        format!("failed to append compacted oplog chunk for worker {worker_id} in indexed storage: {err}")
+                    })?;
+            }
+
+            self.replace_with_staging(&rewritten).await?;
+
+            let mut cache = self.cache.write().await;
+            for idx in compacted_indices {
+                cache.remove(&idx);
+            }
+            drop(cache);
+
+            self.delete_key(self.staging_namespace()).await?;
+        }
+
+        Ok(stats)
+    }
 }
 
 #[derive(Debug, Clone, Encode, Decode)]
@@ -370,15 +585,12 @@ pub struct CompressedOplogChunk {
 }
 
 impl CompressedOplogChunk {
-    pub fn compress(entries: Vec<OplogEntry>) -> Result<Self, String> {
+    pub fn compress(entries: Vec<OplogEntry>, level: i32) -> Result<Self, String> {
         let count = entries.len() as u64;
         let uncompressed_data =
             serialize(&entries).map_err(|err| format!("failed to serialize oplog chunk: {err}"))?;
-        let compressed_data = zstd::encode_all(
-            &*uncompressed_data,
-            CompressedOplogArchiveService::ZSTD_LEVEL,
-        )
-        .map_err(|err| format!("failed to compress oplog chunk: {err}"))?;
+        let compressed_data = zstd::encode_all(&*uncompressed_data, level)
+            .map_err(|err| format!("failed to compress oplog chunk: {err}"))?;
         Ok(Self {
             count,
             compressed_data,
@@ -391,4 +603,21 @@ impl CompressedOplogChunk {
         deserialize(&uncompressed_data)
             .map_err(|err| format!("failed to deserialize oplog chunk: {err}"))
     }
+
+    /// Applies the compaction to the entries of this chunk ending at `last_idx`. Returns the
+    /// recompressed chunk and the replaced entries, or `None` if nothing changed.
+    pub fn compact(
+        &self,
+        last_idx: OplogIndex,
+        compaction: &OplogCompaction,
+        level: i32,
+    ) -> Result<Option<(Self, CompactedEntries)>, String> {
+        let mut entries = self.decompress()?;
+        let compacted = compaction.compact_chunk(last_idx, &mut entries);
+        if compacted.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some((Self::compress(entries, level)?, compacted)))
+        }
+    }
 }
@@ -14,7 +14,9 @@
 
 use crate::metrics::oplog::record_oplog_call;
 use crate::services::oplog::multilayer::OplogArchive;
-use crate::services::oplog::{CommitLevel, Oplog};
+use crate::services::oplog::{
+    downcast_oplog, CommitLevel, Oplog, OplogCompaction, OplogCompactionStats,
+};
 use async_mutex::Mutex;
 use async_trait::async_trait;
 use bytes::Bytes;
@@ -88,6 +90,18 @@ impl EphemeralOplog {
             close_fn: Some(close),
         }
     }
+
+    /// Compacts the committed entries of an ephemeral oplog. As ephemeral workers are never
+    /// recovered, everything written before the current oplog index is replay-irrelevant.
+    pub async fn try_compact(
+        this: &Arc<dyn Oplog + Send + Sync>,
+        dry_run: bool,
+    ) -> Option<Result<OplogCompactionStats, String>> {
+        let this = downcast_oplog::<EphemeralOplog>(this)?;
+        let state = this.state.lock().await;
+        let compaction = OplogCompaction::for_ephemeral_worker(state.last_committed_idx)?;
+        Some(state.target.compact(&compaction, dry_run).await)
+    }
 }
 
 impl Drop for EphemeralOplog {
@@ -24,29 +24,35 @@ use async_trait::async_trait;
 use bincode::{Decode, Encode};
 pub use blob::BlobOplogArchiveService;
 use bytes::Bytes;
+pub use compaction::{CompactedEntries, OplogCompaction, OplogCompactionStats};
 pub use compressed::{CompressedOplogArchive, CompressedOplogArchiveService, CompressedOplogChunk};
+pub use ephemeral::EphemeralOplog;
 use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode};
 use golem_common::model::oplog::{
     OplogEntry, OplogIndex, OplogPayload, UpdateDescription, WrappedFunctionType,
 };
 use golem_common::model::{
-    AccountId, ComponentId, ComponentVersion, IdempotencyKey, OwnedWorkerId, ScanCursor, Timestamp,
-    WorkerId, WorkerMetadata,
+    AccountId, ComponentId, ComponentVersion, IdempotencyKey, InvocationOptions, OwnedWorkerId,
+    ScanCursor, Timestamp, WorkerId, WorkerMetadata,
 };
 use golem_common::serialization::{serialize, try_deserialize};
 pub use multilayer::{MultiLayerOplog, MultiLayerOplogService, OplogArchiveService};
 pub use primary::PrimaryOplogService;
+pub use sandbox::SandboxOplogService;
 use tracing::Instrument;
 
 use crate::error::GolemError;
 use crate::model::ExecutionStatus;
 
 mod blob;
+mod compaction;
 mod compressed;
 mod ephemeral;
 mod multilayer;
 pub mod plugin;
 mod primary;
+mod sandbox;
+pub mod sink;
 
 #[cfg(test)]
 mod tests;
@@ -151,6 +157,13 @@ pub trait OplogService: Debug {
         owned_worker_id: &OwnedWorkerId,
         payload: &OplogPayload,
     ) -> Result<Bytes, String>;
+
+    /// Deletes a big oplog payload which is no longer referenced by any oplog entry
+    async fn delete_payload(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        payload: &OplogPayload,
+    ) -> Result<(), String>;
 }
 
 /// Level of commit guarantees
@@ -247,6 +260,7 @@ pub trait OplogOps: Oplog {
         function_name: String,
         request: &R,
         idempotency_key: IdempotencyKey,
+        options: InvocationOptions,
     ) -> Result<OplogEntry, String> {
         let serialized_request = serialize(request)?.to_vec();
 
@@ -256,6 +270,7 @@ pub trait OplogOps: Oplog {
             function_name,
             request: payload,
             idempotency_key,
+            options,
         };
         self.add(entry.clone()).await;
         Ok(entry)
@@ -303,7 +318,8 @@ pub trait OplogOps: Oplog {
                 let response_bytes: Bytes = self.download_payload(response).await?;
                 try_deserialize(&response_bytes)
             }
-            OplogEntry::ExportedFunctionInvoked { request, .. } => {
+            OplogEntry::ExportedFunctionInvokedV1 { request, .. }
+            | OplogEntry::ExportedFunctionInvoked { request, .. } => {
                 let response_bytes: Bytes = self.download_payload(request).await?;
                 try_deserialize(&response_bytes)
             }
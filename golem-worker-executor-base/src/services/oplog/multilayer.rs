@@ -28,13 +28,15 @@ use tracing::{debug, error, info, warn, Instrument};
 
 use crate::error::GolemError;
 use crate::model::ExecutionStatus;
+use crate::services::oplog::compaction::{OplogCompaction, OplogCompactionStats};
 use crate::services::oplog::ephemeral::EphemeralOplog;
 use crate::services::oplog::multilayer::BackgroundTransferMessage::{
-    TransferFromLower, TransferFromPrimary,
+    Compact, TransferFromLower, TransferFromPrimary,
 };
 use crate::services::oplog::{
     downcast_oplog, CommitLevel, OpenOplogs, Oplog, OplogConstructor, OplogService,
 };
+use golem_common::model::component_metadata::OplogPolicy;
 use golem_common::model::oplog::{OplogEntry, OplogIndex, OplogPayload};
 use golem_common::model::{
     AccountId, ComponentId, ComponentType, OwnedWorkerId, ScanCursor, WorkerMetadata,
@@ -111,6 +113,26 @@ pub trait OplogArchive: Debug {
 
     /// Gets the last index in this oplog archive
     async fn get_last_index(&self) -> OplogIndex;
+
+    /// Sets the compression level used for subsequently appended chunks, if the archive
+    /// compresses its entries
+    fn set_compression_level(&self, _level: i32) {}
+
+    /// Returns true if this archive stores its entries in blob storage
+    fn is_blob_storage(&self) -> bool {
+        false
+    }
+
+    /// Rewrites the archived entries which are no longer needed for replaying the worker,
+    /// as described by `compaction`. In dry-run mode the archive is not modified, only the
+    /// statistics are calculated.
+    async fn compact(
+        &self,
+        _compaction: &OplogCompaction,
+        _dry_run: bool,
+    ) -> Result<OplogCompactionStats, String> {
+        Ok(OplogCompactionStats::default())
+    }
 }
 
 #[derive(Debug)]
@@ -462,6 +484,14 @@ impl OplogService for MultiLayerOplogService {
             .download_payload(owned_worker_id, payload)
             .await
     }
+
+    async fn delete_payload(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        payload: &OplogPayload,
+    ) -> Result<(), String> {
+        self.primary.delete_payload(owned_worker_id, payload).await
+    }
 }
 
 pub struct MultiLayerOplog {
@@ -599,21 +629,92 @@ impl MultiLayerOplog {
                         done.send(()).unwrap()
                     }
                 }
+                Compact {
+                    compaction,
+                    dry_run,
+                    mut keep_alive,
+                    done,
+                } => {
+                    info!("Compacting archived oplog layers (dry run: {dry_run})");
+
+                    let mut result = Ok(OplogCompactionStats::default());
+                    for layer in &lower {
+                        match layer.compact(&compaction, dry_run).await {
+                            Ok(layer_stats) => {
+                                if let Ok(stats) = &mut result {
+                                    *stats += layer_stats;
+                                }
+                            }
+                            Err(err) => {
+                                // The layers are independent, so the rest still get compacted
+                                error!("Failed to compact archived oplog layer: {err}");
+                                result = Err(err);
+                            }
+                        }
+                    }
+                    let _ = keep_alive.take();
+                    let _ = done.send(result);
+                }
             }
         }
     }
 
     pub async fn try_archive(this: &Arc<dyn Oplog + Send + Sync>) -> Option<bool> {
+        Self::try_archive_with_policy(this, None).await
+    }
+
+    /// Archives the first non-empty layer, respecting the component's oplog policy
+    pub async fn try_archive_with_policy(
+        this: &Arc<dyn Oplog + Send + Sync>,
+        policy: Option<&OplogPolicy>,
+    ) -> Option<bool> {
         let this = downcast_oplog::<MultiLayerOplog>(this)?;
-        Some(Self::archive(this, false).await)
+        Some(Self::archive(this, false, policy).await)
     }
 
     pub async fn try_archive_blocking(this: &Arc<dyn Oplog + Send + Sync>) -> Option<bool> {
         let this = downcast_oplog::<MultiLayerOplog>(this)?;
-        Some(Self::archive(this, true).await)
+        Some(Self::archive(this, true, None).await)
+    }
+
+    /// Compacts the archived layers of the oplog. The compaction is performed by the background
+    /// transfer fiber, so it never runs concurrently with transfers between the layers.
+    pub async fn try_compact(
+        this: &Arc<dyn Oplog + Send + Sync>,
+        compaction: OplogCompaction,
+        dry_run: bool,
+    ) -> Option<Result<OplogCompactionStats, String>> {
+        let this = downcast_oplog::<MultiLayerOplog>(this)?;
+        let (done_tx, done_rx) = tokio::sync::oneshot::channel();
+        if let Err(err) = this.transfer.send(Compact {
+            compaction,
+            dry_run,
+            keep_alive: Some(this.clone()),
+            done: done_tx,
+        }) {
+            return Some(Err(format!(
+                "failed to enqueue compaction of the oplog: {err}"
+            )));
+        }
+        Some(
+            done_rx
+                .await
+                .unwrap_or_else(|_| Err("the oplog compaction was cancelled".to_string())),
+        )
     }
 
-    async fn archive(this: Arc<Self>, blocking: bool) -> bool {
+    async fn archive(this: Arc<Self>, blocking: bool, policy: Option<&OplogPolicy>) -> bool {
+        if let Some(level) = policy.and_then(|policy| policy.compression_level) {
+            for layer in &this.lower {
+                layer.set_compression_level(level);
+            }
+        }
+        let allows_blob_storage = policy
+            .map(|policy| policy.allows_blob_storage())
+            .unwrap_or(true);
+        let can_transfer_to =
+            |layer: usize| allows_blob_storage || !this.lower[layer].is_blob_storage();
+
         let (done_tx, done_rx) = if blocking {
             let (done_tx, done_rx) = tokio::sync::oneshot::channel();
             (Some(done_tx), Some(done_rx))
@@ -621,6 +722,10 @@ impl MultiLayerOplog {
             (None, None)
         };
         let result = if this.primary_length.load(Ordering::Acquire) > 0 {
+            if !can_transfer_to(0) {
+                return false;
+            }
+
             // transferring the whole primary oplog to the next layer
             this.transfer
                 .send(TransferFromPrimary {
@@ -631,7 +736,7 @@ impl MultiLayerOplog {
                 .expect("Failed to enqueue transfer of primary oplog entries");
 
             // If there are more layers to transfer from, return true
-            this.lower.len().get() > 1
+            this.lower.len().get() > 1 && can_transfer_to(1)
         } else {
             let mut n = 0;
             let first_non_empty = loop {
@@ -647,6 +752,11 @@ impl MultiLayerOplog {
             };
 
             if let Some(first_non_empty) = first_non_empty {
+                if !can_transfer_to(first_non_empty + 1) {
+                    // The policy does not allow moving the entries to the next layer
+                    return false;
+                }
+
                 // transferring the whole non-empty lower layer to the next layer
                 this.transfer
                     .send(TransferFromLower {
@@ -660,7 +770,7 @@ impl MultiLayerOplog {
                     .expect("Failed to enqueue transfer of primary oplog entries");
 
                 // If there are more layers to transfer from, return true
-                first_non_empty < this.lower.len().get() - 2
+                first_non_empty < this.lower.len().get() - 2 && can_transfer_to(first_non_empty + 2)
             } else {
                 // Fully archived
                 false
@@ -772,6 +882,12 @@ enum BackgroundTransferMessage {
         keep_alive: Option<Arc<dyn Oplog + Send + Sync>>,
         done: Option<Sender<()>>,
     },
+    Compact {
+        compaction: OplogCompaction,
+        dry_run: bool,
+        keep_alive: Option<Arc<dyn Oplog + Send + Sync>>,
+        done: Sender<Result<OplogCompactionStats, String>>,
+    },
 }
 
 #[async_trait]
@@ -870,6 +986,22 @@ impl OplogArchive for WrappedOplogArchive {
     async fn get_last_index(&self) -> OplogIndex {
         self.archive.get_last_index().await
     }
+
+    fn set_compression_level(&self, level: i32) {
+        self.archive.set_compression_level(level)
+    }
+
+    fn is_blob_storage(&self) -> bool {
+        self.archive.is_blob_storage()
+    }
+
+    async fn compact(
+        &self,
+        compaction: &OplogCompaction,
+        dry_run: bool,
+    ) -> Result<OplogCompactionStats, String> {
+        self.archive.compact(compaction, dry_run).await
+    }
 }
 
 struct BackgroundTransferFromPrimary {
@@ -16,6 +16,7 @@ use crate::error::GolemError;
 use crate::model::public_oplog::PublicOplogEntryOps;
 use crate::model::ExecutionStatus;
 use crate::services::component::ComponentService;
+use crate::services::oplog::sink::OplogSinks;
 use crate::services::oplog::{CommitLevel, OpenOplogs, Oplog, OplogConstructor, OplogService};
 use crate::services::plugins::Plugins;
 use crate::services::shard::ShardService;
@@ -31,13 +32,13 @@ use bytes::Bytes;
 use golem_common::model::component::ComponentOwner;
 use golem_common::model::oplog::{OplogEntry, OplogIndex, OplogPayload};
 use golem_common::model::plugin::{
-    OplogProcessorDefinition, PluginDefinition, PluginOwner, PluginScope,
+    OplogProcessorDefinition, PluginDefinition, PluginInstallation, PluginOwner, PluginScope,
     PluginTypeSpecificDefinition,
 };
 use golem_common::model::public_oplog::PublicOplogEntry;
 use golem_common::model::{
-    AccountId, ComponentId, ComponentVersion, IdempotencyKey, OwnedWorkerId, PluginInstallationId,
-    ScanCursor, ShardId, TargetWorkerId, WorkerId, WorkerMetadata,
+    AccountId, ComponentId, ComponentVersion, IdempotencyKey, InvocationOptions, OwnedWorkerId,
+    PluginInstallationId, ScanCursor, ShardId, TargetWorkerId, WorkerId, WorkerMetadata,
 };
 use golem_wasm_rpc::{IntoValue, Value};
 use std::collections::hash_map::Entry;
@@ -58,9 +59,30 @@ pub trait OplogProcessorPlugin {
         entries: Vec<PublicOplogEntry>,
     ) -> Result<(), GolemError>;
 
+    /// Gets how far the plugin installation got in processing the worker's oplog. For the plugins
+    /// tracking their progress per worker, the unprocessed entries are sent again when the
+    /// worker's oplog gets opened, so the entries which were still buffered when the executor
+    /// stopped are not lost.
+    async fn progress(
+        &self,
+        worker_metadata: &WorkerMetadata,
+        plugin_installation_id: &PluginInstallationId,
+    ) -> Result<OplogProcessorProgress, GolemError>;
+
     async fn on_shard_assignment_changed(&self) -> Result<(), GolemError>;
 }
 
+/// How far an oplog processor plugin installation got in processing a worker's oplog
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OplogProcessorProgress {
+    /// The plugin does not track its progress per worker
+    Untracked,
+    /// The plugin has not processed any entries of the worker yet
+    NotStarted,
+    /// The plugin has processed the entries before the given oplog index
+    FirstUnprocessed(OplogIndex),
+}
+
 /// An implementation of the `OplogProcessorPlugin` trait that runs a single instance of each
 /// used plugin on each worker executor node.
 pub struct PerExecutorOplogProcessorPlugin<Ctx: WorkerCtx> {
@@ -74,6 +96,7 @@ pub struct PerExecutorOplogProcessorPlugin<Ctx: WorkerCtx> {
             + Send
             + Sync,
     >,
+    sinks: Arc<OplogSinks>,
 }
 
 type WorkerKey = (AccountId, String, String);
@@ -95,6 +118,7 @@ impl<Ctx: WorkerCtx> PerExecutorOplogProcessorPlugin<Ctx> {
                 + Send
                 + Sync,
         >,
+        sinks: Arc<OplogSinks>,
     ) -> Self {
         Self {
             workers: Arc::new(RwLock::new(HashMap::new())),
@@ -102,26 +126,19 @@ impl<Ctx: WorkerCtx> PerExecutorOplogProcessorPlugin<Ctx> {
             shard_service,
             worker_activator,
             plugins,
+            sinks,
         }
     }
 
     async fn resolve_plugin_worker(
         &self,
         account_id: &AccountId,
-        component_id: &ComponentId,
-        component_version: ComponentVersion,
-        plugin_installation_id: &PluginInstallationId,
+        installation: &PluginInstallation,
+        definition: &PluginDefinition<
+            <Ctx::ComponentOwner as ComponentOwner>::PluginOwner,
+            Ctx::PluginScope,
+        >,
     ) -> Result<RunningPlugin, GolemError> {
-        let (installation, definition) = self
-            .plugins
-            .get(
-                account_id,
-                component_id,
-                component_version,
-                plugin_installation_id,
-            )
-            .await?;
-
         let workers = self.workers.upgradable_read().await;
         let key = (
             account_id.clone(),
@@ -136,7 +153,7 @@ impl<Ctx: WorkerCtx> PerExecutorOplogProcessorPlugin<Ctx> {
                     Some(worker_id) => Ok(worker_id.clone()),
                     None => {
                         let (plugin_component_id, plugin_component_version) =
-                            Self::get_oplog_processor_component_id(&definition)?;
+                            Self::get_oplog_processor_component_id(definition)?;
                         let worker_id = self.generate_worker_id_for(&plugin_component_id).await?;
                         let owned_worker_id = OwnedWorkerId {
                             account_id: account_id.clone(),
@@ -198,8 +215,9 @@ impl<Ctx: WorkerCtx> OplogProcessorPlugin for PerExecutorOplogProcessorPlugin<Ct
         initial_oplog_index: OplogIndex,
         entries: Vec<PublicOplogEntry>,
     ) -> Result<(), GolemError> {
-        let running_plugin = self
-            .resolve_plugin_worker(
+        let (installation, definition) = self
+            .plugins
+            .get(
                 &worker_metadata.account_id,
                 &worker_metadata.worker_id.component_id,
                 worker_metadata.last_known_status.component_version,
@@ -207,6 +225,36 @@ impl<Ctx: WorkerCtx> OplogProcessorPlugin for PerExecutorOplogProcessorPlugin<Ct
             )
             .await?;
 
+        match &definition.specs {
+            PluginTypeSpecificDefinition::OplogArchiveSink(_) => {
+                return self
+                    .sinks
+                    .archive(
+                        &worker_metadata,
+                        &installation,
+                        initial_oplog_index,
+                        entries,
+                    )
+                    .await;
+            }
+            PluginTypeSpecificDefinition::OplogWebhookSink(_) => {
+                return self
+                    .sinks
+                    .webhook(
+                        &worker_metadata,
+                        &installation,
+                        initial_oplog_index,
+                        entries,
+                    )
+                    .await;
+            }
+            _ => {}
+        }
+
+        let running_plugin = self
+            .resolve_plugin_worker(&worker_metadata.account_id, &installation, &definition)
+            .await?;
+
         let worker = self
             .worker_activator
             .get_or_create_running(
@@ -258,12 +306,50 @@ impl<Ctx: WorkerCtx> OplogProcessorPlugin for PerExecutorOplogProcessorPlugin<Ct
         ];
 
         worker
-            .invoke(idempotency_key, function_name, function_input)
+            .invoke(
+                idempotency_key,
+                function_name,
+                function_input,
+                InvocationOptions::default(),
+            )
             .await?;
 
         Ok(())
     }
 
+    async fn progress(
+        &self,
+        worker_metadata: &WorkerMetadata,
+        plugin_installation_id: &PluginInstallationId,
+    ) -> Result<OplogProcessorProgress, GolemError> {
+        let (installation, definition) = self
+            .plugins
+            .get(
+                &worker_metadata.account_id,
+                &worker_metadata.worker_id.component_id,
+                worker_metadata.last_known_status.component_version,
+                plugin_installation_id,
+            )
+            .await?;
+
+        match &definition.specs {
+            PluginTypeSpecificDefinition::OplogArchiveSink(_)
+            | PluginTypeSpecificDefinition::OplogWebhookSink(_) => {
+                let first_unexported = self
+                    .sinks
+                    .first_unexported(&worker_metadata.worker_id, &installation.id)
+                    .await?;
+                Ok(match first_unexported {
+                    Some(first_unexported) => {
+                        OplogProcessorProgress::FirstUnprocessed(first_unexported)
+                    }
+                    None => OplogProcessorProgress::NotStarted,
+                })
+            }
+            _ => Ok(OplogProcessorProgress::Untracked),
+        }
+    }
+
     async fn on_shard_assignment_changed(&self) -> Result<(), GolemError> {
         let new_assignment = self.shard_service.current_assignment()?;
 
@@ -302,6 +388,7 @@ impl<Ctx: WorkerCtx> Clone for PerExecutorOplogProcessorPlugin<Ctx> {
             shard_service: self.shard_service.clone(),
             worker_activator: self.worker_activator.clone(),
             plugins: self.plugins.clone(),
+            sinks: self.sinks.clone(),
         }
     }
 }
@@ -584,6 +671,7 @@ impl<Owner: PluginOwner, Scope: PluginScope> ForwardingOplog<Owner, Scope> {
     ) -> Self {
         let state = Arc::new(Mutex::new(ForwardingOplogState {
             buffer: VecDeque::new(),
+            resumed: false,
             commit_count: 0,
             last_send: Instant::now(),
             oplog_plugins,
@@ -602,7 +690,9 @@ impl<Owner: PluginOwner, Scope: PluginScope> ForwardingOplog<Owner, Scope> {
                 loop {
                     tokio::time::sleep(MAX_ELAPSED_TIME).await;
                     let mut state = state.lock().await;
-                    if !state.buffer.is_empty() && state.last_send.elapsed() > MAX_ELAPSED_TIME {
+                    if (!state.resumed || !state.buffer.is_empty())
+                        && state.last_send.elapsed() > MAX_ELAPSED_TIME
+                    {
                         state.send_buffer().await;
                     }
                 }
@@ -683,6 +773,9 @@ impl<Owner: PluginOwner, Scope: PluginScope> Oplog for ForwardingOplog<Owner, Sc
 
 struct ForwardingOplogState<Owner: PluginOwner, Scope: PluginScope> {
     buffer: VecDeque<OplogEntry>,
+    /// Whether the entries added before opening the oplog, which have not been processed by the
+    /// plugins tracking their progress, have been sent again
+    resumed: bool,
     commit_count: usize,
     last_send: Instant,
     oplog_plugins: Arc<dyn OplogProcessorPlugin + Send + Sync>,
@@ -707,6 +800,21 @@ impl<Owner: PluginOwner, Scope: PluginScope> ForwardingOplogState<Owner, Scope>
 
         let active_plugins = metadata.last_known_status.active_plugins();
         if !active_plugins.is_empty() {
+            if !self.resumed {
+                // The unprocessed entries preceding the buffer have to be sent first, otherwise
+                // the plugins would record the buffer as processed and skip them
+                if let Err(err) = self.resume(&metadata).await {
+                    log::error!("Failed to resend unprocessed oplog entries: {}", err);
+                    self.last_send = Instant::now();
+                    return;
+                }
+                self.resumed = true;
+            }
+            if self.buffer.is_empty() {
+                self.last_send = Instant::now();
+                return;
+            }
+
             let entries: Vec<_> = self.buffer.drain(..).collect();
             let initial_oplog_index =
                 OplogIndex::from_u64(Into::<u64>::into(self.last_oplog_idx) - entries.len() as u64);
@@ -716,11 +824,10 @@ impl<Owner: PluginOwner, Scope: PluginScope> ForwardingOplogState<Owner, Scope>
                 .await
             {
                 log::error!("Failed to send oplog entries: {}", err);
-                // In case of an error we keep the unsent entries in the buffer.
-                // This does not guarantee that we don't double-send entries (in case the error happened
-                // only for one of the `send` calls, for example) - this is going to be handled
-                // better in future versions where the last known oplog index will be tracked for
-                // each active plugin.
+                // In case of an error we keep the unsent entries in the buffer and send them again
+                // to every active plugin. The plugins tracking their progress per worker, such as
+                // the oplog sinks, skip the entries they have already processed; the others may
+                // receive some of the entries twice.
                 self.buffer.extend(entries);
             } else {
                 self.last_send = Instant::now();
@@ -733,12 +840,138 @@ impl<Owner: PluginOwner, Scope: PluginScope> ForwardingOplogState<Owner, Scope>
         }
     }
 
+    /// Sends the entries which were added to the oplog before it got opened, and which have not
+    /// been processed yet by the plugins tracking their progress, such as the oplog sinks.
+    ///
+    /// Plugins which have not processed anything yet get every entry since they got activated
+    /// for the worker, and plugins which got reactivated since their last processed entry get
+    /// the entries since the reactivation.
+    async fn resume(&self, metadata: &WorkerMetadata) -> Result<(), GolemError> {
+        let first_buffered = OplogIndex::from_u64(
+            Into::<u64>::into(self.last_oplog_idx) - self.buffer.len() as u64 + 1,
+        );
+
+        for installation_id in metadata.last_known_status.active_plugins() {
+            let progress = self
+                .oplog_plugins
+                .progress(metadata, installation_id)
+                .await?;
+
+            let first_unprocessed = match progress {
+                OplogProcessorProgress::Untracked => continue,
+                OplogProcessorProgress::NotStarted => {
+                    self.activation_index(
+                        metadata,
+                        installation_id,
+                        OplogIndex::INITIAL,
+                        first_buffered,
+                    )
+                    .await
+                }
+                OplogProcessorProgress::FirstUnprocessed(first_unprocessed) => {
+                    self.activation_index(
+                        metadata,
+                        installation_id,
+                        first_unprocessed,
+                        first_buffered,
+                    )
+                    .await
+                }
+            };
+
+            if first_unprocessed < first_buffered {
+                let entries = self
+                    .oplog_service
+                    .read(
+                        &metadata.owned_worker_id(),
+                        first_unprocessed,
+                        Into::<u64>::into(first_buffered) - Into::<u64>::into(first_unprocessed),
+                    )
+                    .await;
+
+                if let Some(initial_oplog_index) = entries.keys().next().cloned() {
+                    let entries = entries.into_values().collect::<Vec<_>>();
+                    let public_entries = self.to_public_entries(metadata, &entries).await?;
+                    self.oplog_plugins
+                        .send(
+                            metadata.clone(),
+                            installation_id,
+                            initial_oplog_index,
+                            public_entries,
+                        )
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds the index of the last `ActivatePlugin` entry of the given plugin installation between
+    /// `from` (inclusive) and `until` (exclusive), searching backwards. If there is none, the
+    /// plugin has been active since before `from` and `from` is returned.
+    async fn activation_index(
+        &self,
+        metadata: &WorkerMetadata,
+        installation_id: &PluginInstallationId,
+        from: OplogIndex,
+        until: OplogIndex,
+    ) -> OplogIndex {
+        const CHUNK_SIZE: u64 = 1024;
+
+        let owned_worker_id = metadata.owned_worker_id();
+        let from = u64::from(from);
+        let mut end = u64::from(until);
+        while end > from {
+            let start = end.saturating_sub(CHUNK_SIZE).max(from);
+            let entries = self
+                .oplog_service
+                .read(&owned_worker_id, OplogIndex::from_u64(start), end - start)
+                .await;
+            let activation = entries
+                .into_iter()
+                .rev()
+                .find_map(|(idx, entry)| match entry {
+                    OplogEntry::ActivatePlugin { plugin, .. } if &plugin == installation_id => {
+                        Some(idx)
+                    }
+                    _ => None,
+                });
+            if let Some(activation) = activation {
+                return activation;
+            }
+            end = start;
+        }
+        OplogIndex::from_u64(from)
+    }
+
     async fn try_send_entries(
         &self,
         metadata: WorkerMetadata,
         initial_oplog_index: OplogIndex,
         entries: &[OplogEntry],
     ) -> Result<(), GolemError> {
+        let public_entries = self.to_public_entries(&metadata, entries).await?;
+
+        for installation_id in metadata.last_known_status.active_plugins() {
+            self.oplog_plugins
+                .send(
+                    metadata.clone(),
+                    installation_id,
+                    initial_oplog_index,
+                    public_entries.clone(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn to_public_entries(
+        &self,
+        metadata: &WorkerMetadata,
+        entries: &[OplogEntry],
+    ) -> Result<Vec<PublicOplogEntry>, GolemError> {
         let mut public_entries = Vec::new();
 
         for entry in entries {
@@ -760,17 +993,6 @@ impl<Owner: PluginOwner, Scope: PluginScope> ForwardingOplogState<Owner, Scope>
             public_entries.push(public_entry);
         }
 
-        for installation_id in metadata.last_known_status.active_plugins() {
-            self.oplog_plugins
-                .send(
-                    metadata.clone(),
-                    installation_id,
-                    initial_oplog_index,
-                    public_entries.clone(),
-                )
-                .await?;
-        }
-
-        Ok(())
+        Ok(public_entries)
     }
 }
@@ -351,6 +351,32 @@ impl OplogService for PrimaryOplogService {
     ) -> Result<Bytes, String> {
         Self::download_payload(self.blob_storage.clone(), owned_worker_id, payload).await
     }
+
+    async fn delete_payload(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        payload: &OplogPayload,
+    ) -> Result<(), String> {
+        match payload {
+            OplogPayload::Inline(_) => Ok(()),
+            OplogPayload::External {
+                payload_id,
+                md5_hash,
+            } => {
+                self.blob_storage
+                    .delete(
+                        "oplog",
+                        "delete_payload",
+                        BlobStorageNamespace::OplogPayload {
+                            account_id: owned_worker_id.account_id(),
+                            worker_id: owned_worker_id.worker_id(),
+                        },
+                        Path::new(&format!("{}/{}", hex::encode(md5_hash), payload_id.0)),
+                    )
+                    .await
+            }
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -0,0 +1,353 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use golem_common::model::oplog::{OplogEntry, OplogIndex, OplogPayload};
+use golem_common::model::{AccountId, ComponentId, OwnedWorkerId, ScanCursor, WorkerMetadata};
+
+use crate::error::GolemError;
+use crate::model::ExecutionStatus;
+use crate::services::oplog::{CommitLevel, Oplog, OplogService};
+
+/// Oplog service replaying a single worker in a sandbox, used for dry-running updates.
+///
+/// Entries of the replayed worker up to `replay_until` are read from its real oplog. Entries
+/// added after that, including the ones given at construction, are only kept in memory and big
+/// payloads are inlined instead of being uploaded, so nothing is ever written to the real oplog.
+/// The oplogs of any other worker created in the sandbox are entirely kept in memory.
+pub struct SandboxOplogService {
+    inner: Arc<dyn OplogService + Send + Sync>,
+    owned_worker_id: OwnedWorkerId,
+    replay_until: OplogIndex,
+    state: Arc<SandboxOplogState>,
+    others: RwLock<HashMap<OwnedWorkerId, Arc<SandboxOplogState>>>,
+}
+
+struct SandboxOplogState {
+    added: RwLock<Vec<OplogEntry>>,
+}
+
+impl SandboxOplogState {
+    fn new(added: Vec<OplogEntry>) -> Arc<Self> {
+        Arc::new(Self {
+            added: RwLock::new(added),
+        })
+    }
+}
+
+impl SandboxOplogService {
+    pub fn new(
+        inner: Arc<dyn OplogService + Send + Sync>,
+        owned_worker_id: OwnedWorkerId,
+        replay_until: OplogIndex,
+        added: Vec<OplogEntry>,
+    ) -> Self {
+        Self {
+            inner,
+            owned_worker_id,
+            replay_until,
+            state: SandboxOplogState::new(added),
+            others: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Finds the last index of the worker's oplog which is not in the middle of an invocation.
+    ///
+    /// Replaying further would make the sandboxed worker switch to live mode while finishing the
+    /// last invocation, performing its side effects again.
+    pub async fn replay_boundary(
+        inner: &Arc<dyn OplogService + Send + Sync>,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> OplogIndex {
+        const CHUNK_SIZE: u64 = 1024;
+
+        let last_index = inner.get_last_index(owned_worker_id).await;
+        let mut end = last_index;
+        while end > OplogIndex::NONE {
+            let start = OplogIndex::from_u64(
+                u64::from(end)
+                    .saturating_sub(CHUNK_SIZE - 1)
+                    .max(u64::from(OplogIndex::INITIAL)),
+            );
+            let entries = inner.read_range(owned_worker_id, start, end).await;
+            for (idx, entry) in entries.iter().rev() {
+                match entry {
+                    OplogEntry::ExportedFunctionCompleted { .. } => return last_index,
+                    OplogEntry::ExportedFunctionInvokedV1 { .. }
+                    | OplogEntry::ExportedFunctionInvoked { .. } => return idx.previous(),
+                    _ => {}
+                }
+            }
+            end = start.previous();
+        }
+        last_index
+    }
+
+    /// The entries added to the replayed worker's oplog after `replay_until`
+    pub fn added_entries(&self) -> Vec<OplogEntry> {
+        self.state.added.read().unwrap().clone()
+    }
+
+    /// The last index read from the real oplog, and the in-memory state of a worker's oplog.
+    /// Other workers never read from the real oplog.
+    fn worker_state(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Option<(OplogIndex, Arc<SandboxOplogState>)> {
+        if owned_worker_id == &self.owned_worker_id {
+            Some((self.replay_until, self.state.clone()))
+        } else {
+            self.others
+                .read()
+                .unwrap()
+                .get(owned_worker_id)
+                .map(|state| (OplogIndex::NONE, state.clone()))
+        }
+    }
+
+    fn sandbox_oplog(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        replay_until: OplogIndex,
+        state: Arc<SandboxOplogState>,
+    ) -> Arc<dyn Oplog + Send + Sync + 'static> {
+        Arc::new(SandboxOplog {
+            inner: self.inner.clone(),
+            owned_worker_id: owned_worker_id.clone(),
+            replay_until,
+            state,
+        })
+    }
+}
+
+impl Debug for SandboxOplogService {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SandboxOplogService")
+            .field("owned_worker_id", &self.owned_worker_id)
+            .field("replay_until", &self.replay_until)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl OplogService for SandboxOplogService {
+    async fn create(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        initial_entry: OplogEntry,
+        _initial_worker_metadata: WorkerMetadata,
+        _execution_status: Arc<std::sync::RwLock<ExecutionStatus>>,
+    ) -> Arc<dyn Oplog + Send + Sync + 'static> {
+        if owned_worker_id == &self.owned_worker_id {
+            // The replayed worker already exists
+            self.sandbox_oplog(owned_worker_id, self.replay_until, self.state.clone())
+        } else {
+            let state = SandboxOplogState::new(vec![initial_entry]);
+            self.others
+                .write()
+                .unwrap()
+                .insert(owned_worker_id.clone(), state.clone());
+            self.sandbox_oplog(owned_worker_id, OplogIndex::NONE, state)
+        }
+    }
+
+    async fn open(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        _last_oplog_index: OplogIndex,
+        _initial_worker_metadata: WorkerMetadata,
+        _execution_status: Arc<std::sync::RwLock<ExecutionStatus>>,
+    ) -> Arc<dyn Oplog + Send + Sync + 'static> {
+        let (replay_until, state) = match self.worker_state(owned_worker_id) {
+            Some(worker_state) => worker_state,
+            None => {
+                let state = self
+                    .others
+                    .write()
+                    .unwrap()
+                    .entry(owned_worker_id.clone())
+                    .or_insert_with(|| SandboxOplogState::new(vec![]))
+                    .clone();
+                (OplogIndex::NONE, state)
+            }
+        };
+        self.sandbox_oplog(owned_worker_id, replay_until, state)
+    }
+
+    async fn get_last_index(&self, owned_worker_id: &OwnedWorkerId) -> OplogIndex {
+        self.worker_state(owned_worker_id)
+            .map(|(replay_until, state)| last_index(replay_until, &state))
+            .unwrap_or(OplogIndex::NONE)
+    }
+
+    async fn delete(&self, owned_worker_id: &OwnedWorkerId) {
+        if owned_worker_id == &self.owned_worker_id {
+            self.state.added.write().unwrap().clear();
+        } else {
+            self.others.write().unwrap().remove(owned_worker_id);
+        }
+    }
+
+    async fn read(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idx: OplogIndex,
+        n: u64,
+    ) -> BTreeMap<OplogIndex, OplogEntry> {
+        let Some((replay_until, state)) = self.worker_state(owned_worker_id) else {
+            return BTreeMap::new();
+        };
+
+        let last_idx = idx.range_end(n);
+        let mut result = BTreeMap::new();
+        if idx <= replay_until {
+            let until = last_idx.min(replay_until);
+            result = self.inner.read_range(owned_worker_id, idx, until).await;
+        }
+        if last_idx > replay_until {
+            let added = state.added.read().unwrap();
+            let mut current = replay_until;
+            for entry in added.iter() {
+                current = current.next();
+                if current >= idx && current <= last_idx {
+                    result.insert(current, entry.clone());
+                }
+            }
+        }
+        result
+    }
+
+    async fn exists(&self, owned_worker_id: &OwnedWorkerId) -> bool {
+        self.worker_state(owned_worker_id).is_some()
+    }
+
+    async fn scan_for_component(
+        &self,
+        _account_id: &AccountId,
+        _component_id: &ComponentId,
+        _cursor: ScanCursor,
+        _count: u64,
+    ) -> Result<(ScanCursor, Vec<OwnedWorkerId>), GolemError> {
+        // Sandboxed workers are never enumerated
+        Ok((ScanCursor::default(), vec![]))
+    }
+
+    async fn upload_payload(
+        &self,
+        _owned_worker_id: &OwnedWorkerId,
+        data: &[u8],
+    ) -> Result<OplogPayload, String> {
+        Ok(OplogPayload::Inline(data.to_vec()))
+    }
+
+    async fn download_payload(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        payload: &OplogPayload,
+    ) -> Result<Bytes, String> {
+        self.inner.download_payload(owned_worker_id, payload).await
+    }
+
+    async fn delete_payload(
+        &self,
+        _owned_worker_id: &OwnedWorkerId,
+        _payload: &OplogPayload,
+    ) -> Result<(), String> {
+        // The payloads belong to the real oplog, which the sandbox never changes
+        Ok(())
+    }
+}
+
+/// The open oplog of a sandboxed worker
+struct SandboxOplog {
+    inner: Arc<dyn OplogService + Send + Sync>,
+    owned_worker_id: OwnedWorkerId,
+    replay_until: OplogIndex,
+    state: Arc<SandboxOplogState>,
+}
+
+impl Debug for SandboxOplog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SandboxOplog")
+            .field("owned_worker_id", &self.owned_worker_id)
+            .field("replay_until", &self.replay_until)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Oplog for SandboxOplog {
+    async fn add(&self, entry: OplogEntry) {
+        self.state.added.write().unwrap().push(entry);
+    }
+
+    async fn drop_prefix(&self, _last_dropped_id: OplogIndex) {}
+
+    async fn commit(&self, _level: CommitLevel) {}
+
+    async fn current_oplog_index(&self) -> OplogIndex {
+        last_index(self.replay_until, &self.state)
+    }
+
+    async fn wait_for_replicas(&self, _replicas: u8, _timeout: Duration) -> bool {
+        true
+    }
+
+    async fn read(&self, oplog_index: OplogIndex) -> OplogEntry {
+        if oplog_index <= self.replay_until {
+            self.inner
+                .read(&self.owned_worker_id, oplog_index, 1)
+                .await
+                .remove(&oplog_index)
+        } else {
+            let offset = u64::from(oplog_index) - u64::from(self.replay_until) - 1;
+            self.state
+                .added
+                .read()
+                .unwrap()
+                .get(offset as usize)
+                .cloned()
+        }
+        .unwrap_or_else(|| {
+            panic!(
+                "missing oplog entry {oplog_index} in the sandbox of worker {}",
+                self.owned_worker_id
+            )
+        })
+    }
+
+    async fn length(&self) -> u64 {
+        last_index(self.replay_until, &self.state).into()
+    }
+
+    async fn upload_payload(&self, data: &[u8]) -> Result<OplogPayload, String> {
+        Ok(OplogPayload::Inline(data.to_vec()))
+    }
+
+    async fn download_payload(&self, payload: &OplogPayload) -> Result<Bytes, String> {
+        self.inner
+            .download_payload(&self.owned_worker_id, payload)
+            .await
+    }
+}
+
+fn last_index(replay_until: OplogIndex, state: &SandboxOplogState) -> OplogIndex {
+    OplogIndex::from_u64(u64::from(replay_until) + state.added.read().unwrap().len() as u64)
+}
@@ -0,0 +1,487 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::GolemError;
+use crate::services::component::ComponentService;
+use crate::storage::keyvalue::{
+    KeyValueStorage, KeyValueStorageLabelledApi, KeyValueStorageNamespace,
+};
+use bincode::{Decode, Encode};
+use golem_common::config::RetryConfig;
+use golem_common::model::oplog::OplogIndex;
+use golem_common::model::plugin::{
+    OplogArchiveSinkDefinition, OplogWebhookSinkDefinition, PluginInstallation,
+};
+use golem_common::model::public_oplog::PublicOplogEntry;
+use golem_common::model::secret::SecretReference;
+use golem_common::model::{PluginInstallationId, Timestamp, WorkerId, WorkerMetadata};
+use golem_common::retries::with_retries;
+use golem_service_base::storage::blob::{
+    BlobStorage, BlobStorageLabelledApi, BlobStorageNamespace,
+};
+use reqwest::StatusCode;
+use serde::Serialize;
+use std::fmt::{Debug, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::debug;
+
+/// Built-in oplog processor plugins exporting the oplog entries directly from the worker
+/// executor, without running a plugin component.
+///
+/// Both sinks remember the last oplog index they have successfully exported per worker and
+/// plugin installation, and skip the already exported entries when a batch is sent again
+/// after a failure. The forwarding oplog retries failed batches, and resends the entries
+/// following the last exported one when a worker's oplog gets opened again, for example after
+/// an executor restart, which gives at-least-once delivery.
+pub struct OplogSinks {
+    blob_storage: Arc<dyn BlobStorage + Send + Sync>,
+    key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+    component_service: Arc<dyn ComponentService + Send + Sync>,
+    http_client: reqwest::Client,
+    retry_config: RetryConfig,
+}
+
+impl OplogSinks {
+    const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(30);
+
+    pub fn new(
+        blob_storage: Arc<dyn BlobStorage + Send + Sync>,
+        key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+        component_service: Arc<dyn ComponentService + Send + Sync>,
+        retry_config: RetryConfig,
+    ) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(Self::WEBHOOK_TIMEOUT)
+            .build()
+            .expect("Failed to create HTTP client for the oplog webhook sink");
+
+        Self {
+            blob_storage,
+            key_value_storage,
+            component_service,
+            http_client,
+            retry_config,
+        }
+    }
+
+    /// Appends the not yet exported entries as JSON lines to the current archive segment, named
+    /// after the first oplog index it contains.
+    ///
+    /// A new segment is started when appending the batch would make the current one exceed the
+    /// installation's maximum segment size, or when the current one reached its maximum age.
+    /// A batch sent again after a failure only appends the entries which were not exported yet,
+    /// so entries are only archived twice if writing the export progress fails.
+    pub async fn archive(
+        &self,
+        worker_metadata: &WorkerMetadata,
+        installation: &PluginInstallation,
+        initial_oplog_index: OplogIndex,
+        entries: Vec<PublicOplogEntry>,
+    ) -> Result<(), GolemError> {
+        let max_segment_size =
+            OplogArchiveSinkDefinition::max_segment_size(&installation.parameters)
+                .map_err(GolemError::runtime)?;
+        let max_segment_age = OplogArchiveSinkDefinition::max_segment_age(&installation.parameters)
+            .map_err(GolemError::runtime)?;
+
+        let key = Self::state_key(&worker_metadata.worker_id, &installation.id);
+        let mut state = self.get_state(&key).await?;
+        let Some((first_index, entries)) = state.unexported(initial_oplog_index, entries) else {
+            return Ok(());
+        };
+
+        let mut data = Vec::new();
+        let mut oplog_index = first_index;
+        for entry in &entries {
+            serde_json::to_writer(&mut data, &ArchivedOplogEntry { oplog_index, entry }).map_err(
+                |err| GolemError::runtime(format!("Failed to serialize oplog entry: {err}")),
+            )?;
+            data.push(b'\n');
+            oplog_index = oplog_index.next();
+        }
+        let last_index = oplog_index.previous();
+
+        let namespace = BlobStorageNamespace::OplogExport {
+            account_id: worker_metadata.account_id.clone(),
+            component_id: worker_metadata.worker_id.component_id.clone(),
+        };
+        let dir =
+            Path::new(&worker_metadata.worker_id.worker_name).join(installation.id.to_string());
+        let blob_storage = self.blob_storage.with("oplog_sink", "archive");
+
+        let now = Timestamp::now_utc();
+        let segment = match state.segment.take() {
+            Some(segment)
+                if !segment.is_full(data.len() as u64, max_segment_size, max_segment_age, now) =>
+            {
+                segment
+            }
+            _ => ArchiveSegment {
+                first_index,
+                size: 0,
+                created_at: now,
+            },
+        };
+        let path = Self::archive_segment(&dir, segment.first_index);
+
+        let data = if segment.size > 0 {
+            let existing = blob_storage
+                .get_raw(namespace.clone(), &path)
+                .await
+                .map_err(|err| {
+                    GolemError::runtime(format!("Failed to read oplog archive segment: {err}"))
+                })?
+                .unwrap_or_default();
+            [existing.as_ref(), &data[..]].concat()
+        } else {
+            data
+        };
+
+        blob_storage
+            .put_raw(namespace, &path, &data)
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to write oplog archive segment: {err}"))
+            })?;
+
+        debug!(
+            "Archived {} oplog entries of {} starting from {} to {}",
+            entries.len(),
+            worker_metadata.worker_id,
+            first_index,
+            path.display()
+        );
+
+        state.last_exported = Some(last_index);
+        state.segment = Some(ArchiveSegment {
+            size: data.len() as u64,
+            ..segment
+        });
+        self.set_state(&key, &state).await
+    }
+
+    /// Posts the not yet exported entries as a single JSON batch to the configured `url`,
+    /// retrying failed requests according to the executor's retry configuration.
+    pub async fn webhook(
+        &self,
+        worker_metadata: &WorkerMetadata,
+        installation: &PluginInstallation,
+        initial_oplog_index: OplogIndex,
+        entries: Vec<PublicOplogEntry>,
+    ) -> Result<(), GolemError> {
+        OplogWebhookSinkDefinition::validate_parameters(&installation.parameters)
+            .map_err(GolemError::runtime)?;
+        let url = installation.parameters[OplogWebhookSinkDefinition::URL_PARAMETER].clone();
+
+        let key = Self::state_key(&worker_metadata.worker_id, &installation.id);
+        let mut state = self.get_state(&key).await?;
+        let Some((first_index, entries)) = state.unexported(initial_oplog_index, entries) else {
+            return Ok(());
+        };
+
+        let authorization = match installation
+            .parameters
+            .get(OplogWebhookSinkDefinition::AUTHORIZATION_PARAMETER)
+            .and_then(|value| SecretReference::parse(value))
+        {
+            Some(secret) => {
+                let mut values = self
+                    .component_service
+                    .resolve_secrets(
+                        &worker_metadata.worker_id.component_id,
+                        &[secret.to_string()],
+                    )
+                    .await?;
+                Some(values.remove(secret).ok_or_else(|| {
+                    GolemError::runtime(format!(
+                        "Unknown secret {secret} used by the oplog webhook sink"
+                    ))
+                })?)
+            }
+            None => None,
+        };
+
+        let body = serde_json::to_vec(&WebhookBatch {
+            worker_id: &worker_metadata.worker_id,
+            plugin_installation_id: &installation.id,
+            first_entry_index: first_index,
+            entries: &entries,
+        })
+        .map_err(|err| GolemError::runtime(format!("Failed to serialize oplog entries: {err}")))?;
+
+        with_retries(
+            "oplog_sink",
+            "webhook",
+            Some(worker_metadata.worker_id.to_string()),
+            &self.retry_config,
+            &(self.http_client.clone(), url, authorization, body),
+            |(client, url, authorization, body)| {
+                Box::pin(async move {
+                    let mut request = client
+                        .post(url)
+                        .header(reqwest::header::CONTENT_TYPE, "application/json")
+                        .body(body.clone());
+                    if let Some(authorization) = authorization {
+                        request = request.header(reqwest::header::AUTHORIZATION, authorization);
+                    }
+                    let response = request.send().await?;
+                    if response.status().is_success() {
+                        Ok(())
+                    } else {
+                        Err(WebhookError::Status(response.status()))
+                    }
+                })
+            },
+            WebhookError::is_retriable,
+        )
+        .await
+        .map_err(|err| GolemError::runtime(format!("Failed to send oplog entries: {err}")))?;
+
+        state.last_exported = Some(OplogIndex::from_u64(
+            u64::from(first_index) + entries.len() as u64 - 1,
+        ));
+        self.set_state(&key, &state).await
+    }
+
+    /// Gets the first oplog index which has not been exported yet, if anything has been exported
+    /// for the worker and plugin installation
+    pub async fn first_unexported(
+        &self,
+        worker_id: &WorkerId,
+        plugin_installation_id: &PluginInstallationId,
+    ) -> Result<Option<OplogIndex>, GolemError> {
+        let state = self
+            .get_state(&Self::state_key(worker_id, plugin_installation_id))
+            .await?;
+        Ok(state
+            .last_exported
+            .map(|last_exported| last_exported.next()))
+    }
+
+    fn state_key(worker_id: &WorkerId, plugin_installation_id: &PluginInstallationId) -> String {
+        format!("{}:{}", worker_id.to_redis_key(), plugin_installation_id)
+    }
+
+    fn archive_segment(dir: &Path, first_index: OplogIndex) -> PathBuf {
+        dir.join(format!("{:020}.jsonl", u64::from(first_index)))
+    }
+
+    async fn get_state(&self, key: &str) -> Result<OplogSinkState, GolemError> {
+        Ok(self
+            .key_value_storage
+            .with_entity("oplog_sink", "get_state", "oplog_sink_state")
+            .get(KeyValueStorageNamespace::OplogSink, key)
+            .await
+            .map_err(GolemError::runtime)?
+            .unwrap_or_default())
+    }
+
+    async fn set_state(&self, key: &str, state: &OplogSinkState) -> Result<(), GolemError> {
+        self.key_value_storage
+            .with_entity("oplog_sink", "set_state", "oplog_sink_state")
+            .set(KeyValueStorageNamespace::OplogSink, key, state)
+            .await
+            .map_err(GolemError::runtime)
+    }
+}
+
+impl Debug for OplogSinks {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OplogSinks").finish()
+    }
+}
+
+/// Export progress of a sink for a single worker and plugin installation
+#[derive(Debug, Clone, Default, PartialEq, Encode, Decode)]
+struct OplogSinkState {
+    /// The last oplog index which has been successfully exported
+    last_exported: Option<OplogIndex>,
+    /// The archive segment the archive sink currently appends to
+    segment: Option<ArchiveSegment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+struct ArchiveSegment {
+    /// The first oplog index stored in the segment, also used as its name
+    first_index: OplogIndex,
+    /// The segment's size in bytes
+    size: u64,
+    created_at: Timestamp,
+}
+
+impl ArchiveSegment {
+    /// Checks whether a new segment has to be started instead of appending `additional_size`
+    /// bytes to this one. A single batch larger than the limit still gets its own segment.
+    fn is_full(
+        &self,
+        additional_size: u64,
+        max_size: u64,
+        max_age: Duration,
+        now: Timestamp,
+    ) -> bool {
+        let age = now.to_millis().saturating_sub(self.created_at.to_millis());
+        self.size + additional_size > max_size || age >= max_age.as_millis() as u64
+    }
+}
+
+impl OplogSinkState {
+    /// Drops the entries which have already been exported, returning the index of the first
+    /// remaining entry and the remaining entries, or `None` if there is nothing to export.
+    fn unexported(
+        &self,
+        initial_oplog_index: OplogIndex,
+        entries: Vec<PublicOplogEntry>,
+    ) -> Option<(OplogIndex, Vec<PublicOplogEntry>)> {
+        let skip = match self.last_exported {
+            Some(last_exported) if last_exported >= initial_oplog_index => {
+                (u64::from(last_exported) - u64::from(initial_oplog_index) + 1) as usize
+            }
+            _ => 0,
+        };
+        if skip >= entries.len() {
+            None
+        } else {
+            let first_index = OplogIndex::from_u64(u64::from(initial_oplog_index) + skip as u64);
+            Some((first_index, entries.into_iter().skip(skip).collect()))
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArchivedOplogEntry<'a> {
+    oplog_index: OplogIndex,
+    entry: &'a PublicOplogEntry,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WebhookBatch<'a> {
+    worker_id: &'a WorkerId,
+    plugin_installation_id: &'a PluginInstallationId,
+    first_entry_index: OplogIndex,
+    entries: &'a [PublicOplogEntry],
+}
+
+#[derive(Debug, thiserror::Error)]
+enum WebhookError {
+    #[error("Request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("Webhook responded with {0}")]
+    Status(StatusCode),
+}
+
+impl WebhookError {
+    fn is_retriable(&self) -> bool {
+        match self {
+            WebhookError::Request(_) => true,
+            WebhookError::Status(status) => {
+                status.is_server_error() || *status == StatusCode::TOO_MANY_REQUESTS
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::{ArchiveSegment, OplogSinkState, OplogSinks};
+    use golem_common::model::oplog::OplogIndex;
+    use golem_common::model::public_oplog::{PublicOplogEntry, TimestampParameter};
+    use golem_common::model::Timestamp;
+    use std::path::Path;
+    use std::time::Duration;
+
+    fn entries(n: usize) -> Vec<PublicOplogEntry> {
+        (0..n)
+            .map(|_| {
+                PublicOplogEntry::Suspend(TimestampParameter {
+                    timestamp: Timestamp::now_utc(),
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn unexported_without_state_keeps_everything() {
+        let state = OplogSinkState::default();
+        let (first, remaining) = state
+            .unexported(OplogIndex::from_u64(5), entries(3))
+            .unwrap();
+        assert_eq!(first, OplogIndex::from_u64(5));
+        assert_eq!(remaining.len(), 3);
+    }
+
+    #[test]
+    fn unexported_skips_exported_prefix() {
+        let state = OplogSinkState {
+            last_exported: Some(OplogIndex::from_u64(6)),
+            segment: None,
+        };
+        let (first, remaining) = state
+            .unexported(OplogIndex::from_u64(5), entries(4))
+            .unwrap();
+        assert_eq!(first, OplogIndex::from_u64(7));
+        assert_eq!(remaining.len(), 2);
+    }
+
+    #[test]
+    fn unexported_returns_none_for_fully_exported_batch() {
+        let state = OplogSinkState {
+            last_exported: Some(OplogIndex::from_u64(8)),
+            segment: None,
+        };
+        assert!(state
+            .unexported(OplogIndex::from_u64(5), entries(4))
+            .is_none());
+    }
+
+    #[test]
+    fn archive_segments_are_ordered_by_oplog_index() {
+        let dir = Path::new("worker");
+        let first = OplogSinks::archive_segment(dir, OplogIndex::from_u64(9));
+        let second = OplogSinks::archive_segment(dir, OplogIndex::from_u64(120));
+        assert_eq!(first, Path::new("worker/00000000000000000009.jsonl"));
+        assert!(first < second);
+    }
+
+    #[test]
+    fn archive_segment_is_full_when_exceeding_size() {
+        let now = Timestamp::now_utc();
+        let segment = ArchiveSegment {
+            first_index: OplogIndex::from_u64(1),
+            size: 900,
+            created_at: now,
+        };
+        let max_age = Duration::from_secs(60);
+        assert!(!segment.is_full(100, 1000, max_age, now));
+        assert!(segment.is_full(101, 1000, max_age, now));
+    }
+
+    #[test]
+    fn archive_segment_is_full_when_exceeding_age() {
+        let created_at = Timestamp::from(1_000_000);
+        let segment = ArchiveSegment {
+            first_index: OplogIndex::from_u64(1),
+            size: 0,
+            created_at,
+        };
+        let max_age = Duration::from_secs(60);
+        assert!(!segment.is_full(10, 1000, max_age, Timestamp::from(1_059_999)));
+        assert!(segment.is_full(10, 1000, max_age, Timestamp::from(1_060_000)));
+    }
+}
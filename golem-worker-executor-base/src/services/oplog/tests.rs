@@ -23,17 +23,20 @@ use tracing::{debug, info};
 use uuid::Uuid;
 
 use golem_common::config::RedisConfig;
+use golem_common::model::component_metadata::OplogPolicy;
 use golem_common::model::oplog::WorkerError;
-use golem_common::model::regions::OplogRegion;
-use golem_common::model::{ComponentId, ComponentType, WorkerStatusRecord};
+use golem_common::model::regions::{DeletedRegions, OplogRegion};
+use golem_common::model::{
+    ComponentId, ComponentType, InvocationOptions, WorkerStatus, WorkerStatusRecord,
+};
 use golem_common::redis::RedisPool;
 use golem_common::tracing::{init_tracing, TracingConfig};
 
-use crate::services::oplog::compressed::CompressedOplogArchiveService;
-use crate::services::oplog::multilayer::OplogArchiveService;
+use crate::services::oplog::compressed::{CompressedOplogArchive, CompressedOplogArchiveService};
+use crate::services::oplog::multilayer::{OplogArchive, OplogArchiveService};
 use crate::storage::indexed::memory::InMemoryIndexedStorage;
 use crate::storage::indexed::redis::RedisIndexedStorage;
-use crate::storage::indexed::IndexedStorage;
+use crate::storage::indexed::{IndexedStorage, IndexedStorageLabelledApi, IndexedStorageNamespace};
 use golem_service_base::storage::blob::memory::InMemoryBlobStorage;
 
 use super::*;
@@ -128,12 +131,12 @@ fn rounded(entry: OplogEntry) -> OplogEntry {
             response,
             wrapped_function_type,
         },
-        OplogEntry::ExportedFunctionInvoked {
+        OplogEntry::ExportedFunctionInvokedV1 {
             timestamp,
             function_name,
             request,
             idempotency_key,
-        } => OplogEntry::ExportedFunctionInvoked {
+        } => OplogEntry::ExportedFunctionInvokedV1 {
             timestamp: rounded_ts(timestamp),
             function_name,
             request,
@@ -281,6 +284,54 @@ fn rounded(entry: OplogEntry) -> OplogEntry {
             timestamp: rounded_ts(timestamp),
             plugin,
         },
+        OplogEntry::UpdateLabels { timestamp, labels } => OplogEntry::UpdateLabels {
+            timestamp: rounded_ts(timestamp),
+            labels,
+        },
+        OplogEntry::PutFile {
+            timestamp,
+            path,
+            content,
+        } => OplogEntry::PutFile {
+            timestamp: rounded_ts(timestamp),
+            path,
+            content,
+        },
+        OplogEntry::DeleteFile { timestamp, path } => OplogEntry::DeleteFile {
+            timestamp: rounded_ts(timestamp),
+            path,
+        },
+        OplogEntry::MakeDirectory { timestamp, path } => OplogEntry::MakeDirectory {
+            timestamp: rounded_ts(timestamp),
+            path,
+        },
+        OplogEntry::CancelPendingInvocation {
+            timestamp,
+            idempotency_key,
+        } => OplogEntry::CancelPendingInvocation {
+            timestamp: rounded_ts(timestamp),
+            idempotency_key,
+        },
+        OplogEntry::ExportedFunctionInvoked {
+            timestamp,
+            function_name,
+            request,
+            idempotency_key,
+            options,
+        } => OplogEntry::ExportedFunctionInvoked {
+            timestamp: rounded_ts(timestamp),
+            function_name,
+            request,
+            idempotency_key,
+            options,
+        },
+        OplogEntry::InvocationTimedOut {
+            timestamp,
+            idempotency_key,
+        } => OplogEntry::InvocationTimedOut {
+            timestamp: rounded_ts(timestamp),
+            idempotency_key,
+        },
     }
 }
 
@@ -454,6 +505,7 @@ async fn entries_with_small_payload(_tracing: &Tracing) {
                 "f2".to_string(),
                 &"request".to_string(),
                 IdempotencyKey::fresh(),
+                InvocationOptions::default(),
             )
             .await
             .unwrap(),
@@ -573,6 +625,7 @@ async fn entries_with_large_payload(_tracing: &Tracing) {
                 "f2".to_string(),
                 &large_payload2,
                 IdempotencyKey::fresh(),
+                InvocationOptions::default(),
             )
             .await
             .unwrap(),
@@ -1581,6 +1634,518 @@ async fn scheduled_archive_impl(use_blob: bool) {
     assert_eq!(last_oplog_index_2, last_oplog_index_3);
 }
 
+#[test]
+async fn scheduled_archive_respects_blob_storage_policy(_tracing: &Tracing) {
+    let indexed_storage = Arc::new(InMemoryIndexedStorage::new());
+    let blob_storage = Arc::new(InMemoryBlobStorage::new());
+    let primary_oplog_service = Arc::new(
+        PrimaryOplogService::new(indexed_storage.clone(), blob_storage.clone(), 1, 100).await,
+    );
+    let secondary_layer: Arc<dyn OplogArchiveService + Send + Sync> = Arc::new(
+        CompressedOplogArchiveService::new(indexed_storage.clone(), 1),
+    );
+    let tertiary_layer: Arc<dyn OplogArchiveService + Send + Sync> =
+        Arc::new(BlobOplogArchiveService::new(blob_storage.clone(), 2));
+    let oplog_service = Arc::new(MultiLayerOplogService::new(
+        primary_oplog_service.clone(),
+        nev![secondary_layer.clone(), tertiary_layer.clone()],
+        1000, // no transfer will occur by reaching limit in this test
+        10,
+    ));
+    let account_id = AccountId {
+        value: "user1".to_string(),
+    };
+    let worker_id = WorkerId {
+        component_id: ComponentId(Uuid::new_v4()),
+        worker_name: "test".to_string(),
+    };
+    let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+    let policy = OplogPolicy {
+        archive_interval_seconds: None,
+        compression_level: Some(3),
+        archive_to_blob_storage: Some(false),
+        exited_worker_ttl_seconds: None,
+    };
+
+    let timestamp = Timestamp::now_utc();
+    let last_oplog_index = oplog_service.get_last_index(&owned_worker_id).await;
+    let oplog = oplog_service
+        .open(
+            &owned_worker_id,
+            last_oplog_index,
+            WorkerMetadata::default(worker_id.clone(), account_id.clone()),
+            default_execution_status(ComponentType::Durable),
+        )
+        .await;
+    for i in 0..100 {
+        oplog
+            .add(rounded(OplogEntry::Error {
+                timestamp,
+                error: WorkerError::Unknown(i.to_string()),
+            }))
+            .await;
+    }
+    oplog.commit(CommitLevel::Always).await;
+
+    let archive_result1 = MultiLayerOplog::try_archive_with_policy(&oplog, Some(&policy)).await;
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    let archive_result2 = MultiLayerOplog::try_archive_with_policy(&oplog, Some(&policy)).await;
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    drop(oplog);
+
+    let secondary_length = secondary_layer.open(&owned_worker_id).await.length().await;
+    let tertiary_length = tertiary_layer.open(&owned_worker_id).await.length().await;
+
+    assert_eq!(archive_result1, Some(false));
+    assert_eq!(archive_result2, Some(false));
+    assert_eq!(secondary_length, 1);
+    assert_eq!(tertiary_length, 0);
+}
+
+#[test]
+async fn compaction_of_archived_layers(_tracing: &Tracing) {
+    let indexed_storage = Arc::new(InMemoryIndexedStorage::new());
+    let blob_storage = Arc::new(InMemoryBlobStorage::new());
+    let primary_oplog_service = Arc::new(
+        PrimaryOplogService::new(indexed_storage.clone(), blob_storage.clone(), 1, 100).await,
+    );
+    let secondary_layer: Arc<dyn OplogArchiveService + Send + Sync> = Arc::new(
+        CompressedOplogArchiveService::new(indexed_storage.clone(), 1),
+    );
+    let tertiary_layer: Arc<dyn OplogArchiveService + Send + Sync> =
+        Arc::new(BlobOplogArchiveService::new(blob_storage.clone(), 2));
+    let oplog_service = Arc::new(MultiLayerOplogService::new(
+        primary_oplog_service.clone(),
+        nev![secondary_layer.clone(), tertiary_layer.clone()],
+        1000, // no transfer will occur by reaching limit in this test
+        10,
+    ));
+    let account_id = AccountId {
+        value: "user1".to_string(),
+    };
+    let worker_id = WorkerId {
+        component_id: ComponentId(Uuid::new_v4()),
+        worker_name: "test".to_string(),
+    };
+    let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+    let last_oplog_index = oplog_service.get_last_index(&owned_worker_id).await;
+    let oplog = oplog_service
+        .open(
+            &owned_worker_id,
+            last_oplog_index,
+            WorkerMetadata::default(worker_id.clone(), account_id.clone()),
+            default_execution_status(ComponentType::Durable),
+        )
+        .await;
+
+    // Each invocation takes 3 entries: exported function invoked, imported function invoked
+    // and exported function completed
+    for i in 0..10 {
+        oplog
+            .add_exported_function_invoked(
+                "f1".to_string(),
+                &i.to_string(),
+                IdempotencyKey::fresh(),
+                InvocationOptions::default(),
+            )
+            .await
+            .unwrap();
+        oplog
+            .add_imported_function_invoked(
+                "f2".to_string(),
+                &"request".to_string(),
+                &"response".to_string(),
+                WrappedFunctionType::ReadRemote,
+            )
+            .await
+            .unwrap();
+        oplog
+            .add_exported_function_completed(&i.to_string(), 42)
+            .await
+            .unwrap();
+    }
+    oplog.commit(CommitLevel::Always).await;
+
+    // Moving everything to the compressed layer
+    MultiLayerOplog::try_archive_blocking(&oplog).await;
+    let original = oplog_service
+        .read(&owned_worker_id, OplogIndex::INITIAL, 30)
+        .await;
+
+    let compaction =
+        OplogCompaction::from_regions(vec![OplogIndex::from_u64(1)..=OplogIndex::from_u64(15)]);
+    let dry_run_stats = MultiLayerOplog::try_compact(&oplog, compaction.clone(), true)
+        .await
+        .unwrap()
+        .unwrap();
+    let after_dry_run = oplog_service
+        .read(&owned_worker_id, OplogIndex::INITIAL, 30)
+        .await;
+
+    let stats1 = MultiLayerOplog::try_compact(&oplog, compaction, false)
+        .await
+        .unwrap()
+        .unwrap();
+    let after_compaction1 = oplog_service
+        .read(&owned_worker_id, OplogIndex::INITIAL, 30)
+        .await;
+
+    // Moving everything to the blob layer and compacting the whole oplog
+    MultiLayerOplog::try_archive_blocking(&oplog).await;
+    let stats2 = MultiLayerOplog::try_compact(
+        &oplog,
+        OplogCompaction::from_regions(vec![OplogIndex::from_u64(1)..=OplogIndex::from_u64(30)]),
+        false,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    let after_compaction2 = oplog_service
+        .read(&owned_worker_id, OplogIndex::INITIAL, 30)
+        .await;
+    drop(oplog);
+
+    let compacted_indices = |entries: &BTreeMap<OplogIndex, OplogEntry>| {
+        entries
+            .iter()
+            .filter(|(_, entry)| matches!(entry, OplogEntry::NoOp { .. }))
+            .map(|(idx, _)| u64::from(*idx))
+            .collect::<Vec<_>>()
+    };
+    let exported_entries = |entries: &BTreeMap<OplogIndex, OplogEntry>| {
+        entries
+            .values()
+            .filter(|entry| {
+                matches!(
+                    entry,
+                    OplogEntry::ExportedFunctionInvoked { .. }
+                        | OplogEntry::ExportedFunctionCompleted { .. }
+                )
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(dry_run_stats.compacted_entries, 5);
+    assert_eq!(dry_run_stats.rewritten_chunks, 1);
+    assert_eq!(after_dry_run, original);
+
+    assert_eq!(stats1, dry_run_stats);
+    assert_eq!(compacted_indices(&after_compaction1), vec![2, 5, 8, 11, 14]);
+
+    assert_eq!(stats2.compacted_entries, 5);
+    assert_eq!(
+        compacted_indices(&after_compaction2),
+        vec![2, 5, 8, 11, 14, 17, 20, 23, 26, 29]
+    );
+    assert_eq!(after_compaction2.len(), 30);
+    assert_eq!(
+        exported_entries(&after_compaction2),
+        exported_entries(&original)
+    );
+}
+
+#[test]
+async fn compaction_criteria_do_not_depend_on_worker_status(_tracing: &Tracing) {
+    let imported = OplogEntry::ImportedFunctionInvoked {
+        timestamp: Timestamp::now_utc(),
+        function_name: "f".to_string(),
+        request: OplogPayload::Inline(vec![]),
+        response: OplogPayload::Inline(vec![]),
+        wrapped_function_type: WrappedFunctionType::ReadRemote,
+    };
+
+    let exited = WorkerStatusRecord {
+        status: WorkerStatus::Exited,
+        oplog_idx: OplogIndex::from_u64(10),
+        ..WorkerStatusRecord::default()
+    };
+    check!(OplogCompaction::for_worker(&exited).is_none());
+
+    let exited_with_jump = WorkerStatusRecord {
+        deleted_regions: DeletedRegions::from_regions(vec![OplogRegion::from_range(2..=5)]),
+        ..exited
+    };
+    let compaction = OplogCompaction::for_worker(&exited_with_jump).unwrap();
+    check!(compaction
+        .compact_entry(OplogIndex::from_u64(3), &imported)
+        .is_some());
+    check!(compaction
+        .compact_entry(OplogIndex::from_u64(7), &imported)
+        .is_none());
+}
+
+#[test]
+async fn compaction_deletes_orphaned_payloads(_tracing: &Tracing) {
+    let indexed_storage = Arc::new(InMemoryIndexedStorage::new());
+    let blob_storage = Arc::new(InMemoryBlobStorage::new());
+    let primary_oplog_service = Arc::new(
+        PrimaryOplogService::new(indexed_storage.clone(), blob_storage.clone(), 1, 100).await,
+    );
+    let secondary_layer: Arc<dyn OplogArchiveService + Send + Sync> = Arc::new(
+        CompressedOplogArchiveService::new(indexed_storage.clone(), 1),
+    );
+    let tertiary_layer: Arc<dyn OplogArchiveService + Send + Sync> =
+        Arc::new(BlobOplogArchiveService::new(blob_storage.clone(), 2));
+    let oplog_service = Arc::new(MultiLayerOplogService::new(
+        primary_oplog_service.clone(),
+        nev![secondary_layer.clone(), tertiary_layer.clone()],
+        1000,
+        10,
+    ));
+    let account_id = AccountId {
+        value: "user1".to_string(),
+    };
+    let worker_id = WorkerId {
+        component_id: ComponentId(Uuid::new_v4()),
+        worker_name: "test".to_string(),
+    };
+    let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+    let last_oplog_index = oplog_service.get_last_index(&owned_worker_id).await;
+    let oplog = oplog_service
+        .open(
+            &owned_worker_id,
+            last_oplog_index,
+            WorkerMetadata::default(worker_id.clone(), account_id.clone()),
+            default_execution_status(ComponentType::Durable),
+        )
+        .await;
+
+    // The responses are bigger than the 100 byte limit, so they are stored in blob storage
+    let large_response = "x".repeat(1000);
+    for _ in 0..2 {
+        oplog
+            .add_imported_function_invoked(
+                "f".to_string(),
+                &"request".to_string(),
+                &large_response,
+                WrappedFunctionType::ReadRemote,
+            )
+            .await
+            .unwrap();
+    }
+    oplog.commit(CommitLevel::Always).await;
+    MultiLayerOplog::try_archive_blocking(&oplog).await;
+
+    let original = oplog_service
+        .read(&owned_worker_id, OplogIndex::INITIAL, 2)
+        .await;
+    let payloads = original
+        .values()
+        .filter_map(|entry| match entry {
+            OplogEntry::ImportedFunctionInvoked { response, .. } => Some(response.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Only the first call is in the compacted region
+    let compaction =
+        OplogCompaction::from_regions(vec![OplogIndex::from_u64(1)..=OplogIndex::from_u64(1)]);
+    let stats = MultiLayerOplog::try_compact(&oplog, compaction, false)
+        .await
+        .unwrap()
+        .unwrap();
+    for payload in &stats.orphaned_payloads {
+        oplog_service
+            .delete_payload(&owned_worker_id, payload)
+            .await
+            .unwrap();
+    }
+    drop(oplog);
+
+    let first_payload = oplog_service
+        .download_payload(&owned_worker_id, &payloads[0])
+        .await;
+    let second_payload = oplog_service
+        .download_payload(&owned_worker_id, &payloads[1])
+        .await;
+
+    check!(matches!(payloads[0], OplogPayload::External { .. }));
+    assert_eq!(stats.orphaned_payloads, vec![payloads[0].clone()]);
+    check!(first_payload.is_err());
+    check!(second_payload.is_ok());
+}
+
+#[test]
+async fn compaction_of_ephemeral_oplog(_tracing: &Tracing) {
+    let indexed_storage = Arc::new(InMemoryIndexedStorage::new());
+    let blob_storage = Arc::new(InMemoryBlobStorage::new());
+    let primary_oplog_service = Arc::new(
+        PrimaryOplogService::new(indexed_storage.clone(), blob_storage.clone(), 1, 100).await,
+    );
+    let secondary_layer: Arc<dyn OplogArchiveService + Send + Sync> = Arc::new(
+        CompressedOplogArchiveService::new(indexed_storage.clone(), 1),
+    );
+    let tertiary_layer: Arc<dyn OplogArchiveService + Send + Sync> =
+        Arc::new(BlobOplogArchiveService::new(blob_storage.clone(), 2));
+    let oplog_service = Arc::new(MultiLayerOplogService::new(
+        primary_oplog_service.clone(),
+        nev![secondary_layer.clone(), tertiary_layer.clone()],
+        10,
+        10,
+    ));
+    let account_id = AccountId {
+        value: "user1".to_string(),
+    };
+    let worker_id = WorkerId {
+        component_id: ComponentId(Uuid::new_v4()),
+        worker_name: "test".to_string(),
+    };
+    let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+    let oplog = oplog_service
+        .create(
+            &owned_worker_id,
+            OplogEntry::nop(),
+            WorkerMetadata::default(worker_id.clone(), account_id.clone()),
+            default_execution_status(ComponentType::Ephemeral),
+        )
+        .await;
+
+    for i in 0..3 {
+        oplog
+            .add_exported_function_invoked(
+                "f1".to_string(),
+                &i.to_string(),
+                IdempotencyKey::fresh(),
+                InvocationOptions::default(),
+            )
+            .await
+            .unwrap();
+        oplog
+            .add_imported_function_invoked(
+                "f2".to_string(),
+                &"request".to_string(),
+                &"response".to_string(),
+                WrappedFunctionType::ReadRemote,
+            )
+            .await
+            .unwrap();
+        oplog
+            .add_exported_function_completed(&i.to_string(), 42)
+            .await
+            .unwrap();
+    }
+    oplog.commit(CommitLevel::Immediate).await;
+
+    let durable_oplog_result = MultiLayerOplog::try_compact(
+        &oplog,
+        OplogCompaction::from_regions(vec![OplogIndex::from_u64(1)..=OplogIndex::from_u64(10)]),
+        false,
+    )
+    .await;
+    let stats = EphemeralOplog::try_compact(&oplog, false)
+        .await
+        .unwrap()
+        .unwrap();
+    let entries = oplog_service
+        .read(&owned_worker_id, OplogIndex::INITIAL, 10)
+        .await;
+    drop(oplog);
+
+    check!(durable_oplog_result.is_none());
+    assert_eq!(stats.compacted_entries, 3);
+    assert_eq!(entries.len(), 10);
+    assert_eq!(
+        entries
+            .iter()
+            .filter(|(_, entry)| matches!(entry, OplogEntry::NoOp { .. }))
+            .map(|(idx, _)| u64::from(*idx))
+            .collect::<Vec<_>>(),
+        vec![1, 3, 6, 9]
+    );
+    assert_eq!(
+        entries
+            .values()
+            .filter(|entry| matches!(entry, OplogEntry::ExportedFunctionCompleted { .. }))
+            .count(),
+        3
+    );
+}
+
+#[test]
+async fn interrupted_compaction_of_compressed_layer_is_recovered(_tracing: &Tracing) {
+    let indexed_storage = Arc::new(InMemoryIndexedStorage::new());
+    let worker_id = WorkerId {
+        component_id: ComponentId(Uuid::new_v4()),
+        worker_name: "test".to_string(),
+    };
+    let key = worker_id.to_redis_key();
+    let entry = |i: u64| OplogEntry::NoOp {
+        timestamp: Timestamp::from(i),
+    };
+
+    let archive = CompressedOplogArchive::new(worker_id.clone(), indexed_storage.clone(), 1);
+    for chunk in 0..3 {
+        archive
+            .append(
+                (1..=10)
+                    .map(|i| {
+                        let idx = chunk * 10 + i;
+                        (OplogIndex::from_u64(idx), entry(idx))
+                    })
+                    .collect(),
+            )
+            .await;
+    }
+    let original = archive.read(OplogIndex::INITIAL, 30).await;
+
+    // Simulating a compaction which got interrupted after copying the first staged chunk back
+    let namespace = IndexedStorageNamespace::CompressedOpLog { level: 1 };
+    let staging_namespace = IndexedStorageNamespace::CompactedOpLog { level: 1 };
+    let chunks: Vec<(u64, CompressedOplogChunk)> = indexed_storage
+        .with_entity("test", "test", "compressed_entry")
+        .read(namespace.clone(), &key, 0, 30)
+        .await
+        .unwrap();
+    for (id, chunk) in &chunks {
+        indexed_storage
+            .with_entity("test", "test", "compressed_entry")
+            .append(staging_namespace.clone(), &key, *id, chunk)
+            .await
+            .unwrap();
+    }
+    indexed_storage
+        .with("test", "test")
+        .delete(namespace.clone(), &key)
+        .await
+        .unwrap();
+    indexed_storage
+        .with_entity("test", "test", "compressed_entry")
+        .append(namespace.clone(), &key, chunks[0].0, &chunks[0].1)
+        .await
+        .unwrap();
+
+    let archive = CompressedOplogArchive::new(worker_id.clone(), indexed_storage.clone(), 1);
+    let during_recovery = archive.read(OplogIndex::INITIAL, 30).await;
+    let length_during_recovery = archive.length().await;
+    let last_index_during_recovery = archive.get_last_index().await;
+
+    archive
+        .append(vec![(OplogIndex::from_u64(31), entry(31))])
+        .await;
+    let recovered_length = indexed_storage
+        .with("test", "test")
+        .length(namespace, &key)
+        .await
+        .unwrap();
+    let staging_exists = indexed_storage
+        .with("test", "test")
+        .exists(staging_namespace, &key)
+        .await
+        .unwrap();
+    let after_recovery = archive.read(OplogIndex::INITIAL, 30).await;
+
+    assert_eq!(during_recovery, original);
+    assert_eq!(length_during_recovery, 3);
+    assert_eq!(last_index_during_recovery, OplogIndex::from_u64(30));
+    assert_eq!(recovered_length, 4);
+    check!(!staging_exists);
+    assert_eq!(after_recovery, original);
+}
+
 #[test]
 async fn multilayer_scan_for_component(_tracing: &Tracing) {
     let indexed_storage = Arc::new(InMemoryIndexedStorage::new());
@@ -1682,3 +2247,105 @@ async fn multilayer_scan_for_component(_tracing: &Tracing) {
 
     assert_eq!(result.len(), 100);
 }
+
+#[test]
+async fn sandbox_never_writes_to_the_real_oplog(_tracing: &Tracing) {
+    let indexed_storage = Arc::new(InMemoryIndexedStorage::new());
+    let blob_storage = Arc::new(InMemoryBlobStorage::new());
+    let oplog_service: Arc<dyn OplogService + Send + Sync> =
+        Arc::new(PrimaryOplogService::new(indexed_storage, blob_storage, 1, 100).await);
+    let account_id = AccountId {
+        value: "user1".to_string(),
+    };
+    let worker_id = WorkerId {
+        component_id: ComponentId(Uuid::new_v4()),
+        worker_name: "test".to_string(),
+    };
+    let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+    let oplog = oplog_service
+        .open(
+            &owned_worker_id,
+            OplogIndex::NONE,
+            WorkerMetadata::default(worker_id.clone(), account_id.clone()),
+            default_execution_status(ComponentType::Durable),
+        )
+        .await;
+
+    let entry1 = rounded(OplogEntry::suspend());
+    let entry2 = rounded(OplogEntry::exited());
+    let entry3 = rounded(OplogEntry::interrupted());
+    oplog.add(entry1.clone()).await;
+    oplog.add(entry2.clone()).await;
+    oplog.add(entry3.clone()).await;
+    oplog.commit(CommitLevel::Always).await;
+
+    let pending = rounded(OplogEntry::pending_update(UpdateDescription::Automatic {
+        target_version: 2,
+    }));
+    let sandbox = SandboxOplogService::new(
+        oplog_service.clone(),
+        owned_worker_id.clone(),
+        OplogIndex::from_u64(2),
+        vec![pending.clone()],
+    );
+    let sandbox_oplog = sandbox
+        .open(
+            &owned_worker_id,
+            OplogIndex::from_u64(3),
+            WorkerMetadata::default(worker_id.clone(), account_id.clone()),
+            default_execution_status(ComponentType::Durable),
+        )
+        .await;
+
+    let failed = rounded(OplogEntry::failed_update(2, None));
+    sandbox_oplog.add_and_commit(failed.clone()).await;
+
+    check!(sandbox.get_last_index(&owned_worker_id).await == OplogIndex::from_u64(4));
+    check!(sandbox_oplog.read(OplogIndex::from_u64(3)).await == pending);
+    check!(
+        sandbox
+            .read(&owned_worker_id, OplogIndex::INITIAL, 10)
+            .await
+            .into_values()
+            .collect::<Vec<_>>()
+            == vec![entry1.clone(), entry2.clone(), pending, failed]
+    );
+
+    let other_worker_id = OwnedWorkerId::new(
+        &account_id,
+        &WorkerId {
+            component_id: worker_id.component_id.clone(),
+            worker_name: "other".to_string(),
+        },
+    );
+    check!(!sandbox.exists(&other_worker_id).await);
+    let other_oplog = sandbox
+        .create(
+            &other_worker_id,
+            entry3.clone(),
+            WorkerMetadata::default(other_worker_id.worker_id(), account_id.clone()),
+            default_execution_status(ComponentType::Durable),
+        )
+        .await;
+    other_oplog.add_and_commit(entry1.clone()).await;
+    check!(sandbox.exists(&other_worker_id).await);
+    check!(
+        sandbox
+            .read(&other_worker_id, OplogIndex::INITIAL, 10)
+            .await
+            .into_values()
+            .collect::<Vec<_>>()
+            == vec![entry3.clone(), entry1.clone()]
+    );
+    check!(!oplog_service.exists(&other_worker_id).await);
+
+    check!(oplog_service.get_last_index(&owned_worker_id).await == OplogIndex::from_u64(3));
+    check!(
+        oplog_service
+            .read(&owned_worker_id, OplogIndex::INITIAL, 10)
+            .await
+            .into_values()
+            .collect::<Vec<_>>()
+            == vec![entry1, entry2, entry3]
+    );
+}
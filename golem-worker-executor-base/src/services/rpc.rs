@@ -14,16 +14,19 @@
 
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use bincode::{Decode, Encode};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::WitValue;
 use tokio::runtime::Handle;
-use tracing::debug;
+use tracing::{debug, warn};
 
 use super::file_loader::FileLoader;
+use super::volume::VolumeService;
 use crate::error::GolemError;
 use crate::services::events::Events;
 use crate::services::oplog::plugin::OplogProcessorPlugin;
@@ -36,13 +39,15 @@ use crate::services::{
     HasBlobStoreService, HasComponentService, HasConfig, HasEvents, HasExtraDeps, HasFileLoader,
     HasKeyValueService, HasOplogProcessorPlugin, HasOplogService, HasPlugins, HasPromiseService,
     HasRpc, HasRunningWorkerEnumerationService, HasSchedulerService, HasShardManagerService,
-    HasShardService, HasWasmtimeEngine, HasWorkerActivator, HasWorkerEnumerationService,
-    HasWorkerProxy, HasWorkerService,
+    HasShardService, HasVolumeService, HasWasmtimeEngine, HasWorkerActivator,
+    HasWorkerEnumerationService, HasWorkerProxy, HasWorkerService,
 };
 use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
 use golem_common::model::component::ComponentOwner;
-use golem_common::model::{IdempotencyKey, OwnedWorkerId, TargetWorkerId, WorkerId};
+use golem_common::model::{
+    IdempotencyKey, InvocationOptions, OwnedWorkerId, TargetWorkerId, WorkerId,
+};
 
 #[async_trait]
 pub trait Rpc {
@@ -57,6 +62,7 @@ pub trait Rpc {
         self_worker_id: &WorkerId,
         self_args: &[String],
         self_env: &[(String, String)],
+        timeout: Option<Duration>,
     ) -> Result<TypeAnnotatedValue, RpcError>;
 
     async fn invoke(
@@ -70,6 +76,14 @@ pub trait Rpc {
         self_env: &[(String, String)],
     ) -> Result<(), RpcError>;
 
+    /// Cancels a pending invocation of a remote worker, identified by its idempotency key.
+    /// Returns `false` if the invocation is no longer pending.
+    async fn cancel_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idempotency_key: &IdempotencyKey,
+    ) -> Result<bool, RpcError>;
+
     async fn generate_unique_local_worker_id(
         &self,
         target_worker_id: TargetWorkerId,
@@ -82,6 +96,8 @@ pub enum RpcError {
     Denied { details: String },
     NotFound { details: String },
     RemoteInternalError { details: String },
+    Timeout { details: String },
+    Cancelled { details: String },
 }
 
 impl Display for RpcError {
@@ -93,6 +109,8 @@ impl Display for RpcError {
             RpcError::RemoteInternalError { details } => {
                 write!(f, "Remote internal error: {}", details)
             }
+            RpcError::Timeout { details } => write!(f, "Timeout: {}", details),
+            RpcError::Cancelled { details } => write!(f, "Cancelled: {}", details),
         }
     }
 }
@@ -151,6 +169,37 @@ impl From<WorkerProxyError> for RpcError {
 
 pub trait RpcDemand: Send + Sync {}
 
+/// Awaits an invocation of a remote worker, cancelling it if it does not complete
+/// within the given timeout.
+async fn with_timeout<R: Rpc + ?Sized>(
+    rpc: &R,
+    owned_worker_id: &OwnedWorkerId,
+    idempotency_key: &IdempotencyKey,
+    timeout: Option<Duration>,
+    invocation: impl Future<Output = Result<TypeAnnotatedValue, RpcError>>,
+) -> Result<TypeAnnotatedValue, RpcError> {
+    match timeout {
+        Some(timeout) => match tokio::time::timeout(timeout, invocation).await {
+            Ok(result) => result,
+            Err(_) => {
+                if let Err(err) = rpc
+                    .cancel_invocation(owned_worker_id, idempotency_key)
+                    .await
+                {
+                    warn!("Failed to cancel timed out invocation {idempotency_key} of worker {owned_worker_id}: {err}");
+                }
+                Err(RpcError::Timeout {
+                    details: format!(
+                        "Invocation of worker {} did not complete in {:?}",
+                        owned_worker_id.worker_id, timeout
+                    ),
+                })
+            }
+        },
+        None => invocation.await,
+    }
+}
+
 pub struct RemoteInvocationRpc {
     worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
     shard_service: Arc<dyn ShardService + Send + Sync>,
@@ -204,19 +253,25 @@ impl Rpc for RemoteInvocationRpc {
         self_worker_id: &WorkerId,
         self_args: &[String],
         self_env: &[(String, String)],
+        timeout: Option<Duration>,
     ) -> Result<TypeAnnotatedValue, RpcError> {
-        Ok(self
-            .worker_proxy
-            .invoke_and_await(
-                owned_worker_id,
-                idempotency_key,
-                function_name,
-                function_params,
-                self_worker_id.clone(),
-                self_args.to_vec(),
-                HashMap::from_iter(self_env.to_vec()),
-            )
-            .await?)
+        let idempotency_key = idempotency_key.unwrap_or(IdempotencyKey::fresh());
+
+        with_timeout(self, owned_worker_id, &idempotency_key, timeout, async {
+            Ok(self
+                .worker_proxy
+                .invoke_and_await(
+                    owned_worker_id,
+                    Some(idempotency_key.clone()),
+                    function_name,
+                    function_params,
+                    self_worker_id.clone(),
+                    self_args.to_vec(),
+                    HashMap::from_iter(self_env.to_vec()),
+                )
+                .await?)
+        })
+        .await
     }
 
     async fn invoke(
@@ -243,6 +298,17 @@ impl Rpc for RemoteInvocationRpc {
             .await?)
     }
 
+    async fn cancel_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idempotency_key: &IdempotencyKey,
+    ) -> Result<bool, RpcError> {
+        Ok(self
+            .worker_proxy
+            .cancel_invocation(owned_worker_id, idempotency_key)
+            .await?)
+    }
+
     async fn generate_unique_local_worker_id(
         &self,
         target_worker_id: TargetWorkerId,
@@ -260,6 +326,7 @@ pub struct DirectWorkerInvocationRpc<Ctx: WorkerCtx> {
     active_workers: Arc<active_workers::ActiveWorkers<Ctx>>,
     engine: Arc<wasmtime::Engine>,
     linker: Arc<wasmtime::component::Linker<Ctx>>,
+    instance_pre_cache: Arc<component::InstancePreCache<Ctx>>,
     runtime: Handle,
     component_service: Arc<dyn component::ComponentService + Send + Sync>,
     shard_manager_service: Arc<dyn shard_manager::ShardManagerService + Send + Sync>,
@@ -277,6 +344,7 @@ pub struct DirectWorkerInvocationRpc<Ctx: WorkerCtx> {
     worker_activator: Arc<dyn worker_activator::WorkerActivator<Ctx> + Send + Sync>,
     events: Arc<Events>,
     file_loader: Arc<FileLoader>,
+    volume_service: Arc<VolumeService>,
     plugins: Arc<
         dyn Plugins<<Ctx::ComponentOwner as ComponentOwner>::PluginOwner, Ctx::PluginScope>
             + Send
@@ -293,6 +361,7 @@ impl<Ctx: WorkerCtx> Clone for DirectWorkerInvocationRpc<Ctx> {
             active_workers: self.active_workers.clone(),
             engine: self.engine.clone(),
             linker: self.linker.clone(),
+            instance_pre_cache: self.instance_pre_cache.clone(),
             runtime: self.runtime.clone(),
             component_service: self.component_service.clone(),
             shard_manager_service: self.shard_manager_service.clone(),
@@ -309,6 +378,7 @@ impl<Ctx: WorkerCtx> Clone for DirectWorkerInvocationRpc<Ctx> {
             worker_activator: self.worker_activator.clone(),
             events: self.events.clone(),
             file_loader: self.file_loader.clone(),
+            volume_service: self.volume_service.clone(),
             plugins: self.plugins.clone(),
             oplog_processor_plugin: self.oplog_processor_plugin.clone(),
             extra_deps: self.extra_deps.clone(),
@@ -377,6 +447,10 @@ impl<Ctx: WorkerCtx> HasWasmtimeEngine<Ctx> for DirectWorkerInvocationRpc<Ctx> {
         self.linker.clone()
     }
 
+    fn instance_pre_cache(&self) -> Arc<component::InstancePreCache<Ctx>> {
+        self.instance_pre_cache.clone()
+    }
+
     fn runtime(&self) -> Handle {
         self.runtime.clone()
     }
@@ -448,6 +522,12 @@ impl<Ctx: WorkerCtx> HasFileLoader for DirectWorkerInvocationRpc<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasVolumeService for DirectWorkerInvocationRpc<Ctx> {
+    fn volume_service(&self) -> Arc<VolumeService> {
+        self.volume_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx>
     HasPlugins<<Ctx::ComponentOwner as ComponentOwner>::PluginOwner, Ctx::PluginScope>
     for DirectWorkerInvocationRpc<Ctx>
@@ -476,6 +556,7 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
         active_workers: Arc<active_workers::ActiveWorkers<Ctx>>,
         engine: Arc<wasmtime::Engine>,
         linker: Arc<wasmtime::component::Linker<Ctx>>,
+        instance_pre_cache: Arc<component::InstancePreCache<Ctx>>,
         runtime: Handle,
         component_service: Arc<dyn component::ComponentService + Send + Sync>,
         worker_service: Arc<dyn worker::WorkerService + Send + Sync>,
@@ -496,6 +577,7 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
         worker_activator: Arc<dyn worker_activator::WorkerActivator<Ctx> + Send + Sync>,
         events: Arc<Events>,
         file_loader: Arc<FileLoader>,
+        volume_service: Arc<VolumeService>,
         plugins: Arc<
             dyn Plugins<<Ctx::ComponentOwner as ComponentOwner>::PluginOwner, Ctx::PluginScope>
                 + Send
@@ -509,6 +591,7 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
             active_workers,
             engine,
             linker,
+            instance_pre_cache,
             runtime,
             component_service,
             shard_manager_service,
@@ -525,6 +608,7 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
             worker_activator,
             events,
             file_loader,
+            volume_service,
             plugins,
             oplog_processor_plugin,
             extra_deps,
@@ -548,6 +632,7 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
         self_worker_id: &WorkerId,
         self_args: &[String],
         self_env: &[(String, String)],
+        timeout: Option<Duration>,
     ) -> Result<TypeAnnotatedValue, RpcError> {
         let idempotency_key = idempotency_key.unwrap_or(IdempotencyKey::fresh());
 
@@ -573,11 +658,18 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
             )
             .await?;
 
-            let result_values = worker
-                .invoke_and_await(idempotency_key, function_name, input_values)
-                .await?;
-
-            Ok(result_values)
+            with_timeout(self, owned_worker_id, &idempotency_key, timeout, async {
+                let result_values = worker
+                    .invoke_and_await(
+                        idempotency_key.clone(),
+                        function_name,
+                        input_values,
+                        InvocationOptions::default(),
+                    )
+                    .await?;
+                Ok(result_values)
+            })
+            .await
         } else {
             self.remote_rpc
                 .invoke_and_await(
@@ -588,6 +680,7 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
                     self_worker_id,
                     self_args,
                     self_env,
+                    timeout,
                 )
                 .await
         }
@@ -628,7 +721,12 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
             .await?;
 
             worker
-                .invoke(idempotency_key, function_name, input_values)
+                .invoke(
+                    idempotency_key,
+                    function_name,
+                    input_values,
+                    InvocationOptions::default(),
+                )
                 .await?;
             Ok(())
         } else {
@@ -646,6 +744,42 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
         }
     }
 
+    async fn cancel_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idempotency_key: &IdempotencyKey,
+    ) -> Result<bool, RpcError> {
+        if self
+            .shard_service()
+            .check_worker(&owned_worker_id.worker_id)
+            .is_ok()
+        {
+            debug!("Cancelling pending invocation {idempotency_key} of local worker");
+
+            match self.worker_service().get(owned_worker_id).await {
+                Some(_) => {
+                    let worker = Worker::get_or_create_suspended(
+                        self,
+                        owned_worker_id,
+                        None,
+                        None,
+                        None,
+                        None,
+                    )
+                    .await?;
+                    Ok(worker.cancel_invocation(idempotency_key.clone()).await?)
+                }
+                None => Err(RpcError::NotFound {
+                    details: format!("Worker {} not found", owned_worker_id.worker_id),
+                }),
+            }
+        } else {
+            self.remote_rpc
+                .cancel_invocation(owned_worker_id, idempotency_key)
+                .await
+        }
+    }
+
     async fn generate_unique_local_worker_id(
         &self,
         target_worker_id: TargetWorkerId,
@@ -0,0 +1,223 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dependencies of workers replayed in a sandbox.
+//!
+//! A sandboxed worker must not affect anything outside of the sandbox, so it cannot invoke other
+//! workers, schedule actions or send its oplog to plugins. These implementations reject or drop
+//! every such request.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use golem_api_grpc::proto::golem::worker::UpdateMode;
+use golem_common::model::oplog::OplogIndex;
+use golem_common::model::public_oplog::PublicOplogEntry;
+use golem_common::model::{
+    ComponentVersion, IdempotencyKey, OwnedWorkerId, PluginInstallationId, ScheduleId,
+    ScheduledAction, TargetWorkerId, WorkerId, WorkerMetadata,
+};
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use golem_wasm_rpc::WitValue;
+use uuid::Uuid;
+
+use crate::error::GolemError;
+use crate::services::oplog::plugin::{OplogProcessorPlugin, OplogProcessorProgress};
+use crate::services::rpc::{Rpc, RpcDemand, RpcError};
+use crate::services::scheduler::SchedulerService;
+use crate::services::worker_proxy::{WorkerProxy, WorkerProxyError};
+
+const NOT_IN_SANDBOX: &str = "Other workers cannot be invoked from a sandbox";
+
+/// RPC of sandboxed workers, failing every remote invocation
+pub struct SandboxRpc {
+    inner: Arc<dyn Rpc + Send + Sync>,
+}
+
+impl SandboxRpc {
+    /// Only uses `inner` for generating worker IDs, which has no side effects
+    pub fn new(inner: Arc<dyn Rpc + Send + Sync>) -> Self {
+        Self { inner }
+    }
+
+    fn denied() -> RpcError {
+        RpcError::Denied {
+            details: NOT_IN_SANDBOX.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl Rpc for SandboxRpc {
+    async fn create_demand(&self, _owned_worker_id: &OwnedWorkerId) -> Box<dyn RpcDemand> {
+        Box::new(())
+    }
+
+    async fn invoke_and_await(
+        &self,
+        _owned_worker_id: &OwnedWorkerId,
+        _idempotency_key: Option<IdempotencyKey>,
+        _function_name: String,
+        _function_params: Vec<WitValue>,
+        _self_worker_id: &WorkerId,
+        _self_args: &[String],
+        _self_env: &[(String, String)],
+        _timeout: Option<Duration>,
+    ) -> Result<TypeAnnotatedValue, RpcError> {
+        Err(Self::denied())
+    }
+
+    async fn invoke(
+        &self,
+        _owned_worker_id: &OwnedWorkerId,
+        _idempotency_key: Option<IdempotencyKey>,
+        _function_name: String,
+        _function_params: Vec<WitValue>,
+        _self_worker_id: &WorkerId,
+        _self_args: &[String],
+        _self_env: &[(String, String)],
+    ) -> Result<(), RpcError> {
+        Err(Self::denied())
+    }
+
+    async fn cancel_invocation(
+        &self,
+        _owned_worker_id: &OwnedWorkerId,
+        _idempotency_key: &IdempotencyKey,
+    ) -> Result<bool, RpcError> {
+        Err(Self::denied())
+    }
+
+    async fn generate_unique_local_worker_id(
+        &self,
+        target_worker_id: TargetWorkerId,
+    ) -> Result<WorkerId, GolemError> {
+        self.inner
+            .generate_unique_local_worker_id(target_worker_id)
+            .await
+    }
+}
+
+/// Worker proxy of sandboxed workers, failing every request
+pub struct SandboxWorkerProxy;
+
+impl SandboxWorkerProxy {
+    fn denied() -> WorkerProxyError {
+        WorkerProxyError::BadRequest(vec![NOT_IN_SANDBOX.to_string()])
+    }
+}
+
+#[async_trait]
+impl WorkerProxy for SandboxWorkerProxy {
+    async fn invoke_and_await(
+        &self,
+        _owned_worker_id: &OwnedWorkerId,
+        _idempotency_key: Option<IdempotencyKey>,
+        _function_name: String,
+        _function_params: Vec<WitValue>,
+        _caller_worker_id: WorkerId,
+        _caller_args: Vec<String>,
+        _caller_env: HashMap<String, String>,
+    ) -> Result<TypeAnnotatedValue, WorkerProxyError> {
+        Err(Self::denied())
+    }
+
+    async fn invoke(
+        &self,
+        _owned_worker_id: &OwnedWorkerId,
+        _idempotency_key: Option<IdempotencyKey>,
+        _function_name: String,
+        _function_params: Vec<WitValue>,
+        _caller_worker_id: WorkerId,
+        _caller_args: Vec<String>,
+        _caller_env: HashMap<String, String>,
+    ) -> Result<(), WorkerProxyError> {
+        Err(Self::denied())
+    }
+
+    async fn update(
+        &self,
+        _owned_worker_id: &OwnedWorkerId,
+        _target_version: ComponentVersion,
+        _mode: UpdateMode,
+    ) -> Result<(), WorkerProxyError> {
+        Err(Self::denied())
+    }
+
+    async fn cancel_invocation(
+        &self,
+        _owned_worker_id: &OwnedWorkerId,
+        _idempotency_key: &IdempotencyKey,
+    ) -> Result<bool, WorkerProxyError> {
+        Err(Self::denied())
+    }
+
+    async fn publish_to_topic(
+        &self,
+        _publisher: &OwnedWorkerId,
+        _topic: String,
+        _message_id: Uuid,
+        _message: WitValue,
+    ) -> Result<(), WorkerProxyError> {
+        Err(Self::denied())
+    }
+}
+
+/// Scheduler of sandboxed workers. Scheduled actions are dropped, as the sandbox is discarded
+/// before any of them could be due.
+pub struct SandboxSchedulerService;
+
+#[async_trait]
+impl SchedulerService for SandboxSchedulerService {
+    async fn schedule(&self, time: DateTime<Utc>, action: ScheduledAction) -> ScheduleId {
+        ScheduleId {
+            timestamp: time.timestamp_millis(),
+            action,
+        }
+    }
+
+    async fn cancel(&self, _id: ScheduleId) {}
+}
+
+/// Oplog processor plugin of sandboxed workers, dropping the entries instead of sending them to
+/// the plugins
+pub struct SandboxOplogProcessorPlugin;
+
+#[async_trait]
+impl OplogProcessorPlugin for SandboxOplogProcessorPlugin {
+    async fn send(
+        &self,
+        _worker_metadata: WorkerMetadata,
+        _plugin_installation_id: &PluginInstallationId,
+        _initial_oplog_index: OplogIndex,
+        _entries: Vec<PublicOplogEntry>,
+    ) -> Result<(), GolemError> {
+        Ok(())
+    }
+
+    async fn progress(
+        &self,
+        _worker_metadata: &WorkerMetadata,
+        _plugin_installation_id: &PluginInstallationId,
+    ) -> Result<OplogProcessorProgress, GolemError> {
+        Ok(OplogProcessorProgress::Untracked)
+    }
+
+    async fn on_shard_assignment_changed(&self) -> Result<(), GolemError> {
+        Ok(())
+    }
+}
@@ -24,18 +24,23 @@ use tokio::time::Instant;
 use tracing::{error, info, span, warn, Instrument, Level};
 
 use crate::error::GolemError;
-use crate::metrics::oplog::record_scheduled_archive;
+use crate::metrics::oplog::{record_scheduled_archive, record_scheduled_compaction};
 use crate::metrics::promises::record_scheduled_promise_completed;
-use crate::services::oplog::{MultiLayerOplog, Oplog, OplogService};
+use crate::services::golem_config::OplogConfig;
+use crate::services::oplog::{
+    EphemeralOplog, MultiLayerOplog, Oplog, OplogCompaction, OplogCompactionStats, OplogService,
+};
 use crate::services::promise::PromiseService;
 use crate::services::shard::ShardService;
 use crate::services::worker::WorkerService;
 use crate::services::worker_activator::WorkerActivator;
-use crate::services::HasOplog;
+use crate::services::{HasActiveWorkers, HasOplog, HasWorkerService, UsesAllDeps};
 use crate::storage::keyvalue::{
     KeyValueStorage, KeyValueStorageLabelledApi, KeyValueStorageNamespace,
 };
+use crate::worker::get_component_metadata;
 use crate::workerctx::WorkerCtx;
+use golem_common::model::component_metadata::OplogPolicy;
 use golem_common::model::{OwnedWorkerId, ScheduleId, ScheduledAction};
 
 #[async_trait]
@@ -54,6 +59,11 @@ pub trait SchedulerWorkerAccess {
         &self,
         owned_worker_id: &OwnedWorkerId,
     ) -> Result<Arc<dyn Oplog + Send + Sync>, GolemError>;
+    async fn oplog_policy(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Option<OplogPolicy>, GolemError>;
+    async fn delete_worker(&self, owned_worker_id: &OwnedWorkerId) -> Result<(), GolemError>;
 }
 
 #[async_trait]
@@ -71,6 +81,30 @@ impl<Ctx: WorkerCtx> SchedulerWorkerAccess for Arc<dyn WorkerActivator<Ctx> + Se
             .await?;
         Ok(worker.oplog())
     }
+
+    async fn oplog_policy(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Option<OplogPolicy>, GolemError> {
+        let worker = self
+            .get_or_create_suspended(owned_worker_id, None, None, None, None)
+            .await?;
+        let component_metadata = get_component_metadata(&worker).await?;
+        Ok(component_metadata.oplog_policy)
+    }
+
+    async fn delete_worker(&self, owned_worker_id: &OwnedWorkerId) -> Result<(), GolemError> {
+        let worker = self
+            .get_or_create_suspended(owned_worker_id, None, None, None, None)
+            .await?;
+        worker.stop().await;
+
+        let deps = worker.all();
+        Ctx::on_worker_deleted(deps, &owned_worker_id.worker_id).await?;
+        deps.worker_service().remove(owned_worker_id).await;
+        deps.active_workers().remove(&owned_worker_id.worker_id);
+        Ok(())
+    }
 }
 
 #[derive(Clone)]
@@ -82,6 +116,7 @@ pub struct SchedulerServiceDefault {
     worker_access: Arc<dyn SchedulerWorkerAccess + Send + Sync>,
     oplog_service: Arc<dyn OplogService + Send + Sync>,
     worker_service: Arc<dyn WorkerService + Send + Sync>,
+    oplog_config: OplogConfig,
 }
 
 impl SchedulerServiceDefault {
@@ -92,6 +127,7 @@ impl SchedulerServiceDefault {
         worker_access: Arc<dyn SchedulerWorkerAccess + Send + Sync>,
         oplog_service: Arc<dyn OplogService + Send + Sync>,
         worker_service: Arc<dyn WorkerService + Send + Sync>,
+        oplog_config: OplogConfig,
         process_interval: Duration,
     ) -> Arc<Self> {
         let svc = Self {
@@ -102,6 +138,7 @@ impl SchedulerServiceDefault {
             oplog_service,
             worker_service,
             worker_access,
+            oplog_config,
         };
         let svc = Arc::new(svc);
         let background_handle = {
@@ -173,6 +210,7 @@ impl SchedulerServiceDefault {
             .collect::<Vec<_>>();
 
         let mut owned_worker_ids = HashSet::new();
+        let mut deleted_worker_ids = HashSet::new();
         for (key, action) in matching {
             owned_worker_ids.insert(action.owned_worker_id().clone());
             self.key_value_storage
@@ -201,8 +239,24 @@ impl SchedulerServiceDefault {
                             // Need to create the `Worker` instance to avoid race conditions
                             match self.worker_access.open_oplog(&owned_worker_id).await {
                                 Ok(oplog) => {
+                                    let policy = self
+                                        .worker_access
+                                        .oplog_policy(&owned_worker_id)
+                                        .await
+                                        .unwrap_or_else(|error| {
+                                            warn!(
+                                                worker_id = owned_worker_id.to_string(),
+                                                "Failed to get the oplog policy, using the defaults: {error}"
+                                            );
+                                            None
+                                        });
                                     let start = Instant::now();
-                                    if let Some(more) = MultiLayerOplog::try_archive(&oplog).await {
+                                    if let Some(more) = MultiLayerOplog::try_archive_with_policy(
+                                        &oplog,
+                                        policy.as_ref(),
+                                    )
+                                    .await
+                                    {
                                         record_scheduled_archive(start.elapsed(), more);
                                         if more {
                                             self.schedule(
@@ -215,6 +269,10 @@ impl SchedulerServiceDefault {
                                             )
                                             .await;
                                         } else {
+                                            if self.oplog_config.compaction_enabled {
+                                                self.compact_oplog(&owned_worker_id, &oplog).await;
+                                            }
+
                                             info!(
                                                 worker_id = owned_worker_id.to_string(),
                                                 "Deleting cached status of fully archived worker"
@@ -224,6 +282,10 @@ impl SchedulerServiceDefault {
                                                 .remove_cached_status(&owned_worker_id)
                                                 .await;
                                         }
+                                    } else if self.oplog_config.compaction_enabled {
+                                        // Ephemeral oplogs have no layers to archive into
+                                        self.compact_ephemeral_oplog(&owned_worker_id, &oplog)
+                                            .await;
                                     }
                                 }
                                 Err(error) => {
@@ -238,17 +300,47 @@ impl SchedulerServiceDefault {
                         // TODO: metrics
                     }
                 }
+                ScheduledAction::DeleteExitedWorker {
+                    owned_worker_id,
+                    last_oplog_index,
+                }
+                | ScheduledAction::DeleteFailedWorker {
+                    owned_worker_id,
+                    last_oplog_index,
+                } => {
+                    if self.oplog_service.exists(&owned_worker_id).await {
+                        let current_last_index =
+                            self.oplog_service.get_last_index(&owned_worker_id).await;
+                        if current_last_index == last_oplog_index {
+                            match self.worker_access.delete_worker(&owned_worker_id).await {
+                                Ok(()) => {
+                                    info!(
+                                        worker_id = owned_worker_id.to_string(),
+                                        "Deleted exited or failed worker after its retention period"
+                                    );
+                                    deleted_worker_ids.insert(owned_worker_id);
+                                }
+                                Err(error) => {
+                                    error!(
+                                        worker_id = owned_worker_id.to_string(),
+                                        "Failed to delete exited or failed worker: {error}"
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
             }
         }
 
-        for owned_worker_id in owned_worker_ids {
+        for owned_worker_id in owned_worker_ids.difference(&deleted_worker_ids) {
             let span = span!(
                 Level::INFO,
                 "scheduler",
                 worker_id = owned_worker_id.worker_id.to_string()
             );
             self.worker_access
-                .activate_worker(&owned_worker_id)
+                .activate_worker(owned_worker_id)
                 .instrument(span)
                 .await;
         }
@@ -256,6 +348,81 @@ impl SchedulerServiceDefault {
         Ok(())
     }
 
+    /// Compacts the archived layers of a fully archived oplog, based on the worker's last known
+    /// status. Must be called before the cached status gets deleted.
+    async fn compact_oplog(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        oplog: &Arc<dyn Oplog + Send + Sync>,
+    ) {
+        let compaction = self
+            .worker_service
+            .get(owned_worker_id)
+            .await
+            .and_then(|metadata| OplogCompaction::for_worker(&metadata.last_known_status));
+
+        if let Some(compaction) = compaction {
+            let dry_run = self.oplog_config.compaction_dry_run;
+            let start = Instant::now();
+            let result = MultiLayerOplog::try_compact(oplog, compaction, dry_run).await;
+            self.finish_compaction(owned_worker_id, result, start, dry_run)
+                .await;
+        }
+    }
+
+    /// Compacts the oplog of an ephemeral worker, which is written directly to the last layer
+    async fn compact_ephemeral_oplog(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        oplog: &Arc<dyn Oplog + Send + Sync>,
+    ) {
+        let dry_run = self.oplog_config.compaction_dry_run;
+        let start = Instant::now();
+        let result = EphemeralOplog::try_compact(oplog, dry_run).await;
+        self.finish_compaction(owned_worker_id, result, start, dry_run)
+            .await;
+    }
+
+    /// Records the result of a compaction and deletes the payloads which are no longer referenced
+    async fn finish_compaction(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        result: Option<Result<OplogCompactionStats, String>>,
+        start: Instant,
+        dry_run: bool,
+    ) {
+        match result {
+            Some(Ok(stats)) => {
+                if !dry_run {
+                    for payload in &stats.orphaned_payloads {
+                        if let Err(err) = self
+                            .oplog_service
+                            .delete_payload(owned_worker_id, payload)
+                            .await
+                        {
+                            warn!(
+                                worker_id = owned_worker_id.to_string(),
+                                "Failed to delete orphaned oplog payload: {err}"
+                            );
+                        }
+                    }
+                }
+                record_scheduled_compaction(start.elapsed(), &stats, dry_run);
+                info!(
+                    worker_id = owned_worker_id.to_string(),
+                    dry_run, "Compacted archived oplog: {stats}"
+                );
+            }
+            Some(Err(err)) => {
+                error!(
+                    worker_id = owned_worker_id.to_string(),
+                    dry_run, "Failed to compact archived oplog: {err}"
+                );
+            }
+            None => {}
+        }
+    }
+
     const HOUR_IN_MILLIS: i64 = 1000 * 60 * 60;
 
     fn split_time<Tz: TimeZone>(time: DateTime<Tz>) -> (i64, f64) {
@@ -342,6 +509,8 @@ mod tests {
     use uuid::Uuid;
 
     use crate::error::GolemError;
+    use crate::model::ExecutionStatus;
+    use crate::services::golem_config::OplogConfig;
     use crate::services::oplog::{Oplog, OplogService, PrimaryOplogService};
     use crate::services::promise::PromiseServiceMock;
     use crate::services::scheduler::{
@@ -351,9 +520,11 @@ mod tests {
     use crate::services::worker::{DefaultWorkerService, WorkerService};
     use crate::storage::indexed::memory::InMemoryIndexedStorage;
     use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
-    use golem_common::model::oplog::OplogIndex;
+    use golem_common::model::component_metadata::OplogPolicy;
+    use golem_common::model::oplog::{OplogEntry, OplogIndex};
     use golem_common::model::{
-        AccountId, ComponentId, OwnedWorkerId, PromiseId, ScheduledAction, ShardId, WorkerId,
+        AccountId, ComponentId, ComponentType, OwnedWorkerId, PromiseId, ScheduledAction, ShardId,
+        Timestamp, WorkerId, WorkerMetadata, WorkerStatusRecord,
     };
     use golem_service_base::storage::blob::memory::InMemoryBlobStorage;
 
@@ -368,6 +539,15 @@ mod tests {
         ) -> Result<Arc<dyn Oplog + Send + Sync>, GolemError> {
             unimplemented!()
         }
+        async fn oplog_policy(
+            &self,
+            _owned_worker_id: &OwnedWorkerId,
+        ) -> Result<Option<OplogPolicy>, GolemError> {
+            Ok(None)
+        }
+        async fn delete_worker(&self, _owned_worker_id: &OwnedWorkerId) -> Result<(), GolemError> {
+            unimplemented!()
+        }
     }
 
     fn serialized_bytes<T: Encode>(entry: &T) -> Vec<u8> {
@@ -456,6 +636,7 @@ mod tests {
             worker_access,
             oplog_service,
             worker_service,
+            OplogConfig::default(),
             Duration::from_secs(1000), // not testing process() here
         );
 
@@ -573,6 +754,7 @@ mod tests {
             worker_access,
             oplog_service,
             worker_service,
+            OplogConfig::default(),
             Duration::from_secs(1000), // not testing process() here
         );
 
@@ -675,6 +857,7 @@ mod tests {
             worker_access,
             oplog_service,
             worker_service,
+            OplogConfig::default(),
             Duration::from_secs(1000), // explicitly calling process for testing
         );
 
@@ -782,6 +965,7 @@ mod tests {
             worker_access,
             oplog_service,
             worker_service,
+            OplogConfig::default(),
             Duration::from_secs(1000), // explicitly calling process for testing
         );
 
@@ -887,6 +1071,7 @@ mod tests {
             worker_access,
             oplog_service,
             worker_service,
+            OplogConfig::default(),
             Duration::from_secs(1000), // explicitly calling process for testing
         );
 
@@ -998,6 +1183,7 @@ mod tests {
             worker_access,
             oplog_service,
             worker_service,
+            OplogConfig::default(),
             Duration::from_secs(1000), // explicitly calling process for testing
         );
 
@@ -1053,4 +1239,122 @@ mod tests {
         assert!(completed_promises.contains(&p3));
         assert!(completed_promises.contains(&p2));
     }
+
+    #[derive(Default)]
+    struct RecordingSchedulerWorkerAccess {
+        deleted: std::sync::Mutex<Vec<OwnedWorkerId>>,
+    }
+
+    #[async_trait]
+    impl SchedulerWorkerAccess for RecordingSchedulerWorkerAccess {
+        async fn activate_worker(&self, _owned_worker_id: &OwnedWorkerId) {}
+        async fn open_oplog(
+            &self,
+            _owned_worker_id: &OwnedWorkerId,
+        ) -> Result<Arc<dyn Oplog + Send + Sync>, GolemError> {
+            unimplemented!()
+        }
+        async fn oplog_policy(
+            &self,
+            _owned_worker_id: &OwnedWorkerId,
+        ) -> Result<Option<OplogPolicy>, GolemError> {
+            Ok(None)
+        }
+        async fn delete_worker(&self, owned_worker_id: &OwnedWorkerId) -> Result<(), GolemError> {
+            self.deleted.lock().unwrap().push(owned_worker_id.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    pub async fn process_deletes_exited_workers_with_unchanged_oplog() {
+        let account_id = AccountId {
+            value: "test-account".to_string(),
+        };
+        let component_id = ComponentId(Uuid::new_v4());
+        let idle_worker_id = OwnedWorkerId::new(
+            &account_id,
+            &WorkerId {
+                component_id: component_id.clone(),
+                worker_name: "idle".to_string(),
+            },
+        );
+        let restarted_worker_id = OwnedWorkerId::new(
+            &account_id,
+            &WorkerId {
+                component_id: component_id.clone(),
+                worker_name: "restarted".to_string(),
+            },
+        );
+
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+
+        let shard_service = create_shard_service_mock();
+        let promise_service = create_promise_service_mock();
+        let worker_access = Arc::new(RecordingSchedulerWorkerAccess::default());
+        let oplog_service = create_oplog_service_mock().await;
+        let worker_service =
+            create_worker_service_mock(kvs.clone(), shard_service.clone(), oplog_service.clone());
+
+        let mut oplogs = Vec::new();
+        let mut last_oplog_indices = Vec::new();
+        for owned_worker_id in [&idle_worker_id, &restarted_worker_id] {
+            let oplog = oplog_service
+                .create(
+                    owned_worker_id,
+                    OplogEntry::nop(),
+                    WorkerMetadata::default(
+                        owned_worker_id.worker_id(),
+                        owned_worker_id.account_id(),
+                    ),
+                    Arc::new(std::sync::RwLock::new(ExecutionStatus::Suspended {
+                        last_known_status: WorkerStatusRecord::default(),
+                        component_type: ComponentType::Durable,
+                        timestamp: Timestamp::now_utc(),
+                    })),
+                )
+                .await;
+            oplog.add_and_commit(OplogEntry::nop()).await;
+            last_oplog_indices.push(oplog.current_oplog_index().await);
+            oplogs.push(oplog);
+        }
+
+        // The second worker got invoked again after exiting, so its oplog grew since the deletion
+        // got scheduled
+        oplogs[1].add_and_commit(OplogEntry::nop()).await;
+
+        let svc = SchedulerServiceDefault::new(
+            kvs.clone(),
+            shard_service,
+            promise_service,
+            worker_access.clone(),
+            oplog_service,
+            worker_service,
+            OplogConfig::default(),
+            Duration::from_secs(1000), // explicitly calling process for testing
+        );
+
+        svc.schedule(
+            DateTime::from_str("2023-07-17T10:05:00Z").unwrap(),
+            ScheduledAction::DeleteExitedWorker {
+                owned_worker_id: idle_worker_id.clone(),
+                last_oplog_index: last_oplog_indices[0],
+            },
+        )
+        .await;
+        svc.schedule(
+            DateTime::from_str("2023-07-17T10:06:00Z").unwrap(),
+            ScheduledAction::DeleteExitedWorker {
+                owned_worker_id: restarted_worker_id.clone(),
+                last_oplog_index: last_oplog_indices[1],
+            },
+        )
+        .await;
+
+        svc.process(DateTime::from_str("2023-07-17T10:15:00Z").unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(*worker_access.deleted.lock().unwrap(), vec![idle_worker_id]);
+    }
 }
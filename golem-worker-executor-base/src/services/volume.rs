@@ -0,0 +1,509 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::GolemError;
+use golem_common::model::{AccountId, ComponentId};
+use golem_service_base::storage::blob::{
+    BlobStorage, BlobStorageLabelledApi, BlobStorageNamespace, ExistsResult,
+};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+use tracing::debug;
+
+/// Keeps the locally mounted persistent volumes of workers in sync with the blob storage.
+///
+/// The contents of a volume are stored under [`BlobStorageNamespace::WorkerVolume`] and are shared
+/// by all the workers of the component. The consistency model is deliberately simple:
+/// - a worker sees the stored state of the volume as of the time it got loaded, and again after it
+///   finished replaying its oplog,
+/// - local changes are written back file by file at commit points, the last writer wins,
+/// - changes made while replaying the oplog are discarded, as the volume is durable by itself.
+pub struct VolumeService {
+    blob_storage: Arc<dyn BlobStorage + Send + Sync>,
+    read_only: bool,
+}
+
+/// The state of a locally mounted volume as of the last restore or sync
+#[derive(Debug, Clone, Default)]
+pub struct VolumeSnapshot {
+    files: HashMap<PathBuf, FileState>,
+    directories: HashSet<PathBuf>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileState {
+    modified: SystemTime,
+    size: u64,
+}
+
+impl VolumeService {
+    pub fn new(blob_storage: Arc<dyn BlobStorage + Send + Sync>) -> Self {
+        Self {
+            blob_storage,
+            read_only: false,
+        }
+    }
+
+    /// A volume service restoring the stored volumes, but never writing back the local changes.
+    /// Used by workers replayed in a sandbox.
+    pub fn read_only(&self) -> Self {
+        Self {
+            blob_storage: self.blob_storage.clone(),
+            read_only: true,
+        }
+    }
+
+    /// Replaces the contents of `target` with the stored state of the volume.
+    ///
+    /// Existing files are overwritten in place, so descriptors opened by the worker remain valid.
+    pub async fn restore(
+        &self,
+        account_id: &AccountId,
+        component_id: &ComponentId,
+        volume_name: &str,
+        target: &Path,
+    ) -> Result<VolumeSnapshot, GolemError> {
+        let namespace = Self::namespace(account_id, component_id, volume_name);
+        let (remote_files, remote_directories) = self
+            .list_remote(namespace.clone())
+            .await
+            .map_err(|err| Self::error(volume_name, err))?;
+        let local = Self::scan_local(target)
+            .await
+            .map_err(|err| Self::error(volume_name, err))?;
+
+        for path in local.files.keys() {
+            if !remote_files.contains(path) {
+                tokio::fs::remove_file(target.join(path))
+                    .await
+                    .map_err(|err| Self::error(volume_name, err.to_string()))?;
+            }
+        }
+        let mut removed_directories = local
+            .directories
+            .iter()
+            .filter(|path| !remote_directories.contains(*path))
+            .collect::<Vec<_>>();
+        removed_directories.sort_by_key(|path| std::cmp::Reverse(path.components().count()));
+        for path in removed_directories {
+            tokio::fs::remove_dir_all(target.join(path))
+                .await
+                .map_err(|err| Self::error(volume_name, err.to_string()))?;
+        }
+
+        for path in &remote_directories {
+            tokio::fs::create_dir_all(target.join(path))
+                .await
+                .map_err(|err| Self::error(volume_name, err.to_string()))?;
+        }
+        for path in &remote_files {
+            let content = self
+                .blob_storage
+                .with("volume", "restore")
+                .get_raw(namespace.clone(), path)
+                .await
+                .map_err(|err| Self::error(volume_name, err))?
+                .unwrap_or_default();
+            let local_path = target.join(path);
+            if let Some(parent) = local_path.parent() {
+                tokio::fs::create_dir_all(parent)
+                    .await
+                    .map_err(|err| Self::error(volume_name, err.to_string()))?;
+            }
+            tokio::fs::write(&local_path, &content)
+                .await
+                .map_err(|err| Self::error(volume_name, err.to_string()))?;
+        }
+
+        debug!(
+            "Restored volume {volume_name} with {} files into {target:?}",
+            remote_files.len()
+        );
+
+        Self::scan_local(target)
+            .await
+            .map_err(|err| Self::error(volume_name, err))
+    }
+
+    /// Writes back the changes made in `source` since `snapshot` was taken, and updates the
+    /// snapshot to the synced state
+    pub async fn sync(
+        &self,
+        account_id: &AccountId,
+        component_id: &ComponentId,
+        volume_name: &str,
+        source: &Path,
+        snapshot: &mut VolumeSnapshot,
+    ) -> Result<(), GolemError> {
+        if self.read_only {
+            return Ok(());
+        }
+
+        let namespace = Self::namespace(account_id, component_id, volume_name);
+        let storage = self.blob_storage.with("volume", "sync");
+        let current = Self::scan_local(source)
+            .await
+            .map_err(|err| Self::error(volume_name, err))?;
+
+        for path in &current.directories {
+            if !snapshot.directories.contains(path) {
+                storage
+                    .create_dir(namespace.clone(), path)
+                    .await
+                    .map_err(|err| Self::error(volume_name, err))?;
+            }
+        }
+        for (path, state) in &current.files {
+            if snapshot.files.get(path) != Some(state) {
+                let content = tokio::fs::read(source.join(path))
+                    .await
+                    .map_err(|err| Self::error(volume_name, err.to_string()))?;
+                storage
+                    .put_raw(namespace.clone(), path, &content)
+                    .await
+                    .map_err(|err| Self::error(volume_name, err))?;
+            }
+        }
+
+        let deleted_files = snapshot
+            .files
+            .keys()
+            .filter(|path| !current.files.contains_key(*path))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !deleted_files.is_empty() {
+            storage
+                .delete_many(namespace.clone(), &deleted_files)
+                .await
+                .map_err(|err| Self::error(volume_name, err))?;
+        }
+        for path in &snapshot.directories {
+            if !current.directories.contains(path) {
+                storage
+                    .delete_dir(namespace.clone(), path)
+                    .await
+                    .map_err(|err| Self::error(volume_name, err))?;
+            }
+        }
+
+        *snapshot = current;
+        Ok(())
+    }
+
+    fn namespace(
+        account_id: &AccountId,
+        component_id: &ComponentId,
+        volume_name: &str,
+    ) -> BlobStorageNamespace {
+        BlobStorageNamespace::WorkerVolume {
+            account_id: account_id.clone(),
+            component_id: component_id.clone(),
+            volume_name: volume_name.to_string(),
+        }
+    }
+
+    fn error(volume_name: &str, reason: impl AsRef<str>) -> GolemError {
+        GolemError::FileSystemError {
+            path: volume_name.to_string(),
+            reason: format!("Failed to synchronize volume: {}", reason.as_ref()),
+        }
+    }
+
+    async fn list_remote(
+        &self,
+        namespace: BlobStorageNamespace,
+    ) -> Result<(HashSet<PathBuf>, HashSet<PathBuf>), String> {
+        let storage = self.blob_storage.with("volume", "list");
+        let mut files = HashSet::new();
+        let mut directories = HashSet::new();
+        let mut pending = vec![PathBuf::new()];
+
+        while let Some(dir) = pending.pop() {
+            for entry in storage.list_dir(namespace.clone(), &dir).await? {
+                match storage.exists(namespace.clone(), &entry).await? {
+                    ExistsResult::File => {
+                        files.insert(entry);
+                    }
+                    ExistsResult::Directory => {
+                        directories.insert(entry.clone());
+                        pending.push(entry);
+                    }
+                    ExistsResult::DoesNotExist => {}
+                }
+            }
+        }
+
+        Ok((files, directories))
+    }
+
+    async fn scan_local(root: &Path) -> Result<VolumeSnapshot, String> {
+        let mut snapshot = VolumeSnapshot::default();
+        let mut pending = vec![PathBuf::new()];
+
+        while let Some(dir) = pending.pop() {
+            let mut entries = tokio::fs::read_dir(root.join(&dir))
+                .await
+                .map_err(|err| err.to_string())?;
+            while let Some(entry) = entries.next_entry().await.map_err(|err| err.to_string())? {
+                let path = dir.join(entry.file_name());
+                let metadata = tokio::fs::symlink_metadata(entry.path())
+                    .await
+                    .map_err(|err| err.to_string())?;
+                if metadata.is_dir() {
+                    snapshot.directories.insert(path.clone());
+                    pending.push(path);
+                } else if metadata.is_file() {
+                    let modified = metadata.modified().map_err(|err| err.to_string())?;
+                    snapshot.files.insert(
+                        path,
+                        FileState {
+                            modified,
+                            size: metadata.len(),
+                        },
+                    );
+                }
+            }
+        }
+
+        Ok(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::VolumeService;
+    use golem_common::model::{AccountId, ComponentId};
+    use golem_service_base::storage::blob::memory::InMemoryBlobStorage;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn service() -> VolumeService {
+        VolumeService::new(Arc::new(InMemoryBlobStorage::new()))
+    }
+
+    fn ids() -> (AccountId, ComponentId) {
+        (
+            AccountId {
+                value: "test-account".to_string(),
+            },
+            ComponentId(Uuid::new_v4()),
+        )
+    }
+
+    #[test]
+    async fn synced_changes_are_visible_to_newly_loaded_workers() {
+        let service = service();
+        let (account_id, component_id) = ids();
+
+        let worker1 = tempfile::tempdir().unwrap();
+        let mut snapshot = service
+            .restore(&account_id, &component_id, "data", worker1.path())
+            .await
+            .unwrap();
+        std::fs::create_dir_all(worker1.path().join("a/b")).unwrap();
+        std::fs::write(worker1.path().join("a/b/hello.txt"), "hello").unwrap();
+        std::fs::create_dir(worker1.path().join("empty")).unwrap();
+        service
+            .sync(
+                &account_id,
+                &component_id,
+                "data",
+                worker1.path(),
+                &mut snapshot,
+            )
+            .await
+            .unwrap();
+
+        let worker2 = tempfile::tempdir().unwrap();
+        service
+            .restore(&account_id, &component_id, "data", worker2.path())
+            .await
+            .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(worker2.path().join("a/b/hello.txt")).unwrap(),
+            "hello"
+        );
+        assert!(worker2.path().join("empty").is_dir());
+    }
+
+    #[test]
+    async fn unsynced_changes_are_discarded_by_restore() {
+        let service = service();
+        let (account_id, component_id) = ids();
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut snapshot = service
+            .restore(&account_id, &component_id, "data", dir.path())
+            .await
+            .unwrap();
+        std::fs::write(dir.path().join("kept.txt"), "synced").unwrap();
+        service
+            .sync(
+                &account_id,
+                &component_id,
+                "data",
+                dir.path(),
+                &mut snapshot,
+            )
+            .await
+            .unwrap();
+
+        // Changes made while replaying the oplog are never synced and get reverted
+        std::fs::write(dir.path().join("kept.txt"), "replayed").unwrap();
+        std::fs::write(dir.path().join("new.txt"), "replayed").unwrap();
+        service
+            .restore(&account_id, &component_id, "data", dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("kept.txt")).unwrap(),
+            "synced"
+        );
+        assert!(!dir.path().join("new.txt").exists());
+    }
+
+    #[test]
+    async fn deletions_are_synced() {
+        let service = service();
+        let (account_id, component_id) = ids();
+
+        let worker1 = tempfile::tempdir().unwrap();
+        let mut snapshot = service
+            .restore(&account_id, &component_id, "data", worker1.path())
+            .await
+            .unwrap();
+        std::fs::create_dir(worker1.path().join("dir")).unwrap();
+        std::fs::write(worker1.path().join("dir/file.txt"), "content").unwrap();
+        service
+            .sync(
+                &account_id,
+                &component_id,
+                "data",
+                worker1.path(),
+                &mut snapshot,
+            )
+            .await
+            .unwrap();
+
+        std::fs::remove_dir_all(worker1.path().join("dir")).unwrap();
+        service
+            .sync(
+                &account_id,
+                &component_id,
+                "data",
+                worker1.path(),
+                &mut snapshot,
+            )
+            .await
+            .unwrap();
+
+        let worker2 = tempfile::tempdir().unwrap();
+        service
+            .restore(&account_id, &component_id, "data", worker2.path())
+            .await
+            .unwrap();
+        assert!(!worker2.path().join("dir").exists());
+    }
+
+    #[test]
+    async fn last_writer_wins_between_workers() {
+        let service = service();
+        let (account_id, component_id) = ids();
+
+        let worker1 = tempfile::tempdir().unwrap();
+        let worker2 = tempfile::tempdir().unwrap();
+        let mut snapshot1 = service
+            .restore(&account_id, &component_id, "data", worker1.path())
+            .await
+            .unwrap();
+        let mut snapshot2 = service
+            .restore(&account_id, &component_id, "data", worker2.path())
+            .await
+            .unwrap();
+
+        std::fs::write(worker1.path().join("shared.txt"), "from worker 1").unwrap();
+        std::fs::write(worker2.path().join("shared.txt"), "from worker 2").unwrap();
+        service
+            .sync(
+                &account_id,
+                &component_id,
+                "data",
+                worker1.path(),
+                &mut snapshot1,
+            )
+            .await
+            .unwrap();
+        service
+            .sync(
+                &account_id,
+                &component_id,
+                "data",
+                worker2.path(),
+                &mut snapshot2,
+            )
+            .await
+            .unwrap();
+
+        // Workers do not observe each other's changes until they get loaded again
+        assert_eq!(
+            std::fs::read_to_string(worker1.path().join("shared.txt")).unwrap(),
+            "from worker 1"
+        );
+
+        let worker3 = tempfile::tempdir().unwrap();
+        service
+            .restore(&account_id, &component_id, "data", worker3.path())
+            .await
+            .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(worker3.path().join("shared.txt")).unwrap(),
+            "from worker 2"
+        );
+    }
+
+    #[test]
+    async fn volumes_are_isolated_by_name() {
+        let service = service();
+        let (account_id, component_id) = ids();
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut snapshot = service
+            .restore(&account_id, &component_id, "first", dir.path())
+            .await
+            .unwrap();
+        std::fs::write(dir.path().join("file.txt"), "content").unwrap();
+        service
+            .sync(
+                &account_id,
+                &component_id,
+                "first",
+                dir.path(),
+                &mut snapshot,
+            )
+            .await
+            .unwrap();
+
+        let other = tempfile::tempdir().unwrap();
+        service
+            .restore(&account_id, &component_id, "second", other.path())
+            .await
+            .unwrap();
+        assert!(!other.path().join("file.txt").exists());
+    }
+}
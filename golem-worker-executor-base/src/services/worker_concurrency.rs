@@ -0,0 +1,599 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use tokio::sync::oneshot;
+use tracing::debug;
+
+use golem_common::model::{AccountId, ComponentId, OwnedWorkerId, WorkerId};
+
+use crate::metrics::concurrency::{record_waiting_workers, record_worker_admission_time};
+use crate::model::InvocationPriority;
+use crate::services::golem_config::ConcurrencyConfig;
+
+/// The concurrency limit a worker is waiting for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConcurrencyScope {
+    Executor,
+    Component,
+    Account,
+}
+
+/// Limits the number of running workers of the executor, of each component and of each account.
+///
+/// Waiting workers are admitted by priority first. Between equal priorities the account with the
+/// fewest running workers goes first, so a burst of one account cannot starve the others, and
+/// within an account workers are admitted in the order they started waiting.
+pub struct WorkerConcurrencyLimiter {
+    state: Arc<Mutex<LimiterState>>,
+}
+
+impl WorkerConcurrencyLimiter {
+    pub fn new(config: &ConcurrencyConfig) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(LimiterState {
+                config: config.clone(),
+                next_ticket: 0,
+                running: 0,
+                running_per_component: HashMap::new(),
+                running_per_account: HashMap::new(),
+                blocked: HashMap::new(),
+                waiting: Vec::new(),
+            })),
+        }
+    }
+
+    /// Waits until the worker fits into the concurrency limits. The worker counts as running
+    /// until the returned permit is dropped.
+    pub async fn acquire(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        priority: InvocationPriority,
+    ) -> WorkerConcurrencyPermit {
+        let start = Instant::now();
+        let (sender, receiver) = oneshot::channel();
+
+        let ticket = {
+            let mut state = self.state.lock().unwrap();
+            let ticket = state.next_ticket;
+            state.next_ticket += 1;
+            state.waiting.push(WaitingWorker {
+                ticket,
+                owned_worker_id: owned_worker_id.clone(),
+                priority,
+                sender,
+            });
+            state.record_waiting(&owned_worker_id.worker_id.component_id);
+            state.dispatch();
+            ticket
+        };
+
+        let mut pending = PendingPermit {
+            state: self.state.clone(),
+            ticket,
+            owned_worker_id: Some(owned_worker_id.clone()),
+        };
+
+        receiver
+            .await
+            .expect("concurrency limiter dropped a waiting worker");
+        pending.owned_worker_id = None;
+
+        record_worker_admission_time(&priority.to_string(), start.elapsed());
+
+        WorkerConcurrencyPermit {
+            state: self.state.clone(),
+            owned_worker_id: owned_worker_id.clone(),
+        }
+    }
+
+    /// Raises the priority of a waiting worker, for example when an interactive invocation
+    /// arrives for a worker which was started by a background one.
+    pub fn raise_priority(&self, worker_id: &WorkerId, priority: InvocationPriority) {
+        let mut state = self.state.lock().unwrap();
+        for waiting in state
+            .waiting
+            .iter_mut()
+            .filter(|waiting| &waiting.owned_worker_id.worker_id == worker_id)
+        {
+            waiting.priority = waiting.priority.max(priority);
+        }
+    }
+
+    /// Returns the limit the worker would have to wait for if it was started now
+    pub fn blocking_scope(&self, owned_worker_id: &OwnedWorkerId) -> Option<ConcurrencyScope> {
+        self.state.lock().unwrap().blocking_scope(owned_worker_id)
+    }
+
+    /// Stops counting a running worker while it waits for another worker, so the other worker
+    /// can be admitted even if the caller is the one keeping a limit full. The worker counts as
+    /// running again when the returned guard is dropped, even if that exceeds a limit.
+    pub fn blocked(&self, owned_worker_id: &OwnedWorkerId) -> BlockedWorker {
+        let mut state = self.state.lock().unwrap();
+        let blocked = state
+            .blocked
+            .entry(owned_worker_id.worker_id.clone())
+            .or_default();
+        *blocked += 1;
+        if *blocked == 1 {
+            state.release(owned_worker_id);
+            state.dispatch();
+        }
+        BlockedWorker {
+            state: self.state.clone(),
+            owned_worker_id: owned_worker_id.clone(),
+        }
+    }
+}
+
+/// Counts a worker as running until dropped
+pub struct WorkerConcurrencyPermit {
+    state: Arc<Mutex<LimiterState>>,
+    owned_worker_id: OwnedWorkerId,
+}
+
+impl Drop for WorkerConcurrencyPermit {
+    fn drop(&mut self) {
+        let mut state = self.state.lock().unwrap();
+        // A blocked worker is not counted anymore
+        if state
+            .blocked
+            .remove(&self.owned_worker_id.worker_id)
+            .is_none()
+        {
+            state.release(&self.owned_worker_id);
+        }
+        state.dispatch();
+    }
+}
+
+/// Counts a blocked worker as running again when dropped
+pub struct BlockedWorker {
+    state: Arc<Mutex<LimiterState>>,
+    owned_worker_id: OwnedWorkerId,
+}
+
+impl Drop for BlockedWorker {
+    fn drop(&mut self) {
+        let mut state = self.state.lock().unwrap();
+        let worker_id = &self.owned_worker_id.worker_id;
+        // Not counted again if the worker has been stopped in the meantime
+        if let Some(blocked) = state.blocked.get_mut(worker_id) {
+            *blocked -= 1;
+            if *blocked == 0 {
+                state.blocked.remove(worker_id);
+                state.admit(&self.owned_worker_id);
+            }
+        }
+    }
+}
+
+/// Cleans up after an `acquire` call which was cancelled while waiting
+struct PendingPermit {
+    state: Arc<Mutex<LimiterState>>,
+    ticket: u64,
+    owned_worker_id: Option<OwnedWorkerId>,
+}
+
+impl Drop for PendingPermit {
+    fn drop(&mut self) {
+        if let Some(owned_worker_id) = self.owned_worker_id.take() {
+            let mut state = self.state.lock().unwrap();
+            match state
+                .waiting
+                .iter()
+                .position(|waiting| waiting.ticket == self.ticket)
+            {
+                Some(idx) => {
+                    state.waiting.remove(idx);
+                    state.record_waiting(&owned_worker_id.worker_id.component_id);
+                }
+                None => {
+                    // Admitted, but cancelled before the permit was taken over
+                    state.release(&owned_worker_id);
+                    state.dispatch();
+                }
+            }
+        }
+    }
+}
+
+struct WaitingWorker {
+    ticket: u64,
+    owned_worker_id: OwnedWorkerId,
+    priority: InvocationPriority,
+    sender: oneshot::Sender<()>,
+}
+
+struct LimiterState {
+    config: ConcurrencyConfig,
+    next_ticket: u64,
+    running: usize,
+    running_per_component: HashMap<ComponentId, usize>,
+    running_per_account: HashMap<AccountId, usize>,
+    /// Running workers not counted while waiting for other workers, by the number of guards
+    blocked: HashMap<WorkerId, usize>,
+    waiting: Vec<WaitingWorker>,
+}
+
+impl LimiterState {
+    fn running_of_component(&self, component_id: &ComponentId) -> usize {
+        self.running_per_component
+            .get(component_id)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn running_of_account(&self, account_id: &AccountId) -> usize {
+        self.running_per_account
+            .get(account_id)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn blocking_scope(&self, owned_worker_id: &OwnedWorkerId) -> Option<ConcurrencyScope> {
+        let component_id = &owned_worker_id.worker_id.component_id;
+        let account_id = &owned_worker_id.account_id;
+
+        if self
+            .config
+            .max_active_workers
+            .is_some_and(|limit| self.running >= limit)
+        {
+            Some(ConcurrencyScope::Executor)
+        } else if self
+            .config
+            .component_limit(component_id)
+            .is_some_and(|limit| self.running_of_component(component_id) >= limit)
+        {
+            Some(ConcurrencyScope::Component)
+        } else if self
+            .config
+            .max_active_workers_per_account
+            .is_some_and(|limit| self.running_of_account(account_id) >= limit)
+        {
+            Some(ConcurrencyScope::Account)
+        } else {
+            None
+        }
+    }
+
+    fn admit(&mut self, owned_worker_id: &OwnedWorkerId) {
+        self.running += 1;
+        *self
+            .running_per_component
+            .entry(owned_worker_id.worker_id.component_id.clone())
+            .or_default() += 1;
+        *self
+            .running_per_account
+            .entry(owned_worker_id.account_id.clone())
+            .or_default() += 1;
+    }
+
+    fn release(&mut self, owned_worker_id: &OwnedWorkerId) {
+        self.running = self.running.saturating_sub(1);
+        let component_id = &owned_worker_id.worker_id.component_id;
+        if let Some(count) = self.running_per_component.get_mut(component_id) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                self.running_per_component.remove(component_id);
+            }
+        }
+        let account_id = &owned_worker_id.account_id;
+        if let Some(count) = self.running_per_account.get_mut(account_id) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                self.running_per_account.remove(account_id);
+            }
+        }
+    }
+
+    /// Admits waiting workers until none of them fits into the limits
+    fn dispatch(&mut self) {
+        while let Some(idx) = self.next_admissible() {
+            let waiting = self.waiting.remove(idx);
+            self.record_waiting(&waiting.owned_worker_id.worker_id.component_id);
+            if waiting.sender.send(()).is_ok() {
+                debug!(
+                    "Admitted worker {} with {} priority",
+                    waiting.owned_worker_id, waiting.priority
+                );
+                self.admit(&waiting.owned_worker_id);
+            }
+        }
+    }
+
+    fn next_admissible(&self) -> Option<usize> {
+        self.waiting
+            .iter()
+            .enumerate()
+            .filter(|(_, waiting)| self.blocking_scope(&waiting.owned_worker_id).is_none())
+            .min_by_key(|(_, waiting)| {
+                (
+                    Reverse(waiting.priority),
+                    self.running_of_account(&waiting.owned_worker_id.account_id),
+                    waiting.ticket,
+                )
+            })
+            .map(|(idx, _)| idx)
+    }
+
+    fn record_waiting(&self, component_id: &ComponentId) {
+        let count = self
+            .waiting
+            .iter()
+            .filter(|waiting| &waiting.owned_worker_id.worker_id.component_id == component_id)
+            .count();
+        record_waiting_workers(&component_id.to_string(), count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use std::time::Duration;
+
+    use golem_common::model::{AccountId, ComponentId, OwnedWorkerId, WorkerId};
+
+    use super::{ConcurrencyScope, WorkerConcurrencyLimiter};
+    use crate::model::InvocationPriority;
+    use crate::services::golem_config::ConcurrencyConfig;
+
+    fn worker(account: &str, component_id: &ComponentId, name: &str) -> OwnedWorkerId {
+        OwnedWorkerId::new(
+            &AccountId {
+                value: account.to_string(),
+            },
+            &WorkerId {
+                component_id: component_id.clone(),
+                worker_name: name.to_string(),
+            },
+        )
+    }
+
+    fn executor_limit(max_active_workers: usize) -> ConcurrencyConfig {
+        ConcurrencyConfig {
+            max_active_workers: Some(max_active_workers),
+            ..ConcurrencyConfig::default()
+        }
+    }
+
+    async fn is_admitted(
+        limiter: &WorkerConcurrencyLimiter,
+        owned_worker_id: &OwnedWorkerId,
+        priority: InvocationPriority,
+    ) -> bool {
+        tokio::time::timeout(
+            Duration::from_millis(50),
+            limiter.acquire(owned_worker_id, priority),
+        )
+        .await
+        .is_ok()
+    }
+
+    #[test]
+    async fn component_limit_blocks_only_the_same_component() {
+        let component1 = ComponentId::new_v4();
+        let component2 = ComponentId::new_v4();
+        let limiter = WorkerConcurrencyLimiter::new(&ConcurrencyConfig {
+            max_active_workers_per_component: Some(1),
+            ..ConcurrencyConfig::default()
+        });
+
+        let w1 = worker("a", &component1, "w1");
+        let w2 = worker("a", &component1, "w2");
+        let w3 = worker("a", &component2, "w3");
+
+        let permit1 = limiter.acquire(&w1, InvocationPriority::Background).await;
+        assert_eq!(
+            limiter.blocking_scope(&w2),
+            Some(ConcurrencyScope::Component)
+        );
+        assert!(!is_admitted(&limiter, &w2, InvocationPriority::Background).await);
+        assert!(is_admitted(&limiter, &w3, InvocationPriority::Background).await);
+
+        drop(permit1);
+        assert_eq!(limiter.blocking_scope(&w2), None);
+        assert!(is_admitted(&limiter, &w2, InvocationPriority::Background).await);
+    }
+
+    #[test]
+    async fn component_override_takes_precedence() {
+        let component1 = ComponentId::new_v4();
+        let limiter = WorkerConcurrencyLimiter::new(&ConcurrencyConfig {
+            max_active_workers_per_component: Some(1),
+            components: [(component1.to_string(), 2)].into_iter().collect(),
+            ..ConcurrencyConfig::default()
+        });
+
+        let _permit1 = limiter
+            .acquire(
+                &worker("a", &component1, "w1"),
+                InvocationPriority::Background,
+            )
+            .await;
+        let _permit2 = limiter
+            .acquire(
+                &worker("a", &component1, "w2"),
+                InvocationPriority::Background,
+            )
+            .await;
+        assert_eq!(
+            limiter.blocking_scope(&worker("a", &component1, "w3")),
+            Some(ConcurrencyScope::Component)
+        );
+    }
+
+    #[test]
+    async fn interactive_workers_are_admitted_first() {
+        let component_id = ComponentId::new_v4();
+        let limiter = std::sync::Arc::new(WorkerConcurrencyLimiter::new(&executor_limit(1)));
+
+        let permit = limiter
+            .acquire(
+                &worker("a", &component_id, "w1"),
+                InvocationPriority::Background,
+            )
+            .await;
+
+        let background = {
+            let limiter = limiter.clone();
+            let owned_worker_id = worker("a", &component_id, "w2");
+            tokio::spawn(async move {
+                limiter
+                    .acquire(&owned_worker_id, InvocationPriority::Background)
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let interactive = {
+            let limiter = limiter.clone();
+            let owned_worker_id = worker("a", &component_id, "w3");
+            tokio::spawn(async move {
+                limiter
+                    .acquire(&owned_worker_id, InvocationPriority::Interactive)
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        drop(permit);
+        let interactive_permit = interactive.await.unwrap();
+        assert!(!background.is_finished());
+
+        drop(interactive_permit);
+        background.await.unwrap();
+    }
+
+    #[test]
+    async fn accounts_with_fewer_running_workers_are_admitted_first() {
+        let component_id = ComponentId::new_v4();
+        let limiter = std::sync::Arc::new(WorkerConcurrencyLimiter::new(&executor_limit(2)));
+
+        let permit_a1 = limiter
+            .acquire(
+                &worker("a", &component_id, "a1"),
+                InvocationPriority::Background,
+            )
+            .await;
+        let _permit_a2 = limiter
+            .acquire(
+                &worker("a", &component_id, "a2"),
+                InvocationPriority::Background,
+            )
+            .await;
+
+        let a3 = {
+            let limiter = limiter.clone();
+            let owned_worker_id = worker("a", &component_id, "a3");
+            tokio::spawn(async move {
+                limiter
+                    .acquire(&owned_worker_id, InvocationPriority::Background)
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let b1 = {
+            let limiter = limiter.clone();
+            let owned_worker_id = worker("b", &component_id, "b1");
+            tokio::spawn(async move {
+                limiter
+                    .acquire(&owned_worker_id, InvocationPriority::Background)
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        drop(permit_a1);
+        let _permit_b1 = b1.await.unwrap();
+        assert!(!a3.is_finished());
+    }
+
+    #[test]
+    async fn blocked_workers_do_not_count() {
+        let component_id = ComponentId::new_v4();
+        let limiter = WorkerConcurrencyLimiter::new(&ConcurrencyConfig {
+            max_active_workers_per_account: Some(1),
+            ..ConcurrencyConfig::default()
+        });
+
+        let caller = worker("a", &component_id, "caller");
+        let callee = worker("a", &component_id, "callee");
+        let other = worker("a", &component_id, "other");
+
+        let _caller_permit = limiter
+            .acquire(&caller, InvocationPriority::Interactive)
+            .await;
+        assert!(!is_admitted(&limiter, &callee, InvocationPriority::Interactive).await);
+
+        let blocked = limiter.blocked(&caller);
+        let callee_permit = limiter
+            .acquire(&callee, InvocationPriority::Interactive)
+            .await;
+        assert_eq!(
+            limiter.blocking_scope(&other),
+            Some(ConcurrencyScope::Account)
+        );
+
+        drop(callee_permit);
+        assert_eq!(limiter.blocking_scope(&other), None);
+
+        drop(blocked);
+        assert_eq!(
+            limiter.blocking_scope(&other),
+            Some(ConcurrencyScope::Account)
+        );
+    }
+
+    #[test]
+    async fn workers_stopped_while_blocked_are_released_once() {
+        let component_id = ComponentId::new_v4();
+        let limiter = WorkerConcurrencyLimiter::new(&executor_limit(1));
+
+        let w1 = worker("a", &component_id, "w1");
+        let w2 = worker("a", &component_id, "w2");
+
+        let permit = limiter.acquire(&w1, InvocationPriority::Background).await;
+        let blocked = limiter.blocked(&w1);
+        drop(permit);
+        drop(blocked);
+
+        let _permit2 = limiter.acquire(&w2, InvocationPriority::Background).await;
+        assert_eq!(
+            limiter.blocking_scope(&w1),
+            Some(ConcurrencyScope::Executor)
+        );
+    }
+
+    #[test]
+    async fn cancelled_waiting_releases_its_place() {
+        let component_id = ComponentId::new_v4();
+        let limiter = WorkerConcurrencyLimiter::new(&executor_limit(1));
+
+        let w1 = worker("a", &component_id, "w1");
+        let w2 = worker("a", &component_id, "w2");
+        let w3 = worker("a", &component_id, "w3");
+
+        let permit = limiter.acquire(&w1, InvocationPriority::Background).await;
+        assert!(!is_admitted(&limiter, &w2, InvocationPriority::Interactive).await);
+
+        drop(permit);
+        assert!(is_admitted(&limiter, &w3, InvocationPriority::Background).await);
+    }
+}
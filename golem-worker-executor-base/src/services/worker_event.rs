@@ -194,6 +194,7 @@ fn label(event: &WorkerEvent) -> &'static str {
         WorkerEvent::Log { .. } => "log",
         WorkerEvent::InvocationStart { .. } => "invocation_start",
         WorkerEvent::InvocationFinished { .. } => "invocation_finished",
+        WorkerEvent::WorkerFailed { .. } => "worker_failed",
         WorkerEvent::Close => "close",
     }
 }
@@ -18,9 +18,11 @@ use async_trait::async_trait;
 use bincode::{Decode, Encode};
 use golem_api_grpc::proto::golem::worker::v1::worker_service_client::WorkerServiceClient;
 use golem_api_grpc::proto::golem::worker::v1::{
-    invoke_and_await_typed_response, invoke_response, update_worker_response, worker_error,
-    InvokeAndAwaitRequest, InvokeAndAwaitTypedResponse, InvokeRequest, InvokeResponse,
-    UpdateWorkerRequest, UpdateWorkerResponse, WorkerError,
+    cancel_invocation_response, invoke_and_await_typed_response, invoke_response,
+    publish_topic_message_response, update_worker_response, worker_error, CancelInvocationRequest,
+    CancelInvocationResponse, InvokeAndAwaitRequest, InvokeAndAwaitTypedResponse, InvokeRequest,
+    InvokeResponse, PublishTopicMessageRequest, PublishTopicMessageResponse, UpdateWorkerRequest,
+    UpdateWorkerResponse, WorkerError,
 };
 use golem_api_grpc::proto::golem::worker::{InvocationContext, InvokeParameters, UpdateMode};
 use golem_common::client::GrpcClient;
@@ -66,6 +68,22 @@ pub trait WorkerProxy {
         target_version: ComponentVersion,
         mode: UpdateMode,
     ) -> Result<(), WorkerProxyError>;
+
+    async fn cancel_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idempotency_key: &IdempotencyKey,
+    ) -> Result<bool, WorkerProxyError>;
+
+    /// Publishes a message to a topic of the worker service. Publishing the same message id
+    /// again is a no-op.
+    async fn publish_to_topic(
+        &self,
+        publisher: &OwnedWorkerId,
+        topic: String,
+        message_id: Uuid,
+        message: WitValue,
+    ) -> Result<(), WorkerProxyError>;
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
@@ -190,6 +208,8 @@ impl WorkerProxy for RemoteWorkerProxy {
             .collect();
         let invoke_parameters = Some(InvokeParameters {
             params: proto_params,
+            retry_policy: None,
+            timeout_millis: None,
         });
 
         let response: InvokeAndAwaitTypedResponse = self
@@ -256,6 +276,8 @@ impl WorkerProxy for RemoteWorkerProxy {
             .collect();
         let invoke_parameters = Some(InvokeParameters {
             params: proto_params,
+            retry_policy: None,
+            timeout_millis: None,
         });
 
         let response: InvokeResponse = self
@@ -319,4 +341,70 @@ impl WorkerProxy for RemoteWorkerProxy {
             ))),
         }
     }
+
+    async fn cancel_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idempotency_key: &IdempotencyKey,
+    ) -> Result<bool, WorkerProxyError> {
+        debug!("Cancelling pending invocation {idempotency_key} of remote worker");
+
+        let response: CancelInvocationResponse = self
+            .client
+            .call("cancel_invocation", move |client| {
+                Box::pin(client.cancel_invocation(authorised_grpc_request(
+                    CancelInvocationRequest {
+                        worker_id: Some(owned_worker_id.worker_id().into()),
+                        idempotency_key: Some(idempotency_key.clone().into()),
+                    },
+                    &self.access_token,
+                )))
+            })
+            .await?
+            .into_inner();
+
+        match response.result {
+            Some(cancel_invocation_response::Result::Success(cancelled)) => Ok(cancelled),
+            Some(cancel_invocation_response::Result::Error(error)) => Err(error.into()),
+            None => Err(WorkerProxyError::InternalError(GolemError::unknown(
+                "Empty response through the worker API".to_string(),
+            ))),
+        }
+    }
+    async fn publish_to_topic(
+        &self,
+        publisher: &OwnedWorkerId,
+        topic: String,
+        message_id: Uuid,
+        message: WitValue,
+    ) -> Result<(), WorkerProxyError> {
+        debug!("Publishing message {message_id} to topic {topic}");
+
+        let value: Value = message.into();
+        let message = value.into();
+
+        let response: PublishTopicMessageResponse = self
+            .client
+            .call("publish_topic_message", move |client| {
+                Box::pin(client.publish_topic_message(authorised_grpc_request(
+                    PublishTopicMessageRequest {
+                        publisher: Some(publisher.worker_id().into()),
+                        topic: topic.clone(),
+                        message_id: message_id.to_string(),
+                        message: Some(message.clone()),
+                    },
+                    &self.access_token,
+                )))
+            })
+            .await?
+            .into_inner();
+
+        match response.result {
+            Some(publish_topic_message_response::Result::Success(_)) => Ok(()),
+            Some(publish_topic_message_response::Result::Error(error)) => Err(error.into()),
+            None => Err(WorkerProxyError::InternalError(GolemError::unknown(
+                "Empty response through the worker API".to_string(),
+            ))),
+        }
+    }
 }
@@ -0,0 +1,309 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use http_02::{Response, StatusCode};
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use warp::filters::BoxedFilter;
+use warp::hyper::Body;
+use warp::Filter;
+
+use crate::model::InterruptKind;
+use crate::services::{HasActiveWorkers, HasShardManagerService};
+use crate::workerctx::WorkerCtx;
+
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutorState {
+    /// The executor is starting up and has not registered with the shard manager yet
+    Starting,
+    /// The executor accepts invocations
+    Serving,
+    /// The executor is shutting down and does not accept new invocations
+    Draining,
+}
+
+impl Display for ExecutorState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExecutorState::Starting => write!(f, "starting"),
+            ExecutorState::Serving => write!(f, "serving"),
+            ExecutorState::Draining => write!(f, "draining"),
+        }
+    }
+}
+
+/// Shared view of the executor's lifecycle, used by the gRPC API to reject new invocations
+/// while draining and by the readiness probe.
+pub struct ExecutorLifecycle {
+    state: RwLock<ExecutorState>,
+}
+
+impl Default for ExecutorLifecycle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExecutorLifecycle {
+    pub fn new() -> Self {
+        Self {
+            state: RwLock::new(ExecutorState::Starting),
+        }
+    }
+
+    pub fn state(&self) -> ExecutorState {
+        *self.state.read().unwrap()
+    }
+
+    pub fn set_serving(&self) {
+        let mut state = self.state.write().unwrap();
+        if *state == ExecutorState::Starting {
+            *state = ExecutorState::Serving;
+        }
+    }
+
+    pub fn start_draining(&self) {
+        *self.state.write().unwrap() = ExecutorState::Draining;
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.state() == ExecutorState::Serving
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.state() == ExecutorState::Draining
+    }
+}
+
+/// Handle to the executor's graceful shutdown, returned by `Bootstrap::run`
+///
+/// The graceful shutdown starts either on a termination signal or when requested through this
+/// handle. The process is expected to exit once it has completed.
+#[derive(Clone, Default)]
+pub struct ShutdownHandle {
+    requested: CancellationToken,
+    completed: CancellationToken,
+}
+
+impl ShutdownHandle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts the graceful shutdown the same way as a termination signal
+    pub fn request(&self) {
+        self.requested.cancel();
+    }
+
+    /// Waits until the graceful shutdown has been requested or a termination signal has arrived
+    pub async fn requested(&self) {
+        tokio::select! {
+            _ = self.requested.cancelled() => {},
+            _ = termination_signal() => {},
+        }
+    }
+
+    /// Waits until the graceful shutdown has successfully finished. Never resolves when the
+    /// graceful shutdown is disabled.
+    pub async fn completed(&self) {
+        self.completed.cancelled().await
+    }
+
+    pub(crate) fn complete(&self) {
+        self.completed.cancel();
+    }
+}
+
+/// `/liveness` and `/readiness` routes for the health server.
+///
+/// Liveness only reports that the process is up, while readiness is only successful when the
+/// executor is registered and not draining.
+pub fn lifecycle_routes(lifecycle: Arc<ExecutorLifecycle>) -> BoxedFilter<(Response<Body>,)> {
+    let liveness = warp::path!("liveness").map(|| {
+        Response::builder()
+            .status(StatusCode::OK)
+            .body(Body::from("alive"))
+            .unwrap()
+    });
+
+    let readiness = warp::path!("readiness").map(move || {
+        let state = lifecycle.state();
+        let status = if state == ExecutorState::Serving {
+            StatusCode::OK
+        } else {
+            StatusCode::SERVICE_UNAVAILABLE
+        };
+        Response::builder()
+            .status(status)
+            .body(Body::from(state.to_string()))
+            .unwrap()
+    });
+
+    liveness.or(readiness).unify().boxed()
+}
+
+/// Waits for a termination signal (SIGTERM or Ctrl-C)
+pub async fn termination_signal() {
+    #[cfg(unix)]
+    {
+        let mut sigterm = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Failed to install SIGTERM handler");
+        tokio::select! {
+            _ = sigterm.recv() => {},
+            _ = tokio::signal::ctrl_c() => {},
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
+
+/// Gracefully shuts down the executor.
+///
+/// New invocations are rejected as soon as the lifecycle switches to draining. Idle workers are
+/// stopped right away, busy ones get `drain_timeout` to reach a completion or suspension point
+/// before being interrupted. Finally all oplogs are committed and the executor unregisters from
+/// the shard manager so its shards get reassigned without waiting for the health check.
+pub async fn graceful_shutdown<Ctx: WorkerCtx, Svcs>(
+    services: &Svcs,
+    lifecycle: &ExecutorLifecycle,
+    host: String,
+    port: u16,
+    drain_timeout: Duration,
+) -> anyhow::Result<()>
+where
+    Svcs: HasActiveWorkers<Ctx> + HasShardManagerService,
+{
+    info!("Starting graceful shutdown, draining in-flight invocations");
+    lifecycle.start_draining();
+
+    let deadline = Instant::now() + drain_timeout;
+    loop {
+        let mut in_flight = 0;
+        for (_, worker) in services.active_workers().iter() {
+            if worker.is_currently_idle_but_running() {
+                worker.stop_if_idle().await;
+            }
+            if worker.is_loaded() {
+                in_flight += 1;
+            }
+        }
+
+        if in_flight == 0 {
+            info!("All in-flight invocations finished");
+            break;
+        }
+        if Instant::now() >= deadline {
+            warn!(
+                "Drain timeout reached with {in_flight} workers still running, interrupting them"
+            );
+            break;
+        }
+        tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+    }
+
+    for (worker_id, worker) in services.active_workers().iter() {
+        if worker.is_loaded() {
+            if let Some(mut await_interrupted) =
+                worker.set_interrupting(InterruptKind::Restart).await
+            {
+                let _ = await_interrupted.recv().await;
+            }
+            worker.stop().await;
+            info!("Interrupted worker {worker_id}, it will be recovered by its new executor");
+        }
+        worker.commit_oplog().await;
+    }
+
+    services
+        .shard_manager_service()
+        .unregister(host, port)
+        .await?;
+
+    info!("Graceful shutdown finished, shards handed back to the shard manager");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::{lifecycle_routes, ExecutorLifecycle, ExecutorState, ShutdownHandle};
+    use http_02::StatusCode;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn lifecycle_only_becomes_serving_from_starting() {
+        let lifecycle = ExecutorLifecycle::new();
+        assert_eq!(lifecycle.state(), ExecutorState::Starting);
+        assert!(!lifecycle.is_ready());
+
+        lifecycle.set_serving();
+        assert!(lifecycle.is_ready());
+
+        lifecycle.start_draining();
+        lifecycle.set_serving();
+        assert!(lifecycle.is_draining());
+        assert!(!lifecycle.is_ready());
+    }
+
+    #[test]
+    async fn readiness_follows_the_lifecycle() {
+        let lifecycle = Arc::new(ExecutorLifecycle::new());
+        let routes = lifecycle_routes(lifecycle.clone());
+
+        let readiness = || warp::test::request().path("/readiness").reply(&routes);
+        let liveness = || warp::test::request().path("/liveness").reply(&routes);
+
+        assert_eq!(readiness().await.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(liveness().await.status(), StatusCode::OK);
+
+        lifecycle.set_serving();
+        assert_eq!(readiness().await.status(), StatusCode::OK);
+
+        lifecycle.start_draining();
+        let response = readiness().await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.body().as_ref(), b"draining");
+        assert_eq!(liveness().await.status(), StatusCode::OK);
+    }
+
+    #[test]
+    async fn shutdown_handle_resolves_on_request_and_completion() {
+        let handle = ShutdownHandle::new();
+
+        let not_yet_completed =
+            tokio::time::timeout(Duration::from_millis(50), handle.completed()).await;
+        assert!(not_yet_completed.is_err());
+
+        handle.request();
+        tokio::time::timeout(Duration::from_secs(1), handle.requested())
+            .await
+            .expect("Shutdown was not requested");
+
+        handle.complete();
+        tokio::time::timeout(Duration::from_secs(1), handle.completed())
+            .await
+            .expect("Shutdown was not completed");
+    }
+}
@@ -22,6 +22,7 @@ use bytes::Bytes;
 use golem_common::serialization::{deserialize, serialize};
 
 pub mod memory;
+pub mod postgres;
 pub mod redis;
 pub mod sqlite;
 
@@ -553,4 +554,6 @@ impl<'a, S: ?Sized + IndexedStorage> LabelledEntityIndexedStorage<'a, S> {
 pub enum IndexedStorageNamespace {
     OpLog,
     CompressedOpLog { level: usize },
+    /// Staging area for the rewritten chunks of a compressed oplog layer while it is being compacted
+    CompactedOpLog { level: usize },
 }
@@ -37,6 +37,9 @@ impl RedisIndexedStorage {
             IndexedStorageNamespace::CompressedOpLog { level } => {
                 format!("worker:c{level}-oplog:{key}")
             }
+            IndexedStorageNamespace::CompactedOpLog { level } => {
+                format!("worker:c{level}-compacted-oplog:{key}")
+            }
         }
     }
 
@@ -60,6 +60,9 @@ impl SqliteIndexedStorage {
             IndexedStorageNamespace::CompressedOpLog { level } => {
                 format!("worker-c{level}-oplog")
             }
+            IndexedStorageNamespace::CompactedOpLog { level } => {
+                format!("worker-c{level}-compacted-oplog")
+            }
         }
     }
 }
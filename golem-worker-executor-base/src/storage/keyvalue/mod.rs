@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod memory;
+pub mod postgres;
 pub mod redis;
 pub mod sqlite;
 
@@ -605,6 +606,7 @@ pub enum KeyValueStorageNamespace {
     Worker,
     Promise,
     Schedule,
+    OplogSink,
     UserDefined {
         account_id: AccountId,
         bucket: String,
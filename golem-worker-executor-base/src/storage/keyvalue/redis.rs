@@ -37,6 +37,7 @@ impl RedisKeyValueStorage {
             KeyValueStorageNamespace::Worker => None,
             KeyValueStorageNamespace::Promise => Some("promises".to_string()),
             KeyValueStorageNamespace::Schedule => None,
+            KeyValueStorageNamespace::OplogSink => Some("oplog-sinks".to_string()),
             KeyValueStorageNamespace::UserDefined { account_id, bucket } => {
                 Some(format!("user-defined:{account_id}:{bucket}"))
             }
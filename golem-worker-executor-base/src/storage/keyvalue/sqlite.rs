@@ -92,6 +92,7 @@ impl SqliteKeyValueStorage {
             KeyValueStorageNamespace::Worker => "worker".to_string(),
             KeyValueStorageNamespace::Promise => "promise".to_string(),
             KeyValueStorageNamespace::Schedule => "schedule".to_string(),
+            KeyValueStorageNamespace::OplogSink => "oplog-sink".to_string(),
             KeyValueStorageNamespace::UserDefined { account_id, bucket } => {
                 format!("user-defined:{}:{}", account_id, bucket)
             }
@@ -14,3 +14,9 @@
 
 pub mod indexed;
 pub mod keyvalue;
+
+use sqlx::migrate::Migrator;
+
+/// Migrations creating the tables of the Postgres key-value and indexed storage. Both storages
+/// apply them when created, so they can share the same database.
+pub static POSTGRES_MIGRATOR: Migrator = sqlx::migrate!("./db/migration/postgres");
@@ -0,0 +1,213 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use golem_common::model::component_metadata::{CapabilityPolicy, HostCapability};
+use wasmtime::component::{Linker, Resource};
+use wasmtime_wasi::bindings::sockets::tcp_create_socket::{IpAddressFamily, TcpSocket};
+use wasmtime_wasi::bindings::sockets::udp_create_socket::UdpSocket;
+use wasmtime_wasi::bindings::sockets::{tcp_create_socket, udp_create_socket};
+use wasmtime_wasi::SocketError;
+use wasmtime_wasi_http::bindings::http::types;
+use wasmtime_wasi_http::bindings::wasi::http::outgoing_handler;
+use wasmtime_wasi_http::types::{HostFutureIncomingResponse, HostOutgoingRequest};
+use wasmtime_wasi_http::{HttpError, HttpResult};
+
+use crate::preview2::wasi::blobstore::blobstore;
+use crate::preview2::wasi::blobstore::blobstore::{Container, ContainerName, ObjectId};
+use crate::workerctx::WorkerCtx;
+
+/// Capabilities enforced by the linker. When a component's capability policy denies one of them,
+/// the entry points of the capability are linked to stubs that trap when called, so the component
+/// still instantiates but cannot use the capability at all.
+///
+/// The other capabilities are checked when their entry points get called, because they are also
+/// used implicitly (the file system by the language runtimes, name lookups by HTTP clients) or
+/// have no single entry point interface.
+pub const LINKED_CAPABILITIES: [HostCapability; 3] = [
+    HostCapability::Http,
+    HostCapability::Sockets,
+    HostCapability::BlobStore,
+];
+
+/// Returns the linker enforced capabilities denied by the given policy
+pub fn linked_denied_capabilities(policy: Option<&CapabilityPolicy>) -> BTreeSet<HostCapability> {
+    LINKED_CAPABILITIES
+        .into_iter()
+        .filter(|capability| policy.is_some_and(|policy| policy.denies(*capability)))
+        .collect()
+}
+
+/// Returns all the subsets of the linker enforced capabilities, each of them requiring a
+/// separate linker
+pub fn linked_capability_sets() -> Vec<BTreeSet<HostCapability>> {
+    (0..(1 << LINKED_CAPABILITIES.len()))
+        .map(|mask: usize| {
+            LINKED_CAPABILITIES
+                .into_iter()
+                .enumerate()
+                .filter(|(idx, _)| mask & (1 << idx) != 0)
+                .map(|(_, capability)| capability)
+                .collect()
+        })
+        .collect()
+}
+
+/// Replaces the entry points of the given denied capabilities, previously added by
+/// `create_linker`, with stubs that trap
+pub fn link_denied_capabilities<Ctx: WorkerCtx>(
+    linker: &mut Linker<Ctx>,
+    denied: &BTreeSet<HostCapability>,
+) -> wasmtime::Result<()> {
+    linker.allow_shadowing(true);
+
+    if denied.contains(&HostCapability::Http) {
+        outgoing_handler::add_to_linker_get_host(linker, get_denied)?;
+    }
+    if denied.contains(&HostCapability::Sockets) {
+        tcp_create_socket::add_to_linker_get_host(linker, get_denied)?;
+        udp_create_socket::add_to_linker_get_host(linker, get_denied)?;
+    }
+    if denied.contains(&HostCapability::BlobStore) {
+        blobstore::add_to_linker_get_host(linker, get_denied)?;
+    }
+
+    linker.allow_shadowing(false);
+    Ok(())
+}
+
+fn get_denied<Ctx>(_ctx: &mut Ctx) -> DeniedCapability {
+    DeniedCapability
+}
+
+/// Host implementation of the entry points of denied capabilities
+pub struct DeniedCapability;
+
+impl DeniedCapability {
+    fn trap(capability: HostCapability) -> anyhow::Error {
+        anyhow!("The {capability} capability is denied by the capability policy of the component")
+    }
+}
+
+#[async_trait]
+impl outgoing_handler::Host for DeniedCapability {
+    async fn handle(
+        &mut self,
+        _request: Resource<HostOutgoingRequest>,
+        _options: Option<Resource<types::RequestOptions>>,
+    ) -> HttpResult<Resource<HostFutureIncomingResponse>> {
+        Err(HttpError::trap(Self::trap(HostCapability::Http)))
+    }
+}
+
+#[async_trait]
+impl tcp_create_socket::Host for DeniedCapability {
+    fn create_tcp_socket(
+        &mut self,
+        _address_family: IpAddressFamily,
+    ) -> Result<Resource<TcpSocket>, SocketError> {
+        Err(SocketError::trap(Self::trap(HostCapability::Sockets)))
+    }
+}
+
+#[async_trait]
+impl udp_create_socket::Host for DeniedCapability {
+    fn create_udp_socket(
+        &mut self,
+        _address_family: IpAddressFamily,
+    ) -> Result<Resource<UdpSocket>, SocketError> {
+        Err(SocketError::trap(Self::trap(HostCapability::Sockets)))
+    }
+}
+
+#[async_trait]
+impl blobstore::Host for DeniedCapability {
+    async fn create_container(
+        &mut self,
+        _name: ContainerName,
+    ) -> anyhow::Result<Result<Resource<Container>, blobstore::Error>> {
+        Err(Self::trap(HostCapability::BlobStore))
+    }
+
+    async fn get_container(
+        &mut self,
+        _name: ContainerName,
+    ) -> anyhow::Result<Result<Resource<Container>, blobstore::Error>> {
+        Err(Self::trap(HostCapability::BlobStore))
+    }
+
+    async fn delete_container(
+        &mut self,
+        _name: ContainerName,
+    ) -> anyhow::Result<Result<(), blobstore::Error>> {
+        Err(Self::trap(HostCapability::BlobStore))
+    }
+
+    async fn container_exists(
+        &mut self,
+        _name: ContainerName,
+    ) -> anyhow::Result<Result<bool, blobstore::Error>> {
+        Err(Self::trap(HostCapability::BlobStore))
+    }
+
+    async fn copy_object(
+        &mut self,
+        _src: ObjectId,
+        _dest: ObjectId,
+    ) -> anyhow::Result<Result<(), blobstore::Error>> {
+        Err(Self::trap(HostCapability::BlobStore))
+    }
+
+    async fn move_object(
+        &mut self,
+        _src: ObjectId,
+        _dest: ObjectId,
+    ) -> anyhow::Result<Result<(), blobstore::Error>> {
+        Err(Self::trap(HostCapability::BlobStore))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::{linked_capability_sets, linked_denied_capabilities};
+    use golem_common::model::component_metadata::{CapabilityPolicy, HostCapability};
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn linked_capability_sets_cover_all_denied_sets() {
+        let sets = linked_capability_sets();
+        let policy = CapabilityPolicy {
+            denied: vec![
+                HostCapability::Sockets,
+                HostCapability::FileSystem,
+                HostCapability::BlobStore,
+            ],
+            allowed_http_hosts: None,
+        };
+        let denied = linked_denied_capabilities(Some(&policy));
+
+        assert_eq!(sets.len(), 8);
+        assert_eq!(
+            denied,
+            BTreeSet::from([HostCapability::Sockets, HostCapability::BlobStore])
+        );
+        assert!(sets.contains(&denied));
+        assert!(linked_denied_capabilities(None).is_empty());
+    }
+}
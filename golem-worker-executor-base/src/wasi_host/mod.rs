@@ -23,6 +23,7 @@ use wasmtime_wasi::{
     DirPerms, FilePerms, ResourceTable, StdinStream, StdoutStream, WasiCtx, WasiCtxBuilder,
 };
 
+pub mod capabilities;
 pub mod helpers;
 pub mod logging;
 
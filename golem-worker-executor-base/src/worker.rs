@@ -24,17 +24,18 @@ use crate::error::{GolemError, WorkerOutOfMemory};
 use crate::function_result_interpreter::interpret_function_results;
 use crate::invocation::{invoke_worker, InvokeResult};
 use crate::model::{
-    ExecutionStatus, InterruptKind, ListDirectoryResult, LookupResult, ReadFileResult, TrapType,
-    WorkerConfig,
+    DeleteFileResult, ExecutionStatus, InterruptKind, InvocationPriority, ListDirectoryResult,
+    LookupResult, MakeDirectoryResult, PutFileResult, ReadFileResult, TrapType, WorkerConfig,
 };
 use crate::services::component::ComponentMetadata;
 use crate::services::events::Event;
 use crate::services::oplog::{CommitLevel, Oplog, OplogOps};
+use crate::services::worker_concurrency::WorkerConcurrencyPermit;
 use crate::services::worker_event::{WorkerEventService, WorkerEventServiceDefault};
 use crate::services::{
     All, HasActiveWorkers, HasAll, HasBlobStoreService, HasComponentService, HasConfig, HasEvents,
     HasExtraDeps, HasFileLoader, HasKeyValueService, HasOplog, HasOplogService, HasPlugins,
-    HasPromiseService, HasRpc, HasSchedulerService, HasWasmtimeEngine, HasWorker,
+    HasPromiseService, HasRpc, HasSchedulerService, HasVolumeService, HasWasmtimeEngine, HasWorker,
     HasWorkerEnumerationService, HasWorkerProxy, HasWorkerService, UsesAllDeps,
 };
 use crate::workerctx::{PublicWorkerIo, WorkerCtx};
@@ -51,9 +52,9 @@ use golem_common::model::{
     exports, ComponentFilePath, ComponentType, PluginInstallationId, WorkerStatusRecordExtensions,
 };
 use golem_common::model::{
-    ComponentVersion, FailedUpdateRecord, IdempotencyKey, OwnedWorkerId, SuccessfulUpdateRecord,
-    Timestamp, TimestampedWorkerInvocation, WorkerId, WorkerInvocation, WorkerMetadata,
-    WorkerResourceDescription, WorkerStatus, WorkerStatusRecord,
+    ComponentVersion, FailedUpdateRecord, IdempotencyKey, InvocationOptions, OwnedWorkerId,
+    SuccessfulUpdateRecord, Timestamp, TimestampedWorkerInvocation, WorkerId, WorkerInvocation,
+    WorkerMetadata, WorkerResourceDescription, WorkerStatus, WorkerStatusRecord,
 };
 use golem_common::retries::get_delay;
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
@@ -222,7 +223,10 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         let queue = Arc::new(RwLock::new(VecDeque::from_iter(
             initial_pending_invocations
                 .iter()
-                .map(|inv| QueuedWorkerInvocation::External(inv.clone())),
+                .map(|inv| QueuedWorkerInvocation::External {
+                    invocation: inv.clone(),
+                    priority: InvocationPriority::Background,
+                }),
         )));
         let pending_updates = Arc::new(RwLock::new(VecDeque::from_iter(
             initial_pending_updates.iter().cloned(),
@@ -266,12 +270,22 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
     }
 
     pub async fn start_if_needed(this: Arc<Worker<Ctx>>) -> Result<bool, GolemError> {
-        Self::start_if_needed_internal(this, 0).await
+        Self::start_if_needed_with_priority(this, InvocationPriority::Background).await
+    }
+
+    /// Starts the worker if it is not running yet. If the worker has to wait for one of the
+    /// concurrency limits, it is admitted according to the given priority.
+    pub async fn start_if_needed_with_priority(
+        this: Arc<Worker<Ctx>>,
+        priority: InvocationPriority,
+    ) -> Result<bool, GolemError> {
+        Self::start_if_needed_internal(this, 0, priority).await
     }
 
     async fn start_if_needed_internal(
         this: Arc<Worker<Ctx>>,
         oom_retry_count: u64,
+        priority: InvocationPriority,
     ) -> Result<bool, GolemError> {
         let mut instance = this.instance.lock().await;
         if instance.is_unloaded() {
@@ -280,9 +294,14 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                 this.clone(),
                 this.memory_requirement().await?,
                 oom_retry_count,
+                priority,
             ));
             Ok(true)
         } else {
+            if instance.is_waiting_for_permit() {
+                this.active_workers()
+                    .raise_priority(&this.owned_worker_id.worker_id, priority);
+            }
             debug!("Worker is already running or waiting for permit");
             Ok(false)
         }
@@ -291,6 +310,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
     pub(crate) async fn start_with_permit(
         this: Arc<Worker<Ctx>>,
         permit: OwnedSemaphorePermit,
+        concurrency_permit: WorkerConcurrencyPermit,
         oom_retry_count: u64,
     ) {
         let mut instance = this.instance.lock().await;
@@ -301,10 +321,39 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             this.oplog(),
             this.execution_status.clone(),
             permit,
+            concurrency_permit,
             oom_retry_count,
         ));
     }
 
+    /// Creates an instance of the worker and recovers it by replaying its oplog, without starting
+    /// the invocation loop. If the worker has a pending update, the replay attempts it.
+    ///
+    /// Returns the result of the recovery, and the index of the oplog entry the replay stopped at
+    /// if it did not reach the end of the oplog.
+    /// This is used for replaying workers in a sandbox, the instance is dropped afterwards.
+    pub(crate) async fn replay_in_sandbox(
+        this: Arc<Worker<Ctx>>,
+    ) -> Result<(Result<RetryDecision, GolemError>, Option<OplogIndex>), GolemError> {
+        let (instance, store) = RunningWorker::create_instance(this.clone()).await?;
+        let mut store = store.lock().await;
+        store.data_mut().set_suspended().await?;
+
+        let result = Ctx::prepare_instance(&this.owned_worker_id.worker_id, &instance, &mut *store)
+            .instrument(span!(
+                Level::INFO,
+                "sandbox-replay",
+                worker_id = this.owned_worker_id.worker_id.to_string(),
+            ))
+            .await;
+        let stopped_at = if store.data().is_replay() {
+            Some(store.data().last_replayed_index())
+        } else {
+            None
+        };
+        Ok((result, stopped_at))
+    }
+
     pub async fn stop(&self) {
         self.stop_internal(false, None).await;
     }
@@ -457,6 +506,27 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         idempotency_key: IdempotencyKey,
         full_function_name: String,
         function_input: Vec<Value>,
+        options: InvocationOptions,
+    ) -> Result<Option<Result<TypeAnnotatedValue, GolemError>>, GolemError> {
+        self.invoke_with_priority(
+            idempotency_key,
+            full_function_name,
+            function_input,
+            options,
+            InvocationPriority::Background,
+        )
+        .await
+    }
+
+    /// Invokes the function unless it has already been invoked with the same idempotency key.
+    /// The invocation is queued behind the pending invocations of the same or higher priority.
+    async fn invoke_with_priority(
+        &self,
+        idempotency_key: IdempotencyKey,
+        full_function_name: String,
+        function_input: Vec<Value>,
+        options: InvocationOptions,
+        priority: InvocationPriority,
     ) -> Result<Option<Result<TypeAnnotatedValue, GolemError>>, GolemError> {
         let output = self.lookup_invocation_result(&idempotency_key).await;
 
@@ -466,8 +536,14 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             LookupResult::Pending => Ok(None),
             LookupResult::New => {
                 // Invoke the function in the background
-                self.enqueue(idempotency_key, full_function_name, function_input)
-                    .await;
+                self.enqueue(
+                    idempotency_key,
+                    full_function_name,
+                    function_input,
+                    options,
+                    priority,
+                )
+                .await;
                 Ok(None)
             }
         }
@@ -478,9 +554,16 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         idempotency_key: IdempotencyKey,
         full_function_name: String,
         function_input: Vec<Value>,
+        options: InvocationOptions,
     ) -> Result<TypeAnnotatedValue, GolemError> {
         match self
-            .invoke(idempotency_key.clone(), full_function_name, function_input)
+            .invoke_with_priority(
+                idempotency_key.clone(),
+                full_function_name,
+                function_input,
+                options,
+                InvocationPriority::Interactive,
+            )
             .await?
         {
             Some(Ok(output)) => Ok(output),
@@ -547,10 +630,13 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                     timestamp: entry.timestamp(),
                     invocation,
                 };
-                self.queue
-                    .write()
-                    .unwrap()
-                    .push_back(QueuedWorkerInvocation::External(timestamped_invocation));
+                enqueue_by_priority(
+                    &mut self.queue.write().unwrap(),
+                    QueuedWorkerInvocation::External {
+                        invocation: timestamped_invocation,
+                        priority: InvocationPriority::Background,
+                    },
+                );
                 self.oplog.add_and_commit(entry).await;
                 self.update_metadata()
                     .await
@@ -559,6 +645,15 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         }
     }
 
+    pub fn owned_worker_id(&self) -> &OwnedWorkerId {
+        &self.owned_worker_id
+    }
+
+    /// Number of queued invocations, including the internal ones
+    pub fn pending_invocation_count(&self) -> usize {
+        self.queue.read().unwrap().len()
+    }
+
     pub fn pending_invocations(&self) -> Vec<TimestampedWorkerInvocation> {
         self.queue
             .read()
@@ -660,6 +755,24 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         }
     }
 
+    pub async fn store_invocation_timeout(&self, key: &IdempotencyKey, oplog_index: OplogIndex) {
+        self.invocation_results.write().unwrap().insert(
+            key.clone(),
+            InvocationResult::Cached {
+                result: Err(FailedInvocationResult {
+                    trap_type: TrapType::Error(WorkerError::InvocationTimedOut),
+                    stderr: "".to_string(),
+                }),
+                oplog_idx: oplog_index,
+            },
+        );
+        self.events().publish(Event::InvocationCompleted {
+            worker_id: self.owned_worker_id.worker_id(),
+            idempotency_key: key.clone(),
+            result: Err(GolemError::InvocationTimedOut),
+        });
+    }
+
     pub async fn store_invocation_resuming(&self, key: &IdempotencyKey) {
         let mut map = self.invocation_results.write().unwrap();
         map.remove(key);
@@ -724,6 +837,19 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         }
     }
 
+    /// Returns true if the worker is loaded in memory, either running or waiting for a permit
+    pub fn is_loaded(&self) -> bool {
+        match self.instance.try_lock() {
+            Ok(guard) => !guard.is_unloaded(),
+            Err(_) => true,
+        }
+    }
+
+    /// Commits the worker's oplog, waiting until all the pending entries are persisted
+    pub async fn commit_oplog(&self) {
+        self.oplog.commit(CommitLevel::Immediate).await;
+    }
+
     /// Gets the timestamp of the last time the execution status changed
     pub async fn last_execution_state_change(&self) -> Timestamp {
         self.execution_status.read().unwrap().timestamp()
@@ -750,11 +876,19 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         idempotency_key: IdempotencyKey,
         full_function_name: String,
         function_input: Vec<Value>,
+        options: InvocationOptions,
+        priority: InvocationPriority,
     ) {
         match &*self.instance.lock().await {
             WorkerInstance::Running(running) => {
                 running
-                    .enqueue(idempotency_key, full_function_name, function_input)
+                    .enqueue(
+                        idempotency_key,
+                        full_function_name,
+                        function_input,
+                        options,
+                        priority,
+                    )
                     .await;
             }
             WorkerInstance::Unloaded | WorkerInstance::WaitingForPermit(_) => {
@@ -763,16 +897,20 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                     idempotency_key,
                     full_function_name,
                     function_input,
+                    options,
                 };
                 let entry = OplogEntry::pending_worker_invocation(invocation.clone());
                 let timestamped_invocation = TimestampedWorkerInvocation {
                     timestamp: entry.timestamp(),
                     invocation,
                 };
-                self.queue
-                    .write()
-                    .unwrap()
-                    .push_back(QueuedWorkerInvocation::External(timestamped_invocation));
+                enqueue_by_priority(
+                    &mut self.queue.write().unwrap(),
+                    QueuedWorkerInvocation::External {
+                        invocation: timestamped_invocation,
+                        priority,
+                    },
+                );
                 self.oplog.add_and_commit(entry).await;
                 self.update_metadata()
                     .await
@@ -789,10 +927,10 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
 
         let mutex = self.instance.lock().await;
 
-        self.queue
-            .write()
-            .unwrap()
-            .push_back(QueuedWorkerInvocation::ListDirectory { path, sender });
+        enqueue_by_priority(
+            &mut self.queue.write().unwrap(),
+            QueuedWorkerInvocation::ListDirectory { path, sender },
+        );
 
         // Two cases here:
         // - Worker is running, we can send the invocation command and the worker will look at the queue immediately
@@ -812,10 +950,81 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
 
         let mutex = self.instance.lock().await;
 
-        self.queue
-            .write()
-            .unwrap()
-            .push_back(QueuedWorkerInvocation::ReadFile { path, sender });
+        enqueue_by_priority(
+            &mut self.queue.write().unwrap(),
+            QueuedWorkerInvocation::ReadFile { path, sender },
+        );
+
+        if let WorkerInstance::Running(running) = &*mutex {
+            running.sender.send(WorkerCommand::Invocation).unwrap();
+        };
+
+        drop(mutex);
+
+        receiver.await.unwrap()
+    }
+
+    pub async fn put_file(
+        &self,
+        path: ComponentFilePath,
+        content: Vec<u8>,
+    ) -> Result<PutFileResult, GolemError> {
+        let (sender, receiver) = oneshot::channel();
+
+        let mutex = self.instance.lock().await;
+
+        enqueue_by_priority(
+            &mut self.queue.write().unwrap(),
+            QueuedWorkerInvocation::PutFile {
+                path,
+                content,
+                sender,
+            },
+        );
+
+        if let WorkerInstance::Running(running) = &*mutex {
+            running.sender.send(WorkerCommand::Invocation).unwrap();
+        };
+
+        drop(mutex);
+
+        receiver.await.unwrap()
+    }
+
+    pub async fn delete_file(
+        &self,
+        path: ComponentFilePath,
+    ) -> Result<DeleteFileResult, GolemError> {
+        let (sender, receiver) = oneshot::channel();
+
+        let mutex = self.instance.lock().await;
+
+        enqueue_by_priority(
+            &mut self.queue.write().unwrap(),
+            QueuedWorkerInvocation::DeleteFile { path, sender },
+        );
+
+        if let WorkerInstance::Running(running) = &*mutex {
+            running.sender.send(WorkerCommand::Invocation).unwrap();
+        };
+
+        drop(mutex);
+
+        receiver.await.unwrap()
+    }
+
+    pub async fn make_directory(
+        &self,
+        path: ComponentFilePath,
+    ) -> Result<MakeDirectoryResult, GolemError> {
+        let (sender, receiver) = oneshot::channel();
+
+        let mutex = self.instance.lock().await;
+
+        enqueue_by_priority(
+            &mut self.queue.write().unwrap(),
+            QueuedWorkerInvocation::MakeDirectory { path, sender },
+        );
 
         if let WorkerInstance::Running(running) = &*mutex {
             running.sender.send(WorkerCommand::Invocation).unwrap();
@@ -848,6 +1057,56 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         Ok(())
     }
 
+    /// Removes a pending invocation from the invocation queue if it has not been started yet.
+    ///
+    /// Returns `true` if the invocation was still pending and got cancelled. Callers waiting
+    /// for its result get an error.
+    pub async fn cancel_invocation(
+        &self,
+        idempotency_key: IdempotencyKey,
+    ) -> Result<bool, GolemError> {
+        // Holding the instance lock so the queue cannot be taken over by a starting invocation loop
+        let _instance = self.instance.lock().await;
+
+        let cancelled = {
+            let mut queue = self.queue.write().unwrap();
+            match queue.iter().position(|item| {
+                item.as_external()
+                    .is_some_and(|inv| inv.invocation.is_idempotency_key(&idempotency_key))
+            }) {
+                Some(idx) => queue.remove(idx).is_some(),
+                None => false,
+            }
+        };
+
+        if cancelled {
+            debug!("Cancelled pending invocation {idempotency_key}");
+            self.oplog
+                .add_and_commit(OplogEntry::cancel_pending_invocation(
+                    idempotency_key.clone(),
+                ))
+                .await;
+            self.update_metadata().await?;
+            self.events().publish(Event::InvocationCompleted {
+                worker_id: self.owned_worker_id.worker_id(),
+                idempotency_key: idempotency_key.clone(),
+                result: Err(GolemError::runtime(format!(
+                    "Invocation {idempotency_key} was cancelled before it got started"
+                ))),
+            });
+        }
+        Ok(cancelled)
+    }
+
+    /// Replaces the worker's labels
+    pub async fn update_labels(&self, labels: BTreeMap<String, String>) -> Result<(), GolemError> {
+        self.oplog
+            .add_and_commit(OplogEntry::update_labels(labels))
+            .await;
+        self.update_metadata().await?;
+        Ok(())
+    }
+
     async fn wait_for_invocation_result(
         &self,
         key: &IdempotencyKey,
@@ -909,6 +1168,14 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                         }),
                     ..
                 } => LookupResult::Pending,
+                InvocationResult::Cached {
+                    result:
+                        Err(FailedInvocationResult {
+                            trap_type: TrapType::Error(WorkerError::InvocationTimedOut),
+                            ..
+                        }),
+                    ..
+                } => LookupResult::Complete(Err(GolemError::InvocationTimedOut)),
                 InvocationResult::Cached {
                     result:
                         Err(FailedInvocationResult {
@@ -985,7 +1252,9 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                 // to get the error.
                 for item in queued_items {
                     match item {
-                        QueuedWorkerInvocation::External(inner) => {
+                        QueuedWorkerInvocation::External {
+                            invocation: inner, ..
+                        } => {
                             if let Some(idempotency_key) = inner.invocation.idempotency_key() {
                                 self.events().publish(Event::InvocationCompleted {
                                     worker_id: self.owned_worker_id.worker_id(),
@@ -1000,6 +1269,15 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                         QueuedWorkerInvocation::ReadFile { sender, .. } => {
                             let _ = sender.send(Err(fail_pending_invocations.clone()));
                         }
+                        QueuedWorkerInvocation::PutFile { sender, .. } => {
+                            let _ = sender.send(Err(fail_pending_invocations.clone()));
+                        }
+                        QueuedWorkerInvocation::DeleteFile { sender, .. } => {
+                            let _ = sender.send(Err(fail_pending_invocations.clone()));
+                        }
+                        QueuedWorkerInvocation::MakeDirectory { sender, .. } => {
+                            let _ = sender.send(Err(fail_pending_invocations.clone()));
+                        }
                     }
                 }
             } else {
@@ -1028,7 +1306,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         if let Some(delay) = delay {
             tokio::time::sleep(delay).await;
         }
-        Self::start_if_needed_internal(this, oom_retry_count).await
+        Self::start_if_needed_internal(this, oom_retry_count, InvocationPriority::Background).await
     }
 
     async fn get_or_create_worker_metadata<
@@ -1069,12 +1347,13 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                             .iter()
                             .map(|m| m.initial)
                             .sum(),
-                        extensions: WorkerStatusRecordExtensions::Extension1 {
+                        extensions: WorkerStatusRecordExtensions::Extension2 {
                             active_plugins: component_metadata
                                 .plugin_installations
                                 .iter()
                                 .map(|i| i.id.clone())
                                 .collect(),
+                            labels: BTreeMap::new(),
                         },
                         ..initial_status
                     },
@@ -1123,7 +1402,6 @@ impl WorkerInstance {
         matches!(self, WorkerInstance::Running(_))
     }
 
-    #[allow(unused)]
     pub fn is_waiting_for_permit(&self) -> bool {
         matches!(self, WorkerInstance::WaitingForPermit(_))
     }
@@ -1142,6 +1420,7 @@ impl WaitingWorker {
         parent: Arc<Worker<Ctx>>,
         memory_requirement: u64,
         oom_retry_count: u64,
+        priority: InvocationPriority,
     ) -> Self {
         let span = span!(
             Level::INFO,
@@ -1150,8 +1429,13 @@ impl WaitingWorker {
         );
         let handle = tokio::task::spawn(
             async move {
+                let concurrency_permit = parent
+                    .active_workers()
+                    .acquire_concurrency(&parent.owned_worker_id, priority)
+                    .await;
                 let permit = parent.active_workers().acquire(memory_requirement).await;
-                Worker::start_with_permit(parent, permit, oom_retry_count).await;
+                Worker::start_with_permit(parent, permit, concurrency_permit, oom_retry_count)
+                    .await;
             }
             .instrument(span),
         );
@@ -1178,6 +1462,8 @@ struct RunningWorker {
     oplog: Arc<dyn Oplog + Send + Sync>,
 
     permit: OwnedSemaphorePermit,
+    #[allow(dead_code)]
+    concurrency_permit: WorkerConcurrencyPermit,
     waiting_for_command: Arc<AtomicBool>,
 }
 
@@ -1189,6 +1475,7 @@ impl RunningWorker {
         oplog: Arc<dyn Oplog + Send + Sync>,
         execution_status: Arc<RwLock<ExecutionStatus>>,
         permit: OwnedSemaphorePermit,
+        concurrency_permit: WorkerConcurrencyPermit,
         oom_retry_count: u64,
     ) -> Self {
         let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
@@ -1228,6 +1515,7 @@ impl RunningWorker {
             oplog,
             execution_status,
             permit,
+            concurrency_permit,
             waiting_for_command,
         }
     }
@@ -1245,21 +1533,29 @@ impl RunningWorker {
         idempotency_key: IdempotencyKey,
         full_function_name: String,
         function_input: Vec<Value>,
+        options: InvocationOptions,
+        priority: InvocationPriority,
     ) {
         let invocation = WorkerInvocation::ExportedFunction {
             idempotency_key,
             full_function_name,
             function_input,
+            options,
         };
-        self.enqueue_worker_invocation(invocation).await;
+        self.enqueue_worker_invocation(invocation, priority).await;
     }
 
     pub async fn enqueue_manual_update(&self, target_version: ComponentVersion) {
         let invocation = WorkerInvocation::ManualUpdate { target_version };
-        self.enqueue_worker_invocation(invocation).await;
+        self.enqueue_worker_invocation(invocation, InvocationPriority::Background)
+            .await;
     }
 
-    async fn enqueue_worker_invocation(&self, invocation: WorkerInvocation) {
+    async fn enqueue_worker_invocation(
+        &self,
+        invocation: WorkerInvocation,
+        priority: InvocationPriority,
+    ) {
         let entry = OplogEntry::pending_worker_invocation(invocation.clone());
         let timestamped_invocation = TimestampedWorkerInvocation {
             timestamp: entry.timestamp(),
@@ -1270,10 +1566,13 @@ impl RunningWorker {
             // The worker is currently busy, so we write the pending worker invocation to the oplog
             self.oplog.add_and_commit(entry).await;
         }
-        self.queue
-            .write()
-            .unwrap()
-            .push_back(QueuedWorkerInvocation::External(timestamped_invocation));
+        enqueue_by_priority(
+            &mut self.queue.write().unwrap(),
+            QueuedWorkerInvocation::External {
+                invocation: timestamped_invocation,
+                priority,
+            },
+        );
         self.sender.send(WorkerCommand::Invocation).unwrap()
     }
 
@@ -1316,6 +1615,7 @@ impl RunningWorker {
                 component_version,
             )
             .await?;
+        let capability_policy = component_metadata.capability_policy.clone();
 
         let context = Ctx::create(
             OwnedWorkerId::new(&worker_metadata.account_id, &worker_metadata.worker_id),
@@ -1346,6 +1646,7 @@ impl RunningWorker {
             ),
             parent.execution_status.clone(),
             parent.file_loader(),
+            parent.volume_service(),
             parent.plugins(),
         )
         .await?;
@@ -1362,6 +1663,9 @@ impl RunningWorker {
 
             match store.data_mut().check_interrupt() {
                 Some(kind) => Err(kind.into()),
+                None if store.data().is_invocation_deadline_exceeded() => {
+                    Err(GolemError::InvocationTimedOut.into())
+                }
                 None => Ok(UpdateDeadline::Yield(1)),
             }
         });
@@ -1371,15 +1675,24 @@ impl RunningWorker {
 
         store.limiter_async(|ctx| ctx.resource_limiter());
 
-        let instance_pre = parent.linker().instantiate_pre(&component).map_err(|e| {
-            GolemError::worker_creation_failed(
-                parent.owned_worker_id.worker_id(),
-                format!(
-                    "Failed to pre-instantiate worker {}: {e}",
-                    parent.owned_worker_id
-                ),
+        let instance_pre = parent
+            .instance_pre_cache()
+            .get(
+                &component_id,
+                component_version,
+                &component,
+                capability_policy.as_ref(),
             )
-        })?;
+            .await
+            .map_err(|e| {
+                GolemError::worker_creation_failed(
+                    parent.owned_worker_id.worker_id(),
+                    format!(
+                        "Failed to pre-instantiate worker {}: {e}",
+                        parent.owned_worker_id
+                    ),
+                )
+            })?;
 
         let instance = instance_pre
             .instantiate_async(&mut store)
@@ -1473,11 +1786,12 @@ impl RunningWorker {
                     waiting_for_command.store(false, Ordering::Release);
                     match cmd {
                         WorkerCommand::Invocation => {
-                            let message = active
-                                .write()
-                                .unwrap()
-                                .pop_front()
-                                .expect("Message should be present");
+                            let message = active.write().unwrap().pop_front();
+                            let Some(message) = message else {
+                                // The queued invocation this command was sent for got cancelled
+                                waiting_for_command.store(true, Ordering::Release);
+                                continue;
+                            };
 
                             let mut store_mutex = store.lock().await;
                             let store = store_mutex.deref_mut();
@@ -1510,12 +1824,67 @@ impl RunningWorker {
                                         }
                                     };
                                 }
-                                QueuedWorkerInvocation::External(inner) => {
-                                    match inner.invocation {
+                                QueuedWorkerInvocation::PutFile {
+                                    path,
+                                    content,
+                                    sender,
+                                } => {
+                                    let result = async {
+                                        let result =
+                                            store.data_mut().put_file(&path, &content).await?;
+                                        if result == PutFileResult::Ok {
+                                            let payload = parent
+                                                .oplog
+                                                .upload_payload(&content)
+                                                .await
+                                                .map_err(|err| GolemError::FileSystemError {
+                                                    path: path.to_string(),
+                                                    reason: format!(
+                                                        "Failed to store file contents: {err}"
+                                                    ),
+                                                })?;
+                                            parent
+                                                .oplog
+                                                .add_and_commit(OplogEntry::put_file(
+                                                    path.clone(),
+                                                    payload,
+                                                ))
+                                                .await;
+                                        }
+                                        Ok::<_, GolemError>(result)
+                                    }
+                                    .await;
+                                    let _ = sender.send(result);
+                                }
+                                QueuedWorkerInvocation::DeleteFile { path, sender } => {
+                                    let result = store.data_mut().delete_file(&path).await;
+                                    if let Ok(DeleteFileResult::Ok) = &result {
+                                        parent
+                                            .oplog
+                                            .add_and_commit(OplogEntry::delete_file(path))
+                                            .await;
+                                    }
+                                    let _ = sender.send(result);
+                                }
+                                QueuedWorkerInvocation::MakeDirectory { path, sender } => {
+                                    let result = store.data_mut().make_directory(&path).await;
+                                    if let Ok(MakeDirectoryResult::Ok) = &result {
+                                        parent
+                                            .oplog
+                                            .add_and_commit(OplogEntry::make_directory(path))
+                                            .await;
+                                    }
+                                    let _ = sender.send(result);
+                                }
+                                QueuedWorkerInvocation::External {
+                                    invocation: inner, ..
+                                } => {
+                                    match inner.invocation.upgrade() {
                                         WorkerInvocation::ExportedFunction {
                                             idempotency_key: invocation_key,
                                             full_function_name,
                                             function_input,
+                                            options,
                                         } => {
                                             let span = span!(
                                                 Level::INFO,
@@ -1525,10 +1894,15 @@ impl RunningWorker {
                                                 function = full_function_name
                                             );
                                             let do_break = async {
+                                                let remaining_time = options.remaining_time();
                                                 store
                                                     .data_mut()
                                                     .set_current_idempotency_key(invocation_key)
                                                     .await;
+                                                store
+                                                    .data_mut()
+                                                    .set_current_invocation_options(options)
+                                                    .await;
 
                                                 if let Some(idempotency_key) =
                                                     &store.data().get_current_idempotency_key().await
@@ -1545,13 +1919,47 @@ impl RunningWorker {
                                                 // the invocation writes the invocation start oplog entry
                                                 store.data_mut().update_pending_invocations().await;
 
-                                                let result = invoke_worker(
+                                                if store.data().is_invocation_deadline_exceeded() {
+                                                    // The deadline passed while the invocation was waiting in the queue
+                                                    store
+                                                        .data_mut()
+                                                        .on_invocation_failure(&TrapType::Error(
+                                                            WorkerError::InvocationTimedOut,
+                                                        ))
+                                                        .await;
+                                                    return false; // continue processing the queue
+                                                }
+
+                                                let invocation = invoke_worker(
                                                     full_function_name.clone(),
                                                     function_input.clone(),
                                                     store,
                                                     &instance,
-                                                )
-                                                .await;
+                                                );
+                                                let result = match remaining_time {
+                                                    Some(remaining_time) => {
+                                                        // The epoch deadline callback only fires while the worker is
+                                                        // executing, so an invocation blocked in an asynchronous host
+                                                        // call (sleep, HTTP, RPC, promises) is timed out here
+                                                        match tokio::time::timeout(remaining_time, invocation).await {
+                                                            Ok(result) => result,
+                                                            Err(_) => {
+                                                                store
+                                                                    .data_mut()
+                                                                    .on_invocation_failure(&TrapType::Error(
+                                                                        WorkerError::InvocationTimedOut,
+                                                                    ))
+                                                                    .await;
+
+                                                                // The aborted call leaves the instance in an undefined
+                                                                // state, so the worker is always restarted
+                                                                final_decision = RetryDecision::Immediate;
+                                                                return true; // break
+                                                            }
+                                                        }
+                                                    }
+                                                    None => invocation.await,
+                                                };
 
                                                 match result {
                                                     Ok(InvokeResult::Succeeded {
@@ -1791,6 +2199,11 @@ impl RunningWorker {
                                                 break;
                                             }
                                         }
+                                        WorkerInvocation::ExportedFunctionV1 { .. } => {
+                                            unreachable!(
+                                                "Queued invocations are upgraded before processing"
+                                            )
+                                        }
                                     }
                                 }
                             }
@@ -1935,6 +2348,7 @@ impl InvocationResult {
                 },
                 OplogEntry::Interrupted { .. } => Err(FailedInvocationResult { trap_type: TrapType::Interrupt(InterruptKind::Interrupt), stderr: "".to_string()}),
                 OplogEntry::Exited { .. } => Err(FailedInvocationResult { trap_type: TrapType::Exit, stderr: "".to_string()}),
+                OplogEntry::InvocationTimedOut { .. } => Err(FailedInvocationResult { trap_type: TrapType::Error(WorkerError::InvocationTimedOut), stderr: "".to_string()}),
                 _ => panic!("Unexpected oplog entry pointed by invocation result at index {oplog_idx} for {owned_worker_id:?}")
             };
 
@@ -2006,6 +2420,7 @@ where
             .await;
 
         let active_plugins = last_known.active_plugins().clone();
+        let labels = last_known.labels().clone();
 
         let overridden_retry_config = calculate_overridden_retry_policy(
             last_known.overridden_retry_config.clone(),
@@ -2065,6 +2480,8 @@ where
 
         let active_plugins = calculate_active_plugins(active_plugins, &new_entries);
 
+        let labels = calculate_labels(labels, &new_entries);
+
         let result = WorkerStatusRecord {
             oplog_idx: last_oplog_index,
             status,
@@ -2080,7 +2497,10 @@ where
             component_size,
             owned_resources,
             total_linear_memory_size,
-            extensions: WorkerStatusRecordExtensions::Extension1 { active_plugins },
+            extensions: WorkerStatusRecordExtensions::Extension2 {
+                active_plugins,
+                labels,
+            },
         };
         Ok(result)
     }
@@ -2095,6 +2515,7 @@ fn calculate_latest_worker_status(
     let mut result = initial.clone();
     let mut last_error_count = 0;
     let mut current_retry_policy = initial_retry_policy;
+    let mut invocation_retry_policy = None;
     for entry in entries.values() {
         if !matches!(entry, OplogEntry::Error { .. }) {
             last_error_count = 0;
@@ -2110,10 +2531,16 @@ fn calculate_latest_worker_status(
             OplogEntry::ImportedFunctionInvoked { .. } => {
                 result = WorkerStatus::Running;
             }
-            OplogEntry::ExportedFunctionInvoked { .. } => {
+            OplogEntry::ExportedFunctionInvokedV1 { .. } => {
+                invocation_retry_policy = None;
+                result = WorkerStatus::Running;
+            }
+            OplogEntry::ExportedFunctionInvoked { options, .. } => {
+                invocation_retry_policy.clone_from(&options.retry_policy);
                 result = WorkerStatus::Running;
             }
             OplogEntry::ExportedFunctionCompleted { .. } => {
+                invocation_retry_policy = None;
                 result = WorkerStatus::Idle;
             }
             OplogEntry::Suspend { .. } => {
@@ -2123,8 +2550,9 @@ fn calculate_latest_worker_status(
                 last_error_count += 1;
 
                 if is_worker_error_retriable(
-                    current_retry_policy
+                    invocation_retry_policy
                         .as_ref()
+                        .or(current_retry_policy.as_ref())
                         .unwrap_or(default_retry_policy),
                     error,
                     last_error_count,
@@ -2186,6 +2614,15 @@ fn calculate_latest_worker_status(
             OplogEntry::SuccessfulUpdateV1 { .. } => {}
             OplogEntry::ActivatePlugin { .. } => {}
             OplogEntry::DeactivatePlugin { .. } => {}
+            OplogEntry::UpdateLabels { .. } => {}
+            OplogEntry::PutFile { .. } => {}
+            OplogEntry::DeleteFile { .. } => {}
+            OplogEntry::MakeDirectory { .. } => {}
+            OplogEntry::CancelPendingInvocation { .. } => {}
+            OplogEntry::InvocationTimedOut { .. } => {
+                invocation_retry_policy = None;
+                result = WorkerStatus::Idle;
+            }
         }
     }
     result
@@ -2234,19 +2671,20 @@ fn calculate_pending_invocations(
                     invocation: invocation.clone(),
                 });
             }
-            OplogEntry::ExportedFunctionInvoked {
+            OplogEntry::ExportedFunctionInvokedV1 {
+                idempotency_key, ..
+            }
+            | OplogEntry::ExportedFunctionInvoked {
+                idempotency_key, ..
+            }
+            | OplogEntry::CancelPendingInvocation {
+                idempotency_key, ..
+            }
+            | OplogEntry::InvocationTimedOut {
                 idempotency_key, ..
             } => {
-                result.retain(|invocation| match invocation {
-                    TimestampedWorkerInvocation {
-                        invocation:
-                            WorkerInvocation::ExportedFunction {
-                                idempotency_key: key,
-                                ..
-                            },
-                        ..
-                    } => key != idempotency_key,
-                    _ => true,
+                result.retain(|invocation| {
+                    !invocation.invocation.is_idempotency_key(idempotency_key)
                 });
             }
             OplogEntry::PendingUpdate {
@@ -2367,7 +2805,10 @@ fn calculate_invocation_results(
 
     for (oplog_idx, entry) in entries {
         match entry {
-            OplogEntry::ExportedFunctionInvoked {
+            OplogEntry::ExportedFunctionInvokedV1 {
+                idempotency_key, ..
+            }
+            | OplogEntry::ExportedFunctionInvoked {
                 idempotency_key, ..
             } => {
                 current_idempotency_key = Some(idempotency_key.clone());
@@ -2388,6 +2829,14 @@ fn calculate_invocation_results(
                     invocation_results.insert(idempotency_key.clone(), *oplog_idx);
                 }
             }
+            OplogEntry::InvocationTimedOut {
+                idempotency_key, ..
+            } => {
+                invocation_results.insert(idempotency_key.clone(), *oplog_idx);
+                if current_idempotency_key.as_ref() == Some(idempotency_key) {
+                    current_idempotency_key = None;
+                }
+            }
             _ => {}
         }
     }
@@ -2466,6 +2915,19 @@ fn calculate_active_plugins(
     result
 }
 
+fn calculate_labels(
+    initial: BTreeMap<String, String>,
+    entries: &BTreeMap<OplogIndex, OplogEntry>,
+) -> BTreeMap<String, String> {
+    let mut result = initial;
+    for entry in entries.values() {
+        if let OplogEntry::UpdateLabels { labels, .. } = entry {
+            result = labels.clone();
+        }
+    }
+    result
+}
+
 pub fn is_worker_error_retriable(
     retry_config: &RetryConfig,
     error: &WorkerError,
@@ -2476,6 +2938,7 @@ pub fn is_worker_error_retriable(
         WorkerError::InvalidRequest(_) => false,
         WorkerError::StackOverflow => false,
         WorkerError::OutOfMemory => true,
+        WorkerError::InvocationTimedOut => false,
     }
 }
 
@@ -2487,7 +2950,10 @@ fn is_running_worker_idle(running: &RunningWorker) -> bool {
 pub enum QueuedWorkerInvocation {
     /// 'Real' invocations that make sense from a domain model point of view and should be exposed to the user.
     /// All other cases here are used for concurrency control and should not be exposed to the user.
-    External(TimestampedWorkerInvocation),
+    External {
+        invocation: TimestampedWorkerInvocation,
+        priority: InvocationPriority,
+    },
     ListDirectory {
         path: ComponentFilePath,
         sender: oneshot::Sender<Result<ListDirectoryResult, GolemError>>,
@@ -2497,13 +2963,48 @@ pub enum QueuedWorkerInvocation {
         path: ComponentFilePath,
         sender: oneshot::Sender<Result<ReadFileResult, GolemError>>,
     },
+    // Changes to the filesystem are recorded in the oplog, so they can be reapplied during replay
+    PutFile {
+        path: ComponentFilePath,
+        content: Vec<u8>,
+        sender: oneshot::Sender<Result<PutFileResult, GolemError>>,
+    },
+    DeleteFile {
+        path: ComponentFilePath,
+        sender: oneshot::Sender<Result<DeleteFileResult, GolemError>>,
+    },
+    MakeDirectory {
+        path: ComponentFilePath,
+        sender: oneshot::Sender<Result<MakeDirectoryResult, GolemError>>,
+    },
 }
 
 impl QueuedWorkerInvocation {
     fn as_external(&self) -> Option<&TimestampedWorkerInvocation> {
         match self {
-            Self::External(invocation) => Some(invocation),
+            Self::External { invocation, .. } => Some(invocation),
             _ => None,
         }
     }
+
+    fn priority(&self) -> InvocationPriority {
+        match self {
+            Self::External { priority, .. } => *priority,
+            // The file system operations are only requested by callers waiting for their result
+            _ => InvocationPriority::Interactive,
+        }
+    }
+}
+
+/// Adds an item to the invocation queue behind the ones with the same or a higher priority, so
+/// an invocation somebody is waiting for does not wait for queued background invocations.
+///
+/// The priorities are not persisted, pending invocations restored from the oplog are background ones.
+fn enqueue_by_priority(queue: &mut VecDeque<QueuedWorkerInvocation>, item: QueuedWorkerInvocation) {
+    let priority = item.priority();
+    let idx = queue
+        .iter()
+        .position(|queued| queued.priority() < priority)
+        .unwrap_or(queue.len());
+    queue.insert(idx, item);
 }
@@ -23,8 +23,9 @@ use wasmtime::{AsContextMut, ResourceLimiterAsync};
 
 use crate::error::GolemError;
 use crate::model::{
-    CurrentResourceLimits, ExecutionStatus, InterruptKind, LastError, ListDirectoryResult,
-    ReadFileResult, TrapType, WorkerConfig,
+    CurrentResourceLimits, DeleteFileResult, ExecutionStatus, InterruptKind, LastError,
+    ListDirectoryResult, MakeDirectoryResult, PutFileResult, ReadFileResult, TrapType,
+    WorkerConfig,
 };
 use crate::services::active_workers::ActiveWorkers;
 use crate::services::blob_store::BlobStoreService;
@@ -37,6 +38,7 @@ use crate::services::plugins::Plugins;
 use crate::services::promise::PromiseService;
 use crate::services::rpc::Rpc;
 use crate::services::scheduler::SchedulerService;
+use crate::services::volume::VolumeService;
 use crate::services::worker::WorkerService;
 use crate::services::worker_event::WorkerEventService;
 use crate::services::worker_proxy::WorkerProxy;
@@ -45,11 +47,12 @@ use crate::services::{
 };
 use crate::worker::{RetryDecision, Worker};
 use golem_common::model::component::ComponentOwner;
-use golem_common::model::oplog::WorkerResourceId;
+use golem_common::model::oplog::{OplogIndex, WorkerResourceId};
 use golem_common::model::plugin::PluginScope;
 use golem_common::model::{
-    AccountId, ComponentFilePath, ComponentVersion, IdempotencyKey, OwnedWorkerId,
-    PluginInstallationId, WorkerId, WorkerMetadata, WorkerStatus, WorkerStatusRecord,
+    AccountId, ComponentFilePath, ComponentVersion, IdempotencyKey, InvocationOptions,
+    OwnedWorkerId, PluginInstallationId, WorkerId, WorkerMetadata, WorkerStatus,
+    WorkerStatusRecord,
 };
 
 /// WorkerCtx is the primary customization and extension point of worker executor. It is the context
@@ -66,6 +69,7 @@ pub trait WorkerCtx:
     + IndexedResourceStore
     + UpdateManagement
     + FileSystemReading
+    + FileSystemWriting
     + Send
     + Sync
     + Sized
@@ -100,6 +104,7 @@ pub trait WorkerCtx:
     /// - `worker_config`: Configuration for this specific worker
     /// - `execution_status`: Lock created to store the execution status
     /// - `file_loader`: The service for loading files and making them available to workers
+    /// - `volume_service`: The service keeping the persistent volumes of workers in sync with the blob storage
     #[allow(clippy::too_many_arguments)]
     async fn create(
         owned_worker_id: OwnedWorkerId,
@@ -125,6 +130,7 @@ pub trait WorkerCtx:
         worker_config: WorkerConfig,
         execution_status: Arc<RwLock<ExecutionStatus>>,
         file_loader: Arc<FileLoader>,
+        volume_service: Arc<VolumeService>,
         plugins: Arc<
             dyn Plugins<<Self::ComponentOwner as ComponentOwner>::PluginOwner, Self::PluginScope>
                 + Send
@@ -209,11 +215,22 @@ pub trait InvocationManagement {
     /// Gets the invocation key associated with the current invocation of the worker.
     async fn get_current_idempotency_key(&self) -> Option<IdempotencyKey>;
 
+    /// Sets the retry policy and deadline associated with the current invocation of the worker.
+    async fn set_current_invocation_options(&mut self, options: InvocationOptions);
+
+    /// Returns whether the current invocation has a deadline which has already passed.
+    /// Always false when replaying.
+    fn is_invocation_deadline_exceeded(&self) -> bool;
+
     /// Returns whether we are in live mode where we are executing new calls.
     fn is_live(&self) -> bool;
 
     /// Returns whether we are in replay mode where we are replaying old calls.
     fn is_replay(&self) -> bool;
+
+    /// Returns the index of the last oplog entry processed by the replay. If the replay failed,
+    /// this is the entry it failed at.
+    fn last_replayed_index(&self) -> OplogIndex;
 }
 
 /// The status management interface of a worker context is responsible for querying and storing
@@ -400,3 +417,26 @@ pub trait FileSystemReading {
     ) -> Result<ListDirectoryResult, GolemError>;
     async fn read_file(&self, path: &ComponentFilePath) -> Result<ReadFileResult, GolemError>;
 }
+
+/// Trait used for modifying the worker filesystem from outside of the worker. Just like with
+/// `FileSystemReading`, the worker will not be running any invocations when these are called.
+#[async_trait]
+pub trait FileSystemWriting {
+    // Writes a file, creating its parent directories if necessary. Existing files are overwritten
+    // unless they are read-only.
+    async fn put_file(
+        &mut self,
+        path: &ComponentFilePath,
+        content: &[u8],
+    ) -> Result<PutFileResult, GolemError>;
+    // Deletes a file or an empty directory
+    async fn delete_file(
+        &mut self,
+        path: &ComponentFilePath,
+    ) -> Result<DeleteFileResult, GolemError>;
+    // Creates a directory, together with its missing parent directories
+    async fn make_directory(
+        &mut self,
+        path: &ComponentFilePath,
+    ) -> Result<MakeDirectoryResult, GolemError>;
+}
@@ -14,7 +14,7 @@
 
 use test_r::{inherit_test_dep, test};
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::io::Write;
 use std::net::SocketAddr;
@@ -27,8 +27,12 @@ use assert2::check;
 use http_02::{Response, StatusCode};
 use redis::Commands;
 
-use golem_api_grpc::proto::golem::worker::v1::{worker_execution_error, ComponentParseFailed};
-use golem_api_grpc::proto::golem::workerexecutor::v1::CompletePromiseRequest;
+use golem_api_grpc::proto::golem::worker::v1::{
+    worker_execution_error, ComponentParseFailed, InvocationTimedOut,
+};
+use golem_api_grpc::proto::golem::workerexecutor::v1::{
+    CompletePromiseRequest, UpdateWorkerLabelsRequest,
+};
 use golem_common::model::{
     AccountId, ComponentId, FilterComparator, IdempotencyKey, PromiseId, ScanCursor,
     StringFilterComparator, TargetWorkerId, Timestamp, WorkerFilter, WorkerId, WorkerMetadata,
@@ -36,10 +40,15 @@ use golem_common::model::{
 };
 use golem_wasm_rpc::Value;
 
-use crate::common::{start, TestContext, TestWorkerExecutor};
+use crate::common::{set_failure_policy, start, TestContext, TestWorkerExecutor};
 use crate::compatibility::worker_recovery::save_recovery_golden_file;
 use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
+use golem_common::config::RetryConfig;
+use golem_common::model::component_metadata::{
+    DeadLetterHandler, FailedWorkerAction, FailurePolicy,
+};
 use golem_common::model::oplog::{IndexedResourceKey, OplogIndex, WorkerResourceId};
+use golem_common::model::public_oplog::PublicOplogEntry;
 use golem_test_framework::config::TestDependencies;
 use golem_test_framework::dsl::{
     drain_connection, is_worker_execution_error, stdout_event_matching, stdout_events,
@@ -822,6 +831,103 @@ async fn invoking_with_same_idempotency_key_is_idempotent_after_restart(
     );
 }
 
+#[test]
+#[tracing::instrument]
+async fn worker_labels_survive_recovery(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let worker_id = executor
+        .start_worker(&component_id, "shopping-cart-labels-1")
+        .await;
+
+    executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await
+        .unwrap();
+
+    executor
+        .client()
+        .await
+        .expect("Failed to get client")
+        .update_worker_labels(UpdateWorkerLabelsRequest {
+            worker_id: Some(worker_id.clone().into()),
+            account_id: Some(
+                AccountId {
+                    value: "test-account".to_string(),
+                }
+                .into(),
+            ),
+            labels: HashMap::from([("team".to_string(), "payments".to_string())]),
+        })
+        .await
+        .unwrap();
+
+    executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(5),
+            ])],
+        )
+        .await
+        .unwrap();
+
+    // Replaying the oplog skips the label update but keeps the labels
+    executor.simulated_crash(&worker_id).await;
+    let contents_after_crash = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get-cart-contents}", vec![])
+        .await
+        .unwrap();
+    let (metadata_after_crash, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+
+    // A restarted executor recomputes the worker status from the oplog
+    drop(executor);
+    let executor = start(deps, &context).await.unwrap();
+
+    let contents_after_restart = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get-cart-contents}", vec![])
+        .await
+        .unwrap();
+    let (metadata_after_restart, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+    let oplog = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await;
+
+    drop(executor);
+
+    let expected_labels = BTreeMap::from([("team".to_string(), "payments".to_string())]);
+    let expected_contents = vec![Value::List(vec![Value::Record(vec![
+        Value::String("G1000".to_string()),
+        Value::String("Golem T-Shirt M".to_string()),
+        Value::F32(100.0),
+        Value::U32(5),
+    ])])];
+
+    check!(contents_after_crash == expected_contents);
+    check!(contents_after_restart == expected_contents);
+    check!(metadata_after_crash.last_known_status.labels() == &expected_labels);
+    check!(metadata_after_restart.last_known_status.labels() == &expected_labels);
+    check!(
+        oplog
+            .iter()
+            .filter(|entry| matches!(entry, PublicOplogEntry::UpdateLabels(_)))
+            .count()
+            == 1
+    );
+}
+
 #[test]
 #[tracing::instrument]
 async fn optional_parameters(
@@ -2761,3 +2867,286 @@ async fn stderr_returned_for_failed_component(
     check!(all[0].1.is_some());
     check!(all[0].1.clone().unwrap().ends_with(&expected_stderr));
 }
+
+#[test]
+#[tracing::instrument]
+async fn failed_worker_notifies_dead_letter_handler(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let handler_component_id = executor.store_component("dead-letter-handler").await;
+    let handler_worker_id = executor
+        .start_worker(&handler_component_id, "dead-letter-handler-1")
+        .await;
+
+    let component_id = executor.store_unique_component("failing-component").await;
+    set_failure_policy(
+        &component_id,
+        &FailurePolicy {
+            dead_letter_handler: Some(DeadLetterHandler {
+                worker_id: handler_worker_id.clone(),
+                function_name: "golem:it/api.{handle-failure}".to_string(),
+            }),
+            action: FailedWorkerAction::Keep,
+            delete_after_seconds: None,
+        },
+    )
+    .await;
+    let worker_id = executor
+        .start_worker(&component_id, "failing-worker-dead-letter-1")
+        .await;
+
+    let result = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:component/api.{add}",
+            vec![Value::U64(50)],
+        )
+        .await;
+
+    let deadline = Instant::now() + Duration::from_secs(30);
+    let failures = loop {
+        let failures = executor
+            .invoke_and_await(&handler_worker_id, "golem:it/api.{get-failures}", vec![])
+            .await
+            .unwrap();
+        if failures != vec![Value::List(vec![])] || Instant::now() > deadline {
+            break failures;
+        }
+        sleep(Duration::from_millis(200)).await;
+    };
+
+    let (metadata, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+
+    drop(executor);
+
+    check!(result.is_err());
+    check!(metadata.last_known_status.status == WorkerStatus::Failed);
+    check!(
+        failures
+            == vec![Value::List(vec![Value::Tuple(vec![
+                Value::String(worker_id.to_string()),
+                Value::Option(Some(Box::new(Value::String(
+                    "golem:component/api.{add}".to_string()
+                )))),
+            ])])]
+    );
+}
+
+#[test]
+#[tracing::instrument]
+async fn failed_worker_gets_deleted(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_unique_component("failing-component").await;
+    set_failure_policy(
+        &component_id,
+        &FailurePolicy {
+            dead_letter_handler: None,
+            action: FailedWorkerAction::Delete,
+            delete_after_seconds: Some(0),
+        },
+    )
+    .await;
+    let worker_id = executor
+        .start_worker(&component_id, "failing-worker-delete-1")
+        .await;
+
+    let result1 = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{add}", vec![Value::U64(5)])
+        .await;
+    let result2 = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:component/api.{add}",
+            vec![Value::U64(50)],
+        )
+        .await;
+
+    let deadline = Instant::now() + Duration::from_secs(30);
+    let metadata = loop {
+        let metadata = executor.get_worker_metadata(&worker_id).await;
+        if metadata.is_none() || Instant::now() > deadline {
+            break metadata;
+        }
+        sleep(Duration::from_millis(200)).await;
+    };
+
+    drop(executor);
+
+    check!(result1.is_ok());
+    check!(result2.is_err());
+    check!(metadata.is_none());
+}
+
+#[test]
+#[tracing::instrument]
+async fn failed_invocation_gets_reverted(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_unique_component("failing-component").await;
+    set_failure_policy(
+        &component_id,
+        &FailurePolicy {
+            dead_letter_handler: None,
+            action: FailedWorkerAction::Revert,
+            delete_after_seconds: None,
+        },
+    )
+    .await;
+    let worker_id = executor
+        .start_worker(&component_id, "failing-worker-revert-1")
+        .await;
+
+    let result1 = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{add}", vec![Value::U64(5)])
+        .await;
+    let result2 = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:component/api.{add}",
+            vec![Value::U64(50)],
+        )
+        .await;
+    let result3 = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{add}", vec![Value::U64(3)])
+        .await;
+    let result4 = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{get}", vec![])
+        .await;
+
+    drop(executor);
+    let executor = start(deps, &context).await.unwrap();
+
+    // Recovering the worker skips the reverted invocation
+    let result5 = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{get}", vec![])
+        .await;
+    let (metadata, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+
+    drop(executor);
+
+    check!(result1.is_ok());
+    check!(result2.is_err());
+    check!(result3.is_ok());
+    check!(result4 == Ok(vec![Value::U64(8)]));
+    check!(result5 == Ok(vec![Value::U64(8)]));
+    check!(metadata.last_known_status.status != WorkerStatus::Failed);
+}
+
+#[test]
+#[tracing::instrument]
+async fn invocation_times_out_while_blocked_in_host_call(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("clock-service").await;
+    let worker_id = executor
+        .start_worker(&component_id, "invocation-timeout-1")
+        .await;
+
+    let start = Instant::now();
+    let result1 = executor
+        .invoke_and_await_with_options(
+            &worker_id,
+            "golem:it/api.{sleep}",
+            vec![Value::U64(5)],
+            None,
+            Some(Duration::from_secs(1)),
+        )
+        .await;
+    let elapsed = start.elapsed();
+
+    // The worker is restarted after the timed out invocation and keeps processing invocations
+    let result2 = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{sleep}", vec![Value::U64(0)])
+        .await;
+
+    drop(executor);
+
+    check!(is_worker_execution_error(
+        &result1.err().unwrap(),
+        &worker_execution_error::Error::InvocationTimedOut(InvocationTimedOut {})
+    ));
+    check!(elapsed < Duration::from_secs(4));
+    check!(result2.is_ok());
+}
+
+#[test]
+#[tracing::instrument]
+async fn invocation_retry_policy_overrides_worker_retry_policy(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("failing-component").await;
+    let worker_id1 = executor
+        .start_worker(&component_id, "invocation-retry-policy-1")
+        .await;
+    let worker_id2 = executor
+        .start_worker(&component_id, "invocation-retry-policy-2")
+        .await;
+
+    let no_retries = RetryConfig {
+        max_attempts: 0,
+        min_delay: Duration::from_millis(100),
+        max_delay: Duration::from_secs(1),
+        multiplier: 2.0,
+        max_jitter_factor: None,
+    };
+
+    let result1 = executor
+        .invoke_and_await_with_options(
+            &worker_id1,
+            "golem:component/api.{add}",
+            vec![Value::U64(50)],
+            Some(no_retries),
+            None,
+        )
+        .await;
+    let result2 = executor
+        .invoke_and_await(
+            &worker_id2,
+            "golem:component/api.{add}",
+            vec![Value::U64(50)],
+        )
+        .await;
+
+    let oplog1 = executor.get_oplog(&worker_id1, OplogIndex::INITIAL).await;
+    let oplog2 = executor.get_oplog(&worker_id2, OplogIndex::INITIAL).await;
+
+    drop(executor);
+
+    let error_count = |oplog: &[PublicOplogEntry]| {
+        oplog
+            .iter()
+            .filter(|entry| matches!(entry, PublicOplogEntry::Error(_)))
+            .count()
+    };
+
+    check!(result1.is_err());
+    check!(result2.is_err());
+    check!(error_count(&oplog1) == 1);
+    check!(error_count(&oplog2) > 1);
+}
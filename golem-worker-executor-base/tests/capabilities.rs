@@ -0,0 +1,213 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use test_r::{inherit_test_dep, test};
+
+use crate::common::{set_capability_policy, start, TestContext};
+use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
+use assert2::check;
+use golem_common::model::component_metadata::{CapabilityPolicy, HostCapability};
+use golem_test_framework::dsl::{worker_error_message, TestDslUnsafe};
+use golem_wasm_rpc::Value;
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+inherit_test_dep!(WorkerExecutorTestDependencies);
+inherit_test_dep!(LastUniqueId);
+inherit_test_dep!(Tracing);
+
+fn denying(capability: HostCapability) -> CapabilityPolicy {
+    CapabilityPolicy {
+        denied: vec![capability],
+        allowed_http_hosts: None,
+    }
+}
+
+#[test]
+#[tracing::instrument]
+async fn denied_blobstore_traps(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("blob-store-service").await;
+    set_capability_policy(&component_id, 0, &denying(HostCapability::BlobStore)).await;
+    let worker_name = "denied-blob-store-service-1";
+    let worker_id = executor.start_worker(&component_id, worker_name).await;
+
+    let result = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{create-container}",
+            vec![Value::String(format!(
+                "{component_id}-{worker_name}-container"
+            ))],
+        )
+        .await;
+
+    drop(executor);
+
+    check!(result.is_err());
+    check!(worker_error_message(&result.err().unwrap())
+        .contains("The blobstore capability is denied by the capability policy"));
+}
+
+#[test]
+#[tracing::instrument]
+async fn denied_sockets_fail_name_lookups(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("networking").await;
+    set_capability_policy(&component_id, 0, &denying(HostCapability::Sockets)).await;
+    let worker_id = executor
+        .start_worker(&component_id, "denied-networking-1")
+        .await;
+
+    // The guest panics on the access-denied error of the name lookup
+    let result = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get}", vec![])
+        .await;
+
+    drop(executor);
+
+    check!(result.is_err());
+    check!(worker_error_message(&result.err().unwrap()).contains("resolve_addresses"));
+}
+
+#[test]
+#[tracing::instrument]
+async fn denied_rpc_traps(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let registry_component_id = executor.store_component("auction_registry_composed").await;
+    let auction_component_id = executor.store_component("auction").await;
+    set_capability_policy(&registry_component_id, 0, &denying(HostCapability::Rpc)).await;
+
+    let mut env = HashMap::new();
+    env.insert(
+        "AUCTION_COMPONENT_ID".to_string(),
+        auction_component_id.to_string(),
+    );
+    let registry_worker_id = executor
+        .start_worker_with(
+            &registry_component_id,
+            "denied-auction-registry-1",
+            vec![],
+            env,
+        )
+        .await;
+
+    let expiration = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let result = executor
+        .invoke_and_await(
+            &registry_worker_id,
+            "auction:registry/api.{create-auction}",
+            vec![
+                Value::String("test-auction".to_string()),
+                Value::String("this is a test".to_string()),
+                Value::F32(100.0),
+                Value::U64(expiration + 600),
+            ],
+        )
+        .await;
+
+    drop(executor);
+
+    check!(result.is_err());
+    check!(worker_error_message(&result.err().unwrap())
+        .contains("The rpc capability is denied by the capability policy"));
+}
+
+#[test]
+#[tracing::instrument]
+async fn recovery_keeps_the_policy_of_the_worker_version(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("blob-store-service").await;
+    let worker_name = "policy-change-blob-store-service-1";
+    let container_name = format!("{component_id}-{worker_name}-container");
+    let worker_id = executor.start_worker(&component_id, worker_name).await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{create-container}",
+            vec![Value::String(container_name.clone())],
+        )
+        .await
+        .unwrap();
+
+    // Changing the policy creates a new component version
+    let new_version = executor
+        .update_component(&component_id, "blob-store-service")
+        .await;
+    set_capability_policy(
+        &component_id,
+        new_version,
+        &denying(HostCapability::BlobStore),
+    )
+    .await;
+
+    drop(executor);
+    let executor = start(deps, &context).await.unwrap();
+
+    // The worker recovers on its original version, replaying the blob store calls under the
+    // policy they were recorded with
+    let exists = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{container-exists}",
+            vec![Value::String(container_name)],
+        )
+        .await;
+
+    // New workers start on the latest version
+    let new_worker_name = "policy-change-blob-store-service-2";
+    let new_worker_id = executor.start_worker(&component_id, new_worker_name).await;
+    let denied = executor
+        .invoke_and_await(
+            &new_worker_id,
+            "golem:it/api.{create-container}",
+            vec![Value::String(format!(
+                "{component_id}-{new_worker_name}-container"
+            ))],
+        )
+        .await;
+
+    drop(executor);
+
+    check!(exists == Ok(vec![Value::Bool(true)]));
+    check!(denied.is_err());
+}
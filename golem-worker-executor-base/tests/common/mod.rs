@@ -1,9 +1,9 @@
 use anyhow::Error;
 use async_trait::async_trait;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use golem_service_base::service::initial_component_files::InitialComponentFilesService;
-use golem_service_base::storage::blob::BlobStorage;
+use golem_service_base::storage::blob::{BlobStorage, BlobStorageNamespace};
 use golem_wasm_rpc::wasmtime::ResourceStore;
 use golem_wasm_rpc::{Uri, Value};
 use golem_worker_executor_base::services::file_loader::FileLoader;
@@ -17,42 +17,48 @@ use std::sync::atomic::Ordering;
 use std::sync::{Arc, RwLock, Weak};
 
 use golem_common::model::{
-    AccountId, ComponentFilePath, ComponentId, ComponentVersion, IdempotencyKey, OwnedWorkerId,
-    PluginInstallationId, ScanCursor, WorkerFilter, WorkerId, WorkerMetadata, WorkerStatus,
-    WorkerStatusRecord,
+    AccountId, ComponentFilePath, ComponentId, ComponentVersion, IdempotencyKey, InvocationOptions,
+    OwnedWorkerId, PluginInstallationId, ScanCursor, WorkerFilter, WorkerId, WorkerMetadata,
+    WorkerStatus, WorkerStatusRecord,
 };
 use golem_service_base::config::{BlobStorageConfig, LocalFileSystemBlobStorageConfig};
+use golem_service_base::model::UpdateDryRunResult;
 use golem_worker_executor_base::error::GolemError;
 use golem_worker_executor_base::services::golem_config::{
     CompiledComponentServiceConfig, CompiledComponentServiceEnabledConfig, ComponentServiceConfig,
-    ComponentServiceLocalConfig, GolemConfig, IndexedStorageConfig, KeyValueStorageConfig,
-    MemoryConfig, ShardManagerServiceConfig, WorkerServiceGrpcConfig,
+    ComponentServiceLocalConfig, ConcurrencyConfig, GolemConfig, GracefulShutdownConfig,
+    IndexedStorageConfig, KeyValueStorageConfig, MemoryConfig, ShardManagerServiceConfig,
+    WorkerServiceGrpcConfig,
 };
 
 use golem_worker_executor_base::durable_host::{
     DurableWorkerCtx, DurableWorkerCtxView, PublicDurableWorkerState,
 };
 use golem_worker_executor_base::model::{
-    CurrentResourceLimits, ExecutionStatus, InterruptKind, LastError, ListDirectoryResult,
-    ReadFileResult, TrapType, WorkerConfig,
+    CurrentResourceLimits, DeleteFileResult, ExecutionStatus, InterruptKind, LastError,
+    ListDirectoryResult, MakeDirectoryResult, PutFileResult, ReadFileResult, TrapType,
+    WorkerConfig,
 };
 use golem_worker_executor_base::services::active_workers::ActiveWorkers;
 use golem_worker_executor_base::services::blob_store::BlobStoreService;
-use golem_worker_executor_base::services::component::{ComponentMetadata, ComponentService};
+use golem_worker_executor_base::services::component::{
+    ComponentMetadata, ComponentService, InstancePreCache,
+};
 use golem_worker_executor_base::services::key_value::KeyValueService;
 use golem_worker_executor_base::services::oplog::{Oplog, OplogService};
 use golem_worker_executor_base::services::promise::PromiseService;
 use golem_worker_executor_base::services::scheduler::SchedulerService;
 use golem_worker_executor_base::services::shard::ShardService;
 use golem_worker_executor_base::services::shard_manager::ShardManagerService;
+use golem_worker_executor_base::services::volume::VolumeService;
 use golem_worker_executor_base::services::worker::WorkerService;
 use golem_worker_executor_base::services::worker_activator::WorkerActivator;
 use golem_worker_executor_base::services::worker_event::WorkerEventService;
-use golem_worker_executor_base::services::{plugins, All, HasAll, HasConfig, HasOplogService};
+use golem_worker_executor_base::services::{All, HasAll, HasConfig, HasOplogService};
 use golem_worker_executor_base::wasi_host::create_linker;
 use golem_worker_executor_base::workerctx::{
-    ExternalOperations, FileSystemReading, FuelManagement, IndexedResourceStore, InvocationHooks,
-    InvocationManagement, StatusManagement, UpdateManagement, WorkerCtx,
+    ExternalOperations, FileSystemReading, FileSystemWriting, FuelManagement, IndexedResourceStore,
+    InvocationHooks, InvocationManagement, StatusManagement, UpdateManagement, WorkerCtx,
 };
 use golem_worker_executor_base::Bootstrap;
 
@@ -64,13 +70,18 @@ use golem::api0_2_0;
 use golem_common::config::RedisConfig;
 
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
-    get_running_workers_metadata_response, get_workers_metadata_response,
-    GetRunningWorkersMetadataRequest, GetRunningWorkersMetadataSuccessResponse,
-    GetWorkersMetadataRequest, GetWorkersMetadataSuccessResponse,
+    dry_run_update_worker_response, get_running_workers_metadata_response,
+    get_workers_metadata_response, DryRunUpdateWorkerRequest, GetRunningWorkersMetadataRequest,
+    GetRunningWorkersMetadataSuccessResponse, GetWorkersMetadataRequest,
+    GetWorkersMetadataSuccessResponse,
 };
 use golem_common::model::component::{ComponentOwner, DefaultComponentOwner};
-use golem_common::model::oplog::WorkerResourceId;
-use golem_common::model::plugin::{DefaultPluginOwner, DefaultPluginScope};
+use golem_common::model::component_metadata::{CapabilityPolicy, FailurePolicy};
+use golem_common::model::oplog::{OplogIndex, WorkerResourceId};
+use golem_common::model::plugin::{
+    DefaultPluginOwner, DefaultPluginScope, OplogArchiveSinkDefinition, OplogWebhookSinkDefinition,
+    PluginDefinition, PluginInstallation, PluginTypeSpecificDefinition,
+};
 use golem_test_framework::components::component_compilation_service::ComponentCompilationService;
 use golem_test_framework::components::rdb::Rdb;
 use golem_test_framework::components::redis::Redis;
@@ -80,7 +91,7 @@ use golem_test_framework::components::worker_executor_cluster::WorkerExecutorClu
 use golem_test_framework::config::TestDependencies;
 use golem_test_framework::dsl::to_worker_metadata;
 use golem_worker_executor_base::preview2::golem;
-use golem_worker_executor_base::preview2::golem::api1_1_0;
+use golem_worker_executor_base::preview2::golem::{api1_1_0, api1_2_0};
 use golem_worker_executor_base::services::events::Events;
 use golem_worker_executor_base::services::oplog::plugin::OplogProcessorPlugin;
 use golem_worker_executor_base::services::plugins::{Plugins, PluginsObservations};
@@ -182,6 +193,76 @@ impl TestWorkerExecutor {
             }
         }
     }
+
+    pub async fn dry_run_update_worker(
+        &self,
+        worker_id: &WorkerId,
+        target_version: ComponentVersion,
+    ) -> UpdateDryRunResult {
+        let response = self
+            .client()
+            .await
+            .expect("Failed to get client")
+            .dry_run_update_worker(DryRunUpdateWorkerRequest {
+                worker_id: Some(worker_id.clone().into()),
+                target_version,
+                account_id: Some(
+                    AccountId {
+                        value: "test-account".to_string(),
+                    }
+                    .into(),
+                ),
+            })
+            .await
+            .expect("Failed to dry run the worker update")
+            .into_inner();
+
+        match response.result {
+            None => panic!("No response from dry_run_update_worker"),
+            Some(dry_run_update_worker_response::Result::Success(result)) => result.into(),
+            Some(dry_run_update_worker_response::Result::Failure(error)) => {
+                panic!("Failed to dry run the worker update: {error:?}")
+            }
+        }
+    }
+
+    /// Reads the entries exported by an oplog archive sink installation for a worker, grouped by
+    /// archive segment in order
+    pub async fn read_oplog_archive(
+        &self,
+        worker_id: &WorkerId,
+        installation_id: &PluginInstallationId,
+    ) -> Vec<Vec<serde_json::Value>> {
+        let namespace = BlobStorageNamespace::OplogExport {
+            account_id: AccountId {
+                value: "test-account".to_string(),
+            },
+            component_id: worker_id.component_id.clone(),
+        };
+        let dir = Path::new(&worker_id.worker_name).join(installation_id.to_string());
+        let blob_storage = self.blob_storage();
+        let mut segments = blob_storage
+            .list_dir("test", "read_oplog_archive", namespace.clone(), &dir)
+            .await
+            .unwrap_or_default();
+        segments.sort();
+
+        let mut result = Vec::new();
+        for segment in segments {
+            let data = blob_storage
+                .get_raw("test", "read_oplog_archive", namespace.clone(), &segment)
+                .await
+                .expect("Failed to read oplog archive segment")
+                .expect("Oplog archive segment not found");
+            result.push(
+                String::from_utf8_lossy(&data)
+                    .lines()
+                    .map(|line| serde_json::from_str(line).expect("Invalid archived oplog entry"))
+                    .collect(),
+            );
+        }
+        result
+    }
 }
 
 impl Clone for TestWorkerExecutor {
@@ -287,10 +368,130 @@ pub async fn start(
     start_limited(deps, context, None).await
 }
 
+/// Sets the failure policy of a component in the local component store, before starting any
+/// of its workers
+pub async fn set_failure_policy(component_id: &ComponentId, failure_policy: &FailurePolicy) {
+    set_component_property(
+        component_id,
+        0,
+        "failurePolicy",
+        serde_json::to_value(failure_policy).expect("Failed to serialize failure policy"),
+    )
+    .await
+}
+
+pub async fn set_capability_policy(
+    component_id: &ComponentId,
+    component_version: ComponentVersion,
+    capability_policy: &CapabilityPolicy,
+) {
+    set_component_property(
+        component_id,
+        component_version,
+        "capabilityPolicy",
+        serde_json::to_value(capability_policy).expect("Failed to serialize capability policy"),
+    )
+    .await
+}
+
+/// Installs one of the built-in plugins served by the test executor for a component version in
+/// the local component store, before starting any of its workers
+pub async fn install_plugin(
+    component_id: &ComponentId,
+    component_version: ComponentVersion,
+    plugin_name: &str,
+    parameters: HashMap<String, String>,
+) -> PluginInstallationId {
+    let installation = PluginInstallation {
+        id: PluginInstallationId::new_v4(),
+        name: plugin_name.to_string(),
+        version: "1.0.0".to_string(),
+        priority: 0,
+        parameters,
+    };
+    let mut installations = get_plugin_installations(component_id, component_version).await;
+    installations.push(installation.clone());
+    set_component_property(
+        component_id,
+        component_version,
+        "pluginInstallations",
+        serde_json::to_value(installations).expect("Failed to serialize plugin installations"),
+    )
+    .await;
+    installation.id
+}
+
+async fn get_plugin_installations(
+    component_id: &ComponentId,
+    component_version: ComponentVersion,
+) -> Vec<PluginInstallation> {
+    let properties = get_component_properties(component_id, component_version).await;
+    serde_json::from_value(properties["pluginInstallations"].clone())
+        .expect("Failed to parse plugin installations")
+}
+
+async fn get_component_properties(
+    component_id: &ComponentId,
+    component_version: ComponentVersion,
+) -> serde_json::Value {
+    let properties =
+        tokio::fs::read_to_string(component_properties_path(component_id, component_version))
+            .await
+            .expect("Failed to read component properties");
+    serde_json::from_str(&properties).expect("Failed to parse component properties")
+}
+
+async fn set_component_property(
+    component_id: &ComponentId,
+    component_version: ComponentVersion,
+    name: &str,
+    value: serde_json::Value,
+) {
+    let mut properties = get_component_properties(component_id, component_version).await;
+    properties[name] = value;
+    tokio::fs::write(
+        component_properties_path(component_id, component_version),
+        properties.to_string(),
+    )
+    .await
+    .expect("Failed to write component properties");
+}
+
+fn component_properties_path(
+    component_id: &ComponentId,
+    component_version: ComponentVersion,
+) -> PathBuf {
+    Path::new("data/components").join(format!("{component_id}-{component_version}.json"))
+}
+
 pub async fn start_limited(
     deps: &WorkerExecutorTestDependencies,
     context: &TestContext,
     system_memory_override: Option<u64>,
+) -> anyhow::Result<TestWorkerExecutor> {
+    start_customized(
+        deps,
+        context,
+        system_memory_override,
+        ConcurrencyConfig::default(),
+    )
+    .await
+}
+
+/// Starts a worker executor with limits on the number of running workers
+pub async fn start_with_concurrency_limits(
+    deps: &WorkerExecutorTestDependencies,
+    context: &TestContext,
+    concurrency: ConcurrencyConfig,
+) -> anyhow::Result<TestWorkerExecutor> {
+    start_customized(deps, context, None, concurrency).await
+}
+
+async fn start_customized(
+    deps: &WorkerExecutorTestDependencies,
+    context: &TestContext,
+    system_memory_override: Option<u64>,
+    concurrency: ConcurrencyConfig,
 ) -> anyhow::Result<TestWorkerExecutor> {
     let redis = deps.redis();
     let redis_monitor = deps.redis_monitor();
@@ -327,6 +528,11 @@ pub async fn start_limited(
             system_memory_override,
             ..Default::default()
         },
+        concurrency,
+        graceful_shutdown: GracefulShutdownConfig {
+            enabled: false,
+            ..Default::default()
+        },
         ..Default::default()
     };
 
@@ -501,6 +707,16 @@ impl InvocationManagement for TestWorkerCtx {
         self.durable_ctx.get_current_idempotency_key().await
     }
 
+    async fn set_current_invocation_options(&mut self, options: InvocationOptions) {
+        self.durable_ctx
+            .set_current_invocation_options(options)
+            .await
+    }
+
+    fn is_invocation_deadline_exceeded(&self) -> bool {
+        self.durable_ctx.is_invocation_deadline_exceeded()
+    }
+
     fn is_live(&self) -> bool {
         self.durable_ctx.is_live()
     }
@@ -508,6 +724,10 @@ impl InvocationManagement for TestWorkerCtx {
     fn is_replay(&self) -> bool {
         self.durable_ctx.is_replay()
     }
+
+    fn last_replayed_index(&self) -> OplogIndex {
+        self.durable_ctx.last_replayed_index()
+    }
 }
 
 #[async_trait]
@@ -623,6 +843,73 @@ impl UpdateManagement for TestWorkerCtx {
 
 struct ServerBootstrap {}
 
+pub const OPLOG_ARCHIVE_SINK_PLUGIN: &str = "oplog-archive-sink";
+pub const OPLOG_WEBHOOK_SINK_PLUGIN: &str = "oplog-webhook-sink";
+
+/// Plugins of the test executor, serving the plugin installations stored in the local component
+/// store. Only the built-in oplog sinks are available, selected by the installation's plugin name.
+struct TestPlugins;
+
+#[async_trait]
+impl PluginsObservations for TestPlugins {
+    async fn observe_plugin_installation(
+        &self,
+        _account_id: &AccountId,
+        _component_id: &ComponentId,
+        _component_version: ComponentVersion,
+        _plugin_installation: &PluginInstallation,
+    ) -> Result<(), GolemError> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Plugins<DefaultPluginOwner, DefaultPluginScope> for TestPlugins {
+    async fn get_plugin_installation(
+        &self,
+        _account_id: &AccountId,
+        component_id: &ComponentId,
+        component_version: ComponentVersion,
+        installation_id: &PluginInstallationId,
+    ) -> Result<PluginInstallation, GolemError> {
+        get_plugin_installations(component_id, component_version)
+            .await
+            .into_iter()
+            .find(|installation| &installation.id == installation_id)
+            .ok_or_else(|| {
+                GolemError::runtime(format!("Unknown plugin installation {installation_id}"))
+            })
+    }
+
+    async fn get_plugin_definition(
+        &self,
+        _account_id: &AccountId,
+        _component_id: &ComponentId,
+        _component_version: ComponentVersion,
+        plugin_installation: &PluginInstallation,
+    ) -> Result<PluginDefinition<DefaultPluginOwner, DefaultPluginScope>, GolemError> {
+        let specs = match plugin_installation.name.as_str() {
+            OPLOG_ARCHIVE_SINK_PLUGIN => {
+                PluginTypeSpecificDefinition::OplogArchiveSink(OplogArchiveSinkDefinition {})
+            }
+            OPLOG_WEBHOOK_SINK_PLUGIN => {
+                PluginTypeSpecificDefinition::OplogWebhookSink(OplogWebhookSinkDefinition {})
+            }
+            name => return Err(GolemError::runtime(format!("Unknown plugin {name}"))),
+        };
+        Ok(PluginDefinition {
+            name: plugin_installation.name.clone(),
+            version: plugin_installation.version.clone(),
+            description: "".to_string(),
+            icon: vec![],
+            homepage: "".to_string(),
+            specs,
+            scope: DefaultPluginScope::global(),
+            owner: DefaultPluginOwner,
+        })
+    }
+}
+
 #[async_trait]
 impl WorkerCtx for TestWorkerCtx {
     type PublicState = PublicDurableWorkerState<TestWorkerCtx>;
@@ -651,6 +938,7 @@ impl WorkerCtx for TestWorkerCtx {
         worker_config: WorkerConfig,
         execution_status: Arc<RwLock<ExecutionStatus>>,
         file_loader: Arc<FileLoader>,
+        volume_service: Arc<VolumeService>,
         plugins: Arc<
             dyn Plugins<<Self::ComponentOwner as ComponentOwner>::PluginOwner, Self::PluginScope>
                 + Send
@@ -677,6 +965,7 @@ impl WorkerCtx for TestWorkerCtx {
             worker_config,
             execution_status,
             file_loader,
+            volume_service,
             plugins,
         )
         .await?;
@@ -766,18 +1055,43 @@ impl FileSystemReading for TestWorkerCtx {
     }
 }
 
+#[async_trait]
+impl FileSystemWriting for TestWorkerCtx {
+    async fn put_file(
+        &mut self,
+        path: &ComponentFilePath,
+        content: &[u8],
+    ) -> Result<PutFileResult, GolemError> {
+        self.durable_ctx.put_file(path, content).await
+    }
+
+    async fn delete_file(
+        &mut self,
+        path: &ComponentFilePath,
+    ) -> Result<DeleteFileResult, GolemError> {
+        self.durable_ctx.delete_file(path).await
+    }
+
+    async fn make_directory(
+        &mut self,
+        path: &ComponentFilePath,
+    ) -> Result<MakeDirectoryResult, GolemError> {
+        self.durable_ctx.make_directory(path).await
+    }
+}
+
 #[async_trait]
 impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
     fn create_active_workers(
         &self,
         golem_config: &GolemConfig,
     ) -> Arc<ActiveWorkers<TestWorkerCtx>> {
-        Arc::new(ActiveWorkers::<TestWorkerCtx>::new(&golem_config.memory))
+        Arc::new(ActiveWorkers::<TestWorkerCtx>::new(golem_config))
     }
 
     fn create_plugins(
         &self,
-        golem_config: &GolemConfig,
+        _golem_config: &GolemConfig,
     ) -> (
         Arc<
             dyn Plugins<
@@ -788,7 +1102,7 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
         >,
         Arc<dyn PluginsObservations + Send + Sync>,
     ) {
-        plugins::default_configured(&golem_config.plugin_service)
+        (Arc::new(TestPlugins), Arc::new(TestPlugins))
     }
 
     async fn create_services(
@@ -796,6 +1110,7 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
         active_workers: Arc<ActiveWorkers<TestWorkerCtx>>,
         engine: Arc<Engine>,
         linker: Arc<Linker<TestWorkerCtx>>,
+        instance_pre_cache: Arc<InstancePreCache<TestWorkerCtx>>,
         runtime: Handle,
         component_service: Arc<dyn ComponentService + Send + Sync>,
         shard_manager_service: Arc<dyn ShardManagerService + Send + Sync>,
@@ -813,6 +1128,7 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         events: Arc<Events>,
         file_loader: Arc<FileLoader>,
+        volume_service: Arc<VolumeService>,
         plugins: Arc<dyn Plugins<DefaultPluginOwner, DefaultPluginScope> + Send + Sync>,
         oplog_processor_plugin: Arc<dyn OplogProcessorPlugin + Send + Sync>,
     ) -> anyhow::Result<All<TestWorkerCtx>> {
@@ -824,6 +1140,7 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             active_workers.clone(),
             engine.clone(),
             linker.clone(),
+            instance_pre_cache.clone(),
             runtime.clone(),
             component_service.clone(),
             worker_service.clone(),
@@ -840,6 +1157,7 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             worker_activator.clone(),
             events.clone(),
             file_loader.clone(),
+            volume_service.clone(),
             plugins.clone(),
             oplog_processor_plugin.clone(),
             (),
@@ -848,6 +1166,7 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             active_workers,
             engine,
             linker,
+            instance_pre_cache,
             runtime,
             component_service,
             shard_manager_service,
@@ -866,6 +1185,7 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             worker_proxy,
             events,
             file_loader,
+            volume_service,
             plugins,
             oplog_processor_plugin,
             (),
@@ -876,6 +1196,10 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
         let mut linker = create_linker(engine, get_durable_ctx)?;
         api0_2_0::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         api1_1_0::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        api1_2_0::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        api1_2_0::oplog::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        api1_2_0::rpc::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        api1_2_0::topics::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_wasm_rpc::golem::rpc::types::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         Ok(linker)
     }
@@ -712,3 +712,128 @@ async fn manual_update_on_idle_with_failing_load(
     check!(metadata.last_known_status.failed_updates.len() == 1);
     check!(metadata.last_known_status.successful_updates.is_empty());
 }
+
+#[test]
+#[tracing::instrument]
+async fn dry_run_update_with_non_diverging_history(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = common::TestContext::new(last_unique_id);
+    let executor = common::start(deps, &context).await.unwrap();
+
+    let mut env = HashMap::new();
+    env.insert("TEST_ENV_1".to_string(), "value-1".to_string());
+    env.insert("TEST_ENV_2".to_string(), "value-2".to_string());
+
+    let component_id = executor.store_unique_component("update-test-v1").await;
+    let worker_id = executor
+        .start_worker_with(
+            &component_id,
+            "dry_run_update_with_non_diverging_history",
+            vec!["arg-1".to_string(), "arg-2".to_string()],
+            env,
+        )
+        .await;
+    let _ = executor.log_output(&worker_id).await;
+
+    let target_version = executor
+        .update_component(&component_id, "update-test-v2")
+        .await;
+    info!("Updated component to version {target_version}");
+
+    let before = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{f3}", vec![])
+        .await
+        .unwrap();
+
+    let dry_run = executor
+        .dry_run_update_worker(&worker_id, target_version)
+        .await;
+
+    let after = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{f3}", vec![])
+        .await
+        .unwrap();
+    let (metadata, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+
+    drop(executor);
+
+    // Expectation: f3 counts the arguments and environment variables of the worker and does not
+    // change between the versions, so replaying it on the new version with the worker's own
+    // arguments and environment succeeds. The worker itself is not updated.
+    check!(dry_run.succeeded);
+    check!(dry_run.target_version == target_version);
+    check!(dry_run.diverged_at.is_none());
+    check!(dry_run.error.is_none());
+    check!(before == after);
+    check!(metadata.args == vec!["arg-1".to_string(), "arg-2".to_string()]);
+    check!(metadata.last_known_status.component_version == 0);
+    check!(metadata.last_known_status.pending_updates.is_empty());
+    check!(metadata.last_known_status.failed_updates.is_empty());
+    check!(metadata.last_known_status.successful_updates.is_empty());
+}
+
+#[test]
+#[tracing::instrument]
+async fn dry_run_update_with_diverging_history(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = common::TestContext::new(last_unique_id);
+    let executor = common::start(deps, &context).await.unwrap();
+
+    let host_http_port = context.host_http_port();
+    let http_server = TestHttpServer::start(host_http_port);
+    let mut env = HashMap::new();
+    env.insert("PORT".to_string(), context.host_http_port().to_string());
+
+    let component_id = executor.store_unique_component("update-test-v1").await;
+    let worker_id = executor
+        .start_worker_with(
+            &component_id,
+            "dry_run_update_with_diverging_history",
+            vec![],
+            env,
+        )
+        .await;
+    let _ = executor.log_output(&worker_id).await;
+
+    let target_version = executor
+        .update_component(&component_id, "update-test-v2")
+        .await;
+    info!("Updated component to version {target_version}");
+
+    let _ = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{f1}", vec![Value::U64(0)])
+        .await
+        .unwrap();
+
+    let dry_run = executor
+        .dry_run_update_worker(&worker_id, target_version)
+        .await;
+
+    let result = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{f3}", vec![])
+        .await
+        .unwrap();
+    let (metadata, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+
+    drop(executor);
+    http_server.abort();
+
+    // Expectation: f1 reads the port of the test server from the environment, and its new
+    // version returns 150 instead of the recorded 300, which is detected as a divergence.
+    // The worker itself stays on the original version, with its original environment.
+    check!(!dry_run.succeeded);
+    check!(dry_run.diverged_at.is_some());
+    check!(dry_run.diverged_at <= Some(dry_run.replayed_until));
+    check!(dry_run.error.is_some());
+    check!(result == vec![Value::U64(4)]);
+    check!(metadata.last_known_status.component_version == 0);
+    check!(metadata.last_known_status.pending_updates.is_empty());
+    check!(metadata.last_known_status.failed_updates.is_empty());
+    check!(metadata.last_known_status.successful_updates.is_empty());
+}
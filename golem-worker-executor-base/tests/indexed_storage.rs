@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::key_value_storage::start_docker_postgres;
 use crate::WorkerExecutorTestDependencies;
 use golem_common::config::RedisConfig;
 use golem_common::redis::RedisPool;
@@ -20,12 +21,15 @@ use golem_test_framework::components::redis::Redis;
 use golem_test_framework::components::redis_monitor::RedisMonitor;
 use golem_test_framework::config::TestDependencies;
 use golem_worker_executor_base::storage::indexed::memory::InMemoryIndexedStorage;
+use golem_worker_executor_base::storage::indexed::postgres::PostgresIndexedStorage;
 use golem_worker_executor_base::storage::indexed::redis::RedisIndexedStorage;
 use golem_worker_executor_base::storage::indexed::sqlite::SqliteIndexedStorage;
 use golem_worker_executor_base::storage::indexed::{IndexedStorage, IndexedStorageNamespace};
 use sqlx::sqlite::SqlitePoolOptions;
 use std::sync::Arc;
 use test_r::inherit_test_dep;
+use testcontainers::ContainerAsync;
+use testcontainers_modules::postgres::Postgres;
 use uuid::Uuid;
 
 pub(crate) trait GetIndexedStorage {
@@ -114,6 +118,28 @@ pub(crate) async fn sqlite_storage(
     SqliteIndexedStorageWrapper { sis }
 }
 
+struct PostgresIndexedStorageWrapper {
+    pis: PostgresIndexedStorage,
+    _container: ContainerAsync<Postgres>,
+}
+
+impl GetIndexedStorage for PostgresIndexedStorageWrapper {
+    fn get_indexed_storage(&self) -> &dyn IndexedStorage {
+        &self.pis
+    }
+}
+
+pub(crate) async fn postgres_storage(
+    _deps: &WorkerExecutorTestDependencies,
+) -> impl GetIndexedStorage {
+    let (pool, container) = start_docker_postgres().await;
+    let pis = PostgresIndexedStorage::new(pool).await.unwrap();
+    PostgresIndexedStorageWrapper {
+        pis,
+        _container: container,
+    }
+}
+
 pub fn ns() -> IndexedStorageNamespace {
     IndexedStorageNamespace::OpLog
 }
@@ -895,3 +921,4 @@ macro_rules! test_indexed_storage {
 test_indexed_storage!(in_memory, crate::indexed_storage::in_memory_storage);
 test_indexed_storage!(redis, crate::indexed_storage::redis_storage);
 test_indexed_storage!(sqlite, crate::indexed_storage::sqlite_storage);
+test_indexed_storage!(postgres, crate::indexed_storage::postgres_storage);
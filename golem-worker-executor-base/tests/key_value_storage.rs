@@ -13,20 +13,25 @@
 // limitations under the License.
 
 use crate::WorkerExecutorTestDependencies;
-use golem_common::config::RedisConfig;
+use golem_common::config::{DbPostgresConfig, RedisConfig};
 use golem_common::model::AccountId;
 use golem_common::redis::RedisPool;
+use golem_service_base::storage::postgres::PostgresPool;
 use golem_service_base::storage::sqlite::SqlitePool;
 use golem_test_framework::components::redis::Redis;
 use golem_test_framework::components::redis_monitor::RedisMonitor;
 use golem_test_framework::config::TestDependencies;
 use golem_worker_executor_base::storage::keyvalue::memory::InMemoryKeyValueStorage;
+use golem_worker_executor_base::storage::keyvalue::postgres::PostgresKeyValueStorage;
 use golem_worker_executor_base::storage::keyvalue::redis::RedisKeyValueStorage;
 use golem_worker_executor_base::storage::keyvalue::sqlite::SqliteKeyValueStorage;
 use golem_worker_executor_base::storage::keyvalue::{KeyValueStorage, KeyValueStorageNamespace};
 use sqlx::sqlite::SqlitePoolOptions;
 use std::sync::Arc;
 use test_r::inherit_test_dep;
+use testcontainers::runners::AsyncRunner;
+use testcontainers::{ContainerAsync, ImageExt};
+use testcontainers_modules::postgres::Postgres;
 use uuid::Uuid;
 
 pub(crate) trait GetKeyValueStorage {
@@ -118,6 +123,53 @@ pub(crate) async fn sqlite_storage(
     SqliteKeyValueStorageWrapper { kvs }
 }
 
+pub(crate) async fn start_docker_postgres() -> (PostgresPool, ContainerAsync<Postgres>) {
+    let container = Postgres::default()
+        .with_tag("14.7-alpine")
+        .start()
+        .await
+        .expect("Failed to start postgres container");
+
+    let pool = PostgresPool::configured(&DbPostgresConfig {
+        host: "localhost".to_string(),
+        port: container
+            .get_host_port_ipv4(5432)
+            .await
+            .expect("Failed to get port"),
+        database: "postgres".to_string(),
+        username: "postgres".to_string(),
+        password: "postgres".to_string(),
+        schema: Some("test".to_string()),
+        max_connections: 10,
+    })
+    .await
+    .expect("Cannot connect to postgres db");
+
+    (pool, container)
+}
+
+struct PostgresKeyValueStorageWrapper {
+    kvs: PostgresKeyValueStorage,
+    _container: ContainerAsync<Postgres>,
+}
+
+impl GetKeyValueStorage for PostgresKeyValueStorageWrapper {
+    fn get_key_value_storage(&self) -> &dyn KeyValueStorage {
+        &self.kvs
+    }
+}
+
+pub(crate) async fn postgres_storage(
+    _deps: &WorkerExecutorTestDependencies,
+) -> impl GetKeyValueStorage {
+    let (pool, container) = start_docker_postgres().await;
+    let kvs = PostgresKeyValueStorage::new(pool).await.unwrap();
+    PostgresKeyValueStorageWrapper {
+        kvs,
+        _container: container,
+    }
+}
+
 pub fn ns() -> KeyValueStorageNamespace {
     KeyValueStorageNamespace::Worker
 }
@@ -788,3 +840,9 @@ test_kv_storage!(
     crate::key_value_storage::ns2,
     crate::key_value_storage::ns
 );
+test_kv_storage!(
+    postgres,
+    crate::key_value_storage::postgres_storage,
+    crate::key_value_storage::ns,
+    crate::key_value_storage::ns2
+);
@@ -45,6 +45,7 @@ mod common;
 
 pub mod api;
 pub mod blobstore;
+pub mod capabilities;
 pub mod compatibility;
 pub mod guest_languages1;
 pub mod guest_languages2;
@@ -55,6 +56,7 @@ pub mod key_value_storage;
 pub mod keyvalue;
 pub mod measure_test_component_mem;
 pub mod observability;
+pub mod oplog_sinks;
 pub mod rust_rpc;
 pub mod scalability;
 pub mod transactions;
@@ -66,7 +68,9 @@ test_r::enable!();
 
 tag_suite!(api, group1);
 tag_suite!(blobstore, group1);
+tag_suite!(capabilities, group1);
 tag_suite!(keyvalue, group1);
+tag_suite!(oplog_sinks, group1);
 
 tag_suite!(guest_languages1, group2);
 
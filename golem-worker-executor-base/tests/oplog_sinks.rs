@@ -0,0 +1,326 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use test_r::{inherit_test_dep, test};
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use assert2::check;
+use golem_common::model::oplog::OplogIndex;
+use golem_common::model::plugin::{OplogArchiveSinkDefinition, OplogWebhookSinkDefinition};
+use golem_common::model::{PluginInstallationId, WorkerId};
+use golem_test_framework::dsl::TestDslUnsafe;
+use golem_wasm_rpc::Value;
+use http_02::StatusCode;
+use tokio::time::sleep;
+use warp::Filter;
+
+use crate::common::{
+    install_plugin, start, TestContext, TestWorkerExecutor, OPLOG_ARCHIVE_SINK_PLUGIN,
+    OPLOG_WEBHOOK_SINK_PLUGIN,
+};
+use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
+
+inherit_test_dep!(WorkerExecutorTestDependencies);
+inherit_test_dep!(LastUniqueId);
+inherit_test_dep!(Tracing);
+
+async fn add_items(executor: &TestWorkerExecutor, worker_id: &WorkerId, count: usize) {
+    for i in 0..count {
+        executor
+            .invoke_and_await(
+                worker_id,
+                "golem:it/api.{add-item}",
+                vec![Value::Record(vec![
+                    Value::String(format!("G{i}")),
+                    Value::String(format!("Golem T-Shirt {i}")),
+                    Value::F32(100.0),
+                    Value::U32(1),
+                ])],
+            )
+            .await
+            .unwrap();
+    }
+}
+
+/// Waits until the exported entries cover the whole oplog of the worker, returning the oplog
+/// entry types and the exported entries
+async fn wait_for_export<F, Fut>(
+    executor: &TestWorkerExecutor,
+    worker_id: &WorkerId,
+    exported: F,
+) -> (Vec<serde_json::Value>, Vec<serde_json::Value>)
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = Vec<serde_json::Value>>,
+{
+    let start = Instant::now();
+    loop {
+        let oplog = executor.get_oplog(worker_id, OplogIndex::INITIAL).await;
+        let entries = exported().await;
+        if entries.len() >= oplog.len() {
+            let types = oplog
+                .iter()
+                .map(|entry| serde_json::to_value(entry).unwrap()["type"].clone())
+                .collect();
+            break (types, entries);
+        }
+        if start.elapsed() > Duration::from_secs(60) {
+            panic!(
+                "Timeout waiting for the oplog export, exported {} of {} entries",
+                entries.len(),
+                oplog.len()
+            );
+        }
+        sleep(Duration::from_millis(500)).await;
+    }
+}
+
+fn oplog_indexes(entries: &[serde_json::Value]) -> Vec<u64> {
+    entries
+        .iter()
+        .map(|entry| entry["oplogIndex"].as_u64().unwrap())
+        .collect()
+}
+
+fn entry_types(entries: &[serde_json::Value]) -> Vec<serde_json::Value> {
+    entries
+        .iter()
+        .map(|entry| entry["entry"]["type"].clone())
+        .collect()
+}
+
+async fn read_archive(
+    executor: &TestWorkerExecutor,
+    worker_id: &WorkerId,
+    installation_id: &PluginInstallationId,
+) -> Vec<serde_json::Value> {
+    executor
+        .read_oplog_archive(worker_id, installation_id)
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+#[test]
+#[tracing::instrument]
+async fn oplog_archive_sink_archives_every_entry_in_rotated_segments(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let installation_id = install_plugin(
+        &component_id,
+        0,
+        OPLOG_ARCHIVE_SINK_PLUGIN,
+        HashMap::from_iter(vec![(
+            OplogArchiveSinkDefinition::MAX_SEGMENT_SIZE_PARAMETER.to_string(),
+            "2048".to_string(),
+        )]),
+    )
+    .await;
+    let worker_id = executor
+        .start_worker(&component_id, "oplog-archive-sink-1")
+        .await;
+
+    executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await
+        .unwrap();
+    add_items(&executor, &worker_id, 10).await;
+
+    let (oplog_types, archived) = wait_for_export(&executor, &worker_id, || {
+        read_archive(&executor, &worker_id, &installation_id)
+    })
+    .await;
+    let segments = executor
+        .read_oplog_archive(&worker_id, &installation_id)
+        .await;
+
+    drop(executor);
+
+    check!(oplog_indexes(&archived) == (1..=archived.len() as u64).collect::<Vec<_>>());
+    check!(entry_types(&archived)[..oplog_types.len()] == oplog_types[..]);
+    check!(segments.len() > 1);
+    check!(segments[0][0]["entry"]["type"] == "Create");
+}
+
+#[test]
+#[tracing::instrument]
+async fn oplog_archive_sink_resumes_after_restart(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let installation_id =
+        install_plugin(&component_id, 0, OPLOG_ARCHIVE_SINK_PLUGIN, HashMap::new()).await;
+    let worker_id = executor
+        .start_worker(&component_id, "oplog-archive-sink-2")
+        .await;
+
+    executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await
+        .unwrap();
+
+    // Stopping the executor before the buffered entries get exported
+    drop(executor);
+
+    let executor = start(deps, &context).await.unwrap();
+    add_items(&executor, &worker_id, 1).await;
+
+    let (oplog_types, archived) = wait_for_export(&executor, &worker_id, || {
+        read_archive(&executor, &worker_id, &installation_id)
+    })
+    .await;
+
+    drop(executor);
+
+    // The entries added before the restart are archived once the worker's oplog gets opened
+    // again, starting from the plugin's activation with the worker's creation. Entries may get
+    // archived twice if the executor stopped while exporting them.
+    let mut indexes = oplog_indexes(&archived);
+    indexes.sort();
+    indexes.dedup();
+    check!(indexes.len() >= oplog_types.len());
+    check!(indexes == (1..=indexes.len() as u64).collect::<Vec<_>>());
+    check!(archived[0]["entry"]["type"] == "Create");
+}
+
+#[test]
+#[tracing::instrument]
+async fn oplog_webhook_sink_retries_failed_deliveries(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let failures = Arc::new(Mutex::new(2));
+    let delivered = Arc::new(Mutex::new(Vec::<serde_json::Value>::new()));
+    let host_http_port = context.host_http_port();
+
+    let http_server = tokio::spawn({
+        let failures = failures.clone();
+        let delivered = delivered.clone();
+        async move {
+            let route = warp::path::path("oplog")
+                .and(warp::post())
+                .and(warp::body::json())
+                .map(move |batch: serde_json::Value| {
+                    let mut failures = failures.lock().unwrap();
+                    if *failures > 0 {
+                        *failures -= 1;
+                        warp::reply::with_status("unavailable", StatusCode::SERVICE_UNAVAILABLE)
+                    } else {
+                        delivered.lock().unwrap().push(batch);
+                        warp::reply::with_status("ok", StatusCode::OK)
+                    }
+                });
+
+            warp::serve(route)
+                .run(
+                    format!("0.0.0.0:{}", host_http_port)
+                        .parse::<SocketAddr>()
+                        .unwrap(),
+                )
+                .await;
+        }
+    });
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let installation_id = install_plugin(
+        &component_id,
+        0,
+        OPLOG_WEBHOOK_SINK_PLUGIN,
+        HashMap::from_iter(vec![(
+            OplogWebhookSinkDefinition::URL_PARAMETER.to_string(),
+            format!("http://localhost:{host_http_port}/oplog"),
+        )]),
+    )
+    .await;
+    let worker_id = executor
+        .start_worker(&component_id, "oplog-webhook-sink-1")
+        .await;
+
+    executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await
+        .unwrap();
+    add_items(&executor, &worker_id, 3).await;
+
+    let delivered_entries = || {
+        let delivered = delivered.clone();
+        async move {
+            delivered
+                .lock()
+                .unwrap()
+                .iter()
+                .flat_map(|batch| {
+                    let first_entry_index = batch["firstEntryIndex"].as_u64().unwrap();
+                    batch["entries"]
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .enumerate()
+                        .map(move |(i, entry)| {
+                            serde_json::json!({
+                                "oplogIndex": first_entry_index + i as u64,
+                                "entry": entry
+                            })
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        }
+    };
+    let (oplog_types, entries) = wait_for_export(&executor, &worker_id, delivered_entries).await;
+    let batches = delivered.lock().unwrap().clone();
+
+    drop(executor);
+    http_server.abort();
+
+    check!(*failures.lock().unwrap() == 0);
+    check!(oplog_indexes(&entries) == (1..=entries.len() as u64).collect::<Vec<_>>());
+    check!(entry_types(&entries)[..oplog_types.len()] == oplog_types[..]);
+    check!(batches.iter().all(
+        |batch| batch["workerId"]["workerName"] == "oplog-webhook-sink-1"
+            && batch["pluginInstallationId"] == installation_id.to_string()
+    ));
+}
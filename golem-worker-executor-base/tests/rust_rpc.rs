@@ -752,3 +752,172 @@ async fn ephemeral_worker_invocation_via_rpc1(
         _ => panic!("Unexpected result value"),
     }
 }
+
+#[test]
+#[tracing::instrument]
+async fn invoke_and_await_with_timeout(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let clocks_component_id = executor.store_component("clocks").await;
+    let caller_component_id = executor.store_component("rpc-timeout").await;
+
+    let mut env = HashMap::new();
+    env.insert(
+        "CLOCKS_COMPONENT_ID".to_string(),
+        clocks_component_id.to_string(),
+    );
+    let caller_worker_id = executor
+        .start_worker_with(&caller_component_id, "rpc-timeout-1", vec![], env)
+        .await;
+
+    let timed_out = executor
+        .invoke_and_await(
+            &caller_worker_id,
+            "sleep-with-timeout",
+            vec![
+                Value::String("clocks-timeout-1".to_string()),
+                Value::F64(10.0),
+                Value::U64(500),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let completed = executor
+        .invoke_and_await(
+            &caller_worker_id,
+            "sleep-with-timeout",
+            vec![
+                Value::String("clocks-timeout-2".to_string()),
+                Value::F64(0.1),
+                Value::U64(10000),
+            ],
+        )
+        .await
+        .unwrap();
+
+    drop(executor);
+
+    info!("results are: {timed_out:?}, {completed:?}");
+
+    check!(matches!(
+        &timed_out[..],
+        [Value::Result(Err(Some(error)))] if matches!(error.as_ref(), Value::String(message) if message.starts_with("timeout:"))
+    ));
+    check!(matches!(&completed[..], [Value::Result(Ok(Some(_)))]));
+}
+
+#[test]
+#[tracing::instrument]
+async fn cancel_pending_invocation(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let clocks_component_id = executor.store_component("clocks").await;
+    let caller_component_id = executor.store_component("rpc-timeout").await;
+
+    let mut env = HashMap::new();
+    env.insert(
+        "CLOCKS_COMPONENT_ID".to_string(),
+        clocks_component_id.to_string(),
+    );
+    let caller_worker_id = executor
+        .start_worker_with(&caller_component_id, "rpc-cancel-1", vec![], env)
+        .await;
+
+    let result = executor
+        .invoke_and_await(
+            &caller_worker_id,
+            "cancel-queued-sleep",
+            vec![
+                Value::String("clocks-cancel-1".to_string()),
+                Value::F64(2.0),
+            ],
+        )
+        .await
+        .unwrap();
+
+    drop(executor);
+
+    info!("result is: {result:?}");
+
+    check!(matches!(
+        &result[..],
+        [Value::Tuple(values)] if matches!(
+            &values[..],
+            [Value::Bool(true), Value::Result(Err(Some(error)))]
+                if matches!(error.as_ref(), Value::String(message) if message.starts_with("cancelled:"))
+        )
+    ));
+}
+
+#[test]
+#[tracing::instrument]
+async fn cancel_pending_invocation_with_restart(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let clocks_component_id = executor.store_component("clocks").await;
+    let caller_component_id = executor.store_component("rpc-timeout").await;
+
+    let mut env = HashMap::new();
+    env.insert(
+        "CLOCKS_COMPONENT_ID".to_string(),
+        clocks_component_id.to_string(),
+    );
+    let caller_worker_id = executor
+        .start_worker_with(&caller_component_id, "rpc-cancel-2r", vec![], env)
+        .await;
+
+    let result1 = executor
+        .invoke_and_await(
+            &caller_worker_id,
+            "cancel-queued-sleep",
+            vec![
+                Value::String("clocks-cancel-2r".to_string()),
+                Value::F64(2.0),
+            ],
+        )
+        .await
+        .unwrap();
+
+    drop(executor);
+    let executor = start(deps, &context).await.unwrap();
+
+    // Recovering the caller replays the recorded cancellation before running the new invocation
+    let result2 = executor
+        .invoke_and_await(
+            &caller_worker_id,
+            "sleep-with-timeout",
+            vec![
+                Value::String("clocks-cancel-2r".to_string()),
+                Value::F64(0.1),
+                Value::U64(10000),
+            ],
+        )
+        .await;
+
+    drop(executor);
+
+    check!(matches!(
+        &result1[..],
+        [Value::Tuple(values)] if matches!(&values[..], [Value::Bool(true), Value::Result(Err(_))])
+    ));
+    check!(matches!(
+        result2.as_deref(),
+        Ok([Value::Result(Ok(Some(_)))])
+    ));
+}
@@ -14,7 +14,9 @@
 
 use test_r::{inherit_test_dep, test, timeout};
 
-use crate::common::{start, start_limited, TestContext, TestWorkerExecutor};
+use crate::common::{
+    start, start_limited, start_with_concurrency_limits, TestContext, TestWorkerExecutor,
+};
 use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
 use assert2::check;
 use futures_util::stream::FuturesUnordered;
@@ -22,8 +24,10 @@ use futures_util::StreamExt;
 use golem_common::model::ComponentId;
 use golem_test_framework::dsl::TestDslUnsafe;
 use golem_wasm_rpc::Value;
+use golem_worker_executor_base::services::golem_config::ConcurrencyConfig;
+use std::collections::HashMap;
 use std::future::Future;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 use tokio::spawn;
 use tokio::task::JoinSet;
 use tracing::info;
@@ -308,3 +312,141 @@ async fn dynamic_large_memory_allocation(
         check!(results[i][0] == Value::U64(0));
     }
 }
+
+#[test]
+#[timeout(120000)]
+#[tracing::instrument]
+async fn executor_limit_stops_idle_workers(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start_with_concurrency_limits(
+        deps,
+        &context,
+        ConcurrencyConfig {
+            max_active_workers: Some(1),
+            ..ConcurrencyConfig::default()
+        },
+    )
+    .await
+    .unwrap();
+    let component_id = executor.store_component("clocks").await;
+
+    let mut results = Vec::new();
+    for n in 0..3 {
+        let worker_id = executor
+            .start_worker(&component_id, &format!("limited-worker-{n}"))
+            .await;
+        results.push(
+            executor
+                .invoke_and_await(&worker_id, "sleep-for", vec![Value::F64(0.1)])
+                .await,
+        );
+    }
+
+    drop(executor);
+
+    for result in results {
+        check!(result.is_ok());
+    }
+}
+
+#[test]
+#[timeout(120000)]
+#[tracing::instrument]
+async fn rpc_caller_does_not_block_the_account_limit(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start_with_concurrency_limits(
+        deps,
+        &context,
+        ConcurrencyConfig {
+            max_active_workers_per_account: Some(1),
+            ..ConcurrencyConfig::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let registry_component_id = executor.store_component("auction_registry_composed").await;
+    let auction_component_id = executor.store_component("auction").await;
+
+    let mut env = HashMap::new();
+    env.insert(
+        "AUCTION_COMPONENT_ID".to_string(),
+        auction_component_id.to_string(),
+    );
+    let registry_worker_id = executor
+        .start_worker_with(
+            &registry_component_id,
+            "auction-registry-limited",
+            vec![],
+            env,
+        )
+        .await;
+
+    let expiration = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    // The registry worker calls an auction worker of the same account while it is running
+    let result = tokio::time::timeout(
+        Duration::from_secs(60),
+        executor.invoke_and_await(
+            &registry_worker_id,
+            "auction:registry/api.{create-auction}",
+            vec![
+                Value::String("test-auction".to_string()),
+                Value::String("this is a test".to_string()),
+                Value::F32(100.0),
+                Value::U64(expiration + 600),
+            ],
+        ),
+    )
+    .await;
+
+    drop(executor);
+
+    check!(matches!(result, Ok(Ok(_))));
+}
+
+#[test]
+#[timeout(120000)]
+#[tracing::instrument]
+async fn interactive_invocations_overtake_queued_background_ones(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+    let component_id = executor.store_component("clocks").await;
+    let worker_id = executor
+        .start_worker(&component_id, "prioritized-worker")
+        .await;
+
+    for _ in 0..3 {
+        executor
+            .invoke(&worker_id, "sleep-for", vec![Value::F64(3.0)])
+            .await
+            .unwrap();
+    }
+
+    let start = tokio::time::Instant::now();
+    let result = executor
+        .invoke_and_await(&worker_id, "sleep-for", vec![Value::F64(0.0)])
+        .await;
+    let elapsed = start.elapsed();
+
+    drop(executor);
+
+    check!(result.is_ok());
+    // Only waiting for the background invocation already running, not for the queued ones
+    check!(elapsed < Duration::from_secs(6));
+}
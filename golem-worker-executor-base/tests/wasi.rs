@@ -455,6 +455,113 @@ async fn initial_file_reading_through_api(
     check!(result2 == "hello world");
 }
 
+#[test]
+#[tracing::instrument]
+async fn file_writing_through_api(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let account_id = AccountId {
+        value: "test-account".to_string(),
+    };
+
+    let file1_key = executor
+        .add_initial_component_file(
+            &account_id,
+            PathBuf::from("initial-file-read-write/files/foo.txt").as_path(),
+        )
+        .await;
+    let file2_key = executor
+        .add_initial_component_file(
+            &account_id,
+            PathBuf::from("initial-file-read-write/files/baz.txt").as_path(),
+        )
+        .await;
+
+    let component_files: Vec<InitialComponentFile> = vec![
+        InitialComponentFile {
+            key: file1_key,
+            path: ComponentFilePath::from_abs_str("/foo.txt").unwrap(),
+            permissions: ComponentFilePermissions::ReadOnly,
+        },
+        InitialComponentFile {
+            key: file2_key,
+            path: ComponentFilePath::from_abs_str("/bar/baz.txt").unwrap(),
+            permissions: ComponentFilePermissions::ReadWrite,
+        },
+    ];
+
+    let component_id = executor
+        .store_unique_component_with_files(
+            "initial-file-read-write",
+            ComponentType::Durable,
+            &component_files,
+        )
+        .await;
+    let worker_id = executor
+        .start_worker(&component_id, "initial-file-read-write-4")
+        .await;
+
+    executor
+        .put_file(&worker_id, "/new/hello.txt", b"hello".to_vec())
+        .await;
+    executor.make_directory(&worker_id, "/empty").await;
+    executor.delete_file(&worker_id, "/bar/baz.txt").await;
+
+    let overwrite_read_only = golem_test_framework::dsl::TestDsl::put_file(
+        &executor,
+        &worker_id,
+        "/foo.txt",
+        b"bar".to_vec(),
+    )
+    .await;
+    let mkdir_under_read_only = golem_test_framework::dsl::TestDsl::make_directory(
+        &executor,
+        &worker_id,
+        "/foo.txt/nested",
+    )
+    .await;
+
+    // the changes must be reproduced when the worker is recovered from its oplog
+    drop(executor);
+    let executor = start(deps, &context).await.unwrap();
+
+    let hello = executor
+        .get_file_contents(&worker_id, "/new/hello.txt")
+        .await;
+    let hello = std::str::from_utf8(&hello).unwrap();
+
+    let foo = executor.get_file_contents(&worker_id, "/foo.txt").await;
+    let foo = std::str::from_utf8(&foo).unwrap();
+
+    let mut root = executor
+        .list_directory(&worker_id, "/")
+        .await
+        .into_iter()
+        .map(|e| e.name)
+        .collect::<Vec<_>>();
+    root.sort();
+    let bar = executor
+        .list_directory(&worker_id, "/bar")
+        .await
+        .into_iter()
+        .map(|e| e.name)
+        .collect::<Vec<_>>();
+
+    drop(executor);
+
+    check!(overwrite_read_only.is_err());
+    check!(mkdir_under_read_only.is_err());
+    check!(hello == "hello");
+    check!(foo == "foo\n");
+    check!(root == vec!["bar", "empty", "foo.txt", "new"]);
+    check!(bar.is_empty());
+}
+
 #[test]
 #[tracing::instrument]
 async fn directories(
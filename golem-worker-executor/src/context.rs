@@ -16,11 +16,11 @@ use crate::services::AdditionalDeps;
 use anyhow::Error;
 use async_trait::async_trait;
 use golem_common::model::component::{ComponentOwner, DefaultComponentOwner};
-use golem_common::model::oplog::WorkerResourceId;
+use golem_common::model::oplog::{OplogIndex, WorkerResourceId};
 use golem_common::model::plugin::DefaultPluginScope;
 use golem_common::model::{
-    AccountId, ComponentVersion, IdempotencyKey, OwnedWorkerId, WorkerId, WorkerMetadata,
-    WorkerStatus, WorkerStatusRecord,
+    AccountId, ComponentVersion, IdempotencyKey, InvocationOptions, OwnedWorkerId, WorkerId,
+    WorkerMetadata, WorkerStatus, WorkerStatusRecord,
 };
 use golem_common::model::{ComponentFilePath, PluginInstallationId};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
@@ -31,8 +31,9 @@ use golem_worker_executor_base::durable_host::{
 };
 use golem_worker_executor_base::error::GolemError;
 use golem_worker_executor_base::model::{
-    CurrentResourceLimits, ExecutionStatus, InterruptKind, LastError, ListDirectoryResult,
-    ReadFileResult, TrapType, WorkerConfig,
+    CurrentResourceLimits, DeleteFileResult, ExecutionStatus, InterruptKind, LastError,
+    ListDirectoryResult, MakeDirectoryResult, PutFileResult, ReadFileResult, TrapType,
+    WorkerConfig,
 };
 use golem_worker_executor_base::services::active_workers::ActiveWorkers;
 use golem_worker_executor_base::services::blob_store::BlobStoreService;
@@ -45,6 +46,7 @@ use golem_worker_executor_base::services::plugins::Plugins;
 use golem_worker_executor_base::services::promise::PromiseService;
 use golem_worker_executor_base::services::rpc::Rpc;
 use golem_worker_executor_base::services::scheduler::SchedulerService;
+use golem_worker_executor_base::services::volume::VolumeService;
 use golem_worker_executor_base::services::worker::WorkerService;
 use golem_worker_executor_base::services::worker_event::WorkerEventService;
 use golem_worker_executor_base::services::worker_proxy::WorkerProxy;
@@ -53,8 +55,8 @@ use golem_worker_executor_base::services::{
 };
 use golem_worker_executor_base::worker::{RetryDecision, Worker};
 use golem_worker_executor_base::workerctx::{
-    ExternalOperations, FileSystemReading, FuelManagement, IndexedResourceStore, InvocationHooks,
-    InvocationManagement, StatusManagement, UpdateManagement, WorkerCtx,
+    ExternalOperations, FileSystemReading, FileSystemWriting, FuelManagement, IndexedResourceStore,
+    InvocationHooks, InvocationManagement, StatusManagement, UpdateManagement, WorkerCtx,
 };
 use std::collections::HashSet;
 use std::sync::{Arc, RwLock, Weak};
@@ -154,6 +156,16 @@ impl InvocationManagement for Context {
         self.durable_ctx.get_current_idempotency_key().await
     }
 
+    async fn set_current_invocation_options(&mut self, options: InvocationOptions) {
+        self.durable_ctx
+            .set_current_invocation_options(options)
+            .await
+    }
+
+    fn is_invocation_deadline_exceeded(&self) -> bool {
+        self.durable_ctx.is_invocation_deadline_exceeded()
+    }
+
     fn is_live(&self) -> bool {
         self.durable_ctx.is_live()
     }
@@ -161,6 +173,10 @@ impl InvocationManagement for Context {
     fn is_replay(&self) -> bool {
         self.durable_ctx.is_replay()
     }
+
+    fn last_replayed_index(&self) -> OplogIndex {
+        self.durable_ctx.last_replayed_index()
+    }
 }
 
 #[async_trait]
@@ -313,6 +329,7 @@ impl WorkerCtx for Context {
         worker_config: WorkerConfig,
         execution_status: Arc<RwLock<ExecutionStatus>>,
         file_loader: Arc<FileLoader>,
+        volume_service: Arc<VolumeService>,
         plugins: Arc<
             dyn Plugins<<Self::ComponentOwner as ComponentOwner>::PluginOwner, Self::PluginScope>
                 + Send
@@ -339,6 +356,7 @@ impl WorkerCtx for Context {
             worker_config,
             execution_status,
             file_loader,
+            volume_service,
             plugins,
         )
         .await?;
@@ -435,3 +453,28 @@ impl FileSystemReading for Context {
         self.durable_ctx.read_file(path).await
     }
 }
+
+#[async_trait]
+impl FileSystemWriting for Context {
+    async fn put_file(
+        &mut self,
+        path: &ComponentFilePath,
+        content: &[u8],
+    ) -> Result<PutFileResult, GolemError> {
+        self.durable_ctx.put_file(path, content).await
+    }
+
+    async fn delete_file(
+        &mut self,
+        path: &ComponentFilePath,
+    ) -> Result<DeleteFileResult, GolemError> {
+        self.durable_ctx.delete_file(path).await
+    }
+
+    async fn make_directory(
+        &mut self,
+        path: &ComponentFilePath,
+    ) -> Result<MakeDirectoryResult, GolemError> {
+        self.durable_ctx.make_directory(path).await
+    }
+}
@@ -23,10 +23,10 @@ use async_trait::async_trait;
 use golem_common::model::component::ComponentOwner;
 use golem_common::model::plugin::{DefaultPluginOwner, DefaultPluginScope};
 use golem_worker_executor_base::durable_host::DurableWorkerCtx;
-use golem_worker_executor_base::preview2::golem::{api0_2_0, api1_1_0};
+use golem_worker_executor_base::preview2::golem::{api0_2_0, api1_1_0, api1_2_0};
 use golem_worker_executor_base::services::active_workers::ActiveWorkers;
 use golem_worker_executor_base::services::blob_store::BlobStoreService;
-use golem_worker_executor_base::services::component::ComponentService;
+use golem_worker_executor_base::services::component::{ComponentService, InstancePreCache};
 use golem_worker_executor_base::services::events::Events;
 use golem_worker_executor_base::services::file_loader::FileLoader;
 use golem_worker_executor_base::services::golem_config::GolemConfig;
@@ -39,6 +39,7 @@ use golem_worker_executor_base::services::rpc::{DirectWorkerInvocationRpc, Remot
 use golem_worker_executor_base::services::scheduler::SchedulerService;
 use golem_worker_executor_base::services::shard::ShardService;
 use golem_worker_executor_base::services::shard_manager::ShardManagerService;
+use golem_worker_executor_base::services::volume::VolumeService;
 use golem_worker_executor_base::services::worker::WorkerService;
 use golem_worker_executor_base::services::worker_activator::WorkerActivator;
 use golem_worker_executor_base::services::worker_enumeration::{
@@ -64,7 +65,7 @@ struct ServerBootstrap;
 #[async_trait]
 impl Bootstrap<Context> for ServerBootstrap {
     fn create_active_workers(&self, golem_config: &GolemConfig) -> Arc<ActiveWorkers<Context>> {
-        Arc::new(ActiveWorkers::<Context>::new(&golem_config.memory))
+        Arc::new(ActiveWorkers::<Context>::new(golem_config))
     }
 
     fn create_plugins(
@@ -88,6 +89,7 @@ impl Bootstrap<Context> for ServerBootstrap {
         active_workers: Arc<ActiveWorkers<Context>>,
         engine: Arc<Engine>,
         linker: Arc<Linker<Context>>,
+        instance_pre_cache: Arc<InstancePreCache<Context>>,
         runtime: Handle,
         component_service: Arc<dyn ComponentService + Send + Sync>,
         shard_manager_service: Arc<dyn ShardManagerService + Send + Sync>,
@@ -105,6 +107,7 @@ impl Bootstrap<Context> for ServerBootstrap {
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         events: Arc<Events>,
         file_loader: Arc<FileLoader>,
+        volume_service: Arc<VolumeService>,
         plugins: Arc<dyn Plugins<DefaultPluginOwner, DefaultPluginScope> + Send + Sync>,
         oplog_processor_plugin: Arc<dyn OplogProcessorPlugin + Send + Sync>,
     ) -> anyhow::Result<All<Context>> {
@@ -118,6 +121,7 @@ impl Bootstrap<Context> for ServerBootstrap {
             active_workers.clone(),
             engine.clone(),
             linker.clone(),
+            instance_pre_cache.clone(),
             runtime.clone(),
             component_service.clone(),
             worker_service.clone(),
@@ -134,6 +138,7 @@ impl Bootstrap<Context> for ServerBootstrap {
             worker_activator.clone(),
             events.clone(),
             file_loader.clone(),
+            volume_service.clone(),
             plugins.clone(),
             oplog_processor_plugin.clone(),
             additional_deps.clone(),
@@ -143,6 +148,7 @@ impl Bootstrap<Context> for ServerBootstrap {
             active_workers,
             engine,
             linker,
+            instance_pre_cache,
             runtime.clone(),
             component_service,
             shard_manager_service,
@@ -161,6 +167,7 @@ impl Bootstrap<Context> for ServerBootstrap {
             worker_proxy.clone(),
             events.clone(),
             file_loader.clone(),
+            volume_service.clone(),
             plugins.clone(),
             oplog_processor_plugin,
             additional_deps,
@@ -171,6 +178,10 @@ impl Bootstrap<Context> for ServerBootstrap {
         let mut linker = create_linker(engine, get_durable_ctx)?;
         api0_2_0::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         api1_1_0::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        api1_2_0::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        api1_2_0::oplog::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        api1_2_0::rpc::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        api1_2_0::topics::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_wasm_rpc::golem::rpc::types::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         Ok(linker)
     }
@@ -48,10 +48,17 @@ async fn async_main(
     runtime: Arc<tokio::runtime::Runtime>,
 ) -> Result<(), anyhow::Error> {
     let mut join_set = JoinSet::new();
-    run(config, prometheus, runtime.handle().clone(), &mut join_set).await?;
+    let run_details = run(config, prometheus, runtime.handle().clone(), &mut join_set).await?;
 
-    while let Some(res) = join_set.join_next().await {
-        res??
+    // Once the graceful shutdown triggered by a termination signal has finished, the remaining
+    // tasks are aborted by dropping the join set
+    tokio::select! {
+        result = async {
+            while let Some(res) = join_set.join_next().await {
+                res??
+            }
+            Ok::<(), anyhow::Error>(())
+        } => result,
+        _ = run_details.shutdown.completed() => Ok(()),
     }
-    Ok(())
 }
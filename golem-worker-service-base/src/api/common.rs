@@ -15,6 +15,7 @@
 use std::fmt::{Debug, Formatter};
 
 use golem_api_grpc::proto::golem::apidefinition::v1::{api_definition_error, ApiDefinitionError};
+use golem_api_grpc::proto::golem::apideployment::v1::{api_deployment_error, ApiDeploymentError};
 use golem_api_grpc::proto::golem::worker;
 use golem_common::metrics::api::TraceErrorKind;
 use golem_common::SafeDisplay;
@@ -158,6 +159,29 @@ impl TraceErrorKind for ApiDefinitionTraceErrorKind<'_> {
     }
 }
 
+pub struct ApiDeploymentTraceErrorKind<'a>(pub &'a ApiDeploymentError);
+
+impl Debug for ApiDeploymentTraceErrorKind<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl TraceErrorKind for ApiDeploymentTraceErrorKind<'_> {
+    fn trace_error_kind(&self) -> &'static str {
+        match &self.0.error {
+            None => "None",
+            Some(error) => match error {
+                api_deployment_error::Error::BadRequest(_) => "BadRequest",
+                api_deployment_error::Error::Unauthorized(_) => "Unauthorized",
+                api_deployment_error::Error::NotFound(_) => "NotFound",
+                api_deployment_error::Error::AlreadyExists(_) => "AlreadyExists",
+                api_deployment_error::Error::InternalError(_) => "InternalError",
+            },
+        }
+    }
+}
+
 mod conversion {
     use super::{ApiEndpointError, ValidationErrorsBody, WorkerServiceErrorsBody};
     use crate::service::gateway::api_definition::ApiDefinitionError as ApiDefinitionServiceError;
@@ -166,9 +190,11 @@ mod conversion {
 
     use crate::gateway_security::IdentityProviderError;
     use crate::service::gateway::security_scheme::SecuritySchemeServiceError;
+    use crate::service::topic::TopicServiceError;
     use golem_api_grpc::proto::golem::common::ErrorsBody;
     use golem_api_grpc::proto::golem::{
         apidefinition::v1::{api_definition_error, ApiDefinitionError, RouteValidationErrorsBody},
+        apideployment::v1::{api_deployment_error, ApiDeploymentError as GrpcApiDeploymentError},
         common::ErrorBody,
     };
     use golem_common::{safe, SafeDisplay};
@@ -187,6 +213,19 @@ mod conversion {
         }
     }
 
+    impl From<TopicServiceError> for ApiEndpointError {
+        fn from(value: TopicServiceError) -> Self {
+            match value {
+                TopicServiceError::TopicNotFound(_) => ApiEndpointError::not_found(value),
+                TopicServiceError::TopicAlreadyExists(_) => ApiEndpointError::already_exists(value),
+                TopicServiceError::SubscriptionNotFound(_) => ApiEndpointError::not_found(value),
+                TopicServiceError::InvalidFilter(_) => ApiEndpointError::bad_request(value),
+                TopicServiceError::InternalRepoError(_) => ApiEndpointError::internal(value),
+                TopicServiceError::Internal(_) => ApiEndpointError::internal(value),
+            }
+        }
+    }
+
     impl From<IdentityProviderError> for ApiEndpointError {
         fn from(value: IdentityProviderError) -> Self {
             match value {
@@ -254,6 +293,9 @@ mod conversion {
                 ApiDeploymentError::ApiDeploymentNotFound(_, _) => {
                     ApiEndpointError::not_found(error)
                 }
+                ApiDeploymentError::ApiDeploymentRevisionNotFound(_, _, _) => {
+                    ApiEndpointError::not_found(error)
+                }
                 ApiDeploymentError::ApiDeploymentConflict(_) => {
                     ApiEndpointError::already_exists(error)
                 }
@@ -271,6 +313,39 @@ mod conversion {
         }
     }
 
+    impl<Namespace: Display> From<ApiDeploymentError<Namespace>> for GrpcApiDeploymentError {
+        fn from(error: ApiDeploymentError<Namespace>) -> Self {
+            let error = match error {
+                ApiDeploymentError::ApiDefinitionNotFound(_, _)
+                | ApiDeploymentError::ApiDeploymentNotFound(_, _)
+                | ApiDeploymentError::ApiDeploymentRevisionNotFound(_, _, _) => {
+                    api_deployment_error::Error::NotFound(ErrorBody {
+                        error: error.to_safe_string(),
+                    })
+                }
+                ApiDeploymentError::ApiDeploymentConflict(_) => {
+                    api_deployment_error::Error::AlreadyExists(ErrorBody {
+                        error: error.to_safe_string(),
+                    })
+                }
+                ApiDeploymentError::ApiDefinitionsConflict(_) => {
+                    api_deployment_error::Error::BadRequest(ErrorsBody {
+                        errors: vec![error.to_safe_string()],
+                    })
+                }
+                ApiDeploymentError::InternalRepoError(_)
+                | ApiDeploymentError::InternalConversionError { .. }
+                | ApiDeploymentError::ComponentConstraintCreateError(_) => {
+                    api_deployment_error::Error::InternalError(ErrorBody {
+                        error: error.to_safe_string(),
+                    })
+                }
+            };
+
+            GrpcApiDeploymentError { error: Some(error) }
+        }
+    }
+
     impl From<ValidationErrors> for ApiEndpointError {
         fn from(error: ValidationErrors) -> Self {
             let error = WorkerServiceErrorsBody::Validation(ValidationErrorsBody {
@@ -30,6 +30,7 @@ use crate::gateway_execution::auth_call_back_binding_handler::DefaultAuthCallBac
 use crate::gateway_execution::gateway_http_input_executor::{
     DefaultGatewayInputExecutor, GatewayHttpInput, GatewayHttpInputExecutor,
 };
+use crate::gateway_execution::gateway_response_cache::GatewayResponseCache;
 use crate::gateway_execution::gateway_session::{GatewaySession, GatewaySessionStore};
 use crate::gateway_execution::GatewayWorkerRequestExecutor;
 use crate::gateway_request::http_request::InputHttpRequest;
@@ -63,6 +64,7 @@ impl<Namespace: Clone + Send + Sync + 'static> CustomHttpRequestApi<Namespace> {
         >,
         file_server_binding_handler: Arc<dyn FileServerBindingHandler<Namespace> + Sync + Send>,
         gateway_session_store: Arc<dyn GatewaySession + Sync + Send>,
+        gateway_response_cache: Arc<dyn GatewayResponseCache + Sync + Send>,
     ) -> Self {
         let evaluator = Arc::new(DefaultRibInterpreter::from_worker_request_executor(
             worker_request_executor_service.clone(),
@@ -74,6 +76,7 @@ impl<Namespace: Clone + Send + Sync + 'static> CustomHttpRequestApi<Namespace> {
             evaluator,
             file_server_binding_handler,
             auth_call_back_binding_handler,
+            response_cache: gateway_response_cache,
         });
 
         Self {
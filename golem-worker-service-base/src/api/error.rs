@@ -113,9 +113,19 @@ impl From<WorkerServiceError> for WorkerApiBaseError {
             ServiceError::FileNotFound(_) => WorkerApiBaseError::NotFound(Json(ErrorBody {
                 error: error.to_safe_string(),
             })),
-            ServiceError::BadFileType(_) => WorkerApiBaseError::BadRequest(Json(ErrorsBody {
-                errors: vec![error.to_safe_string()],
+            ServiceError::BadFileType(_) | ServiceError::DirectoryNotEmpty(_) => {
+                WorkerApiBaseError::BadRequest(Json(ErrorsBody {
+                    errors: vec![error.to_safe_string()],
+                }))
+            }
+            ServiceError::ReadOnlyFile(_) => WorkerApiBaseError::Forbidden(Json(ErrorBody {
+                error: error.to_safe_string(),
             })),
+            ServiceError::FileAlreadyExists(_) => {
+                WorkerApiBaseError::AlreadyExists(Json(ErrorBody {
+                    error: error.to_safe_string(),
+                }))
+            }
         }
     }
 }
@@ -17,6 +17,7 @@ pub use custom_http_request_api::*;
 pub use error::*;
 pub use healthcheck::*;
 pub use register_api_definition_api::*;
+pub use topic_api::*;
 
 // Components and request data that can be reused for implementing server API endpoints
 mod common;
@@ -24,3 +25,4 @@ mod custom_http_request_api;
 mod error;
 mod healthcheck;
 mod register_api_definition_api;
+mod topic_api;
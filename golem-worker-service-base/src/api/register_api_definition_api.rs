@@ -16,9 +16,10 @@ use crate::gateway_api_definition::http::{
     AllPathPatterns, CompiledHttpApiDefinition, CompiledRoute, MethodPattern, Route, RouteRequest,
 };
 use crate::gateway_api_definition::{ApiDefinitionId, ApiVersion};
-use crate::gateway_api_deployment::ApiSite;
+use crate::gateway_api_deployment::{ApiDeploymentChange, ApiRouteKey, ApiSite};
 use crate::gateway_binding::{
-    GatewayBinding, GatewayBindingCompiled, StaticBinding, WorkerBinding, WorkerBindingCompiled,
+    GatewayBinding, GatewayBindingCompiled, ResponseCache, StaticBinding, WorkerBinding,
+    WorkerBindingCompiled,
 };
 use crate::gateway_middleware::{CorsPreflightExpr, HttpCors, HttpMiddleware, HttpMiddlewares};
 use crate::gateway_security::{
@@ -26,6 +27,9 @@ use crate::gateway_security::{
     SecuritySchemeWithProviderMetadata,
 };
 use golem_api_grpc::proto::golem::apidefinition as grpc_apidefinition;
+use golem_api_grpc::proto::golem::apideployment as grpc_apideployment;
+use golem_common::config::RetryConfig;
+use golem_common::model::public_oplog::PublicRetryConfig;
 use golem_common::model::GatewayBindingType;
 use golem_service_base::model::VersionedComponentId;
 use openidconnect::{ClientId, ClientSecret, RedirectUrl, Scope};
@@ -34,7 +38,7 @@ use rib::{RibInputTypeInfo, RibOutputTypeInfo};
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
 use std::result::Result;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
@@ -53,6 +57,45 @@ pub struct ApiDeployment {
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ApiDeploymentRevision {
+    pub revision: u64,
+    pub site: ApiSite,
+    pub api_definitions: Vec<ApiDefinitionInfo>,
+    pub change: ApiDeploymentChange,
+    pub source_revision: Option<u64>, // If change is rollback
+    pub created_by: String,           // The principal who made the change
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ApiDeploymentRollbackRequest {
+    pub revision: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ApiDeploymentRevisionDiff {
+    pub from_revision: u64,
+    pub to_revision: u64,
+    pub added_routes: Vec<ApiRouteInfo>,
+    pub removed_routes: Vec<ApiRouteInfo>,
+    pub changed_routes: Vec<ApiRouteInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ApiRouteInfo {
+    pub method: MethodPattern,
+    pub path: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
@@ -321,6 +364,16 @@ pub struct GatewayBindingData {
     // For binding type - worker
     // Optional only to keep backward compatibility
     pub response: Option<String>,
+    // For binding type - worker
+    pub retry_policy: Option<PublicRetryConfig>,
+    // For binding type - worker
+    #[serde(default, with = "humantime_serde")]
+    pub invocation_timeout: Option<Duration>,
+    // For binding type - worker
+    pub cache_key: Option<String>,
+    // For binding type - worker
+    #[serde(default, with = "humantime_serde")]
+    pub cache_ttl: Option<Duration>,
 
     // CORS binding type
     //  For binding type - cors-middleware
@@ -359,12 +412,24 @@ impl GatewayBindingData {
             None
         };
 
+        let cache_key = if let Some(response_cache) = &worker_binding.response_cache {
+            Some(rib::to_string(&response_cache.cache_key).map_err(|e| e.to_string())?)
+        } else {
+            None
+        };
+
         Ok(Self {
             binding_type: Some(binding_type),
             component_id: Some(worker_binding.component_id),
             worker_name: worker_id,
             idempotency_key,
             response: Some(response),
+            retry_policy: worker_binding.retry_policy.map(PublicRetryConfig::from),
+            invocation_timeout: worker_binding.invocation_timeout,
+            cache_key,
+            cache_ttl: worker_binding
+                .response_cache
+                .map(|response_cache| response_cache.ttl),
             allow_origin: None,
             allow_methods: None,
             allow_headers: None,
@@ -459,6 +524,13 @@ pub struct GatewayBindingResponseData {
     pub idempotency_key_input: Option<RibInputTypeInfo>, // If bindingType is Default or FilerServer
     pub cors_preflight: Option<HttpCors>, // If bindingType is CorsPreflight (internally, a static binding)
     pub response_mapping_output: Option<RibOutputTypeInfo>, // If bindingType is Default or FileServer
+    pub retry_policy: Option<PublicRetryConfig>, // If bindingType is Default or FileServer
+    #[serde(default, with = "humantime_serde")]
+    pub invocation_timeout: Option<Duration>, // If bindingType is Default or FileServer
+    pub cache_key: Option<String>,               // If bindingType is Default or FileServer
+    pub cache_key_input: Option<RibInputTypeInfo>, // If bindingType is Default or FileServer
+    #[serde(default, with = "humantime_serde")]
+    pub cache_ttl: Option<Duration>, // If bindingType is Default or FileServer
 }
 
 impl GatewayBindingResponseData {
@@ -491,6 +563,19 @@ impl GatewayBindingResponseData {
                 .map(|idempotency_key_compiled| idempotency_key_compiled.rib_input),
             cors_preflight: None,
             response_mapping_output: worker_binding.response_compiled.rib_output,
+            retry_policy: worker_binding.retry_policy.map(PublicRetryConfig::from),
+            invocation_timeout: worker_binding.invocation_timeout,
+            cache_key: worker_binding
+                .response_cache_compiled
+                .clone()
+                .map(|compiled| compiled.cache_key.to_string()),
+            cache_key_input: worker_binding
+                .response_cache_compiled
+                .clone()
+                .map(|compiled| compiled.rib_input),
+            cache_ttl: worker_binding
+                .response_cache_compiled
+                .map(|compiled| compiled.ttl),
         }
     }
 }
@@ -535,6 +620,11 @@ impl TryFrom<GatewayBindingCompiled> for GatewayBindingResponseData {
                     idempotency_key_input: None,
                     cors_preflight: static_binding.get_cors_preflight(),
                     response_mapping_output: None,
+                    retry_policy: None,
+                    invocation_timeout: None,
+                    cache_key: None,
+                    cache_key_input: None,
+                    cache_ttl: None,
                 })
             }
         }
@@ -560,6 +650,50 @@ impl<N> From<crate::gateway_api_deployment::ApiDeployment<N>> for ApiDeployment
     }
 }
 
+impl<N> From<crate::gateway_api_deployment::ApiDeploymentRevision<N>> for ApiDeploymentRevision {
+    fn from(value: crate::gateway_api_deployment::ApiDeploymentRevision<N>) -> Self {
+        let api_definitions = value
+            .api_definition_keys
+            .into_iter()
+            .map(|key| ApiDefinitionInfo {
+                id: key.id,
+                version: key.version,
+            })
+            .collect();
+
+        Self {
+            revision: value.revision,
+            site: value.site,
+            api_definitions,
+            change: value.change,
+            source_revision: value.source_revision,
+            created_by: value.actor,
+            created_at: value.created_at,
+        }
+    }
+}
+
+impl From<ApiRouteKey> for ApiRouteInfo {
+    fn from(value: ApiRouteKey) -> Self {
+        Self {
+            method: value.method,
+            path: value.path.to_string(),
+        }
+    }
+}
+
+impl From<crate::gateway_api_deployment::ApiDeploymentRevisionDiff> for ApiDeploymentRevisionDiff {
+    fn from(value: crate::gateway_api_deployment::ApiDeploymentRevisionDiff) -> Self {
+        Self {
+            from_revision: value.from_revision,
+            to_revision: value.to_revision,
+            added_routes: value.added_routes.into_iter().map(|r| r.into()).collect(),
+            removed_routes: value.removed_routes.into_iter().map(|r| r.into()).collect(),
+            changed_routes: value.changed_routes.into_iter().map(|r| r.into()).collect(),
+        }
+    }
+}
+
 impl TryFrom<crate::gateway_api_definition::http::HttpApiDefinition>
     for HttpApiDefinitionRequestData
 {
@@ -638,6 +772,10 @@ impl TryFrom<GatewayBinding> for GatewayBindingData {
                     worker_name: None,
                     idempotency_key: None,
                     response: None,
+                    retry_policy: None,
+                    invocation_timeout: None,
+                    cache_key: None,
+                    cache_ttl: None,
                     allow_origin: Some(cors.get_allow_origin()),
                     allow_methods: Some(cors.get_allow_methods()),
                     allow_headers: Some(cors.get_allow_headers()),
@@ -685,11 +823,26 @@ impl TryFrom<GatewayBindingData> for GatewayBinding {
                     None
                 };
 
+                let response_cache = match (
+                    &gateway_binding_data.cache_key,
+                    gateway_binding_data.cache_ttl,
+                ) {
+                    (Some(cache_key), Some(ttl)) => Some(ResponseCache {
+                        cache_key: rib::from_string(cache_key).map_err(|e| e.to_string())?,
+                        ttl,
+                    }),
+                    (None, None) => None,
+                    _ => return Err("cacheKey and cacheTtl must be set together".to_string()),
+                };
+
                 let worker_binding = WorkerBinding {
                     component_id,
                     worker_name,
                     idempotency_key,
                     response_mapping: response,
+                    retry_policy: gateway_binding_data.retry_policy.map(RetryConfig::from),
+                    invocation_timeout: gateway_binding_data.invocation_timeout,
+                    response_cache,
                 };
 
                 if v == Some(GatewayBindingType::FileServer) {
@@ -872,6 +1025,79 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::CompiledHttpRoute> for
     }
 }
 
+impl From<ApiSite> for grpc_apideployment::ApiSite {
+    fn from(value: ApiSite) -> Self {
+        Self {
+            host: value.host,
+            subdomain: value.subdomain,
+        }
+    }
+}
+
+impl From<ApiDeploymentChange> for grpc_apideployment::ApiDeploymentChange {
+    fn from(value: ApiDeploymentChange) -> Self {
+        match value {
+            ApiDeploymentChange::Deploy => grpc_apideployment::ApiDeploymentChange::Deploy,
+            ApiDeploymentChange::Undeploy => grpc_apideployment::ApiDeploymentChange::Undeploy,
+            ApiDeploymentChange::Delete => grpc_apideployment::ApiDeploymentChange::Delete,
+            ApiDeploymentChange::Rollback => grpc_apideployment::ApiDeploymentChange::Rollback,
+        }
+    }
+}
+
+impl<N> From<crate::gateway_api_deployment::ApiDeploymentRevision<N>>
+    for grpc_apideployment::ApiDeploymentRevision
+{
+    fn from(value: crate::gateway_api_deployment::ApiDeploymentRevision<N>) -> Self {
+        let definitions = value
+            .api_definition_keys
+            .into_iter()
+            .map(|key| grpc_apideployment::ApiDefinitionIdWithVersion {
+                id: Some(grpc_apidefinition::ApiDefinitionId { value: key.id.0 }),
+                version: key.version.0,
+            })
+            .collect();
+        let change: grpc_apideployment::ApiDeploymentChange = value.change.into();
+
+        Self {
+            revision: value.revision,
+            site: Some(value.site.into()),
+            definitions,
+            change: change as i32,
+            source_revision: value.source_revision,
+            actor: value.actor,
+            created_at: Some(prost_types::Timestamp::from(SystemTime::from(
+                value.created_at,
+            ))),
+        }
+    }
+}
+
+impl From<ApiRouteKey> for grpc_apideployment::ApiRouteKey {
+    fn from(value: ApiRouteKey) -> Self {
+        let method: grpc_apidefinition::HttpMethod = value.method.into();
+
+        Self {
+            method: method as i32,
+            path: value.path.to_string(),
+        }
+    }
+}
+
+impl From<crate::gateway_api_deployment::ApiDeploymentRevisionDiff>
+    for grpc_apideployment::ApiDeploymentRevisionDiff
+{
+    fn from(value: crate::gateway_api_deployment::ApiDeploymentRevisionDiff) -> Self {
+        Self {
+            from_revision: value.from_revision,
+            to_revision: value.to_revision,
+            added_routes: value.added_routes.into_iter().map(|r| r.into()).collect(),
+            removed_routes: value.removed_routes.into_iter().map(|r| r.into()).collect(),
+            changed_routes: value.changed_routes.into_iter().map(|r| r.into()).collect(),
+        }
+    }
+}
+
 impl From<MethodPattern> for grpc_apidefinition::HttpMethod {
     fn from(value: MethodPattern) -> Self {
         match value {
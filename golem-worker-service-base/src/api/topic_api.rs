@@ -0,0 +1,106 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::service::topic::{Topic, TopicSubscription, TopicSubscriptionRequest};
+use chrono::{DateTime, Utc};
+use golem_common::model::WorkerId;
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct TopicData {
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Topic> for TopicData {
+    fn from(value: Topic) -> Self {
+        Self {
+            name: value.name,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct CreateTopicRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct TopicSubscriptionData {
+    pub subscription_id: Uuid,
+    pub topic: String,
+    pub worker_id: WorkerId,
+    pub function_name: String,
+    pub filter: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TopicSubscription> for TopicSubscriptionData {
+    fn from(value: TopicSubscription) -> Self {
+        Self {
+            subscription_id: value.subscription_id,
+            topic: value.topic,
+            worker_id: value.worker_id,
+            function_name: value.function_name,
+            filter: value.filter,
+            created_at: value.created_at,
+        }
+    }
+}
+
+/// Subscribes an exported function of a worker to a topic. The function gets invoked with the
+/// published message as its only parameter, for each message matching the optional Rib filter
+/// expression, in which the message is available as `message`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct SubscribeRequest {
+    pub worker_id: WorkerId,
+    pub function_name: String,
+    pub filter: Option<String>,
+}
+
+impl From<SubscribeRequest> for TopicSubscriptionRequest {
+    fn from(value: SubscribeRequest) -> Self {
+        Self {
+            worker_id: value.worker_id,
+            function_name: value.function_name,
+            filter: value.filter,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct PublishRequest {
+    pub message: TypeAnnotatedValue,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct PublishResponse {
+    pub message_id: Uuid,
+}
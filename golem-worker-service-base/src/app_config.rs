@@ -34,6 +34,7 @@ pub struct WorkerServiceBaseConfig {
     pub environment: String,
     pub tracing: TracingConfig,
     pub gateway_session_storage: KeyValueStorageConfig,
+    pub gateway_response_cache: GatewayResponseCacheConfig,
     pub db: DbConfig,
     pub component_service: ComponentServiceConfig,
     pub port: u16,
@@ -42,6 +43,7 @@ pub struct WorkerServiceBaseConfig {
     pub routing_table: RoutingTableConfig,
     pub worker_executor_retries: RetryConfig,
     pub blob_storage: BlobStorageConfig,
+    pub topics: TopicsConfig,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -62,6 +64,33 @@ impl KeyValueStorageConfig {
     }
 }
 
+// The in-memory cache is local to each worker service instance, so invalidating it, either explicitly
+// or by changing a deployment, only affects the instance handling the request. Redis has to be used
+// when running more than one instance.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "config")]
+pub enum GatewayResponseCacheConfig {
+    InMemory(InMemoryGatewayResponseCacheConfig),
+    Redis(RedisConfig),
+}
+
+impl Default for GatewayResponseCacheConfig {
+    fn default() -> Self {
+        Self::InMemory(InMemoryGatewayResponseCacheConfig::default())
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InMemoryGatewayResponseCacheConfig {
+    pub capacity: usize,
+}
+
+impl Default for InMemoryGatewayResponseCacheConfig {
+    fn default() -> Self {
+        Self { capacity: 1024 }
+    }
+}
+
 impl WorkerServiceBaseConfig {
     pub fn is_local_env(&self) -> bool {
         self.environment.to_lowercase() == "local"
@@ -77,6 +106,7 @@ impl Default for WorkerServiceBaseConfig {
                 max_connections: 10,
             }),
             gateway_session_storage: KeyValueStorageConfig::default_redis(),
+            gateway_response_cache: GatewayResponseCacheConfig::default(),
             component_service: ComponentServiceConfig::default(),
             tracing: TracingConfig::local_dev("worker-service"),
             port: 9005,
@@ -91,6 +121,7 @@ impl Default for WorkerServiceBaseConfig {
                 max_jitter_factor: Some(0.15),
             },
             blob_storage: BlobStorageConfig::default(),
+            topics: TopicsConfig::default(),
         }
     }
 }
@@ -117,6 +148,39 @@ impl HasConfigExamples<WorkerServiceBaseConfig> for WorkerServiceBaseConfig {
     }
 }
 
+/// Redelivery of topic messages which could not be delivered to all subscribers when published
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TopicsConfig {
+    #[serde(with = "humantime_serde")]
+    pub redelivery_interval: Duration,
+    pub redelivery_batch_size: u32,
+    /// Backoff between the delivery attempts of a message. Once `max_attempts` is reached
+    /// the message is moved to the dead-letter state and no longer retried.
+    pub delivery_retries: RetryConfig,
+    /// How long delivered and dead-lettered messages are kept before being deleted
+    #[serde(with = "humantime_serde")]
+    pub message_retention: Duration,
+    pub filter_cache_capacity: usize,
+}
+
+impl Default for TopicsConfig {
+    fn default() -> Self {
+        Self {
+            redelivery_interval: Duration::from_secs(10),
+            redelivery_batch_size: 100,
+            delivery_retries: RetryConfig {
+                max_attempts: 10,
+                min_delay: Duration::from_secs(10),
+                max_delay: Duration::from_secs(3600),
+                multiplier: 2.0,
+                max_jitter_factor: Some(0.15),
+            },
+            message_retention: Duration::from_secs(7 * 24 * 60 * 60),
+            filter_cache_capacity: 1024,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ComponentServiceConfig {
     pub host: String,
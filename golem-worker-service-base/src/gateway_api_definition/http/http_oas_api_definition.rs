@@ -118,12 +118,17 @@ impl poem_openapi::types::Type for OpenApiHttpApiDefinitionRequest {
 
 mod internal {
     use crate::gateway_api_definition::http::{AllPathPatterns, MethodPattern, RouteRequest};
+    use golem_common::config::RetryConfig;
+    use golem_common::model::public_oplog::PublicRetryConfig;
     use golem_common::model::{ComponentId, GatewayBindingType};
     use openapiv3::{OpenAPI, Operation, Paths, ReferenceOr};
     use rib::Expr;
     use serde_json::Value;
+    use std::time::Duration;
 
-    use crate::gateway_binding::{GatewayBinding, ResponseMapping, StaticBinding, WorkerBinding};
+    use crate::gateway_binding::{
+        GatewayBinding, ResponseCache, ResponseMapping, StaticBinding, WorkerBinding,
+    };
     use crate::gateway_middleware::{CorsPreflightExpr, HttpCors};
     use crate::gateway_security::{SecuritySchemeIdentifier, SecuritySchemeReference};
     use golem_service_base::model::VersionedComponentId;
@@ -312,6 +317,9 @@ mod internal {
             component_id: get_component_id(gateway_binding_value)?,
             idempotency_key: get_idempotency_key(gateway_binding_value)?,
             response_mapping: get_response_mapping(gateway_binding_value)?,
+            retry_policy: get_retry_policy(gateway_binding_value)?,
+            invocation_timeout: get_invocation_timeout(gateway_binding_value)?,
+            response_cache: get_response_cache(gateway_binding_value)?,
         };
 
         Ok(binding)
@@ -426,6 +434,58 @@ mod internal {
         }
     }
 
+    pub(crate) fn get_retry_policy(
+        gateway_binding_value: &Value,
+    ) -> Result<Option<RetryConfig>, String> {
+        gateway_binding_value
+            .get("retry-policy")
+            .map(|value| {
+                serde_json::from_value::<PublicRetryConfig>(value.clone())
+                    .map(RetryConfig::from)
+                    .map_err(|err| format!("Invalid schema for retry-policy. {}", err))
+            })
+            .transpose()
+    }
+
+    pub(crate) fn get_invocation_timeout(
+        gateway_binding_value: &Value,
+    ) -> Result<Option<Duration>, String> {
+        if let Some(timeout) = gateway_binding_value.get("invocation-timeout") {
+            let timeout = timeout
+                .as_str()
+                .ok_or("invocation-timeout is not a string")?;
+            Ok(Some(
+                humantime_serde::re::humantime::parse_duration(timeout)
+                    .map_err(|err| format!("Invalid invocation-timeout. {}", err))?,
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub(crate) fn get_response_cache(
+        gateway_binding_value: &Value,
+    ) -> Result<Option<ResponseCache>, String> {
+        match (
+            gateway_binding_value.get("cache-key"),
+            gateway_binding_value.get("cache-ttl"),
+        ) {
+            (Some(key), Some(ttl)) => {
+                let key_expr = key.as_str().ok_or("cache-key is not a string")?;
+                let ttl = ttl.as_str().ok_or("cache-ttl is not a string")?;
+
+                Ok(Some(ResponseCache {
+                    cache_key: rib::from_string(key_expr).map_err(|err| err.to_string())?,
+                    ttl: humantime_serde::re::humantime::parse_duration(ttl)
+                        .map_err(|err| format!("Invalid cache-ttl. {}", err))?,
+                }))
+            }
+            (None, None) => Ok(None),
+            (Some(_), None) => Err("cache-key requires cache-ttl".to_string()),
+            (None, Some(_)) => Err("cache-ttl requires cache-key".to_string()),
+        }
+    }
+
     pub(crate) fn get_path_pattern(path: &str) -> Result<AllPathPatterns, String> {
         AllPathPatterns::parse(path)
     }
@@ -489,6 +549,62 @@ mod tests {
         assert_eq!(result, Ok(expected));
     }
 
+    #[test]
+    fn test_get_gateway_binding_with_invocation_options() {
+        let binding = json!({
+            "component-id": "0b6d9cd8-f373-4e29-8a5a-548e61b868a5",
+            "component-version": 0,
+            "response": "let status: u64 = 200; {status: status}",
+            "retry-policy": {
+                "max_attempts": 2,
+                "min_delay": "100ms",
+                "max_delay": "1s",
+                "multiplier": 2.0
+            },
+            "invocation-timeout": "30s"
+        });
+
+        let result = get_gateway_binding(&binding).unwrap();
+
+        assert_eq!(
+            result.retry_policy.map(|policy| policy.max_attempts),
+            Some(2)
+        );
+        assert_eq!(
+            result.invocation_timeout,
+            Some(std::time::Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn test_get_gateway_binding_with_response_cache() {
+        let binding = json!({
+            "component-id": "0b6d9cd8-f373-4e29-8a5a-548e61b868a5",
+            "component-version": 0,
+            "response": "let status: u64 = 200; {status: status}",
+            "cache-key": "request.path.user",
+            "cache-ttl": "5m"
+        });
+
+        let result = get_gateway_binding(&binding).unwrap();
+
+        assert_eq!(
+            result
+                .response_cache
+                .map(|response_cache| response_cache.ttl),
+            Some(std::time::Duration::from_secs(300))
+        );
+
+        let binding = json!({
+            "component-id": "0b6d9cd8-f373-4e29-8a5a-548e61b868a5",
+            "component-version": 0,
+            "response": "let status: u64 = 200; {status: status}",
+            "cache-key": "request.path.user"
+        });
+
+        assert!(get_gateway_binding(&binding).is_err());
+    }
+
     #[test]
     fn test_get_route_with_no_binding_with_options_method() {
         let path_item = Operation::default();
@@ -143,6 +143,9 @@ mod tests {
             worker_name: None,
             idempotency_key: None,
             response_mapping: ResponseMapping(Expr::literal("")),
+            retry_policy: None,
+            invocation_timeout: None,
+            response_cache: None,
         };
 
         Route {
@@ -162,6 +165,9 @@ mod tests {
             worker_name: None,
             idempotency_key: None,
             response_mapping: ResponseMapping(Expr::literal("")),
+            retry_policy: None,
+            invocation_timeout: None,
+            response_cache: None,
         };
 
         Route {
@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::gateway_api_definition::http::{AllPathPatterns, MethodPattern};
 use crate::service::gateway::api_definition::ApiDefinitionIdWithVersion;
-use poem_openapi::{NewType, Object};
+use poem_openapi::{Enum, NewType, Object};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
+use std::str::FromStr;
 
 #[derive(Eq, Hash, PartialEq, Clone, Debug, serde::Deserialize)]
 pub struct ApiDeploymentRequest<Namespace> {
@@ -32,6 +34,74 @@ pub struct ApiDeployment<Namespace> {
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
+// Every change of the set of API definitions deployed to a site is recorded as a revision,
+// holding the complete set of definitions deployed after the change. The content of these
+// definitions is kept with the revision, so later updates of the definitions do not affect it.
+#[derive(Eq, Hash, PartialEq, Clone, Debug)]
+pub struct ApiDeploymentRevision<Namespace> {
+    pub namespace: Namespace,
+    pub site: ApiSite,
+    pub revision: u64,
+    pub api_definition_keys: Vec<ApiDefinitionIdWithVersion>,
+    pub change: ApiDeploymentChange,
+    // The revision restored by a rollback
+    pub source_revision: Option<u64>,
+    // The principal who made the change
+    pub actor: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Enum)]
+#[serde(rename_all = "kebab-case")]
+#[oai(rename_all = "kebab-case")]
+pub enum ApiDeploymentChange {
+    Deploy,
+    Undeploy,
+    Delete,
+    Rollback,
+}
+
+impl Display for ApiDeploymentChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiDeploymentChange::Deploy => write!(f, "deploy"),
+            ApiDeploymentChange::Undeploy => write!(f, "undeploy"),
+            ApiDeploymentChange::Delete => write!(f, "delete"),
+            ApiDeploymentChange::Rollback => write!(f, "rollback"),
+        }
+    }
+}
+
+impl FromStr for ApiDeploymentChange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "deploy" => Ok(ApiDeploymentChange::Deploy),
+            "undeploy" => Ok(ApiDeploymentChange::Undeploy),
+            "delete" => Ok(ApiDeploymentChange::Delete),
+            "rollback" => Ok(ApiDeploymentChange::Rollback),
+            _ => Err(format!("Invalid API deployment change: {}", s)),
+        }
+    }
+}
+
+// Routes are identified by their method and path pattern when comparing revisions
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ApiRouteKey {
+    pub method: MethodPattern,
+    pub path: AllPathPatterns,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiDeploymentRevisionDiff {
+    pub from_revision: u64,
+    pub to_revision: u64,
+    pub added_routes: Vec<ApiRouteKey>,
+    pub removed_routes: Vec<ApiRouteKey>,
+    pub changed_routes: Vec<ApiRouteKey>,
+}
+
 #[derive(Debug, Eq, Clone, Hash, PartialEq, Serialize, Deserialize, Object)]
 pub struct ApiSite {
     pub host: String,
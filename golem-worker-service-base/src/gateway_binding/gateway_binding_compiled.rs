@@ -14,13 +14,16 @@
 
 use crate::gateway_binding::StaticBinding;
 use crate::gateway_binding::{
-    GatewayBinding, IdempotencyKeyCompiled, ResponseMappingCompiled, WorkerBinding,
-    WorkerBindingCompiled, WorkerNameCompiled,
+    GatewayBinding, IdempotencyKeyCompiled, ResponseCacheCompiled, ResponseMappingCompiled,
+    WorkerBinding, WorkerBindingCompiled, WorkerNameCompiled,
 };
 use golem_api_grpc::proto::golem::apidefinition::GatewayBindingType as ProtoGatewayBindingType;
+use golem_common::config::RetryConfig;
+use golem_common::model::public_oplog::PublicRetryConfig;
 use golem_common::model::GatewayBindingType;
 use rib::RibOutputTypeInfo;
 use std::ops::Deref;
+use std::time::Duration;
 
 // A compiled binding is a binding with all existence of Rib Expr
 // get replaced with their compiled form - RibByteCode.
@@ -114,6 +117,12 @@ impl TryFrom<GatewayBindingCompiled>
                             )?,
                         ),
                         response_rib_output: None,
+                        retry_policy: None,
+                        timeout_millis: None,
+                        cache_key: None,
+                        compiled_cache_key_expr: None,
+                        cache_key_rib_input: None,
+                        cache_ttl_millis: None,
                     },
                 )
             }
@@ -200,6 +209,32 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::CompiledGatewayBinding
                         .transpose()?,
                 };
 
+                let retry_policy = value
+                    .retry_policy
+                    .map(|policy| PublicRetryConfig::try_from(policy).map(RetryConfig::from))
+                    .transpose()?;
+                let invocation_timeout = value.timeout_millis.map(Duration::from_millis);
+
+                let response_cache_compiled = match (
+                    value.cache_key,
+                    value.compiled_cache_key_expr,
+                    value.cache_key_rib_input,
+                    value.cache_ttl_millis,
+                ) {
+                    (
+                        Some(cache_key),
+                        Some(compiled_cache_key),
+                        Some(rib_input),
+                        Some(ttl_millis),
+                    ) => Some(ResponseCacheCompiled {
+                        cache_key: rib::Expr::try_from(cache_key)?,
+                        compiled_cache_key: rib::RibByteCode::try_from(compiled_cache_key)?,
+                        rib_input: rib::RibInputTypeInfo::try_from(rib_input)?,
+                        ttl: Duration::from_millis(ttl_millis),
+                    }),
+                    _ => None,
+                };
+
                 let binding_type = value.binding_type.ok_or("Missing binding_type")?;
 
                 if binding_type == 0 {
@@ -208,6 +243,9 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::CompiledGatewayBinding
                         worker_name_compiled,
                         idempotency_key_compiled,
                         response_compiled,
+                        retry_policy,
+                        invocation_timeout,
+                        response_cache_compiled,
                     }))
                 } else {
                     Ok(GatewayBindingCompiled::FileServer(WorkerBindingCompiled {
@@ -215,6 +253,9 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::CompiledGatewayBinding
                         worker_name_compiled,
                         idempotency_key_compiled,
                         response_compiled,
+                        retry_policy,
+                        invocation_timeout,
+                        response_cache_compiled,
                     }))
                 }
             }
@@ -234,6 +275,7 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::CompiledGatewayBinding
 mod internal {
     use crate::gateway_binding::WorkerBindingCompiled;
 
+    use golem_common::model::public_oplog::PublicRetryConfig;
     use golem_common::model::GatewayBindingType;
 
     pub(crate) fn to_gateway_binding_compiled_proto(
@@ -285,6 +327,24 @@ mod internal {
             .worker_calls
             .map(|x| x.into());
 
+        let retry_policy = worker_binding
+            .retry_policy
+            .map(|policy| PublicRetryConfig::from(policy).into());
+        let timeout_millis = worker_binding
+            .invocation_timeout
+            .map(|timeout| timeout.as_millis() as u64);
+
+        let (cache_key, compiled_cache_key_expr, cache_key_rib_input, cache_ttl_millis) =
+            match worker_binding.response_cache_compiled {
+                Some(x) => (
+                    Some(x.cache_key.into()),
+                    Some(x.compiled_cache_key.into()),
+                    Some(x.rib_input.into()),
+                    Some(x.ttl.as_millis() as u64),
+                ),
+                None => (None, None, None, None),
+            };
+
         let binding_type = match binding_type {
             GatewayBindingType::Default => 0,
             GatewayBindingType::FileServer => 1,
@@ -306,6 +366,12 @@ mod internal {
             binding_type: Some(binding_type),
             static_binding: None,
             response_rib_output,
+            retry_policy,
+            timeout_millis,
+            cache_key,
+            compiled_cache_key_expr,
+            cache_key_rib_input,
+            cache_ttl_millis,
         }
     }
 }
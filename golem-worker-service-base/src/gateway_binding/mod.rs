@@ -17,10 +17,13 @@ pub(crate) use crate::gateway_execution::rib_input_value_resolver::*;
 pub(crate) use crate::gateway_request::request_details::*;
 pub(crate) use gateway_binding_compiled::*;
 use golem_api_grpc::proto::golem::apidefinition::GatewayBindingType;
+use golem_common::config::RetryConfig;
+use golem_common::model::public_oplog::PublicRetryConfig;
 use golem_service_base::model::VersionedComponentId;
 use rib::Expr;
 pub use static_binding::*;
 use std::ops::Deref;
+use std::time::Duration;
 pub(crate) use worker_binding::*;
 pub(crate) use worker_binding_compiled::*;
 
@@ -97,6 +100,19 @@ impl TryFrom<GatewayBinding> for golem_api_grpc::proto::golem::apidefinition::Ga
                     response: Some(worker_binding.response_mapping.0.into()),
                     idempotency_key: worker_binding.idempotency_key.map(|x| x.into()),
                     static_binding: None,
+                    retry_policy: worker_binding
+                        .retry_policy
+                        .map(|policy| PublicRetryConfig::from(policy).into()),
+                    timeout_millis: worker_binding
+                        .invocation_timeout
+                        .map(|timeout| timeout.as_millis() as u64),
+                    cache_key: worker_binding
+                        .response_cache
+                        .clone()
+                        .map(|response_cache| response_cache.cache_key.into()),
+                    cache_ttl_millis: worker_binding
+                        .response_cache
+                        .map(|response_cache| response_cache.ttl.as_millis() as u64),
                 },
             ),
             GatewayBinding::FileServer(worker_binding) => Ok(
@@ -107,6 +123,19 @@ impl TryFrom<GatewayBinding> for golem_api_grpc::proto::golem::apidefinition::Ga
                     response: Some(worker_binding.response_mapping.0.into()),
                     idempotency_key: worker_binding.idempotency_key.map(|x| x.into()),
                     static_binding: None,
+                    retry_policy: worker_binding
+                        .retry_policy
+                        .map(|policy| PublicRetryConfig::from(policy).into()),
+                    timeout_millis: worker_binding
+                        .invocation_timeout
+                        .map(|timeout| timeout.as_millis() as u64),
+                    cache_key: worker_binding
+                        .response_cache
+                        .clone()
+                        .map(|response_cache| response_cache.cache_key.into()),
+                    cache_ttl_millis: worker_binding
+                        .response_cache
+                        .map(|response_cache| response_cache.ttl.as_millis() as u64),
                 },
             ),
             GatewayBinding::Static(static_binding) => {
@@ -133,6 +162,10 @@ impl TryFrom<GatewayBinding> for golem_api_grpc::proto::golem::apidefinition::Ga
                         response: None,
                         idempotency_key: None,
                         static_binding: Some(static_binding),
+                        retry_policy: None,
+                        timeout_millis: None,
+                        cache_key: None,
+                        cache_ttl_millis: None,
                     },
                 )
             }
@@ -161,12 +194,27 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::GatewayBinding> for Ga
                 let idempotency_key = value.idempotency_key.map(Expr::try_from).transpose()?;
                 let response_proto = value.response.ok_or("Missing response field")?;
                 let response = Expr::try_from(response_proto)?;
+                let retry_policy = value
+                    .retry_policy
+                    .map(|policy| PublicRetryConfig::try_from(policy).map(RetryConfig::from))
+                    .transpose()?;
+                let invocation_timeout = value.timeout_millis.map(Duration::from_millis);
+                let response_cache = match (value.cache_key, value.cache_ttl_millis) {
+                    (Some(cache_key), Some(ttl_millis)) => Some(ResponseCache {
+                        cache_key: Expr::try_from(cache_key)?,
+                        ttl: Duration::from_millis(ttl_millis),
+                    }),
+                    _ => None,
+                };
 
                 Ok(GatewayBinding::Default(WorkerBinding {
                     component_id,
                     worker_name,
                     idempotency_key,
                     response_mapping: ResponseMapping(response),
+                    retry_policy,
+                    invocation_timeout,
+                    response_cache,
                 }))
             }
             golem_api_grpc::proto::golem::apidefinition::GatewayBindingType::FileServer => {
@@ -177,12 +225,27 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::GatewayBinding> for Ga
                 let idempotency_key = value.idempotency_key.map(Expr::try_from).transpose()?;
                 let response_proto = value.response.ok_or("Missing response field")?;
                 let response = Expr::try_from(response_proto)?;
+                let retry_policy = value
+                    .retry_policy
+                    .map(|policy| PublicRetryConfig::try_from(policy).map(RetryConfig::from))
+                    .transpose()?;
+                let invocation_timeout = value.timeout_millis.map(Duration::from_millis);
+                let response_cache = match (value.cache_key, value.cache_ttl_millis) {
+                    (Some(cache_key), Some(ttl_millis)) => Some(ResponseCache {
+                        cache_key: Expr::try_from(cache_key)?,
+                        ttl: Duration::from_millis(ttl_millis),
+                    }),
+                    _ => None,
+                };
 
                 Ok(GatewayBinding::FileServer(WorkerBinding {
                     component_id,
                     worker_name,
                     idempotency_key,
                     response_mapping: ResponseMapping(response),
+                    retry_policy,
+                    invocation_timeout,
+                    response_cache,
                 }))
             }
             golem_api_grpc::proto::golem::apidefinition::GatewayBindingType::CorsPreflight => {
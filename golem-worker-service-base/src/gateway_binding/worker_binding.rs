@@ -15,8 +15,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::gateway_binding::WorkerBindingCompiled;
+use golem_common::config::RetryConfig;
 use golem_service_base::model::VersionedComponentId;
 use rib::Expr;
+use std::time::Duration;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct WorkerBinding {
@@ -24,6 +26,19 @@ pub struct WorkerBinding {
     pub worker_name: Option<Expr>,
     pub idempotency_key: Option<Expr>,
     pub response_mapping: ResponseMapping,
+    // Retry policy applied to every invocation made through this binding
+    pub retry_policy: Option<RetryConfig>,
+    // Every invocation made through this binding is abandoned after this timeout
+    pub invocation_timeout: Option<Duration>,
+    pub response_cache: Option<ResponseCache>,
+}
+
+// Responses are cached under the key computed by a Rib expression from the request,
+// and are served from the cache without invoking the worker until the ttl elapses
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseCache {
+    pub cache_key: Expr,
+    pub ttl: Duration,
 }
 
 // ResponseMapping will consist of actual logic such as invoking worker functions
@@ -45,6 +60,14 @@ impl From<WorkerBindingCompiled> for WorkerBinding {
             response_mapping: ResponseMapping(
                 worker_binding.response_compiled.response_mapping_expr,
             ),
+            retry_policy: worker_binding.retry_policy,
+            invocation_timeout: worker_binding.invocation_timeout,
+            response_cache: worker_binding
+                .response_cache_compiled
+                .map(|compiled| ResponseCache {
+                    cache_key: compiled.cache_key,
+                    ttl: compiled.ttl,
+                }),
         }
     }
 }
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::gateway_binding::{ResponseMapping, WorkerBinding};
+use crate::gateway_binding::{ResponseCache, ResponseMapping, WorkerBinding};
 use crate::gateway_rib_compiler::{DefaultWorkerServiceRibCompiler, WorkerServiceRibCompiler};
+use golem_common::config::RetryConfig;
+use golem_common::model::InvocationOptions;
 use golem_service_base::model::VersionedComponentId;
 use golem_wasm_ast::analysis::AnalysedExport;
 use rib::{Expr, RibByteCode, RibInputTypeInfo, RibOutputTypeInfo, WorkerFunctionsInRib};
+use std::time::Duration;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct WorkerBindingCompiled {
@@ -24,6 +27,9 @@ pub struct WorkerBindingCompiled {
     pub worker_name_compiled: Option<WorkerNameCompiled>,
     pub idempotency_key_compiled: Option<IdempotencyKeyCompiled>,
     pub response_compiled: ResponseMappingCompiled,
+    pub retry_policy: Option<RetryConfig>,
+    pub invocation_timeout: Option<Duration>,
+    pub response_cache_compiled: Option<ResponseCacheCompiled>,
 }
 
 impl WorkerBindingCompiled {
@@ -51,13 +57,29 @@ impl WorkerBindingCompiled {
             export_metadata,
         )?;
 
+        let response_cache_compiled = gateway_worker_binding
+            .response_cache
+            .as_ref()
+            .map(|response_cache| {
+                ResponseCacheCompiled::from_response_cache(response_cache, export_metadata)
+            })
+            .transpose()?;
+
         Ok(WorkerBindingCompiled {
             component_id: gateway_worker_binding.component_id.clone(),
             worker_name_compiled,
             idempotency_key_compiled,
             response_compiled,
+            retry_policy: gateway_worker_binding.retry_policy.clone(),
+            invocation_timeout: gateway_worker_binding.invocation_timeout,
+            response_cache_compiled,
         })
     }
+
+    // The deadline is computed from the timeout at the time of the request
+    pub fn invocation_options(&self) -> InvocationOptions {
+        InvocationOptions::with_timeout(self.retry_policy.clone(), self.invocation_timeout)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -105,6 +127,31 @@ impl IdempotencyKeyCompiled {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseCacheCompiled {
+    pub cache_key: Expr,
+    pub compiled_cache_key: RibByteCode,
+    pub rib_input: RibInputTypeInfo,
+    pub ttl: Duration,
+}
+
+impl ResponseCacheCompiled {
+    pub fn from_response_cache(
+        response_cache: &ResponseCache,
+        exports: &[AnalysedExport],
+    ) -> Result<Self, String> {
+        let cache_key_compiled =
+            DefaultWorkerServiceRibCompiler::compile(&response_cache.cache_key, exports)?;
+
+        Ok(ResponseCacheCompiled {
+            cache_key: response_cache.cache_key.clone(),
+            compiled_cache_key: cache_key_compiled.byte_code,
+            rib_input: cache_key_compiled.rib_input_type_info,
+            ttl: response_cache.ttl,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ResponseMappingCompiled {
     pub response_mapping_expr: Expr,
@@ -15,12 +15,13 @@
 use crate::gateway_api_definition::http::{CompiledHttpApiDefinition, VarInfo};
 use crate::gateway_binding::{GatewayBindingCompiled, RibInputTypeMismatch, StaticBinding};
 use crate::gateway_binding::{GatewayRequestDetails, ResponseMappingCompiled};
+use crate::gateway_execution::gateway_response_cache::ResponseCacheEntryKey;
 use crate::gateway_execution::router::RouterPattern;
 use crate::gateway_execution::to_response_failure::ToHttpResponseFromSafeDisplay;
 use crate::gateway_request::http_request::{router, InputHttpRequest};
 use crate::gateway_security::OpenIdClient;
 use async_trait::async_trait;
-use golem_common::model::IdempotencyKey;
+use golem_common::model::{IdempotencyKey, InvocationOptions};
 use golem_common::SafeDisplay;
 use golem_service_base::model::VersionedComponentId;
 use http::StatusCode;
@@ -28,6 +29,7 @@ use openidconnect::{CsrfToken, Nonce};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::time::Duration;
 
 // Every type of request (example: InputHttpRequest (which corresponds to a Route)) can have an instance of this resolver,
 // which will resolve the gateway binding equired for that request.
@@ -98,6 +100,7 @@ pub struct WorkerDetail {
     pub component_id: VersionedComponentId,
     pub worker_name: Option<String>,
     pub idempotency_key: Option<IdempotencyKey>,
+    pub invocation_options: InvocationOptions,
 }
 
 impl WorkerDetail {
@@ -131,6 +134,13 @@ pub struct ResolvedWorkerBinding<Namespace> {
     pub worker_detail: WorkerDetail,
     pub compiled_response_mapping: ResponseMappingCompiled,
     pub namespace: Namespace,
+    pub response_cache: Option<ResolvedResponseCache>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedResponseCache {
+    pub entry_key: ResponseCacheEntryKey,
+    pub ttl: Duration,
 }
 
 impl<Namespace> ResolvedGatewayBinding<Namespace> {
@@ -171,13 +181,8 @@ impl<Namespace: Clone + Send + Sync + 'static>
         &self,
         compiled_api_definitions: Vec<CompiledHttpApiDefinition<Namespace>>,
     ) -> Result<ResolvedGatewayBinding<Namespace>, GatewayBindingResolverError> {
-        let compiled_routes = compiled_api_definitions
-            .iter()
-            .flat_map(|x| x.routes.iter().map(|y| (x.namespace.clone(), y.clone())))
-            .collect::<Vec<_>>();
-
         let api_request = self;
-        let router = router::build(compiled_routes);
+        let router = router::build(&compiled_api_definitions);
 
         let path: Vec<&str> = RouterPattern::split(&api_request.api_input_path.base_path).collect();
         let request_query_variables = self.api_input_path.query_components().unwrap_or_default();
@@ -190,6 +195,7 @@ impl<Namespace: Clone + Send + Sync + 'static>
             namespace,
             binding,
             middlewares,
+            route,
         } = router
             .check_path(&api_request.req_method, &path)
             .ok_or(GatewayBindingResolverError::RouteNotFound)?;
@@ -233,6 +239,8 @@ impl<Namespace: Clone + Send + Sync + 'static>
                 &http_request_details,
                 namespace,
                 headers,
+                &self.host,
+                route,
             )
             .await
             .map(|resolved_binding| ResolvedGatewayBinding {
@@ -244,6 +252,8 @@ impl<Namespace: Clone + Send + Sync + 'static>
                 &http_request_details,
                 namespace,
                 headers,
+                &self.host,
+                route,
             )
             .await
             .map(|resolved_binding| ResolvedGatewayBinding {
@@ -258,10 +268,12 @@ impl<Namespace: Clone + Send + Sync + 'static>
 }
 
 mod internal {
+    use crate::gateway_api_deployment::ApiSiteString;
     use crate::gateway_binding::{
-        GatewayBindingResolverError, GatewayRequestDetails, ResolvedWorkerBinding,
-        RibInputValueResolver, WorkerBindingCompiled, WorkerDetail,
+        GatewayBindingResolverError, GatewayRequestDetails, ResolvedResponseCache,
+        ResolvedWorkerBinding, RibInputValueResolver, WorkerBindingCompiled, WorkerDetail,
     };
+    use crate::gateway_execution::gateway_response_cache::{CacheKey, ResponseCacheEntryKey};
     use golem_common::model::IdempotencyKey;
     use http::HeaderMap;
 
@@ -270,6 +282,8 @@ mod internal {
         gateway_request_details: &GatewayRequestDetails,
         namespace: &Namespace,
         headers: &HeaderMap,
+        site: &ApiSiteString,
+        route: &str,
     ) -> Result<ResolvedWorkerBinding<Namespace>, GatewayBindingResolverError> {
         let GatewayRequestDetails::Http(http_request_details) = gateway_request_details;
 
@@ -330,16 +344,55 @@ mod internal {
                     .map(|value| IdempotencyKey::new(value.to_string()))
             };
 
+        let response_cache = if let Some(response_cache_compiled) = &binding.response_cache_compiled
+        {
+            let resolve_rib_input = http_request_details
+                .resolve_rib_input_value(&response_cache_compiled.rib_input)
+                .map_err(GatewayBindingResolverError::RibInputTypeMismatch)?;
+
+            let cache_key_value = rib::interpret_pure(
+                &response_cache_compiled.compiled_cache_key,
+                &resolve_rib_input,
+            )
+            .await
+            .map_err(|err| {
+                GatewayBindingResolverError::Internal(format!(
+                    "Failed to evaluate cache key rib expression. {}",
+                    err
+                ))
+            })?;
+
+            let cache_key = cache_key_value
+                .get_literal()
+                .ok_or(GatewayBindingResolverError::internal(
+                    "Cache key is not a Rib expression that resolves to a literal",
+                ))?
+                .as_string();
+
+            Some(ResolvedResponseCache {
+                entry_key: ResponseCacheEntryKey {
+                    site: site.clone(),
+                    route: route.to_string(),
+                    cache_key: CacheKey(cache_key),
+                },
+                ttl: response_cache_compiled.ttl,
+            })
+        } else {
+            None
+        };
+
         let worker_detail = WorkerDetail {
             component_id: component_id.clone(),
             worker_name: worker_name_opt,
             idempotency_key,
+            invocation_options: binding.invocation_options(),
         };
 
         let resolved_binding = ResolvedWorkerBinding {
             worker_detail,
             compiled_response_mapping: binding.response_compiled.clone(),
             namespace: namespace.clone(),
+            response_cache,
         };
 
         Ok(resolved_binding)
@@ -13,8 +13,8 @@
 // limitations under the License.
 
 use crate::gateway_binding::{
-    HttpRequestDetails, ResolvedBinding, ResolvedWorkerBinding, RibInputTypeMismatch,
-    RibInputValueResolver, StaticBinding,
+    HttpRequestDetails, ResolvedBinding, ResolvedResponseCache, ResolvedWorkerBinding,
+    RibInputTypeMismatch, RibInputValueResolver, StaticBinding,
 };
 use crate::gateway_execution::auth_call_back_binding_handler::{
     AuthCallBackBindingHandler, AuthCallBackResult,
@@ -22,8 +22,11 @@ use crate::gateway_execution::auth_call_back_binding_handler::{
 use crate::gateway_execution::file_server_binding_handler::{
     FileServerBindingHandler, FileServerBindingResult,
 };
+use crate::gateway_execution::gateway_response_cache::GatewayResponseCacheStore;
 use crate::gateway_execution::gateway_session::{GatewaySession, GatewaySessionStore, SessionId};
-use crate::gateway_execution::to_response::ToHttpResponse;
+use crate::gateway_execution::to_response::{
+    is_cacheable_response, to_cached_response, ToHttpResponse,
+};
 use crate::gateway_execution::to_response_failure::ToHttpResponseFromSafeDisplay;
 use crate::gateway_middleware::{
     HttpCors as CorsPreflight, HttpMiddlewares, MiddlewareError, MiddlewareSuccess,
@@ -31,9 +34,12 @@ use crate::gateway_middleware::{
 use crate::gateway_rib_interpreter::{EvaluationError, WorkerServiceRibInterpreter};
 use crate::gateway_security::{IdentityProvider, SecuritySchemeWithProviderMetadata};
 use async_trait::async_trait;
+use golem_common::metrics::caching::{record_cache_hit, record_cache_miss};
+use golem_common::SafeDisplay;
 use http::StatusCode;
 use rib::{RibInput, RibResult};
 use std::sync::Arc;
+use tracing::warn;
 
 // Response is type parameterised here, mainly to support
 // other protocols.
@@ -83,18 +89,23 @@ pub struct DefaultGatewayInputExecutor<Namespace> {
     pub evaluator: Arc<dyn WorkerServiceRibInterpreter<Namespace> + Sync + Send>,
     pub file_server_binding_handler: Arc<dyn FileServerBindingHandler<Namespace> + Sync + Send>,
     pub auth_call_back_binding_handler: Arc<dyn AuthCallBackBindingHandler + Sync + Send>,
+    pub response_cache: GatewayResponseCacheStore,
 }
 
+const RESPONSE_CACHE_METRICS_NAME: &str = "gateway_response_cache";
+
 impl<Namespace: Clone> DefaultGatewayInputExecutor<Namespace> {
     pub fn new(
         evaluator: Arc<dyn WorkerServiceRibInterpreter<Namespace> + Sync + Send>,
         file_server_binding_handler: Arc<dyn FileServerBindingHandler<Namespace> + Sync + Send>,
         auth_call_back_binding_handler: Arc<dyn AuthCallBackBindingHandler + Sync + Send>,
+        response_cache: GatewayResponseCacheStore,
     ) -> Self {
         Self {
             evaluator,
             file_server_binding_handler,
             auth_call_back_binding_handler,
+            response_cache,
         }
     }
 
@@ -150,6 +161,7 @@ impl<Namespace: Clone> DefaultGatewayInputExecutor<Namespace> {
                     .component_id
                     .component_id,
                 &resolved_worker_binding.worker_detail.idempotency_key,
+                &resolved_worker_binding.worker_detail.invocation_options,
                 &resolved_worker_binding
                     .compiled_response_mapping
                     .response_mapping_compiled,
@@ -166,6 +178,103 @@ impl<Namespace: Clone> DefaultGatewayInputExecutor<Namespace> {
         request_details: &mut HttpRequestDetails,
         resolved_binding: &ResolvedWorkerBinding<Namespace>,
     ) -> poem::Response
+    where
+        RibResult: ToHttpResponse,
+        EvaluationError: ToHttpResponseFromSafeDisplay,
+        RibInputTypeMismatch: ToHttpResponseFromSafeDisplay,
+    {
+        match &resolved_binding.response_cache {
+            Some(response_cache) => {
+                self.handle_cached_worker_binding(
+                    session_id,
+                    session_store,
+                    request_details,
+                    resolved_binding,
+                    response_cache,
+                )
+                .await
+            }
+            None => {
+                self.evaluate_worker_binding(
+                    session_id,
+                    session_store,
+                    request_details,
+                    resolved_binding,
+                )
+                .await
+            }
+        }
+    }
+
+    // Serves the response from the cache if present, otherwise the successful response
+    // of the worker binding gets cached. Failing to access the cache doesn't fail the request.
+    async fn handle_cached_worker_binding(
+        &self,
+        session_id: Option<SessionId>,
+        session_store: &GatewaySessionStore,
+        request_details: &mut HttpRequestDetails,
+        resolved_binding: &ResolvedWorkerBinding<Namespace>,
+        response_cache: &ResolvedResponseCache,
+    ) -> poem::Response
+    where
+        RibResult: ToHttpResponse,
+        EvaluationError: ToHttpResponseFromSafeDisplay,
+        RibInputTypeMismatch: ToHttpResponseFromSafeDisplay,
+    {
+        // Cache-Control: no-cache in the request forces a refresh of the cached response
+        if !request_details.has_no_cache_directive() {
+            match self.response_cache.get(&response_cache.entry_key).await {
+                Ok(Some(cached_response)) => {
+                    record_cache_hit(RESPONSE_CACHE_METRICS_NAME);
+                    return cached_response
+                        .to_response(request_details, session_store)
+                        .await;
+                }
+                Ok(None) => {}
+                Err(err) => warn!(
+                    "Failed to get cached gateway response: {}",
+                    err.to_safe_string()
+                ),
+            }
+        }
+
+        record_cache_miss(RESPONSE_CACHE_METRICS_NAME);
+
+        let response = self
+            .evaluate_worker_binding(session_id, session_store, request_details, resolved_binding)
+            .await;
+
+        if !is_cacheable_response(&response) {
+            return response;
+        }
+
+        match to_cached_response(response, response_cache.ttl).await {
+            Ok(cached_response) => {
+                if let Err(err) = self
+                    .response_cache
+                    .insert(response_cache.entry_key.clone(), cached_response.clone())
+                    .await
+                {
+                    warn!("Failed to cache gateway response: {}", err.to_safe_string());
+                }
+
+                cached_response
+                    .to_response(request_details, session_store)
+                    .await
+            }
+            Err(err) => poem::Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(format!("Failed to read response body: {}", err)),
+        }
+    }
+
+    async fn evaluate_worker_binding(
+        &self,
+        session_id: Option<SessionId>,
+        session_store: &GatewaySessionStore,
+        request_details: &mut HttpRequestDetails,
+        resolved_binding: &ResolvedWorkerBinding<Namespace>,
+    ) -> poem::Response
     where
         RibResult: ToHttpResponse,
         EvaluationError: ToHttpResponseFromSafeDisplay,
@@ -0,0 +1,396 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::gateway_api_deployment::ApiSiteString;
+use async_trait::async_trait;
+use bincode::{Decode, Encode};
+use bytes::Bytes;
+use fred::types::RedisValue;
+use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode, SimpleCache};
+use golem_common::redis::RedisPool;
+use golem_common::SafeDisplay;
+use rustc_hash::FxHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Storage of the responses of the routes having a cache configured.
+// Entries are scoped to the site and the route which produced them,
+// and looked up by the cache key computed from the request.
+#[async_trait]
+pub trait GatewayResponseCache {
+    async fn get(
+        &self,
+        entry_key: &ResponseCacheEntryKey,
+    ) -> Result<Option<CachedResponse>, GatewayResponseCacheError>;
+
+    async fn insert(
+        &self,
+        entry_key: ResponseCacheEntryKey,
+        response: CachedResponse,
+    ) -> Result<(), GatewayResponseCacheError>;
+
+    // Removes every cached response of the site, or only the ones stored under the given cache key
+    async fn invalidate(
+        &self,
+        site: &ApiSiteString,
+        cache_key: Option<&CacheKey>,
+    ) -> Result<(), GatewayResponseCacheError>;
+}
+
+#[derive(Debug, Clone)]
+pub enum GatewayResponseCacheError {
+    InternalError(String),
+}
+
+impl SafeDisplay for GatewayResponseCacheError {
+    fn to_safe_string(&self) -> String {
+        match self {
+            GatewayResponseCacheError::InternalError(e) => format!("Internal error: {}", e),
+        }
+    }
+}
+
+pub type GatewayResponseCacheStore = Arc<dyn GatewayResponseCache + Send + Sync>;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct CacheKey(pub String);
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct ResponseCacheEntryKey {
+    pub site: ApiSiteString,
+    pub route: String,
+    pub cache_key: CacheKey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct CachedResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    pub etag: String,
+    // Milliseconds since the unix epoch
+    pub expires_at: u64,
+}
+
+impl CachedResponse {
+    pub fn new(status: u16, headers: Vec<(String, String)>, body: Vec<u8>, ttl: Duration) -> Self {
+        let mut hasher = FxHasher::default();
+        status.hash(&mut hasher);
+        body.hash(&mut hasher);
+        let etag = format!("\"{:x}-{:016x}\"", body.len(), hasher.finish());
+
+        CachedResponse {
+            status,
+            headers,
+            body,
+            etag,
+            expires_at: now_millis() + ttl.as_millis() as u64,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= now_millis()
+    }
+
+    pub fn remaining_ttl(&self) -> Duration {
+        Duration::from_millis(self.expires_at.saturating_sub(now_millis()))
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[derive(Clone)]
+pub struct InMemoryGatewayResponseCache {
+    cache: Cache<ResponseCacheEntryKey, (), CachedResponse, GatewayResponseCacheError>,
+}
+
+impl InMemoryGatewayResponseCache {
+    pub fn new(capacity: usize) -> Self {
+        let cache = Cache::new(
+            Some(capacity),
+            FullCacheEvictionMode::LeastRecentlyUsed(1),
+            BackgroundEvictionMode::None,
+            "gateway_response_cache_in_memory",
+        );
+
+        Self { cache }
+    }
+}
+
+#[async_trait]
+impl GatewayResponseCache for InMemoryGatewayResponseCache {
+    async fn get(
+        &self,
+        entry_key: &ResponseCacheEntryKey,
+    ) -> Result<Option<CachedResponse>, GatewayResponseCacheError> {
+        match self.cache.get(entry_key).await {
+            Some(response) if response.is_expired() => {
+                self.cache.remove(entry_key);
+                Ok(None)
+            }
+            result => Ok(result),
+        }
+    }
+
+    async fn insert(
+        &self,
+        entry_key: ResponseCacheEntryKey,
+        response: CachedResponse,
+    ) -> Result<(), GatewayResponseCacheError> {
+        self.cache.remove(&entry_key);
+        self.cache
+            .get_or_insert_simple(&entry_key, || Box::pin(async move { Ok(response) }))
+            .await?;
+        Ok(())
+    }
+
+    async fn invalidate(
+        &self,
+        site: &ApiSiteString,
+        cache_key: Option<&CacheKey>,
+    ) -> Result<(), GatewayResponseCacheError> {
+        let invalidated = self
+            .cache
+            .iter()
+            .map(|(entry_key, _)| entry_key)
+            .filter(|entry_key| {
+                &entry_key.site == site
+                    && cache_key.map_or(true, |cache_key| &entry_key.cache_key == cache_key)
+            })
+            .collect::<Vec<_>>();
+
+        for entry_key in invalidated {
+            self.cache.remove(&entry_key);
+        }
+
+        Ok(())
+    }
+}
+
+// Responses stored under the same cache key are kept in a single hash with a field per route.
+// The site is enclosed in braces in the key, so the keys of a site can be matched by a pattern
+// without ambiguity, as neither hosts nor ports can contain braces.
+#[derive(Clone)]
+pub struct RedisGatewayResponseCache {
+    redis: RedisPool,
+}
+
+impl RedisGatewayResponseCache {
+    const SCAN_COUNT: u64 = 100;
+
+    // PTTL returns a negative value for hashes without an expiry, so new hashes always get one
+    const INSERT_SCRIPT: &'static str = r#"
+        redis.call('HSET', KEYS[1], ARGV[1], ARGV[2])
+        if redis.call('PTTL', KEYS[1]) < tonumber(ARGV[3]) then
+            redis.call('PEXPIRE', KEYS[1], ARGV[3])
+        end
+    "#;
+
+    pub fn new(redis: RedisPool) -> Self {
+        Self { redis }
+    }
+
+    pub fn redis_key(site: &ApiSiteString, cache_key: &CacheKey) -> String {
+        format!("gateway_response_cache:{{{}}}:{}", site.0, cache_key.0)
+    }
+
+    pub fn redis_key_pattern_of_site(site: &ApiSiteString) -> String {
+        format!("gateway_response_cache:{{{}}}:*", site.0)
+    }
+}
+
+#[async_trait]
+impl GatewayResponseCache for RedisGatewayResponseCache {
+    async fn get(
+        &self,
+        entry_key: &ResponseCacheEntryKey,
+    ) -> Result<Option<CachedResponse>, GatewayResponseCacheError> {
+        let result: Option<Bytes> = self
+            .redis
+            .with("gateway_response_cache", "get")
+            .hget(
+                Self::redis_key(&entry_key.site, &entry_key.cache_key),
+                entry_key.route.as_str(),
+            )
+            .await
+            .map_err(|e| GatewayResponseCacheError::InternalError(e.to_string()))?;
+
+        match result {
+            Some(bytes) => {
+                let response: CachedResponse = golem_common::serialization::deserialize(&bytes)
+                    .map_err(|e| GatewayResponseCacheError::InternalError(e.to_string()))?;
+
+                if response.is_expired() {
+                    Ok(None)
+                } else {
+                    Ok(Some(response))
+                }
+            }
+            None => Ok(None),
+        }
+    }
+
+    // The response and the expiry of its hash are written atomically, so a failure can't leave
+    // a hash behind which never expires. The routes sharing the hash can have different TTLs, so
+    // the expiry of the hash is only ever extended, to the expiry of its longest living response.
+    // Expired responses left in the hash are never served, as their own expiry is checked by `get`.
+    async fn insert(
+        &self,
+        entry_key: ResponseCacheEntryKey,
+        response: CachedResponse,
+    ) -> Result<(), GatewayResponseCacheError> {
+        let redis_key = Self::redis_key(&entry_key.site, &entry_key.cache_key);
+        let ttl_millis = response.remaining_ttl().as_millis().max(1) as i64;
+
+        let serialised = golem_common::serialization::serialize(&response)
+            .map_err(|e| GatewayResponseCacheError::InternalError(e.to_string()))?;
+
+        let _: () = self
+            .redis
+            .with("gateway_response_cache", "insert")
+            .eval(
+                Self::INSERT_SCRIPT,
+                vec![redis_key],
+                vec![
+                    RedisValue::from(entry_key.route),
+                    RedisValue::from(serialised),
+                    RedisValue::from(ttl_millis),
+                ],
+            )
+            .await
+            .map_err(|e| GatewayResponseCacheError::InternalError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn invalidate(
+        &self,
+        site: &ApiSiteString,
+        cache_key: Option<&CacheKey>,
+    ) -> Result<(), GatewayResponseCacheError> {
+        match cache_key {
+            Some(cache_key) => {
+                let _: () = self
+                    .redis
+                    .with("gateway_response_cache", "invalidate")
+                    .del(Self::redis_key(site, cache_key))
+                    .await
+                    .map_err(|e| GatewayResponseCacheError::InternalError(e.to_string()))?;
+            }
+            None => {
+                let pattern = Self::redis_key_pattern_of_site(site);
+                let mut cursor = 0;
+
+                loop {
+                    let (next_cursor, redis_keys) = self
+                        .redis
+                        .with("gateway_response_cache", "invalidate")
+                        .scan(pattern.as_str(), cursor, Self::SCAN_COUNT)
+                        .await
+                        .map_err(|e| GatewayResponseCacheError::InternalError(e.to_string()))?;
+
+                    if !redis_keys.is_empty() {
+                        let _: () = self
+                            .redis
+                            .with("gateway_response_cache", "invalidate")
+                            .del_many(redis_keys)
+                            .await
+                            .map_err(|e| GatewayResponseCacheError::InternalError(e.to_string()))?;
+                    }
+
+                    if next_cursor == 0 {
+                        break;
+                    }
+                    cursor = next_cursor;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_r::test;
+
+    fn entry_key(site: &str, route: &str, cache_key: &str) -> ResponseCacheEntryKey {
+        ResponseCacheEntryKey {
+            site: ApiSiteString(site.to_string()),
+            route: route.to_string(),
+            cache_key: CacheKey(cache_key.to_string()),
+        }
+    }
+
+    fn response(body: &str, ttl: Duration) -> CachedResponse {
+        CachedResponse::new(200, vec![], body.as_bytes().to_vec(), ttl)
+    }
+
+    #[test]
+    async fn test_in_memory_cache_get_and_invalidate() {
+        let cache = InMemoryGatewayResponseCache::new(16);
+
+        let key1 = entry_key("test.com", "Get /users/{id}", "1");
+        let key2 = entry_key("test.com", "Get /users/{id}", "2");
+        let key3 = entry_key("other.com", "Get /users/{id}", "1");
+
+        for key in [&key1, &key2, &key3] {
+            cache
+                .insert(key.clone(), response("user", Duration::from_secs(60)))
+                .await
+                .unwrap();
+        }
+
+        assert!(cache.get(&key1).await.unwrap().is_some());
+
+        cache
+            .invalidate(&key1.site, Some(&key1.cache_key))
+            .await
+            .unwrap();
+        assert!(cache.get(&key1).await.unwrap().is_none());
+        assert!(cache.get(&key2).await.unwrap().is_some());
+
+        cache.invalidate(&key2.site, None).await.unwrap();
+        assert!(cache.get(&key2).await.unwrap().is_none());
+        assert!(cache.get(&key3).await.unwrap().is_some());
+    }
+
+    #[test]
+    async fn test_in_memory_cache_expiry() {
+        let cache = InMemoryGatewayResponseCache::new(16);
+        let key = entry_key("test.com", "Get /users/{id}", "1");
+
+        cache
+            .insert(key.clone(), response("user", Duration::ZERO))
+            .await
+            .unwrap();
+
+        assert!(cache.get(&key).await.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_cached_response_etag_depends_on_body() {
+        let ttl = Duration::from_secs(60);
+
+        assert_eq!(response("a", ttl).etag, response("a", ttl).etag);
+        assert_ne!(response("a", ttl).etag, response("b", ttl).etag);
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use golem_common::model::{ComponentId, IdempotencyKey};
+use golem_common::model::{ComponentId, IdempotencyKey, InvocationOptions};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 
 pub mod api_definition_lookup;
@@ -20,6 +20,7 @@ pub mod auth_call_back_binding_handler;
 pub mod file_server_binding_handler;
 pub mod gateway_binding_resolver;
 pub mod gateway_http_input_executor;
+pub mod gateway_response_cache;
 pub mod gateway_session;
 mod gateway_worker_request_executor;
 mod http_content_type_mapper;
@@ -37,5 +38,6 @@ pub struct GatewayResolvedWorkerRequest<Namespace> {
     pub function_name: String,
     pub function_params: Vec<TypeAnnotatedValue>,
     pub idempotency_key: Option<IdempotencyKey>,
+    pub invocation_options: InvocationOptions,
     pub namespace: Namespace,
 }
@@ -18,15 +18,18 @@ use crate::gateway_execution::auth_call_back_binding_handler::AuthCallBackResult
 use crate::gateway_execution::file_server_binding_handler::{
     FileServerBindingError, FileServerBindingResult,
 };
+use crate::gateway_execution::gateway_response_cache::CachedResponse;
 use crate::gateway_execution::gateway_session::GatewaySessionStore;
 use crate::gateway_execution::to_response_failure::ToHttpResponseFromSafeDisplay;
 use crate::gateway_middleware::HttpCors as CorsPreflight;
 use async_trait::async_trait;
 use http::header::*;
 use http::StatusCode;
+use poem::error::ReadBodyError;
 use poem::Body;
 use poem::IntoResponse;
 use rib::RibResult;
+use std::time::Duration;
 
 #[async_trait]
 pub trait ToHttpResponse {
@@ -128,6 +131,93 @@ impl ToHttpResponse for RibResult {
     }
 }
 
+// A response served from the gateway response cache. Clients revalidating with a matching
+// If-None-Match get a 304 without the body.
+#[async_trait]
+impl ToHttpResponse for CachedResponse {
+    async fn to_response(
+        self,
+        request_details: &HttpRequestDetails,
+        _session_store: &GatewaySessionStore,
+    ) -> poem::Response {
+        let not_modified = request_details
+            .get_if_none_match_header()
+            .map(|if_none_match| {
+                if_none_match.split(',').any(|tag| {
+                    let tag = tag.trim();
+                    tag == "*" || tag.trim_start_matches("W/") == self.etag
+                })
+            })
+            .unwrap_or(false);
+
+        let mut response = if not_modified {
+            poem::Response::builder()
+                .status(StatusCode::NOT_MODIFIED)
+                .finish()
+        } else {
+            let mut builder = poem::Response::builder()
+                .status(StatusCode::from_u16(self.status).unwrap_or(StatusCode::OK));
+            for (name, value) in &self.headers {
+                builder = builder.header(name.as_str(), value.as_str());
+            }
+            builder.body(self.body)
+        };
+
+        response
+            .headers_mut()
+            .insert(ETAG, self.etag.parse().unwrap());
+        response.headers_mut().insert(
+            CACHE_CONTROL,
+            format!("max-age={}", self.remaining_ttl().as_secs())
+                .parse()
+                .unwrap(),
+        );
+
+        response
+    }
+}
+
+// Only successful responses get stored in the gateway response cache. Responses setting cookies
+// never do, as the cookies belong to the client the response was created for.
+pub fn is_cacheable_response(response: &poem::Response) -> bool {
+    response.status().is_success() && !response.headers().contains_key(SET_COOKIE)
+}
+
+// Buffers the response so that it can be stored in the gateway response cache. Client specific
+// headers are not stored, even if the response was not checked with `is_cacheable_response`.
+pub async fn to_cached_response(
+    response: poem::Response,
+    ttl: Duration,
+) -> Result<CachedResponse, ReadBodyError> {
+    let (parts, body) = response.into_parts();
+
+    let headers = parts
+        .headers
+        .iter()
+        .filter(|(name, _)| {
+            *name != ETAG
+                && *name != CACHE_CONTROL
+                && *name != CONTENT_LENGTH
+                && *name != SET_COOKIE
+        })
+        .filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|value| (name.to_string(), value.to_string()))
+        })
+        .collect();
+
+    let body = body.into_vec().await?;
+
+    Ok(CachedResponse::new(
+        parts.status.as_u16(),
+        headers,
+        body,
+        ttl,
+    ))
+}
+
 #[async_trait]
 impl ToHttpResponse for AuthCallBackResult {
     async fn to_response(
@@ -292,10 +382,13 @@ mod test {
     use crate::gateway_execution::gateway_session::{
         DataKey, DataValue, GatewaySession, GatewaySessionError, SessionId,
     };
-    use crate::gateway_execution::to_response::ToHttpResponse;
-    use http::header::CONTENT_TYPE;
+    use crate::gateway_execution::to_response::{
+        is_cacheable_response, to_cached_response, ToHttpResponse,
+    };
+    use http::header::{CONTENT_TYPE, SET_COOKIE};
     use http::StatusCode;
     use rib::RibResult;
+    use std::time::Duration;
 
     fn create_record(values: Vec<(String, TypeAnnotatedValue)>) -> TypeAnnotatedValue {
         let mut name_type_pairs = vec![];
@@ -395,6 +488,49 @@ mod test {
         assert_eq!(status, expected_status);
     }
 
+    #[test]
+    async fn test_responses_setting_cookies_are_not_cacheable() {
+        let plain = poem::Response::builder()
+            .status(StatusCode::OK)
+            .header(CONTENT_TYPE, "application/json")
+            .body("{}");
+        let with_cookie = poem::Response::builder()
+            .status(StatusCode::OK)
+            .header(SET_COOKIE, "session=secret; HttpOnly")
+            .body("{}");
+        let failed = poem::Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .body("error");
+
+        assert!(is_cacheable_response(&plain));
+        assert!(!is_cacheable_response(&with_cookie));
+        assert!(!is_cacheable_response(&failed));
+    }
+
+    #[test]
+    async fn test_cached_response_does_not_store_cookies() {
+        let response = poem::Response::builder()
+            .status(StatusCode::OK)
+            .header(CONTENT_TYPE, "application/json")
+            .header(SET_COOKIE, "session=secret; HttpOnly")
+            .body("{}");
+
+        let cached = to_cached_response(response, Duration::from_secs(60))
+            .await
+            .unwrap();
+
+        let session_store: Arc<dyn GatewaySession + Send + Sync> = Arc::new(TestSessionStore);
+        let http_response = cached
+            .to_response(&HttpRequestDetails::empty(), &session_store)
+            .await;
+
+        assert_eq!(
+            http_response.headers().get(CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+        assert!(http_response.headers().get(SET_COOKIE).is_none());
+    }
+
     struct TestSessionStore;
 
     #[async_trait]
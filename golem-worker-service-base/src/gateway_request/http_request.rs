@@ -134,7 +134,7 @@ impl ApiInputPath {
 }
 
 pub mod router {
-    use crate::gateway_api_definition::http::CompiledRoute;
+    use crate::gateway_api_definition::http::CompiledHttpApiDefinition;
     use crate::gateway_api_definition::http::{PathPattern, QueryInfo, VarInfo};
     use crate::gateway_binding::GatewayBindingCompiled;
     use crate::gateway_execution::router::{Router, RouterPattern};
@@ -154,50 +154,64 @@ pub mod router {
         pub namespace: Namespace,
         pub binding: GatewayBindingCompiled,
         pub middlewares: Option<HttpMiddlewares>,
+        // Identifies the route and its API definition, used to scope the cached responses
+        pub route: String,
     }
 
-    pub fn build<Namespace>(
-        routes: Vec<(Namespace, CompiledRoute)>,
+    pub fn build<Namespace: Clone>(
+        definitions: &[CompiledHttpApiDefinition<Namespace>],
     ) -> Router<RouteEntry<Namespace>> {
         let mut router = Router::new();
 
-        for (namespace, route) in routes {
-            let method = route.method.into();
-            let path = route.path;
-            let binding = route.binding;
-
-            let path_params = path
-                .path_patterns
-                .iter()
-                .enumerate()
-                .filter_map(|(i, x)| match x {
-                    PathPattern::Var(var_info) => Some(PathParamExtractor::Single {
-                        var_info: var_info.clone(),
-                        index: i,
-                    }),
-                    PathPattern::CatchAllVar(var_info) => Some(PathParamExtractor::AllFollowing {
-                        var_info: var_info.clone(),
-                        index: i,
-                    }),
-                    _ => None,
-                })
-                .collect();
-
-            let entry = RouteEntry {
-                path_params,
-                query_params: path.query_params,
-                namespace,
-                binding,
-                middlewares: route.middlewares,
-            };
-
-            let path: Vec<RouterPattern> = path
-                .path_patterns
-                .iter()
-                .map(|x| x.clone().into())
-                .collect();
-
-            router.add_route(method, path, entry);
+        for definition in definitions {
+            for route in definition.routes.iter().cloned() {
+                // Responses cached for a route are scoped to the version of the API definition it
+                // belongs to, so they are not served for the routes of another deployed version
+                let route_id = format!(
+                    "{}@{} {} {}",
+                    definition.id, definition.version, route.method, route.path
+                );
+                let namespace = definition.namespace.clone();
+                let method = route.method.into();
+                let path = route.path;
+                let binding = route.binding;
+
+                let path_params = path
+                    .path_patterns
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, x)| match x {
+                        PathPattern::Var(var_info) => Some(PathParamExtractor::Single {
+                            var_info: var_info.clone(),
+                            index: i,
+                        }),
+                        PathPattern::CatchAllVar(var_info) => {
+                            Some(PathParamExtractor::AllFollowing {
+                                var_info: var_info.clone(),
+                                index: i,
+                            })
+                        }
+                        _ => None,
+                    })
+                    .collect();
+
+                let entry = RouteEntry {
+                    path_params,
+                    query_params: path.query_params,
+                    namespace,
+                    binding,
+                    middlewares: route.middlewares,
+                    route: route_id,
+                };
+
+                let path: Vec<RouterPattern> = path
+                    .path_patterns
+                    .iter()
+                    .map(|x| x.clone().into())
+                    .collect();
+
+                router.add_route(method, path, entry);
+            }
         }
 
         router
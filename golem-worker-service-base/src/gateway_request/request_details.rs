@@ -248,6 +248,26 @@ impl HttpRequestDetails {
             .and_then(|field| field.value.as_str().map(|x| x.to_string()))
     }
 
+    pub fn get_if_none_match_header(&self) -> Option<String> {
+        self.request_headers
+            .0
+            .fields
+            .iter()
+            .find(|field| field.name == http::header::IF_NONE_MATCH.to_string())
+            .and_then(|field| field.value.as_str().map(|x| x.to_string()))
+    }
+
+    pub fn has_no_cache_directive(&self) -> bool {
+        self.request_headers
+            .0
+            .fields
+            .iter()
+            .filter(|field| field.name == http::header::CACHE_CONTROL.to_string())
+            .filter_map(|field| field.value.as_str())
+            .flat_map(|value| value.split(','))
+            .any(|directive| directive.trim().eq_ignore_ascii_case("no-cache"))
+    }
+
     pub fn from_input_http_request(
         scheme: &Option<Scheme>,
         host: &ApiSiteString,
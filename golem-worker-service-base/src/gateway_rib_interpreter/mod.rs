@@ -19,7 +19,7 @@ use std::sync::Arc;
 
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 
-use golem_common::model::{ComponentId, IdempotencyKey};
+use golem_common::model::{ComponentId, IdempotencyKey, InvocationOptions};
 use golem_common::SafeDisplay;
 use rib::{RibByteCode, RibFunctionInvoke, RibInput, RibResult};
 
@@ -36,6 +36,7 @@ pub trait WorkerServiceRibInterpreter<Namespace> {
         worker_name: Option<&str>,
         component_id: &ComponentId,
         idempotency_key: &Option<IdempotencyKey>,
+        invocation_options: &InvocationOptions,
         rib_byte_code: &RibByteCode,
         rib_input: &RibInput,
         namespace: Namespace,
@@ -86,6 +87,7 @@ impl<Namespace: Clone + Send + Sync + 'static> WorkerServiceRibInterpreter<Names
         worker_name: Option<&str>,
         component_id: &ComponentId,
         idempotency_key: &Option<IdempotencyKey>,
+        invocation_options: &InvocationOptions,
         expr: &RibByteCode,
         rib_input: &RibInput,
         namespace: Namespace,
@@ -95,12 +97,14 @@ impl<Namespace: Clone + Send + Sync + 'static> WorkerServiceRibInterpreter<Names
         let worker_invoke_function: RibFunctionInvoke = Arc::new({
             let component_id = component_id.clone();
             let idempotency_key = idempotency_key.clone();
+            let invocation_options = invocation_options.clone();
             let worker_name = worker_name.map(|s| s.to_string()).clone();
 
             move |function_name: String, parameters: Vec<TypeAnnotatedValue>| {
                 let component_id = component_id.clone();
                 let worker_name = worker_name.clone();
                 let idempotency_key = idempotency_key.clone();
+                let invocation_options = invocation_options.clone();
                 let executor = executor.clone();
                 let namespace = namespace.clone();
 
@@ -111,6 +115,7 @@ impl<Namespace: Clone + Send + Sync + 'static> WorkerServiceRibInterpreter<Names
                         function_name,
                         function_params: parameters,
                         idempotency_key,
+                        invocation_options,
                         namespace,
                     };
 
@@ -195,6 +195,9 @@ pub fn error_to_status(error: WorkerError) -> Status {
                 worker_execution_error::Error::FileSystemError(_) => {
                     "Failed accessing worker filesystem".to_string()
                 }
+                worker_execution_error::Error::InvocationTimedOut(_) => {
+                    "Invocation Timed Out".to_string()
+                }
             };
             Status::internal(message)
         }
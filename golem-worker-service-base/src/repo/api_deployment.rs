@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::gateway_api_deployment::ApiSite;
+use crate::gateway_api_deployment::{ApiDeploymentChange, ApiDeploymentRevision, ApiSite};
 use crate::repo::api_definition::ApiDefinitionRecord;
 use crate::service::gateway::api_definition::ApiDefinitionIdWithVersion;
 use async_trait::async_trait;
@@ -54,6 +54,92 @@ impl ApiDeploymentRecord {
     }
 }
 
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct ApiDeploymentRevisionRecord {
+    pub site: String,
+    pub revision: i64,
+    pub namespace: String,
+    pub host: String,
+    pub subdomain: Option<String>,
+    pub change: String,
+    pub source_revision: Option<i64>,
+    pub definitions: String,
+    pub actor: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+// A change of the API definitions deployed to a site. The repo applies it and records the
+// resulting revision in a single transaction.
+#[derive(Debug, Clone)]
+pub struct ApiDeploymentChangeRecord {
+    pub namespace: String,
+    pub site: String,
+    pub host: String,
+    pub subdomain: Option<String>,
+    pub change: String,
+    pub source_revision: Option<i64>,
+    pub actor: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub deleted: Vec<ApiDeploymentRecord>,
+    pub created: Vec<ApiDeploymentRecord>,
+    // API definitions restored to their content at an earlier revision
+    pub restored: Vec<ApiDefinitionRecord>,
+}
+
+impl ApiDeploymentChangeRecord {
+    pub fn new<Namespace: Display>(
+        namespace: Namespace,
+        site: ApiSite,
+        change: ApiDeploymentChange,
+        source_revision: Option<u64>,
+        actor: String,
+        created_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            site: site.to_string(),
+            host: site.host,
+            subdomain: site.subdomain,
+            change: change.to_string(),
+            source_revision: source_revision.map(|r| r as i64),
+            actor,
+            created_at,
+            deleted: vec![],
+            created: vec![],
+            restored: vec![],
+        }
+    }
+}
+
+impl<Namespace> TryFrom<ApiDeploymentRevisionRecord> for ApiDeploymentRevision<Namespace>
+where
+    Namespace: TryFrom<String>,
+    <Namespace as TryFrom<String>>::Error: Display,
+{
+    type Error = String;
+    fn try_from(value: ApiDeploymentRevisionRecord) -> Result<Self, Self::Error> {
+        let api_definition_keys = serde_json::from_str(&value.definitions)
+            .map_err(|e| format!("Failed to deserialize API definition keys: {e}"))?;
+
+        let namespace = Namespace::try_from(value.namespace)
+            .map_err(|e| format!("Failed to convert namespace: {e}"))?;
+
+        Ok(Self {
+            namespace,
+            site: ApiSite {
+                host: value.host,
+                subdomain: value.subdomain,
+            },
+            revision: value.revision as u64,
+            api_definition_keys,
+            change: value.change.parse()?,
+            source_revision: value.source_revision.map(|r| r as u64),
+            actor: value.actor,
+            created_at: value.created_at,
+        })
+    }
+}
+
 #[async_trait]
 pub trait ApiDeploymentRepo {
     async fn create(&self, deployments: Vec<ApiDeploymentRecord>) -> Result<(), RepoError>;
@@ -79,6 +165,33 @@ pub trait ApiDeploymentRepo {
         &self,
         site: &str,
     ) -> Result<Vec<ApiDefinitionRecord>, RepoError>;
+
+    async fn get_revisions(
+        &self,
+        site: &str,
+    ) -> Result<Vec<ApiDeploymentRevisionRecord>, RepoError>;
+
+    async fn get_revision(
+        &self,
+        site: &str,
+        revision: i64,
+    ) -> Result<Option<ApiDeploymentRevisionRecord>, RepoError>;
+
+    // Contents of the API definitions deployed to the site at the given revision
+    async fn get_revision_definitions(
+        &self,
+        site: &str,
+        revision: i64,
+    ) -> Result<Vec<ApiDefinitionRecord>, RepoError>;
+
+    // Replaces deployments, updates the draft flags of the affected API definitions, restores
+    // definition contents and records the resulting revision with a snapshot of the deployed
+    // definitions, all in a single transaction. The revision number is assigned in the
+    // transaction, so concurrent changes of the same site fail instead of sharing a revision.
+    async fn apply_change(
+        &self,
+        change: ApiDeploymentChangeRecord,
+    ) -> Result<ApiDeploymentRevisionRecord, RepoError>;
 }
 
 pub struct LoggedDeploymentRepo<Repo: ApiDeploymentRepo> {
@@ -168,6 +281,40 @@ impl<Repo: ApiDeploymentRepo + Sync> ApiDeploymentRepo for LoggedDeploymentRepo<
         let result = self.repo.get_definitions_by_site(site).await;
         Self::logged("get_definitions_by_site", result)
     }
+
+    async fn get_revisions(
+        &self,
+        site: &str,
+    ) -> Result<Vec<ApiDeploymentRevisionRecord>, RepoError> {
+        let result = self.repo.get_revisions(site).await;
+        Self::logged("get_revisions", result)
+    }
+
+    async fn get_revision(
+        &self,
+        site: &str,
+        revision: i64,
+    ) -> Result<Option<ApiDeploymentRevisionRecord>, RepoError> {
+        let result = self.repo.get_revision(site, revision).await;
+        Self::logged("get_revision", result)
+    }
+
+    async fn get_revision_definitions(
+        &self,
+        site: &str,
+        revision: i64,
+    ) -> Result<Vec<ApiDefinitionRecord>, RepoError> {
+        let result = self.repo.get_revision_definitions(site, revision).await;
+        Self::logged("get_revision_definitions", result)
+    }
+
+    async fn apply_change(
+        &self,
+        change: ApiDeploymentChangeRecord,
+    ) -> Result<ApiDeploymentRevisionRecord, RepoError> {
+        let result = self.repo.apply_change(change).await;
+        Self::logged("apply_change", result)
+    }
 }
 
 pub struct DbApiDeploymentRepo<DB: Database> {
@@ -388,4 +535,288 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<sqlx::Postgres> {
             .await
             .map_err(|e| e.into())
     }
+
+    #[when(sqlx::Postgres -> get_revisions)]
+    async fn get_revisions_postgres(
+        &self,
+        site: &str,
+    ) -> Result<Vec<ApiDeploymentRevisionRecord>, RepoError> {
+        sqlx::query_as::<_, ApiDeploymentRevisionRecord>(
+            r#"
+                SELECT site, revision, namespace, host, subdomain, change, source_revision, definitions, actor, created_at::timestamptz
+                FROM api_deployment_revisions
+                WHERE site = $1
+                ORDER BY revision
+                "#,
+        )
+        .bind(site)
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Sqlite -> get_revisions)]
+    async fn get_revisions_sqlite(
+        &self,
+        site: &str,
+    ) -> Result<Vec<ApiDeploymentRevisionRecord>, RepoError> {
+        sqlx::query_as::<_, ApiDeploymentRevisionRecord>(
+            r#"
+                SELECT site, revision, namespace, host, subdomain, change, source_revision, definitions, actor, created_at
+                FROM api_deployment_revisions
+                WHERE site = $1
+                ORDER BY revision
+                "#,
+        )
+        .bind(site)
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Postgres -> get_revision)]
+    async fn get_revision_postgres(
+        &self,
+        site: &str,
+        revision: i64,
+    ) -> Result<Option<ApiDeploymentRevisionRecord>, RepoError> {
+        sqlx::query_as::<_, ApiDeploymentRevisionRecord>(
+            r#"
+                SELECT site, revision, namespace, host, subdomain, change, source_revision, definitions, actor, created_at::timestamptz
+                FROM api_deployment_revisions
+                WHERE site = $1 AND revision = $2
+                "#,
+        )
+        .bind(site)
+        .bind(revision)
+        .fetch_optional(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Sqlite -> get_revision)]
+    async fn get_revision_sqlite(
+        &self,
+        site: &str,
+        revision: i64,
+    ) -> Result<Option<ApiDeploymentRevisionRecord>, RepoError> {
+        sqlx::query_as::<_, ApiDeploymentRevisionRecord>(
+            r#"
+                SELECT site, revision, namespace, host, subdomain, change, source_revision, definitions, actor, created_at
+                FROM api_deployment_revisions
+                WHERE site = $1 AND revision = $2
+                "#,
+        )
+        .bind(site)
+        .bind(revision)
+        .fetch_optional(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Postgres -> get_revision_definitions)]
+    async fn get_revision_definitions_postgres(
+        &self,
+        site: &str,
+        revision: i64,
+    ) -> Result<Vec<ApiDefinitionRecord>, RepoError> {
+        sqlx::query_as::<_, ApiDefinitionRecord>(
+            r#"
+                SELECT definitions.namespace, definitions.definition_id AS id, definitions.definition_version AS version, false AS draft, definitions.data AS data, revisions.created_at::timestamptz
+                FROM api_deployment_revision_definitions AS definitions
+                  JOIN api_deployment_revisions AS revisions ON definitions.site = revisions.site AND definitions.revision = revisions.revision
+                WHERE definitions.site = $1 AND definitions.revision = $2
+                "#,
+        )
+        .bind(site)
+        .bind(revision)
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Sqlite -> get_revision_definitions)]
+    async fn get_revision_definitions_sqlite(
+        &self,
+        site: &str,
+        revision: i64,
+    ) -> Result<Vec<ApiDefinitionRecord>, RepoError> {
+        sqlx::query_as::<_, ApiDefinitionRecord>(
+            r#"
+                SELECT definitions.namespace, definitions.definition_id AS id, definitions.definition_version AS version, false AS draft, definitions.data, revisions.created_at
+                FROM api_deployment_revision_definitions AS definitions
+                  JOIN api_deployment_revisions AS revisions ON definitions.site = revisions.site AND definitions.revision = revisions.revision
+                WHERE definitions.site = $1 AND definitions.revision = $2
+                "#,
+        )
+        .bind(site)
+        .bind(revision)
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn apply_change(
+        &self,
+        change: ApiDeploymentChangeRecord,
+    ) -> Result<ApiDeploymentRevisionRecord, RepoError> {
+        let mut transaction = self.db_pool.begin().await?;
+
+        for definition in change.restored {
+            sqlx::query(
+                r#"
+                  INSERT INTO api_definitions
+                    (namespace, id, version, draft, data, created_at)
+                  VALUES
+                    ($1, $2, $3, false, $4, $5)
+                  ON CONFLICT (namespace, id, version) DO UPDATE SET draft = false, data = excluded.data
+                   "#,
+            )
+            .bind(definition.namespace)
+            .bind(definition.id)
+            .bind(definition.version)
+            .bind(definition.data)
+            .bind(definition.created_at)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        for deployment in change.deleted.iter() {
+            sqlx::query(
+                "DELETE FROM api_deployments WHERE namespace = $1 AND site = $2 AND definition_id = $3 AND definition_version = $4",
+            )
+                .bind(deployment.namespace.clone())
+                .bind(deployment.site.clone())
+                .bind(deployment.definition_id.clone())
+                .bind(deployment.definition_version.clone())
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        for deployment in change.created.iter() {
+            sqlx::query(
+                r#"
+                  INSERT INTO api_deployments
+                    (namespace, site, host, subdomain, definition_id, definition_version, created_at)
+                  VALUES
+                    ($1, $2, $3, $4, $5, $6, $7)
+                   "#,
+            )
+            .bind(deployment.namespace.clone())
+            .bind(deployment.site.clone())
+            .bind(deployment.host.clone())
+            .bind(deployment.subdomain.clone())
+            .bind(deployment.definition_id.clone())
+            .bind(deployment.definition_version.clone())
+            .bind(deployment.created_at)
+            .execute(&mut *transaction)
+            .await?;
+
+            sqlx::query(
+                "UPDATE api_definitions SET draft = false WHERE namespace = $1 AND id = $2 AND version = $3",
+            )
+                .bind(deployment.namespace.clone())
+                .bind(deployment.definition_id.clone())
+                .bind(deployment.definition_version.clone())
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        // Definitions no longer deployed to any site become drafts again
+        for deployment in change.deleted {
+            sqlx::query(
+                r#"
+                  UPDATE api_definitions SET draft = true
+                  WHERE namespace = $1 AND id = $2 AND version = $3
+                    AND NOT EXISTS (
+                      SELECT 1 FROM api_deployments
+                      WHERE namespace = $1 AND definition_id = $2 AND definition_version = $3
+                    )
+                   "#,
+            )
+            .bind(deployment.namespace)
+            .bind(deployment.definition_id)
+            .bind(deployment.definition_version)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        let revision: i64 = sqlx::query_scalar(
+            "SELECT COALESCE(MAX(revision), 0) + 1 FROM api_deployment_revisions WHERE site = $1",
+        )
+        .bind(change.site.clone())
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        let definition_keys = sqlx::query_as::<_, (String, String)>(
+            "SELECT definition_id, definition_version FROM api_deployments WHERE site = $1 ORDER BY definition_id, definition_version",
+        )
+            .bind(change.site.clone())
+            .fetch_all(&mut *transaction)
+            .await?
+            .into_iter()
+            .map(|(id, version)| ApiDefinitionIdWithVersion {
+                id: id.into(),
+                version: version.into(),
+            })
+            .collect::<Vec<_>>();
+
+        let definitions = serde_json::to_string(&definition_keys).map_err(|e| {
+            RepoError::Internal(format!("Failed to serialize API definition keys: {e}"))
+        })?;
+
+        let record = ApiDeploymentRevisionRecord {
+            site: change.site,
+            revision,
+            namespace: change.namespace,
+            host: change.host,
+            subdomain: change.subdomain,
+            change: change.change,
+            source_revision: change.source_revision,
+            definitions,
+            actor: change.actor,
+            created_at: change.created_at,
+        };
+
+        sqlx::query(
+            r#"
+              INSERT INTO api_deployment_revisions
+                (site, revision, namespace, host, subdomain, change, source_revision, definitions, actor, created_at)
+              VALUES
+                ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+               "#,
+        )
+        .bind(record.site.clone())
+        .bind(record.revision)
+        .bind(record.namespace.clone())
+        .bind(record.host.clone())
+        .bind(record.subdomain.clone())
+        .bind(record.change.clone())
+        .bind(record.source_revision)
+        .bind(record.definitions.clone())
+        .bind(record.actor.clone())
+        .bind(record.created_at)
+        .execute(&mut *transaction)
+        .await?;
+
+        // Snapshot of the deployed definitions, so the revision can be restored and compared
+        // even if the definitions are updated or deleted later
+        sqlx::query(
+            r#"
+              INSERT INTO api_deployment_revision_definitions
+                (site, revision, namespace, definition_id, definition_version, data)
+              SELECT api_deployments.site, $2, api_definitions.namespace, api_definitions.id, api_definitions.version, api_definitions.data
+              FROM api_deployments
+                JOIN api_definitions ON api_deployments.namespace = api_definitions.namespace AND api_deployments.definition_id = api_definitions.id AND api_deployments.definition_version = api_definitions.version
+              WHERE api_deployments.site = $1
+               "#,
+        )
+        .bind(record.site.clone())
+        .bind(record.revision)
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+        Ok(record)
+    }
 }
@@ -15,3 +15,4 @@
 pub mod api_definition;
 pub mod api_deployment;
 pub mod security_scheme;
+pub mod topic;
@@ -0,0 +1,631 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use conditional_trait_gen::{trait_gen, when};
+use golem_service_base::repo::RepoError;
+use sqlx::{Database, Pool};
+use std::ops::Deref;
+use std::sync::Arc;
+use tracing::{debug, error};
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct TopicRecord {
+    pub namespace: String,
+    pub name: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct TopicSubscriptionRecord {
+    pub namespace: String,
+    pub topic: String,
+    pub subscription_id: String,
+    pub component_id: String,
+    pub worker_name: String,
+    pub function_name: String,
+    pub filter: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct TopicMessageRecord {
+    pub namespace: String,
+    pub topic: String,
+    pub message_id: String,
+    pub payload: Vec<u8>,
+    pub delivered: bool,
+    /// Set when the delivery of the message has been given up after too many failed attempts
+    pub dead_letter: bool,
+    pub attempts: i32,
+    pub published_at: chrono::DateTime<chrono::Utc>,
+    pub next_attempt_at: chrono::DateTime<chrono::Utc>,
+    /// The time the message has been delivered or moved to the dead-letter state
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[async_trait]
+pub trait TopicRepo {
+    async fn create(&self, topic: &TopicRecord) -> Result<(), RepoError>;
+
+    async fn get(&self, namespace: &str, name: &str) -> Result<Option<TopicRecord>, RepoError>;
+
+    async fn get_all(&self, namespace: &str) -> Result<Vec<TopicRecord>, RepoError>;
+
+    /// Deletes the topic together with its subscriptions and messages
+    async fn delete(&self, namespace: &str, name: &str) -> Result<bool, RepoError>;
+
+    async fn create_subscription(
+        &self,
+        subscription: &TopicSubscriptionRecord,
+    ) -> Result<(), RepoError>;
+
+    async fn get_subscriptions(
+        &self,
+        namespace: &str,
+        topic: &str,
+    ) -> Result<Vec<TopicSubscriptionRecord>, RepoError>;
+
+    async fn delete_subscription(
+        &self,
+        namespace: &str,
+        topic: &str,
+        subscription_id: &str,
+    ) -> Result<bool, RepoError>;
+
+    /// Stores the message, returning false if a message with the same id already exists
+    async fn create_message(&self, message: &TopicMessageRecord) -> Result<bool, RepoError>;
+
+    /// Gets the messages which were not yet delivered to all the subscribers of their topic and
+    /// are due for another delivery attempt at `now`, the longest waiting first
+    async fn get_undelivered_messages(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        limit: u32,
+    ) -> Result<Vec<TopicMessageRecord>, RepoError>;
+
+    async fn set_message_delivered(
+        &self,
+        message_id: &str,
+        completed_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RepoError>;
+
+    /// Records a failed delivery attempt, scheduling the next one at `next_attempt_at`
+    async fn set_message_retry(
+        &self,
+        message_id: &str,
+        attempts: i32,
+        next_attempt_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RepoError>;
+
+    /// Records the last failed delivery attempt, moving the message to the dead-letter state
+    async fn set_message_dead_letter(
+        &self,
+        message_id: &str,
+        attempts: i32,
+        completed_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RepoError>;
+
+    /// Deletes the delivered and dead-lettered messages completed before the given time,
+    /// returning the number of deleted messages
+    async fn delete_completed_messages(
+        &self,
+        completed_before: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u64, RepoError>;
+}
+
+pub struct LoggedTopicRepo<Repo: TopicRepo> {
+    repo: Repo,
+}
+
+impl<Repo: TopicRepo> LoggedTopicRepo<Repo> {
+    pub fn new(repo: Repo) -> Self {
+        Self { repo }
+    }
+
+    fn logged<R>(message: &'static str, result: Result<R, RepoError>) -> Result<R, RepoError> {
+        match &result {
+            Ok(_) => debug!("{}", message),
+            Err(error) => error!(error = error.to_string(), "{message}"),
+        }
+        result
+    }
+
+    fn logged_with_topic<R>(
+        message: &'static str,
+        namespace: &str,
+        topic: &str,
+        result: Result<R, RepoError>,
+    ) -> Result<R, RepoError> {
+        match &result {
+            Ok(_) => debug!(namespace = namespace, topic = topic, "{}", message),
+            Err(error) => error!(
+                namespace = namespace,
+                topic = topic,
+                error = error.to_string(),
+                "{message}"
+            ),
+        }
+        result
+    }
+}
+
+#[async_trait]
+impl<Repo: TopicRepo + Sync> TopicRepo for LoggedTopicRepo<Repo> {
+    async fn create(&self, topic: &TopicRecord) -> Result<(), RepoError> {
+        let result = self.repo.create(topic).await;
+        Self::logged_with_topic("create", &topic.namespace, &topic.name, result)
+    }
+
+    async fn get(&self, namespace: &str, name: &str) -> Result<Option<TopicRecord>, RepoError> {
+        let result = self.repo.get(namespace, name).await;
+        Self::logged_with_topic("get", namespace, name, result)
+    }
+
+    async fn get_all(&self, namespace: &str) -> Result<Vec<TopicRecord>, RepoError> {
+        let result = self.repo.get_all(namespace).await;
+        Self::logged("get_all", result)
+    }
+
+    async fn delete(&self, namespace: &str, name: &str) -> Result<bool, RepoError> {
+        let result = self.repo.delete(namespace, name).await;
+        Self::logged_with_topic("delete", namespace, name, result)
+    }
+
+    async fn create_subscription(
+        &self,
+        subscription: &TopicSubscriptionRecord,
+    ) -> Result<(), RepoError> {
+        let result = self.repo.create_subscription(subscription).await;
+        Self::logged_with_topic(
+            "create_subscription",
+            &subscription.namespace,
+            &subscription.topic,
+            result,
+        )
+    }
+
+    async fn get_subscriptions(
+        &self,
+        namespace: &str,
+        topic: &str,
+    ) -> Result<Vec<TopicSubscriptionRecord>, RepoError> {
+        let result = self.repo.get_subscriptions(namespace, topic).await;
+        Self::logged_with_topic("get_subscriptions", namespace, topic, result)
+    }
+
+    async fn delete_subscription(
+        &self,
+        namespace: &str,
+        topic: &str,
+        subscription_id: &str,
+    ) -> Result<bool, RepoError> {
+        let result = self
+            .repo
+            .delete_subscription(namespace, topic, subscription_id)
+            .await;
+        Self::logged_with_topic("delete_subscription", namespace, topic, result)
+    }
+
+    async fn create_message(&self, message: &TopicMessageRecord) -> Result<bool, RepoError> {
+        let result = self.repo.create_message(message).await;
+        Self::logged_with_topic("create_message", &message.namespace, &message.topic, result)
+    }
+
+    async fn get_undelivered_messages(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        limit: u32,
+    ) -> Result<Vec<TopicMessageRecord>, RepoError> {
+        let result = self.repo.get_undelivered_messages(now, limit).await;
+        Self::logged("get_undelivered_messages", result)
+    }
+
+    async fn set_message_delivered(
+        &self,
+        message_id: &str,
+        completed_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RepoError> {
+        let result = self
+            .repo
+            .set_message_delivered(message_id, completed_at)
+            .await;
+        Self::logged("set_message_delivered", result)
+    }
+
+    async fn set_message_retry(
+        &self,
+        message_id: &str,
+        attempts: i32,
+        next_attempt_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RepoError> {
+        let result = self
+            .repo
+            .set_message_retry(message_id, attempts, next_attempt_at)
+            .await;
+        Self::logged("set_message_retry", result)
+    }
+
+    async fn set_message_dead_letter(
+        &self,
+        message_id: &str,
+        attempts: i32,
+        completed_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RepoError> {
+        let result = self
+            .repo
+            .set_message_dead_letter(message_id, attempts, completed_at)
+            .await;
+        Self::logged("set_message_dead_letter", result)
+    }
+
+    async fn delete_completed_messages(
+        &self,
+        completed_before: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u64, RepoError> {
+        let result = self.repo.delete_completed_messages(completed_before).await;
+        Self::logged("delete_completed_messages", result)
+    }
+}
+
+pub struct DbTopicRepo<DB: Database> {
+    db_pool: Arc<Pool<DB>>,
+}
+
+impl<DB: Database> DbTopicRepo<DB> {
+    pub fn new(db_pool: Arc<Pool<DB>>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[trait_gen(sqlx::Postgres -> sqlx::Postgres, sqlx::Sqlite)]
+#[async_trait]
+impl TopicRepo for DbTopicRepo<sqlx::Postgres> {
+    async fn create(&self, topic: &TopicRecord) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+              INSERT INTO topics
+                (namespace, name, created_at)
+              VALUES
+                ($1, $2, $3)
+               "#,
+        )
+        .bind(topic.namespace.clone())
+        .bind(topic.name.clone())
+        .bind(topic.created_at)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(())
+    }
+
+    #[when(sqlx::Postgres -> get)]
+    async fn get_postgres(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Option<TopicRecord>, RepoError> {
+        sqlx::query_as::<_, TopicRecord>(
+            r#"
+                SELECT namespace, name, created_at::timestamptz
+                FROM topics
+                WHERE namespace = $1 AND name = $2
+                "#,
+        )
+        .bind(namespace)
+        .bind(name)
+        .fetch_optional(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Sqlite -> get)]
+    async fn get_sqlite(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Option<TopicRecord>, RepoError> {
+        sqlx::query_as::<_, TopicRecord>(
+            r#"
+                SELECT namespace, name, created_at
+                FROM topics
+                WHERE namespace = $1 AND name = $2
+                "#,
+        )
+        .bind(namespace)
+        .bind(name)
+        .fetch_optional(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Postgres -> get_all)]
+    async fn get_all_postgres(&self, namespace: &str) -> Result<Vec<TopicRecord>, RepoError> {
+        sqlx::query_as::<_, TopicRecord>(
+            r#"
+                SELECT namespace, name, created_at::timestamptz
+                FROM topics
+                WHERE namespace = $1
+                ORDER BY name
+                "#,
+        )
+        .bind(namespace)
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Sqlite -> get_all)]
+    async fn get_all_sqlite(&self, namespace: &str) -> Result<Vec<TopicRecord>, RepoError> {
+        sqlx::query_as::<_, TopicRecord>(
+            r#"
+                SELECT namespace, name, created_at
+                FROM topics
+                WHERE namespace = $1
+                ORDER BY name
+                "#,
+        )
+        .bind(namespace)
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn delete(&self, namespace: &str, name: &str) -> Result<bool, RepoError> {
+        let mut transaction = self.db_pool.begin().await?;
+
+        sqlx::query("DELETE FROM topic_messages WHERE namespace = $1 AND topic = $2")
+            .bind(namespace)
+            .bind(name)
+            .execute(&mut *transaction)
+            .await?;
+
+        sqlx::query("DELETE FROM topic_subscriptions WHERE namespace = $1 AND topic = $2")
+            .bind(namespace)
+            .bind(name)
+            .execute(&mut *transaction)
+            .await?;
+
+        let result = sqlx::query("DELETE FROM topics WHERE namespace = $1 AND name = $2")
+            .bind(namespace)
+            .bind(name)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn create_subscription(
+        &self,
+        subscription: &TopicSubscriptionRecord,
+    ) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+              INSERT INTO topic_subscriptions
+                (namespace, topic, subscription_id, component_id, worker_name, function_name, filter, created_at)
+              VALUES
+                ($1, $2, $3, $4, $5, $6, $7, $8)
+               "#,
+        )
+        .bind(subscription.namespace.clone())
+        .bind(subscription.topic.clone())
+        .bind(subscription.subscription_id.clone())
+        .bind(subscription.component_id.clone())
+        .bind(subscription.worker_name.clone())
+        .bind(subscription.function_name.clone())
+        .bind(subscription.filter.clone())
+        .bind(subscription.created_at)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(())
+    }
+
+    #[when(sqlx::Postgres -> get_subscriptions)]
+    async fn get_subscriptions_postgres(
+        &self,
+        namespace: &str,
+        topic: &str,
+    ) -> Result<Vec<TopicSubscriptionRecord>, RepoError> {
+        sqlx::query_as::<_, TopicSubscriptionRecord>(
+            r#"
+                SELECT namespace, topic, subscription_id, component_id, worker_name, function_name, filter, created_at::timestamptz
+                FROM topic_subscriptions
+                WHERE namespace = $1 AND topic = $2
+                ORDER BY created_at
+                "#,
+        )
+        .bind(namespace)
+        .bind(topic)
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Sqlite -> get_subscriptions)]
+    async fn get_subscriptions_sqlite(
+        &self,
+        namespace: &str,
+        topic: &str,
+    ) -> Result<Vec<TopicSubscriptionRecord>, RepoError> {
+        sqlx::query_as::<_, TopicSubscriptionRecord>(
+            r#"
+                SELECT namespace, topic, subscription_id, component_id, worker_name, function_name, filter, created_at
+                FROM topic_subscriptions
+                WHERE namespace = $1 AND topic = $2
+                ORDER BY created_at
+                "#,
+        )
+        .bind(namespace)
+        .bind(topic)
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn delete_subscription(
+        &self,
+        namespace: &str,
+        topic: &str,
+        subscription_id: &str,
+    ) -> Result<bool, RepoError> {
+        let result = sqlx::query(
+            "DELETE FROM topic_subscriptions WHERE namespace = $1 AND topic = $2 AND subscription_id = $3",
+        )
+        .bind(namespace)
+        .bind(topic)
+        .bind(subscription_id)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn create_message(&self, message: &TopicMessageRecord) -> Result<bool, RepoError> {
+        let result = sqlx::query(
+            r#"
+              INSERT INTO topic_messages
+                (namespace, topic, message_id, payload, delivered, dead_letter, attempts, published_at, next_attempt_at, completed_at)
+              VALUES
+                ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+              ON CONFLICT (message_id) DO NOTHING
+               "#,
+        )
+        .bind(message.namespace.clone())
+        .bind(message.topic.clone())
+        .bind(message.message_id.clone())
+        .bind(message.payload.clone())
+        .bind(message.delivered)
+        .bind(message.dead_letter)
+        .bind(message.attempts)
+        .bind(message.published_at)
+        .bind(message.next_attempt_at)
+        .bind(message.completed_at)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[when(sqlx::Postgres -> get_undelivered_messages)]
+    async fn get_undelivered_messages_postgres(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        limit: u32,
+    ) -> Result<Vec<TopicMessageRecord>, RepoError> {
+        sqlx::query_as::<_, TopicMessageRecord>(
+            r#"
+                SELECT namespace, topic, message_id, payload, delivered, dead_letter, attempts,
+                  published_at::timestamptz, next_attempt_at::timestamptz, completed_at::timestamptz
+                FROM topic_messages
+                WHERE delivered = false AND dead_letter = false AND next_attempt_at <= $1
+                ORDER BY next_attempt_at
+                LIMIT $2
+                "#,
+        )
+        .bind(now)
+        .bind(limit as i64)
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Sqlite -> get_undelivered_messages)]
+    async fn get_undelivered_messages_sqlite(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        limit: u32,
+    ) -> Result<Vec<TopicMessageRecord>, RepoError> {
+        sqlx::query_as::<_, TopicMessageRecord>(
+            r#"
+                SELECT namespace, topic, message_id, payload, delivered, dead_letter, attempts,
+                  published_at, next_attempt_at, completed_at
+                FROM topic_messages
+                WHERE delivered = false AND dead_letter = false AND next_attempt_at <= $1
+                ORDER BY next_attempt_at
+                LIMIT $2
+                "#,
+        )
+        .bind(now)
+        .bind(limit as i64)
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn set_message_delivered(
+        &self,
+        message_id: &str,
+        completed_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RepoError> {
+        sqlx::query(
+            "UPDATE topic_messages SET delivered = true, completed_at = $2 WHERE message_id = $1",
+        )
+        .bind(message_id)
+        .bind(completed_at)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn set_message_retry(
+        &self,
+        message_id: &str,
+        attempts: i32,
+        next_attempt_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RepoError> {
+        sqlx::query(
+            "UPDATE topic_messages SET attempts = $2, next_attempt_at = $3 WHERE message_id = $1",
+        )
+        .bind(message_id)
+        .bind(attempts)
+        .bind(next_attempt_at)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn set_message_dead_letter(
+        &self,
+        message_id: &str,
+        attempts: i32,
+        completed_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RepoError> {
+        sqlx::query(
+            "UPDATE topic_messages SET dead_letter = true, attempts = $2, completed_at = $3 WHERE message_id = $1",
+        )
+        .bind(message_id)
+        .bind(attempts)
+        .bind(completed_at)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_completed_messages(
+        &self,
+        completed_before: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u64, RepoError> {
+        let result = sqlx::query("DELETE FROM topic_messages WHERE completed_at < $1")
+            .bind(completed_before)
+            .execute(self.db_pool.deref())
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
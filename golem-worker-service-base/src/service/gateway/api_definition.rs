@@ -40,7 +40,15 @@ use tracing::{error, info};
 pub type ApiResult<T> = Result<T, ApiDefinitionError>;
 
 #[derive(
-    Eq, Hash, PartialEq, Clone, Debug, serde::Deserialize, bincode::Encode, bincode::Decode,
+    Eq,
+    Hash,
+    PartialEq,
+    Clone,
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    bincode::Encode,
+    bincode::Decode,
 )]
 pub struct ApiDefinitionIdWithVersion {
     pub id: ApiDefinitionId,
@@ -20,23 +20,26 @@ use std::collections::{HashMap, HashSet};
 use async_trait::async_trait;
 
 use std::sync::Arc;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 use crate::gateway_api_definition::http::{
     AllPathPatterns, CompiledHttpApiDefinition, HttpApiDefinition, Route,
 };
 
 use crate::gateway_binding::GatewayBindingCompiled;
+use crate::gateway_execution::gateway_response_cache::GatewayResponseCacheStore;
 use crate::gateway_execution::router::{Router, RouterPattern};
+use crate::repo::api_definition::ApiDefinitionRecord;
 use crate::repo::api_definition::ApiDefinitionRepo;
-use crate::repo::api_deployment::ApiDeploymentRecord;
 use crate::repo::api_deployment::ApiDeploymentRepo;
+use crate::repo::api_deployment::{ApiDeploymentChangeRecord, ApiDeploymentRecord};
 use crate::service::component::ComponentService;
 use crate::service::gateway::api_definition::ApiDefinitionIdWithVersion;
 use chrono::Utc;
 use golem_common::model::component_constraint::FunctionConstraintCollection;
 use golem_common::model::ComponentId;
 use golem_common::SafeDisplay;
+use golem_service_base::auth::HasPrincipal;
 use golem_service_base::repo::RepoError;
 use rib::WorkerFunctionsInRib;
 use std::fmt::{Debug, Display};
@@ -52,6 +55,7 @@ pub trait ApiDeploymentService<AuthCtx, Namespace> {
     async fn undeploy(
         &self,
         deployment: &ApiDeploymentRequest<Namespace>,
+        auth_ctx: &AuthCtx,
     ) -> Result<(), ApiDeploymentError<Namespace>>;
 
     // Example: A newer version of API definition is in dev site, and older version of the same definition-id is in prod site.
@@ -76,7 +80,33 @@ pub trait ApiDeploymentService<AuthCtx, Namespace> {
         &self,
         namespace: &Namespace,
         site: &ApiSiteString,
+        auth_ctx: &AuthCtx,
     ) -> Result<(), ApiDeploymentError<Namespace>>;
+
+    // Lists every recorded change of the site, oldest first
+    async fn get_revisions(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+    ) -> Result<Vec<ApiDeploymentRevision<Namespace>>, ApiDeploymentError<Namespace>>;
+
+    // Restores the set of API definitions deployed to the site at the given revision, together
+    // with their content at that revision, recording the rollback as a new revision
+    async fn rollback(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+        revision: u64,
+        auth_ctx: &AuthCtx,
+    ) -> Result<ApiDeploymentRevision<Namespace>, ApiDeploymentError<Namespace>>;
+
+    async fn diff_revisions(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+        from_revision: u64,
+        to_revision: u64,
+    ) -> Result<ApiDeploymentRevisionDiff, ApiDeploymentError<Namespace>>;
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -85,6 +115,8 @@ pub enum ApiDeploymentError<Namespace> {
     ApiDefinitionNotFound(Namespace, ApiDefinitionId),
     #[error("API deployment not found: {1}")]
     ApiDeploymentNotFound(Namespace, ApiSiteString),
+    #[error("API deployment revision not found: {1} revision {2}")]
+    ApiDeploymentRevisionNotFound(Namespace, ApiSiteString, u64),
     #[error("API deployment conflict error: {0}")]
     ApiDeploymentConflict(ApiSiteString),
     #[error("API deployment definitions conflict error: {0}")]
@@ -117,6 +149,7 @@ impl<Namespace: Display> SafeDisplay for ApiDeploymentError<Namespace> {
         match self {
             ApiDeploymentError::ApiDefinitionNotFound(_, _) => self.to_string(),
             ApiDeploymentError::ApiDeploymentNotFound(_, _) => self.to_string(),
+            ApiDeploymentError::ApiDeploymentRevisionNotFound(_, _, _) => self.to_string(),
             ApiDeploymentError::ApiDeploymentConflict(_) => self.to_string(),
             ApiDeploymentError::ApiDefinitionsConflict(_) => self.to_string(),
             ApiDeploymentError::InternalRepoError(inner) => inner.to_safe_string(),
@@ -170,6 +203,7 @@ pub struct ApiDeploymentServiceDefault<AuthCtx> {
     pub deployment_repo: Arc<dyn ApiDeploymentRepo + Sync + Send>,
     pub definition_repo: Arc<dyn ApiDefinitionRepo + Sync + Send>,
     pub component_service: Arc<dyn ComponentService<AuthCtx> + Send + Sync>,
+    pub response_cache: GatewayResponseCacheStore,
 }
 
 impl<AuthCtx> ApiDeploymentServiceDefault<AuthCtx> {
@@ -177,41 +211,131 @@ impl<AuthCtx> ApiDeploymentServiceDefault<AuthCtx> {
         deployment_repo: Arc<dyn ApiDeploymentRepo + Sync + Send>,
         definition_repo: Arc<dyn ApiDefinitionRepo + Sync + Send>,
         component_service: Arc<dyn ComponentService<AuthCtx> + Send + Sync>,
+        response_cache: GatewayResponseCacheStore,
     ) -> Self {
         Self {
             deployment_repo,
             definition_repo,
             component_service,
+            response_cache,
         }
     }
 
-    async fn set_undeployed_as_draft<Namespace>(
+    // Applies the change and records the resulting revision in a single transaction
+    async fn apply_change<Namespace>(
         &self,
-        deployments: Vec<ApiDeploymentRecord>,
-    ) -> Result<(), ApiDeploymentError<Namespace>> {
-        for deployment in deployments {
-            let existing_deployments = self
-                .deployment_repo
-                .get_by_id_and_version(
-                    deployment.namespace.as_str(),
-                    deployment.definition_id.as_str(),
-                    deployment.definition_version.as_str(),
-                )
-                .await?;
-
-            if existing_deployments.is_empty() {
-                self.definition_repo
-                    .set_draft(
-                        deployment.namespace.as_str(),
-                        deployment.definition_id.as_str(),
-                        deployment.definition_version.as_str(),
-                        true,
-                    )
-                    .await?;
-            }
+        change: ApiDeploymentChangeRecord,
+    ) -> Result<ApiDeploymentRevision<Namespace>, ApiDeploymentError<Namespace>>
+    where
+        Namespace: TryFrom<String>,
+        <Namespace as TryFrom<String>>::Error: Display,
+    {
+        let site = ApiSiteString(change.site.clone());
+
+        let record =
+            self.deployment_repo
+                .apply_change(change)
+                .await
+                .map_err(|error| match error {
+                    // The site has been changed concurrently
+                    RepoError::UniqueViolation(_) => {
+                        ApiDeploymentError::ApiDeploymentConflict(site)
+                    }
+                    error => error.into(),
+                })?;
+
+        // Responses cached for the routes of the previously deployed definitions must not be served
+        // anymore. The change is already committed, so failing to invalidate doesn't fail it.
+        if let Err(err) = self.response_cache.invalidate(&site, None).await {
+            warn!(
+                "Failed to invalidate the cached responses of site {}: {}",
+                site,
+                err.to_safe_string()
+            );
         }
 
-        Ok(())
+        record
+            .try_into()
+            .map_err(|e| ApiDeploymentError::conversion_error("API deployment revision", e))
+    }
+
+    async fn get_revision<Namespace>(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+        revision: u64,
+    ) -> Result<ApiDeploymentRevision<Namespace>, ApiDeploymentError<Namespace>>
+    where
+        Namespace: Display + TryFrom<String> + Clone,
+        <Namespace as TryFrom<String>>::Error: Display,
+    {
+        let record = self
+            .deployment_repo
+            .get_revision(site.to_string().as_str(), revision as i64)
+            .await?
+            .filter(|record| record.namespace == namespace.to_string())
+            .ok_or(ApiDeploymentError::ApiDeploymentRevisionNotFound(
+                namespace.clone(),
+                site.clone(),
+                revision,
+            ))?;
+
+        record
+            .try_into()
+            .map_err(|e| ApiDeploymentError::conversion_error("API deployment revision", e))
+    }
+
+    // The API definitions deployed at the revision, with their content at that revision
+    async fn get_revision_definitions<Namespace>(
+        &self,
+        site: &ApiSiteString,
+        revision: u64,
+    ) -> Result<Vec<ApiDefinitionRecord>, ApiDeploymentError<Namespace>> {
+        let records = self
+            .deployment_repo
+            .get_revision_definitions(site.to_string().as_str(), revision as i64)
+            .await?;
+
+        Ok(records)
+    }
+
+    fn compile_revision_definitions<Namespace>(
+        records: Vec<ApiDefinitionRecord>,
+    ) -> Result<Vec<CompiledHttpApiDefinition<Namespace>>, ApiDeploymentError<Namespace>>
+    where
+        Namespace: Display + TryFrom<String> + Clone,
+        <Namespace as TryFrom<String>>::Error: Display,
+    {
+        records
+            .into_iter()
+            .map(|record| {
+                record
+                    .try_into()
+                    .map_err(|e| ApiDeploymentError::conversion_error("API definition record", e))
+            })
+            .collect()
+    }
+
+    fn get_routes_by_key<Namespace>(
+        definitions: Vec<CompiledHttpApiDefinition<Namespace>>,
+    ) -> HashMap<ApiRouteKey, Route> {
+        definitions
+            .into_iter()
+            .map(HttpApiDefinition::from)
+            .flat_map(|definition| definition.routes)
+            .map(|route| {
+                let key = ApiRouteKey {
+                    method: route.method.clone(),
+                    path: route.path.clone(),
+                };
+                (key, route)
+            })
+            .collect()
+    }
+
+    fn sorted_route_keys(mut keys: Vec<ApiRouteKey>) -> Vec<ApiRouteKey> {
+        keys.sort_by_key(|key| (key.path.to_string(), key.method.to_string()));
+        keys
     }
 
     fn get_worker_functions_in_api_definitions<Namespace>(
@@ -265,7 +389,7 @@ impl<AuthCtx> ApiDeploymentServiceDefault<AuthCtx> {
 impl<AuthCtx, Namespace> ApiDeploymentService<AuthCtx, Namespace>
     for ApiDeploymentServiceDefault<AuthCtx>
 where
-    AuthCtx: Send + Sync,
+    AuthCtx: HasPrincipal + Send + Sync,
     Namespace: Display + TryFrom<String> + Eq + Clone + Send + Sync,
     <Namespace as TryFrom<String>>::Error: Display + Debug + Send + Sync + 'static,
 {
@@ -310,8 +434,6 @@ where
 
         let mut new_deployment_records: Vec<ApiDeploymentRecord> = vec![];
 
-        let mut set_not_draft: Vec<ApiDefinitionIdWithVersion> = vec![];
-
         let mut new_definitions: Vec<CompiledHttpApiDefinition<Namespace>> = vec![];
 
         for api_definition_key in deployment.api_definition_keys.clone() {
@@ -334,9 +456,6 @@ where
                         ));
                     }
                     Some(record) => {
-                        if record.draft {
-                            set_not_draft.push(api_definition_key.clone());
-                        }
                         let definition = record.try_into().map_err(|e| {
                             ApiDeploymentError::conversion_error("API definition record", e)
                         })?;
@@ -383,22 +502,8 @@ where
                 conflicting_definitions,
             ))
         } else if !new_deployment_records.is_empty() {
-            for api_definition_key in set_not_draft {
-                info!(namespace = %deployment.namespace,
-                    "Set API definition as not draft - definition id: {}, definition version: {}",
-                    api_definition_key.id, api_definition_key.version
-                );
-
-                self.definition_repo
-                    .set_draft(
-                        deployment.namespace.to_string().as_str(),
-                        api_definition_key.id.0.as_str(),
-                        api_definition_key.version.0.as_str(),
-                        false,
-                    )
-                    .await?;
-            }
-
+            // Constraints are kept by the component service, so they are updated before
+            // the deployment change is applied
             let constraints =
                 Self::get_worker_functions_in_api_definitions(new_definitions.clone())?;
 
@@ -411,7 +516,18 @@ where
                     })?;
             }
 
-            self.deployment_repo.create(new_deployment_records).await?;
+            let mut change = ApiDeploymentChangeRecord::new(
+                deployment.namespace.clone(),
+                deployment.site.clone(),
+                ApiDeploymentChange::Deploy,
+                None,
+                auth_ctx.principal(),
+                created_at,
+            );
+            change.created = new_deployment_records;
+
+            self.apply_change(change).await?;
+
             Ok(())
         } else {
             Ok(())
@@ -421,6 +537,7 @@ where
     async fn undeploy(
         &self,
         deployment: &ApiDeploymentRequest<Namespace>,
+        auth_ctx: &AuthCtx,
     ) -> Result<(), ApiDeploymentError<Namespace>> {
         info!(namespace = %deployment.namespace, "Undeploying API definitions");
 
@@ -460,12 +577,17 @@ where
         }
 
         if !remove_deployment_records.is_empty() {
-            self.deployment_repo
-                .delete(remove_deployment_records.clone())
-                .await?;
+            let mut change = ApiDeploymentChangeRecord::new(
+                deployment.namespace.clone(),
+                deployment.site.clone(),
+                ApiDeploymentChange::Undeploy,
+                None,
+                auth_ctx.principal(),
+                Utc::now(),
+            );
+            change.deleted = remove_deployment_records;
 
-            self.set_undeployed_as_draft(remove_deployment_records)
-                .await?;
+            self.apply_change(change).await?;
         }
 
         Ok(())
@@ -609,6 +731,7 @@ where
         &self,
         namespace: &Namespace,
         site: &ApiSiteString,
+        auth_ctx: &AuthCtx,
     ) -> Result<(), ApiDeploymentError<Namespace>> {
         info!(namespace = %namespace, "Get API deployment");
         let existing_deployment_records = self
@@ -631,16 +754,224 @@ where
 
             Err(ApiDeploymentError::ApiDeploymentConflict(site.clone()))
         } else {
-            self.deployment_repo
-                .delete(existing_deployment_records.clone())
-                .await?;
+            let api_site = ApiSite {
+                host: existing_deployment_records[0].host.clone(),
+                subdomain: existing_deployment_records[0].subdomain.clone(),
+            };
+
+            let mut change = ApiDeploymentChangeRecord::new(
+                namespace.clone(),
+                api_site,
+                ApiDeploymentChange::Delete,
+                None,
+                auth_ctx.principal(),
+                Utc::now(),
+            );
+            change.deleted = existing_deployment_records;
 
-            self.set_undeployed_as_draft(existing_deployment_records)
-                .await?;
+            self.apply_change(change).await?;
 
             Ok(())
         }
     }
+
+    async fn get_revisions(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+    ) -> Result<Vec<ApiDeploymentRevision<Namespace>>, ApiDeploymentError<Namespace>> {
+        info!(namespace = %namespace, "Get API deployment revisions");
+        let records = self
+            .deployment_repo
+            .get_revisions(site.to_string().as_str())
+            .await?;
+
+        let mut values: Vec<ApiDeploymentRevision<Namespace>> = vec![];
+
+        for record in records {
+            if record.namespace == namespace.to_string() {
+                values.push(record.try_into().map_err(|e| {
+                    ApiDeploymentError::conversion_error("API deployment revision", e)
+                })?);
+            }
+        }
+
+        Ok(values)
+    }
+
+    async fn rollback(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+        revision: u64,
+        auth_ctx: &AuthCtx,
+    ) -> Result<ApiDeploymentRevision<Namespace>, ApiDeploymentError<Namespace>> {
+        info!(namespace = %namespace, "Rollback API deployment to revision {}", revision);
+
+        let created_at = Utc::now();
+
+        let target_revision = self.get_revision(namespace, site, revision).await?;
+
+        let existing_deployment_records = self
+            .deployment_repo
+            .get_by_site(site.to_string().as_str())
+            .await?;
+
+        if existing_deployment_records
+            .iter()
+            .any(|value| value.namespace != namespace.to_string())
+        {
+            error!(
+                "Failed to rollback API deployment - site used by another API (under another namespace/API)"
+            );
+
+            return Err(ApiDeploymentError::ApiDeploymentConflict(site.clone()));
+        }
+
+        let definition_records = self.get_revision_definitions(site, revision).await?;
+        let definitions = Self::compile_revision_definitions(definition_records.clone())?;
+
+        let conflicting_definitions = HttpApiDefinition::find_conflicts(
+            definitions
+                .clone()
+                .into_iter()
+                .map(|x| x.into())
+                .collect::<Vec<HttpApiDefinition>>()
+                .as_slice(),
+        );
+
+        if !conflicting_definitions.is_empty() {
+            let conflicting_definitions = conflicting_definitions
+                .iter()
+                .map(|def| format!("{}", def))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            info!(namespace = %namespace,
+                "Rollback API deployment - failed, conflicting definitions: {}",
+                conflicting_definitions
+            );
+            return Err(ApiDeploymentError::ApiDefinitionsConflict(
+                conflicting_definitions,
+            ));
+        }
+
+        let target_keys: HashSet<ApiDefinitionIdWithVersion> = target_revision
+            .api_definition_keys
+            .iter()
+            .cloned()
+            .collect();
+
+        let existing_keys: HashSet<ApiDefinitionIdWithVersion> = existing_deployment_records
+            .iter()
+            .map(|record| ApiDefinitionIdWithVersion {
+                id: record.definition_id.clone().into(),
+                version: record.definition_version.clone().into(),
+            })
+            .collect();
+
+        let deleted_deployment_records: Vec<ApiDeploymentRecord> = existing_deployment_records
+            .into_iter()
+            .filter(|record| {
+                !target_keys.contains(&ApiDefinitionIdWithVersion {
+                    id: record.definition_id.clone().into(),
+                    version: record.definition_version.clone().into(),
+                })
+            })
+            .collect();
+
+        let mut created_deployment_records: Vec<ApiDeploymentRecord> = vec![];
+
+        for definition in definitions.iter() {
+            let api_definition_key = ApiDefinitionIdWithVersion {
+                id: definition.id.clone(),
+                version: definition.version.clone(),
+            };
+
+            if !existing_keys.contains(&api_definition_key) {
+                created_deployment_records.push(ApiDeploymentRecord::new(
+                    namespace.clone(),
+                    target_revision.site.clone(),
+                    api_definition_key,
+                    created_at,
+                ));
+            }
+        }
+
+        // Constraints are kept by the component service, so they are updated before
+        // the deployment change is applied
+        let constraints = Self::get_worker_functions_in_api_definitions(definitions)?;
+
+        for (component_id, constraints) in constraints {
+            self.component_service
+                .create_or_update_constraints(&component_id, constraints, auth_ctx)
+                .await
+                .map_err(|err| {
+                    ApiDeploymentError::ComponentConstraintCreateError(err.to_safe_string())
+                })?;
+        }
+
+        // Definitions updated or deleted since the revision are restored to their content at the revision
+        let mut change = ApiDeploymentChangeRecord::new(
+            namespace.clone(),
+            target_revision.site,
+            ApiDeploymentChange::Rollback,
+            Some(revision),
+            auth_ctx.principal(),
+            created_at,
+        );
+        change.deleted = deleted_deployment_records;
+        change.created = created_deployment_records;
+        change.restored = definition_records;
+
+        self.apply_change(change).await
+    }
+
+    async fn diff_revisions(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSiteString,
+        from_revision: u64,
+        to_revision: u64,
+    ) -> Result<ApiDeploymentRevisionDiff, ApiDeploymentError<Namespace>> {
+        info!(namespace = %namespace, "Diff API deployment revisions");
+
+        // Make sure both revisions belong to the namespace
+        self.get_revision(namespace, site, from_revision).await?;
+        self.get_revision(namespace, site, to_revision).await?;
+
+        let from_routes = Self::get_routes_by_key(Self::compile_revision_definitions(
+            self.get_revision_definitions(site, from_revision).await?,
+        )?);
+        let to_routes = Self::get_routes_by_key(Self::compile_revision_definitions(
+            self.get_revision_definitions(site, to_revision).await?,
+        )?);
+
+        let mut added_routes = vec![];
+        let mut changed_routes = vec![];
+
+        for (key, route) in to_routes.iter() {
+            match from_routes.get(key) {
+                None => added_routes.push(key.clone()),
+                Some(previous) if previous != route => changed_routes.push(key.clone()),
+                Some(_) => {}
+            }
+        }
+
+        let removed_routes = from_routes
+            .keys()
+            .filter(|key| !to_routes.contains_key(*key))
+            .cloned()
+            .collect();
+
+        Ok(ApiDeploymentRevisionDiff {
+            from_revision,
+            to_revision,
+            added_routes: Self::sorted_route_keys(added_routes),
+            removed_routes: Self::sorted_route_keys(removed_routes),
+            changed_routes: Self::sorted_route_keys(changed_routes),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -20,6 +20,7 @@ use golem_service_base::model::{Component, VersionedComponentId};
 use serde::{Deserialize, Serialize};
 
 use crate::gateway_api_definition::http::{HttpApiDefinition, MethodPattern, Route};
+use crate::gateway_binding::GatewayBinding;
 use crate::gateway_execution::router::{Router, RouterPattern};
 use crate::service::gateway::api_definition_validator::{
     ApiDefinitionValidatorService, ValidationErrors,
@@ -65,7 +66,10 @@ impl ApiDefinitionValidatorService<HttpApiDefinition> for HttpApiDefinitionValid
         api: &HttpApiDefinition,
         _components: &[Component],
     ) -> Result<(), ValidationErrors> {
-        let errors = unique_routes(api.routes.as_slice());
+        let mut errors = unique_routes(api.routes.as_slice());
+        errors.extend(cached_file_server_routes(api.routes.as_slice()));
+        errors.extend(cached_secured_routes(api.routes.as_slice()));
+        errors.extend(cached_unsafe_method_routes(api.routes.as_slice()));
         let errors_string = errors.iter().map(|x| x.to_string()).collect::<Vec<_>>();
 
         if errors_string.is_empty() {
@@ -110,16 +114,77 @@ fn unique_routes(routes: &[Route]) -> Vec<RouteValidationError> {
     errors
 }
 
+// Responses of file server bindings are streamed from the worker's files and are never cached
+fn cached_file_server_routes(routes: &[Route]) -> Vec<RouteValidationError> {
+    routes
+        .iter()
+        .filter_map(|route| match &route.binding {
+            GatewayBinding::FileServer(binding) if binding.response_cache.is_some() => {
+                Some(RouteValidationError {
+                    method: route.method.clone(),
+                    path: route.path.to_string(),
+                    component: Some(binding.component_id.clone()),
+                    detail: "Response cache is not supported for file server bindings".to_string(),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+// Responses of secured routes belong to the authenticated user, while the cached responses are
+// shared by every caller of the route with the same cache key
+fn cached_secured_routes(routes: &[Route]) -> Vec<RouteValidationError> {
+    routes
+        .iter()
+        .filter_map(|route| {
+            let binding = route.binding.get_worker_binding()?;
+            let secured = route.middlewares.as_ref().is_some_and(|middlewares| {
+                middlewares.get_http_authentication_middleware().is_some()
+            });
+
+            (binding.response_cache.is_some() && secured).then(|| RouteValidationError {
+                method: route.method.clone(),
+                path: route.path.to_string(),
+                component: Some(binding.component_id),
+                detail: "Response cache is not supported for secured routes".to_string(),
+            })
+        })
+        .collect()
+}
+
+// Only the responses of safe methods can be served from the cache, other requests are expected
+// to change the state of the worker
+fn cached_unsafe_method_routes(routes: &[Route]) -> Vec<RouteValidationError> {
+    routes
+        .iter()
+        .filter_map(|route| {
+            let binding = route.binding.get_worker_binding()?;
+            let safe_method = matches!(route.method, MethodPattern::Get | MethodPattern::Head);
+
+            (binding.response_cache.is_some() && !safe_method).then(|| RouteValidationError {
+                method: route.method.clone(),
+                path: route.path.to_string(),
+                component: Some(binding.component_id),
+                detail: "Response cache is only supported for GET and HEAD routes".to_string(),
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use test_r::test;
 
     use crate::gateway_api_definition::http::{MethodPattern, Route};
-    use crate::gateway_binding::{GatewayBinding, ResponseMapping};
-    use crate::service::gateway::http_api_definition_validator::unique_routes;
+    use crate::gateway_binding::{GatewayBinding, ResponseCache, ResponseMapping};
+    use crate::service::gateway::http_api_definition_validator::{
+        cached_file_server_routes, cached_unsafe_method_routes, unique_routes,
+    };
     use golem_common::model::ComponentId;
     use golem_service_base::model::VersionedComponentId;
     use rib::Expr;
+    use std::time::Duration;
 
     #[test]
     fn test_unique_routes() {
@@ -135,6 +200,9 @@ mod tests {
                     worker_name: Some(Expr::identifier("request")),
                     idempotency_key: None,
                     response_mapping: ResponseMapping(Expr::literal("sample")),
+                    retry_policy: None,
+                    invocation_timeout: None,
+                    response_cache: None,
                 }),
                 middlewares: None,
             }
@@ -169,4 +237,83 @@ mod tests {
         assert!(errors.len() == 1);
         assert!(errors[0].detail.contains(paths[0]), "Received: {errors:?}");
     }
+
+    #[test]
+    fn test_cached_file_server_routes() {
+        fn make_route(path: &str, file_server: bool, cached: bool) -> Route {
+            let binding = crate::gateway_binding::WorkerBinding {
+                component_id: VersionedComponentId {
+                    component_id: ComponentId::new_v4(),
+                    version: 1,
+                },
+                worker_name: Some(Expr::identifier("request")),
+                idempotency_key: None,
+                response_mapping: ResponseMapping(Expr::literal("sample")),
+                retry_policy: None,
+                invocation_timeout: None,
+                response_cache: cached.then(|| ResponseCache {
+                    cache_key: Expr::literal("key"),
+                    ttl: Duration::from_secs(60),
+                }),
+            };
+
+            Route {
+                method: MethodPattern::Get,
+                path: crate::gateway_api_definition::http::AllPathPatterns::parse(path).unwrap(),
+                binding: if file_server {
+                    GatewayBinding::FileServer(binding)
+                } else {
+                    GatewayBinding::Default(binding)
+                },
+                middlewares: None,
+            }
+        }
+
+        let routes = [
+            make_route("/files/{name}", true, false),
+            make_route("/users/{id}", false, true),
+            make_route("/cached-files/{name}", true, true),
+        ];
+
+        let errors = cached_file_server_routes(&routes);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, "/cached-files/{name}");
+    }
+
+    #[test]
+    fn test_cached_unsafe_method_routes() {
+        fn make_route(method: MethodPattern, path: &str, cached: bool) -> Route {
+            Route {
+                method,
+                path: crate::gateway_api_definition::http::AllPathPatterns::parse(path).unwrap(),
+                binding: GatewayBinding::Default(crate::gateway_binding::WorkerBinding {
+                    component_id: VersionedComponentId {
+                        component_id: ComponentId::new_v4(),
+                        version: 1,
+                    },
+                    worker_name: Some(Expr::identifier("request")),
+                    idempotency_key: None,
+                    response_mapping: ResponseMapping(Expr::literal("sample")),
+                    retry_policy: None,
+                    invocation_timeout: None,
+                    response_cache: cached.then(|| ResponseCache {
+                        cache_key: Expr::literal("key"),
+                        ttl: Duration::from_secs(60),
+                    }),
+                }),
+                middlewares: None,
+            }
+        }
+
+        let routes = [
+            make_route(MethodPattern::Get, "/users/{id}", true),
+            make_route(MethodPattern::Head, "/users/{id}", true),
+            make_route(MethodPattern::Post, "/users/{id}", false),
+            make_route(MethodPattern::Put, "/users/{id}", true),
+        ];
+
+        let errors = cached_unsafe_method_routes(&routes);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].method, MethodPattern::Put);
+    }
 }
@@ -14,6 +14,7 @@
 
 pub mod component;
 pub mod gateway;
+pub mod topic;
 pub mod worker;
 
 pub fn with_metadata<T, I, K, V>(request: T, metadata: I) -> tonic::Request<T>
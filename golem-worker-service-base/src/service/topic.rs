@@ -0,0 +1,846 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::app_config::TopicsConfig;
+use crate::empty_worker_metadata;
+use crate::repo::topic::{TopicMessageRecord, TopicRecord, TopicRepo, TopicSubscriptionRecord};
+use crate::service::component::ComponentService;
+use crate::service::worker::WorkerService;
+use async_trait::async_trait;
+use bytes::{BufMut, BytesMut};
+use chrono::{DateTime, Utc};
+use golem_api_grpc::proto::golem::common::{ErrorBody, ErrorsBody};
+use golem_api_grpc::proto::golem::worker::v1::{
+    worker_error, worker_execution_error, UnknownError, WorkerError as GrpcWorkerError,
+    WorkerExecutionError,
+};
+use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode, SimpleCache};
+use golem_common::config::RetryConfig;
+use golem_common::model::exports::function_by_name;
+use golem_common::model::{
+    ComponentId, IdempotencyKey, InvocationOptions, TargetWorkerId, WorkerId,
+};
+use golem_common::retries::get_delay;
+use golem_common::SafeDisplay;
+use golem_service_base::auth::EmptyAuthCtx;
+use golem_service_base::repo::RepoError;
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use golem_wasm_rpc::{TypeAnnotatedValueConstructors, Value};
+use prost::Message;
+use rib::{RibByteCode, RibInput};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+/// The name of the global variable holding the published message in subscription filters
+pub const FILTER_MESSAGE_VARIABLE: &str = "message";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Topic {
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TopicRecord> for Topic {
+    fn from(value: TopicRecord) -> Self {
+        Self {
+            name: value.name,
+            created_at: value.created_at,
+        }
+    }
+}
+
+/// A worker's exported function receiving the messages published to a topic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicSubscription {
+    pub subscription_id: Uuid,
+    pub topic: String,
+    pub worker_id: WorkerId,
+    pub function_name: String,
+    /// Optional Rib expression evaluating to a boolean, with the published message
+    /// bound to the `message` variable
+    pub filter: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryFrom<TopicSubscriptionRecord> for TopicSubscription {
+    type Error = String;
+
+    fn try_from(value: TopicSubscriptionRecord) -> Result<Self, Self::Error> {
+        Ok(Self {
+            subscription_id: Uuid::parse_str(&value.subscription_id)
+                .map_err(|err| format!("Invalid subscription id: {err}"))?,
+            topic: value.topic,
+            worker_id: WorkerId {
+                component_id: ComponentId(
+                    Uuid::parse_str(&value.component_id).map_err(|err| err.to_string())?,
+                ),
+                worker_name: value.worker_name,
+            },
+            function_name: value.function_name,
+            filter: value.filter,
+            created_at: value.created_at,
+        })
+    }
+}
+
+/// A message published to a topic
+#[derive(Debug, Clone, PartialEq)]
+pub enum TopicMessage {
+    /// Published through the REST API, with its type
+    Typed(TypeAnnotatedValue),
+    /// Published by a worker through the `golem:api` host interface. The value gets its type from the
+    /// first parameter of each subscribed function when delivered.
+    Untyped(Value),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicSubscriptionRequest {
+    pub worker_id: WorkerId,
+    pub function_name: String,
+    pub filter: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TopicServiceError {
+    #[error("Topic not found: {0}")]
+    TopicNotFound(String),
+    #[error("Topic already exists: {0}")]
+    TopicAlreadyExists(String),
+    #[error("Subscription not found: {0}")]
+    SubscriptionNotFound(Uuid),
+    #[error("Invalid subscription filter: {0}")]
+    InvalidFilter(String),
+    #[error("Internal repository error: {0}")]
+    InternalRepoError(RepoError),
+    #[error("Internal error: {0}")]
+    Internal(String),
+}
+
+impl From<RepoError> for TopicServiceError {
+    fn from(error: RepoError) -> Self {
+        TopicServiceError::InternalRepoError(error)
+    }
+}
+
+impl SafeDisplay for TopicServiceError {
+    fn to_safe_string(&self) -> String {
+        match self {
+            TopicServiceError::TopicNotFound(_) => self.to_string(),
+            TopicServiceError::TopicAlreadyExists(_) => self.to_string(),
+            TopicServiceError::SubscriptionNotFound(_) => self.to_string(),
+            TopicServiceError::InvalidFilter(_) => self.to_string(),
+            TopicServiceError::InternalRepoError(inner) => inner.to_safe_string(),
+            TopicServiceError::Internal(_) => self.to_string(),
+        }
+    }
+}
+
+impl From<TopicServiceError> for GrpcWorkerError {
+    fn from(error: TopicServiceError) -> Self {
+        let error = match error {
+            TopicServiceError::TopicNotFound(_) | TopicServiceError::SubscriptionNotFound(_) => {
+                worker_error::Error::NotFound(ErrorBody {
+                    error: error.to_safe_string(),
+                })
+            }
+            TopicServiceError::TopicAlreadyExists(_) => {
+                worker_error::Error::AlreadyExists(ErrorBody {
+                    error: error.to_safe_string(),
+                })
+            }
+            TopicServiceError::InvalidFilter(_) => worker_error::Error::BadRequest(ErrorsBody {
+                errors: vec![error.to_safe_string()],
+            }),
+            TopicServiceError::InternalRepoError(_) | TopicServiceError::Internal(_) => {
+                worker_error::Error::InternalError(WorkerExecutionError {
+                    error: Some(worker_execution_error::Error::Unknown(UnknownError {
+                        details: error.to_safe_string(),
+                    })),
+                })
+            }
+        };
+        GrpcWorkerError { error: Some(error) }
+    }
+}
+
+/// Named topics workers can subscribe to.
+///
+/// Messages published to a topic are delivered at least once to each subscription, by enqueueing
+/// an invocation of the subscribed function with an idempotency key derived from the message id.
+/// Failed deliveries are retried with an exponential backoff, until the message is moved to the
+/// dead-letter state after the configured maximum number of attempts.
+#[async_trait]
+pub trait TopicService<Namespace> {
+    async fn create(&self, namespace: &Namespace, name: &str) -> Result<Topic, TopicServiceError>;
+
+    async fn get(&self, namespace: &Namespace, name: &str) -> Result<Topic, TopicServiceError>;
+
+    async fn get_all(&self, namespace: &Namespace) -> Result<Vec<Topic>, TopicServiceError>;
+
+    async fn delete(&self, namespace: &Namespace, name: &str) -> Result<(), TopicServiceError>;
+
+    async fn subscribe(
+        &self,
+        namespace: &Namespace,
+        topic: &str,
+        request: TopicSubscriptionRequest,
+    ) -> Result<TopicSubscription, TopicServiceError>;
+
+    async fn get_subscriptions(
+        &self,
+        namespace: &Namespace,
+        topic: &str,
+    ) -> Result<Vec<TopicSubscription>, TopicServiceError>;
+
+    async fn unsubscribe(
+        &self,
+        namespace: &Namespace,
+        topic: &str,
+        subscription_id: &Uuid,
+    ) -> Result<(), TopicServiceError>;
+
+    /// Stores the message and delivers it to the topic's subscriptions, returning the message id.
+    /// Messages which could not be delivered to every subscription are retried by `redeliver`.
+    async fn publish(
+        &self,
+        namespace: &Namespace,
+        topic: &str,
+        message: TypeAnnotatedValue,
+    ) -> Result<Uuid, TopicServiceError>;
+
+    /// Publishes an untyped message with a message id chosen by the publisher, so retried
+    /// publications are stored and delivered only once
+    async fn publish_untyped(
+        &self,
+        namespace: &Namespace,
+        topic: &str,
+        message_id: Uuid,
+        message: Value,
+    ) -> Result<Uuid, TopicServiceError>;
+
+    /// Retries the delivery of the undelivered messages whose next attempt is due
+    async fn redeliver(&self) -> Result<(), TopicServiceError>;
+
+    /// Deletes the delivered and dead-lettered messages older than the configured retention
+    async fn purge_completed_messages(&self) -> Result<(), TopicServiceError>;
+}
+
+pub struct DefaultTopicService<Namespace> {
+    repo: Arc<dyn TopicRepo + Sync + Send>,
+    worker_service: Arc<dyn WorkerService + Sync + Send>,
+    component_service: Arc<dyn ComponentService<EmptyAuthCtx> + Sync + Send>,
+    config: TopicsConfig,
+    filter_cache: Cache<Uuid, (), RibByteCode, String>,
+    namespace: PhantomData<Namespace>,
+}
+
+impl<Namespace> DefaultTopicService<Namespace> {
+    pub fn new(
+        repo: Arc<dyn TopicRepo + Sync + Send>,
+        worker_service: Arc<dyn WorkerService + Sync + Send>,
+        component_service: Arc<dyn ComponentService<EmptyAuthCtx> + Sync + Send>,
+        config: TopicsConfig,
+    ) -> Self {
+        Self {
+            repo,
+            worker_service,
+            component_service,
+            filter_cache: Cache::new(
+                Some(config.filter_cache_capacity),
+                FullCacheEvictionMode::LeastRecentlyUsed(1),
+                BackgroundEvictionMode::None,
+                "topic_subscription_filter",
+            ),
+            config,
+            namespace: PhantomData,
+        }
+    }
+
+    async fn get_topic(&self, namespace: &str, name: &str) -> Result<Topic, TopicServiceError> {
+        match self.repo.get(namespace, name).await? {
+            Some(record) => Ok(record.into()),
+            None => Err(TopicServiceError::TopicNotFound(name.to_string())),
+        }
+    }
+
+    async fn get_topic_subscriptions(
+        &self,
+        namespace: &str,
+        topic: &str,
+    ) -> Result<Vec<TopicSubscription>, TopicServiceError> {
+        self.repo
+            .get_subscriptions(namespace, topic)
+            .await?
+            .into_iter()
+            .map(|record| TopicSubscription::try_from(record).map_err(TopicServiceError::Internal))
+            .collect()
+    }
+
+    /// Enqueues an invocation for each matching subscription, returning whether all of them succeeded
+    async fn deliver(
+        &self,
+        namespace: &str,
+        topic: &str,
+        message_id: &Uuid,
+        message: &TopicMessage,
+    ) -> Result<bool, TopicServiceError> {
+        let subscriptions = self.get_topic_subscriptions(namespace, topic).await?;
+
+        let mut delivered = true;
+        for subscription in subscriptions {
+            let result = self
+                .deliver_to_subscription(&subscription, message_id, message)
+                .await;
+
+            if let Err(err) = result {
+                warn!(
+                    topic = topic,
+                    message_id = message_id.to_string(),
+                    worker_id = subscription.worker_id.to_string(),
+                    "Failed to deliver message: {err}"
+                );
+                delivered = false;
+            }
+        }
+
+        Ok(delivered)
+    }
+
+    async fn deliver_to_subscription(
+        &self,
+        subscription: &TopicSubscription,
+        message_id: &Uuid,
+        message: &TopicMessage,
+    ) -> Result<(), String> {
+        let message = match message {
+            TopicMessage::Typed(message) => message.clone(),
+            TopicMessage::Untyped(message) => self.type_message(subscription, message).await?,
+        };
+
+        if !self.matches_filter(subscription, &message).await {
+            return Ok(());
+        }
+
+        let idempotency_key = IdempotencyKey::from_uuid(Uuid::new_v5(
+            message_id,
+            subscription.subscription_id.as_bytes(),
+        ));
+
+        self.worker_service
+            .validate_and_invoke(
+                &TargetWorkerId::from(&subscription.worker_id),
+                Some(idempotency_key),
+                subscription.function_name.clone(),
+                vec![message],
+                None,
+                InvocationOptions::default(),
+                empty_worker_metadata(),
+            )
+            .await
+            .map_err(|err| err.to_safe_string())
+    }
+
+    /// Types an untyped message with the first parameter of the subscribed function
+    async fn type_message(
+        &self,
+        subscription: &TopicSubscription,
+        message: &Value,
+    ) -> Result<TypeAnnotatedValue, String> {
+        let component = self
+            .component_service
+            .get_latest(&subscription.worker_id.component_id, &EmptyAuthCtx())
+            .await
+            .map_err(|err| err.to_safe_string())?;
+
+        let function = function_by_name(&component.metadata.exports, &subscription.function_name)?
+            .ok_or_else(|| {
+                format!(
+                    "Subscribed function {} not found",
+                    subscription.function_name
+                )
+            })?;
+
+        let parameter = function.parameters.first().ok_or_else(|| {
+            format!(
+                "Subscribed function {} has no parameters",
+                subscription.function_name
+            )
+        })?;
+
+        TypeAnnotatedValue::create(message, &parameter.typ).map_err(|errors| errors.join(", "))
+    }
+
+    /// Evaluates the subscription's filter on the message. Messages failing the evaluation are not delivered.
+    async fn matches_filter(
+        &self,
+        subscription: &TopicSubscription,
+        message: &TypeAnnotatedValue,
+    ) -> bool {
+        let Some(filter) = &subscription.filter else {
+            return true;
+        };
+
+        let byte_code = self
+            .filter_cache
+            .get_or_insert_simple(&subscription.subscription_id, || {
+                let filter = filter.clone();
+                Box::pin(async move { compile_filter(&filter) })
+            })
+            .await;
+
+        let byte_code = match byte_code {
+            Ok(byte_code) => byte_code,
+            Err(err) => {
+                warn!(
+                    subscription_id = subscription.subscription_id.to_string(),
+                    "Failed to compile subscription filter: {err}"
+                );
+                return false;
+            }
+        };
+
+        let input = RibInput::new(HashMap::from([(
+            FILTER_MESSAGE_VARIABLE.to_string(),
+            message.clone(),
+        )]));
+
+        match rib::interpret_pure(&byte_code, &input).await {
+            Ok(result) => result.get_bool().unwrap_or(false),
+            Err(err) => {
+                warn!(
+                    subscription_id = subscription.subscription_id.to_string(),
+                    "Failed to evaluate subscription filter: {err}"
+                );
+                false
+            }
+        }
+    }
+
+    /// Stores a new message and attempts its first delivery
+    async fn store_and_deliver(
+        &self,
+        namespace: String,
+        topic: &str,
+        message_id: Uuid,
+        message: TopicMessage,
+    ) -> Result<(), TopicServiceError> {
+        let now = Utc::now();
+        let record = TopicMessageRecord {
+            namespace,
+            topic: topic.to_string(),
+            message_id: message_id.to_string(),
+            payload: message_serde::serialize(&message),
+            delivered: false,
+            dead_letter: false,
+            attempts: 0,
+            published_at: now,
+            next_attempt_at: now,
+            completed_at: None,
+        };
+
+        if !self.repo.create_message(&record).await? {
+            debug!(
+                topic = topic,
+                message_id = record.message_id,
+                "Message has already been published"
+            );
+            return Ok(());
+        }
+
+        self.attempt_delivery(&record, &message_id, &message).await
+    }
+
+    /// Delivers the message and records the outcome of the attempt
+    async fn attempt_delivery(
+        &self,
+        record: &TopicMessageRecord,
+        message_id: &Uuid,
+        message: &TopicMessage,
+    ) -> Result<(), TopicServiceError> {
+        let delivered = self
+            .deliver(&record.namespace, &record.topic, message_id, message)
+            .await?;
+
+        let now = Utc::now();
+        if delivered {
+            self.repo
+                .set_message_delivered(&record.message_id, now)
+                .await?;
+        } else {
+            match failed_delivery(&self.config.delivery_retries, record.attempts, now) {
+                FailedDelivery::Retry {
+                    attempts,
+                    next_attempt_at,
+                } => {
+                    self.repo
+                        .set_message_retry(&record.message_id, attempts, next_attempt_at)
+                        .await?;
+                }
+                FailedDelivery::DeadLetter { attempts } => {
+                    warn!(
+                        topic = record.topic,
+                        message_id = record.message_id,
+                        attempts = attempts,
+                        "Giving up the delivery of the message, moving it to the dead-letter state"
+                    );
+                    self.repo
+                        .set_message_dead_letter(&record.message_id, attempts, now)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Namespace: Display + Send + Sync> TopicService<Namespace> for DefaultTopicService<Namespace> {
+    async fn create(&self, namespace: &Namespace, name: &str) -> Result<Topic, TopicServiceError> {
+        let namespace = namespace.to_string();
+
+        if self.repo.get(&namespace, name).await?.is_some() {
+            return Err(TopicServiceError::TopicAlreadyExists(name.to_string()));
+        }
+
+        let record = TopicRecord {
+            namespace,
+            name: name.to_string(),
+            created_at: Utc::now(),
+        };
+        self.repo.create(&record).await?;
+
+        Ok(record.into())
+    }
+
+    async fn get(&self, namespace: &Namespace, name: &str) -> Result<Topic, TopicServiceError> {
+        self.get_topic(&namespace.to_string(), name).await
+    }
+
+    async fn get_all(&self, namespace: &Namespace) -> Result<Vec<Topic>, TopicServiceError> {
+        let records = self.repo.get_all(&namespace.to_string()).await?;
+        Ok(records.into_iter().map(|record| record.into()).collect())
+    }
+
+    async fn delete(&self, namespace: &Namespace, name: &str) -> Result<(), TopicServiceError> {
+        if self.repo.delete(&namespace.to_string(), name).await? {
+            Ok(())
+        } else {
+            Err(TopicServiceError::TopicNotFound(name.to_string()))
+        }
+    }
+
+    async fn subscribe(
+        &self,
+        namespace: &Namespace,
+        topic: &str,
+        request: TopicSubscriptionRequest,
+    ) -> Result<TopicSubscription, TopicServiceError> {
+        let namespace = namespace.to_string();
+        self.get_topic(&namespace, topic).await?;
+
+        if let Some(filter) = &request.filter {
+            // Only validating here, the compiled filters are cached by subscription when delivering
+            compile_filter(filter).map_err(TopicServiceError::InvalidFilter)?;
+        }
+
+        let subscription = TopicSubscription {
+            subscription_id: Uuid::new_v4(),
+            topic: topic.to_string(),
+            worker_id: request.worker_id,
+            function_name: request.function_name,
+            filter: request.filter,
+            created_at: Utc::now(),
+        };
+
+        self.repo
+            .create_subscription(&TopicSubscriptionRecord {
+                namespace,
+                topic: subscription.topic.clone(),
+                subscription_id: subscription.subscription_id.to_string(),
+                component_id: subscription.worker_id.component_id.to_string(),
+                worker_name: subscription.worker_id.worker_name.clone(),
+                function_name: subscription.function_name.clone(),
+                filter: subscription.filter.clone(),
+                created_at: subscription.created_at,
+            })
+            .await?;
+
+        Ok(subscription)
+    }
+
+    async fn get_subscriptions(
+        &self,
+        namespace: &Namespace,
+        topic: &str,
+    ) -> Result<Vec<TopicSubscription>, TopicServiceError> {
+        let namespace = namespace.to_string();
+        self.get_topic(&namespace, topic).await?;
+        self.get_topic_subscriptions(&namespace, topic).await
+    }
+
+    async fn unsubscribe(
+        &self,
+        namespace: &Namespace,
+        topic: &str,
+        subscription_id: &Uuid,
+    ) -> Result<(), TopicServiceError> {
+        let deleted = self
+            .repo
+            .delete_subscription(&namespace.to_string(), topic, &subscription_id.to_string())
+            .await?;
+
+        if deleted {
+            self.filter_cache.remove(subscription_id);
+            Ok(())
+        } else {
+            Err(TopicServiceError::SubscriptionNotFound(*subscription_id))
+        }
+    }
+
+    async fn publish(
+        &self,
+        namespace: &Namespace,
+        topic: &str,
+        message: TypeAnnotatedValue,
+    ) -> Result<Uuid, TopicServiceError> {
+        let namespace = namespace.to_string();
+        self.get_topic(&namespace, topic).await?;
+
+        let message_id = Uuid::new_v4();
+        self.store_and_deliver(namespace, topic, message_id, TopicMessage::Typed(message))
+            .await?;
+
+        Ok(message_id)
+    }
+
+    async fn publish_untyped(
+        &self,
+        namespace: &Namespace,
+        topic: &str,
+        message_id: Uuid,
+        message: Value,
+    ) -> Result<Uuid, TopicServiceError> {
+        let namespace = namespace.to_string();
+        self.get_topic(&namespace, topic).await?;
+
+        self.store_and_deliver(namespace, topic, message_id, TopicMessage::Untyped(message))
+            .await?;
+
+        Ok(message_id)
+    }
+
+    async fn redeliver(&self) -> Result<(), TopicServiceError> {
+        let messages = self
+            .repo
+            .get_undelivered_messages(Utc::now(), self.config.redelivery_batch_size)
+            .await?;
+
+        for record in messages {
+            debug!(
+                topic = record.topic,
+                message_id = record.message_id,
+                attempts = record.attempts,
+                "Redelivering message"
+            );
+
+            let message_id = Uuid::parse_str(&record.message_id)
+                .map_err(|err| TopicServiceError::Internal(err.to_string()))?;
+            let message =
+                message_serde::deserialize(&record.payload).map_err(TopicServiceError::Internal)?;
+
+            self.attempt_delivery(&record, &message_id, &message)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn purge_completed_messages(&self) -> Result<(), TopicServiceError> {
+        let retention = chrono::Duration::from_std(self.config.message_retention)
+            .map_err(|err| TopicServiceError::Internal(err.to_string()))?;
+
+        let deleted = self
+            .repo
+            .delete_completed_messages(Utc::now() - retention)
+            .await?;
+        if deleted > 0 {
+            debug!("Deleted {deleted} completed topic messages");
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FailedDelivery {
+    Retry {
+        attempts: i32,
+        next_attempt_at: DateTime<Utc>,
+    },
+    DeadLetter {
+        attempts: i32,
+    },
+}
+
+/// Decides what happens to a message after a failed delivery attempt, given the number of
+/// previously failed attempts
+fn failed_delivery(
+    retries: &RetryConfig,
+    previous_attempts: i32,
+    now: DateTime<Utc>,
+) -> FailedDelivery {
+    let attempts = previous_attempts.saturating_add(1);
+    let delay = get_delay(retries, attempts as u64)
+        .and_then(|delay| chrono::Duration::from_std(delay).ok());
+
+    match delay {
+        Some(delay) => FailedDelivery::Retry {
+            attempts,
+            next_attempt_at: now + delay,
+        },
+        None => FailedDelivery::DeadLetter { attempts },
+    }
+}
+
+fn compile_filter(filter: &str) -> Result<rib::RibByteCode, String> {
+    let expr = rib::from_string(filter)?;
+    let compiled = rib::compile_with_limited_globals(
+        &expr,
+        &vec![],
+        Some(vec![FILTER_MESSAGE_VARIABLE.to_string()]),
+    )?;
+    Ok(compiled.byte_code)
+}
+
+mod message_serde {
+    use super::*;
+
+    pub const SERIALIZATION_VERSION_V1: u8 = 1u8;
+    pub const SERIALIZATION_VERSION_V2: u8 = 2u8;
+
+    pub fn serialize(message: &TopicMessage) -> Vec<u8> {
+        let mut bytes = BytesMut::new();
+        match message {
+            TopicMessage::Typed(value) => {
+                let proto_value = golem_wasm_rpc::protobuf::TypeAnnotatedValue {
+                    type_annotated_value: Some(value.clone()),
+                };
+                bytes.put_u8(SERIALIZATION_VERSION_V1);
+                bytes.extend_from_slice(&proto_value.encode_to_vec());
+            }
+            TopicMessage::Untyped(value) => {
+                let proto_value = golem_wasm_rpc::protobuf::Val::from(value.clone());
+                bytes.put_u8(SERIALIZATION_VERSION_V2);
+                bytes.extend_from_slice(&proto_value.encode_to_vec());
+            }
+        }
+        bytes.to_vec()
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> Result<TopicMessage, String> {
+        let (version, data) = bytes.split_at(1);
+
+        match version[0] {
+            SERIALIZATION_VERSION_V1 => {
+                let proto_value: golem_wasm_rpc::protobuf::TypeAnnotatedValue =
+                    Message::decode(data)
+                        .map_err(|e| format!("Failed to deserialize value: {e}"))?;
+                proto_value
+                    .type_annotated_value
+                    .map(TopicMessage::Typed)
+                    .ok_or("Missing type annotated value".to_string())
+            }
+            SERIALIZATION_VERSION_V2 => {
+                let proto_value: golem_wasm_rpc::protobuf::Val = Message::decode(data)
+                    .map_err(|e| format!("Failed to deserialize value: {e}"))?;
+                Value::try_from(proto_value).map(TopicMessage::Untyped)
+            }
+            _ => Err("Unsupported serialization version".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use golem_wasm_ast::analysis::analysed_type::{field, record, str, u32};
+    use std::time::Duration;
+    use test_r::test;
+
+    fn retries() -> RetryConfig {
+        RetryConfig {
+            max_attempts: 3,
+            min_delay: Duration::from_secs(10),
+            max_delay: Duration::from_secs(60),
+            multiplier: 2.0,
+            max_jitter_factor: None,
+        }
+    }
+
+    #[test]
+    fn failed_delivery_backs_off_until_max_attempts() {
+        let now = Utc::now();
+
+        assert_eq!(
+            failed_delivery(&retries(), 0, now),
+            FailedDelivery::Retry {
+                attempts: 1,
+                next_attempt_at: now + chrono::Duration::seconds(10),
+            }
+        );
+        assert_eq!(
+            failed_delivery(&retries(), 1, now),
+            FailedDelivery::Retry {
+                attempts: 2,
+                next_attempt_at: now + chrono::Duration::seconds(20),
+            }
+        );
+        assert_eq!(
+            failed_delivery(&retries(), 2, now),
+            FailedDelivery::DeadLetter { attempts: 3 }
+        );
+    }
+
+    #[test]
+    fn typed_message_roundtrip() {
+        let message = TopicMessage::Typed(
+            TypeAnnotatedValue::create(
+                &Value::Record(vec![Value::String("order-1".to_string()), Value::U32(3)]),
+                &record(vec![field("id", str()), field("quantity", u32())]),
+            )
+            .unwrap(),
+        );
+
+        let bytes = message_serde::serialize(&message);
+        assert_eq!(bytes[0], message_serde::SERIALIZATION_VERSION_V1);
+        assert_eq!(message_serde::deserialize(&bytes).unwrap(), message);
+    }
+
+    #[test]
+    fn untyped_message_roundtrip() {
+        let message = TopicMessage::Untyped(Value::Record(vec![
+            Value::String("order-1".to_string()),
+            Value::U32(3),
+        ]));
+
+        let bytes = message_serde::serialize(&message);
+        assert_eq!(bytes[0], message_serde::SERIALIZATION_VERSION_V2);
+        assert_eq!(message_serde::deserialize(&bytes).unwrap(), message);
+    }
+}
@@ -26,9 +26,10 @@ use golem_api_grpc::proto::golem::worker::{InvocationContext, InvokeResult};
 use golem_api_grpc::proto::golem::workerexecutor;
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_client::WorkerExecutorClient;
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
-    ActivatePluginRequest, CompletePromiseRequest, ConnectWorkerRequest, CreateWorkerRequest,
-    DeactivatePluginRequest, InterruptWorkerRequest, InvokeAndAwaitWorkerRequest,
-    ResumeWorkerRequest, SearchOplogResponse, UpdateWorkerRequest,
+    ActivatePluginRequest, CancelInvocationRequest, CompletePromiseRequest, ConnectWorkerRequest,
+    CreateWorkerRequest, DeactivatePluginRequest, InterruptWorkerRequest,
+    InvokeAndAwaitWorkerRequest, ResumeWorkerRequest, SearchOplogResponse,
+    UpdateWorkerLabelsRequest, UpdateWorkerRequest,
 };
 use golem_common::client::MultiTargetGrpcClient;
 use golem_common::config::RetryConfig;
@@ -36,12 +37,13 @@ use golem_common::model::oplog::OplogIndex;
 use golem_common::model::public_oplog::{OplogCursor, PublicOplogEntry};
 use golem_common::model::{
     AccountId, ComponentFilePath, ComponentFileSystemNode, ComponentId, ComponentVersion,
-    FilterComparator, IdempotencyKey, PluginInstallationId, PromiseId, ScanCursor, TargetWorkerId,
-    WorkerFilter, WorkerId, WorkerStatus,
+    FilterComparator, IdempotencyKey, InvocationOptions, PluginInstallationId, PromiseId,
+    ScanCursor, TargetWorkerId, WorkerFilter, WorkerId, WorkerStatus,
 };
 use golem_service_base::model::GolemError;
 use golem_service_base::model::{
-    GetOplogResponse, GolemErrorUnknown, PublicOplogEntryWithIndex, ResourceLimits, WorkerMetadata,
+    GetOplogResponse, GolemErrorUnknown, PublicOplogEntryWithIndex, ResourceLimits,
+    UpdateDryRunResult, WorkerMetadata,
 };
 use golem_service_base::service::routing_table::{HasRoutingTableService, RoutingTableService};
 use golem_wasm_ast::analysis::AnalysedFunctionResult;
@@ -63,6 +65,7 @@ pub trait WorkerService {
         component_version: u64,
         arguments: Vec<String>,
         environment_variables: HashMap<String, String>,
+        labels: HashMap<String, String>,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<WorkerId>;
 
@@ -92,6 +95,7 @@ pub trait WorkerService {
         function_name: String,
         params: Vec<TypeAnnotatedValue>,
         invocation_context: Option<InvocationContext>,
+        invocation_options: InvocationOptions,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<TypeAnnotatedValue> {
         let params = self.validate_typed_parameters(params)?;
@@ -101,6 +105,7 @@ pub trait WorkerService {
             function_name,
             params,
             invocation_context,
+            invocation_options,
             metadata,
         )
         .await
@@ -115,6 +120,7 @@ pub trait WorkerService {
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        invocation_options: InvocationOptions,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<TypeAnnotatedValue>;
 
@@ -127,6 +133,7 @@ pub trait WorkerService {
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        invocation_options: InvocationOptions,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<InvokeResult>;
 
@@ -139,6 +146,7 @@ pub trait WorkerService {
         function_name: String,
         params: Vec<TypeAnnotatedValue>,
         invocation_context: Option<InvocationContext>,
+        invocation_options: InvocationOptions,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<()> {
         let params = self.validate_typed_parameters(params)?;
@@ -148,6 +156,7 @@ pub trait WorkerService {
             function_name,
             params,
             invocation_context,
+            invocation_options,
             metadata,
         )
         .await
@@ -162,6 +171,7 @@ pub trait WorkerService {
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        invocation_options: InvocationOptions,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<()>;
 
@@ -210,6 +220,15 @@ pub trait WorkerService {
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<()>;
 
+    /// Replays the worker against the target version in a sandbox on its executor, without
+    /// affecting the worker
+    async fn dry_run_update(
+        &self,
+        worker_id: &WorkerId,
+        target_version: ComponentVersion,
+        metadata: WorkerRequestMetadata,
+    ) -> WorkerResult<UpdateDryRunResult>;
+
     async fn get_oplog(
         &self,
         worker_id: &WorkerId,
@@ -242,6 +261,31 @@ pub trait WorkerService {
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<Pin<Box<dyn Stream<Item = WorkerResult<Bytes>> + Send + 'static>>>;
 
+    /// Writes a file into the worker's filesystem, overwriting it if it already exists
+    async fn put_file(
+        &self,
+        worker_id: &TargetWorkerId,
+        path: ComponentFilePath,
+        content: Vec<u8>,
+        metadata: WorkerRequestMetadata,
+    ) -> WorkerResult<()>;
+
+    /// Deletes a file or an empty directory from the worker's filesystem
+    async fn delete_file(
+        &self,
+        worker_id: &TargetWorkerId,
+        path: ComponentFilePath,
+        metadata: WorkerRequestMetadata,
+    ) -> WorkerResult<()>;
+
+    /// Creates a directory in the worker's filesystem, together with its missing parents
+    async fn make_directory(
+        &self,
+        worker_id: &TargetWorkerId,
+        path: ComponentFilePath,
+        metadata: WorkerRequestMetadata,
+    ) -> WorkerResult<()>;
+
     async fn activate_plugin(
         &self,
         worker_id: &WorkerId,
@@ -255,6 +299,23 @@ pub trait WorkerService {
         plugin_installation_id: &PluginInstallationId,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<()>;
+
+    /// Replaces all labels of a worker
+    async fn update_labels(
+        &self,
+        worker_id: &WorkerId,
+        labels: HashMap<String, String>,
+        metadata: WorkerRequestMetadata,
+    ) -> WorkerResult<()>;
+
+    /// Removes a pending invocation from the worker's invocation queue if it has not been
+    /// started yet. Returns `true` if the invocation got cancelled.
+    async fn cancel_invocation(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: &IdempotencyKey,
+        metadata: WorkerRequestMetadata,
+    ) -> WorkerResult<bool>;
 }
 
 pub struct TypedResult {
@@ -316,6 +377,7 @@ impl WorkerService for WorkerServiceDefault {
         component_version: u64,
         arguments: Vec<String>,
         environment_variables: HashMap<String, String>,
+        labels: HashMap<String, String>,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<WorkerId> {
         let worker_id_clone = worker_id.clone();
@@ -330,6 +392,7 @@ impl WorkerService for WorkerServiceDefault {
                     component_version,
                     args: arguments.clone(),
                     env: environment_variables.clone(),
+                    labels: labels.clone(),
                     account_id: metadata.account_id.clone().map(|id| id.into()),
                     account_limits: metadata.limits.clone().map(|id| id.into()),
                 }))
@@ -442,6 +505,7 @@ impl WorkerService for WorkerServiceDefault {
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        invocation_options: InvocationOptions,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<TypeAnnotatedValue> {
         let worker_id = worker_id.clone();
@@ -462,6 +526,7 @@ impl WorkerService for WorkerServiceDefault {
                         account_id: metadata.account_id.clone().map(|id| id.into()),
                         account_limits: metadata.limits.clone().map(|id| id.into()),
                         context: invocation_context.clone(),
+                        options: Some(invocation_options.clone().into()),
                     }
                 )
                 )
@@ -505,6 +570,7 @@ impl WorkerService for WorkerServiceDefault {
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        invocation_options: InvocationOptions,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<InvokeResult> {
         let worker_id = worker_id.clone();
@@ -524,6 +590,7 @@ impl WorkerService for WorkerServiceDefault {
                         account_id: metadata.account_id.clone().map(|id| id.into()),
                         account_limits: metadata.limits.clone().map(|id| id.into()),
                         context: invocation_context.clone(),
+                        options: Some(invocation_options.clone().into()),
                     }
                 )
                 )
@@ -566,6 +633,7 @@ impl WorkerService for WorkerServiceDefault {
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        invocation_options: InvocationOptions,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<()> {
         let worker_id = worker_id.clone();
@@ -584,6 +652,7 @@ impl WorkerService for WorkerServiceDefault {
                         account_id: metadata.account_id.clone().map(|id| id.into()),
                         account_limits: metadata.limits.clone().map(|id| id.into()),
                         context: invocation_context.clone(),
+                        options: Some(invocation_options.clone().into()),
                     },
                 ))
             },
@@ -831,6 +900,51 @@ impl WorkerService for WorkerServiceDefault {
         Ok(())
     }
 
+    async fn dry_run_update(
+        &self,
+        worker_id: &WorkerId,
+        target_version: ComponentVersion,
+        metadata: WorkerRequestMetadata,
+    ) -> WorkerResult<UpdateDryRunResult> {
+        let worker_id = worker_id.clone();
+        let result = self
+            .call_worker_executor(
+                worker_id.clone(),
+                "dry_run_update_worker",
+                move |worker_executor_client| {
+                    info!("Dry-run update worker");
+                    let worker_id = worker_id.clone();
+                    Box::pin(worker_executor_client.dry_run_update_worker(
+                        workerexecutor::v1::DryRunUpdateWorkerRequest {
+                            worker_id: Some(worker_id.into()),
+                            target_version,
+                            account_id: metadata.account_id.clone().map(|id| id.into()),
+                        },
+                    ))
+                },
+                |response| match response.into_inner() {
+                    workerexecutor::v1::DryRunUpdateWorkerResponse {
+                        result:
+                            Some(workerexecutor::v1::dry_run_update_worker_response::Result::Success(
+                                result,
+                            )),
+                    } => Ok(result.into()),
+                    workerexecutor::v1::DryRunUpdateWorkerResponse {
+                        result:
+                            Some(workerexecutor::v1::dry_run_update_worker_response::Result::Failure(
+                                err,
+                            )),
+                    } => Err(err.into()),
+                    workerexecutor::v1::DryRunUpdateWorkerResponse { .. } => {
+                        Err("Empty response".into())
+                    }
+                },
+                WorkerServiceError::InternalCallError,
+            )
+            .await?;
+        Ok(result)
+    }
+
     async fn get_oplog(
         &self,
         worker_id: &WorkerId,
@@ -1113,6 +1227,142 @@ impl WorkerService for WorkerServiceDefault {
         Ok(Box::pin(stream))
     }
 
+    async fn put_file(
+        &self,
+        worker_id: &TargetWorkerId,
+        path: ComponentFilePath,
+        content: Vec<u8>,
+        metadata: WorkerRequestMetadata,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        let path_clone = path.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "put_file",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(
+                    worker_executor_client.put_file(workerexecutor::v1::PutFileRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        account_limits: metadata.limits.clone().map(|id| id.into()),
+                        file_path: path_clone.to_string(),
+                        content: content.clone(),
+                    }),
+                )
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::PutFileResponse {
+                    result: Some(workerexecutor::v1::put_file_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::PutFileResponse {
+                    result: Some(workerexecutor::v1::put_file_response::Result::NotAFile(_)),
+                } => Err(WorkerServiceError::BadFileType(path.clone()).into()),
+                workerexecutor::v1::PutFileResponse {
+                    result: Some(workerexecutor::v1::put_file_response::Result::ReadOnly(_)),
+                } => Err(WorkerServiceError::ReadOnlyFile(path.clone()).into()),
+                workerexecutor::v1::PutFileResponse {
+                    result: Some(workerexecutor::v1::put_file_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::PutFileResponse { result: None } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn delete_file(
+        &self,
+        worker_id: &TargetWorkerId,
+        path: ComponentFilePath,
+        metadata: WorkerRequestMetadata,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        let path_clone = path.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "delete_file",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(
+                    worker_executor_client.delete_file(workerexecutor::v1::DeleteFileRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        account_limits: metadata.limits.clone().map(|id| id.into()),
+                        file_path: path_clone.to_string(),
+                    }),
+                )
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::DeleteFileResponse {
+                    result: Some(workerexecutor::v1::delete_file_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::DeleteFileResponse {
+                    result: Some(workerexecutor::v1::delete_file_response::Result::NotFound(_)),
+                } => Err(WorkerServiceError::FileNotFound(path.clone()).into()),
+                workerexecutor::v1::DeleteFileResponse {
+                    result: Some(workerexecutor::v1::delete_file_response::Result::ReadOnly(_)),
+                } => Err(WorkerServiceError::ReadOnlyFile(path.clone()).into()),
+                workerexecutor::v1::DeleteFileResponse {
+                    result: Some(workerexecutor::v1::delete_file_response::Result::DirectoryNotEmpty(_)),
+                } => Err(WorkerServiceError::DirectoryNotEmpty(path.clone()).into()),
+                workerexecutor::v1::DeleteFileResponse {
+                    result: Some(workerexecutor::v1::delete_file_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::DeleteFileResponse { result: None } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await
+    }
+
+    async fn make_directory(
+        &self,
+        worker_id: &TargetWorkerId,
+        path: ComponentFilePath,
+        metadata: WorkerRequestMetadata,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        let path_clone = path.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "make_directory",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.make_directory(
+                    workerexecutor::v1::MakeDirectoryRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        account_limits: metadata.limits.clone().map(|id| id.into()),
+                        path: path_clone.to_string(),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::MakeDirectoryResponse {
+                    result: Some(workerexecutor::v1::make_directory_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::MakeDirectoryResponse {
+                    result:
+                        Some(workerexecutor::v1::make_directory_response::Result::AlreadyExists(_)),
+                } => Err(WorkerServiceError::FileAlreadyExists(path.clone()).into()),
+                workerexecutor::v1::MakeDirectoryResponse {
+                    result: Some(workerexecutor::v1::make_directory_response::Result::ReadOnly(_)),
+                } => Err(WorkerServiceError::ReadOnlyFile(path.clone()).into()),
+                workerexecutor::v1::MakeDirectoryResponse {
+                    result: Some(workerexecutor::v1::make_directory_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::MakeDirectoryResponse { result: None } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
     async fn activate_plugin(
         &self,
         worker_id: &WorkerId,
@@ -1188,6 +1438,81 @@ impl WorkerService for WorkerServiceDefault {
 
         Ok(())
     }
+
+    async fn update_labels(
+        &self,
+        worker_id: &WorkerId,
+        labels: HashMap<String, String>,
+        metadata: WorkerRequestMetadata,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "update_worker_labels",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(
+                    worker_executor_client.update_worker_labels(UpdateWorkerLabelsRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        labels: labels.clone(),
+                    }),
+                )
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::UpdateWorkerLabelsResponse {
+                    result: Some(workerexecutor::v1::update_worker_labels_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::UpdateWorkerLabelsResponse {
+                    result:
+                    Some(workerexecutor::v1::update_worker_labels_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::UpdateWorkerLabelsResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn cancel_invocation(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: &IdempotencyKey,
+        metadata: WorkerRequestMetadata,
+    ) -> WorkerResult<bool> {
+        let worker_id = worker_id.clone();
+        let idempotency_key = idempotency_key.clone();
+        let cancelled = self.call_worker_executor(
+            worker_id.clone(),
+            "cancel_invocation",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(
+                    worker_executor_client.cancel_invocation(CancelInvocationRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        idempotency_key: Some(idempotency_key.clone().into()),
+                    }),
+                )
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::CancelInvocationResponse {
+                    result: Some(workerexecutor::v1::cancel_invocation_response::Result::Success(cancelled)),
+                } => Ok(cancelled),
+                workerexecutor::v1::CancelInvocationResponse {
+                    result:
+                    Some(workerexecutor::v1::cancel_invocation_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::CancelInvocationResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await?;
+
+        Ok(cancelled)
+    }
 }
 
 impl WorkerServiceDefault {
@@ -46,6 +46,12 @@ pub enum WorkerServiceError {
     FileNotFound(ComponentFilePath),
     #[error("Bad file type: {0}")]
     BadFileType(ComponentFilePath),
+    #[error("File is read-only: {0}")]
+    ReadOnlyFile(ComponentFilePath),
+    #[error("Directory is not empty: {0}")]
+    DirectoryNotEmpty(ComponentFilePath),
+    #[error("File already exists: {0}")]
+    FileAlreadyExists(ComponentFilePath),
 }
 
 impl SafeDisplay for WorkerServiceError {
@@ -62,6 +68,9 @@ impl SafeDisplay for WorkerServiceError {
             WorkerServiceError::InternalCallError(inner) => inner.to_safe_string(),
             WorkerServiceError::FileNotFound(_) => self.to_string(),
             WorkerServiceError::BadFileType(_) => self.to_string(),
+            WorkerServiceError::ReadOnlyFile(_) => self.to_string(),
+            WorkerServiceError::DirectoryNotEmpty(_) => self.to_string(),
+            WorkerServiceError::FileAlreadyExists(_) => self.to_string(),
         }
     }
 }
@@ -110,9 +119,18 @@ impl From<WorkerServiceError> for worker_error::Error {
             WorkerServiceError::FileNotFound(_) => worker_error::Error::NotFound(ErrorBody {
                 error: error.to_safe_string(),
             }),
-            WorkerServiceError::BadFileType(_) => worker_error::Error::BadRequest(ErrorsBody {
-                errors: vec![error.to_safe_string()],
-            }),
+            WorkerServiceError::BadFileType(_)
+            | WorkerServiceError::ReadOnlyFile(_)
+            | WorkerServiceError::DirectoryNotEmpty(_) => {
+                worker_error::Error::BadRequest(ErrorsBody {
+                    errors: vec![error.to_safe_string()],
+                })
+            }
+            WorkerServiceError::FileAlreadyExists(_) => {
+                worker_error::Error::AlreadyExists(ErrorBody {
+                    error: error.to_safe_string(),
+                })
+            }
         }
     }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use test_r::test;
 
@@ -31,6 +32,7 @@ use golem_worker_service_base::gateway_execution::gateway_binding_resolver::Gate
 use golem_worker_service_base::gateway_execution::gateway_http_input_executor::{
     DefaultGatewayInputExecutor, GatewayHttpInput, GatewayHttpInputExecutor,
 };
+use golem_worker_service_base::gateway_execution::gateway_response_cache::InMemoryGatewayResponseCache;
 use golem_worker_service_base::gateway_execution::gateway_session::{
     GatewaySession, GatewaySessionStore,
 };
@@ -41,7 +43,7 @@ use golem_worker_service_base::gateway_security::{
     Provider, SecurityScheme, SecuritySchemeIdentifier,
 };
 use golem_worker_service_base::{api, gateway_api_definition};
-use http::header::LOCATION;
+use http::header::{CACHE_CONTROL, ETAG, IF_NONE_MATCH, LOCATION};
 use http::uri::Scheme;
 use http::{HeaderMap, HeaderValue, Method, StatusCode};
 use openidconnect::{ClientId, ClientSecret, RedirectUrl, Scope};
@@ -67,8 +69,26 @@ async fn execute(
         internal::get_test_rib_interpreter(),
         internal::get_test_file_server_binding_handler(),
         Arc::new(DefaultAuthCallBack),
+        Arc::new(InMemoryGatewayResponseCache::new(1024)),
     );
 
+    execute_with_executor(
+        &test_executor,
+        api_request,
+        api_specification,
+        session_store,
+        test_identity_provider,
+    )
+    .await
+}
+
+async fn execute_with_executor(
+    test_executor: &DefaultGatewayInputExecutor<DefaultNamespace>,
+    api_request: &InputHttpRequest,
+    api_specification: &HttpApiDefinition,
+    session_store: &GatewaySessionStore,
+    test_identity_provider: &TestIdentityProvider,
+) -> Response {
     // Compile the API definition
     let compiled = CompiledHttpApiDefinition::from_http_api_definition(
         api_specification,
@@ -1212,6 +1232,113 @@ async fn test_api_def_for_valid_input_with_idempotency_key_in_header() {
     test_key(&headers, Some(IdempotencyKey::new("bar".to_string()))).await;
 }
 
+#[test]
+async fn test_api_def_with_response_cache() {
+    let invocations = Arc::new(AtomicUsize::new(0));
+
+    let test_executor = DefaultGatewayInputExecutor::new(
+        internal::get_counting_test_rib_interpreter(invocations.clone()),
+        internal::get_test_file_server_binding_handler(),
+        Arc::new(DefaultAuthCallBack),
+        Arc::new(InMemoryGatewayResponseCache::new(1024)),
+    );
+
+    let response_mapping = r#"
+      let response = golem:it/api.{get-cart-contents}("a", "b");
+      response
+    "#;
+
+    let cache_key = r#"
+      let id: u64 = request.path.user-id;
+      "user-${id}"
+    "#;
+
+    let api_specification: HttpApiDefinition = get_api_def_with_cached_worker_binding(
+        "/foo/{user-id}",
+        "\"shopping-cart\"",
+        response_mapping,
+        cache_key,
+    )
+    .await;
+
+    let session_store = internal::get_session_store();
+
+    let execute_request = |headers: HeaderMap, path: &'static str| {
+        let test_executor = &test_executor;
+        let api_specification = &api_specification;
+        let session_store = &session_store;
+        async move {
+            execute_with_executor(
+                test_executor,
+                &get_gateway_request(path, None, &headers, Value::Null),
+                api_specification,
+                session_store,
+                &TestIdentityProvider::default(),
+            )
+            .await
+        }
+    };
+
+    // The first request misses the cache and invokes the worker
+    let response = execute_request(HeaderMap::new(), "/foo/1").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(invocations.load(Ordering::SeqCst), 1);
+    let etag = response
+        .headers()
+        .get(ETAG)
+        .expect("Cached responses expect an ETag")
+        .clone();
+    let test_response = internal::get_details_from_response(response).await;
+    assert_eq!(
+        test_response.function_name,
+        "golem:it/api.{get-cart-contents}"
+    );
+
+    // The same cache key is served from the cache
+    let response = execute_request(HeaderMap::new(), "/foo/1").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(ETAG), Some(&etag));
+    assert_eq!(invocations.load(Ordering::SeqCst), 1);
+    let cached_test_response = internal::get_details_from_response(response).await;
+    assert_eq!(
+        cached_test_response.function_params,
+        test_response.function_params
+    );
+
+    // Another cache key misses the cache
+    let response = execute_request(HeaderMap::new(), "/foo/2").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(invocations.load(Ordering::SeqCst), 2);
+
+    // Revalidating with the ETag of the cached response gets a 304 without the body
+    let mut headers = HeaderMap::new();
+    headers.insert(IF_NONE_MATCH, etag.clone());
+    let response = execute_request(headers, "/foo/1").await;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(invocations.load(Ordering::SeqCst), 2);
+    let body = response.into_body().into_bytes().await.unwrap();
+    assert!(body.is_empty());
+
+    // Revalidating with another ETag gets the cached response
+    let mut headers = HeaderMap::new();
+    headers.insert(IF_NONE_MATCH, HeaderValue::from_static("\"other\""));
+    let response = execute_request(headers, "/foo/1").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(invocations.load(Ordering::SeqCst), 2);
+
+    // Cache-Control: no-cache forces the worker to be invoked again
+    let mut headers = HeaderMap::new();
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    let response = execute_request(headers, "/foo/1").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(invocations.load(Ordering::SeqCst), 3);
+
+    // The refreshed response is cached again
+    let response = execute_request(HeaderMap::new(), "/foo/1").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(invocations.load(Ordering::SeqCst), 3);
+}
+
 fn get_gateway_request(
     base_path: &str,
     query_path: Option<&str>,
@@ -1294,6 +1421,52 @@ async fn get_api_def_with_worker_binding(
     .unwrap()
 }
 
+async fn get_api_def_with_cached_worker_binding(
+    path_pattern: &str,
+    worker_name: &str,
+    rib_expression: &str,
+    cache_key: &str,
+) -> HttpApiDefinition {
+    let yaml_string = format!(
+        r#"
+          id: users-api
+          version: 0.0.1
+          createdAt: 2024-08-21T07:42:15.696Z
+          routes:
+          - method: Get
+            path: {}
+            binding:
+              type: wit-worker
+              componentId:
+                componentId: 0b6d9cd8-f373-4e29-8a5a-548e61b868a5
+                version: 0
+              workerName: '{}'
+              response: '${{{}}}'
+              cacheKey: '{}'
+              cacheTtl: 60s
+
+        "#,
+        path_pattern, worker_name, rib_expression, cache_key
+    );
+
+    let http_api_definition_request: api::HttpApiDefinitionRequest =
+        serde_yaml::from_str(yaml_string.as_str()).unwrap();
+
+    let core_request: gateway_api_definition::http::HttpApiDefinitionRequest =
+        http_api_definition_request.try_into().unwrap();
+
+    let create_at: DateTime<Utc> = "2024-08-21T07:42:15.696Z".parse().unwrap();
+
+    HttpApiDefinition::from_http_api_definition_request(
+        &DefaultNamespace(),
+        core_request,
+        create_at,
+        &security::get_test_security_scheme_service(TestIdentityProvider::default()),
+    )
+    .await
+    .unwrap()
+}
+
 // https://swagger.io/docs/specification/v3_0/authentication/openid-connect-discovery/
 async fn get_api_def_with_security(
     path_pattern: &str,
@@ -1606,9 +1779,12 @@ mod internal {
     };
     use serde_json::Value;
     use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::{Arc, Mutex};
 
-    pub struct TestApiGatewayWorkerRequestExecutor {}
+    pub struct TestApiGatewayWorkerRequestExecutor {
+        pub invocations: Arc<AtomicUsize>,
+    }
 
     #[async_trait]
     impl GatewayWorkerRequestExecutor<DefaultNamespace> for TestApiGatewayWorkerRequestExecutor {
@@ -1617,6 +1793,7 @@ mod internal {
             &self,
             resolved_worker_request: GatewayResolvedWorkerRequest<DefaultNamespace>,
         ) -> Result<WorkerResponse, WorkerRequestExecutorError> {
+            self.invocations.fetch_add(1, Ordering::SeqCst);
             let type_annotated_value = convert_to_worker_response(&resolved_worker_request);
             let worker_response = create_tuple(vec![type_annotated_value]);
 
@@ -1779,9 +1956,16 @@ mod internal {
     }
 
     pub fn get_test_rib_interpreter(
+    ) -> Arc<dyn WorkerServiceRibInterpreter<DefaultNamespace> + Sync + Send> {
+        get_counting_test_rib_interpreter(Arc::new(AtomicUsize::new(0)))
+    }
+
+    // Counts the worker invocations, to tell the responses served from the response cache apart
+    pub fn get_counting_test_rib_interpreter(
+        invocations: Arc<AtomicUsize>,
     ) -> Arc<dyn WorkerServiceRibInterpreter<DefaultNamespace> + Sync + Send> {
         Arc::new(DefaultRibInterpreter::from_worker_request_executor(
-            Arc::new(TestApiGatewayWorkerRequestExecutor {}),
+            Arc::new(TestApiGatewayWorkerRequestExecutor { invocations }),
         ))
     }
 
@@ -17,7 +17,7 @@ use test_r::test;
 
 use async_trait::async_trait;
 use golem_common::config::{DbPostgresConfig, DbSqliteConfig, RedisConfig, RetryConfig};
-use golem_common::model::ComponentId;
+use golem_common::model::{AccountId, ComponentId};
 use golem_service_base::auth::{DefaultNamespace, EmptyAuthCtx};
 use golem_service_base::db;
 use golem_service_base::model::Component;
@@ -45,7 +45,11 @@ use golem_common::redis::RedisPool;
 use golem_wasm_ast::analysis::analysed_type::str;
 use golem_worker_service_base::api;
 use golem_worker_service_base::gateway_api_deployment::{
-    ApiDeploymentRequest, ApiSite, ApiSiteString,
+    ApiDeploymentChange, ApiDeploymentRequest, ApiSite, ApiSiteString,
+};
+use golem_worker_service_base::gateway_execution::gateway_response_cache::{
+    CacheKey, CachedResponse, GatewayResponseCache, GatewayResponseCacheStore,
+    InMemoryGatewayResponseCache, RedisGatewayResponseCache, ResponseCacheEntryKey,
 };
 use golem_worker_service_base::gateway_execution::gateway_session::{
     DataKey, DataValue, GatewaySession, GatewaySessionError, GatewaySessionWithInMemoryCache,
@@ -57,6 +61,9 @@ use golem_worker_service_base::gateway_security::{
     SecuritySchemeWithProviderMetadata,
 };
 use golem_worker_service_base::repo::security_scheme::{DbSecuritySchemeRepo, SecuritySchemeRepo};
+use golem_worker_service_base::repo::topic::{
+    DbTopicRepo, TopicMessageRecord, TopicRecord, TopicRepo, TopicSubscriptionRecord,
+};
 use golem_worker_service_base::service::gateway::security_scheme::{
     DefaultSecuritySchemeService, SecuritySchemeService,
 };
@@ -72,6 +79,7 @@ use openidconnect::{
     TokenUrl, UserInfoUrl,
 };
 use std::sync::Arc;
+use std::time::Duration;
 use testcontainers::runners::AsyncRunner;
 use testcontainers::{ContainerAsync, ImageExt};
 use testcontainers_modules::postgres::Postgres;
@@ -171,12 +179,16 @@ pub async fn test_with_postgres_db() {
     let security_scheme_repo: Arc<dyn SecuritySchemeRepo + Sync + Send> =
         Arc::new(DbSecuritySchemeRepo::new(db_pool.clone().into()));
 
+    let topic_repo: Arc<dyn TopicRepo + Sync + Send> =
+        Arc::new(DbTopicRepo::new(db_pool.clone().into()));
+
     test_services(
         api_definition_repo,
         api_deployment_repo,
         security_scheme_repo,
     )
     .await;
+    test_topic_repo(topic_repo).await;
 }
 
 #[test]
@@ -230,6 +242,50 @@ pub async fn test_gateway_session_expiry() {
     assert_eq!(result, data_value);
 }
 
+#[test]
+pub async fn test_redis_gateway_response_cache_route_ttls() {
+    let (redis_config, _container) = start_docker_redis().await;
+
+    let redis = RedisPool::configured(&redis_config).await.unwrap();
+    let response_cache = RedisGatewayResponseCache::new(redis);
+
+    let entry_key = |route: &str| ResponseCacheEntryKey {
+        site: ApiSiteString("test-ttl.com".to_string()),
+        route: route.to_string(),
+        cache_key: CacheKey("user-1".to_string()),
+    };
+    let long_lived = entry_key("users-api@0.0.1 GET /users/{id}");
+    let short_lived = entry_key("users-api@0.0.1 GET /users/{id}/status");
+
+    // The routes share a hash, the response of the second route must not shorten the lifetime
+    // of the first one
+    response_cache
+        .insert(
+            long_lived.clone(),
+            CachedResponse::new(200, vec![], b"user".to_vec(), Duration::from_secs(60)),
+        )
+        .await
+        .unwrap();
+    response_cache
+        .insert(
+            short_lived.clone(),
+            CachedResponse::new(200, vec![], b"online".to_vec(), Duration::from_secs(1)),
+        )
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    let long_lived_response = response_cache.get(&long_lived).await.unwrap();
+    let short_lived_response = response_cache.get(&short_lived).await.unwrap();
+
+    assert_eq!(
+        long_lived_response.map(|response| response.body),
+        Some(b"user".to_vec())
+    );
+    assert!(short_lived_response.is_none());
+}
+
 async fn insert_and_get_with_redis(
     session_id: SessionId,
     data_key: DataKey,
@@ -298,12 +354,16 @@ pub async fn test_with_sqlite_db() {
     let security_scheme_repo: Arc<dyn SecuritySchemeRepo + Sync + Send> =
         Arc::new(DbSecuritySchemeRepo::new(db_pool.clone().into()));
 
+    let topic_repo: Arc<dyn TopicRepo + Sync + Send> =
+        Arc::new(DbTopicRepo::new(db_pool.clone().into()));
+
     test_services(
         api_definition_repo,
         api_deployment_repo,
         security_scheme_repo,
     )
     .await;
+    test_topic_repo(topic_repo).await;
 }
 
 struct TestComponentService;
@@ -326,6 +386,13 @@ impl TestComponentService {
                 exports: Self::get_metadata(),
                 producers: vec![],
                 memories: vec![],
+                oplog_policy: None,
+                volumes: vec![],
+                imports: vec![],
+                capability_policy: None,
+                egress_policy: None,
+                secrets: vec![],
+                failure_policy: None,
             },
             created_at: Some(Utc::now()),
             component_type: None,
@@ -413,12 +480,15 @@ async fn test_services(
         api_definition_validator_service.clone(),
     ));
 
+    let response_cache: GatewayResponseCacheStore = Arc::new(InMemoryGatewayResponseCache::new(16));
+
     let deployment_service: Arc<
         dyn ApiDeploymentService<EmptyAuthCtx, DefaultNamespace> + Sync + Send,
     > = Arc::new(ApiDeploymentServiceDefault::new(
         api_deployment_repo.clone(),
         api_definition_repo.clone(),
         component_service.clone(),
+        response_cache.clone(),
     ));
 
     test_security_crud(security_scheme_service.clone()).await;
@@ -426,6 +496,13 @@ async fn test_services(
     test_delete_non_existing(definition_service.clone()).await;
     test_deployment(definition_service.clone(), deployment_service.clone()).await;
     test_deployment_conflict(definition_service.clone(), deployment_service.clone()).await;
+    test_deployment_revisions(
+        definition_service.clone(),
+        deployment_service.clone(),
+        response_cache.clone(),
+    )
+    .await;
+    test_cached_route_validation(definition_service.clone(), security_scheme_service.clone()).await;
 }
 
 async fn test_deployment(
@@ -598,7 +675,10 @@ async fn test_deployment(
     ));
 
     let deployment = get_api_deployment("test.com", None, vec![&def3.id.0]);
-    deployment_service.undeploy(&deployment).await.unwrap();
+    deployment_service
+        .undeploy(&deployment, &EmptyAuthCtx::default())
+        .await
+        .unwrap();
 
     let definitions: Vec<HttpApiDefinition> = deployment_service
         .get_definitions_by_site(&ApiSiteString("test.com".to_string()))
@@ -747,6 +827,232 @@ async fn test_deployment_conflict(
     );
 }
 
+async fn test_deployment_revisions(
+    definition_service: Arc<dyn ApiDefinitionService<EmptyAuthCtx, DefaultNamespace> + Sync + Send>,
+    deployment_service: Arc<dyn ApiDeploymentService<EmptyAuthCtx, DefaultNamespace> + Sync + Send>,
+    response_cache: GatewayResponseCacheStore,
+) {
+    let def1 = get_api_definition(
+        &Uuid::new_v4().to_string(),
+        "0.0.1",
+        "/api/revisions/1",
+        "\"worker1\"",
+        "${ {body: golem:it/api.{get-cart-contents}(\"foo\")} }",
+        false,
+    );
+    let def2 = get_api_definition(
+        &Uuid::new_v4().to_string(),
+        "0.0.1",
+        "/api/revisions/2",
+        "\"worker2\"",
+        "${ {body: golem:it/api.{get-cart-contents}(\"foo\")} }",
+        false,
+    );
+
+    for def in [&def1, &def2] {
+        definition_service
+            .create(def, &DefaultNamespace::default(), &EmptyAuthCtx::default())
+            .await
+            .unwrap();
+    }
+
+    let site = ApiSiteString("test-revisions.com".to_string());
+
+    let deployment = get_api_deployment("test-revisions.com", None, vec![&def1.id.0]);
+    deployment_service
+        .deploy(&deployment, &EmptyAuthCtx::default())
+        .await
+        .unwrap();
+
+    let deployment = get_api_deployment("test-revisions.com", None, vec![&def2.id.0]);
+    deployment_service
+        .deploy(&deployment, &EmptyAuthCtx::default())
+        .await
+        .unwrap();
+
+    let deployment = get_api_deployment("test-revisions.com", None, vec![&def1.id.0]);
+    deployment_service
+        .undeploy(&deployment, &EmptyAuthCtx::default())
+        .await
+        .unwrap();
+
+    let revisions = deployment_service
+        .get_revisions(&DefaultNamespace::default(), &site)
+        .await
+        .unwrap();
+    assert_eq!(
+        revisions
+            .iter()
+            .map(|revision| (revision.revision, revision.change))
+            .collect::<Vec<_>>(),
+        vec![
+            (1, ApiDeploymentChange::Deploy),
+            (2, ApiDeploymentChange::Deploy),
+            (3, ApiDeploymentChange::Undeploy),
+        ]
+    );
+    assert_eq!(revisions[1].api_definition_keys.len(), 2);
+    assert_eq!(revisions[2].api_definition_keys.len(), 1);
+    assert!(revisions
+        .iter()
+        .all(|revision| revision.actor == AccountId::placeholder().to_string()));
+
+    let diff = deployment_service
+        .diff_revisions(&DefaultNamespace::default(), &site, 1, 2)
+        .await
+        .unwrap();
+    assert_eq!(
+        diff.added_routes
+            .iter()
+            .map(|route| route.path.to_string())
+            .collect::<Vec<_>>(),
+        vec!["/api/revisions/2".to_string()]
+    );
+    assert!(diff.removed_routes.is_empty());
+    assert!(diff.changed_routes.is_empty());
+
+    // The undeployed definition is a draft again, so its content can change before the rollback
+    let def1_upd = get_api_definition(
+        &def1.id.0,
+        "0.0.1",
+        "/api/revisions/1/updated",
+        "\"worker1\"",
+        "${ {body: golem:it/api.{get-cart-contents}(\"foo\")} }",
+        false,
+    );
+    definition_service
+        .update(
+            &def1_upd,
+            &DefaultNamespace::default(),
+            &EmptyAuthCtx::default(),
+        )
+        .await
+        .unwrap();
+
+    let cache_entry_key = ResponseCacheEntryKey {
+        site: site.clone(),
+        route: format!("{}@0.0.1 GET /api/revisions/1", def1.id),
+        cache_key: CacheKey("foo".to_string()),
+    };
+    response_cache
+        .insert(
+            cache_entry_key.clone(),
+            CachedResponse::new(200, vec![], b"cached".to_vec(), Duration::from_secs(60)),
+        )
+        .await
+        .unwrap();
+
+    let rollback = deployment_service
+        .rollback(
+            &DefaultNamespace::default(),
+            &site,
+            2,
+            &EmptyAuthCtx::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(rollback.revision, 4);
+    assert_eq!(rollback.change, ApiDeploymentChange::Rollback);
+    assert_eq!(rollback.source_revision, Some(2));
+
+    // Deployment changes invalidate the responses cached for the site
+    assert!(response_cache
+        .get(&cache_entry_key)
+        .await
+        .unwrap()
+        .is_none());
+
+    let definitions: Vec<HttpApiDefinition> = deployment_service
+        .get_definitions_by_site(&site)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|x| x.into())
+        .collect::<Vec<_>>();
+    // The rollback restores the content the definitions had at the revision
+    assert_eq!(definitions.len(), 2);
+    assert!(contains_definitions(
+        definitions,
+        vec![def1.clone(), def2.clone()]
+    ));
+
+    let missing_revision = deployment_service
+        .rollback(
+            &DefaultNamespace::default(),
+            &site,
+            42,
+            &EmptyAuthCtx::default(),
+        )
+        .await;
+    assert!(missing_revision.is_err());
+}
+
+async fn test_cached_route_validation(
+    definition_service: Arc<dyn ApiDefinitionService<EmptyAuthCtx, DefaultNamespace> + Sync + Send>,
+    security_scheme_service: Arc<dyn SecuritySchemeService<DefaultNamespace> + Sync + Send>,
+) {
+    let security_identifier = SecuritySchemeIdentifier::new("cached-route-test".to_string());
+    security_scheme_service
+        .create(&DefaultNamespace(), &get_security(&security_identifier))
+        .await
+        .expect("Failed to create security scheme");
+
+    let cached_get = get_cached_api_definition(
+        &Uuid::new_v4().to_string(),
+        "Get",
+        "/api/cached/{user-id}",
+        None,
+    );
+    let cached_post = get_cached_api_definition(
+        &Uuid::new_v4().to_string(),
+        "Post",
+        "/api/cached/{user-id}",
+        None,
+    );
+    let cached_secured_get = get_cached_api_definition(
+        &Uuid::new_v4().to_string(),
+        "Get",
+        "/api/cached/{user-id}",
+        Some("cached-route-test"),
+    );
+
+    definition_service
+        .create(
+            &cached_get,
+            &DefaultNamespace::default(),
+            &EmptyAuthCtx::default(),
+        )
+        .await
+        .unwrap();
+
+    let post_result = definition_service
+        .create(
+            &cached_post,
+            &DefaultNamespace::default(),
+            &EmptyAuthCtx::default(),
+        )
+        .await;
+    assert!(matches!(
+        &post_result,
+        Err(ApiDefinitionError::ValidationError(errors))
+            if errors.to_string().contains("Response cache is only supported for GET and HEAD routes")
+    ));
+
+    // The cached responses would be shared by every authenticated caller of the route
+    let secured_result = definition_service
+        .create(
+            &cached_secured_get,
+            &DefaultNamespace::default(),
+            &EmptyAuthCtx::default(),
+        )
+        .await;
+    assert!(matches!(
+        &secured_result,
+        Err(ApiDefinitionError::ValidationError(errors))
+            if errors.to_string().contains("Response cache is not supported for secured routes")
+    ));
+}
+
 async fn test_security_crud(
     security_scheme_service: Arc<dyn SecuritySchemeService<DefaultNamespace> + Sync + Send>,
 ) {
@@ -770,6 +1076,136 @@ async fn test_security_crud(
     assert_eq!(insert.provider_metadata, get.provider_metadata)
 }
 
+async fn test_topic_repo(topic_repo: Arc<dyn TopicRepo + Sync + Send>) {
+    let namespace = DefaultNamespace().to_string();
+
+    let topic = TopicRecord {
+        namespace: namespace.clone(),
+        name: "orders".to_string(),
+        created_at: Utc::now(),
+    };
+    topic_repo.create(&topic).await.unwrap();
+
+    let get = topic_repo.get(&namespace, "orders").await.unwrap();
+    assert_eq!(get.map(|topic| topic.name), Some("orders".to_string()));
+    assert_eq!(topic_repo.get_all(&namespace).await.unwrap().len(), 1);
+
+    let subscription = TopicSubscriptionRecord {
+        namespace: namespace.clone(),
+        topic: "orders".to_string(),
+        subscription_id: Uuid::new_v4().to_string(),
+        component_id: Uuid::new_v4().to_string(),
+        worker_name: "worker-1".to_string(),
+        function_name: "golem:it/api.{on-order}".to_string(),
+        filter: None,
+        created_at: Utc::now(),
+    };
+    topic_repo.create_subscription(&subscription).await.unwrap();
+
+    let subscriptions = topic_repo
+        .get_subscriptions(&namespace, "orders")
+        .await
+        .unwrap();
+    assert_eq!(subscriptions.len(), 1);
+    assert_eq!(
+        subscriptions[0].subscription_id,
+        subscription.subscription_id
+    );
+
+    let now = Utc::now();
+    let message = TopicMessageRecord {
+        namespace: namespace.clone(),
+        topic: "orders".to_string(),
+        message_id: Uuid::new_v4().to_string(),
+        payload: vec![1, 2, 3],
+        delivered: false,
+        dead_letter: false,
+        attempts: 0,
+        published_at: now,
+        next_attempt_at: now,
+        completed_at: None,
+    };
+    assert!(topic_repo.create_message(&message).await.unwrap());
+    assert!(!topic_repo.create_message(&message).await.unwrap());
+
+    let undelivered = topic_repo.get_undelivered_messages(now, 10).await.unwrap();
+    assert_eq!(undelivered.len(), 1);
+    assert_eq!(undelivered[0].payload, message.payload);
+
+    // A failed attempt postpones the message until its next attempt is due
+    let next_attempt_at = now + chrono::Duration::seconds(30);
+    topic_repo
+        .set_message_retry(&message.message_id, 1, next_attempt_at)
+        .await
+        .unwrap();
+    assert!(topic_repo
+        .get_undelivered_messages(now, 10)
+        .await
+        .unwrap()
+        .is_empty());
+    let due = topic_repo
+        .get_undelivered_messages(next_attempt_at, 10)
+        .await
+        .unwrap();
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].attempts, 1);
+
+    topic_repo
+        .set_message_delivered(&message.message_id, now)
+        .await
+        .unwrap();
+    assert!(topic_repo
+        .get_undelivered_messages(next_attempt_at, 10)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let dead_letter = TopicMessageRecord {
+        message_id: Uuid::new_v4().to_string(),
+        ..message.clone()
+    };
+    topic_repo.create_message(&dead_letter).await.unwrap();
+    topic_repo
+        .set_message_dead_letter(&dead_letter.message_id, 10, next_attempt_at)
+        .await
+        .unwrap();
+    assert!(topic_repo
+        .get_undelivered_messages(next_attempt_at, 10)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // Only the messages completed before the retention cutoff are purged
+    assert_eq!(
+        topic_repo
+            .delete_completed_messages(now + chrono::Duration::seconds(1))
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        topic_repo
+            .delete_completed_messages(next_attempt_at + chrono::Duration::seconds(1))
+            .await
+            .unwrap(),
+        1
+    );
+
+    let deleted = topic_repo
+        .delete_subscription(&namespace, "orders", &subscription.subscription_id)
+        .await
+        .unwrap();
+    assert!(deleted);
+
+    assert!(topic_repo.delete(&namespace, "orders").await.unwrap());
+    assert!(topic_repo
+        .get(&namespace, "orders")
+        .await
+        .unwrap()
+        .is_none());
+    assert!(!topic_repo.delete(&namespace, "orders").await.unwrap());
+}
+
 async fn test_definition_crud(
     definition_service: Arc<dyn ApiDefinitionService<EmptyAuthCtx, DefaultNamespace> + Sync + Send>,
 ) {
@@ -1000,6 +1436,40 @@ fn get_api_definition(
     api.try_into().unwrap()
 }
 
+fn get_cached_api_definition(
+    id: &str,
+    method: &str,
+    path_pattern: &str,
+    security: Option<&str>,
+) -> HttpApiDefinitionRequest {
+    let security = security
+        .map(|security| format!("security: {security}"))
+        .unwrap_or_default();
+    let yaml_string = format!(
+        r#"
+          id: {}
+          version: 0.0.1
+          draft: true
+          routes:
+          - method: {}
+            path: {}
+            {}
+            binding:
+              componentId:
+                componentId: 0b6d9cd8-f373-4e29-8a5a-548e61b868a5
+                version: 0
+              workerName: '"worker1"'
+              response: '${{ {{body: golem:it/api.{{get-cart-contents}}("foo")}} }}'
+              cacheKey: '"cart"'
+              cacheTtl: 60s
+        "#,
+        id, method, path_pattern, security
+    );
+
+    let api: api::HttpApiDefinitionRequest = serde_yaml::from_str(yaml_string.as_str()).unwrap();
+    api.try_into().unwrap()
+}
+
 fn contains_definitions(
     result: Vec<HttpApiDefinition>,
     expected: Vec<HttpApiDefinitionRequest>,
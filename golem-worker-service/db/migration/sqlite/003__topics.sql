@@ -0,0 +1,38 @@
+CREATE TABLE topics
+(
+    namespace  text NOT NULL,
+    name       text NOT NULL,
+    created_at timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (namespace, name)
+);
+
+CREATE TABLE topic_subscriptions
+(
+    namespace       text NOT NULL,
+    topic           text NOT NULL,
+    subscription_id text NOT NULL,
+    component_id    text NOT NULL,
+    worker_name     text NOT NULL,
+    function_name   text NOT NULL,
+    filter          text,
+    created_at      timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (namespace, topic, subscription_id)
+);
+
+CREATE TABLE topic_messages
+(
+    namespace       text    NOT NULL,
+    topic           text    NOT NULL,
+    message_id      text    NOT NULL,
+    payload         blob    NOT NULL,
+    delivered       boolean NOT NULL DEFAULT false,
+    dead_letter     boolean NOT NULL DEFAULT false,
+    attempts        integer NOT NULL DEFAULT 0,
+    published_at    timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    next_attempt_at timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    completed_at    timestamp without time zone,
+    PRIMARY KEY (message_id)
+);
+
+CREATE INDEX topic_messages_undelivered_idx ON topic_messages (delivered, dead_letter, next_attempt_at);
+CREATE INDEX topic_messages_completed_idx ON topic_messages (completed_at);
@@ -0,0 +1,26 @@
+CREATE TABLE api_deployment_revisions
+(
+    site            text    NOT NULL,
+    revision        bigint  NOT NULL,
+    namespace       text    NOT NULL,
+    host            text    NOT NULL,
+    subdomain       text,
+    change          text    NOT NULL,
+    source_revision bigint,
+    definitions     text    NOT NULL,
+    actor           text    NOT NULL,
+    created_at      timestamp without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (site, revision)
+);
+
+CREATE TABLE api_deployment_revision_definitions
+(
+    site               text    NOT NULL,
+    revision           bigint  NOT NULL,
+    namespace          text    NOT NULL,
+    definition_id      text    NOT NULL,
+    definition_version text    NOT NULL,
+    data               blob    NOT NULL,
+    PRIMARY KEY (site, revision, namespace, definition_id, definition_version),
+    FOREIGN KEY (site, revision) REFERENCES api_deployment_revisions (site, revision)
+);
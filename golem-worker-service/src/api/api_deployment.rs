@@ -4,10 +4,16 @@ use golem_common::{recorded_http_api_request, safe};
 use golem_service_base::api_tags::ApiTags;
 use golem_service_base::auth::{DefaultNamespace, EmptyAuthCtx};
 use golem_worker_service_base::api::ApiEndpointError;
-use golem_worker_service_base::api::{ApiDeployment, ApiDeploymentRequest};
+use golem_worker_service_base::api::{
+    ApiDeployment, ApiDeploymentRequest, ApiDeploymentRevision, ApiDeploymentRevisionDiff,
+    ApiDeploymentRollbackRequest,
+};
 use golem_worker_service_base::gateway_api_definition::ApiDefinitionId;
 use golem_worker_service_base::gateway_api_deployment;
 use golem_worker_service_base::gateway_api_deployment::ApiSiteString;
+use golem_worker_service_base::gateway_execution::gateway_response_cache::{
+    CacheKey, GatewayResponseCache,
+};
 use golem_worker_service_base::service::gateway::api_definition::ApiDefinitionIdWithVersion;
 use golem_worker_service_base::service::gateway::api_deployment::ApiDeploymentService;
 use poem_openapi::param::{Path, Query};
@@ -17,6 +23,7 @@ use tracing::Instrument;
 
 pub struct ApiDeploymentApi {
     deployment_service: Arc<dyn ApiDeploymentService<EmptyAuthCtx, DefaultNamespace> + Sync + Send>,
+    gateway_response_cache: Arc<dyn GatewayResponseCache + Sync + Send>,
 }
 
 #[OpenApi(prefix_path = "/v1/api/deployments", tag = ApiTags::ApiDeployment)]
@@ -25,8 +32,12 @@ impl ApiDeploymentApi {
         deployment_service: Arc<
             dyn ApiDeploymentService<EmptyAuthCtx, DefaultNamespace> + Sync + Send,
         >,
+        gateway_response_cache: Arc<dyn GatewayResponseCache + Sync + Send>,
     ) -> Self {
-        Self { deployment_service }
+        Self {
+            deployment_service,
+            gateway_response_cache,
+        }
     }
 
     /// Creates or updates a deployment
@@ -135,7 +146,11 @@ impl ApiDeploymentApi {
             let site = site.0;
 
             self.deployment_service
-                .delete(&DefaultNamespace::default(), &ApiSiteString(site))
+                .delete(
+                    &DefaultNamespace::default(),
+                    &ApiSiteString(site),
+                    &EmptyAuthCtx::default(),
+                )
                 .await?;
 
             Ok(Json("API deployment deleted".to_string()))
@@ -143,4 +158,141 @@ impl ApiDeploymentApi {
 
         record.result(response)
     }
+
+    /// Get the revisions of an API deployment
+    ///
+    /// Lists every recorded change of the API definitions deployed to a site, oldest first.
+    #[oai(
+        path = "/:site/revisions",
+        method = "get",
+        operation_id = "list_deployment_revisions"
+    )]
+    async fn list_revisions(
+        &self,
+        site: Path<String>,
+    ) -> Result<Json<Vec<ApiDeploymentRevision>>, ApiEndpointError> {
+        let record = recorded_http_api_request!("list_deployment_revisions", site = site.0);
+        let response = {
+            let values = self
+                .deployment_service
+                .get_revisions(&DefaultNamespace::default(), &ApiSiteString(site.0))
+                .instrument(record.span.clone())
+                .await?;
+
+            Ok(Json(values.into_iter().map(|v| v.into()).collect()))
+        };
+
+        record.result(response)
+    }
+
+    /// Rollback an API deployment
+    ///
+    /// Restores the set of API definitions deployed to a site at the given revision, together with their content at that revision. The rollback is recorded as a new revision.
+    #[oai(
+        path = "/:site/rollback",
+        method = "post",
+        operation_id = "rollback_deployment"
+    )]
+    async fn rollback(
+        &self,
+        site: Path<String>,
+        payload: Json<ApiDeploymentRollbackRequest>,
+    ) -> Result<Json<ApiDeploymentRevision>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "rollback_deployment",
+            site = site.0,
+            revision = payload.0.revision.to_string()
+        );
+        let response = {
+            let value = self
+                .deployment_service
+                .rollback(
+                    &DefaultNamespace::default(),
+                    &ApiSiteString(site.0),
+                    payload.0.revision,
+                    &EmptyAuthCtx::default(),
+                )
+                .instrument(record.span.clone())
+                .await?;
+
+            Ok(Json(value.into()))
+        };
+
+        record.result(response)
+    }
+
+    /// Compare two revisions of an API deployment
+    ///
+    /// Lists the routes added, removed and changed between the two revisions.
+    #[oai(
+        path = "/:site/revisions/diff",
+        method = "get",
+        operation_id = "diff_deployment_revisions"
+    )]
+    async fn diff_revisions(
+        &self,
+        site: Path<String>,
+        from: Query<u64>,
+        to: Query<u64>,
+    ) -> Result<Json<ApiDeploymentRevisionDiff>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "diff_deployment_revisions",
+            site = site.0,
+            from = from.0.to_string(),
+            to = to.0.to_string()
+        );
+        let response = {
+            let value = self
+                .deployment_service
+                .diff_revisions(
+                    &DefaultNamespace::default(),
+                    &ApiSiteString(site.0),
+                    from.0,
+                    to.0,
+                )
+                .instrument(record.span.clone())
+                .await?;
+
+            Ok(Json(value.into()))
+        };
+
+        record.result(response)
+    }
+
+    /// Invalidate the cached responses of an API deployment
+    ///
+    /// Removes the responses cached for the site. If a key is given, only the responses cached under that key are removed. With the in-memory response cache, only the responses cached by the worker service instance receiving the request are removed; use the Redis response cache when running more than one instance.
+    #[oai(
+        path = "/:site/cache",
+        method = "delete",
+        operation_id = "invalidate_deployment_cache"
+    )]
+    async fn invalidate_cache(
+        &self,
+        site: Path<String>,
+        key: Query<Option<String>>,
+    ) -> Result<Json<String>, ApiEndpointError> {
+        let record = recorded_http_api_request!("invalidate_deployment_cache", site = site.0);
+        let response = {
+            let site = ApiSiteString(site.0);
+
+            self.deployment_service
+                .get_by_site(&site)
+                .instrument(record.span.clone())
+                .await?
+                .ok_or(ApiEndpointError::not_found(safe(
+                    "Api deployment not found".to_string(),
+                )))?;
+
+            self.gateway_response_cache
+                .invalidate(&site, key.0.map(CacheKey).as_ref())
+                .instrument(record.span.clone())
+                .await
+                .map_err(ApiEndpointError::internal)?;
+
+            Ok(Json("API deployment cache invalidated".to_string()))
+        };
+
+        record.result(response)
+    }
 }
@@ -1,6 +1,7 @@
 pub mod api_definition;
 pub mod api_deployment;
 mod security_scheme;
+mod topic;
 pub mod worker;
 pub mod worker_connect;
 
@@ -18,6 +19,7 @@ pub type ApiServices = (
     api_definition::RegisterApiDefinitionApi,
     api_deployment::ApiDeploymentApi,
     security_scheme::SecuritySchemeApi,
+    topic::TopicApi,
     HealthcheckApi,
 );
 
@@ -47,6 +49,7 @@ pub fn custom_request_route(services: &Services) -> Route {
         services.http_definition_lookup_service.clone(),
         services.fileserver_binding_handler.clone(),
         services.gateway_session_store.clone(),
+        services.gateway_response_cache.clone(),
     );
 
     Route::new().nest("/", custom_request_executor)
@@ -60,8 +63,12 @@ pub fn make_open_api_service(services: &Services) -> OpenApiService<ApiServices,
                 worker_service: services.worker_service.clone(),
             },
             api_definition::RegisterApiDefinitionApi::new(services.definition_service.clone()),
-            api_deployment::ApiDeploymentApi::new(services.deployment_service.clone()),
+            api_deployment::ApiDeploymentApi::new(
+                services.deployment_service.clone(),
+                services.gateway_response_cache.clone(),
+            ),
             security_scheme::SecuritySchemeApi::new(services.security_scheme_service.clone()),
+            topic::TopicApi::new(services.topic_service.clone()),
             HealthcheckApi,
         ),
         "Golem API",
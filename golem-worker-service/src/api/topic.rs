@@ -0,0 +1,201 @@
+use golem_common::recorded_http_api_request;
+use golem_service_base::api_tags::ApiTags;
+use golem_service_base::auth::DefaultNamespace;
+use golem_worker_service_base::api::{
+    ApiEndpointError, CreateTopicRequest, PublishRequest, PublishResponse, SubscribeRequest,
+    TopicData, TopicSubscriptionData,
+};
+use golem_worker_service_base::service::topic::TopicService;
+use poem_openapi::param::Path;
+use poem_openapi::payload::Json;
+use poem_openapi::OpenApi;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use tracing::Instrument;
+
+pub struct TopicApi {
+    topic_service: Arc<dyn TopicService<DefaultNamespace> + Sync + Send>,
+}
+
+impl TopicApi {
+    pub fn new(topic_service: Arc<dyn TopicService<DefaultNamespace> + Sync + Send>) -> Self {
+        Self { topic_service }
+    }
+}
+
+#[OpenApi(prefix_path = "/v1/topics", tag = ApiTags::Topic)]
+impl TopicApi {
+    /// Create a topic
+    #[oai(path = "/", method = "post", operation_id = "create_topic")]
+    async fn create(
+        &self,
+        payload: Json<CreateTopicRequest>,
+    ) -> Result<Json<TopicData>, ApiEndpointError> {
+        let record = recorded_http_api_request!("create_topic", topic = payload.0.name);
+        let response = self
+            .topic_service
+            .create(&DefaultNamespace::default(), &payload.0.name)
+            .instrument(record.span.clone())
+            .await
+            .map(|topic| Json(TopicData::from(topic)))
+            .map_err(|err| err.into());
+
+        record.result(response)
+    }
+
+    /// Get all topics
+    #[oai(path = "/", method = "get", operation_id = "get_topics")]
+    async fn get_all(&self) -> Result<Json<Vec<TopicData>>, ApiEndpointError> {
+        let record = recorded_http_api_request!("get_topics",);
+        let response = self
+            .topic_service
+            .get_all(&DefaultNamespace::default())
+            .instrument(record.span.clone())
+            .await
+            .map(|topics| Json(topics.into_iter().map(TopicData::from).collect()))
+            .map_err(|err| err.into());
+
+        record.result(response)
+    }
+
+    /// Get a topic
+    #[oai(path = "/:topic", method = "get", operation_id = "get_topic")]
+    async fn get(&self, topic: Path<String>) -> Result<Json<TopicData>, ApiEndpointError> {
+        let record = recorded_http_api_request!("get_topic", topic = topic.0);
+        let response = self
+            .topic_service
+            .get(&DefaultNamespace::default(), &topic.0)
+            .instrument(record.span.clone())
+            .await
+            .map(|topic| Json(TopicData::from(topic)))
+            .map_err(|err| err.into());
+
+        record.result(response)
+    }
+
+    /// Delete a topic
+    ///
+    /// Deletes the topic together with its subscriptions and undelivered messages.
+    #[oai(path = "/:topic", method = "delete", operation_id = "delete_topic")]
+    async fn delete(&self, topic: Path<String>) -> Result<Json<String>, ApiEndpointError> {
+        let record = recorded_http_api_request!("delete_topic", topic = topic.0);
+        let response = self
+            .topic_service
+            .delete(&DefaultNamespace::default(), &topic.0)
+            .instrument(record.span.clone())
+            .await
+            .map(|_| Json("Topic deleted".to_string()))
+            .map_err(|err| err.into());
+
+        record.result(response)
+    }
+
+    /// Subscribe a worker to a topic
+    ///
+    /// Every message published to the topic and matching the optional filter is delivered
+    /// to the worker by invoking the given function with the message as its only parameter.
+    #[oai(
+        path = "/:topic/subscriptions",
+        method = "post",
+        operation_id = "subscribe"
+    )]
+    async fn subscribe(
+        &self,
+        topic: Path<String>,
+        payload: Json<SubscribeRequest>,
+    ) -> Result<Json<TopicSubscriptionData>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "subscribe",
+            topic = topic.0,
+            worker_id = payload.0.worker_id.to_string()
+        );
+        let response = self
+            .topic_service
+            .subscribe(&DefaultNamespace::default(), &topic.0, payload.0.into())
+            .instrument(record.span.clone())
+            .await
+            .map(|subscription| Json(TopicSubscriptionData::from(subscription)))
+            .map_err(|err| err.into());
+
+        record.result(response)
+    }
+
+    /// Get the subscriptions of a topic
+    #[oai(
+        path = "/:topic/subscriptions",
+        method = "get",
+        operation_id = "get_subscriptions"
+    )]
+    async fn get_subscriptions(
+        &self,
+        topic: Path<String>,
+    ) -> Result<Json<Vec<TopicSubscriptionData>>, ApiEndpointError> {
+        let record = recorded_http_api_request!("get_subscriptions", topic = topic.0);
+        let response = self
+            .topic_service
+            .get_subscriptions(&DefaultNamespace::default(), &topic.0)
+            .instrument(record.span.clone())
+            .await
+            .map(|subscriptions| {
+                Json(
+                    subscriptions
+                        .into_iter()
+                        .map(TopicSubscriptionData::from)
+                        .collect(),
+                )
+            })
+            .map_err(|err| err.into());
+
+        record.result(response)
+    }
+
+    /// Unsubscribe from a topic
+    #[oai(
+        path = "/:topic/subscriptions/:subscription_id",
+        method = "delete",
+        operation_id = "unsubscribe"
+    )]
+    async fn unsubscribe(
+        &self,
+        topic: Path<String>,
+        subscription_id: Path<Uuid>,
+    ) -> Result<Json<String>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "unsubscribe",
+            topic = topic.0,
+            subscription_id = subscription_id.0.to_string()
+        );
+        let response = self
+            .topic_service
+            .unsubscribe(&DefaultNamespace::default(), &topic.0, &subscription_id.0)
+            .instrument(record.span.clone())
+            .await
+            .map(|_| Json("Subscription deleted".to_string()))
+            .map_err(|err| err.into());
+
+        record.result(response)
+    }
+
+    /// Publish a message to a topic
+    ///
+    /// The message is stored durably before being delivered to the subscribers. Deliveries
+    /// which fail are retried in the background, so subscribers receive each message at least once.
+    #[oai(path = "/:topic/publish", method = "post", operation_id = "publish")]
+    async fn publish(
+        &self,
+        topic: Path<String>,
+        payload: Json<PublishRequest>,
+    ) -> Result<Json<PublishResponse>, ApiEndpointError> {
+        let record = recorded_http_api_request!("publish", topic = topic.0);
+        let response = self
+            .topic_service
+            .publish(&DefaultNamespace::default(), &topic.0, payload.0.message)
+            .instrument(record.span.clone())
+            .await
+            .map(|message_id| Json(PublishResponse { message_id }))
+            .map_err(|err| err.into());
+
+        record.result(response)
+    }
+}
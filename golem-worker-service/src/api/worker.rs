@@ -37,7 +37,8 @@ impl WorkerApi {
     /// The parameters in the request are the following:
     /// - `name` is the name of the created worker. This has to be unique, but only for a given component
     /// - `args` is a list of strings which appear as command line arguments for the worker
-    /// - `env` is a list of key-value pairs (represented by arrays) which appear as environment variables for the worker
+    /// - `env` is a list of key-value pairs (represented by arrays) which appear as environment variables for the worker. Values of the form `${secret:NAME}` are replaced with the value of the secret when the worker gets loaded
+    /// - `labels` is a list of key-value pairs attached to the worker, which can be used for filtering workers
     #[oai(
         path = "/:component_id/workers",
         method = "post",
@@ -71,7 +72,12 @@ impl WorkerApi {
                     }))
                 })?;
 
-            let WorkerCreationRequest { name, args, env } = request.0;
+            let WorkerCreationRequest {
+                name,
+                args,
+                env,
+                labels,
+            } = request.0;
 
             let worker_id = make_worker_id(component_id, name)?;
             let worker_id = self
@@ -81,6 +87,7 @@ impl WorkerApi {
                     latest_component.versioned_component_id.version,
                     args,
                     env,
+                    labels,
                     empty_worker_metadata(),
                 )
                 .instrument(record.span.clone())
@@ -146,6 +153,8 @@ impl WorkerApi {
             function = function.0
         );
 
+        let invocation_options = params.0.invocation_options();
+
         let response = self
             .worker_service
             .validate_and_invoke_and_await_typed(
@@ -154,6 +163,7 @@ impl WorkerApi {
                 function.0,
                 params.0.params,
                 None,
+                invocation_options,
                 empty_worker_metadata(),
             )
             .instrument(record.span.clone())
@@ -189,6 +199,8 @@ impl WorkerApi {
             function = function.0
         );
 
+        let invocation_options = params.0.invocation_options();
+
         let response = self
             .worker_service
             .validate_and_invoke_and_await_typed(
@@ -197,6 +209,7 @@ impl WorkerApi {
                 function.0,
                 params.0.params,
                 None,
+                invocation_options,
                 empty_worker_metadata(),
             )
             .instrument(record.span.clone())
@@ -231,6 +244,8 @@ impl WorkerApi {
             function = function.0
         );
 
+        let invocation_options = params.0.invocation_options();
+
         let response = self
             .worker_service
             .validate_and_invoke(
@@ -239,6 +254,7 @@ impl WorkerApi {
                 function.0,
                 params.0.params,
                 None,
+                invocation_options,
                 empty_worker_metadata(),
             )
             .instrument(record.span.clone())
@@ -274,6 +290,8 @@ impl WorkerApi {
             function = function.0
         );
 
+        let invocation_options = params.0.invocation_options();
+
         let response = self
             .worker_service
             .validate_and_invoke(
@@ -282,6 +300,7 @@ impl WorkerApi {
                 function.0,
                 params.0.params,
                 None,
+                invocation_options,
                 empty_worker_metadata(),
             )
             .instrument(record.span.clone())
@@ -590,6 +609,69 @@ impl WorkerApi {
         record.result(response)
     }
 
+    /// Dry-run the update of a worker
+    ///
+    /// Replays the worker against the target component version in a sandbox, and reports whether
+    /// the automatic update would succeed and where the replay diverged. The sandbox does not
+    /// change the worker's oplog, key-value, blob store, promise or volume state, and does not
+    /// invoke other workers. Outgoing HTTP requests of the worker are not intercepted.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/update/dry-run",
+        method = "post",
+        operation_id = "dry_run_update_worker"
+    )]
+    async fn dry_run_update_worker(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        params: Json<DryRunUpdateWorkerRequest>,
+    ) -> Result<Json<UpdateDryRunResult>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record =
+            recorded_http_api_request!("dry_run_update_worker", worker_id = worker_id.to_string());
+
+        let response = self
+            .worker_service
+            .dry_run_update(&worker_id, params.target_version, empty_worker_metadata())
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(Json);
+
+        record.result(response)
+    }
+
+    /// Update the labels of a worker
+    ///
+    /// Replaces all the labels of the worker with the given ones.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/labels",
+        method = "put",
+        operation_id = "update_worker_labels"
+    )]
+    async fn update_worker_labels(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        params: Json<UpdateWorkerLabelsRequest>,
+    ) -> Result<Json<UpdateWorkerLabelsResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record =
+            recorded_http_api_request!("update_worker_labels", worker_id = worker_id.to_string());
+
+        let response = self
+            .worker_service
+            .update_labels(&worker_id, params.0.labels, empty_worker_metadata())
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(UpdateWorkerLabelsResponse {}));
+
+        record.result(response)
+    }
+
     /// Get or search the oplog of a worker
     #[oai(
         path = "/:component_id/workers/:worker_name/oplog",
@@ -730,6 +812,92 @@ impl WorkerApi {
         record.result(response)
     }
 
+    /// Write a file in a worker
+    ///
+    /// Creates or overwrites the file at the given path with the request body. Missing parent
+    /// directories are created. Read-only files provided by the component cannot be overwritten.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/files/:file_name",
+        method = "put",
+        operation_id = "put_file"
+    )]
+    async fn put_file(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        file_name: Path<String>,
+        content: Binary<Vec<u8>>,
+    ) -> Result<Json<PutFileResponse>> {
+        let worker_id = make_target_worker_id(component_id.0, Some(worker_name.0))?;
+        let path = make_component_file_path(file_name.0)?;
+        let record = recorded_http_api_request!("put_file", worker_id = worker_id.to_string());
+
+        let response = self
+            .worker_service
+            .put_file(&worker_id, path, content.0, empty_worker_metadata())
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(PutFileResponse {}));
+
+        record.result(response)
+    }
+
+    /// Delete a file or an empty directory in a worker
+    #[oai(
+        path = "/:component_id/workers/:worker_name/files/:file_name",
+        method = "delete",
+        operation_id = "delete_file"
+    )]
+    async fn delete_file(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        file_name: Path<String>,
+    ) -> Result<Json<DeleteFileResponse>> {
+        let worker_id = make_target_worker_id(component_id.0, Some(worker_name.0))?;
+        let path = make_component_file_path(file_name.0)?;
+        let record = recorded_http_api_request!("delete_file", worker_id = worker_id.to_string());
+
+        let response = self
+            .worker_service
+            .delete_file(&worker_id, path, empty_worker_metadata())
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(DeleteFileResponse {}));
+
+        record.result(response)
+    }
+
+    /// Create a directory in a worker
+    #[oai(
+        path = "/:component_id/workers/:worker_name/directories/:file_name",
+        method = "post",
+        operation_id = "make_directory"
+    )]
+    async fn make_directory(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        file_name: Path<String>,
+    ) -> Result<Json<MakeDirectoryResponse>> {
+        let worker_id = make_target_worker_id(component_id.0, Some(worker_name.0))?;
+        let path = make_component_file_path(file_name.0)?;
+        let record =
+            recorded_http_api_request!("make_directory", worker_id = worker_id.to_string());
+
+        let response = self
+            .worker_service
+            .make_directory(&worker_id, path, empty_worker_metadata())
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(MakeDirectoryResponse {}));
+
+        record.result(response)
+    }
+
     /// Activate a plugin
     ///
     /// The plugin must be one of the installed plugins for the worker's current component version.
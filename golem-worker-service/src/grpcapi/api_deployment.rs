@@ -0,0 +1,200 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::Instrument;
+
+use golem_api_grpc::proto::golem::apideployment::v1::{
+    api_deployment_service_server::ApiDeploymentService, diff_api_deployment_revisions_response,
+    get_api_deployment_revisions_response, rollback_api_deployment_response, ApiDeploymentError,
+    DiffApiDeploymentRevisionsRequest, DiffApiDeploymentRevisionsResponse,
+    GetApiDeploymentRevisionsRequest, GetApiDeploymentRevisionsResponse,
+    RollbackApiDeploymentRequest, RollbackApiDeploymentResponse,
+};
+use golem_api_grpc::proto::golem::apideployment::{
+    ApiDeploymentRevision as GrpcApiDeploymentRevision, ApiDeploymentRevisionDiff,
+    ApiDeploymentRevisionList,
+};
+use golem_common::recorded_grpc_api_request;
+use golem_service_base::auth::{DefaultNamespace, EmptyAuthCtx};
+use golem_worker_service_base::api::ApiDeploymentTraceErrorKind;
+use golem_worker_service_base::gateway_api_deployment::ApiSiteString;
+
+#[derive(Clone)]
+pub struct GrpcApiDeploymentService {
+    deployment_service: Arc<
+        dyn golem_worker_service_base::service::gateway::api_deployment::ApiDeploymentService<
+                EmptyAuthCtx,
+                DefaultNamespace,
+            > + Sync
+            + Send,
+    >,
+}
+
+impl GrpcApiDeploymentService {
+    pub fn new(
+        deployment_service: Arc<
+            dyn golem_worker_service_base::service::gateway::api_deployment::ApiDeploymentService<
+                    EmptyAuthCtx,
+                    DefaultNamespace,
+                > + Sync
+                + Send,
+        >,
+    ) -> Self {
+        Self { deployment_service }
+    }
+}
+
+#[async_trait]
+impl ApiDeploymentService for GrpcApiDeploymentService {
+    async fn get_api_deployment_revisions(
+        &self,
+        request: tonic::Request<GetApiDeploymentRevisionsRequest>,
+    ) -> Result<tonic::Response<GetApiDeploymentRevisionsResponse>, tonic::Status> {
+        let request = request.into_inner();
+        let record =
+            recorded_grpc_api_request!("get_api_deployment_revisions", site = request.site,);
+
+        let result = match self
+            .get_api_deployment_revisions(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(revisions) => {
+                record.succeed(get_api_deployment_revisions_response::Result::Success(
+                    ApiDeploymentRevisionList { revisions },
+                ))
+            }
+            Err(error) => record.fail(
+                get_api_deployment_revisions_response::Result::Error(error.clone()),
+                &ApiDeploymentTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(tonic::Response::new(GetApiDeploymentRevisionsResponse {
+            result: Some(result),
+        }))
+    }
+
+    async fn rollback_api_deployment(
+        &self,
+        request: tonic::Request<RollbackApiDeploymentRequest>,
+    ) -> Result<tonic::Response<RollbackApiDeploymentResponse>, tonic::Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "rollback_api_deployment",
+            site = request.site,
+            revision = request.revision,
+        );
+
+        let result = match self
+            .rollback_api_deployment(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(revision) => {
+                record.succeed(rollback_api_deployment_response::Result::Success(revision))
+            }
+            Err(error) => record.fail(
+                rollback_api_deployment_response::Result::Error(error.clone()),
+                &ApiDeploymentTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(tonic::Response::new(RollbackApiDeploymentResponse {
+            result: Some(result),
+        }))
+    }
+
+    async fn diff_api_deployment_revisions(
+        &self,
+        request: tonic::Request<DiffApiDeploymentRevisionsRequest>,
+    ) -> Result<tonic::Response<DiffApiDeploymentRevisionsResponse>, tonic::Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "diff_api_deployment_revisions",
+            site = request.site,
+            from_revision = request.from_revision,
+            to_revision = request.to_revision,
+        );
+
+        let result = match self
+            .diff_api_deployment_revisions(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(diff) => record.succeed(diff_api_deployment_revisions_response::Result::Success(
+                diff,
+            )),
+            Err(error) => record.fail(
+                diff_api_deployment_revisions_response::Result::Error(error.clone()),
+                &ApiDeploymentTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(tonic::Response::new(DiffApiDeploymentRevisionsResponse {
+            result: Some(result),
+        }))
+    }
+}
+
+impl GrpcApiDeploymentService {
+    async fn get_api_deployment_revisions(
+        &self,
+        request: GetApiDeploymentRevisionsRequest,
+    ) -> Result<Vec<GrpcApiDeploymentRevision>, ApiDeploymentError> {
+        let revisions = self
+            .deployment_service
+            .get_revisions(&DefaultNamespace::default(), &ApiSiteString(request.site))
+            .await?;
+
+        Ok(revisions.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn rollback_api_deployment(
+        &self,
+        request: RollbackApiDeploymentRequest,
+    ) -> Result<GrpcApiDeploymentRevision, ApiDeploymentError> {
+        let revision = self
+            .deployment_service
+            .rollback(
+                &DefaultNamespace::default(),
+                &ApiSiteString(request.site),
+                request.revision,
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(revision.into())
+    }
+
+    async fn diff_api_deployment_revisions(
+        &self,
+        request: DiffApiDeploymentRevisionsRequest,
+    ) -> Result<ApiDeploymentRevisionDiff, ApiDeploymentError> {
+        let diff = self
+            .deployment_service
+            .diff_revisions(
+                &DefaultNamespace::default(),
+                &ApiSiteString(request.site),
+                request.from_revision,
+                request.to_revision,
+            )
+            .await?;
+
+        Ok(diff.into())
+    }
+}
@@ -13,11 +13,13 @@
 // limitations under the License.
 
 use crate::grpcapi::api_definition::GrpcApiDefinitionService;
+use crate::grpcapi::api_deployment::GrpcApiDeploymentService;
 use crate::grpcapi::worker::WorkerGrpcApi;
 use crate::service::Services;
 use futures_util::TryFutureExt;
 use golem_api_grpc::proto;
 use golem_api_grpc::proto::golem::apidefinition::v1::api_definition_service_server::ApiDefinitionServiceServer;
+use golem_api_grpc::proto::golem::apideployment::v1::api_deployment_service_server::ApiDeploymentServiceServer;
 use golem_api_grpc::proto::golem::worker::v1::worker_service_server::WorkerServiceServer;
 use std::net::SocketAddr;
 use tokio::net::TcpListener;
@@ -28,6 +30,7 @@ use tonic::transport::Server;
 use tracing::Instrument;
 
 mod api_definition;
+mod api_deployment;
 mod worker;
 
 pub async fn start_grpc_server(
@@ -48,6 +51,10 @@ pub async fn start_grpc_server(
         .set_serving::<ApiDefinitionServiceServer<GrpcApiDefinitionService>>()
         .await;
 
+    health_reporter
+        .set_serving::<ApiDeploymentServiceServer<GrpcApiDeploymentService>>()
+        .await;
+
     let reflection_service = tonic_reflection::server::Builder::configure()
         .register_encoded_file_descriptor_set(proto::FILE_DESCRIPTOR_SET)
         .build()
@@ -62,6 +69,7 @@ pub async fn start_grpc_server(
                     WorkerServiceServer::new(WorkerGrpcApi::new(
                         services.component_service.clone(),
                         services.worker_service.clone(),
+                        services.topic_service.clone(),
                     ))
                     .accept_compressed(CompressionEncoding::Gzip)
                     .send_compressed(CompressionEncoding::Gzip),
@@ -73,6 +81,13 @@ pub async fn start_grpc_server(
                     .accept_compressed(CompressionEncoding::Gzip)
                     .send_compressed(CompressionEncoding::Gzip),
                 )
+                .add_service(
+                    ApiDeploymentServiceServer::new(GrpcApiDeploymentService::new(
+                        services.deployment_service.clone(),
+                    ))
+                    .accept_compressed(CompressionEncoding::Gzip)
+                    .send_compressed(CompressionEncoding::Gzip),
+                )
                 .serve_with_incoming(TcpListenerStream::new(listener))
                 .map_err(anyhow::Error::from)
                 .await
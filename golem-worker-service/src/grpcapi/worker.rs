@@ -20,27 +20,34 @@ use futures::StreamExt;
 use golem_api_grpc::proto::golem::common::{Empty, ErrorBody};
 use golem_api_grpc::proto::golem::worker::v1::worker_service_server::WorkerService as GrpcWorkerService;
 use golem_api_grpc::proto::golem::worker::v1::{
-    activate_plugin_response, complete_promise_response, deactivate_plugin_response,
-    delete_worker_response, get_oplog_response, get_worker_metadata_response,
+    activate_plugin_response, cancel_invocation_response, complete_promise_response,
+    deactivate_plugin_response, delete_file_response, delete_worker_response,
+    dry_run_update_worker_response, get_oplog_response, get_worker_metadata_response,
     get_workers_metadata_response, interrupt_worker_response, invoke_and_await_json_response,
     invoke_and_await_response, invoke_and_await_typed_response, invoke_response,
-    launch_new_worker_response, resume_worker_response, search_oplog_response,
-    update_worker_response, worker_error, worker_execution_error, ActivatePluginRequest,
-    ActivatePluginResponse, CompletePromiseRequest, CompletePromiseResponse, ConnectWorkerRequest,
-    DeactivatePluginRequest, DeactivatePluginResponse, DeleteWorkerRequest, DeleteWorkerResponse,
-    GetOplogRequest, GetOplogResponse, GetOplogSuccessResponse, GetWorkerMetadataRequest,
-    GetWorkerMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse,
-    GetWorkersMetadataSuccessResponse, InterruptWorkerRequest, InterruptWorkerResponse,
-    InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest,
-    InvokeAndAwaitResponse, InvokeAndAwaitTypedResponse, InvokeJsonRequest, InvokeRequest,
-    InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
-    LaunchNewWorkerSuccessResponse, ResumeWorkerRequest, ResumeWorkerResponse, SearchOplogRequest,
-    SearchOplogResponse, SearchOplogSuccessResponse, UnknownError, UpdateWorkerRequest,
-    UpdateWorkerResponse, WorkerError as GrpcWorkerError, WorkerExecutionError,
+    launch_new_worker_response, make_directory_response, publish_topic_message_response,
+    put_file_response, resume_worker_response, search_oplog_response,
+    update_worker_labels_response, update_worker_response, worker_error, worker_execution_error,
+    ActivatePluginRequest, ActivatePluginResponse, CancelInvocationRequest,
+    CancelInvocationResponse, CompletePromiseRequest, CompletePromiseResponse,
+    ConnectWorkerRequest, DeactivatePluginRequest, DeactivatePluginResponse, DeleteFileRequest,
+    DeleteFileResponse, DeleteWorkerRequest, DeleteWorkerResponse, DryRunUpdateWorkerRequest,
+    DryRunUpdateWorkerResponse, GetOplogRequest, GetOplogResponse, GetOplogSuccessResponse,
+    GetWorkerMetadataRequest, GetWorkerMetadataResponse, GetWorkersMetadataRequest,
+    GetWorkersMetadataResponse, GetWorkersMetadataSuccessResponse, InterruptWorkerRequest,
+    InterruptWorkerResponse, InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse,
+    InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeAndAwaitTypedResponse, InvokeJsonRequest,
+    InvokeRequest, InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
+    LaunchNewWorkerSuccessResponse, MakeDirectoryRequest, MakeDirectoryResponse,
+    PublishTopicMessageRequest, PublishTopicMessageResponse, PutFileRequest, PutFileResponse,
+    ResumeWorkerRequest, ResumeWorkerResponse, SearchOplogRequest, SearchOplogResponse,
+    SearchOplogSuccessResponse, UnknownError, UpdateWorkerLabelsRequest,
+    UpdateWorkerLabelsResponse, UpdateWorkerRequest, UpdateWorkerResponse,
+    WorkerError as GrpcWorkerError, WorkerExecutionError,
 };
 use golem_api_grpc::proto::golem::worker::v1::{list_directory_response, GetFileContentsResponse};
 use golem_api_grpc::proto::golem::worker::{
-    InvokeResult, InvokeResultTyped, LogEvent, WorkerMetadata,
+    InvokeResult, InvokeResultTyped, LogEvent, UpdateDryRunResult, WorkerMetadata,
 };
 use golem_common::grpc::{
     proto_component_id_string, proto_idempotency_key_string,
@@ -48,9 +55,11 @@ use golem_common::grpc::{
     proto_target_worker_id_string, proto_worker_id_string,
 };
 use golem_common::model::oplog::OplogIndex;
-use golem_common::model::{ComponentVersion, ScanCursor, WorkerFilter, WorkerId};
+use golem_common::model::{
+    ComponentVersion, InvocationOptions, ScanCursor, WorkerFilter, WorkerId,
+};
 use golem_common::recorded_grpc_api_request;
-use golem_service_base::auth::EmptyAuthCtx;
+use golem_service_base::auth::{DefaultNamespace, EmptyAuthCtx};
 use golem_worker_service_base::api::WorkerTraceErrorKind;
 use golem_worker_service_base::empty_worker_metadata;
 use golem_worker_service_base::grpcapi::{
@@ -58,22 +67,31 @@ use golem_worker_service_base::grpcapi::{
     validate_protobuf_plugin_installation_id, validate_protobuf_target_worker_id,
     validate_protobuf_worker_id, validated_worker_id,
 };
+use golem_worker_service_base::service::topic::TopicService;
 use golem_worker_service_base::service::worker::WorkerStream;
 use std::pin::Pin;
+use std::sync::Arc;
 use tap::TapFallible;
 use tonic::{Request, Response, Status};
 use tracing::Instrument;
+use uuid::Uuid;
 
 pub struct WorkerGrpcApi {
     component_service: ComponentService,
     worker_service: WorkerService,
+    topic_service: Arc<dyn TopicService<DefaultNamespace> + Sync + Send>,
 }
 
 impl WorkerGrpcApi {
-    pub fn new(component_service: ComponentService, worker_service: WorkerService) -> Self {
+    pub fn new(
+        component_service: ComponentService,
+        worker_service: WorkerService,
+        topic_service: Arc<dyn TopicService<DefaultNamespace> + Sync + Send>,
+    ) -> Self {
         Self {
             component_service,
             worker_service,
+            topic_service,
         }
     }
 }
@@ -481,6 +499,33 @@ impl GrpcWorkerService for WorkerGrpcApi {
         }))
     }
 
+    async fn dry_run_update_worker(
+        &self,
+        request: Request<DryRunUpdateWorkerRequest>,
+    ) -> Result<Response<DryRunUpdateWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "dry_run_update_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let response = match self
+            .dry_run_update_worker(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(result) => record.succeed(dry_run_update_worker_response::Result::Success(result)),
+            Err(error) => record.fail(
+                dry_run_update_worker_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(DryRunUpdateWorkerResponse {
+            result: Some(response),
+        }))
+    }
+
     async fn get_oplog(
         &self,
         request: Request<GetOplogRequest>,
@@ -599,6 +644,86 @@ impl GrpcWorkerService for WorkerGrpcApi {
         Ok(Response::new(stream))
     }
 
+    async fn put_file(
+        &self,
+        request: Request<PutFileRequest>,
+    ) -> Result<Response<PutFileResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "put_file",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            path = request.file_path,
+        );
+
+        let response = match self.put_file(request).instrument(record.span.clone()).await {
+            Ok(()) => record.succeed(put_file_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                put_file_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(PutFileResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn delete_file(
+        &self,
+        request: Request<DeleteFileRequest>,
+    ) -> Result<Response<DeleteFileResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "delete_file",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            path = request.file_path,
+        );
+
+        let response = match self
+            .delete_file(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(()) => record.succeed(delete_file_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                delete_file_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(DeleteFileResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn make_directory(
+        &self,
+        request: Request<MakeDirectoryRequest>,
+    ) -> Result<Response<MakeDirectoryResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "make_directory",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            path = request.path,
+        );
+
+        let response = match self
+            .make_directory(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(()) => record.succeed(make_directory_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                make_directory_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(MakeDirectoryResponse {
+            result: Some(response),
+        }))
+    }
+
     async fn activate_plugin(
         &self,
         request: Request<ActivatePluginRequest>,
@@ -654,6 +779,90 @@ impl GrpcWorkerService for WorkerGrpcApi {
             result: Some(response),
         }))
     }
+
+    async fn update_worker_labels(
+        &self,
+        request: Request<UpdateWorkerLabelsRequest>,
+    ) -> Result<Response<UpdateWorkerLabelsResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "update_worker_labels",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let response = match self
+            .update_worker_labels(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(()) => record.succeed(update_worker_labels_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                update_worker_labels_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(UpdateWorkerLabelsResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn cancel_invocation(
+        &self,
+        request: Request<CancelInvocationRequest>,
+    ) -> Result<Response<CancelInvocationResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "cancel_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+        );
+
+        let response = match self
+            .cancel_invocation(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(cancelled) => record.succeed(cancel_invocation_response::Result::Success(cancelled)),
+            Err(error) => record.fail(
+                cancel_invocation_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(CancelInvocationResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn publish_topic_message(
+        &self,
+        request: Request<PublishTopicMessageRequest>,
+    ) -> Result<Response<PublishTopicMessageResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "publish_topic_message",
+            worker_id = proto_worker_id_string(&request.publisher),
+            topic = request.topic,
+            message_id = request.message_id,
+        );
+
+        let response = match self
+            .publish_topic_message(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(()) => record.succeed(publish_topic_message_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                publish_topic_message_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(PublishTopicMessageResponse {
+            result: Some(response),
+        }))
+    }
 }
 
 impl WorkerGrpcApi {
@@ -688,6 +897,7 @@ impl WorkerGrpcApi {
                 latest_component.versioned_component_id.version,
                 request.args,
                 request.env,
+                request.labels,
                 empty_worker_metadata(),
             )
             .await?;
@@ -801,6 +1011,8 @@ impl WorkerGrpcApi {
             .invoke_parameters
             .ok_or_else(|| bad_request_error("Missing invoke parameters"))?;
 
+        let invocation_options = InvocationOptions::try_from(&params).map_err(bad_request_error)?;
+
         self.worker_service
             .invoke(
                 &worker_id,
@@ -808,6 +1020,7 @@ impl WorkerGrpcApi {
                 request.function,
                 params.params,
                 request.context,
+                invocation_options,
                 empty_worker_metadata(),
             )
             .await?;
@@ -832,6 +1045,7 @@ impl WorkerGrpcApi {
                 request.function,
                 params,
                 request.context,
+                InvocationOptions::default(),
                 empty_worker_metadata(),
             )
             .await?;
@@ -849,6 +1063,8 @@ impl WorkerGrpcApi {
             .invoke_parameters
             .ok_or(bad_request_error("Missing invoke parameters"))?;
 
+        let invocation_options = InvocationOptions::try_from(&params).map_err(bad_request_error)?;
+
         let result = self
             .worker_service
             .invoke_and_await(
@@ -857,6 +1073,7 @@ impl WorkerGrpcApi {
                 request.function,
                 params.params,
                 request.context,
+                invocation_options,
                 empty_worker_metadata(),
             )
             .await?;
@@ -884,6 +1101,7 @@ impl WorkerGrpcApi {
                 request.function,
                 params,
                 request.context,
+                InvocationOptions::default(),
                 empty_worker_metadata(),
             )
             .await?;
@@ -908,6 +1126,8 @@ impl WorkerGrpcApi {
             .invoke_parameters
             .ok_or(bad_request_error("Missing invoke parameters"))?;
 
+        let invocation_options = InvocationOptions::try_from(&params).map_err(bad_request_error)?;
+
         let idempotency_key = request
             .idempotency_key
             .ok_or_else(|| bad_request_error("Missing idempotency key"))?
@@ -921,6 +1141,7 @@ impl WorkerGrpcApi {
                 request.function,
                 params.params,
                 request.context,
+                invocation_options,
                 empty_worker_metadata(),
             )
             .await?;
@@ -970,6 +1191,20 @@ impl WorkerGrpcApi {
         Ok(())
     }
 
+    async fn dry_run_update_worker(
+        &self,
+        request: DryRunUpdateWorkerRequest,
+    ) -> Result<UpdateDryRunResult, GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id.clone())?;
+
+        let result = self
+            .worker_service
+            .dry_run_update(&worker_id, request.target_version, empty_worker_metadata())
+            .await?;
+
+        Ok(result.into())
+    }
+
     async fn get_oplog(
         &self,
         request: GetOplogRequest,
@@ -1107,6 +1342,44 @@ impl WorkerGrpcApi {
         Ok(Box::pin(stream))
     }
 
+    async fn put_file(&self, request: PutFileRequest) -> Result<(), GrpcWorkerError> {
+        let worker_id = validate_protobuf_target_worker_id(request.worker_id)?;
+        let file_path = validate_component_file_path(request.file_path)?;
+
+        self.worker_service
+            .put_file(
+                &worker_id,
+                file_path,
+                request.content,
+                empty_worker_metadata(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_file(&self, request: DeleteFileRequest) -> Result<(), GrpcWorkerError> {
+        let worker_id = validate_protobuf_target_worker_id(request.worker_id)?;
+        let file_path = validate_component_file_path(request.file_path)?;
+
+        self.worker_service
+            .delete_file(&worker_id, file_path, empty_worker_metadata())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn make_directory(&self, request: MakeDirectoryRequest) -> Result<(), GrpcWorkerError> {
+        let worker_id = validate_protobuf_target_worker_id(request.worker_id)?;
+        let path = validate_component_file_path(request.path)?;
+
+        self.worker_service
+            .make_directory(&worker_id, path, empty_worker_metadata())
+            .await?;
+
+        Ok(())
+    }
+
     async fn activate_plugin(&self, request: ActivatePluginRequest) -> Result<(), GrpcWorkerError> {
         let worker_id = validate_protobuf_worker_id(request.worker_id)?;
         let plugin_installation_id =
@@ -1133,4 +1406,60 @@ impl WorkerGrpcApi {
 
         Ok(())
     }
+
+    async fn update_worker_labels(
+        &self,
+        request: UpdateWorkerLabelsRequest,
+    ) -> Result<(), GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+
+        self.worker_service
+            .update_labels(&worker_id, request.labels, empty_worker_metadata())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn cancel_invocation(
+        &self,
+        request: CancelInvocationRequest,
+    ) -> Result<bool, GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+
+        let idempotency_key = request
+            .idempotency_key
+            .ok_or_else(|| bad_request_error("Missing idempotency key"))?
+            .into();
+
+        let cancelled = self
+            .worker_service
+            .cancel_invocation(&worker_id, &idempotency_key, empty_worker_metadata())
+            .await?;
+
+        Ok(cancelled)
+    }
+
+    async fn publish_topic_message(
+        &self,
+        request: PublishTopicMessageRequest,
+    ) -> Result<(), GrpcWorkerError> {
+        let message_id = Uuid::parse_str(&request.message_id)
+            .map_err(|err| bad_request_error(format!("Invalid message id: {err}")))?;
+
+        let message = request
+            .message
+            .ok_or_else(|| bad_request_error("Missing message"))?;
+        let message = golem_wasm_rpc::Value::try_from(message).map_err(bad_request_error)?;
+
+        self.topic_service
+            .publish_untyped(
+                &DefaultNamespace::default(),
+                &request.topic,
+                message_id,
+                message,
+            )
+            .await?;
+
+        Ok(())
+    }
 }
@@ -26,7 +26,7 @@ use poem_openapi::OpenApiService;
 use prometheus::Registry;
 use std::net::{Ipv4Addr, SocketAddrV4};
 use tokio::task::JoinSet;
-use tracing::Instrument;
+use tracing::{warn, Instrument};
 
 pub mod api;
 pub mod config;
@@ -86,6 +86,7 @@ impl WorkerService {
         let grpc_port = self.start_grpc_server(join_set).await?;
         let http_port = self.start_http_server(join_set).await?;
         let custom_request_port = self.start_api_gateway_server(join_set).await?;
+        self.start_topic_redelivery(join_set);
 
         Ok(RunDetails {
             http_port,
@@ -171,4 +172,25 @@ impl WorkerService {
 
         Ok(port)
     }
+
+    fn start_topic_redelivery(&self, join_set: &mut JoinSet<anyhow::Result<()>>) {
+        let topic_service = self.services.topic_service.clone();
+        let redelivery_interval = self.config.topics.redelivery_interval;
+
+        join_set.spawn(
+            async move {
+                let mut interval = tokio::time::interval(redelivery_interval);
+                loop {
+                    interval.tick().await;
+                    if let Err(err) = topic_service.redeliver().await {
+                        warn!("Failed to redeliver topic messages: {err}");
+                    }
+                    if let Err(err) = topic_service.purge_completed_messages().await {
+                        warn!("Failed to purge completed topic messages: {err}");
+                    }
+                }
+            }
+            .in_current_span(),
+        );
+    }
 }
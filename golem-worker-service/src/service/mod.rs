@@ -29,7 +29,9 @@ use golem_worker_service_base::gateway_api_definition::http::{
 };
 
 use golem_service_base::auth::{DefaultNamespace, EmptyAuthCtx};
-use golem_worker_service_base::app_config::{KeyValueStorageConfig, WorkerServiceBaseConfig};
+use golem_worker_service_base::app_config::{
+    GatewayResponseCacheConfig, KeyValueStorageConfig, WorkerServiceBaseConfig,
+};
 
 use golem_worker_service_base::gateway_execution::api_definition_lookup::{
     ApiDefinitionsLookup, HttpApiDefinitionLookup,
@@ -37,6 +39,7 @@ use golem_worker_service_base::gateway_execution::api_definition_lookup::{
 use golem_worker_service_base::gateway_execution::GatewayWorkerRequestExecutor;
 use golem_worker_service_base::repo::api_definition;
 use golem_worker_service_base::repo::api_deployment;
+use golem_worker_service_base::repo::topic;
 use golem_worker_service_base::service::component::RemoteComponentService;
 use golem_worker_service_base::service::gateway::api_definition::{
     ApiDefinitionService, ApiDefinitionServiceDefault,
@@ -52,6 +55,9 @@ use golem_common::config::RetryConfig;
 use golem_common::config::DbConfig;
 use golem_common::redis::RedisPool;
 use golem_service_base::db;
+use golem_worker_service_base::gateway_execution::gateway_response_cache::{
+    GatewayResponseCache, InMemoryGatewayResponseCache, RedisGatewayResponseCache,
+};
 use golem_worker_service_base::gateway_execution::gateway_session::{
     GatewaySession, GatewaySessionWithInMemoryCache, RedisGatewaySession,
 };
@@ -64,6 +70,7 @@ use golem_worker_service_base::service::gateway::api_deployment::{
 use golem_worker_service_base::service::gateway::security_scheme::{
     DefaultSecuritySchemeService, SecuritySchemeService,
 };
+use golem_worker_service_base::service::topic::{DefaultTopicService, TopicService};
 use std::sync::Arc;
 use std::time::Duration;
 use tonic::codec::CompressionEncoding;
@@ -87,10 +94,12 @@ pub struct Services {
     pub worker_to_http_service:
         Arc<dyn GatewayWorkerRequestExecutor<DefaultNamespace> + Sync + Send>,
     pub gateway_session_store: Arc<dyn GatewaySession + Sync + Send>,
+    pub gateway_response_cache: Arc<dyn GatewayResponseCache + Sync + Send>,
     pub api_definition_validator_service:
         Arc<dyn ApiDefinitionValidatorService<HttpApiDefinition> + Sync + Send>,
     pub fileserver_binding_handler:
         Arc<dyn FileServerBindingHandler<DefaultNamespace> + Sync + Send>,
+    pub topic_service: Arc<dyn TopicService<DefaultNamespace> + Sync + Send>,
 }
 
 impl Services {
@@ -157,55 +166,83 @@ impl Services {
             }
         };
 
-        let (api_definition_repo, api_deployment_repo, security_scheme_repo) = match config
-            .db
-            .clone()
-        {
-            DbConfig::Postgres(c) => {
-                let db_pool = db::create_postgres_pool(&c)
-                    .await
-                    .map_err(|e| e.to_string())?;
-                let api_definition_repo: Arc<dyn api_definition::ApiDefinitionRepo + Sync + Send> =
-                    Arc::new(api_definition::LoggedApiDefinitionRepo::new(
+        let gateway_response_cache: Arc<dyn GatewayResponseCache + Sync + Send> =
+            match &config.gateway_response_cache {
+                GatewayResponseCacheConfig::InMemory(in_memory_config) => {
+                    Arc::new(InMemoryGatewayResponseCache::new(in_memory_config.capacity))
+                }
+                GatewayResponseCacheConfig::Redis(redis_config) => {
+                    let redis = RedisPool::configured(redis_config)
+                        .await
+                        .map_err(|e| e.to_string())?;
+
+                    Arc::new(RedisGatewayResponseCache::new(redis))
+                }
+            };
+
+        let (api_definition_repo, api_deployment_repo, security_scheme_repo, topic_repo) =
+            match config.db.clone() {
+                DbConfig::Postgres(c) => {
+                    let db_pool = db::create_postgres_pool(&c)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    let api_definition_repo: Arc<
+                        dyn api_definition::ApiDefinitionRepo + Sync + Send,
+                    > = Arc::new(api_definition::LoggedApiDefinitionRepo::new(
                         api_definition::DbApiDefinitionRepo::new(db_pool.clone().into()),
                     ));
-                let api_deployment_repo: Arc<dyn api_deployment::ApiDeploymentRepo + Sync + Send> =
-                    Arc::new(api_deployment::LoggedDeploymentRepo::new(
+                    let api_deployment_repo: Arc<
+                        dyn api_deployment::ApiDeploymentRepo + Sync + Send,
+                    > = Arc::new(api_deployment::LoggedDeploymentRepo::new(
                         api_deployment::DbApiDeploymentRepo::new(db_pool.clone().into()),
                     ));
 
-                let security_scheme_repo: Arc<dyn SecuritySchemeRepo + Sync + Send> =
-                    Arc::new(DbSecuritySchemeRepo::new(db_pool.clone().into()));
-
-                (
-                    api_definition_repo,
-                    api_deployment_repo,
-                    security_scheme_repo,
-                )
-            }
-            DbConfig::Sqlite(c) => {
-                let db_pool = db::create_sqlite_pool(&c)
-                    .await
-                    .map_err(|e| e.to_string())?;
-                let api_definition_repo: Arc<dyn api_definition::ApiDefinitionRepo + Sync + Send> =
-                    Arc::new(api_definition::LoggedApiDefinitionRepo::new(
+                    let security_scheme_repo: Arc<dyn SecuritySchemeRepo + Sync + Send> =
+                        Arc::new(DbSecuritySchemeRepo::new(db_pool.clone().into()));
+
+                    let topic_repo: Arc<dyn topic::TopicRepo + Sync + Send> =
+                        Arc::new(topic::LoggedTopicRepo::new(topic::DbTopicRepo::new(
+                            db_pool.clone().into(),
+                        )));
+
+                    (
+                        api_definition_repo,
+                        api_deployment_repo,
+                        security_scheme_repo,
+                        topic_repo,
+                    )
+                }
+                DbConfig::Sqlite(c) => {
+                    let db_pool = db::create_sqlite_pool(&c)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    let api_definition_repo: Arc<
+                        dyn api_definition::ApiDefinitionRepo + Sync + Send,
+                    > = Arc::new(api_definition::LoggedApiDefinitionRepo::new(
                         api_definition::DbApiDefinitionRepo::new(db_pool.clone().into()),
                     ));
-                let api_deployment_repo: Arc<dyn api_deployment::ApiDeploymentRepo + Sync + Send> =
-                    Arc::new(api_deployment::LoggedDeploymentRepo::new(
+                    let api_deployment_repo: Arc<
+                        dyn api_deployment::ApiDeploymentRepo + Sync + Send,
+                    > = Arc::new(api_deployment::LoggedDeploymentRepo::new(
                         api_deployment::DbApiDeploymentRepo::new(db_pool.clone().into()),
                     ));
 
-                let security_scheme_repo: Arc<dyn SecuritySchemeRepo + Sync + Send> =
-                    Arc::new(DbSecuritySchemeRepo::new(db_pool.clone().into()));
+                    let security_scheme_repo: Arc<dyn SecuritySchemeRepo + Sync + Send> =
+                        Arc::new(DbSecuritySchemeRepo::new(db_pool.clone().into()));
 
-                (
-                    api_definition_repo,
-                    api_deployment_repo,
-                    security_scheme_repo,
-                )
-            }
-        };
+                    let topic_repo: Arc<dyn topic::TopicRepo + Sync + Send> =
+                        Arc::new(topic::LoggedTopicRepo::new(topic::DbTopicRepo::new(
+                            db_pool.clone().into(),
+                        )));
+
+                    (
+                        api_definition_repo,
+                        api_deployment_repo,
+                        security_scheme_repo,
+                        topic_repo,
+                    )
+                }
+            };
 
         let blob_storage: Arc<dyn BlobStorage + Sync + Send> = match &config.blob_storage {
             BlobStorageConfig::S3(config) => Arc::new(
@@ -268,11 +305,20 @@ impl Services {
             api_deployment_repo.clone(),
             api_definition_repo.clone(),
             component_service.clone(),
+            gateway_response_cache.clone(),
         ));
 
         let http_definition_lookup_service =
             Arc::new(HttpApiDefinitionLookup::new(deployment_service.clone()));
 
+        let topic_service: Arc<dyn TopicService<DefaultNamespace> + Sync + Send> =
+            Arc::new(DefaultTopicService::new(
+                topic_repo.clone(),
+                worker_service.clone(),
+                component_service.clone(),
+                config.topics.clone(),
+            ));
+
         Ok(Services {
             worker_service,
             definition_service,
@@ -284,6 +330,8 @@ impl Services {
             api_definition_validator_service,
             fileserver_binding_handler,
             gateway_session_store,
+            gateway_response_cache,
+            topic_service,
         })
     }
 }
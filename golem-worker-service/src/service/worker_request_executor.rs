@@ -99,6 +99,7 @@ impl GatewayWorkerRequestExecutor<DefaultNamespace> for UnauthorisedWorkerReques
                 worker_request_params.function_name,
                 invoke_parameters,
                 None,
+                worker_request_params.invocation_options,
                 empty_worker_metadata(),
             )
             .await
@@ -21,7 +21,9 @@ use golem_common::config::DbSqliteConfig;
 use golem_common::model::Empty;
 use golem_component_service::config::ComponentServiceConfig;
 use golem_component_service::ComponentService;
-use golem_component_service_base::config::{ComponentStoreConfig, ComponentStoreLocalConfig};
+use golem_component_service_base::config::{
+    ComponentStoreConfig, ComponentStoreLocalConfig, SecretsConfig,
+};
 use golem_service_base::config::BlobStorageConfig;
 use golem_service_base::config::LocalFileSystemBlobStorageConfig;
 use golem_service_base::service::routing_table::RoutingTableConfig;
@@ -33,7 +35,7 @@ use golem_worker_executor_base::services::golem_config::ComponentServiceGrpcConf
 use golem_worker_executor_base::services::golem_config::ShardManagerServiceConfig;
 use golem_worker_executor_base::services::golem_config::ShardManagerServiceGrpcConfig;
 use golem_worker_executor_base::services::golem_config::{
-    GolemConfig, IndexedStorageConfig, KeyValueStorageConfig,
+    GolemConfig, GracefulShutdownConfig, IndexedStorageConfig, KeyValueStorageConfig,
 };
 use golem_worker_service::WorkerService;
 use golem_worker_service_base::app_config::WorkerServiceBaseConfig;
@@ -82,7 +84,7 @@ pub async fn launch_golem_services(args: &LaunchArgs) -> Result<(), anyhow::Erro
 
     let shard_manager = run_shard_manager(shard_manager_config(args), &mut join_set).await?;
     let component_service =
-        run_component_service(component_service_config(args), &mut join_set).await?;
+        run_component_service(component_service_config(args)?, &mut join_set).await?;
     let worker_executor = run_worker_executor(
         worker_executor_config(args, &shard_manager, &component_service),
         &mut join_set,
@@ -140,8 +142,32 @@ fn shard_manager_config(args: &LaunchArgs) -> ShardManagerConfig {
     }
 }
 
-fn component_service_config(args: &LaunchArgs) -> ComponentServiceConfig {
-    ComponentServiceConfig {
+/// Reads the key encrypting the component secrets from the data directory, generating it on the
+/// first launch
+fn secrets_encryption_key(args: &LaunchArgs) -> Result<String, anyhow::Error> {
+    let path = args.data_dir.join("secrets-encryption.key");
+    if path.exists() {
+        let key = std::fs::read_to_string(&path).with_context(|| {
+            format!(
+                "Failed to read the secrets encryption key from {}",
+                path.display()
+            )
+        })?;
+        Ok(key.trim().to_string())
+    } else {
+        let key = hex::encode(rand::random::<[u8; 32]>());
+        std::fs::write(&path, &key).with_context(|| {
+            format!(
+                "Failed to write the secrets encryption key to {}",
+                path.display()
+            )
+        })?;
+        Ok(key)
+    }
+}
+
+fn component_service_config(args: &LaunchArgs) -> Result<ComponentServiceConfig, anyhow::Error> {
+    Ok(ComponentServiceConfig {
         http_port: 0,
         grpc_port: 0,
         db: DbConfig::Sqlite(DbSqliteConfig {
@@ -164,8 +190,12 @@ fn component_service_config(args: &LaunchArgs) -> ComponentServiceConfig {
         compilation: golem_component_service_base::config::ComponentCompilationConfig::Disabled(
             Empty {},
         ),
+        secrets: SecretsConfig {
+            encryption_key: Some(secrets_encryption_key(args)?),
+            resolver_access_token: Some(ComponentServiceGrpcConfig::default().access_token),
+        },
         ..Default::default()
-    }
+    })
 }
 
 fn worker_executor_config(
@@ -199,6 +229,10 @@ fn worker_executor_config(
             port: shard_manager_run_details.grpc_port,
             ..ShardManagerServiceGrpcConfig::default()
         }),
+        graceful_shutdown: GracefulShutdownConfig {
+            enabled: false,
+            ..GracefulShutdownConfig::default()
+        },
         ..Default::default()
     };
 
@@ -0,0 +1,128 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use golem_common::model::ComponentType;
+use golem_test_framework::config::{CliParams, TestDependencies};
+use golem_test_framework::dsl::benchmark::{Benchmark, BenchmarkRecorder, RunConfig};
+use golem_test_framework::dsl::TestDsl;
+use golem_wasm_rpc::Value;
+use integration_tests::benchmarks::{
+    benchmark_invocations, generate_worker_ids, invoke_and_await, run_benchmark, setup_benchmark,
+    SimpleBenchmarkContext, SimpleIterationContext,
+};
+
+/// Measures the invocation latency of an ephemeral component, where every invocation creates
+/// a new instance. The first invocation pays for compiling and pre-instantiating the component,
+/// all the following ones should reuse the cached `InstancePre`.
+///
+/// Run it with `--instance-pre-cache true --instance-pre-cache false` and
+/// `--pooling-allocator false --pooling-allocator true` to compare the worker executor variants.
+struct EphemeralInstantiation {
+    config: RunConfig,
+    params: CliParams,
+}
+
+#[async_trait]
+impl Benchmark for EphemeralInstantiation {
+    type BenchmarkContext = SimpleBenchmarkContext;
+    type IterationContext = SimpleIterationContext;
+
+    fn name() -> &'static str {
+        "ephemeral-instantiation"
+    }
+
+    async fn create_benchmark_context(
+        params: CliParams,
+        cluster_size: usize,
+    ) -> Self::BenchmarkContext {
+        setup_benchmark(params, cluster_size).await
+    }
+
+    async fn cleanup(benchmark_context: Self::BenchmarkContext) {
+        benchmark_context.deps.kill_all().await
+    }
+
+    async fn create(params: CliParams, config: RunConfig) -> Self {
+        Self { config, params }
+    }
+
+    async fn setup_iteration(
+        &self,
+        benchmark_context: &Self::BenchmarkContext,
+    ) -> Self::IterationContext {
+        // Uploading a fresh ephemeral component for each iteration, so the first invocation
+        // always starts with an empty instance cache
+        let component_id = benchmark_context
+            .deps
+            .store_unique_component_with_files("rust-echo", ComponentType::Ephemeral, &[])
+            .await;
+
+        let worker_ids = generate_worker_ids(self.config.size, &component_id, "worker");
+        SimpleIterationContext { worker_ids }
+    }
+
+    async fn warmup(
+        &self,
+        _benchmark_context: &Self::BenchmarkContext,
+        _context: &Self::IterationContext,
+    ) {
+        if !self.params.mode.compilation_service_disabled() {
+            // Waiting a bit so the component compilation service can precompile the component
+            tokio::time::sleep(std::time::Duration::from_secs(10)).await;
+        }
+    }
+
+    async fn run(
+        &self,
+        benchmark_context: &Self::BenchmarkContext,
+        context: &Self::IterationContext,
+        recorder: BenchmarkRecorder,
+    ) {
+        if let Some(first) = context.worker_ids.first() {
+            let result = invoke_and_await(
+                &benchmark_context.deps,
+                first,
+                "golem:it/api.{echo}",
+                vec![Value::String("hello".to_string())],
+            )
+            .await;
+            recorder.duration(&"first-invocation".into(), result.accumulated_time);
+        }
+
+        benchmark_invocations(
+            &benchmark_context.deps,
+            recorder,
+            self.config.length,
+            &context.worker_ids,
+            "golem:it/api.{echo}",
+            vec![Value::String("hello".to_string())],
+            "",
+        )
+        .await
+    }
+
+    async fn cleanup_iteration(
+        &self,
+        _benchmark_context: &Self::BenchmarkContext,
+        _context: Self::IterationContext,
+    ) {
+        // Ephemeral workers are not persisted, so there is nothing to delete
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    run_benchmark::<EphemeralInstantiation>().await;
+}
@@ -72,14 +72,15 @@ impl BenchmarkComparisonReport {
 
         for report in self.results.iter() {
             let mut table = vec![];
-            table.push("| ResultKey | Cluster Size | Size | Length | Previous Avg Time | Current Avg Time |".to_string());
-            table.push("|---------------|--------------|------|--------|-------------------|------------------|".to_string());
+            table.push("| ResultKey | Cluster Size | Worker Executor | Size | Length | Previous Avg Time | Current Avg Time |".to_string());
+            table.push("|---------------|--------------|-----------------|------|--------|-------------------|------------------|".to_string());
 
             for run_config_report in report.comparison_results.results.iter() {
                 table.push(format!(
-                    r#"| {} | {} | {} | {} | {} | {} |"#,
+                    r#"| {} | {} | {} | {} | {} | {} | {} |"#,
                     run_config_report.report_key.result_key,
                     run_config_report.report_key.run_config.cluster_size,
+                    run_config_report.report_key.run_config.worker_executor,
                     run_config_report.report_key.run_config.size,
                     run_config_report.report_key.run_config.length,
                     display_optional_duration(run_config_report.comparison.previous_avg),
@@ -116,14 +117,21 @@ impl BenchmarkReport {
 
         for report in self.results.iter() {
             let mut table = vec![];
-            table.push("| Result Key | Cluster Size | Size | Length | Avg Time |".to_string());
-            table.push("|---------------|--------------|------|--------|----------|".to_string());
+            table.push(
+                "| Result Key | Cluster Size | Worker Executor | Size | Length | Avg Time |"
+                    .to_string(),
+            );
+            table.push(
+                "|---------------|--------------|-----------------|------|--------|----------|"
+                    .to_string(),
+            );
 
             for run_config_report in report.report.results.iter() {
                 table.push(format!(
-                    r#"| {} | {} | {} | {} | {:?} |"#,
+                    r#"| {} | {} | {} | {} | {} | {:?} |"#,
                     run_config_report.report_key.result_key,
                     run_config_report.report_key.run_config.cluster_size,
+                    run_config_report.report_key.run_config.worker_executor,
                     run_config_report.report_key.run_config.size,
                     run_config_report.report_key.run_config.length,
                     run_config_report.avg_time
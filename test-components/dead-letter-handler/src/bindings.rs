@@ -0,0 +1,262 @@
+// Generated by `wit-bindgen` 0.25.0. DO NOT EDIT!
+// Options used:
+#[allow(dead_code)]
+pub mod exports {
+    #[allow(dead_code)]
+    pub mod golem {
+        #[allow(dead_code)]
+        pub mod it {
+            #[allow(dead_code, clippy::all)]
+            pub mod api {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_handle_failure_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: *mut u8,
+                    arg3: usize,
+                    arg4: i32,
+                    arg5: *mut u8,
+                    arg6: usize,
+                    arg7: i32,
+                    arg8: *mut u8,
+                    arg9: usize,
+                ) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let len1 = arg3;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+                    T::handle_failure(
+                        _rt::string_lift(bytes0),
+                        _rt::string_lift(bytes1),
+                        match arg4 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len2 = arg6;
+                                    let bytes2 = _rt::Vec::from_raw_parts(arg5.cast(), len2, len2);
+
+                                    _rt::string_lift(bytes2)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        match arg7 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len3 = arg9;
+                                    let bytes3 = _rt::Vec::from_raw_parts(arg8.cast(), len3, len3);
+
+                                    _rt::string_lift(bytes3)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_get_failures_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::get_failures();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec5 = result0;
+                    let len5 = vec5.len();
+                    let layout5 = _rt::alloc::Layout::from_size_align_unchecked(vec5.len() * 20, 4);
+                    let result5 = if layout5.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout5);
+                        }
+                        ptr
+                    } else {
+                        {
+                            ::core::ptr::null_mut()
+                        }
+                    };
+                    for (i, e) in vec5.into_iter().enumerate() {
+                        let base = result5.add(i * 20);
+                        {
+                            let (t2_0, t2_1) = e;
+                            let vec3 = (t2_0.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *base.add(4).cast::<usize>() = len3;
+                            *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                            match t2_1 {
+                                Some(e) => {
+                                    *base.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *base.add(16).cast::<usize>() = len4;
+                                    *base.add(12).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                None => {
+                                    *base.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len5;
+                    *ptr1.add(0).cast::<*mut u8>() = result5;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_get_failures<T: Guest>(arg0: *mut u8) {
+                    let l5 = *arg0.add(0).cast::<*mut u8>();
+                    let l6 = *arg0.add(4).cast::<usize>();
+                    let base7 = l5;
+                    let len7 = l6;
+                    for i in 0..len7 {
+                        let base = base7.add(i * 20);
+                        {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            _rt::cabi_dealloc(l0, l1, 1);
+                            let l2 = i32::from(*base.add(8).cast::<u8>());
+                            match l2 {
+                                0 => (),
+                                _ => {
+                                    let l3 = *base.add(12).cast::<*mut u8>();
+                                    let l4 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l3, l4, 1);
+                                }
+                            }
+                        }
+                    }
+                    _rt::cabi_dealloc(base7, len7 * 20, 4);
+                }
+                pub trait Guest {
+                    /// Dead-letter handler receiving the failures of the workers of another component
+                    fn handle_failure(
+                        worker_id: _rt::String,
+                        error: _rt::String,
+                        function_name: Option<_rt::String>,
+                        idempotency_key: Option<_rt::String>,
+                    );
+                    /// Returns the ids of the failed workers and the names of the failed functions, in the order they were reported
+                    fn get_failures() -> _rt::Vec<(_rt::String, Option<_rt::String>)>;
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_golem_it_api_cabi{
+        ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+          #[export_name = "golem:it/api#handle-failure"]
+          unsafe extern "C" fn export_handle_failure(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: i32,arg5: *mut u8,arg6: usize,arg7: i32,arg8: *mut u8,arg9: usize,) {
+            $($path_to_types)*::_export_handle_failure_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9)
+          }
+          #[export_name = "golem:it/api#get-failures"]
+          unsafe extern "C" fn export_get_failures() -> *mut u8 {
+            $($path_to_types)*::_export_get_failures_cabi::<$ty>()
+          }
+          #[export_name = "cabi_post_golem:it/api#get-failures"]
+          unsafe extern "C" fn _post_return_get_failures(arg0: *mut u8,) {
+            $($path_to_types)*::__post_return_get_failures::<$ty>(arg0)
+          }
+        };);
+      }
+                #[doc(hidden)]
+                pub(crate) use __export_golem_it_api_cabi;
+                #[repr(align(4))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+            }
+        }
+    }
+}
+mod _rt {
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn run_ctors_once() {
+        wit_bindgen_rt::run_ctors_once();
+    }
+    pub use alloc_crate::vec::Vec;
+    pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
+        if cfg!(debug_assertions) {
+            String::from_utf8(bytes).unwrap()
+        } else {
+            String::from_utf8_unchecked(bytes)
+        }
+    }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            core::hint::unreachable_unchecked()
+        }
+    }
+    pub use alloc_crate::alloc;
+    pub use alloc_crate::string::String;
+    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
+        if size == 0 {
+            return;
+        }
+        let layout = alloc::Layout::from_size_align_unchecked(size, align);
+        alloc::dealloc(ptr as *mut u8, layout);
+    }
+    extern crate alloc as alloc_crate;
+}
+
+/// Generates `#[no_mangle]` functions to export the specified type as the
+/// root implementation of all generated traits.
+///
+/// For more information see the documentation of `wit_bindgen::generate!`.
+///
+/// ```rust
+/// # macro_rules! export{ ($($t:tt)*) => (); }
+/// # trait Guest {}
+/// struct MyType;
+///
+/// impl Guest for MyType {
+///     // ...
+/// }
+///
+/// export!(MyType);
+/// ```
+#[allow(unused_macros)]
+#[doc(hidden)]
+
+macro_rules! __export_dead_letter_handler_impl {
+  ($ty:ident) => (self::export!($ty with_types_in self););
+  ($ty:ident with_types_in $($path_to_types_root:tt)*) => (
+  $($path_to_types_root)*::exports::golem::it::api::__export_golem_it_api_cabi!($ty with_types_in $($path_to_types_root)*::exports::golem::it::api);
+  )
+}
+#[doc(inline)]
+pub(crate) use __export_dead_letter_handler_impl as export;
+
+#[cfg(target_arch = "wasm32")]
+#[link_section = "component-type:wit-bindgen:0.25.0:dead-letter-handler:encoded world"]
+#[doc(hidden)]
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 304] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xa6\x01\x01A\x02\x01\
+A\x02\x01B\x07\x01ks\x01@\x04\x09worker-ids\x05errors\x0dfunction-name\0\x0fidem\
+potency-key\0\x01\0\x04\0\x0ehandle-failure\x01\x01\x01o\x02s\0\x01p\x02\x01@\0\0\
+\x03\x04\0\x0cget-failures\x01\x04\x04\x01\x0cgolem:it/api\x05\0\x04\x01\x1cgole\
+m:it/dead-letter-handler\x04\0\x0b\x19\x01\0\x13dead-letter-handler\x03\0\0\0G\x09\
+producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.208.1\x10wit-bindgen-rus\
+t\x060.25.0";
+
+#[inline(never)]
+#[doc(hidden)]
+#[cfg(target_arch = "wasm32")]
+pub fn __link_custom_section_describing_imports() {
+    wit_bindgen_rt::maybe_link_cabi_realloc();
+}
@@ -0,0 +1,35 @@
+mod bindings;
+
+use crate::bindings::exports::golem::it::api::Guest;
+
+struct State {
+    failures: Vec<(String, Option<String>)>,
+}
+
+static mut STATE: State = State {
+    failures: Vec::new(),
+};
+
+fn with_state<T>(f: impl FnOnce(&mut State) -> T) -> T {
+    unsafe { f(&mut STATE) }
+}
+
+struct Component;
+
+impl Guest for Component {
+    fn handle_failure(
+        worker_id: String,
+        error: String,
+        function_name: Option<String>,
+        idempotency_key: Option<String>,
+    ) {
+        println!("{worker_id} failed in {function_name:?} ({idempotency_key:?}): {error}");
+        with_state(|state| state.failures.push((worker_id, function_name)));
+    }
+
+    fn get_failures() -> Vec<(String, Option<String>)> {
+        with_state(|state| state.failures.clone())
+    }
+}
+
+bindings::export!(Component with_types_in bindings);
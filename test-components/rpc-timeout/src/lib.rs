@@ -0,0 +1,79 @@
+mod bindings;
+
+use crate::bindings::golem::api::rpc::{
+    async_invoke_and_await, cancel, get_result, invoke_and_await, RpcError,
+};
+use crate::bindings::golem::rpc::types::{Uri, WasmRpc, WitNode, WitValue};
+use crate::bindings::Guest;
+use std::env;
+
+struct Component;
+
+impl Guest for Component {
+    fn sleep_with_timeout(
+        worker_name: String,
+        seconds: f64,
+        timeout_millis: u64,
+    ) -> Result<f64, String> {
+        let rpc = WasmRpc::new(&clocks_uri(&worker_name));
+        let timeout = timeout_millis * 1_000_000;
+        invoke_and_await(&rpc, "sleep-for", &[f64_value(seconds)], Some(timeout))
+            .map(get_f64)
+            .map_err(error_message)
+    }
+
+    fn cancel_queued_sleep(worker_name: String, seconds: f64) -> (bool, Result<f64, String>) {
+        let rpc = WasmRpc::new(&clocks_uri(&worker_name));
+        rpc.invoke("sleep-for", &[f64_value(seconds)])
+            .expect("failed to occupy the remote worker");
+
+        let future = async_invoke_and_await(&rpc, "sleep-for", &[f64_value(seconds)], None);
+        let cancelled = cancel(&future);
+
+        let result = loop {
+            match get_result(&future) {
+                Some(result) => break result,
+                None => future.subscribe().block(),
+            }
+        };
+        (cancelled, result.map(get_f64).map_err(error_message))
+    }
+}
+
+fn clocks_uri(worker_name: &str) -> Uri {
+    let component_id = env::var("CLOCKS_COMPONENT_ID").expect("CLOCKS_COMPONENT_ID not set");
+    Uri {
+        value: format!("urn:worker:{component_id}/{worker_name}"),
+    }
+}
+
+fn f64_value(value: f64) -> WitValue {
+    WitValue {
+        nodes: vec![WitNode::PrimFloat64(value)],
+    }
+}
+
+/// Extracts the single `float64` result from the tuple of results returned by the remote function
+fn get_f64(value: WitValue) -> f64 {
+    let element = match value.nodes.first() {
+        Some(WitNode::TupleValue(elements)) if elements.len() == 1 => elements[0] as usize,
+        other => panic!("unexpected result: {other:?}"),
+    };
+    match value.nodes.get(element) {
+        Some(WitNode::PrimFloat64(value)) => *value,
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+fn error_message(error: RpcError) -> String {
+    match error {
+        RpcError::ProtocolError(details) => format!("protocol-error: {details}"),
+        RpcError::Denied(details) => format!("denied: {details}"),
+        RpcError::NotFound(details) => format!("not-found: {details}"),
+        RpcError::RemoteInternalError(details) => format!("remote-internal-error: {details}"),
+        RpcError::Timeout(details) => format!("timeout: {details}"),
+        RpcError::Cancelled(details) => format!("cancelled: {details}"),
+    }
+}
+
+bindings::export!(Component with_types_in bindings);
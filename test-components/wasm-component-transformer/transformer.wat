@@ -0,0 +1,64 @@
+;; A minimal component transformer plugin implementing `golem:plugin/component-transformer@1.0.0`.
+;; It returns the input component with an empty custom section named `transformed` appended.
+(component
+  (core module $transformer
+    (memory (export "memory") 1)
+    (global $heap (mut i32) (i32.const 1024))
+
+    ;; The appended custom section: id 0, size 12, name length 11, name
+    (data (i32.const 16) "\00\0c\0btransformed")
+
+    ;; Bump allocator, growing the memory as needed
+    (func $realloc (export "cabi_realloc")
+      (param $old_ptr i32) (param $old_size i32) (param $align i32) (param $new_size i32)
+      (result i32)
+      (local $ptr i32)
+      (local.set $ptr
+        (i32.and
+          (i32.add (global.get $heap) (i32.sub (local.get $align) (i32.const 1)))
+          (i32.sub (i32.const 0) (local.get $align))))
+      (global.set $heap (i32.add (local.get $ptr) (local.get $new_size)))
+      (block $enough
+        (loop $grow
+          (br_if $enough
+            (i32.le_u (global.get $heap) (i32.shl (memory.size) (i32.const 16))))
+          (if (i32.eq (memory.grow (i32.const 1)) (i32.const -1))
+            (then unreachable))
+          (br $grow)))
+      (local.get $ptr))
+
+    ;; Returns a pointer to the `result<list<u8>, string>` stored at address 0
+    (func (export "transform")
+      (param $component_ptr i32) (param $component_len i32)
+      (param $metadata_ptr i32) (param $metadata_len i32)
+      (param $parameters_ptr i32) (param $parameters_len i32)
+      (result i32)
+      (local $out i32)
+      (local.set $out
+        (call $realloc
+          (i32.const 0) (i32.const 0) (i32.const 1)
+          (i32.add (local.get $component_len) (i32.const 14))))
+      (memory.copy (local.get $out) (local.get $component_ptr) (local.get $component_len))
+      (memory.copy
+        (i32.add (local.get $out) (local.get $component_len))
+        (i32.const 16)
+        (i32.const 14))
+      (i32.store8 (i32.const 0) (i32.const 0))
+      (i32.store (i32.const 4) (local.get $out))
+      (i32.store (i32.const 8) (i32.add (local.get $component_len) (i32.const 14)))
+      (i32.const 0)))
+
+  (core instance $instance (instantiate $transformer))
+
+  (func $transform
+    (param "component" (list u8))
+    (param "metadata" string)
+    (param "parameters" (list (tuple string string)))
+    (result (result (list u8) (error string)))
+    (canon lift
+      (core func $instance "transform")
+      (memory $instance "memory")
+      (realloc (func $instance "cabi_realloc"))))
+
+  (instance $component-transformer (export "transform" (func $transform)))
+  (export "golem:plugin/component-transformer@1.0.0" (instance $component-transformer)))